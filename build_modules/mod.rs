@@ -1,2 +0,0 @@
-pub mod api_generator;
-pub mod configuration_transformer;
\ No newline at end of file
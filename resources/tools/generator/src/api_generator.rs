@@ -12,28 +12,93 @@ use ast_shaper::utils::path::Path;
 use ast_shaper::utils::punctuated::PunctuatedExt;
 use ast_shaper::utils::statement::{Expr, Statement};
 
-pub struct ApiGenerator;
+pub struct ApiGenerator {
+    method_suffix: Option<String>,
+    group_by_prefix: bool,
+    module_groups: Vec<String>,
+}
 
 impl ApiGenerator {
     pub fn new() -> Self {
-        Self
+        Self {
+            method_suffix: None,
+            group_by_prefix: false,
+            module_groups: Vec::new(),
+        }
+    }
+
+    /// Generator for the Podman libpod API. Operations are suffixed with `_libpod` and a tag
+    /// module can hold several operation groups, e.g. `play_kube_libpod` in `containers_api`,
+    /// so one struct is generated per operation prefix. `module_groups` are the groups named
+    /// after the tag modules, e.g. `container` for `containers_api`.
+    pub fn libpod(module_groups: Vec<String>) -> Self {
+        Self {
+            method_suffix: Some("_libpod".to_string()),
+            group_by_prefix: true,
+            module_groups,
+        }
+    }
+
+    pub fn generate_all(&self, module: &mut ModuleItem) -> Vec<StructItem> {
+        if self.group_by_prefix == false {
+            return vec![self.generate(module)];
+        }
+        let file_name = module.file_name().replace("_api", "");
+        let module_group = file_name.trim_end_matches('s').to_string();
+        let groups = module
+            .find_items_by(|item| {
+                match item {
+                    Item::Fn(_) => true,
+                    _ => false
+                }
+            })
+            .iter()
+            .map(|item| item.ident().split('_').next().unwrap().to_string())
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+        groups.iter()
+            .map(|group| {
+                // A group of another tag module is prefixed with this one, e.g. `image_commit_libpod`
+                // of `containers_api` is in `ContainerImageApi`, not in `ImageApi`.
+                let name = match *group == module_group || self.module_groups.contains(group) == false {
+                    true => group.clone(),
+                    false => format!("{}_{}", module_group, group),
+                };
+                let prefix = format!("{}_", group);
+                let items = module.take_items_by(|item| {
+                    match item {
+                        Item::Fn(value) => value.ident().starts_with(&prefix),
+                        _ => false
+                    }
+                });
+                self.generate_struct(items, group, &name)
+            })
+            .collect()
     }
 
     pub fn generate(&self, module: &mut ModuleItem) -> StructItem {
         let file_name = module.file_name().clone();
         let file_name = file_name.replace("_api", "");
-        let mut items = module.take_items_by(|item| {
+        let items = module.take_items_by(|item| {
             match item {
                 Item::Fn(value) => value.ident().contains(&file_name),
                 _ => false
             }
         });
+        self.generate_struct(items, &file_name, &file_name)
+    }
+
+    fn generate_struct(&self, mut items: Vec<Item>, prefix: &str, name: &str) -> StructItem {
         let items = items.iter_mut()
             .map(|item| {
                 match item {
                     Item::Fn(value) => {
                         let ident = value.ident();
-                        let ident = ident.replace(&format!("{}_", file_name), "");
+                        let mut ident = ident.replace(&format!("{}_", prefix), "");
+                        if let Some(suffix) = self.method_suffix.as_ref() {
+                            ident = ident.trim_end_matches(suffix.as_str()).to_string();
+                        }
                         value.item.signature_mut().ident = create_ident(&ident);
                         value.clone()
                     },
@@ -41,7 +106,7 @@ impl ApiGenerator {
                 }
             })
             .collect::<Vec<_>>();
-        let ident = create_ident(format!("{}Api", NamingConventions::to_pascal_case(&file_name)).as_str());
+        let ident = create_ident(format!("{}Api", NamingConventions::to_pascal_case(name)).as_str());
         let item_struct = self.generate_struct_item(&ident);
         let struct_impl_item = self.generate_struct_impl_item(&ident, &items);
         StructItem::new(
@@ -170,13 +235,13 @@ impl ApiGenerator {
         *first_argument = FnArg::Receiver(Receiver {
             attrs: vec![],
             reference: Some((Default::default(), None)),
-            mutability: Some(Default::default()),
+            mutability: None,
             self_token: Default::default(),
             colon_token: None,
             ty: Box::new(Type::Reference(TypeReference {
                 and_token: Default::default(),
                 lifetime: None,
-                mutability: Some(Default::default()),
+                mutability: None,
                 elem: Box::new(Type::Path(TypePath {
                     qself: None,
                     path: Path::new("Self").to_syn_path(),
@@ -1,15 +1,21 @@
 use ast_shaper::items::item::ItemTrait;
 use ast_shaper::items::module_item::ModuleItem;
+use ast_shaper::utils::create_use;
 use ast_shaper::utils::path::Path;
 use ast_shaper::utils::statement::{Expr, Statement};
 use syn::punctuated::Punctuated;
-use syn::{Block, Field, Fields, FieldsNamed, ItemStruct, Member, Token};
+use syn::{Block, ExprCall, ExprPath, Field, Fields, FieldsNamed, ItemStruct, Member, Token, Type, TypePath};
 
-pub struct ConfigurationTransformer;
+pub struct ConfigurationTransformer {
+    transport: String,
+}
 
 impl ConfigurationTransformer {
-    pub fn new() -> Self {
-        Self
+    /// `transport` is the type from `crate::api::transport` replacing the reqwest client.
+    pub fn new(transport: &str) -> Self {
+        Self {
+            transport: transport.to_string(),
+        }
     }
 
     pub fn transform(&self, module: &mut ModuleItem) {
@@ -23,6 +29,7 @@ impl ConfigurationTransformer {
         if file_name != "configuration" {
             return;
         }
+        module.push_use_item(create_use(Path::new("crate").join("api").join("transport").join(&self.transport)));
         module.take_items_by(|item| item.ident() == "BasicAuth");
         module.take_items_by(|item| item.ident() == "ApiKey");
         let configuration_item = module
@@ -31,11 +38,12 @@ impl ConfigurationTransformer {
             .as_struct_mut()
             .unwrap();
         Self::transform_fields(&mut configuration_item.item, fields_to_remove.clone());
+        Self::transform_client_field(&mut configuration_item.item, &self.transport);
         let new_function = configuration_item.impl_items.iter_mut()
             .flat_map(|impl_item| &mut impl_item.functions)
             .find(|function| function.ident() == "new")
             .unwrap();
-        Self::transform_init_function_body(new_function.block_mut(), fields_to_remove.clone());
+        Self::transform_init_function_body(new_function.block_mut(), fields_to_remove.clone(), &self.transport);
         let default_function = configuration_item.impl_items.iter_mut()
             .filter_map(|impl_item| {
                 let trait_impl = &impl_item.item.trait_;
@@ -54,7 +62,7 @@ impl ConfigurationTransformer {
             .flatten()
             .find(|function| function.ident() == "default")
             .unwrap();
-        Self::transform_init_function_body(default_function.block_mut(), fields_to_remove.clone());
+        Self::transform_init_function_body(default_function.block_mut(), fields_to_remove.clone(), &self.transport);
     }
     
     fn transform_fields(item: &mut ItemStruct, fields_to_remove: Vec<String>) {
@@ -70,7 +78,31 @@ impl ConfigurationTransformer {
         });
     }
 
-    fn transform_init_function_body(block: &mut Block, fields_to_remove: Vec<String>) {
+    fn transform_client_field(item: &mut ItemStruct, transport: &String) {
+        let client_field = item.fields.iter_mut()
+            .find(|field| field.ident.as_ref().unwrap() == "client")
+            .unwrap();
+        client_field.ty = Type::Path(TypePath {
+            qself: None,
+            path: Path::new(transport).to_syn_path(),
+        });
+    }
+
+    fn transport_default_expr(transport: &String) -> syn::Expr {
+        syn::Expr::Call(ExprCall {
+            attrs: vec![],
+            func: Box::new(syn::Expr::Path(ExprPath {
+                attrs: vec![],
+                qself: None,
+                path: Path::new(transport).join("default").to_syn_path(),
+            })),
+            paren_token: Default::default(),
+            args: Punctuated::new(),
+        })
+    }
+
+    fn transform_init_function_body(block: &mut Block, fields_to_remove: Vec<String>, transport: &String) {
+        let transport = transport.clone();
         let mut context = ast_shaper::functions::transform::create_context(move |expr| {
             match expr {
                 syn::Expr::Struct(value) => {
@@ -83,7 +115,15 @@ impl ConfigurationTransformer {
                                 _ => panic!("Expected named field"),
                             }
                         })
-                        .map(|field| field.clone())
+                        .map(|field| {
+                            let mut field = field.clone();
+                            if let Member::Named(ref value) = field.member {
+                                if value == "client" {
+                                    field.expr = Self::transport_default_expr(&transport);
+                                }
+                            }
+                            field
+                        })
                         .collect::<Punctuated<syn::FieldValue, Token![,]>>();
                     value.fields = fields;
                     true
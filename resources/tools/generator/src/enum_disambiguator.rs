@@ -0,0 +1,162 @@
+use crate::generator::read_source_file;
+use quote::ToTokens;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use syn::visit_mut::VisitMut;
+use syn::{Ident, ItemEnum};
+
+/// Renames the inline enums of the generated models after their parent model.
+///
+/// The generator names inline enums after their property, e.g. `Type` in `mount.rs`, so
+/// several models define the same enum. Once renamed to `MountType`, every enum can be
+/// addressed from `crate::models` after the model files are merged.
+///
+/// Renames are collected over the whole directory first, then applied to every file, so a
+/// reference such as `models::mount::Type` in another model follows the rename.
+pub struct EnumDisambiguator;
+
+/// New enum names by model file, e.g. `mount` -> `Type` -> `MountType`.
+type Renames = BTreeMap<String, HashMap<String, String>>;
+
+impl EnumDisambiguator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Rewrites the model files of `module_path` in place.
+    pub fn transform_directory(&self, module_path: &std::path::Path) {
+        let mut source_files = fs::read_dir(module_path).unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |extension| extension == "rs"))
+            .map(|path| {
+                let module_name = path.file_stem().unwrap().to_string_lossy().to_string();
+                let source_file = read_source_file(&path);
+                (path, module_name, source_file)
+            })
+            .collect::<Vec<_>>();
+        let renames = self.collect_renames(
+            source_files.iter().map(|(_, module_name, source_file)| (module_name.as_str(), source_file))
+        );
+        if renames.is_empty() {
+            return;
+        }
+        for (path, module_name, source_file) in source_files.iter_mut() {
+            if self.transform(module_name, source_file, &renames) {
+                fs::write(&path, source_file.to_token_stream().to_string())
+                    .expect(format!("Unable to write {}", path.display()).as_str());
+            }
+        }
+    }
+
+    /// Renames of every file, fails when two enums, or an enum and a model, end up with the same name.
+    pub fn collect_renames<'a>(&self, source_files: impl Iterator<Item = (&'a str, &'a syn::File)>) -> Renames {
+        let mut renames = Renames::new();
+        let mut owners = HashMap::new();
+        for (module_name, source_file) in source_files {
+            let file_renames = Self::file_renames(source_file);
+            for item in source_file.items.iter() {
+                let ident = match item {
+                    syn::Item::Struct(value) => value.ident.to_string(),
+                    syn::Item::Enum(value) => value.ident.to_string(),
+                    _ => continue,
+                };
+                let name = file_renames.get(&ident).unwrap_or(&ident);
+                if let Some(owner) = owners.insert(name.clone(), module_name.to_string()) {
+                    panic!("Type {} of {} conflicts with a type of {}", name, module_name, owner);
+                }
+            }
+            if file_renames.is_empty() == false {
+                renames.insert(module_name.to_string(), file_renames);
+            }
+        }
+        renames
+    }
+
+    /// Applies `renames` to the file of `module_name`, returns `true` when it changed.
+    pub fn transform(&self, module_name: &str, source_file: &mut syn::File, renames: &Renames) -> bool {
+        let mut renamer = EnumRenamer {
+            local: renames.get(module_name),
+            renames,
+            renamed: false,
+        };
+        renamer.visit_file_mut(source_file);
+        renamer.renamed
+    }
+
+    /// Enum models have their own file without a struct, they are already unique.
+    fn file_renames(source_file: &syn::File) -> HashMap<String, String> {
+        let parent = source_file.items.iter()
+            .find_map(|item| {
+                match item {
+                    syn::Item::Struct(value) => Some(value.ident.to_string()),
+                    _ => None
+                }
+            });
+        let parent = match parent {
+            Some(value) => value,
+            None => return HashMap::new(),
+        };
+        source_file.items.iter()
+            .filter_map(|item| {
+                match item {
+                    syn::Item::Enum(value) if value.ident.to_string().starts_with(&parent) == false => {
+                        Some((value.ident.to_string(), format!("{}{}", parent, value.ident)))
+                    }
+                    _ => None
+                }
+            })
+            .collect()
+    }
+}
+
+struct EnumRenamer<'a> {
+    local: Option<&'a HashMap<String, String>>,
+    renames: &'a Renames,
+    renamed: bool,
+}
+
+impl<'a> EnumRenamer<'a> {
+    fn rename(&mut self, ident: &mut Ident) {
+        if let Some(name) = self.local.and_then(|local| local.get(&ident.to_string())) {
+            *ident = Ident::new(name, ident.span());
+            self.renamed = true;
+        }
+    }
+}
+
+impl<'a> VisitMut for EnumRenamer<'a> {
+    fn visit_item_enum_mut(&mut self, item: &mut ItemEnum) {
+        self.rename(&mut item.ident);
+        syn::visit_mut::visit_item_enum_mut(self, item);
+    }
+
+    /// Inline enums are referenced by bare paths in their file, e.g. `Option<Type>` or
+    /// `Type::Bind`, and through their file module elsewhere, e.g. `models::mount::Type`.
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        let qualified = path.segments.iter()
+            .collect::<Vec<_>>()
+            .windows(2)
+            .position(|segments| {
+                self.renames.get(&segments[0].ident.to_string())
+                    .map_or(false, |renames| renames.contains_key(&segments[1].ident.to_string()))
+            });
+        if let Some(index) = qualified {
+            let segments = path.segments.iter().cloned().collect::<Vec<_>>();
+            let module_name = segments[index].ident.to_string();
+            let mut enum_segment = segments[index + 1].clone();
+            let name = &self.renames[&module_name][&enum_segment.ident.to_string()];
+            enum_segment.ident = Ident::new(name, enum_segment.ident.span());
+            path.segments = segments[..index].iter().cloned()
+                .chain(std::iter::once(enum_segment))
+                .chain(segments[index + 2..].iter().cloned())
+                .collect();
+            self.renamed = true;
+        }
+        else if path.leading_colon.is_none() {
+            if let Some(segment) = path.segments.first_mut() {
+                self.rename(&mut segment.ident);
+            }
+        }
+        syn::visit_mut::visit_path_mut(self, path);
+    }
+}
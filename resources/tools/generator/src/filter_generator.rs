@@ -0,0 +1,237 @@
+use itertools::Itertools;
+use openapiv3::v2::{OpenAPI, Operation};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+const KEYWORDS: [&str; 6] = ["type", "ref", "mod", "use", "fn", "in"];
+
+/// Value accepted by a filter key, read from the `(`a`|`b`)` lists of the descriptions.
+enum FilterValue {
+    Text,
+    Boolean,
+    Enumeration(Vec<String>),
+}
+
+struct Filter {
+    key: String,
+    description: String,
+    value: FilterValue,
+}
+
+/// Generates a typed builder for the `filters` parameter of every operation that has one.
+///
+/// Docker only documents the filter keys in the parameter description, as a Markdown list such
+/// as ``- `status=`(`created`|`running`)``. Each key becomes a method of `<OperationId>Filters`,
+/// keys with a closed set of values take a generated `<OperationId><Key>` enum.
+pub struct FilterGenerator;
+
+impl FilterGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn generate(&self, spec: &OpenAPI) -> syn::File {
+        let items = self.operation_filters(spec)
+            .iter()
+            .map(|(operation_id, filters)| self.generate_filters(operation_id, filters))
+            .collect::<Vec<_>>();
+        syn::parse2(quote! {
+            #(#items)*
+        })
+            .expect("Invalid generated filters")
+    }
+
+    /// Operations with a generated `<OperationId>Filters`, e.g. `ContainerList`.
+    pub fn operations(&self, spec: &OpenAPI) -> Vec<String> {
+        self.operation_filters(spec)
+            .into_iter()
+            .map(|(operation_id, _)| operation_id)
+            .collect()
+    }
+
+    fn operation_filters(&self, spec: &OpenAPI) -> Vec<(String, Vec<Filter>)> {
+        spec.paths.iter()
+            .flat_map(|(_, path)| {
+                vec![&path.get, &path.post, &path.put, &path.patch, &path.delete, &path.head, &path.options]
+            })
+            .filter_map(|operation| operation.as_ref())
+            .filter_map(|operation| self.filters_description(operation)
+                .map(|description| (operation.operation_id.clone().unwrap(), description))
+            )
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .filter_map(|(operation_id, description)| {
+                let filters = Self::parse_filters(&description);
+                match filters.is_empty() {
+                    true => None,
+                    false => Some((operation_id, filters)),
+                }
+            })
+            .collect()
+    }
+
+    fn filters_description(&self, operation: &Operation) -> Option<String> {
+        operation.parameters.as_ref()?
+            .iter()
+            .find(|parameter| parameter.name == "filters")
+            .and_then(|parameter| parameter.description.clone())
+    }
+
+    fn parse_filters(description: &str) -> Vec<Filter> {
+        description.lines()
+            .filter_map(|line| {
+                let line = line.trim().strip_prefix("- `")?;
+                let key = line.chars()
+                    .take_while(|character| ['=', '`', ' ', '!'].contains(character) == false)
+                    .collect::<String>();
+                if key.is_empty() {
+                    return None;
+                }
+                Some(Filter {
+                    key,
+                    description: format!("`{}", line),
+                    value: Self::parse_value(line),
+                })
+            })
+            .unique_by(|filter| filter.key.clone())
+            .collect()
+    }
+
+    fn parse_value(line: &str) -> FilterValue {
+        let values = line.find("(`")
+            .and_then(|start| {
+                let end = line[start..].find(')')?;
+                Some(&line[start + 1..start + end])
+            })
+            .map(|values| {
+                values.split('|')
+                    .map(|value| value.trim())
+                    .filter(|value| value.len() > 2 && value.starts_with('`') && value.ends_with('`'))
+                    .map(|value| value.trim_matches('`').to_string())
+                    .collect::<Vec<_>>()
+            });
+        let values = match values {
+            Some(values) if values.len() > 1 => values,
+            _ => return FilterValue::Text,
+        };
+        let is_identifier = values.iter().all(|value| {
+            value.chars().all(|character| character.is_ascii_alphanumeric() || ['-', '_', '.'].contains(&character))
+                && value.starts_with(|character: char| character.is_ascii_alphabetic())
+        });
+        if is_identifier == false {
+            return FilterValue::Text;
+        }
+        if values.iter().sorted().eq(["false", "true"].iter()) {
+            return FilterValue::Boolean;
+        }
+        FilterValue::Enumeration(values)
+    }
+
+    fn generate_filters(&self, operation_id: &str, filters: &Vec<Filter>) -> TokenStream {
+        let struct_ident = format_ident!("{}Filters", operation_id);
+        let struct_doc = format!("Typed `filters` of the `{}` operation.", operation_id);
+        let mut enums = Vec::new();
+        let methods = filters.iter()
+            .map(|filter| {
+                let key = &filter.key;
+                let doc = &filter.description;
+                let ident = Self::method_ident(key);
+                match &filter.value {
+                    FilterValue::Text => quote! {
+                        #[doc = #doc]
+                        pub fn #ident(&mut self, value: impl Into<String>) -> &mut Self {
+                            self.filters.with(#key, value);
+                            self
+                        }
+                    },
+                    FilterValue::Boolean => quote! {
+                        #[doc = #doc]
+                        pub fn #ident(&mut self, value: bool) -> &mut Self {
+                            self.filters.with(#key, value.to_string());
+                            self
+                        }
+                    },
+                    FilterValue::Enumeration(values) => {
+                        let enum_ident = format_ident!("{}{}", operation_id, Self::to_pascal_case(key));
+                        enums.push(self.generate_enum(&enum_ident, values));
+                        quote! {
+                            #[doc = #doc]
+                            pub fn #ident(&mut self, value: #enum_ident) -> &mut Self {
+                                self.filters.with(#key, value.as_str());
+                                self
+                            }
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        quote! {
+            #[doc = #struct_doc]
+            #[derive(Debug, Clone, Default, PartialEq)]
+            pub struct #struct_ident {
+                filters: Filters,
+            }
+
+            impl #struct_ident {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+
+                #(#methods)*
+
+                /// JSON value of the `filters` parameter.
+                pub fn to_json(&self) -> String {
+                    self.filters.to_json()
+                }
+            }
+
+            /// Lets the `filters` field of the params builder take the typed filters.
+            impl From<#struct_ident> for String {
+                fn from(value: #struct_ident) -> Self {
+                    value.to_json()
+                }
+            }
+
+            #(#enums)*
+        }
+    }
+
+    fn generate_enum(&self, ident: &Ident, values: &Vec<String>) -> TokenStream {
+        let variants = values.iter()
+            .map(|value| format_ident!("{}", Self::to_pascal_case(value)))
+            .collect::<Vec<_>>();
+        quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum #ident {
+                #(#variants),*
+            }
+
+            impl #ident {
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        #(Self::#variants => #values),*
+                    }
+                }
+            }
+        }
+    }
+
+    fn method_ident(key: &str) -> Ident {
+        let name = key.replace(['-', '.'], "_");
+        match KEYWORDS.contains(&name.as_str()) {
+            true => Ident::new_raw(&name, Span::call_site()),
+            false => Ident::new(&name, Span::call_site()),
+        }
+    }
+
+    fn to_pascal_case(value: &str) -> String {
+        value.split(['-', '_', '.'])
+            .filter(|part| part.is_empty() == false)
+            .map(|part| {
+                let mut characters = part.chars();
+                let first = characters.next().unwrap().to_ascii_uppercase();
+                format!("{}{}", first, characters.as_str())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,667 @@
+use crate::api_generator::ApiGenerator;
+use crate::configuration_transformer::ConfigurationTransformer;
+use crate::enum_disambiguator::EnumDisambiguator;
+use crate::filter_generator::FilterGenerator;
+use crate::streaming_transformer::StreamingTransformer;
+use crate::{version_module_name, LIBPOD_API_VERSION};
+use ast_shaper::debug;
+use ast_shaper::items::item::{Item, ItemTrait};
+use ast_shaper::items::module_item::ModuleItem;
+use ast_shaper::items::source_file::SourceFile;
+use ast_shaper::utils::parsing::PathExt;
+use ast_shaper::utils::path::Path;
+use ast_shaper::utils::{create_use, create_use_as_glob};
+use itertools::Itertools;
+use openapiv3::v2::{Operation, Parameter, ReferenceOrSchema};
+use serde_yaml;
+use serde_yaml::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::process::{Command, Stdio};
+use std::rc::Rc;
+use tempfile::NamedTempFile;
+
+/// Params fields holding an image reference, by params struct.
+const IMAGE_REFERENCE_FIELDS: [(&str, &str); 8] = [
+    ("DistributionInspectParams", "name"),
+    ("ImageBuildParams", "t"),
+    ("ImageCreateParams", "from_image"),
+    ("ImageDeleteParams", "name"),
+    ("ImageGetParams", "name"),
+    ("ImageHistoryParams", "name"),
+    ("ImageInspectParams", "name"),
+    ("ImagePushParams", "name"),
+];
+
+/// Runs the openapi-generator over the Docker specification of `api_version` and writes the
+/// transformed sources to `output_path`, as if they were included at the crate root.
+pub fn generate_api_version(
+    tool_path: &std::path::Path,
+    api_version: &str,
+    output_path: &std::path::Path
+) {
+    fs::create_dir_all(output_path).unwrap();
+    let module_root = Path::new("crate");
+    let specification_path = specification_path(api_version);
+    let specification_file = transform_openapi_document(&specification_path);
+    let sync_client_cargo_path = generate_client(
+        tool_path,
+        specification_file,
+        "container-api",
+        api_version,
+        client_version(),
+        false
+    );
+    let specification_file = transform_openapi_document(&specification_path);
+    let async_client_cargo_path = generate_client(
+        tool_path,
+        specification_file,
+        "container-api",
+        api_version,
+        client_version(),
+        true
+    );
+    let model_source_file = merge_model_source_files(&sync_client_cargo_path);
+    output_path.join("models.rs").unparse(&model_source_file);
+    let mut api_source_file = merge_api_source_files(&sync_client_cargo_path, false, &module_root, ApiGenerator::new());
+    let params_source_file = extract_params_structs(&mut api_source_file, &module_root);
+    output_path.join("api-sync.rs").unparse(&api_source_file);
+    output_path.join("params.rs").unparse(&params_source_file);
+    // Async params are the same structs as the sync ones, both APIs share the params module.
+    let mut async_api_source_file = merge_api_source_files(&async_client_cargo_path, true, &module_root, ApiGenerator::new());
+    extract_params_structs(&mut async_api_source_file, &module_root);
+    output_path.join("api-async.rs").unparse(&async_api_source_file);
+    let specification = read_openapi_document(&specification_path);
+    let filter_operations = FilterGenerator::new().operations(&specification);
+    let builders_source_file = create_params_builders(&model_source_file, &params_source_file, &filter_operations, &module_root);
+    output_path.join("builders.rs").unparse(&builders_source_file);
+    let filters_source_file = FilterGenerator::new().generate(&specification);
+    fs::write(output_path.join("filters.rs"), prettyplease::unparse(&filters_source_file)).unwrap();
+}
+
+/// Podman libpod API, exposed as `crate::libpod`.
+///
+/// Builders are not generated, their rules target the Docker models.
+pub fn generate_libpod_api(tool_path: &std::path::Path, output_path: &std::path::Path) {
+    fs::create_dir_all(output_path).unwrap();
+    let module_root = Path::new("crate").join("libpod");
+    let specification_path = std::path::Path::new("resources")
+        .join("specs")
+        .join("libpod")
+        .join(format!("{}.yaml", LIBPOD_API_VERSION));
+    let libpod_specification_file = prepare_libpod_document(&specification_path);
+    let module_groups = libpod_module_groups(libpod_specification_file.path());
+    let specification_file = transform_openapi_document(libpod_specification_file.path());
+    let sync_client_cargo_path = generate_client(
+        tool_path,
+        specification_file,
+        "libpod-api",
+        LIBPOD_API_VERSION,
+        client_version(),
+        false
+    );
+    let specification_file = transform_openapi_document(libpod_specification_file.path());
+    let async_client_cargo_path = generate_client(
+        tool_path,
+        specification_file,
+        "libpod-api",
+        LIBPOD_API_VERSION,
+        client_version(),
+        true
+    );
+    let model_source_file = merge_model_source_files(&sync_client_cargo_path);
+    output_path.join("models.rs").unparse(&model_source_file);
+    let mut api_source_file = merge_api_source_files(&sync_client_cargo_path, false, &module_root, ApiGenerator::libpod(module_groups.clone()));
+    let params_source_file = extract_params_structs(&mut api_source_file, &module_root);
+    output_path.join("api-sync.rs").unparse(&api_source_file);
+    output_path.join("params.rs").unparse(&params_source_file);
+    let mut async_api_source_file = merge_api_source_files(&async_client_cargo_path, true, &module_root, ApiGenerator::libpod(module_groups));
+    extract_params_structs(&mut async_api_source_file, &module_root);
+    output_path.join("api-async.rs").unparse(&async_api_source_file);
+    fs::write(output_path.join("clients.rs"), libpod_apis_macro(&api_source_file)).unwrap();
+}
+
+/// Keeps the `/libpod/` operations of the Podman specification, the compat ones are the Docker API
+/// generated at the crate root.
+///
+/// Operations keep their first tag without the ` (compat)` suffix, so each one is generated once.
+/// References to the shared `responses` are inlined, unsigned integer formats become `int32` or
+/// `int64` with a zero minimum to be generated as `u32` or `u64`, and string bodies, tar archives
+/// and YAML documents, are sent as is.
+fn prepare_libpod_document(document_path: &std::path::Path) -> NamedTempFile {
+    fn transform_unsigned_formats(value: &mut Value) {
+        match value {
+            Value::Mapping(mapping) => {
+                let format = match mapping.get("format").and_then(Value::as_str) {
+                    Some("uint8") | Some("uint16") | Some("uint32") => Some("int32"),
+                    Some("uint64") => Some("int64"),
+                    _ => None,
+                };
+                if let Some(format) = format {
+                    mapping.insert(Value::from("format"), Value::from(format));
+                    mapping.insert(Value::from("minimum"), Value::from(0));
+                }
+                for (_, item) in mapping.iter_mut() {
+                    transform_unsigned_formats(item);
+                }
+            }
+            Value::Sequence(sequence) => {
+                for item in sequence.iter_mut() {
+                    transform_unsigned_formats(item);
+                }
+            }
+            _ => {}
+        }
+    }
+    fn transform_operation(operation: &mut Value, responses: &Value) {
+        if let Some(Value::Sequence(tags)) = operation.get_mut("tags") {
+            let tag = tags.first()
+                .and_then(Value::as_str)
+                .map(|tag| tag.trim_end_matches(" (compat)").to_string())
+                .expect("Expected tagged operations");
+            *tags = vec![Value::from(tag)];
+        }
+        if let Some(Value::Mapping(operation_responses)) = operation.get_mut("responses") {
+            for (_, response) in operation_responses.iter_mut() {
+                let name = response.get("$ref")
+                    .and_then(Value::as_str)
+                    .map(|reference| reference.trim_start_matches("#/responses/").to_string());
+                if let Some(name) = name {
+                    *response = responses.get(name.as_str())
+                        .cloned()
+                        .expect(format!("Response {} not found", name).as_str());
+                }
+            }
+        }
+        if let Some(Value::Sequence(parameters)) = operation.get_mut("parameters") {
+            for parameter in parameters.iter_mut() {
+                if parameter.get("in").and_then(Value::as_str) != Some("body") {
+                    continue;
+                }
+                if let Some(Value::Mapping(schema)) = parameter.get_mut("schema") {
+                    let is_string = schema.get("type").and_then(Value::as_str) == Some("string");
+                    if is_string && schema.contains_key("format") == false {
+                        schema.insert(Value::from("format"), Value::from("binary"));
+                    }
+                }
+            }
+        }
+    }
+    let document_content = fs::read_to_string(document_path)
+        .expect(format!("API specification {} not found", document_path.display()).as_str());
+    let mut document: Value = serde_yaml::from_str(document_content.as_str())
+        .expect("Could not structure OpenAPI file");
+    let responses = document.get("responses")
+        .cloned()
+        .unwrap_or(Value::Null);
+    let paths = document.get_mut("paths")
+        .and_then(Value::as_mapping_mut)
+        .expect("Expected paths");
+    paths.retain(|path, _| path.as_str().map_or(false, |path| path.starts_with("/libpod/")));
+    for (_, path) in paths.iter_mut() {
+        if let Value::Mapping(operations) = path {
+            for (_, operation) in operations.iter_mut() {
+                transform_operation(operation, &responses);
+            }
+        }
+    }
+    transform_unsigned_formats(&mut document);
+    let mut output_file = NamedTempFile::new().unwrap();
+    output_file.write_all(serde_yaml::to_string(&document).unwrap().as_bytes()).unwrap();
+    output_file.seek(SeekFrom::Start(0)).unwrap();
+    output_file
+}
+
+/// Operation group of each tag module, e.g. `container` for `containers_api`.
+fn libpod_module_groups(document_path: &std::path::Path) -> Vec<String> {
+    let specification = read_openapi_document(document_path);
+    specification.paths.values()
+        .flat_map(|path| [&path.get, &path.post, &path.put, &path.patch, &path.delete, &path.head, &path.options])
+        .filter_map(|operation| operation.as_ref())
+        .flat_map(|operation| operation.tags.clone().unwrap_or_default())
+        .map(|tag| tag.trim_end_matches('s').to_string())
+        .unique()
+        .sorted()
+        .collect()
+}
+
+/// `libpod_apis!` passes the API structs generated from the specification to a client macro,
+/// so the libpod clients get one field per API, e.g. `pod: PodApi`.
+fn libpod_apis_macro(api_source_file: &SourceFile) -> String {
+    let fields = api_source_file.modules.iter()
+        .flat_map(|module| module.find_items_by(|item| {
+            match item {
+                Item::Struct(_) => item.ident().to_string().ends_with("Api"),
+                _ => false
+            }
+        }))
+        .map(|item| item.ident().to_string())
+        .sorted()
+        .unique()
+        .map(|ident| {
+            let field = ident.trim_end_matches("Api")
+                .chars()
+                .enumerate()
+                .flat_map(|(index, character)| {
+                    match character.is_ascii_uppercase() && index > 0 {
+                        true => vec!['_', character.to_ascii_lowercase()],
+                        false => vec![character.to_ascii_lowercase()]
+                    }
+                })
+                .collect::<String>();
+            format!("{}: {}", field, ident)
+        })
+        .join(", ");
+    format!(
+        "macro_rules! libpod_apis {{\n    ($client:ident, $api:ident, $root_client:path) => {{\n        $client!($api, $root_client, {});\n    }};\n}}\n",
+        fields
+    )
+}
+
+fn client_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+fn specification_path(api_version: &str) -> std::path::PathBuf {
+    std::path::Path::new("resources")
+        .join("specs")
+        .join(format!("{}.yaml", api_version))
+}
+
+
+fn command(
+    command: &str,
+    arguments: Option<&[&str]>,
+    working_dir: &std::path::Path,
+) {
+    let mut command = Command::new(command);
+    if arguments.is_some() {
+        command.args(arguments.unwrap());
+    }
+    let mut process = command
+        .current_dir(working_dir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stderr = process.stderr.take().expect("Failed to capture stderr");
+    let stderr_reader = BufReader::new(stderr);
+    for line in stderr_reader.lines() {
+        match line {
+            Ok(output) => debug!("{}", output),
+            Err(e) => debug!("Error reading output: {}", e),
+        }
+    }
+    process.wait().expect("Failed to wait on child");
+}
+
+pub fn install_yarn_tool(tool_path: &std::path::Path) {
+    command(
+        "yarn",
+        None,
+        tool_path
+    )
+}
+
+fn transform_openapi_document(
+    document_path: &std::path::Path,
+) -> NamedTempFile
+{
+    fn transform_parameter(parameter: &mut Parameter) -> bool {
+        if parameter.schema.is_none() {
+            return false;
+        }
+        let parameter_schema = parameter.schema.as_mut().unwrap();
+        let parameter_schema = match parameter_schema {
+            ReferenceOrSchema::Reference { .. } => return false,
+            ReferenceOrSchema::Item(value) => value
+        };
+        if parameter_schema.schema_type.is_none() || parameter_schema.format.is_none() {
+            return false;
+        }
+        let schema_type = parameter_schema.schema_type.as_ref().unwrap();
+        let schema_format = parameter_schema.format.as_ref().unwrap();
+        if schema_type != "string" || schema_format != "binary" || parameter.required.is_some() {
+            return false;
+        }
+        parameter.required = Some(true);
+        debug!(
+            "Parameter transformed: {}+{}: required: {}",
+            schema_type,
+            schema_format,
+            true,
+        );
+        true
+    }
+    fn transform_operation(operation: &mut Option<Operation>) {
+        if operation.is_none() {
+            return;
+        }
+        let operation = operation.as_mut().unwrap();
+        if operation.parameters.is_none() {
+            return;
+        }
+        for parameter in operation.parameters.as_mut().unwrap().iter_mut() {
+            let result = transform_parameter(parameter);
+            if result {
+                debug!("in operation {}", operation.operation_id.as_ref().unwrap());
+            }
+        }
+    }
+    let mut spec = read_openapi_document(document_path);
+    for (_, path) in spec.paths.iter_mut() {
+        transform_operation(&mut path.get);
+        transform_operation(&mut path.post);
+        transform_operation(&mut path.put);
+        transform_operation(&mut path.patch);
+        transform_operation(&mut path.delete);
+        transform_operation(&mut path.head);
+        transform_operation(&mut path.options);
+    }
+    let document_content = serde_yaml::to_string(&spec).unwrap();
+    let mut output_file = NamedTempFile::new().unwrap();
+    output_file.write_all(document_content.as_bytes()).unwrap();
+    output_file.seek(SeekFrom::Start(0)).unwrap();
+    output_file
+}
+
+fn read_openapi_document(document_path: &std::path::Path) -> openapiv3::v2::OpenAPI {
+    let document_path = document_path.to_str()
+        .unwrap()
+        .to_string();
+    let document_content = fs::read_to_string(&document_path)
+        .expect(format!(
+            "API specification {} not found, Docker specifications are published as `v<version>.yaml` \
+            in the moby repository and the libpod one as the `swagger-<version>.yaml` of a Podman release",
+            document_path
+        ).as_str());
+    serde_yaml::from_str(document_content.as_str()).expect("Could not structure OpenAPI file")
+}
+
+fn generate_client(
+    tool_path: &std::path::Path,
+    specification_file: NamedTempFile,
+    name: &str,
+    api_version: &str,
+    client_version: &str,
+    support_async: bool
+) -> std::path::PathBuf {
+    let async_name = match support_async {
+        true => "async",
+        false => "sync"
+    };
+    let name = format!("{}-{}", name, async_name);
+    let user_agent = format!("{}-{}", name, client_version);
+    let input_path = specification_file.path().to_str().unwrap();
+    let generated_path = std::path::Path::new("generated")
+        .join(version_module_name(api_version))
+        .join(name.clone());
+    let generated_path_as_string = generated_path.to_str()
+        .unwrap()
+        .to_string();
+    command(
+        "npx",
+        Some(&[
+            "openapi-generator-cli",
+            "generate",
+            format!("--input-spec {}", input_path).as_str(),
+            "--generator-name rust",
+            format!("--output {}", generated_path_as_string).as_str(),
+            format!("--http-user-agent {}", user_agent).as_str(),
+            "--additional-properties=library=reqwest",
+            format!("--additional-properties=supportAsync={}", support_async.to_string()).as_str(),
+            format!("--additional-properties=packageName={}", name).as_str(),
+            format!("--additional-properties=packageVersion={}", client_version).as_str(),
+            "--additional-properties=preferUnsignedInt=true",
+            "--additional-properties=topLevelApiClient=true",
+            "--additional-properties=useSingleRequestParameter=true",
+            "--type-mappings=string+binary=Bytes"
+        ]),
+        tool_path
+    );
+    fs::remove_file(specification_file.path()).unwrap();
+    tool_path.join(generated_path)
+}
+
+pub fn read_source_file(path: &std::path::Path) -> syn::File {
+    let mut file = File::open(path)
+        .expect("Unable to open file");
+    let mut src = String::new();
+    file.read_to_string(&mut src)
+        .expect("Unable to read file");
+    let syntax = syn::parse_file(&src)
+        .expect("Unable to parse file");
+    syntax
+}
+
+fn walk_path(path: &std::path::Path, source_files: &mut HashMap<String, syn::File>) {
+    for entry in fs::read_dir(path).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.is_file() && path.file_name().unwrap().to_str().unwrap().ends_with(".rs") {
+            let module_name = path.file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .replace(".rs", "");
+            let source_file = read_source_file(&path);
+            source_files.insert(module_name, source_file);
+        }
+        else if path.is_dir() {
+            walk_path(&path, source_files);
+        }
+    }
+}
+
+fn merge_model_source_files(cargo_path: &std::path::Path) -> SourceFile {
+    let module_path = cargo_path
+        .join("src")
+        .join("models");
+    EnumDisambiguator::new().transform_directory(&module_path);
+    let source_files = module_path.walk();
+    let mut source_file = merge_source_files(&source_files);
+    let path_prefix = Path::new("crate").join("models").clone();
+    source_file.remove_use_items_starting_with(&path_prefix);
+    let path_prefix = Path::new("models");
+    ast_shaper::functions::trim_path::from_source_file(&mut source_file, &path_prefix);
+    source_file
+}
+
+fn merge_api_source_files(
+    cargo_path: &std::path::Path,
+    support_async: bool,
+    module_root: &Path,
+    api_generator: ApiGenerator
+) -> SourceFile {
+    let module_path = cargo_path
+        .join("src")
+        .join("apis");
+    let transport = match support_async {
+        true => "AsyncTransport",
+        false => "Transport"
+    };
+    let configuration_transformer = ConfigurationTransformer::new(transport);
+    StreamingTransformer::new(support_async)
+        .transform_directory(&module_path, &cargo_path.join("src").join("models"));
+    let mut source_files = module_path.walk();
+    for source_file in source_files.iter_mut() {
+        let path_prefix = Path::new("crate");
+        source_file.remove_use_items_starting_with(&path_prefix);
+        let path_prefix = Path::new("super");
+        source_file.remove_use_items_starting_with(&path_prefix);
+        let path_prefix = Path::new("configuration");
+        ast_shaper::functions::trim_path::from_source_file(source_file, &path_prefix);
+        let path_prefix = Path::new("crate").join("apis").clone();
+        ast_shaper::functions::trim_path::from_source_file(source_file, &path_prefix);
+        let module = source_file.modules.first_mut().unwrap();
+        module.push_use_item(create_use(module_root.clone().join("models")));
+        module.push_use_item(create_use(Path::new("std").join("sync").join("Arc")));
+        if module.file_name() == "configuration" {
+            configuration_transformer.transform(module);
+        }
+        if module.file_name().ends_with("_api") == false {
+            continue;
+        }
+        for api_struct in api_generator.generate_all(module) {
+            module.push_struct_item(api_struct);
+        }
+    }
+    let source_file = merge_source_files(&source_files);
+    source_file
+}
+
+fn merge_source_files(source_files: &Vec<SourceFile>) -> SourceFile {
+    let mut output_file = SourceFile::new(
+        source_files.iter()
+            .flat_map(|source_file| source_file.attributes.clone())
+            .collect(),
+        source_files.iter()
+            .flat_map(|source_file| source_file.modules.clone())
+            .sorted_by(|a, b| {
+                let a = a.name();
+                let b = b.name();
+                a.cmp(&b)
+            })
+            .collect::<Vec<_>>()
+    );
+    output_file.merge();
+    output_file
+}
+
+fn extract_params_structs(source_file: &mut SourceFile, module_root: &Path) -> SourceFile {
+    let params_structs = source_file.modules.iter_mut()
+        .filter_map(|module| {
+            let items = module.take_items_by(
+                |item| item.ident().ends_with("Params")
+            );
+            if items.is_empty() == false {
+                Some(items)
+            }
+            else {
+                None
+            }
+        })
+        .flatten()
+        .sorted_by(|a, b| {
+            let a = a.ident();
+            let b = b.ident();
+            a.cmp(&b)
+        })
+        .collect::<Vec<_>>();
+    let mut module = ModuleItem::new("params");
+    module.push_use_item(create_use(module_root.clone().join("models")));
+    module.push_use_item(create_use(Path::new("bytes").join("Bytes")));
+    for item in params_structs {
+        let struct_item = match item {
+            Item::Struct(value) => value,
+            _ => panic!("Expected struct item")
+        };
+        module.push_struct_item(struct_item);
+    }
+    SourceFile::new(
+        vec![],
+        vec![module]
+    )
+}
+
+fn create_params_builders(
+    model_source_file: &SourceFile,
+    params_source_file: &SourceFile,
+    filter_operations: &Vec<String>,
+    module_root: &Path
+) -> SourceFile {
+    let mut modules = Vec::new();
+    modules.append(&mut model_source_file.modules.clone());
+    modules.append(&mut params_source_file.modules.clone());
+    let modules = Rc::new(RefCell::new(modules));
+    let mut builder = buildify::BuilderGenerator::new(modules.clone());
+    builder.with_rule()
+        .for_all()
+        .and_all_fields()
+        .then_discard_attribute("serde");
+    builder.with_rule()
+        .for_item("ContainerCreateRequest")
+        .with_field_ident("exposed_ports")
+        .then_map_to_vec(Path::from("ExposedPort"));
+    builder.with_rule()
+        .for_item("ContainerConfig")
+        .with_field_ident("exposed_ports")
+        .then_map_to_vec(Path::from("ExposedPort"));
+    builder.with_rule()
+        .for_item("ContainerCreateRequest")
+        .with_field_ident("volumes")
+        .then_map_to_vec(Path::new("PathBuf"));
+    builder.with_rule()
+        .for_item("ContainerConfig")
+        .with_field_ident("volumes")
+        .then_map_to_vec(Path::new("PathBuf"));
+    builder.with_rule()
+        .for_item("ServiceSpecMode")
+        .with_field_ident("global")
+        .then_map(Path::new("String"));
+    builder.with_rule()
+        .for_item("ServiceSpecMode")
+        .with_field_ident("global_job")
+        .then_map(Path::new("String"));
+    builder.with_rule()
+        .for_item("ClusterVolumeSpecAccessMode")
+        .with_field_ident("mount_volume")
+        .then_map(Path::new("String"));
+    // `filters` params take the typed filters of their operation, e.g. `ContainerListFilters`.
+    for operation in filter_operations.iter() {
+        builder.with_rule()
+            .for_item(&format!("{}Params", operation))
+            .with_field_ident("filters")
+            .then_map(Path::new(&format!("{}Filters", operation)));
+    }
+    // Image names and tags take a parsed reference, sent in its canonical form.
+    for (item, field) in IMAGE_REFERENCE_FIELDS.iter() {
+        builder.with_rule()
+            .for_item(item)
+            .with_field_ident(field)
+            .then_map(Path::new("ImageReference"));
+    }
+    let borrowed_modules = modules.borrow();
+    let params_structs = borrowed_modules.iter()
+        .filter_map(|module| {
+            let items = module.find_items_by(
+                |item| item.ident().ends_with("Params")
+            );
+            if items.is_empty() == false {
+                Some(items)
+            }
+            else {
+                None
+            }
+        })
+        .flatten()
+        .sorted_by(|a, b| {
+            let a = a.ident();
+            let b = b.ident();
+            a.cmp(&b)
+        })
+        .collect::<Vec<_>>();
+    let mut module_builders = ModuleItem::new("builders");
+    module_builders.push_use_item(create_use_as_glob(module_root.clone().join("models")));
+    module_builders.push_use_item(create_use_as_glob(module_root.clone().join("params")));
+    module_builders.push_use_item(create_use_as_glob(module_root.clone().join("filters")));
+    module_builders.push_use_item(create_use(Path::new("crate").join("api").join("reference").join("ImageReference")));
+    module_builders.push_use_item(create_use(Path::new("bytes").join("Bytes")));
+    module_builders.push_use_item(create_use(Path::new("std").join("collections").join("HashMap")));
+    module_builders.push_use_item(create_use(Path::new("std").join("path").join("PathBuf")));
+    params_structs.iter()
+        .flat_map(|item| {
+            match item {
+                Item::Struct(value) => {
+                    builder.generate(&syn::Item::Struct(value.item.clone()))
+                }
+                _ => panic!("Unexpected type !")
+            }
+        })
+        .unique_by(|item| item.ident())
+        .for_each(|item| module_builders.push_struct_item(item));
+    SourceFile::new(vec![], vec![module_builders])
+}
\ No newline at end of file
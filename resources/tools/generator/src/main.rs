@@ -0,0 +1,46 @@
+mod api_generator;
+mod configuration_transformer;
+mod enum_disambiguator;
+mod filter_generator;
+mod generator;
+mod streaming_transformer;
+
+/// Podman REST API version of the libpod specification, must match `LIBPOD_API_VERSION` of `build.rs`.
+const LIBPOD_API_VERSION: &str = "v5.0.0";
+
+/// Regenerates the vendored API sources of `resources/tools/openapi-generator/output`.
+///
+/// Takes the API versions to generate, e.g. `v1.47`, or `libpod` for the Podman API:
+/// `cargo run --manifest-path resources/tools/generator/Cargo.toml -- v1.47 libpod`
+fn main() {
+    let repository_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("..");
+    std::env::set_current_dir(&repository_path)
+        .expect("Unable to enter the repository directory");
+    let api_versions = std::env::args().skip(1).collect::<Vec<_>>();
+    if api_versions.is_empty() {
+        panic!("Expected the API versions to generate, e.g. `v1.47` or `libpod`");
+    }
+    let tool_path = std::path::Path::new("resources")
+        .join("tools")
+        .join("openapi-generator");
+    generator::install_yarn_tool(&tool_path);
+    for api_version in api_versions.iter() {
+        let module_name = match api_version.as_str() {
+            "libpod" => "libpod".to_string(),
+            _ => version_module_name(api_version),
+        };
+        let vendored_path = tool_path.join("output").join(module_name);
+        match api_version.as_str() {
+            "libpod" => generator::generate_libpod_api(&tool_path, &vendored_path),
+            _ => generator::generate_api_version(&tool_path, api_version, &vendored_path),
+        }
+    }
+}
+
+/// Module name of an API version, e.g. `v1_41` for `v1.41`.
+fn version_module_name(api_version: &str) -> String {
+    api_version.replace(".", "_")
+}
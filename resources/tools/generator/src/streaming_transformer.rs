@@ -0,0 +1,164 @@
+use crate::generator::read_source_file;
+use quote::ToTokens;
+use std::fs;
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, ExprIf, ItemFn, ReturnType, Type};
+
+/// Body of a streaming operation.
+pub enum StreamKind {
+    /// Concatenated JSON messages of the given model.
+    Json(&'static str),
+    /// Concatenated JSON messages of a type of the crate, for streams the specification only
+    /// partially describes, e.g. the `aux` message of `/images/{name}/push`.
+    Message(&'static str),
+    /// Raw stream output of logs, attach and exec, multiplexed when the container has no TTY.
+    Logs,
+}
+
+/// Operations whose response body is a stream, by generated function name.
+pub const STREAMING_OPERATIONS: [(&str, StreamKind); 8] = [
+    ("image_build", StreamKind::Json("BuildInfo")),
+    ("image_create", StreamKind::Json("CreateImageInfo")),
+    ("image_push", StreamKind::Message("crate::api::push::PushMessage")),
+    ("container_stats", StreamKind::Message("crate::api::stats::ContainerStatsResponse")),
+    ("system_events", StreamKind::Json("EventMessage")),
+    ("container_logs", StreamKind::Logs),
+    ("container_attach", StreamKind::Logs),
+    ("exec_start", StreamKind::Logs),
+];
+
+/// Streaming operations wrapped by a hand written method of the original name, e.g. `ImageApi::push`.
+pub const RENAMED_OPERATIONS: [(&str, &str); 1] = [
+    ("image_push", "image_push_stream"),
+];
+
+/// Makes the streaming operations return the response body as typed streams.
+///
+/// The generator either drops these bodies or parses a single message out of them. Sync
+/// functions return iterators from `crate::api::stream`, async ones return `futures::Stream`s.
+pub struct StreamingTransformer {
+    support_async: bool,
+}
+
+impl StreamingTransformer {
+    pub fn new(support_async: bool) -> Self {
+        Self {
+            support_async
+        }
+    }
+
+    /// Rewrites the API files of `api_path` in place, `models_path` tells which models exist.
+    pub fn transform_directory(&self, api_path: &std::path::Path, models_path: &std::path::Path) {
+        for entry in fs::read_dir(api_path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "rs") {
+                continue;
+            }
+            let mut source_file = read_source_file(&path);
+            let mut transformed = false;
+            for item in source_file.items.iter_mut() {
+                if let syn::Item::Fn(item) = item {
+                    transformed |= self.transform(item, models_path);
+                }
+            }
+            if transformed {
+                fs::write(&path, source_file.to_token_stream().to_string())
+                    .expect(format!("Unable to write {}", path.display()).as_str());
+            }
+        }
+    }
+
+    fn transform(&self, item: &mut ItemFn, models_path: &std::path::Path) -> bool {
+        let ident = item.sig.ident.to_string();
+        let kind = match STREAMING_OPERATIONS.iter().find(|(name, _)| *name == ident.as_str()) {
+            Some((_, kind)) => kind,
+            None => return false,
+        };
+        let (stream_type, stream_expr): (Type, syn::Expr) = match (kind, self.support_async) {
+            (StreamKind::Json(model), false) => {
+                let model = Self::model_type(model, models_path);
+                (parse_quote!(crate::api::stream::JsonStream<#model>), parse_quote!(crate::api::stream::JsonStream::new(resp)))
+            }
+            (StreamKind::Json(model), true) => {
+                let model = Self::model_type(model, models_path);
+                (parse_quote!(crate::api::stream::AsyncJsonStream<#model>), parse_quote!(crate::api::stream::AsyncJsonStream::new(resp)))
+            }
+            (StreamKind::Message(path), false) => {
+                let message = syn::parse_str::<Type>(path).unwrap();
+                (parse_quote!(crate::api::stream::JsonStream<#message>), parse_quote!(crate::api::stream::JsonStream::new(resp)))
+            }
+            (StreamKind::Message(path), true) => {
+                let message = syn::parse_str::<Type>(path).unwrap();
+                (parse_quote!(crate::api::stream::AsyncJsonStream<#message>), parse_quote!(crate::api::stream::AsyncJsonStream::new(resp)))
+            }
+            (StreamKind::Logs, false) => {
+                (parse_quote!(crate::api::stream::LogStream), parse_quote!(crate::api::stream::LogStream::new(resp)))
+            }
+            (StreamKind::Logs, true) => {
+                (parse_quote!(crate::api::stream::AsyncLogStream), parse_quote!(crate::api::stream::AsyncLogStream::new(resp)))
+            }
+        };
+        let error_type = match &item.sig.output {
+            ReturnType::Type(_, value) => Self::error_type(value)
+                .expect(format!("Unexpected return type of {}", ident).as_str()),
+            ReturnType::Default => panic!("Unexpected return type of {}", ident),
+        };
+        item.sig.output = parse_quote!(-> Result<#stream_type, #error_type>);
+        SuccessBranch { expr: stream_expr }.visit_block_mut(&mut item.block);
+        if let Some((_, name)) = RENAMED_OPERATIONS.iter().find(|(name, _)| *name == ident.as_str()) {
+            item.sig.ident = syn::Ident::new(name, item.sig.ident.span());
+        }
+        true
+    }
+
+    /// Fails when the specification has no such model, map the operation to a `StreamKind::Message`
+    /// of the crate instead.
+    fn model_type(model: &str, models_path: &std::path::Path) -> Type {
+        let file_name = model.chars()
+            .enumerate()
+            .fold(String::new(), |mut name, (index, character)| {
+                if character.is_ascii_uppercase() && index > 0 {
+                    name.push('_');
+                }
+                name.push(character.to_ascii_lowercase());
+                name
+            });
+        if models_path.join(format!("{}.rs", file_name)).exists() == false {
+            panic!("Streamed model {} not found in {}", model, models_path.display());
+        }
+        syn::parse_str(&format!("models::{}", model)).unwrap()
+    }
+
+    /// `Error<T>` out of `Result<_, Error<T>>`.
+    fn error_type(value: &Type) -> Option<Type> {
+        let segment = match value {
+            Type::Path(value) => value.path.segments.last()?,
+            _ => return None,
+        };
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(arguments) => {
+                match arguments.args.last()? {
+                    syn::GenericArgument::Type(value) => Some(value.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Replaces the body of the `if !status.is_client_error() && !status.is_server_error()` branch.
+struct SuccessBranch {
+    expr: syn::Expr,
+}
+
+impl VisitMut for SuccessBranch {
+    fn visit_expr_if_mut(&mut self, item: &mut ExprIf) {
+        if item.cond.to_token_stream().to_string().contains("is_client_error") {
+            let expr = &self.expr;
+            item.then_branch = parse_quote!({ Ok(#expr) });
+            return;
+        }
+        syn::visit_mut::visit_expr_if_mut(self, item);
+    }
+}
@@ -0,0 +1,6463 @@
+use crate::libpod::models;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::error;
+use std::fmt;
+use crate::api::transport::Transport;
+/// struct for typed errors of method [`container_archive_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerArchiveLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_attach_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerAttachLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_changes_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerChangesLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_checkpoint_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerCheckpointLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerCreateLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerDeleteLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_export_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerExportLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_healthcheck_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerHealthcheckLibpodError {
+    Status404(models::ErrorModel),
+    Status409(),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_init_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerInitLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_kill_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerKillLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerListLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_logs_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerLogsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_mount_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerMountLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_pause_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerPauseLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerPruneLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_rename_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerRenameLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_resize_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerResizeLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_restart_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerRestartLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_restore_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerRestoreLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_show_mounted_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerShowMountedLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_start_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStartLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_stats_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStatsLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_stop_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStopLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_top_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerTopLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_unmount_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerUnmountLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_unpause_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerUnpauseLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_update_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerUpdateLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_wait_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerWaitLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`containers_stats_all_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainersStatsAllLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`generate_kube_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GenerateKubeLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`generate_systemd_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GenerateSystemdLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_commit_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageCommitLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`kube_apply_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KubeApplyLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`play_kube_down_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PlayKubeDownLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`play_kube_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PlayKubeLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`put_container_archive_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PutContainerArchiveLibpodError {
+    Status400(models::ErrorModel),
+    Status403(),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct ContainerApi {
+    configuration: Arc<Configuration>,
+}
+impl ContainerApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Copy a tar archive of files from a container
+    pub fn archive(
+        &self,
+        params: ContainerArchiveLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<ContainerArchiveLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/archive", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        req_builder = req_builder.query(&[("path", &params.path.to_string())]);
+        if let Some(ref param_value) = params.rename {
+            req_builder = req_builder.query(&[("rename", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerArchiveLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Attach to a container to read its output or send it input. You can attach to the same container multiple times and you can reattach to containers that have been detached.  ### Hijacking  This endpoint hijacks the HTTP connection to transport `stdin`, `stdout`, and `stderr` on the same socket.  This is the response from the service for an attach request:  ``` HTTP/1.1 200 OK Content-Type: application/vnd.docker.raw-stream  [STREAM] ```  After the headers and two new lines, the TCP connection can now be used for raw, bidirectional communication between the client and server.  To inform potential proxies about connection hijacking, the client can also optionally send connection upgrade headers.  For example, the client sends this request to upgrade the connection:  ``` POST /v4.6.0/libpod/containers/16253994b7c4/attach?stream=1&stdout=1 HTTP/1.1 Upgrade: tcp Connection: Upgrade ```  The service will respond with a `101 UPGRADED` response, and will similarly follow with the raw stream:  ``` HTTP/1.1 101 UPGRADED Content-Type: application/vnd.docker.raw-stream Connection: Upgrade Upgrade: tcp  [STREAM] ```  ### Stream format  When the TTY setting is disabled for the container, the HTTP Content-Type header is set to application/vnd.docker.multiplexed-stream (starting with v4.7.0, previously application/vnd.docker.raw-stream was always used) and the stream over the hijacked connected is multiplexed to separate out `stdout` and `stderr`. The stream consists of a series of frames, each containing a header and a payload.  The header contains the information about the output stream type and the size of the payload. It is encoded on the first eight bytes like this:  ```go header := [8]byte{STREAM_TYPE, 0, 0, 0, SIZE1, SIZE2, SIZE3, SIZE4} ```  `STREAM_TYPE` can be:  - 0: `stdin` (is written on `stdout`) - 1: `stdout` - 2: `stderr`  `SIZE1, SIZE2, SIZE3, SIZE4` are the four bytes of the `uint32` size encoded as big endian.  Following the header is the payload, which contains the specified number of bytes as written in the size.  The simplest way to implement this protocol is the following:  1. Read 8 bytes. 2. Choose `stdout` or `stderr` depending on the first byte. 3. Extract the frame size from the last four bytes. 4. Read the extracted size and output it on the correct output. 5. Goto 1.  ### Stream format when using a TTY  When the TTY setting is enabled for the container, the stream is not multiplexed. The data exchanged over the hijacked connection is simply the raw data from the process PTY and client's `stdin`.
+    pub fn attach(
+        &self,
+        params: ContainerAttachLibpodParams,
+    ) -> Result<(), Error<ContainerAttachLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/attach", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.detach_keys {
+            req_builder = req_builder.query(&[("detachKeys", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.logs {
+            req_builder = req_builder.query(&[("logs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdin {
+            req_builder = req_builder.query(&[("stdin", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerAttachLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns which files in a container's filesystem have been added, deleted, or modified. The Kind of modification can be one of:  0: Modified 1: Added 2: Deleted
+    pub fn changes(
+        &self,
+        params: ContainerChangesLibpodParams,
+    ) -> Result<(), Error<ContainerChangesLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/changes", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.parent {
+            req_builder = req_builder.query(&[("parent", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.diff_type {
+            req_builder = req_builder.query(&[("diffType", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerChangesLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn checkpoint(
+        &self,
+        params: ContainerCheckpointLibpodParams,
+    ) -> Result<(), Error<ContainerCheckpointLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/checkpoint", self.configuration.base_path, name
+            = urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.keep {
+            req_builder = req_builder.query(&[("keep", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.leave_running {
+            req_builder = req_builder
+                .query(&[("leaveRunning", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tcp_established {
+            req_builder = req_builder
+                .query(&[("tcpEstablished", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.export {
+            req_builder = req_builder.query(&[("export", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_root_fs {
+            req_builder = req_builder
+                .query(&[("ignoreRootFS", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_volumes {
+            req_builder = req_builder
+                .query(&[("ignoreVolumes", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pre_checkpoint {
+            req_builder = req_builder
+                .query(&[("preCheckpoint", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.with_previous {
+            req_builder = req_builder
+                .query(&[("withPrevious", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.file_locks {
+            req_builder = req_builder.query(&[("fileLocks", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.print_stats {
+            req_builder = req_builder.query(&[("printStats", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerCheckpointLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn create(
+        &self,
+        params: ContainerCreateLibpodParams,
+    ) -> Result<models::ContainerCreateResponse, Error<ContainerCreateLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/create", self.configuration.base_path
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerCreateLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Delete container
+    pub fn delete(
+        &self,
+        params: ContainerDeleteLibpodParams,
+    ) -> Result<
+        Vec<models::LibpodContainersRmReport>,
+        Error<ContainerDeleteLibpodError>,
+    > {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.depend {
+            req_builder = req_builder.query(&[("depend", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore {
+            req_builder = req_builder.query(&[("ignore", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.timeout {
+            req_builder = req_builder.query(&[("timeout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.v {
+            req_builder = req_builder.query(&[("v", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerDeleteLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Quick way to determine if a container exists by name or ID
+    pub fn exists(
+        &self,
+        params: ContainerExistsLibpodParams,
+    ) -> Result<(), Error<ContainerExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerExistsLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Export the contents of a container as a tarball.
+    pub fn export(
+        &self,
+        params: ContainerExportLibpodParams,
+    ) -> Result<(), Error<ContainerExportLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/export", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerExportLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Execute the defined healthcheck and return information about the results
+    pub fn healthcheck(
+        &self,
+        params: ContainerHealthcheckLibpodParams,
+    ) -> Result<models::HealthCheckResults, Error<ContainerHealthcheckLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/healthcheck", self.configuration.base_path, name
+            = urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerHealthcheckLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Performs all tasks necessary for initializing the container but does not start the container.
+    pub fn init(
+        &self,
+        params: ContainerInitLibpodParams,
+    ) -> Result<(), Error<ContainerInitLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/init", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerInitLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return low-level information about a container.
+    pub fn inspect(
+        &self,
+        params: ContainerInspectLibpodParams,
+    ) -> Result<models::InspectContainerData, Error<ContainerInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.size {
+            req_builder = req_builder.query(&[("size", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerInspectLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// send a signal to a container, defaults to killing the container
+    pub fn kill(
+        &self,
+        params: ContainerKillLibpodParams,
+    ) -> Result<(), Error<ContainerKillLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/kill", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.signal {
+            req_builder = req_builder.query(&[("signal", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerKillLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of containers
+    pub fn list(
+        &self,
+        params: ContainerListLibpodParams,
+    ) -> Result<Vec<models::ListContainer>, Error<ContainerListLibpodError>> {
+        let uri_str = format!("{}/libpod/containers/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.limit {
+            req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.namespace {
+            req_builder = req_builder.query(&[("namespace", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pod {
+            req_builder = req_builder.query(&[("pod", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.size {
+            req_builder = req_builder.query(&[("size", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.sync {
+            req_builder = req_builder.query(&[("sync", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerListLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get stdout and stderr logs from a container.  The stream format is the same as described in the attach endpoint.
+    pub fn logs(
+        &self,
+        params: ContainerLogsLibpodParams,
+    ) -> Result<(), Error<ContainerLogsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/logs", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.follow {
+            req_builder = req_builder.query(&[("follow", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.until {
+            req_builder = req_builder.query(&[("until", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.timestamps {
+            req_builder = req_builder.query(&[("timestamps", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tail {
+            req_builder = req_builder.query(&[("tail", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerLogsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Mount a container to the filesystem
+    pub fn mount(
+        &self,
+        params: ContainerMountLibpodParams,
+    ) -> Result<String, Error<ContainerMountLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/mount", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerMountLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Use the cgroups freezer to suspend all processes in a container.
+    pub fn pause(
+        &self,
+        params: ContainerPauseLibpodParams,
+    ) -> Result<(), Error<ContainerPauseLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/pause", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerPauseLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove containers not in use
+    pub fn prune(
+        &self,
+        params: ContainerPruneLibpodParams,
+    ) -> Result<
+        Vec<models::ContainersPruneReportLibpod>,
+        Error<ContainerPruneLibpodError>,
+    > {
+        let uri_str = format!(
+            "{}/libpod/containers/prune", self.configuration.base_path
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerPruneLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Change the name of an existing container.
+    pub fn rename(
+        &self,
+        params: ContainerRenameLibpodParams,
+    ) -> Result<(), Error<ContainerRenameLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/rename", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("name", &params.name2.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerRenameLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resize the terminal attached to a container (for use with Attach).
+    pub fn resize(
+        &self,
+        params: ContainerResizeLibpodParams,
+    ) -> Result<serde_json::Value, Error<ContainerResizeLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/resize", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.h {
+            req_builder = req_builder.query(&[("h", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.w {
+            req_builder = req_builder.query(&[("w", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerResizeLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn restart(
+        &self,
+        params: ContainerRestartLibpodParams,
+    ) -> Result<(), Error<ContainerRestartLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/restart", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerRestartLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Restore a container from a checkpoint.
+    pub fn restore(
+        &self,
+        params: ContainerRestoreLibpodParams,
+    ) -> Result<(), Error<ContainerRestoreLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/restore", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.name2 {
+            req_builder = req_builder.query(&[("name", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.keep {
+            req_builder = req_builder.query(&[("keep", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tcp_established {
+            req_builder = req_builder
+                .query(&[("tcpEstablished", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.import {
+            req_builder = req_builder.query(&[("import", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_root_fs {
+            req_builder = req_builder
+                .query(&[("ignoreRootFS", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_volumes {
+            req_builder = req_builder
+                .query(&[("ignoreVolumes", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_static_ip {
+            req_builder = req_builder
+                .query(&[("ignoreStaticIP", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore_static_mac {
+            req_builder = req_builder
+                .query(&[("ignoreStaticMAC", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.file_locks {
+            req_builder = req_builder.query(&[("fileLocks", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.print_stats {
+            req_builder = req_builder.query(&[("printStats", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pod {
+            req_builder = req_builder.query(&[("pod", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerRestoreLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Lists all mounted containers mount points
+    pub fn show_mounted(
+        &self,
+    ) -> Result<
+        std::collections::HashMap<String, String>,
+        Error<ContainerShowMountedLibpodError>,
+    > {
+        let uri_str = format!(
+            "{}/libpod/containers/showmounted", self.configuration.base_path
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerShowMountedLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn start(
+        &self,
+        params: ContainerStartLibpodParams,
+    ) -> Result<(), Error<ContainerStartLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/start", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.detach_keys {
+            req_builder = req_builder.query(&[("detachKeys", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerStartLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// DEPRECATED. This endpoint will be removed with the next major release. Please use /libpod/containers/stats instead.
+    pub fn stats(
+        &self,
+        params: ContainerStatsLibpodParams,
+    ) -> Result<(), Error<ContainerStatsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/stats", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerStatsLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn stop(
+        &self,
+        params: ContainerStopLibpodParams,
+    ) -> Result<(), Error<ContainerStopLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/stop", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.timeout {
+            req_builder = req_builder.query(&[("timeout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore {
+            req_builder = req_builder.query(&[("Ignore", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerStopLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// List processes running inside a container
+    pub fn top(
+        &self,
+        params: ContainerTopLibpodParams,
+    ) -> Result<models::ContainerTopOKBody, Error<ContainerTopLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/top", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.delay {
+            req_builder = req_builder.query(&[("delay", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ps_args {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("ps_args".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "ps_args",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerTopLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Unmount a container from the filesystem
+    pub fn unmount(
+        &self,
+        params: ContainerUnmountLibpodParams,
+    ) -> Result<(), Error<ContainerUnmountLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/unmount", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerUnmountLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn unpause(
+        &self,
+        params: ContainerUnpauseLibpodParams,
+    ) -> Result<(), Error<ContainerUnpauseLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/unpause", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerUnpauseLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Update an existing containers cgroup configuration.
+    pub fn update(
+        &self,
+        params: ContainerUpdateLibpodParams,
+    ) -> Result<(), Error<ContainerUpdateLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/update", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.restart_policy {
+            req_builder = req_builder
+                .query(&[("restartPolicy", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.restart_retries {
+            req_builder = req_builder
+                .query(&[("restartRetries", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerUpdateLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Wait on a container to meet a given condition
+    pub fn wait(
+        &self,
+        params: ContainerWaitLibpodParams,
+    ) -> Result<i32, Error<ContainerWaitLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/wait", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.condition {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("condition".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "condition",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.interval {
+            req_builder = req_builder.query(&[("interval", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerWaitLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct ContainersApi {
+    configuration: Arc<Configuration>,
+}
+impl ContainersApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Return a live stream of resource usage statistics of one or more container. If no container is specified, the statistics of all containers are returned.
+    pub fn stats_all(
+        &self,
+        params: ContainersStatsAllLibpodParams,
+    ) -> Result<models::ContainerStats, Error<ContainersStatsAllLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/stats", self.configuration.base_path
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.containers {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("containers".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "containers",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.interval {
+            req_builder = req_builder.query(&[("interval", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainersStatsAllLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct GenerateApi {
+    configuration: Arc<Configuration>,
+}
+impl GenerateApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Generate Kubernetes YAML based on a pod or container.
+    pub fn kube(
+        &self,
+        params: GenerateKubeLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<GenerateKubeLibpodError>> {
+        let uri_str = format!("{}/libpod/generate/kube", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        req_builder = match "csv" {
+            "multi" => {
+                req_builder
+                    .query(
+                        &params
+                            .names
+                            .into_iter()
+                            .map(|p| ("names".to_owned(), p.to_string()))
+                            .collect::<Vec<(std::string::String, std::string::String)>>(),
+                    )
+            }
+            _ => {
+                req_builder
+                    .query(
+                        &[
+                            (
+                                "names",
+                                &params
+                                    .names
+                                    .into_iter()
+                                    .map(|p| p.to_string())
+                                    .collect::<Vec<String>>()
+                                    .join(",")
+                                    .to_string(),
+                            ),
+                        ],
+                    )
+            }
+        };
+        if let Some(ref param_value) = params.service {
+            req_builder = req_builder.query(&[("service", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.r#type {
+            req_builder = req_builder.query(&[("type", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.replicas {
+            req_builder = req_builder.query(&[("replicas", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.no_trunc {
+            req_builder = req_builder.query(&[("noTrunc", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.podman_only {
+            req_builder = req_builder.query(&[("podmanOnly", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<GenerateKubeLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Generate Systemd Units based on a pod or container.
+    pub fn systemd(
+        &self,
+        params: GenerateSystemdLibpodParams,
+    ) -> Result<
+        std::collections::HashMap<String, String>,
+        Error<GenerateSystemdLibpodError>,
+    > {
+        let uri_str = format!(
+            "{}/libpod/generate/{name}/systemd", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.use_name {
+            req_builder = req_builder.query(&[("useName", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.new {
+            req_builder = req_builder.query(&[("new", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.no_header {
+            req_builder = req_builder.query(&[("noHeader", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.start_timeout {
+            req_builder = req_builder
+                .query(&[("startTimeout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stop_timeout {
+            req_builder = req_builder
+                .query(&[("stopTimeout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.restart_policy {
+            req_builder = req_builder
+                .query(&[("restartPolicy", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.container_prefix {
+            req_builder = req_builder
+                .query(&[("containerPrefix", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pod_prefix {
+            req_builder = req_builder.query(&[("podPrefix", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.separator {
+            req_builder = req_builder.query(&[("separator", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.restart_sec {
+            req_builder = req_builder.query(&[("restartSec", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.wants {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("wants".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "wants",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.after {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("after".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "after",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.requires {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("requires".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "requires",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.additional_env_variables {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| (
+                                    "additionalEnvVariables".to_owned(),
+                                    p.to_string(),
+                                ))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "additionalEnvVariables",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<GenerateSystemdLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct ContainerImageApi {
+    configuration: Arc<Configuration>,
+}
+impl ContainerImageApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Create a new image from a container
+    pub fn commit(
+        &self,
+        params: ImageCommitLibpodParams,
+    ) -> Result<(), Error<ImageCommitLibpodError>> {
+        let uri_str = format!("{}/libpod/commit", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("container", &params.container.to_string())]);
+        if let Some(ref param_value) = params.author {
+            req_builder = req_builder.query(&[("author", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.changes {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("changes".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "changes",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.comment {
+            req_builder = req_builder.query(&[("comment", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.format {
+            req_builder = req_builder.query(&[("format", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pause {
+            req_builder = req_builder.query(&[("pause", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.squash {
+            req_builder = req_builder.query(&[("squash", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageCommitLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct KubeApi {
+    configuration: Arc<Configuration>,
+}
+impl KubeApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Deploy a podman container, pod, volume, or Kubernetes yaml to a Kubernetes cluster.
+    pub fn apply(
+        &self,
+        params: KubeApplyLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<KubeApplyLibpodError>> {
+        let uri_str = format!("{}/libpod/kube/apply", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.ca_cert_file {
+            req_builder = req_builder.query(&[("caCertFile", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.kube_config {
+            req_builder = req_builder.query(&[("kubeConfig", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.namespace {
+            req_builder = req_builder.query(&[("namespace", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.service {
+            req_builder = req_builder.query(&[("service", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.file {
+            req_builder = req_builder.query(&[("file", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.request);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<KubeApplyLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct PlayApi {
+    configuration: Arc<Configuration>,
+}
+impl PlayApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Tears down pods, secrets, and volumes defined in a YAML file
+    pub fn kube_down(
+        &self,
+        params: PlayKubeDownLibpodParams,
+    ) -> Result<models::PlayKubeReport, Error<PlayKubeDownLibpodError>> {
+        let uri_str = format!("{}/libpod/play/kube", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PlayKubeDownLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Create and run pods based on a Kubernetes YAML file (pod or service kind).
+    pub fn kube(
+        &self,
+        params: PlayKubeLibpodParams,
+    ) -> Result<models::PlayKubeReport, Error<PlayKubeLibpodError>> {
+        let uri_str = format!("{}/libpod/play/kube", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.annotations {
+            req_builder = req_builder
+                .query(&[("annotations", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.log_driver {
+            req_builder = req_builder.query(&[("logDriver", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.log_options {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("logOptions".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "logOptions",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.network {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("network".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "network",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.no_hosts {
+            req_builder = req_builder.query(&[("noHosts", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.no_trunc {
+            req_builder = req_builder.query(&[("noTrunc", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.publish_ports {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("publishPorts".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "publishPorts",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.publish_all_ports {
+            req_builder = req_builder
+                .query(&[("publishAllPorts", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.replace {
+            req_builder = req_builder.query(&[("replace", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.service_container {
+            req_builder = req_builder
+                .query(&[("serviceContainer", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.start {
+            req_builder = req_builder.query(&[("start", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.static_ips {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("staticIPs".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "staticIPs",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.static_macs {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("staticMACs".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "staticMACs",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.userns {
+            req_builder = req_builder.query(&[("userns", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.wait {
+            req_builder = req_builder.query(&[("wait", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.request);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PlayKubeLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct PutApi {
+    configuration: Arc<Configuration>,
+}
+impl PutApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Copy a tar archive of files into a container
+    pub fn container_archive(
+        &self,
+        params: PutContainerArchiveLibpodParams,
+    ) -> Result<(), Error<PutContainerArchiveLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/archive", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::PUT, &uri_str);
+        req_builder = req_builder.query(&[("path", &params.path.to_string())]);
+        if let Some(ref param_value) = params.pause {
+            req_builder = req_builder.query(&[("pause", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.request);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PutContainerArchiveLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`container_exec_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerExecLibpodError {
+    Status404(models::ErrorModel),
+    Status409(),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_resize_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecResizeLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_start_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecStartLibpodError {
+    Status404(models::ErrorModel),
+    Status409(),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct ExecContainerApi {
+    configuration: Arc<Configuration>,
+}
+impl ExecContainerApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Create an exec session to run a command inside a running container. Exec sessions will be automatically removed 5 minutes after they exit.
+    pub fn exec(
+        &self,
+        params: ContainerExecLibpodParams,
+    ) -> Result<(), Error<ContainerExecLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/containers/{name}/exec", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.control);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ContainerExecLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+pub struct ExecApi {
+    configuration: Arc<Configuration>,
+}
+impl ExecApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Return low-level information about an exec instance.
+    pub fn inspect(
+        &self,
+        params: ExecInspectLibpodParams,
+    ) -> Result<(), Error<ExecInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/exec/{id}/json", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ExecInspectLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resize the TTY session used by an exec instance. This endpoint only works if tty was specified as part of creating and starting the exec instance.
+    pub fn resize(
+        &self,
+        params: ExecResizeLibpodParams,
+    ) -> Result<(), Error<ExecResizeLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/exec/{id}/resize", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.h {
+            req_builder = req_builder.query(&[("h", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.w {
+            req_builder = req_builder.query(&[("w", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ExecResizeLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Starts a previously set up exec instance. If detach is true, this endpoint returns immediately after starting the command. Otherwise, it sets up an interactive session with the command. The stream format is the same as the attach endpoint.
+    pub fn start(
+        &self,
+        params: ExecStartLibpodParams,
+    ) -> Result<(), Error<ExecStartLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/exec/{id}/start", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.control);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ExecStartLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`image_build_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageBuildLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_changes_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageChangesLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_delete_all_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageDeleteAllLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageDeleteLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_export_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageExportLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_get_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageGetLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_history_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageHistoryLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_import_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageImportLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageListLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_load_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageLoadLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImagePruneLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_pull_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImagePullLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_push_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImagePushLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_resolve_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageResolveLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_scp_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageScpLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_search_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageSearchLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_tag_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageTagLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_tree_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageTreeLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_untag_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageUntagLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct ImageApi {
+    configuration: Arc<Configuration>,
+}
+impl ImageApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Build an image from the given Dockerfile(s)
+    pub fn build(
+        &self,
+        params: ImageBuildLibpodParams,
+    ) -> Result<models::ImageBuildLibpod200Response, Error<ImageBuildLibpodError>> {
+        let uri_str = format!("{}/libpod/build", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.dockerfile {
+            req_builder = req_builder.query(&[("dockerfile", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.allplatforms {
+            req_builder = req_builder
+                .query(&[("allplatforms", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.extrahosts {
+            req_builder = req_builder.query(&[("extrahosts", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.remote {
+            req_builder = req_builder.query(&[("remote", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.q {
+            req_builder = req_builder.query(&[("q", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.nocache {
+            req_builder = req_builder.query(&[("nocache", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cachefrom {
+            req_builder = req_builder.query(&[("cachefrom", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pull {
+            req_builder = req_builder.query(&[("pull", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.rm {
+            req_builder = req_builder.query(&[("rm", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.forcerm {
+            req_builder = req_builder.query(&[("forcerm", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.memory {
+            req_builder = req_builder.query(&[("memory", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.memswap {
+            req_builder = req_builder.query(&[("memswap", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpushares {
+            req_builder = req_builder.query(&[("cpushares", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpusetcpus {
+            req_builder = req_builder.query(&[("cpusetcpus", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpuperiod {
+            req_builder = req_builder.query(&[("cpuperiod", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpuquota {
+            req_builder = req_builder.query(&[("cpuquota", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.buildargs {
+            req_builder = req_builder.query(&[("buildargs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.shmsize {
+            req_builder = req_builder.query(&[("shmsize", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.squash {
+            req_builder = req_builder.query(&[("squash", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.labels {
+            req_builder = req_builder.query(&[("labels", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.layer_label {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("layerLabel".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "layerLabel",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.layers {
+            req_builder = req_builder.query(&[("layers", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.networkmode {
+            req_builder = req_builder
+                .query(&[("networkmode", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.platform {
+            req_builder = req_builder.query(&[("platform", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.target {
+            req_builder = req_builder.query(&[("target", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.outputs {
+            req_builder = req_builder.query(&[("outputs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.httpproxy {
+            req_builder = req_builder.query(&[("httpproxy", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.unsetenv {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("unsetenv".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "unsetenv",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.unsetlabel {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("unsetlabel".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "unsetlabel",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.volume {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("volume".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "volume",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageBuildLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns which files in an image's filesystem have been added, deleted, or modified. The Kind of modification can be one of:  0: Modified 1: Added 2: Deleted
+    pub fn changes(
+        &self,
+        params: ImageChangesLibpodParams,
+    ) -> Result<(), Error<ImageChangesLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/changes", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.parent {
+            req_builder = req_builder.query(&[("parent", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.diff_type {
+            req_builder = req_builder.query(&[("diffType", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageChangesLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove one or more images from the storage.
+    pub fn delete_all(
+        &self,
+        params: ImageDeleteAllLibpodParams,
+    ) -> Result<models::LibpodImagesRemoveReport, Error<ImageDeleteAllLibpodError>> {
+        let uri_str = format!("{}/libpod/images/remove", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.images {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("images".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "images",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ignore {
+            req_builder = req_builder.query(&[("ignore", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.lookup_manifest {
+            req_builder = req_builder
+                .query(&[("lookupManifest", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageDeleteAllLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove an image from the local storage.
+    pub fn delete(
+        &self,
+        params: ImageDeleteLibpodParams,
+    ) -> Result<models::LibpodImagesRemoveReport, Error<ImageDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageDeleteLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Check if image exists in local store
+    pub fn exists(
+        &self,
+        params: ImageExistsLibpodParams,
+    ) -> Result<(), Error<ImageExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageExistsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Export multiple images into a single object. Only `docker-archive` is currently supported.
+    pub fn export(
+        &self,
+        params: ImageExportLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<ImageExportLibpodError>> {
+        let uri_str = format!("{}/libpod/images/export", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.format {
+            req_builder = req_builder.query(&[("format", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.references {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("references".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "references",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.compress {
+            req_builder = req_builder.query(&[("compress", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.oci_accept_uncompressed_layers {
+            req_builder = req_builder
+                .query(&[("ociAcceptUncompressedLayers", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageExportLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Export an image
+    pub fn get(
+        &self,
+        params: ImageGetLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<ImageGetLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/get", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.format {
+            req_builder = req_builder.query(&[("format", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.compress {
+            req_builder = req_builder.query(&[("compress", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageGetLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return parent layers of an image.
+    pub fn history(
+        &self,
+        params: ImageHistoryLibpodParams,
+    ) -> Result<models::HistoryResponse, Error<ImageHistoryLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/history", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageHistoryLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Import a previously exported tarball as an image.
+    pub fn import(
+        &self,
+        params: ImageImportLibpodParams,
+    ) -> Result<models::ImageImportReport, Error<ImageImportLibpodError>> {
+        let uri_str = format!("{}/libpod/images/import", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.changes {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("changes".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "changes",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.message {
+            req_builder = req_builder.query(&[("message", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.reference {
+            req_builder = req_builder.query(&[("reference", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.url {
+            req_builder = req_builder.query(&[("url", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.content_type {
+            req_builder = req_builder.header("Content-Type", param_value.to_string());
+        }
+        req_builder = req_builder.body(params.upload);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageImportLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Obtain low-level information about an image
+    pub fn inspect(
+        &self,
+        params: ImageInspectLibpodParams,
+    ) -> Result<models::ImageData, Error<ImageInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageInspectLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of images on the server
+    pub fn list(
+        &self,
+        params: ImageListLibpodParams,
+    ) -> Result<Vec<models::LibpodImageSummary>, Error<ImageListLibpodError>> {
+        let uri_str = format!("{}/libpod/images/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageListLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Load an image (oci-archive or docker-archive) stream.
+    pub fn load(
+        &self,
+        params: ImageLoadLibpodParams,
+    ) -> Result<models::ImageLoadReport, Error<ImageLoadLibpodError>> {
+        let uri_str = format!("{}/libpod/images/load", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.upload);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageLoadLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove images that are not being used by a container
+    pub fn prune(
+        &self,
+        params: ImagePruneLibpodParams,
+    ) -> Result<Vec<models::PruneReport>, Error<ImagePruneLibpodError>> {
+        let uri_str = format!("{}/libpod/images/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.external {
+            req_builder = req_builder.query(&[("external", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImagePruneLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Pull one or more images from a container registry.
+    pub fn pull(
+        &self,
+        params: ImagePullLibpodParams,
+    ) -> Result<models::LibpodImagesPullReport, Error<ImagePullLibpodError>> {
+        let uri_str = format!("{}/libpod/images/pull", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.reference {
+            req_builder = req_builder.query(&[("reference", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.quiet {
+            req_builder = req_builder.query(&[("quiet", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.compat_mode {
+            req_builder = req_builder.query(&[("compatMode", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.arch {
+            req_builder = req_builder.query(&[("Arch", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.os {
+            req_builder = req_builder.query(&[("OS", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.variant {
+            req_builder = req_builder.query(&[("Variant", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.policy {
+            req_builder = req_builder.query(&[("policy", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.all_tags {
+            req_builder = req_builder.query(&[("allTags", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImagePullLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Push an image to a container registry
+    pub fn push(
+        &self,
+        params: ImagePushLibpodParams,
+    ) -> Result<reqwest::blocking::Response, Error<ImagePushLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/push", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.destination {
+            req_builder = req_builder
+                .query(&[("destination", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.force_compression_format {
+            req_builder = req_builder
+                .query(&[("forceCompressionFormat", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.quiet {
+            req_builder = req_builder.query(&[("quiet", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImagePushLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resolve the passed image name to a list of fully-qualified images referring to container registries.
+    pub fn resolve(
+        &self,
+        params: ImageResolveLibpodParams,
+    ) -> Result<(), Error<ImageResolveLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/resolve", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageResolveLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Copy an image from one host to another
+    pub fn scp(
+        &self,
+        params: ImageScpLibpodParams,
+    ) -> Result<models::ScpReport, Error<ImageScpLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/scp/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.destination {
+            req_builder = req_builder
+                .query(&[("destination", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.quiet {
+            req_builder = req_builder.query(&[("quiet", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageScpLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Search registries for images
+    pub fn search(
+        &self,
+        params: ImageSearchLibpodParams,
+    ) -> Result<models::ImageSearchLibpod200Response, Error<ImageSearchLibpodError>> {
+        let uri_str = format!("{}/libpod/images/search", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.term {
+            req_builder = req_builder.query(&[("term", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.limit {
+            req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.list_tags {
+            req_builder = req_builder.query(&[("listTags", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageSearchLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Tag an image so that it becomes part of a repository.
+    pub fn tag(
+        &self,
+        params: ImageTagLibpodParams,
+    ) -> Result<(), Error<ImageTagLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/tag", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageTagLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Retrieve the image tree for the provided image name or ID
+    pub fn tree(
+        &self,
+        params: ImageTreeLibpodParams,
+    ) -> Result<models::ImageTreeReport, Error<ImageTreeLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/tree", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.whatrequires {
+            req_builder = req_builder
+                .query(&[("whatrequires", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageTreeLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Untag an image. If not repo and tag are specified, all tags are removed from the image.
+    pub fn untag(
+        &self,
+        params: ImageUntagLibpodParams,
+    ) -> Result<(), Error<ImageUntagLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/images/{name}/untag", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ImageUntagLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`manifest_add_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestAddLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestCreateLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestDeleteLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_modify_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestModifyLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::ManifestModifyReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_push_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestPushLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`manifest_push_v3_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestPushV3LibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct ManifestApi {
+    configuration: Arc<Configuration>,
+}
+impl ManifestApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Add an image to a manifest list  Deprecated: As of 4.0.0 use ManifestModifyLibpod instead
+    pub fn add(
+        &self,
+        params: ManifestAddLibpodParams,
+    ) -> Result<models::IdResponse, Error<ManifestAddLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}/add", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.options);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestAddLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Create a manifest list
+    pub fn create(
+        &self,
+        params: ManifestCreateLibpodParams,
+    ) -> Result<models::IdResponse, Error<ManifestCreateLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("images", &params.images.to_string())]);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.amend {
+            req_builder = req_builder.query(&[("amend", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.options);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestCreateLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Delete named manifest list  As of v4.0.0
+    pub fn delete(
+        &self,
+        params: ManifestDeleteLibpodParams,
+    ) -> Result<models::LibpodImagesRemoveReport, Error<ManifestDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestDeleteLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Check if manifest list exists  Note: There is no contract that the manifest list will exist for a follow-on operation
+    pub fn exists(
+        &self,
+        params: ManifestExistsLibpodParams,
+    ) -> Result<(), Error<ManifestExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestExistsLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Display attributes of given manifest list
+    pub fn inspect(
+        &self,
+        params: ManifestInspectLibpodParams,
+    ) -> Result<models::Schema2ListPublic, Error<ManifestInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestInspectLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Add/Remove an image(s) to a manifest list  Note: operations are not atomic when multiple Images are provided.  As of v4.0.0
+    pub fn modify(
+        &self,
+        params: ManifestModifyLibpodParams,
+    ) -> Result<models::ManifestModifyReport, Error<ManifestModifyLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::PUT, &uri_str);
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.options);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestModifyLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Push a manifest list or image index to the named registry  As of v4.0.0
+    pub fn push(
+        &self,
+        params: ManifestPushLibpodParams,
+    ) -> Result<models::IdResponse, Error<ManifestPushLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}/registry/{destination}", self.configuration
+            .base_path, name = urlencode(params.name), destination = urlencode(params
+            .destination)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.add_compression {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("addCompression".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "addCompression",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.force_compression_format {
+            req_builder = req_builder
+                .query(&[("forceCompressionFormat", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tls_verify {
+            req_builder = req_builder.query(&[("tlsVerify", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.quiet {
+            req_builder = req_builder.query(&[("quiet", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestPushLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Push a manifest list or image index to a registry  Deprecated: As of 4.0.0 use ManifestPushLibpod instead
+    pub fn push_v3(
+        &self,
+        params: ManifestPushV3LibpodParams,
+    ) -> Result<models::IdResponse, Error<ManifestPushV3LibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/manifests/{name}/push", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder
+            .query(&[("destination", &params.destination.to_string())]);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<ManifestPushV3LibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`network_connect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkConnectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkCreateLibpodError {
+    Status400(models::ErrorModel),
+    Status409(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkDeleteLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_disconnect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkDisconnectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkListLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkPruneLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_update_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkUpdateLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct NetworkApi {
+    configuration: Arc<Configuration>,
+}
+impl NetworkApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Connect a container to a network.
+    pub fn connect(
+        &self,
+        params: NetworkConnectLibpodParams,
+    ) -> Result<(), Error<NetworkConnectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}/connect", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkConnectLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Create a new network configuration
+    pub fn create(
+        &self,
+        params: NetworkCreateLibpodParams,
+    ) -> Result<models::Network, Error<NetworkCreateLibpodError>> {
+        let uri_str = format!("{}/libpod/networks/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkCreateLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove a configured network
+    pub fn delete(
+        &self,
+        params: NetworkDeleteLibpodParams,
+    ) -> Result<Vec<models::NetworkRmReport>, Error<NetworkDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkDeleteLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Disconnect a container from a network.
+    pub fn disconnect(
+        &self,
+        params: NetworkDisconnectLibpodParams,
+    ) -> Result<(), Error<NetworkDisconnectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}/disconnect", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkDisconnectLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Check if network exists
+    pub fn exists(
+        &self,
+        params: NetworkExistsLibpodParams,
+    ) -> Result<(), Error<NetworkExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkExistsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Display configuration for a network.
+    pub fn inspect(
+        &self,
+        params: NetworkInspectLibpodParams,
+    ) -> Result<models::NetworkInspectReport, Error<NetworkInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkInspectLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Display summary of network configurations.   - In a 200 response, all of the fields named Bytes are returned as a Base64 encoded string.
+    pub fn list(
+        &self,
+        params: NetworkListLibpodParams,
+    ) -> Result<Vec<models::Network>, Error<NetworkListLibpodError>> {
+        let uri_str = format!("{}/libpod/networks/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkListLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove networks that do not have containers
+    pub fn prune(
+        &self,
+        params: NetworkPruneLibpodParams,
+    ) -> Result<Vec<models::NetworkPruneReport>, Error<NetworkPruneLibpodError>> {
+        let uri_str = format!("{}/libpod/networks/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkPruneLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Update existing podman network
+    pub fn update(
+        &self,
+        params: NetworkUpdateLibpodParams,
+    ) -> Result<(), Error<NetworkUpdateLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/networks/{name}/update", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.update);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<NetworkUpdateLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`pod_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodCreateLibpodError {
+    Status400(models::ErrorModel),
+    Status409(String),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodDeleteLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_kill_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodKillLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::PodKillReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodListLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_pause_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodPauseLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::PodPauseReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodPruneLibpodError {
+    Status400(models::ErrorModel),
+    Status409(),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_restart_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodRestartLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::PodRestartReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_start_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodStartLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::PodStartReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_stats_all_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodStatsAllLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_stop_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodStopLibpodError {
+    Status400(models::ErrorModel),
+    Status404(models::ErrorModel),
+    Status409(models::PodStopReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_top_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodTopLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`pod_unpause_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PodUnpauseLibpodError {
+    Status404(models::ErrorModel),
+    Status409(models::PodUnpauseReport),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct PodApi {
+    configuration: Arc<Configuration>,
+}
+impl PodApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub fn create(
+        &self,
+        params: PodCreateLibpodParams,
+    ) -> Result<models::IdResponse, Error<PodCreateLibpodError>> {
+        let uri_str = format!("{}/libpod/pods/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodCreateLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn delete(
+        &self,
+        params: PodDeleteLibpodParams,
+    ) -> Result<models::PodRmReport, Error<PodDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodDeleteLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Check if a pod exists by name or ID
+    pub fn exists(
+        &self,
+        params: PodExistsLibpodParams,
+    ) -> Result<(), Error<PodExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodExistsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn inspect(
+        &self,
+        params: PodInspectLibpodParams,
+    ) -> Result<models::InspectPodData, Error<PodInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodInspectLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn kill(
+        &self,
+        params: PodKillLibpodParams,
+    ) -> Result<models::PodKillReport, Error<PodKillLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/kill", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.signal {
+            req_builder = req_builder.query(&[("signal", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodKillLibpodError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn list(
+        &self,
+        params: PodListLibpodParams,
+    ) -> Result<Vec<models::ListPodsReport>, Error<PodListLibpodError>> {
+        let uri_str = format!("{}/libpod/pods/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodListLibpodError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Pause a pod
+    pub fn pause(
+        &self,
+        params: PodPauseLibpodParams,
+    ) -> Result<models::PodPauseReport, Error<PodPauseLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/pause", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodPauseLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn prune(&self) -> Result<models::PodPruneReport, Error<PodPruneLibpodError>> {
+        let uri_str = format!("{}/libpod/pods/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodPruneLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn restart(
+        &self,
+        params: PodRestartLibpodParams,
+    ) -> Result<models::PodRestartReport, Error<PodRestartLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/restart", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodRestartLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn start(
+        &self,
+        params: PodStartLibpodParams,
+    ) -> Result<models::PodStartReport, Error<PodStartLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/start", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodStartLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Display a live stream of resource usage statistics for the containers in one or more pods
+    pub fn stats_all(
+        &self,
+        params: PodStatsAllLibpodParams,
+    ) -> Result<Vec<models::PodStatsReport>, Error<PodStatsAllLibpodError>> {
+        let uri_str = format!("{}/libpod/pods/stats", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.names_or_ids {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("namesOrIDs".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "namesOrIDs",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodStatsAllLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn stop(
+        &self,
+        params: PodStopLibpodParams,
+    ) -> Result<models::PodStopReport, Error<PodStopLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/stop", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodStopLibpodError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// List processes running inside a pod
+    pub fn top(
+        &self,
+        params: PodTopLibpodParams,
+    ) -> Result<models::PodTopOKBody, Error<PodTopLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/top", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.delay {
+            req_builder = req_builder.query(&[("delay", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.ps_args {
+            req_builder = req_builder.query(&[("ps_args", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodTopLibpodError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn unpause(
+        &self,
+        params: PodUnpauseLibpodParams,
+    ) -> Result<models::PodUnpauseReport, Error<PodUnpauseLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/pods/{name}/unpause", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<PodUnpauseLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`secret_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretCreateLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretDeleteLibpodError {
+    Status404(models::SecretDeleteLibpod404Response),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretExistsLibpodError {
+    Status404(models::SecretDeleteLibpod404Response),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretInspectLibpodError {
+    Status404(models::SecretDeleteLibpod404Response),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretListLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct SecretApi {
+    configuration: Arc<Configuration>,
+}
+impl SecretApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub fn create(
+        &self,
+        params: SecretCreateLibpodParams,
+    ) -> Result<models::SecretCreateLibpod201Response, Error<SecretCreateLibpodError>> {
+        let uri_str = format!("{}/libpod/secrets/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("name", &params.name.to_string())]);
+        if let Some(ref param_value) = params.driver {
+            req_builder = req_builder.query(&[("driver", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.driveropts {
+            req_builder = req_builder.query(&[("driveropts", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.labels {
+            req_builder = req_builder.query(&[("labels", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.request);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SecretCreateLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn delete(
+        &self,
+        params: SecretDeleteLibpodParams,
+    ) -> Result<(), Error<SecretDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/secrets/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SecretDeleteLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn exists(
+        &self,
+        params: SecretExistsLibpodParams,
+    ) -> Result<(), Error<SecretExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/secrets/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SecretExistsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn inspect(
+        &self,
+        params: SecretInspectLibpodParams,
+    ) -> Result<models::SecretInfoReport, Error<SecretInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/secrets/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.showsecret {
+            req_builder = req_builder.query(&[("showsecret", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SecretInspectLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of secrets
+    pub fn list(
+        &self,
+        params: SecretListLibpodParams,
+    ) -> Result<Vec<models::SecretInfoReport>, Error<SecretListLibpodError>> {
+        let uri_str = format!("{}/libpod/secrets/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SecretListLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`system_data_usage_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemDataUsageLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_events_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemEventsLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_info_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemInfoLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_ping`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPingError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPruneLibpodError {
+    Status400(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_version_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemVersionLibpodError {
+    UnknownValue(serde_json::Value),
+}
+pub struct SystemApi {
+    configuration: Arc<Configuration>,
+}
+impl SystemApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Return information about disk usage for containers, images, and volumes
+    pub fn data_usage(
+        &self,
+    ) -> Result<models::SystemDfReport, Error<SystemDataUsageLibpodError>> {
+        let uri_str = format!("{}/libpod/system/df", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemDataUsageLibpodError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns events filtered on query parameters
+    pub fn events(
+        &self,
+        params: SystemEventsLibpodParams,
+    ) -> Result<(), Error<SystemEventsLibpodError>> {
+        let uri_str = format!("{}/libpod/events", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.until {
+            req_builder = req_builder.query(&[("until", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemEventsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns information on the system and libpod configuration
+    pub fn info(&self) -> Result<models::LibpodInfo, Error<SystemInfoLibpodError>> {
+        let uri_str = format!("{}/libpod/info", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemInfoLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return protocol information in response headers. `HEAD /libpod/_ping` is also supported. `/_ping` is available for compatibility with other engines. The '_ping' endpoints are not versioned.
+    pub fn ping(&self) -> Result<String, Error<SystemPingError>> {
+        let uri_str = format!("{}/libpod/_ping", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemPingError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn prune(
+        &self,
+    ) -> Result<models::SystemPruneReport, Error<SystemPruneLibpodError>> {
+        let uri_str = format!("{}/libpod/system/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemPruneLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn version(
+        &self,
+    ) -> Result<models::SystemComponentVersion, Error<SystemVersionLibpodError>> {
+        let uri_str = format!("{}/libpod/version", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<SystemVersionLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`volume_create_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeCreateLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_delete_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeDeleteLibpodError {
+    Status404(models::ErrorModel),
+    Status409(),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_exists_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeExistsLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_inspect_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeInspectLibpodError {
+    Status404(models::ErrorModel),
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_list_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeListLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_prune_libpod`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumePruneLibpodError {
+    Status500(models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+pub struct VolumeApi {
+    configuration: Arc<Configuration>,
+}
+impl VolumeApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub fn create(
+        &self,
+        params: VolumeCreateLibpodParams,
+    ) -> Result<models::VolumeConfigResponse, Error<VolumeCreateLibpodError>> {
+        let uri_str = format!("{}/libpod/volumes/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.create);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumeCreateLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn delete(
+        &self,
+        params: VolumeDeleteLibpodParams,
+    ) -> Result<(), Error<VolumeDeleteLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/volumes/{name}", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumeDeleteLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Check if a volume exists
+    pub fn exists(
+        &self,
+        params: VolumeExistsLibpodParams,
+    ) -> Result<(), Error<VolumeExistsLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/volumes/{name}/exists", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumeExistsLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn inspect(
+        &self,
+        params: VolumeInspectLibpodParams,
+    ) -> Result<models::VolumeConfigResponse, Error<VolumeInspectLibpodError>> {
+        let uri_str = format!(
+            "{}/libpod/volumes/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumeInspectLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of volumes
+    pub fn list(
+        &self,
+        params: VolumeListLibpodParams,
+    ) -> Result<Vec<models::VolumeConfigResponse>, Error<VolumeListLibpodError>> {
+        let uri_str = format!("{}/libpod/volumes/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumeListLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub fn prune(
+        &self,
+        params: VolumePruneLibpodParams,
+    ) -> Result<Vec<models::PruneReport>, Error<VolumePruneLibpodError>> {
+        let uri_str = format!("{}/libpod/volumes/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req)?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text()?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text()?;
+            let entity: Option<VolumePruneLibpodError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ResponseContent<T> {
+    pub status: reqwest::StatusCode,
+    pub content: String,
+    pub entity: Option<T>,
+}
+#[derive(Debug)]
+pub enum Error<T> {
+    Reqwest(reqwest::Error),
+    Serde(serde_json::Error),
+    Io(std::io::Error),
+    ResponseError(ResponseContent<T>),
+}
+impl<T> fmt::Display for Error<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (module, e) = match self {
+            Error::Reqwest(e) => ("reqwest", e.to_string()),
+            Error::Serde(e) => ("serde", e.to_string()),
+            Error::Io(e) => ("IO", e.to_string()),
+            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+        };
+        write!(f, "error in {}: {}", module, e)
+    }
+}
+impl<T: fmt::Debug> error::Error for Error<T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(
+            match self {
+                Error::Reqwest(e) => e,
+                Error::Serde(e) => e,
+                Error::Io(e) => e,
+                Error::ResponseError(_) => return None,
+            },
+        )
+    }
+}
+impl<T> From<reqwest::Error> for Error<T> {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Reqwest(e)
+    }
+}
+impl<T> From<serde_json::Error> for Error<T> {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serde(e)
+    }
+}
+impl<T> From<std::io::Error> for Error<T> {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+pub fn urlencode<T: AsRef<str>>(s: T) -> String {
+    ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
+}
+pub fn parse_deep_object(
+    prefix: &str,
+    value: &serde_json::Value,
+) -> Vec<(String, String)> {
+    if let serde_json::Value::Object(object) = value {
+        let mut params = vec![];
+        for (key, value) in object {
+            match value {
+                serde_json::Value::Object(_) => {
+                    params
+                        .append(
+                            &mut parse_deep_object(
+                                &format!("{}[{}]", prefix, key),
+                                value,
+                            ),
+                        )
+                }
+                serde_json::Value::Array(array) => {
+                    for (i, value) in array.iter().enumerate() {
+                        params
+                            .append(
+                                &mut parse_deep_object(
+                                    &format!("{}[{}][{}]", prefix, key, i),
+                                    value,
+                                ),
+                            );
+                    }
+                }
+                serde_json::Value::String(s) => {
+                    params.push((format!("{}[{}]", prefix, key), s.clone()))
+                }
+                _ => params.push((format!("{}[{}]", prefix, key), value.to_string())),
+            }
+        }
+        return params;
+    }
+    unimplemented!("Only objects are supported with style=deepObject")
+}
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: Transport,
+}
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+}
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "http://podman.io".to_owned(),
+            user_agent: Some("libpod-api-sync-0.1.0".to_owned()),
+            client: Transport::default(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+macro_rules! libpod_apis {
+    ($client:ident, $api:ident, $root_client:path) => {
+        $client!($api, $root_client, container: ContainerApi, container_image: ContainerImageApi, containers: ContainersApi, exec: ExecApi, exec_container: ExecContainerApi, generate: GenerateApi, image: ImageApi, kube: KubeApi, manifest: ManifestApi, network: NetworkApi, play: PlayApi, pod: PodApi, put: PutApi, secret: SecretApi, system: SystemApi, volume: VolumeApi);
+    };
+}
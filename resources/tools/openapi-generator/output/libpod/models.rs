@@ -0,0 +1,10065 @@
+use serde::{Deserialize, Serialize};
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccessMode {
+    /// Intentionally empty.
+    #[serde(rename = "BlockVolume", skip_serializing_if = "Option::is_none")]
+    pub block_volume: Option<serde_json::Value>,
+    #[serde(rename = "MountVolume", skip_serializing_if = "Option::is_none")]
+    pub mount_volume: Option<Box<TypeMount>>,
+    /// Scope defines the Scope of a Cluster Volume. This is how many nodes a Volume can be accessed simultaneously on.
+    #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// SharingMode defines the Sharing of a Cluster Volume. This is how Tasks using a Volume at the same time can use it.
+    #[serde(rename = "Sharing", skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<String>,
+}
+impl AccessMode {
+    pub fn new() -> AccessMode {
+        AccessMode {
+            block_volume: None,
+            mount_volume: None,
+            scope: None,
+            sharing: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    #[serde(rename = "Addr", skip_serializing_if = "Option::is_none")]
+    pub addr: Option<String>,
+    #[serde(rename = "PrefixLength", skip_serializing_if = "Option::is_none")]
+    pub prefix_length: Option<i64>,
+}
+impl Address {
+    pub fn new() -> Address {
+        Address {
+            addr: None,
+            prefix_length: None,
+        }
+    }
+}
+/// AuthConfig : AuthConfig contains authorization information for connecting to a Registry
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthConfig {
+    #[serde(rename = "auth", skip_serializing_if = "Option::is_none")]
+    pub auth: Option<String>,
+    /// Email is an optional value associated with the username. This field is deprecated and will be removed in a later version of docker.
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// IdentityToken is used to authenticate the user and get an access token for the registry.
+    #[serde(rename = "identitytoken", skip_serializing_if = "Option::is_none")]
+    pub identitytoken: Option<String>,
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// RegistryToken is a bearer token to be sent to a registry
+    #[serde(rename = "registrytoken", skip_serializing_if = "Option::is_none")]
+    pub registrytoken: Option<String>,
+    #[serde(rename = "serveraddress", skip_serializing_if = "Option::is_none")]
+    pub serveraddress: Option<String>,
+    #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+impl AuthConfig {
+    /// AuthConfig contains authorization information for connecting to a Registry
+    pub fn new() -> AuthConfig {
+        AuthConfig {
+            auth: None,
+            email: None,
+            identitytoken: None,
+            password: None,
+            registrytoken: None,
+            serveraddress: None,
+            username: None,
+        }
+    }
+}
+/// AuthReport : AuthReport describes the response for authentication check
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthReport {
+    #[serde(rename = "IdentityToken", skip_serializing_if = "Option::is_none")]
+    pub identity_token: Option<String>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl AuthReport {
+    /// AuthReport describes the response for authentication check
+    pub fn new() -> AuthReport {
+        AuthReport {
+            identity_token: None,
+            status: None,
+        }
+    }
+}
+/// AuthenticateOKBody : AuthenticateOKBody authenticate o k body
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticateOKBody {
+    /// An opaque token used to authenticate a user after a successful login
+    #[serde(rename = "IdentityToken")]
+    pub identity_token: String,
+    /// The status of the authentication
+    #[serde(rename = "Status")]
+    pub status: String,
+}
+impl AuthenticateOKBody {
+    /// AuthenticateOKBody authenticate o k body
+    pub fn new(identity_token: String, status: String) -> AuthenticateOKBody {
+        AuthenticateOKBody {
+            identity_token,
+            status,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AutoUserNsOptions {
+    /// AdditionalGIDMappings specified additional GID mappings to include in the generated user namespace.
+    #[serde(rename = "AdditionalGIDMappings", skip_serializing_if = "Option::is_none")]
+    pub additional_gid_mappings: Option<Vec<IDMap>>,
+    /// AdditionalUIDMappings specified additional UID mappings to include in the generated user namespace.
+    #[serde(rename = "AdditionalUIDMappings", skip_serializing_if = "Option::is_none")]
+    pub additional_uid_mappings: Option<Vec<IDMap>>,
+    /// GroupFile to use if the container uses a volume.
+    #[serde(rename = "GroupFile", skip_serializing_if = "Option::is_none")]
+    pub group_file: Option<String>,
+    /// InitialSize defines the minimum size for the user namespace. The created user namespace will have at least this size.
+    #[serde(rename = "InitialSize", skip_serializing_if = "Option::is_none")]
+    pub initial_size: Option<u32>,
+    /// PasswdFile to use if the container uses a volume.
+    #[serde(rename = "PasswdFile", skip_serializing_if = "Option::is_none")]
+    pub passwd_file: Option<String>,
+    /// Size defines the size for the user namespace.  If it is set to a value bigger than 0, the user namespace will have exactly this size. If it is not set, some heuristics will be used to find its size.
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+impl AutoUserNsOptions {
+    pub fn new() -> AutoUserNsOptions {
+        AutoUserNsOptions {
+            additional_gid_mappings: None,
+            additional_uid_mappings: None,
+            group_file: None,
+            initial_size: None,
+            passwd_file: None,
+            size: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BindOptions {
+    #[serde(rename = "CreateMountpoint", skip_serializing_if = "Option::is_none")]
+    pub create_mountpoint: Option<bool>,
+    #[serde(rename = "NonRecursive", skip_serializing_if = "Option::is_none")]
+    pub non_recursive: Option<bool>,
+    #[serde(rename = "Propagation", skip_serializing_if = "Option::is_none")]
+    pub propagation: Option<String>,
+    /// ReadOnlyForceRecursive raises an error if the mount cannot be made recursively read-only.
+    #[serde(rename = "ReadOnlyForceRecursive", skip_serializing_if = "Option::is_none")]
+    pub read_only_force_recursive: Option<bool>,
+    /// ReadOnlyNonRecursive makes the mount non-recursively read-only, but still leaves the mount recursive (unless NonRecursive is set to true in conjunction).
+    #[serde(rename = "ReadOnlyNonRecursive", skip_serializing_if = "Option::is_none")]
+    pub read_only_non_recursive: Option<bool>,
+}
+impl BindOptions {
+    pub fn new() -> BindOptions {
+        BindOptions {
+            create_mountpoint: None,
+            non_recursive: None,
+            propagation: None,
+            read_only_force_recursive: None,
+            read_only_non_recursive: None,
+        }
+    }
+}
+/// CapacityRange : CapacityRange describes the minimum and maximum capacity a volume should be created with
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CapacityRange {
+    /// LimitBytes specifies that a volume must not be bigger than this. The value of 0 indicates an unspecified maximum
+    #[serde(rename = "LimitBytes", skip_serializing_if = "Option::is_none")]
+    pub limit_bytes: Option<i64>,
+    /// RequiredBytes specifies that a volume must be at least this big. The value of 0 indicates an unspecified minimum.
+    #[serde(rename = "RequiredBytes", skip_serializing_if = "Option::is_none")]
+    pub required_bytes: Option<i64>,
+}
+impl CapacityRange {
+    /// CapacityRange describes the minimum and maximum capacity a volume should be created with
+    pub fn new() -> CapacityRange {
+        CapacityRange {
+            limit_bytes: None,
+            required_bytes: None,
+        }
+    }
+}
+/// ClusterVolume : ClusterVolume contains options and information specific to, and only present on, Swarm CSI cluster volumes.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClusterVolume {
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// ID is the Swarm ID of the volume. Because cluster volumes are Swarm objects, they have an ID, unlike non-cluster volumes, which only have a Name. This ID can be used to refer to the cluster volume.
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<Box<Info>>,
+    /// PublishStatus contains the status of the volume as it pertains to its publishing on Nodes.
+    #[serde(rename = "PublishStatus", skip_serializing_if = "Option::is_none")]
+    pub publish_status: Option<Vec<PublishStatus>>,
+    #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<Box<ClusterVolumeSpec>>,
+    #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<Box<Version>>,
+}
+impl ClusterVolume {
+    /// ClusterVolume contains options and information specific to, and only present on, Swarm CSI cluster volumes.
+    pub fn new() -> ClusterVolume {
+        ClusterVolume {
+            created_at: None,
+            id: None,
+            info: None,
+            publish_status: None,
+            spec: None,
+            updated_at: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClusterVolumeSpec {
+    #[serde(rename = "AccessMode", skip_serializing_if = "Option::is_none")]
+    pub access_mode: Option<Box<AccessMode>>,
+    #[serde(
+        rename = "AccessibilityRequirements",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub accessibility_requirements: Option<Box<TopologyRequirement>>,
+    #[serde(rename = "Availability", skip_serializing_if = "Option::is_none")]
+    pub availability: Option<String>,
+    #[serde(rename = "CapacityRange", skip_serializing_if = "Option::is_none")]
+    pub capacity_range: Option<Box<CapacityRange>>,
+    /// Group defines the volume group of this volume. Volumes belonging to the same group can be referred to by group name when creating Services. Referring to a volume by group instructs swarm to treat volumes in that group interchangeably for the purpose of scheduling. Volumes with an empty string for a group technically all belong to the same, emptystring group.
+    #[serde(rename = "Group", skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Secrets defines Swarm Secrets that are passed to the CSI storage plugin when operating on this volume.
+    #[serde(rename = "Secrets", skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<Secret>>,
+}
+impl ClusterVolumeSpec {
+    pub fn new() -> ClusterVolumeSpec {
+        ClusterVolumeSpec {
+            access_mode: None,
+            accessibility_requirements: None,
+            availability: None,
+            capacity_range: None,
+            group: None,
+            secrets: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComponentVersion {
+    #[serde(rename = "Details", skip_serializing_if = "Option::is_none")]
+    pub details: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl ComponentVersion {
+    pub fn new() -> ComponentVersion {
+        ComponentVersion {
+            details: None,
+            name: None,
+            version: None,
+        }
+    }
+}
+/// Config : It should hold only portable information about the container. Here, \"portable\" means \"independent from the host we are running on\". Non-portable information *should* appear in HostConfig. All fields added to this struct must be marked `omitempty` to keep getting predictable hashes from the old `v1Compatibility` configuration.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(rename = "ArgsEscaped", skip_serializing_if = "Option::is_none")]
+    pub args_escaped: Option<bool>,
+    #[serde(rename = "AttachStderr", skip_serializing_if = "Option::is_none")]
+    pub attach_stderr: Option<bool>,
+    #[serde(rename = "AttachStdin", skip_serializing_if = "Option::is_none")]
+    pub attach_stdin: Option<bool>,
+    #[serde(rename = "AttachStdout", skip_serializing_if = "Option::is_none")]
+    pub attach_stdout: Option<bool>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
+    #[serde(rename = "Domainname", skip_serializing_if = "Option::is_none")]
+    pub domainname: Option<String>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    /// PortSet is a collection of structs indexed by Port
+    #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none")]
+    pub exposed_ports: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
+    pub healthcheck: Option<Box<HealthcheckConfig>>,
+    #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Mac Address of the container.  Deprecated: this field is deprecated since API v1.44. Use EndpointSettings.MacAddress instead.
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "NetworkDisabled", skip_serializing_if = "Option::is_none")]
+    pub network_disabled: Option<bool>,
+    #[serde(rename = "OnBuild", skip_serializing_if = "Option::is_none")]
+    pub on_build: Option<Vec<String>>,
+    #[serde(rename = "OpenStdin", skip_serializing_if = "Option::is_none")]
+    pub open_stdin: Option<bool>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Shell", skip_serializing_if = "Option::is_none")]
+    pub shell: Option<Vec<String>>,
+    #[serde(rename = "StdinOnce", skip_serializing_if = "Option::is_none")]
+    pub stdin_once: Option<bool>,
+    #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<String>,
+    #[serde(rename = "StopTimeout", skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<i64>,
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+impl Config {
+    /// It should hold only portable information about the container. Here, \"portable\" means \"independent from the host we are running on\". Non-portable information *should* appear in HostConfig. All fields added to this struct must be marked `omitempty` to keep getting predictable hashes from the old `v1Compatibility` configuration.
+    pub fn new() -> Config {
+        Config {
+            args_escaped: None,
+            attach_stderr: None,
+            attach_stdin: None,
+            attach_stdout: None,
+            cmd: None,
+            domainname: None,
+            entrypoint: None,
+            env: None,
+            exposed_ports: None,
+            healthcheck: None,
+            hostname: None,
+            image: None,
+            labels: None,
+            mac_address: None,
+            network_disabled: None,
+            on_build: None,
+            open_stdin: None,
+            shell: None,
+            stdin_once: None,
+            stop_signal: None,
+            stop_timeout: None,
+            tty: None,
+            user: None,
+            volumes: None,
+            working_dir: None,
+        }
+    }
+}
+/// ConfigReference : ConfigReference specifies the source which provides a network's configuration
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigReference {
+    #[serde(rename = "Network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+impl ConfigReference {
+    /// ConfigReference specifies the source which provides a network's configuration
+    pub fn new() -> ConfigReference {
+        ConfigReference { network: None }
+    }
+}
+/// ConmonInfo : ConmonInfo describes the conmon executable being used
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConmonInfo {
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl ConmonInfo {
+    /// ConmonInfo describes the conmon executable being used
+    pub fn new() -> ConmonInfo {
+        ConmonInfo {
+            package: None,
+            path: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<Config>>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    #[serde(
+        rename = "DefaultReadOnlyNonRecursive",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_read_only_non_recursive: Option<bool>,
+    #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
+    pub host_config: Option<Box<HostConfig>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "ImageID", skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<MountPoint>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(rename = "NetworkSettings", skip_serializing_if = "Option::is_none")]
+    pub network_settings: Option<Box<SummaryNetworkSettings>>,
+    #[serde(rename = "NetworkingConfig", skip_serializing_if = "Option::is_none")]
+    pub networking_config: Option<Box<NetworkingConfig>>,
+    #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Box<Platform>>,
+    #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<Port>>,
+    #[serde(rename = "SizeRootFs", skip_serializing_if = "Option::is_none")]
+    pub size_root_fs: Option<i64>,
+    #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none")]
+    pub size_rw: Option<i64>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl Container {
+    pub fn new() -> Container {
+        Container {
+            command: None,
+            config: None,
+            created: None,
+            default_read_only_non_recursive: None,
+            host_config: None,
+            id: None,
+            image: None,
+            image_id: None,
+            labels: None,
+            mounts: None,
+            name: None,
+            names: None,
+            network_settings: None,
+            networking_config: None,
+            platform: None,
+            ports: None,
+            size_root_fs: None,
+            size_rw: None,
+            state: None,
+            status: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerBasicConfig {
+    /// Annotations are key-value options passed into the container runtime that can be used to trigger special behavior. Optional.
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Command is the container's command. If not given and Image is specified, this will be populated by the image's configuration. Optional.
+    #[serde(rename = "command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// ConmonPidFile is a path at which a PID file for Conmon will be placed. If not given, a default location will be used. Optional.
+    #[serde(rename = "conmon_pid_file", skip_serializing_if = "Option::is_none")]
+    pub conmon_pid_file: Option<String>,
+    /// ContainerCreateCommand is the command that was used to create this container. This will be shown in the output of Inspect() on the container, and may also be used by some tools that wish to recreate the container (e.g. `podman generate systemd --new`). Optional.
+    #[serde(rename = "containerCreateCommand", skip_serializing_if = "Option::is_none")]
+    pub container_create_command: Option<Vec<String>>,
+    /// DependencyContainers is an array of containers this container depends on. Dependency containers must be started before this container. Dependencies can be specified by name or full/partial ID. Optional.
+    #[serde(rename = "dependencyContainers", skip_serializing_if = "Option::is_none")]
+    pub dependency_containers: Option<Vec<String>>,
+    /// Entrypoint is the container's entrypoint. If not given and Image is specified, this will be populated by the image's configuration. Optional.
+    #[serde(rename = "entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    /// Env is a set of environment variables that will be set in the container. Optional.
+    #[serde(rename = "env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<std::collections::HashMap<String, String>>,
+    /// EnvHost indicates that the host environment should be added to container Optional.
+    #[serde(rename = "env_host", skip_serializing_if = "Option::is_none")]
+    pub env_host: Option<bool>,
+    /// EnvMerge takes the specified environment variables from image and preprocess them before injecting them into the container. Optional.
+    #[serde(rename = "envmerge", skip_serializing_if = "Option::is_none")]
+    pub envmerge: Option<Vec<String>>,
+    /// GroupEntry specifies an arbitrary string to append to the container's /etc/group file. Optional.
+    #[serde(rename = "group_entry", skip_serializing_if = "Option::is_none")]
+    pub group_entry: Option<String>,
+    /// Hostname is the container's hostname. If not set, the hostname will not be modified (if UtsNS is not private) or will be set to the container ID (if UtsNS is private). Conflicts with UtsNS if UtsNS is not set to private. Optional.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// HostUsers is a list of host usernames or UIDs to add to the container etc/passwd file
+    #[serde(rename = "hostusers", skip_serializing_if = "Option::is_none")]
+    pub hostusers: Option<Vec<String>>,
+    /// EnvHTTPProxy indicates that the http host proxy environment variables should be added to container Optional.
+    #[serde(rename = "httpproxy", skip_serializing_if = "Option::is_none")]
+    pub httpproxy: Option<bool>,
+    /// InitContainerType describes if this container is an init container and if so, what type: always or once. Optional.
+    #[serde(rename = "init_container_type", skip_serializing_if = "Option::is_none")]
+    pub init_container_type: Option<String>,
+    /// Labels are key-value pairs that are used to add metadata to containers. Optional.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "log_configuration", skip_serializing_if = "Option::is_none")]
+    pub log_configuration: Option<Box<LogConfigLibpod>>,
+    /// Passwd is a container run option that determines if we are validating users/groups before running the container
+    #[serde(rename = "manage_password", skip_serializing_if = "Option::is_none")]
+    pub manage_password: Option<bool>,
+    /// Name is the name the container will be given. If no name is provided, one will be randomly generated. Optional.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// OCIRuntime is the name of the OCI runtime that will be used to create the container. If not specified, the default will be used. Optional.
+    #[serde(rename = "oci_runtime", skip_serializing_if = "Option::is_none")]
+    pub oci_runtime: Option<String>,
+    /// PasswdEntry specifies an arbitrary string to append to the container's /etc/passwd file. Optional.
+    #[serde(rename = "passwd_entry", skip_serializing_if = "Option::is_none")]
+    pub passwd_entry: Option<String>,
+    #[serde(rename = "personality", skip_serializing_if = "Option::is_none")]
+    pub personality: Option<Box<LinuxPersonality>>,
+    #[serde(rename = "pidns", skip_serializing_if = "Option::is_none")]
+    pub pidns: Option<Box<Namespace>>,
+    /// Pod is the ID of the pod the container will join. Optional.
+    #[serde(rename = "pod", skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    /// Remove indicates if the container should be removed once it has been started and exits. Optional.
+    #[serde(rename = "remove", skip_serializing_if = "Option::is_none")]
+    pub remove: Option<bool>,
+    /// RestartPolicy is the container's restart policy - an action which will be taken when the container exits. If not given, the default policy, which does nothing, will be used. Optional.
+    #[serde(rename = "restart_policy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// RestartRetries is the number of attempts that will be made to restart the container. Only available when RestartPolicy is set to \"on-failure\". Optional.
+    #[serde(rename = "restart_tries", skip_serializing_if = "Option::is_none")]
+    pub restart_tries: Option<u64>,
+    /// Determine how to handle the NOTIFY_SOCKET - do we participate or pass it through \"container\" - let the OCI runtime deal with it, advertise conmon's MAINPID \"conmon-only\" - advertise conmon's MAINPID, send READY when started, don't pass to OCI \"ignore\" - unset NOTIFY_SOCKET Optional.
+    #[serde(rename = "sdnotifyMode", skip_serializing_if = "Option::is_none")]
+    pub sdnotify_mode: Option<String>,
+    /// EnvSecrets are secrets that will be set as environment variables Optional.
+    #[serde(rename = "secret_env", skip_serializing_if = "Option::is_none")]
+    pub secret_env: Option<std::collections::HashMap<String, String>>,
+    /// Stdin is whether the container will keep its STDIN open. Optional.
+    #[serde(rename = "stdin", skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<bool>,
+    /// It implements the os.Signal interface.
+    #[serde(rename = "stop_signal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<i64>,
+    /// StopTimeout is a timeout between the container's stop signal being sent and SIGKILL being sent. If not provided, the default will be used. If 0 is used, stop signal will not be sent, and SIGKILL will be sent instead. Optional.
+    #[serde(rename = "stop_timeout", skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<u64>,
+    /// Sysctl sets kernel parameters for the container
+    #[serde(rename = "sysctl", skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<std::collections::HashMap<String, String>>,
+    /// Systemd is whether the container will be started in systemd mode. Valid options are \"true\", \"false\", and \"always\". \"true\" enables this mode only if the binary run in the container is sbin/init or systemd. \"always\" unconditionally enables systemd mode. \"false\" unconditionally disables systemd mode. If enabled, mounts and stop signal will be modified. If set to \"always\" or set to \"true\" and conditionally triggered, conflicts with StopSignal. If not specified, \"false\" will be assumed. Optional.
+    #[serde(rename = "systemd", skip_serializing_if = "Option::is_none")]
+    pub systemd: Option<String>,
+    /// Terminal is whether the container will create a PTY. Optional.
+    #[serde(rename = "terminal", skip_serializing_if = "Option::is_none")]
+    pub terminal: Option<bool>,
+    /// Timeout is a maximum time in seconds the container will run before main process is sent SIGKILL. If 0 is used, signal will not be sent. Container can run indefinitely if they do not stop after the default termination signal. Optional.
+    #[serde(rename = "timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Timezone is the timezone inside the container. Local means it has the same timezone as the host machine Optional.
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// UnsetEnv unsets the specified default environment variables from the image or from buildin or containers.conf Optional.
+    #[serde(rename = "unsetenv", skip_serializing_if = "Option::is_none")]
+    pub unsetenv: Option<Vec<String>>,
+    /// UnsetEnvAll unsetall default environment variables from the image or from buildin or containers.conf UnsetEnvAll unsets all default environment variables from the image or from buildin Optional.
+    #[serde(rename = "unsetenvall", skip_serializing_if = "Option::is_none")]
+    pub unsetenvall: Option<bool>,
+    #[serde(rename = "utsns", skip_serializing_if = "Option::is_none")]
+    pub utsns: Option<Box<Namespace>>,
+}
+impl ContainerBasicConfig {
+    pub fn new() -> ContainerBasicConfig {
+        ContainerBasicConfig {
+            annotations: None,
+            command: None,
+            conmon_pid_file: None,
+            container_create_command: None,
+            dependency_containers: None,
+            entrypoint: None,
+            env: None,
+            env_host: None,
+            envmerge: None,
+            group_entry: None,
+            hostname: None,
+            hostusers: None,
+            httpproxy: None,
+            init_container_type: None,
+            labels: None,
+            log_configuration: None,
+            manage_password: None,
+            name: None,
+            oci_runtime: None,
+            passwd_entry: None,
+            personality: None,
+            pidns: None,
+            pod: None,
+            remove: None,
+            restart_policy: None,
+            restart_tries: None,
+            sdnotify_mode: None,
+            secret_env: None,
+            stdin: None,
+            stop_signal: None,
+            stop_timeout: None,
+            sysctl: None,
+            systemd: None,
+            terminal: None,
+            timeout: None,
+            timezone: None,
+            unsetenv: None,
+            unsetenvall: None,
+            utsns: None,
+        }
+    }
+}
+/// ContainerCgroupConfig : ContainerCgroupConfig contains configuration information about a container's cgroups.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerCgroupConfig {
+    /// CgroupParent is the container's Cgroup parent. If not set, the default for the current cgroup driver will be used. Optional.
+    #[serde(rename = "cgroup_parent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    #[serde(rename = "cgroupns", skip_serializing_if = "Option::is_none")]
+    pub cgroupns: Option<Box<Namespace>>,
+    /// CgroupsMode sets a policy for how cgroups will be created for the container, including the ability to disable creation entirely. Optional.
+    #[serde(rename = "cgroups_mode", skip_serializing_if = "Option::is_none")]
+    pub cgroups_mode: Option<String>,
+}
+impl ContainerCgroupConfig {
+    /// ContainerCgroupConfig contains configuration information about a container's cgroups.
+    pub fn new() -> ContainerCgroupConfig {
+        ContainerCgroupConfig {
+            cgroup_parent: None,
+            cgroupns: None,
+            cgroups_mode: None,
+        }
+    }
+}
+/// ContainerCreateResponse : ContainerCreateResponse is the response struct for creating a container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerCreateResponse {
+    /// ID of the container created
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// Warnings during container creation
+    #[serde(rename = "Warnings")]
+    pub warnings: Vec<String>,
+}
+impl ContainerCreateResponse {
+    /// ContainerCreateResponse is the response struct for creating a container
+    pub fn new(id: String, warnings: Vec<String>) -> ContainerCreateResponse {
+        ContainerCreateResponse {
+            id,
+            warnings,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerExecLibpodRequest {
+    /// Attach to stderr of the exec command
+    #[serde(rename = "AttachStderr", skip_serializing_if = "Option::is_none")]
+    pub attach_stderr: Option<bool>,
+    /// Attach to stdin of the exec command
+    #[serde(rename = "AttachStdin", skip_serializing_if = "Option::is_none")]
+    pub attach_stdin: Option<bool>,
+    /// Attach to stdout of the exec command
+    #[serde(rename = "AttachStdout", skip_serializing_if = "Option::is_none")]
+    pub attach_stdout: Option<bool>,
+    /// Command to run, as a string or array of strings.
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
+    /// \"Override the key sequence for detaching a container. Format is a single character [a-Z] or ctrl-<value> where <value> is one of: a-z, @, ^, [, , or _.\"
+    #[serde(rename = "DetachKeys", skip_serializing_if = "Option::is_none")]
+    pub detach_keys: Option<String>,
+    /// A list of environment variables in the form [\"VAR=value\", ...]
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    /// Runs the exec process with extended privileges
+    #[serde(rename = "Privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// Allocate a pseudo-TTY
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    /// \"The user, and optionally, group to run the exec process inside the container. Format is one of: user, user:group, uid, or uid:gid.\"
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// The working directory for the exec process inside the container.
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+impl ContainerExecLibpodRequest {
+    pub fn new() -> ContainerExecLibpodRequest {
+        ContainerExecLibpodRequest {
+            attach_stderr: None,
+            attach_stdin: None,
+            attach_stdout: None,
+            cmd: None,
+            detach_keys: None,
+            env: None,
+            privileged: None,
+            tty: None,
+            user: None,
+            working_dir: None,
+        }
+    }
+}
+/// ContainerHealthCheckConfig : ContainerHealthCheckConfig describes a container healthcheck with attributes like command, retries, interval, start period, and timeout.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerHealthCheckConfig {
+    /// HealthCheckOnFailureAction defines how Podman reacts when a container's health status turns unhealthy.
+    #[serde(
+        rename = "health_check_on_failure_action",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub health_check_on_failure_action: Option<i64>,
+    #[serde(rename = "healthconfig", skip_serializing_if = "Option::is_none")]
+    pub healthconfig: Option<Box<Schema2HealthConfig>>,
+    #[serde(rename = "startupHealthConfig", skip_serializing_if = "Option::is_none")]
+    pub startup_health_config: Option<Box<StartupHealthCheck>>,
+}
+impl ContainerHealthCheckConfig {
+    /// ContainerHealthCheckConfig describes a container healthcheck with attributes like command, retries, interval, start period, and timeout.
+    pub fn new() -> ContainerHealthCheckConfig {
+        ContainerHealthCheckConfig {
+            health_check_on_failure_action: None,
+            healthconfig: None,
+            startup_health_config: None,
+        }
+    }
+}
+/// ContainerJSON : ContainerJSON is newly used struct along with MountPoint
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerJSON {
+    #[serde(rename = "AppArmorProfile", skip_serializing_if = "Option::is_none")]
+    pub app_armor_profile: Option<String>,
+    #[serde(rename = "Args", skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<Config>>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "ExecIDs", skip_serializing_if = "Option::is_none")]
+    pub exec_ids: Option<Vec<String>>,
+    #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
+    pub graph_driver: Option<Box<GraphDriverData>>,
+    #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
+    pub host_config: Option<Box<HostConfig>>,
+    #[serde(rename = "HostnamePath", skip_serializing_if = "Option::is_none")]
+    pub hostname_path: Option<String>,
+    #[serde(rename = "HostsPath", skip_serializing_if = "Option::is_none")]
+    pub hosts_path: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "LogPath", skip_serializing_if = "Option::is_none")]
+    pub log_path: Option<String>,
+    #[serde(rename = "MountLabel", skip_serializing_if = "Option::is_none")]
+    pub mount_label: Option<String>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<MountPoint>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "NetworkSettings", skip_serializing_if = "Option::is_none")]
+    pub network_settings: Option<Box<NetworkSettings>>,
+    #[serde(rename = "Node", skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ContainerNode>>,
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    #[serde(rename = "ProcessLabel", skip_serializing_if = "Option::is_none")]
+    pub process_label: Option<String>,
+    #[serde(rename = "ResolvConfPath", skip_serializing_if = "Option::is_none")]
+    pub resolv_conf_path: Option<String>,
+    #[serde(rename = "RestartCount", skip_serializing_if = "Option::is_none")]
+    pub restart_count: Option<i64>,
+    #[serde(rename = "SizeRootFs", skip_serializing_if = "Option::is_none")]
+    pub size_root_fs: Option<i64>,
+    #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none")]
+    pub size_rw: Option<i64>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<ContainerState>>,
+}
+impl ContainerJSON {
+    /// ContainerJSON is newly used struct along with MountPoint
+    pub fn new() -> ContainerJSON {
+        ContainerJSON {
+            app_armor_profile: None,
+            args: None,
+            config: None,
+            created: None,
+            driver: None,
+            exec_ids: None,
+            graph_driver: None,
+            host_config: None,
+            hostname_path: None,
+            hosts_path: None,
+            id: None,
+            image: None,
+            log_path: None,
+            mount_label: None,
+            mounts: None,
+            name: None,
+            network_settings: None,
+            node: None,
+            path: None,
+            platform: None,
+            process_label: None,
+            resolv_conf_path: None,
+            restart_count: None,
+            size_root_fs: None,
+            size_rw: None,
+            state: None,
+        }
+    }
+}
+/// ContainerNetworkConfig : ContainerNetworkConfig contains information on a container's network configuration.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerNetworkConfig {
+    /// Map of networks names or ids that the container should join. You can request additional settings for each network, you can set network aliases, static ips, static mac address  and the network interface name for this container on the specific network. If the map is empty and the bridge network mode is set the container will be joined to the default network. Optional.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, PerNetworkOptions>>,
+    /// BaseHostsFile is the path to a hosts file, the entries from this file are added to the containers hosts file. As special value \"image\" is allowed which uses the /etc/hosts file from within the image and \"none\" which uses no base file at all. If it is empty we should default to the base_hosts_file configuration in containers.conf. Optional.
+    #[serde(rename = "base_hosts_file", skip_serializing_if = "Option::is_none")]
+    pub base_hosts_file: Option<String>,
+    /// CNINetworks is a list of CNI networks to join the container to. If this list is empty, the default CNI network will be joined instead. If at least one entry is present, we will not join the default network (unless it is part of this list). Only available if NetNS is set to bridge. Optional. Deprecated: as of podman 4.0 use \"Networks\" instead.
+    #[serde(rename = "cni_networks", skip_serializing_if = "Option::is_none")]
+    pub cni_networks: Option<Vec<String>>,
+    /// DNSOptions is a set of DNS options that will be used in the container's resolv.conf, replacing the host's DNS options which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_option", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    /// DNSSearch is a set of DNS search domains that will be used in the container's resolv.conf, replacing the host's DNS search domains which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_search", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// DNSServers is a set of DNS servers that will be used in the container's resolv.conf, replacing the host's DNS Servers which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_server", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    /// Expose is a number of ports that will be forwarded to the container if PublishExposedPorts is set. Expose is a map of uint16 (port number) to a string representing protocol i.e map[uint16]string. Allowed protocols are \"tcp\", \"udp\", and \"sctp\", or some combination of the three separated by commas. If protocol is set to \"\" we will assume TCP. Only available if NetNS is set to Bridge or Slirp, and PublishExposedPorts is set. Optional.
+    #[serde(rename = "expose", skip_serializing_if = "Option::is_none")]
+    pub expose: Option<serde_json::Value>,
+    /// HostAdd is a set of hosts which will be added to the container's etc/hosts file. Conflicts with UseImageHosts. Optional.
+    #[serde(rename = "hostadd", skip_serializing_if = "Option::is_none")]
+    pub hostadd: Option<Vec<String>>,
+    #[serde(rename = "netns", skip_serializing_if = "Option::is_none")]
+    pub netns: Option<Box<Namespace>>,
+    /// NetworkOptions are additional options for each network Optional.
+    #[serde(rename = "network_options", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    /// PortBindings is a set of ports to map into the container. Only available if NetNS is set to bridge, slirp, or pasta. Optional.
+    #[serde(rename = "portmappings", skip_serializing_if = "Option::is_none")]
+    pub portmappings: Option<Vec<PortMapping>>,
+    /// PublishExposedPorts will publish ports specified in the image to random unused ports (guaranteed to be above 1024) on the host. This is based on ports set in Expose below, and any ports specified by the Image (if one is given). Only available if NetNS is set to Bridge or Slirp. Optional.
+    #[serde(rename = "publish_image_ports", skip_serializing_if = "Option::is_none")]
+    pub publish_image_ports: Option<bool>,
+    /// UseImageHosts indicates that /etc/hosts should not be managed by Podman, and instead sourced from the image. Conflicts with HostAdd. Optional.
+    #[serde(rename = "use_image_hosts", skip_serializing_if = "Option::is_none")]
+    pub use_image_hosts: Option<bool>,
+    /// UseImageResolvConf indicates that resolv.conf should not be managed by Podman, but instead sourced from the image. Conflicts with DNSServer, DNSSearch, DNSOption. Optional.
+    #[serde(rename = "use_image_resolve_conf", skip_serializing_if = "Option::is_none")]
+    pub use_image_resolve_conf: Option<bool>,
+}
+impl ContainerNetworkConfig {
+    /// ContainerNetworkConfig contains information on a container's network configuration.
+    pub fn new() -> ContainerNetworkConfig {
+        ContainerNetworkConfig {
+            networks: None,
+            base_hosts_file: None,
+            cni_networks: None,
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            expose: None,
+            hostadd: None,
+            netns: None,
+            network_options: None,
+            portmappings: None,
+            publish_image_ports: None,
+            use_image_hosts: None,
+            use_image_resolve_conf: None,
+        }
+    }
+}
+/// ContainerNetworkStats : Statistics for an individual container network interface
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerNetworkStats {
+    #[serde(rename = "RxBytes", skip_serializing_if = "Option::is_none")]
+    pub rx_bytes: Option<u64>,
+    #[serde(rename = "RxDropped", skip_serializing_if = "Option::is_none")]
+    pub rx_dropped: Option<u64>,
+    #[serde(rename = "RxErrors", skip_serializing_if = "Option::is_none")]
+    pub rx_errors: Option<u64>,
+    #[serde(rename = "RxPackets", skip_serializing_if = "Option::is_none")]
+    pub rx_packets: Option<u64>,
+    #[serde(rename = "TxBytes", skip_serializing_if = "Option::is_none")]
+    pub tx_bytes: Option<u64>,
+    #[serde(rename = "TxDropped", skip_serializing_if = "Option::is_none")]
+    pub tx_dropped: Option<u64>,
+    #[serde(rename = "TxErrors", skip_serializing_if = "Option::is_none")]
+    pub tx_errors: Option<u64>,
+    #[serde(rename = "TxPackets", skip_serializing_if = "Option::is_none")]
+    pub tx_packets: Option<u64>,
+}
+impl ContainerNetworkStats {
+    /// Statistics for an individual container network interface
+    pub fn new() -> ContainerNetworkStats {
+        ContainerNetworkStats {
+            rx_bytes: None,
+            rx_dropped: None,
+            rx_errors: None,
+            rx_packets: None,
+            tx_bytes: None,
+            tx_dropped: None,
+            tx_errors: None,
+            tx_packets: None,
+        }
+    }
+}
+/// ContainerNode : ContainerNode stores information about the node that a container is running on.  It's only used by the Docker Swarm standalone API
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerNode {
+    #[serde(rename = "Addr", skip_serializing_if = "Option::is_none")]
+    pub addr: Option<String>,
+    #[serde(rename = "Cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<i64>,
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl ContainerNode {
+    /// ContainerNode stores information about the node that a container is running on.  It's only used by the Docker Swarm standalone API
+    pub fn new() -> ContainerNode {
+        ContainerNode {
+            addr: None,
+            cpus: None,
+            id: None,
+            ip: None,
+            labels: None,
+            memory: None,
+            name: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerResourceConfig {
+    #[serde(rename = "intelRdt", skip_serializing_if = "Option::is_none")]
+    pub intel_rdt: Option<Box<LinuxIntelRdt>>,
+    /// OOMScoreAdj adjusts the score used by the OOM killer to determine processes to kill for the container's process. Optional.
+    #[serde(rename = "oom_score_adj", skip_serializing_if = "Option::is_none")]
+    pub oom_score_adj: Option<i64>,
+    /// Rlimits are POSIX rlimits to apply to the container. Optional.
+    #[serde(rename = "r_limits", skip_serializing_if = "Option::is_none")]
+    pub r_limits: Option<Vec<POSIXRlimit>>,
+    #[serde(rename = "resource_limits", skip_serializing_if = "Option::is_none")]
+    pub resource_limits: Option<Box<LinuxResources>>,
+    /// IO read rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleReadBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO read rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleReadIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_iops_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO write rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleWriteBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO write rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleWriteIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_iops_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// CgroupConf are key-value options passed into the container runtime that are used to configure cgroup v2. Optional.
+    #[serde(rename = "unified", skip_serializing_if = "Option::is_none")]
+    pub unified: Option<std::collections::HashMap<String, String>>,
+    /// Weight per cgroup per device, can override BlkioWeight
+    #[serde(rename = "weightDevice", skip_serializing_if = "Option::is_none")]
+    pub weight_device: Option<std::collections::HashMap<String, LinuxWeightDevice>>,
+}
+impl ContainerResourceConfig {
+    pub fn new() -> ContainerResourceConfig {
+        ContainerResourceConfig {
+            intel_rdt: None,
+            oom_score_adj: None,
+            r_limits: None,
+            resource_limits: None,
+            throttle_read_bps_device: None,
+            throttle_read_iops_device: None,
+            throttle_write_bps_device: None,
+            throttle_write_iops_device: None,
+            unified: None,
+            weight_device: None,
+        }
+    }
+}
+/// ContainerSecurityConfig : ContainerSecurityConfig is a container's security features, including SELinux, Apparmor, and Seccomp.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerSecurityConfig {
+    /// ApparmorProfile is the name of the Apparmor profile the container will use. Optional.
+    #[serde(rename = "apparmor_profile", skip_serializing_if = "Option::is_none")]
+    pub apparmor_profile: Option<String>,
+    /// CapAdd are capabilities which will be added to the container. Conflicts with Privileged. Optional.
+    #[serde(rename = "cap_add", skip_serializing_if = "Option::is_none")]
+    pub cap_add: Option<Vec<String>>,
+    /// CapDrop are capabilities which will be removed from the container. Conflicts with Privileged. Optional.
+    #[serde(rename = "cap_drop", skip_serializing_if = "Option::is_none")]
+    pub cap_drop: Option<Vec<String>>,
+    /// Groups are a list of supplemental groups the container's user will be granted access to. Optional.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    #[serde(rename = "idmappings", skip_serializing_if = "Option::is_none")]
+    pub idmappings: Option<Box<IDMappingOptions>>,
+    /// LabelNested indicates whether or not the container is allowed to run fully nested containers including SELinux labelling. Optional.
+    #[serde(rename = "label_nested", skip_serializing_if = "Option::is_none")]
+    pub label_nested: Option<bool>,
+    /// Mask is the path we want to mask in the container. This masks the paths given in addition to the default list. Optional
+    #[serde(rename = "mask", skip_serializing_if = "Option::is_none")]
+    pub mask: Option<Vec<String>>,
+    /// NoNewPrivileges is whether the container will set the no new privileges flag on create, which disables gaining additional privileges (e.g. via setuid) in the container. Optional.
+    #[serde(rename = "no_new_privileges", skip_serializing_if = "Option::is_none")]
+    pub no_new_privileges: Option<bool>,
+    /// Privileged is whether the container is privileged. Privileged does the following: Adds all devices on the system to the container. Adds all capabilities to the container. Disables Seccomp, SELinux, and Apparmor confinement. (Though SELinux can be manually re-enabled). TODO: this conflicts with things. TODO: this does more. Optional.
+    #[serde(rename = "privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// ProcOpts are the options used for the proc mount.
+    #[serde(rename = "procfs_opts", skip_serializing_if = "Option::is_none")]
+    pub procfs_opts: Option<Vec<String>>,
+    /// ReadOnlyFilesystem indicates that everything will be mounted as read-only. Optional.
+    #[serde(rename = "read_only_filesystem", skip_serializing_if = "Option::is_none")]
+    pub read_only_filesystem: Option<bool>,
+    /// ReadWriteTmpfs indicates that when running with a ReadOnlyFilesystem mount temporary file systems. Optional.
+    #[serde(rename = "read_write_tmpfs", skip_serializing_if = "Option::is_none")]
+    pub read_write_tmpfs: Option<bool>,
+    /// SeccompPolicy determines which seccomp profile gets applied the container. valid values: empty,default,image
+    #[serde(rename = "seccomp_policy", skip_serializing_if = "Option::is_none")]
+    pub seccomp_policy: Option<String>,
+    /// SeccompProfilePath is the path to a JSON file containing the container's Seccomp profile. If not specified, no Seccomp profile will be used. Optional.
+    #[serde(rename = "seccomp_profile_path", skip_serializing_if = "Option::is_none")]
+    pub seccomp_profile_path: Option<String>,
+    /// SelinuxProcessLabel is the process label the container will use. If SELinux is enabled and this is not specified, a label will be automatically generated if not specified. Optional.
+    #[serde(rename = "selinux_opts", skip_serializing_if = "Option::is_none")]
+    pub selinux_opts: Option<Vec<String>>,
+    /// Umask is the umask the init process of the container will be run with.
+    #[serde(rename = "umask", skip_serializing_if = "Option::is_none")]
+    pub umask: Option<String>,
+    /// Unmask a path in the container. Some paths are masked by default, preventing them from being accessed within the container; this undoes that masking. If ALL is passed, all paths will be unmasked. Optional.
+    #[serde(rename = "unmask", skip_serializing_if = "Option::is_none")]
+    pub unmask: Option<Vec<String>>,
+    /// User is the user the container will be run as. Can be given as a UID or a username; if a username, it will be resolved within the container, using the container's /etc/passwd. If unset, the container will be run as root. Optional.
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(rename = "userns", skip_serializing_if = "Option::is_none")]
+    pub userns: Option<Box<Namespace>>,
+}
+impl ContainerSecurityConfig {
+    /// ContainerSecurityConfig is a container's security features, including SELinux, Apparmor, and Seccomp.
+    pub fn new() -> ContainerSecurityConfig {
+        ContainerSecurityConfig {
+            apparmor_profile: None,
+            cap_add: None,
+            cap_drop: None,
+            groups: None,
+            idmappings: None,
+            label_nested: None,
+            mask: None,
+            no_new_privileges: None,
+            privileged: None,
+            procfs_opts: None,
+            read_only_filesystem: None,
+            read_write_tmpfs: None,
+            seccomp_policy: None,
+            seccomp_profile_path: None,
+            selinux_opts: None,
+            umask: None,
+            unmask: None,
+            user: None,
+            userns: None,
+        }
+    }
+}
+/// ContainerSize : ContainerSize holds the size of the container's root filesystem and top read-write layer.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerSize {
+    #[serde(rename = "rootFsSize", skip_serializing_if = "Option::is_none")]
+    pub root_fs_size: Option<i64>,
+    #[serde(rename = "rwSize", skip_serializing_if = "Option::is_none")]
+    pub rw_size: Option<i64>,
+}
+impl ContainerSize {
+    /// ContainerSize holds the size of the container's root filesystem and top read-write layer.
+    pub fn new() -> ContainerSize {
+        ContainerSize {
+            root_fs_size: None,
+            rw_size: None,
+        }
+    }
+}
+/// ContainerState : ContainerState stores container's running state it's part of ContainerJSONBase and will return by \"inspect\" command
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerState {
+    #[serde(rename = "Dead", skip_serializing_if = "Option::is_none")]
+    pub dead: Option<bool>,
+    #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    #[serde(rename = "FinishedAt", skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    #[serde(rename = "Health", skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<Health>>,
+    #[serde(rename = "OOMKilled", skip_serializing_if = "Option::is_none")]
+    pub oom_killed: Option<bool>,
+    #[serde(rename = "Paused", skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<i64>,
+    #[serde(rename = "Restarting", skip_serializing_if = "Option::is_none")]
+    pub restarting: Option<bool>,
+    #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
+    pub running: Option<bool>,
+    #[serde(rename = "StartedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl ContainerState {
+    /// ContainerState stores container's running state it's part of ContainerJSONBase and will return by \"inspect\" command
+    pub fn new() -> ContainerState {
+        ContainerState {
+            dead: None,
+            error: None,
+            exit_code: None,
+            finished_at: None,
+            health: None,
+            oom_killed: None,
+            paused: None,
+            pid: None,
+            restarting: None,
+            running: None,
+            started_at: None,
+            status: None,
+        }
+    }
+}
+/// ContainerStats : ContainerStats contains the statistics information for a running container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStats {
+    #[serde(rename = "AvgCPU", skip_serializing_if = "Option::is_none")]
+    pub avg_cpu: Option<f64>,
+    #[serde(rename = "BlockInput", skip_serializing_if = "Option::is_none")]
+    pub block_input: Option<u64>,
+    #[serde(rename = "BlockOutput", skip_serializing_if = "Option::is_none")]
+    pub block_output: Option<u64>,
+    #[serde(rename = "CPU", skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<f64>,
+    #[serde(rename = "CPUNano", skip_serializing_if = "Option::is_none")]
+    pub cpu_nano: Option<u64>,
+    #[serde(rename = "CPUSystemNano", skip_serializing_if = "Option::is_none")]
+    pub cpu_system_nano: Option<u64>,
+    #[serde(rename = "ContainerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    #[serde(rename = "Duration", skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+    #[serde(rename = "MemLimit", skip_serializing_if = "Option::is_none")]
+    pub mem_limit: Option<u64>,
+    #[serde(rename = "MemPerc", skip_serializing_if = "Option::is_none")]
+    pub mem_perc: Option<f64>,
+    #[serde(rename = "MemUsage", skip_serializing_if = "Option::is_none")]
+    pub mem_usage: Option<u64>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Map of interface name to network statistics for that interface.
+    #[serde(rename = "Network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<std::collections::HashMap<String, ContainerNetworkStats>>,
+    #[serde(rename = "PIDs", skip_serializing_if = "Option::is_none")]
+    pub pids: Option<u64>,
+    #[serde(rename = "PerCPU", skip_serializing_if = "Option::is_none")]
+    pub per_cpu: Option<Vec<u64>>,
+    #[serde(rename = "SystemNano", skip_serializing_if = "Option::is_none")]
+    pub system_nano: Option<u64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "UpTime", skip_serializing_if = "Option::is_none")]
+    pub up_time: Option<i64>,
+}
+impl ContainerStats {
+    /// ContainerStats contains the statistics information for a running container
+    pub fn new() -> ContainerStats {
+        ContainerStats {
+            avg_cpu: None,
+            block_input: None,
+            block_output: None,
+            cpu: None,
+            cpu_nano: None,
+            cpu_system_nano: None,
+            container_id: None,
+            duration: None,
+            mem_limit: None,
+            mem_perc: None,
+            mem_usage: None,
+            name: None,
+            network: None,
+            pids: None,
+            per_cpu: None,
+            system_nano: None,
+            up_time: None,
+        }
+    }
+}
+/// ContainerStorageConfig : ContainerStorageConfig contains information on the storage configuration of a container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStorageConfig {
+    /// ChrootDirs is an additional set of directories that need to be treated as root directories. Standard bind mounts will be mounted into paths relative to these directories. Optional.
+    #[serde(rename = "chroot_directories", skip_serializing_if = "Option::is_none")]
+    pub chroot_directories: Option<Vec<String>>,
+    /// Create the working directory if it doesn't exist. If unset, it doesn't create it. Optional.
+    #[serde(rename = "create_working_dir", skip_serializing_if = "Option::is_none")]
+    pub create_working_dir: Option<bool>,
+    /// DeviceCgroupRule are device cgroup rules that allow containers to use additional types of devices.
+    #[serde(rename = "device_cgroup_rule", skip_serializing_if = "Option::is_none")]
+    pub device_cgroup_rule: Option<Vec<LinuxDeviceCgroup>>,
+    /// Devices are devices that will be added to the container. Optional.
+    #[serde(rename = "devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<LinuxDevice>>,
+    /// DevicesFrom specifies that this container will mount the device(s) from other container(s). Optional.
+    #[serde(rename = "devices_from", skip_serializing_if = "Option::is_none")]
+    pub devices_from: Option<Vec<String>>,
+    /// HostDeviceList is used to recreate the mounted device on inherited containers
+    #[serde(rename = "host_device_list", skip_serializing_if = "Option::is_none")]
+    pub host_device_list: Option<Vec<LinuxDevice>>,
+    /// Image is the image the container will be based on. The image will be used as the container's root filesystem, and its environment vars, volumes, and other configuration will be applied to the container. Conflicts with Rootfs. At least one of Image or Rootfs must be specified.
+    #[serde(rename = "image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// ImageArch is the user-specified image architecture. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_arch", skip_serializing_if = "Option::is_none")]
+    pub image_arch: Option<String>,
+    /// ImageOS is the user-specified OS of the image. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_os", skip_serializing_if = "Option::is_none")]
+    pub image_os: Option<String>,
+    /// ImageVariant is the user-specified image variant. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_variant", skip_serializing_if = "Option::is_none")]
+    pub image_variant: Option<String>,
+    /// ImageVolumeMode indicates how image volumes will be created. Supported modes are \"ignore\" (do not create), \"tmpfs\" (create as tmpfs), and \"anonymous\" (create as anonymous volumes). The default if unset is anonymous. Optional.
+    #[serde(rename = "image_volume_mode", skip_serializing_if = "Option::is_none")]
+    pub image_volume_mode: Option<String>,
+    /// Image volumes bind-mount a container-image mount into the container. Optional.
+    #[serde(rename = "image_volumes", skip_serializing_if = "Option::is_none")]
+    pub image_volumes: Option<Vec<ImageVolume>>,
+    /// Init specifies that an init binary will be mounted into the container, and will be used as PID1. Optional.
+    #[serde(rename = "init", skip_serializing_if = "Option::is_none")]
+    pub init: Option<bool>,
+    /// InitPath specifies the path to the init binary that will be added if Init is specified above. If not specified, the default set in the Libpod config will be used. Ignored if Init above is not set. Optional.
+    #[serde(rename = "init_path", skip_serializing_if = "Option::is_none")]
+    pub init_path: Option<String>,
+    #[serde(rename = "ipcns", skip_serializing_if = "Option::is_none")]
+    pub ipcns: Option<Box<Namespace>>,
+    /// Mounts are mounts that will be added to the container. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    /// Overlay volumes are named volumes that will be added to the container. Optional.
+    #[serde(rename = "overlay_volumes", skip_serializing_if = "Option::is_none")]
+    pub overlay_volumes: Option<Vec<OverlayVolume>>,
+    /// RawImageName is the user-specified and unprocessed input referring to a local or a remote image. Optional, but strongly encouraged to be set if Image is set.
+    #[serde(rename = "raw_image_name", skip_serializing_if = "Option::is_none")]
+    pub raw_image_name: Option<String>,
+    /// Rootfs is the path to a directory that will be used as the container's root filesystem. No modification will be made to the directory, it will be directly mounted into the container as root. Conflicts with Image. At least one of Image or Rootfs must be specified.
+    #[serde(rename = "rootfs", skip_serializing_if = "Option::is_none")]
+    pub rootfs: Option<String>,
+    /// RootfsMapping specifies if there are UID/GID mappings to apply to the rootfs. Optional.
+    #[serde(rename = "rootfs_mapping", skip_serializing_if = "Option::is_none")]
+    pub rootfs_mapping: Option<String>,
+    /// RootfsOverlay tells if rootfs is actually an overlay on top of base path. Optional.
+    #[serde(rename = "rootfs_overlay", skip_serializing_if = "Option::is_none")]
+    pub rootfs_overlay: Option<bool>,
+    /// RootfsPropagation is the rootfs propagation mode for the container. If not set, the default of rslave will be used. Optional.
+    #[serde(rename = "rootfs_propagation", skip_serializing_if = "Option::is_none")]
+    pub rootfs_propagation: Option<String>,
+    /// Secrets are the secrets that will be added to the container Optional.
+    #[serde(rename = "secrets", skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<Secret>>,
+    /// ShmSize is the size of the tmpfs to mount in at /dev/shm, in bytes. Conflicts with ShmSize if IpcNS is not private. Optional.
+    #[serde(rename = "shm_size", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    /// ShmSizeSystemd is the size of systemd-specific tmpfs mounts specifically /run, /run/lock, /var/log/journal and /tmp. Optional
+    #[serde(rename = "shm_size_systemd", skip_serializing_if = "Option::is_none")]
+    pub shm_size_systemd: Option<i64>,
+    /// StorageOpts is the container's storage options Optional.
+    #[serde(rename = "storage_opts", skip_serializing_if = "Option::is_none")]
+    pub storage_opts: Option<std::collections::HashMap<String, String>>,
+    /// Volatile specifies whether the container storage can be optimized at the cost of not syncing all the dirty files in memory. Optional.
+    #[serde(rename = "volatile", skip_serializing_if = "Option::is_none")]
+    pub volatile: Option<bool>,
+    /// Volumes are named volumes that will be added to the container. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<NamedVolume>>,
+    /// VolumesFrom is a set of containers whose volumes will be added to this container. The name or ID of the container must be provided, and may optionally be followed by a : and then one or more comma-separated options. Valid options are 'ro', 'rw', and 'z'. Options will be used for all volumes sourced from the container. Optional.
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+    /// WorkDir is the container's working directory. If unset, the default, /, will be used. Optional.
+    #[serde(rename = "work_dir", skip_serializing_if = "Option::is_none")]
+    pub work_dir: Option<String>,
+}
+impl ContainerStorageConfig {
+    /// ContainerStorageConfig contains information on the storage configuration of a container.
+    pub fn new() -> ContainerStorageConfig {
+        ContainerStorageConfig {
+            chroot_directories: None,
+            create_working_dir: None,
+            device_cgroup_rule: None,
+            devices: None,
+            devices_from: None,
+            host_device_list: None,
+            image: None,
+            image_arch: None,
+            image_os: None,
+            image_variant: None,
+            image_volume_mode: None,
+            image_volumes: None,
+            init: None,
+            init_path: None,
+            ipcns: None,
+            mounts: None,
+            overlay_volumes: None,
+            raw_image_name: None,
+            rootfs: None,
+            rootfs_mapping: None,
+            rootfs_overlay: None,
+            rootfs_propagation: None,
+            secrets: None,
+            shm_size: None,
+            shm_size_systemd: None,
+            storage_opts: None,
+            volatile: None,
+            volumes: None,
+            volumes_from: None,
+            work_dir: None,
+        }
+    }
+}
+/// ContainerStore : ContainerStore describes the quantity of containers in the store by status
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStore {
+    #[serde(rename = "number", skip_serializing_if = "Option::is_none")]
+    pub number: Option<i64>,
+    #[serde(rename = "paused", skip_serializing_if = "Option::is_none")]
+    pub paused: Option<i64>,
+    #[serde(rename = "running", skip_serializing_if = "Option::is_none")]
+    pub running: Option<i64>,
+    #[serde(rename = "stopped", skip_serializing_if = "Option::is_none")]
+    pub stopped: Option<i64>,
+}
+impl ContainerStore {
+    /// ContainerStore describes the quantity of containers in the store by status
+    pub fn new() -> ContainerStore {
+        ContainerStore {
+            number: None,
+            paused: None,
+            running: None,
+            stopped: None,
+        }
+    }
+}
+/// ContainerTopOKBody : ContainerTopOKBody OK response to ContainerTop operation
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerTopOKBody {
+    /// Each process running in the container, where each is process is an array of values corresponding to the titles.
+    #[serde(rename = "Processes")]
+    pub processes: Vec<Vec<String>>,
+    /// The ps column titles
+    #[serde(rename = "Titles")]
+    pub titles: Vec<String>,
+}
+impl ContainerTopOKBody {
+    /// ContainerTopOKBody OK response to ContainerTop operation
+    pub fn new(processes: Vec<Vec<String>>, titles: Vec<String>) -> ContainerTopOKBody {
+        ContainerTopOKBody {
+            processes,
+            titles,
+        }
+    }
+}
+/// ContainerUpdateOKBody : ContainerUpdateOKBody OK response to ContainerUpdate operation
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerUpdateOKBody {
+    /// warnings
+    #[serde(rename = "Warnings")]
+    pub warnings: Vec<String>,
+}
+impl ContainerUpdateOKBody {
+    /// ContainerUpdateOKBody OK response to ContainerUpdate operation
+    pub fn new(warnings: Vec<String>) -> ContainerUpdateOKBody {
+        ContainerUpdateOKBody { warnings }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainersPruneReport {
+    #[serde(rename = "ContainersDeleted", skip_serializing_if = "Option::is_none")]
+    pub containers_deleted: Option<Vec<String>>,
+    #[serde(rename = "SpaceReclaimed", skip_serializing_if = "Option::is_none")]
+    pub space_reclaimed: Option<u64>,
+}
+impl ContainersPruneReport {
+    pub fn new() -> ContainersPruneReport {
+        ContainersPruneReport {
+            containers_deleted: None,
+            space_reclaimed: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainersPruneReportLibpod {
+    /// Error which occurred during prune operation (if any). This field is optional and may be omitted if no error occurred.
+    #[serde(
+        rename = "Err",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub err: Option<Option<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+impl ContainersPruneReportLibpod {
+    pub fn new() -> ContainersPruneReportLibpod {
+        ContainersPruneReportLibpod {
+            err: None,
+            id: None,
+            size: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CPUUsage {
+    #[serde(rename = "idlePercent", skip_serializing_if = "Option::is_none")]
+    pub idle_percent: Option<f64>,
+    #[serde(rename = "systemPercent", skip_serializing_if = "Option::is_none")]
+    pub system_percent: Option<f64>,
+    #[serde(rename = "userPercent", skip_serializing_if = "Option::is_none")]
+    pub user_percent: Option<f64>,
+}
+impl CPUUsage {
+    pub fn new() -> CPUUsage {
+        CPUUsage {
+            idle_percent: None,
+            system_percent: None,
+            user_percent: None,
+        }
+    }
+}
+/// CreateContainerConfig : CreateContainerConfig used when compatible endpoint creates a container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateContainerConfig {
+    #[serde(rename = "ArgsEscaped", skip_serializing_if = "Option::is_none")]
+    pub args_escaped: Option<bool>,
+    #[serde(rename = "AttachStderr", skip_serializing_if = "Option::is_none")]
+    pub attach_stderr: Option<bool>,
+    #[serde(rename = "AttachStdin", skip_serializing_if = "Option::is_none")]
+    pub attach_stdin: Option<bool>,
+    #[serde(rename = "AttachStdout", skip_serializing_if = "Option::is_none")]
+    pub attach_stdout: Option<bool>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
+    #[serde(rename = "Domainname", skip_serializing_if = "Option::is_none")]
+    pub domainname: Option<String>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    #[serde(rename = "EnvMerge", skip_serializing_if = "Option::is_none")]
+    pub env_merge: Option<Vec<String>>,
+    /// PortSet is a collection of structs indexed by Port
+    #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none")]
+    pub exposed_ports: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
+    pub healthcheck: Option<Box<HealthcheckConfig>>,
+    #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
+    pub host_config: Option<Box<HostConfig>>,
+    #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Mac Address of the container.  Deprecated: this field is deprecated since API v1.44. Use EndpointSettings.MacAddress instead.
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "NetworkDisabled", skip_serializing_if = "Option::is_none")]
+    pub network_disabled: Option<bool>,
+    #[serde(rename = "NetworkingConfig", skip_serializing_if = "Option::is_none")]
+    pub networking_config: Option<Box<NetworkingConfig>>,
+    #[serde(rename = "OnBuild", skip_serializing_if = "Option::is_none")]
+    pub on_build: Option<Vec<String>>,
+    #[serde(rename = "OpenStdin", skip_serializing_if = "Option::is_none")]
+    pub open_stdin: Option<bool>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "Shell", skip_serializing_if = "Option::is_none")]
+    pub shell: Option<Vec<String>>,
+    #[serde(rename = "StdinOnce", skip_serializing_if = "Option::is_none")]
+    pub stdin_once: Option<bool>,
+    #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<String>,
+    #[serde(rename = "StopTimeout", skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<i64>,
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    #[serde(rename = "UnsetEnv", skip_serializing_if = "Option::is_none")]
+    pub unset_env: Option<Vec<String>>,
+    #[serde(rename = "UnsetEnvAll", skip_serializing_if = "Option::is_none")]
+    pub unset_env_all: Option<bool>,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+impl CreateContainerConfig {
+    /// CreateContainerConfig used when compatible endpoint creates a container
+    pub fn new() -> CreateContainerConfig {
+        CreateContainerConfig {
+            args_escaped: None,
+            attach_stderr: None,
+            attach_stdin: None,
+            attach_stdout: None,
+            cmd: None,
+            domainname: None,
+            entrypoint: None,
+            env: None,
+            env_merge: None,
+            exposed_ports: None,
+            healthcheck: None,
+            host_config: None,
+            hostname: None,
+            image: None,
+            labels: None,
+            mac_address: None,
+            name: None,
+            network_disabled: None,
+            networking_config: None,
+            on_build: None,
+            open_stdin: None,
+            shell: None,
+            stdin_once: None,
+            stop_signal: None,
+            stop_timeout: None,
+            tty: None,
+            unset_env: None,
+            unset_env_all: None,
+            user: None,
+            volumes: None,
+            working_dir: None,
+        }
+    }
+}
+/// CreateOptions : Volume configuration
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateOptions {
+    #[serde(rename = "ClusterVolumeSpec", skip_serializing_if = "Option::is_none")]
+    pub cluster_volume_spec: Option<Box<ClusterVolumeSpec>>,
+    /// Name of the volume driver to use.
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// A mapping of driver options and values. These options are passed directly to the driver and are driver specific.
+    #[serde(rename = "DriverOpts", skip_serializing_if = "Option::is_none")]
+    pub driver_opts: Option<std::collections::HashMap<String, String>>,
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// The new volume's name. If not specified, Docker generates a name.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl CreateOptions {
+    /// Volume configuration
+    pub fn new() -> CreateOptions {
+        CreateOptions {
+            cluster_volume_spec: None,
+            driver: None,
+            driver_opts: None,
+            labels: None,
+            name: None,
+        }
+    }
+}
+/// CreateResponse : OK response to ContainerCreate operation
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateResponse {
+    /// The ID of the created container
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// Warnings encountered when creating the container
+    #[serde(rename = "Warnings")]
+    pub warnings: Vec<String>,
+}
+impl CreateResponse {
+    /// OK response to ContainerCreate operation
+    pub fn new(id: String, warnings: Vec<String>) -> CreateResponse {
+        CreateResponse { id, warnings }
+    }
+}
+/// DeleteResponse : DeleteResponse delete response
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteResponse {
+    /// The image ID of an image that was deleted
+    #[serde(rename = "Deleted", skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<String>,
+    /// The image ID of an image that was untagged
+    #[serde(rename = "Untagged", skip_serializing_if = "Option::is_none")]
+    pub untagged: Option<String>,
+}
+impl DeleteResponse {
+    /// DeleteResponse delete response
+    pub fn new() -> DeleteResponse {
+        DeleteResponse {
+            deleted: None,
+            untagged: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceMapping {
+    #[serde(rename = "CgroupPermissions", skip_serializing_if = "Option::is_none")]
+    pub cgroup_permissions: Option<String>,
+    #[serde(rename = "PathInContainer", skip_serializing_if = "Option::is_none")]
+    pub path_in_container: Option<String>,
+    #[serde(rename = "PathOnHost", skip_serializing_if = "Option::is_none")]
+    pub path_on_host: Option<String>,
+}
+impl DeviceMapping {
+    pub fn new() -> DeviceMapping {
+        DeviceMapping {
+            cgroup_permissions: None,
+            path_in_container: None,
+            path_on_host: None,
+        }
+    }
+}
+/// DeviceRequest : Used by GPU device drivers.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceRequest {
+    #[serde(rename = "Capabilities", skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<Vec<String>>>,
+    #[serde(rename = "Count", skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[serde(rename = "DeviceIDs", skip_serializing_if = "Option::is_none")]
+    pub device_ids: Option<Vec<String>>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+}
+impl DeviceRequest {
+    /// Used by GPU device drivers.
+    pub fn new() -> DeviceRequest {
+        DeviceRequest {
+            capabilities: None,
+            count: None,
+            device_ids: None,
+            driver: None,
+            options: None,
+        }
+    }
+}
+/// DistributionInfo : DistributionInfo describes the host distribution for libpod
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DistributionInfo {
+    #[serde(rename = "codename", skip_serializing_if = "Option::is_none")]
+    pub codename: Option<String>,
+    #[serde(rename = "distribution", skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<String>,
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl DistributionInfo {
+    /// DistributionInfo describes the host distribution for libpod
+    pub fn new() -> DistributionInfo {
+        DistributionInfo {
+            codename: None,
+            distribution: None,
+            variant: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DNSNetworkInfo {
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl DNSNetworkInfo {
+    pub fn new() -> DNSNetworkInfo {
+        DNSNetworkInfo {
+            package: None,
+            path: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Driver {
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+}
+impl Driver {
+    pub fn new() -> Driver {
+        Driver {
+            name: None,
+            options: None,
+        }
+    }
+}
+/// DriverData : DriverData handles the data for a storage driver
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DriverData {
+    #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
+    pub data: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl DriverData {
+    /// DriverData handles the data for a storage driver
+    pub fn new() -> DriverData {
+        DriverData {
+            data: None,
+            name: None,
+        }
+    }
+}
+/// EndpointIPAMConfig : EndpointIPAMConfig represents IPAM configurations for the endpoint
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EndpointIPAMConfig {
+    #[serde(rename = "IPv4Address", skip_serializing_if = "Option::is_none")]
+    pub ipv4_address: Option<String>,
+    #[serde(rename = "IPv6Address", skip_serializing_if = "Option::is_none")]
+    pub ipv6_address: Option<String>,
+    #[serde(rename = "LinkLocalIPs", skip_serializing_if = "Option::is_none")]
+    pub link_local_ips: Option<Vec<String>>,
+}
+impl EndpointIPAMConfig {
+    /// EndpointIPAMConfig represents IPAM configurations for the endpoint
+    pub fn new() -> EndpointIPAMConfig {
+        EndpointIPAMConfig {
+            ipv4_address: None,
+            ipv6_address: None,
+            link_local_ips: None,
+        }
+    }
+}
+/// EndpointResource : EndpointResource contains network resources allocated and used for a container in a network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EndpointResource {
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    #[serde(rename = "IPv4Address", skip_serializing_if = "Option::is_none")]
+    pub ipv4_address: Option<String>,
+    #[serde(rename = "IPv6Address", skip_serializing_if = "Option::is_none")]
+    pub ipv6_address: Option<String>,
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl EndpointResource {
+    /// EndpointResource contains network resources allocated and used for a container in a network
+    pub fn new() -> EndpointResource {
+        EndpointResource {
+            endpoint_id: None,
+            ipv4_address: None,
+            ipv6_address: None,
+            mac_address: None,
+            name: None,
+        }
+    }
+}
+/// EndpointSettings : EndpointSettings stores the network endpoint details
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EndpointSettings {
+    #[serde(rename = "Aliases", skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    /// DNSNames holds all the (non fully qualified) DNS names associated to this endpoint. First entry is used to generate PTR records.
+    #[serde(rename = "DNSNames", skip_serializing_if = "Option::is_none")]
+    pub dns_names: Option<Vec<String>>,
+    #[serde(rename = "DriverOpts", skip_serializing_if = "Option::is_none")]
+    pub driver_opts: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    #[serde(rename = "Gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_address: Option<String>,
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_prefix_len: Option<i64>,
+    #[serde(rename = "IPAMConfig", skip_serializing_if = "Option::is_none")]
+    pub ipam_config: Option<Box<EndpointIPAMConfig>>,
+    #[serde(rename = "IPAddress", skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "Option::is_none")]
+    pub ip_prefix_len: Option<i64>,
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "Option::is_none")]
+    pub ipv6_gateway: Option<String>,
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<String>>,
+    /// MacAddress may be used to specify a MAC address when the container is created. Once the container is running, it becomes operational data (it may contain a generated address).
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// Operational data
+    #[serde(rename = "NetworkID", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+}
+impl EndpointSettings {
+    /// EndpointSettings stores the network endpoint details
+    pub fn new() -> EndpointSettings {
+        EndpointSettings {
+            aliases: None,
+            dns_names: None,
+            driver_opts: None,
+            endpoint_id: None,
+            gateway: None,
+            global_ipv6_address: None,
+            global_ipv6_prefix_len: None,
+            ipam_config: None,
+            ip_address: None,
+            ip_prefix_len: None,
+            ipv6_gateway: None,
+            links: None,
+            mac_address: None,
+            network_id: None,
+        }
+    }
+}
+/// ErrorModel : ErrorModel is used in remote connections with podman
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ErrorModel {
+    /// API root cause formatted for automated parsing
+    #[serde(rename = "cause", skip_serializing_if = "Option::is_none")]
+    pub cause: Option<String>,
+    /// human error message, formatted for a human to read
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// HTTP response code
+    #[serde(rename = "response", skip_serializing_if = "Option::is_none")]
+    pub response: Option<u64>,
+}
+impl ErrorModel {
+    /// ErrorModel is used in remote connections with podman
+    pub fn new() -> ErrorModel {
+        ErrorModel {
+            cause: None,
+            message: None,
+            response: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    /// The error message.
+    #[serde(rename = "message")]
+    pub message: String,
+}
+impl ErrorResponse {
+    pub fn new(message: String) -> ErrorResponse {
+        ErrorResponse { message }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecStartLibpodRequest {
+    /// Detach from the command.
+    #[serde(rename = "Detach", skip_serializing_if = "Option::is_none")]
+    pub detach: Option<bool>,
+    /// Allocate a pseudo-TTY.
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    /// Height of the TTY session in characters. Tty must be set to true to use it.
+    #[serde(rename = "h", skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+    /// Width of the TTY session in characters. Tty must be set to true to use it.
+    #[serde(rename = "w", skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+}
+impl ExecStartLibpodRequest {
+    pub fn new() -> ExecStartLibpodRequest {
+        ExecStartLibpodRequest {
+            detach: None,
+            tty: None,
+            h: None,
+            w: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FilesystemChange {
+    /// Can be one of:  `0`: Modified (\"C\") `1`: Added (\"A\") `2`: Deleted (\"D\")
+    #[serde(rename = "Kind")]
+    pub kind: u32,
+    /// Path to file or directory that has changed.
+    #[serde(rename = "Path")]
+    pub path: String,
+}
+impl FilesystemChange {
+    pub fn new(kind: u32, path: String) -> FilesystemChange {
+        FilesystemChange { kind, path }
+    }
+}
+/// GraphDriverData : GraphDriverData Information about the storage driver used to store the container's and image's filesystem.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphDriverData {
+    /// Low-level storage metadata, provided as key/value pairs.  This information is driver-specific, and depends on the storage-driver in use, and should be used for informational purposes only.
+    #[serde(rename = "Data")]
+    pub data: std::collections::HashMap<String, String>,
+    /// Name of the storage driver.
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+impl GraphDriverData {
+    /// GraphDriverData Information about the storage driver used to store the container's and image's filesystem.
+    pub fn new(
+        data: std::collections::HashMap<String, String>,
+        name: String,
+    ) -> GraphDriverData {
+        GraphDriverData { data, name }
+    }
+}
+/// Health : Health stores information about the container's healthcheck results
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Health {
+    #[serde(rename = "FailingStreak", skip_serializing_if = "Option::is_none")]
+    pub failing_streak: Option<i64>,
+    #[serde(rename = "Log", skip_serializing_if = "Option::is_none")]
+    pub log: Option<Vec<HealthcheckResult>>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl Health {
+    /// Health stores information about the container's healthcheck results
+    pub fn new() -> Health {
+        Health {
+            failing_streak: None,
+            log: None,
+            status: None,
+        }
+    }
+}
+/// HealthCheckLog : HealthCheckLog describes the results of a single healthcheck
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HealthCheckLog {
+    /// End time as a string
+    #[serde(rename = "End", skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    /// Exitcode is 0 or 1
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// Output is the stdout/stderr from the healthcheck command
+    #[serde(rename = "Output", skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Start time as string
+    #[serde(rename = "Start", skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
+impl HealthCheckLog {
+    /// HealthCheckLog describes the results of a single healthcheck
+    pub fn new() -> HealthCheckLog {
+        HealthCheckLog {
+            end: None,
+            exit_code: None,
+            output: None,
+            start: None,
+        }
+    }
+}
+/// HealthCheckResults : HealthCheckResults describes the results/logs from a healthcheck
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HealthCheckResults {
+    /// FailingStreak is the number of consecutive failed healthchecks
+    #[serde(rename = "FailingStreak", skip_serializing_if = "Option::is_none")]
+    pub failing_streak: Option<i64>,
+    /// Log describes healthcheck attempts and results
+    #[serde(rename = "Log", skip_serializing_if = "Option::is_none")]
+    pub log: Option<Vec<HealthCheckLog>>,
+    /// Status starting, healthy or unhealthy
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl HealthCheckResults {
+    /// HealthCheckResults describes the results/logs from a healthcheck
+    pub fn new() -> HealthCheckResults {
+        HealthCheckResults {
+            failing_streak: None,
+            log: None,
+            status: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HealthcheckConfig {
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Interval", skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// Retries is the number of consecutive failures needed to consider a container as unhealthy. Zero means inherit.
+    #[serde(rename = "Retries", skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartInterval", skip_serializing_if = "Option::is_none")]
+    pub start_interval: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartPeriod", skip_serializing_if = "Option::is_none")]
+    pub start_period: Option<i64>,
+    /// Test is the test to perform to check that the container is healthy. An empty slice means to inherit the default. The options are: {} : inherit healthcheck {\"NONE\"} : disable healthcheck {\"CMD\", args...} : exec arguments directly {\"CMD-SHELL\", command} : run command with system's default shell
+    #[serde(rename = "Test", skip_serializing_if = "Option::is_none")]
+    pub test: Option<Vec<String>>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+}
+impl HealthcheckConfig {
+    pub fn new() -> HealthcheckConfig {
+        HealthcheckConfig {
+            interval: None,
+            retries: None,
+            start_interval: None,
+            start_period: None,
+            test: None,
+            timeout: None,
+        }
+    }
+}
+/// HealthcheckResult : HealthcheckResult stores information about a single run of a healthcheck probe
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HealthcheckResult {
+    #[serde(rename = "End", skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    #[serde(rename = "Output", skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(rename = "Start", skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
+impl HealthcheckResult {
+    /// HealthcheckResult stores information about a single run of a healthcheck probe
+    pub fn new() -> HealthcheckResult {
+        HealthcheckResult {
+            end: None,
+            exit_code: None,
+            output: None,
+            start: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    /// Author is the author of the build point.
+    #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Comment is a custom message set when creating the layer.
+    #[serde(rename = "comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Created is the combined date and time at which the layer was created, formatted as defined by RFC 3339, section 5.6.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// CreatedBy is the command which created the layer.
+    #[serde(rename = "created_by", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// EmptyLayer is used to mark if the history item created a filesystem diff.
+    #[serde(rename = "empty_layer", skip_serializing_if = "Option::is_none")]
+    pub empty_layer: Option<bool>,
+}
+impl History {
+    pub fn new() -> History {
+        History {
+            author: None,
+            comment: None,
+            created: None,
+            created_by: None,
+            empty_layer: None,
+        }
+    }
+}
+/// HistoryResponse : HistoryResponse provides details on image layers
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryResponse {
+    #[serde(rename = "Comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    #[serde(rename = "CreatedBy", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "Tags", skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+impl HistoryResponse {
+    /// HistoryResponse provides details on image layers
+    pub fn new() -> HistoryResponse {
+        HistoryResponse {
+            comment: None,
+            created: None,
+            created_by: None,
+            id: None,
+            size: None,
+            tags: None,
+        }
+    }
+}
+/// HistoryResponseItem : HistoryResponseItem individual image layer information in response to ImageHistory operation
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryResponseItem {
+    /// comment
+    #[serde(rename = "Comment")]
+    pub comment: String,
+    /// created
+    #[serde(rename = "Created")]
+    pub created: i64,
+    /// created by
+    #[serde(rename = "CreatedBy")]
+    pub created_by: String,
+    /// Id
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// size
+    #[serde(rename = "Size")]
+    pub size: i64,
+    /// tags
+    #[serde(rename = "Tags")]
+    pub tags: Vec<String>,
+}
+impl HistoryResponseItem {
+    /// HistoryResponseItem individual image layer information in response to ImageHistory operation
+    pub fn new(
+        comment: String,
+        created: i64,
+        created_by: String,
+        id: String,
+        size: i64,
+        tags: Vec<String>,
+    ) -> HistoryResponseItem {
+        HistoryResponseItem {
+            comment,
+            created,
+            created_by,
+            id,
+            size,
+            tags,
+        }
+    }
+}
+/// HostConfig : Here, \"non-portable\" means \"dependent of the host we are running on\". Portable information *should* appear in Config.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HostConfig {
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "AutoRemove", skip_serializing_if = "Option::is_none")]
+    pub auto_remove: Option<bool>,
+    /// Applicable to all platforms
+    #[serde(rename = "Binds", skip_serializing_if = "Option::is_none")]
+    pub binds: Option<Vec<String>>,
+    #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioWeight", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u32>,
+    #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<WeightDevice>>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "CapAdd", skip_serializing_if = "Option::is_none")]
+    pub cap_add: Option<Vec<String>>,
+    /// We need to override the json decoder to accept both options.
+    #[serde(rename = "CapDrop", skip_serializing_if = "Option::is_none")]
+    pub cap_drop: Option<Vec<String>>,
+    #[serde(rename = "Cgroup", skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Applicable to UNIX platforms
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// CgroupnsMode represents the cgroup namespace mode of the container
+    #[serde(rename = "CgroupnsMode", skip_serializing_if = "Option::is_none")]
+    pub cgroupns_mode: Option<String>,
+    #[serde(rename = "ConsoleSize", skip_serializing_if = "Option::is_none")]
+    pub console_size: Option<Vec<u64>>,
+    #[serde(rename = "ContainerIDFile", skip_serializing_if = "Option::is_none")]
+    pub container_id_file: Option<String>,
+    /// Applicable to Windows
+    #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
+    pub cpu_count: Option<i64>,
+    #[serde(rename = "CpuPercent", skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<i64>,
+    #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<i64>,
+    #[serde(rename = "CpuQuota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    #[serde(rename = "CpuRealtimePeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_period: Option<i64>,
+    #[serde(rename = "CpuRealtimeRuntime", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_runtime: Option<i64>,
+    /// Applicable to all platforms
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<i64>,
+    #[serde(rename = "CpusetCpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    #[serde(rename = "CpusetMems", skip_serializing_if = "Option::is_none")]
+    pub cpuset_mems: Option<String>,
+    #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
+    pub device_cgroup_rules: Option<Vec<String>>,
+    #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
+    pub device_requests: Option<Vec<DeviceRequest>>,
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<DeviceMapping>>,
+    #[serde(rename = "Dns", skip_serializing_if = "Option::is_none")]
+    pub dns: Option<Vec<String>>,
+    #[serde(rename = "DnsOptions", skip_serializing_if = "Option::is_none")]
+    pub dns_options: Option<Vec<String>>,
+    #[serde(rename = "DnsSearch", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    #[serde(rename = "ExtraHosts", skip_serializing_if = "Option::is_none")]
+    pub extra_hosts: Option<Vec<String>>,
+    #[serde(rename = "GroupAdd", skip_serializing_if = "Option::is_none")]
+    pub group_add: Option<Vec<String>>,
+    #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_bandwidth: Option<u64>,
+    #[serde(rename = "IOMaximumIOps", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_i_ops: Option<u64>,
+    /// Run a custom init inside the container, if null, use the daemon's configured settings
+    #[serde(rename = "Init", skip_serializing_if = "Option::is_none")]
+    pub init: Option<bool>,
+    #[serde(rename = "IpcMode", skip_serializing_if = "Option::is_none")]
+    pub ipc_mode: Option<String>,
+    /// Isolation represents the isolation technology of a container. The supported values are platform specific
+    #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<String>,
+    /// KernelMemory specifies the kernel memory limit (in bytes) for the container. Deprecated: kernel 5.4 deprecated kmem.limit_in_bytes.
+    #[serde(rename = "KernelMemory", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory: Option<i64>,
+    #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory_tcp: Option<i64>,
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<String>>,
+    #[serde(rename = "LogConfig", skip_serializing_if = "Option::is_none")]
+    pub log_config: Option<Box<LogConfig>>,
+    /// MaskedPaths is the list of paths to be masked inside the container (this overrides the default set of paths)
+    #[serde(rename = "MaskedPaths", skip_serializing_if = "Option::is_none")]
+    pub masked_paths: Option<Vec<String>>,
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    #[serde(rename = "MemoryReservation", skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    #[serde(rename = "MemorySwap", skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<i64>,
+    #[serde(rename = "MemorySwappiness", skip_serializing_if = "Option::is_none")]
+    pub memory_swappiness: Option<i64>,
+    /// Mounts specs used by the container
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    #[serde(rename = "NanoCpus", skip_serializing_if = "Option::is_none")]
+    pub nano_cpus: Option<i64>,
+    #[serde(rename = "NetworkMode", skip_serializing_if = "Option::is_none")]
+    pub network_mode: Option<String>,
+    #[serde(rename = "OomKillDisable", skip_serializing_if = "Option::is_none")]
+    pub oom_kill_disable: Option<bool>,
+    #[serde(rename = "OomScoreAdj", skip_serializing_if = "Option::is_none")]
+    pub oom_score_adj: Option<i64>,
+    #[serde(rename = "PidMode", skip_serializing_if = "Option::is_none")]
+    pub pid_mode: Option<String>,
+    #[serde(rename = "PidsLimit", skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+    /// PortMap is a collection of PortBinding indexed by Port
+    #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none")]
+    pub port_bindings: Option<std::collections::HashMap<String, Vec<PortBinding>>>,
+    #[serde(rename = "Privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    #[serde(rename = "PublishAllPorts", skip_serializing_if = "Option::is_none")]
+    pub publish_all_ports: Option<bool>,
+    /// ReadonlyPaths is the list of paths to be set as read-only inside the container (this overrides the default set of paths)
+    #[serde(rename = "ReadonlyPaths", skip_serializing_if = "Option::is_none")]
+    pub readonly_paths: Option<Vec<String>>,
+    #[serde(rename = "ReadonlyRootfs", skip_serializing_if = "Option::is_none")]
+    pub readonly_rootfs: Option<bool>,
+    #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<Box<RestartPolicy>>,
+    #[serde(rename = "Runtime", skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<String>,
+    #[serde(rename = "SecurityOpt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+    #[serde(rename = "ShmSize", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    #[serde(rename = "StorageOpt", skip_serializing_if = "Option::is_none")]
+    pub storage_opt: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Sysctls", skip_serializing_if = "Option::is_none")]
+    pub sysctls: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Tmpfs", skip_serializing_if = "Option::is_none")]
+    pub tmpfs: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "UTSMode", skip_serializing_if = "Option::is_none")]
+    pub uts_mode: Option<String>,
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
+    pub ulimits: Option<Vec<Ulimit>>,
+    #[serde(rename = "UsernsMode", skip_serializing_if = "Option::is_none")]
+    pub userns_mode: Option<String>,
+    #[serde(rename = "VolumeDriver", skip_serializing_if = "Option::is_none")]
+    pub volume_driver: Option<String>,
+    #[serde(rename = "VolumesFrom", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl HostConfig {
+    /// Here, \"non-portable\" means \"dependent of the host we are running on\". Portable information *should* appear in Config.
+    pub fn new() -> HostConfig {
+        HostConfig {
+            annotations: None,
+            auto_remove: None,
+            binds: None,
+            blkio_device_read_bps: None,
+            blkio_device_read_i_ops: None,
+            blkio_device_write_bps: None,
+            blkio_device_write_i_ops: None,
+            blkio_weight: None,
+            blkio_weight_device: None,
+            cap_add: None,
+            cap_drop: None,
+            cgroup: None,
+            cgroup_parent: None,
+            cgroupns_mode: None,
+            console_size: None,
+            container_id_file: None,
+            cpu_count: None,
+            cpu_percent: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpu_realtime_period: None,
+            cpu_realtime_runtime: None,
+            cpu_shares: None,
+            cpuset_cpus: None,
+            cpuset_mems: None,
+            device_cgroup_rules: None,
+            device_requests: None,
+            devices: None,
+            dns: None,
+            dns_options: None,
+            dns_search: None,
+            extra_hosts: None,
+            group_add: None,
+            io_maximum_bandwidth: None,
+            io_maximum_i_ops: None,
+            init: None,
+            ipc_mode: None,
+            isolation: None,
+            kernel_memory: None,
+            kernel_memory_tcp: None,
+            links: None,
+            log_config: None,
+            masked_paths: None,
+            memory: None,
+            memory_reservation: None,
+            memory_swap: None,
+            memory_swappiness: None,
+            mounts: None,
+            nano_cpus: None,
+            network_mode: None,
+            oom_kill_disable: None,
+            oom_score_adj: None,
+            pid_mode: None,
+            pids_limit: None,
+            port_bindings: None,
+            privileged: None,
+            publish_all_ports: None,
+            readonly_paths: None,
+            readonly_rootfs: None,
+            restart_policy: None,
+            runtime: None,
+            security_opt: None,
+            shm_size: None,
+            storage_opt: None,
+            sysctls: None,
+            tmpfs: None,
+            uts_mode: None,
+            ulimits: None,
+            userns_mode: None,
+            volume_driver: None,
+            volumes_from: None,
+        }
+    }
+}
+/// HostInfo : HostInfo describes the libpod host
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HostInfo {
+    #[serde(rename = "arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "buildahVersion", skip_serializing_if = "Option::is_none")]
+    pub buildah_version: Option<String>,
+    #[serde(rename = "cgroupControllers", skip_serializing_if = "Option::is_none")]
+    pub cgroup_controllers: Option<Vec<String>>,
+    #[serde(rename = "cgroupManager", skip_serializing_if = "Option::is_none")]
+    pub cgroup_manager: Option<String>,
+    #[serde(rename = "cgroupVersion", skip_serializing_if = "Option::is_none")]
+    pub cgroup_version: Option<String>,
+    #[serde(rename = "conmon", skip_serializing_if = "Option::is_none")]
+    pub conmon: Option<Box<ConmonInfo>>,
+    #[serde(rename = "cpuUtilization", skip_serializing_if = "Option::is_none")]
+    pub cpu_utilization: Option<Box<CPUUsage>>,
+    #[serde(rename = "cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<i64>,
+    #[serde(rename = "databaseBackend", skip_serializing_if = "Option::is_none")]
+    pub database_backend: Option<String>,
+    #[serde(rename = "distribution", skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<Box<DistributionInfo>>,
+    #[serde(rename = "eventLogger", skip_serializing_if = "Option::is_none")]
+    pub event_logger: Option<String>,
+    #[serde(rename = "freeLocks", skip_serializing_if = "Option::is_none")]
+    pub free_locks: Option<u32>,
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(rename = "idMappings", skip_serializing_if = "Option::is_none")]
+    pub id_mappings: Option<Box<IDMappings>>,
+    #[serde(rename = "kernel", skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<String>,
+    #[serde(rename = "linkmode", skip_serializing_if = "Option::is_none")]
+    pub linkmode: Option<String>,
+    #[serde(rename = "logDriver", skip_serializing_if = "Option::is_none")]
+    pub log_driver: Option<String>,
+    #[serde(rename = "memFree", skip_serializing_if = "Option::is_none")]
+    pub mem_free: Option<i64>,
+    #[serde(rename = "memTotal", skip_serializing_if = "Option::is_none")]
+    pub mem_total: Option<i64>,
+    #[serde(rename = "networkBackend", skip_serializing_if = "Option::is_none")]
+    pub network_backend: Option<String>,
+    #[serde(rename = "networkBackendInfo", skip_serializing_if = "Option::is_none")]
+    pub network_backend_info: Option<Box<NetworkInfo>>,
+    #[serde(rename = "ociRuntime", skip_serializing_if = "Option::is_none")]
+    pub oci_runtime: Option<Box<OCIRuntimeInfo>>,
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    #[serde(rename = "pasta", skip_serializing_if = "Option::is_none")]
+    pub pasta: Option<Box<PastaInfo>>,
+    #[serde(rename = "remoteSocket", skip_serializing_if = "Option::is_none")]
+    pub remote_socket: Option<Box<RemoteSocket>>,
+    /// RootlessNetworkCmd returns the default rootless network command (slirp4netns or pasta)
+    #[serde(rename = "rootlessNetworkCmd", skip_serializing_if = "Option::is_none")]
+    pub rootless_network_cmd: Option<String>,
+    #[serde(rename = "runtimeInfo", skip_serializing_if = "Option::is_none")]
+    pub runtime_info: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "security", skip_serializing_if = "Option::is_none")]
+    pub security: Option<Box<SecurityInfo>>,
+    /// ServiceIsRemote is true when the podman/libpod service is remote to the client
+    #[serde(rename = "serviceIsRemote", skip_serializing_if = "Option::is_none")]
+    pub service_is_remote: Option<bool>,
+    #[serde(rename = "slirp4netns", skip_serializing_if = "Option::is_none")]
+    pub slirp4netns: Option<Box<SlirpInfo>>,
+    #[serde(rename = "swapFree", skip_serializing_if = "Option::is_none")]
+    pub swap_free: Option<i64>,
+    #[serde(rename = "swapTotal", skip_serializing_if = "Option::is_none")]
+    pub swap_total: Option<i64>,
+    #[serde(rename = "uptime", skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<String>,
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl HostInfo {
+    /// HostInfo describes the libpod host
+    pub fn new() -> HostInfo {
+        HostInfo {
+            arch: None,
+            buildah_version: None,
+            cgroup_controllers: None,
+            cgroup_manager: None,
+            cgroup_version: None,
+            conmon: None,
+            cpu_utilization: None,
+            cpus: None,
+            database_backend: None,
+            distribution: None,
+            event_logger: None,
+            free_locks: None,
+            hostname: None,
+            id_mappings: None,
+            kernel: None,
+            linkmode: None,
+            log_driver: None,
+            mem_free: None,
+            mem_total: None,
+            network_backend: None,
+            network_backend_info: None,
+            oci_runtime: None,
+            os: None,
+            pasta: None,
+            remote_socket: None,
+            rootless_network_cmd: None,
+            runtime_info: None,
+            security: None,
+            service_is_remote: None,
+            slirp4netns: None,
+            swap_free: None,
+            swap_total: None,
+            uptime: None,
+            variant: None,
+        }
+    }
+}
+/// IDMap : IDMap contains a single entry for user namespace range remapping. An array of IDMap entries represents the structure that will be provided to the Linux kernel for creating a user namespace.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDMap {
+    #[serde(rename = "container_id", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<i64>,
+    #[serde(rename = "host_id", skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<i64>,
+    #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+impl IDMap {
+    /// IDMap contains a single entry for user namespace range remapping. An array of IDMap entries represents the structure that will be provided to the Linux kernel for creating a user namespace.
+    pub fn new() -> IDMap {
+        IDMap {
+            container_id: None,
+            host_id: None,
+            size: None,
+        }
+    }
+}
+/// IDMappingOptions : IDMappingOptions are used for specifying how ID mapping should be set up for a layer or container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDMappingOptions {
+    #[serde(rename = "AutoUserNs", skip_serializing_if = "Option::is_none")]
+    pub auto_user_ns: Option<bool>,
+    #[serde(rename = "AutoUserNsOpts", skip_serializing_if = "Option::is_none")]
+    pub auto_user_ns_opts: Option<Box<AutoUserNsOptions>>,
+    #[serde(rename = "GIDMap", skip_serializing_if = "Option::is_none")]
+    pub gid_map: Option<Vec<IDMap>>,
+    #[serde(rename = "HostGIDMapping", skip_serializing_if = "Option::is_none")]
+    pub host_gid_mapping: Option<bool>,
+    /// UIDMap and GIDMap are used for setting up a layer's root filesystem for use inside of a user namespace where ID mapping is being used. If HostUIDMapping/HostGIDMapping is true, no mapping of the respective type will be used.  Otherwise, if UIDMap and/or GIDMap contain at least one mapping, one or both will be used.  By default, if neither of those conditions apply, if the layer has a parent layer, the parent layer's mapping will be used, and if it does not have a parent layer, the mapping which was passed to the Store object when it was initialized will be used.
+    #[serde(rename = "HostUIDMapping", skip_serializing_if = "Option::is_none")]
+    pub host_uid_mapping: Option<bool>,
+    #[serde(rename = "UIDMap", skip_serializing_if = "Option::is_none")]
+    pub uid_map: Option<Vec<IDMap>>,
+}
+impl IDMappingOptions {
+    /// IDMappingOptions are used for specifying how ID mapping should be set up for a layer or container.
+    pub fn new() -> IDMappingOptions {
+        IDMappingOptions {
+            auto_user_ns: None,
+            auto_user_ns_opts: None,
+            gid_map: None,
+            host_gid_mapping: None,
+            host_uid_mapping: None,
+            uid_map: None,
+        }
+    }
+}
+/// IDMappings : IDMappings describe the GID and UID mappings
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDMappings {
+    #[serde(rename = "gidmap", skip_serializing_if = "Option::is_none")]
+    pub gidmap: Option<Vec<IDMap>>,
+    #[serde(rename = "uidmap", skip_serializing_if = "Option::is_none")]
+    pub uidmap: Option<Vec<IDMap>>,
+}
+impl IDMappings {
+    /// IDMappings describe the GID and UID mappings
+    pub fn new() -> IDMappings {
+        IDMappings {
+            gidmap: None,
+            uidmap: None,
+        }
+    }
+}
+/// IdResponse : IDResponse Response to an API call that returns just an Id
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdResponse {
+    /// The id of the newly created object.
+    #[serde(rename = "Id")]
+    pub id: String,
+}
+impl IdResponse {
+    /// IDResponse Response to an API call that returns just an Id
+    pub fn new(id: String) -> IdResponse {
+        IdResponse { id }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageBuildLibpod200Response {
+    /// output from build process
+    #[serde(rename = "stream")]
+    pub stream: String,
+}
+impl ImageBuildLibpod200Response {
+    pub fn new(stream: String) -> ImageBuildLibpod200Response {
+        ImageBuildLibpod200Response {
+            stream,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageConfig {
+    /// ArgsEscaped  Deprecated: This field is present only for legacy compatibility with Docker and should not be used by new image builders.  It is used by Docker for Windows images to indicate that the `Entrypoint` or `Cmd` or both, contains only a single element array, that is a pre-escaped, and combined into a single string `CommandLine`. If `true` the value in `Entrypoint` or `Cmd` should be used as-is to avoid double escaping. https://github.com/opencontainers/image-spec/pull/892
+    #[serde(rename = "ArgsEscaped", skip_serializing_if = "Option::is_none")]
+    pub args_escaped: Option<bool>,
+    /// Cmd defines the default arguments to the entrypoint of the container.
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
+    /// Entrypoint defines a list of arguments to use as the command to execute when the container starts.
+    #[serde(rename = "Entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    /// Env is a list of environment variables to be used in a container.
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    /// ExposedPorts a set of ports to expose from a container running this image.
+    #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none")]
+    pub exposed_ports: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Labels contains arbitrary metadata for the container.
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// StopSignal contains the system call signal that will be sent to the container to exit.
+    #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<String>,
+    /// User defines the username or UID which the process in the container should run as.
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Volumes is a set of directories describing where the process is likely write data specific to a container instance.
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// WorkingDir sets the current working directory of the entrypoint process in the container.
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+impl ImageConfig {
+    pub fn new() -> ImageConfig {
+        ImageConfig {
+            args_escaped: None,
+            cmd: None,
+            entrypoint: None,
+            env: None,
+            exposed_ports: None,
+            labels: None,
+            stop_signal: None,
+            user: None,
+            volumes: None,
+            working_dir: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageData {
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Architecture", skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    #[serde(rename = "Author", skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(rename = "Comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<ImageConfig>>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// The following is an example of the contents of Digest types:  sha256:7173b809ca12ec5dee4506cd86be934c4596dd234ee82c0662eac04a8c2c71dc  This allows to abstract the digest behind this type and work only in those terms.
+    #[serde(rename = "Digest", skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
+    pub graph_driver: Option<Box<DriverData>>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
+    pub healthcheck: Option<Box<Schema2HealthConfig>>,
+    #[serde(rename = "History", skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<History>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "ManifestType", skip_serializing_if = "Option::is_none")]
+    pub manifest_type: Option<String>,
+    #[serde(rename = "NamesHistory", skip_serializing_if = "Option::is_none")]
+    pub names_history: Option<Vec<String>>,
+    #[serde(rename = "Os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    #[serde(rename = "Parent", skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(rename = "RepoDigests", skip_serializing_if = "Option::is_none")]
+    pub repo_digests: Option<Vec<String>>,
+    #[serde(rename = "RepoTags", skip_serializing_if = "Option::is_none")]
+    pub repo_tags: Option<Vec<String>>,
+    #[serde(rename = "RootFS", skip_serializing_if = "Option::is_none")]
+    pub root_fs: Option<Box<RootFS>>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(rename = "VirtualSize", skip_serializing_if = "Option::is_none")]
+    pub virtual_size: Option<i64>,
+}
+impl ImageData {
+    pub fn new() -> ImageData {
+        ImageData {
+            annotations: None,
+            architecture: None,
+            author: None,
+            comment: None,
+            config: None,
+            created: None,
+            digest: None,
+            graph_driver: None,
+            healthcheck: None,
+            history: None,
+            id: None,
+            labels: None,
+            manifest_type: None,
+            names_history: None,
+            os: None,
+            parent: None,
+            repo_digests: None,
+            repo_tags: None,
+            root_fs: None,
+            size: None,
+            user: None,
+            version: None,
+            virtual_size: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageImportReport {
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl ImageImportReport {
+    pub fn new() -> ImageImportReport {
+        ImageImportReport { id: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageInspect {
+    /// Architecture is the hardware CPU architecture that the image runs on.
+    #[serde(rename = "Architecture", skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    /// Author is the name of the author that was specified when committing the image, or as specified through MAINTAINER (deprecated) in the Dockerfile.
+    #[serde(rename = "Author", skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Comment is an optional message that can be set when committing or importing the image.
+    #[serde(rename = "Comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<Config>>,
+    /// Container is for backwards compat but is basically unused
+    #[serde(rename = "Container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(rename = "ContainerConfig", skip_serializing_if = "Option::is_none")]
+    pub container_config: Option<Box<Config>>,
+    /// Created is the date and time at which the image was created, formatted in RFC 3339 nano-seconds (time.RFC3339Nano).  This information is only available if present in the image, and omitted otherwise.
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// DockerVersion is the version of Docker that was used to build the image.  Depending on how the image was created, this field may be empty.
+    #[serde(rename = "DockerVersion", skip_serializing_if = "Option::is_none")]
+    pub docker_version: Option<String>,
+    #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
+    pub graph_driver: Option<Box<GraphDriverData>>,
+    /// ID is the content-addressable ID of an image.  This identifier is a content-addressable digest calculated from the image's configuration (which includes the digests of layers used by the image).  Note that this digest differs from the `RepoDigests` below, which holds digests of image manifests that reference the image.
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Metadata", skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<Metadata>>,
+    /// OS is the Operating System the image is built to run on.
+    #[serde(rename = "Os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OsVersion is the version of the Operating System the image is built to run on (especially for Windows).
+    #[serde(rename = "OsVersion", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// Parent is the ID of the parent image.  Depending on how the image was created, this field may be empty and is only set for images that were built/created locally. This field is empty if the image was pulled from an image registry.
+    #[serde(rename = "Parent", skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// RepoDigests is a list of content-addressable digests of locally available image manifests that the image is referenced from. Multiple manifests can refer to the same image.  These digests are usually only available if the image was either pulled from a registry, or if the image was pushed to a registry, which is when the manifest is generated and its digest calculated.
+    #[serde(rename = "RepoDigests", skip_serializing_if = "Option::is_none")]
+    pub repo_digests: Option<Vec<String>>,
+    /// RepoTags is a list of image names/tags in the local image cache that reference this image.  Multiple image tags can refer to the same image, and this list may be empty if no tags reference the image, in which case the image is \"untagged\", in which case it can still be referenced by its ID.
+    #[serde(rename = "RepoTags", skip_serializing_if = "Option::is_none")]
+    pub repo_tags: Option<Vec<String>>,
+    #[serde(rename = "RootFS", skip_serializing_if = "Option::is_none")]
+    pub root_fs: Option<Box<RootFS>>,
+    /// Size is the total size of the image including all layers it is composed of.
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// Variant is the CPU architecture variant (presently ARM-only).
+    #[serde(rename = "Variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    /// VirtualSize is the total size of the image including all layers it is composed of.  Deprecated: this field is omitted in API v1.44, but kept for backward compatibility. Use Size instead.
+    #[serde(rename = "VirtualSize", skip_serializing_if = "Option::is_none")]
+    pub virtual_size: Option<i64>,
+}
+impl ImageInspect {
+    pub fn new() -> ImageInspect {
+        ImageInspect {
+            architecture: None,
+            author: None,
+            comment: None,
+            config: None,
+            container: None,
+            container_config: None,
+            created: None,
+            docker_version: None,
+            graph_driver: None,
+            id: None,
+            metadata: None,
+            os: None,
+            os_version: None,
+            parent: None,
+            repo_digests: None,
+            repo_tags: None,
+            root_fs: None,
+            size: None,
+            variant: None,
+            virtual_size: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageLoadReport {
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+}
+impl ImageLoadReport {
+    pub fn new() -> ImageLoadReport {
+        ImageLoadReport { names: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageSearchLibpod200Response {
+    /// Automated indicates if the image was created by an automated build.
+    #[serde(rename = "Automated", skip_serializing_if = "Option::is_none")]
+    pub automated: Option<String>,
+    /// Description of the image.
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Index is the image index
+    #[serde(rename = "Index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+    /// Name is the canonical name of the image
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Official indicates if it's an official image.
+    #[serde(rename = "Official", skip_serializing_if = "Option::is_none")]
+    pub official: Option<String>,
+    /// Stars is the number of stars of the image.
+    #[serde(rename = "Stars", skip_serializing_if = "Option::is_none")]
+    pub stars: Option<i64>,
+    /// Tag is the image tag
+    #[serde(rename = "Tag", skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+impl ImageSearchLibpod200Response {
+    pub fn new() -> ImageSearchLibpod200Response {
+        ImageSearchLibpod200Response {
+            automated: None,
+            description: None,
+            index: None,
+            name: None,
+            official: None,
+            stars: None,
+            tag: None,
+        }
+    }
+}
+/// ImageStore : ImageStore describes the image store.  Right now only the number of images present
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageStore {
+    #[serde(rename = "number", skip_serializing_if = "Option::is_none")]
+    pub number: Option<i64>,
+}
+impl ImageStore {
+    /// ImageStore describes the image store.  Right now only the number of images present
+    pub fn new() -> ImageStore {
+        ImageStore { number: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageTreeReport {
+    #[serde(rename = "Tree", skip_serializing_if = "Option::is_none")]
+    pub tree: Option<String>,
+}
+impl ImageTreeReport {
+    pub fn new() -> ImageTreeReport {
+        ImageTreeReport { tree: None }
+    }
+}
+/// ImageVolume : ImageVolume is a volume based on a container image.  The container image is first mounted on the host and is then bind-mounted into the container.  An ImageVolume is always mounted read-only.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageVolume {
+    /// Destination is the absolute path of the mount in the container.
+    #[serde(rename = "Destination", skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// ReadWrite sets the volume writable.
+    #[serde(rename = "ReadWrite", skip_serializing_if = "Option::is_none")]
+    pub read_write: Option<bool>,
+    /// Source is the source of the image volume.  The image can be referred to by name and by ID.
+    #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// SubPath mounts a particular path within the image. If empty, the whole image is mounted.
+    #[serde(rename = "subPath", skip_serializing_if = "Option::is_none")]
+    pub sub_path: Option<String>,
+}
+impl ImageVolume {
+    /// ImageVolume is a volume based on a container image.  The container image is first mounted on the host and is then bind-mounted into the container.  An ImageVolume is always mounted read-only.
+    pub fn new() -> ImageVolume {
+        ImageVolume {
+            destination: None,
+            read_write: None,
+            source: None,
+            sub_path: None,
+        }
+    }
+}
+/// Info : Info contains information about the Volume as a whole as provided by the CSI storage plugin.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Info {
+    /// AccessibleTopolgoy is the topology this volume is actually accessible from.
+    #[serde(rename = "AccessibleTopology", skip_serializing_if = "Option::is_none")]
+    pub accessible_topology: Option<Vec<Topology>>,
+    /// CapacityBytes is the capacity of the volume in bytes. A value of 0 indicates that the capacity is unknown.
+    #[serde(rename = "CapacityBytes", skip_serializing_if = "Option::is_none")]
+    pub capacity_bytes: Option<i64>,
+    /// VolumeContext is the context originating from the CSI storage plugin when the Volume is created.
+    #[serde(rename = "VolumeContext", skip_serializing_if = "Option::is_none")]
+    pub volume_context: Option<std::collections::HashMap<String, String>>,
+    /// VolumeID is the ID of the Volume as seen by the CSI storage plugin. This is distinct from the Volume's Swarm ID, which is the ID used by all of the Docker Engine to refer to the Volume. If this field is blank, then the Volume has not been successfully created yet.
+    #[serde(rename = "VolumeID", skip_serializing_if = "Option::is_none")]
+    pub volume_id: Option<String>,
+}
+impl Info {
+    /// Info contains information about the Volume as a whole as provided by the CSI storage plugin.
+    pub fn new() -> Info {
+        Info {
+            accessible_topology: None,
+            capacity_bytes: None,
+            volume_context: None,
+            volume_id: None,
+        }
+    }
+}
+/// InspectAdditionalNetwork : InspectAdditionalNetwork holds information about non-default networks the container has been connected to. As with InspectNetworkSettings, many fields are unused and maintained only for compatibility with Docker.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectAdditionalNetwork {
+    /// AdditionalMacAddresses is a set of additional MAC Addresses beyond the first. CNI may configure more than one interface for a single network, which can cause this.
+    #[serde(rename = "AdditionalMACAddresses", skip_serializing_if = "Option::is_none")]
+    pub additional_mac_addresses: Option<Vec<String>>,
+    /// Aliases are any network aliases the container has in this network.
+    #[serde(rename = "Aliases", skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    /// DriverOpts is presently unused and maintained exclusively for compatibility.
+    #[serde(rename = "DriverOpts", skip_serializing_if = "Option::is_none")]
+    pub driver_opts: Option<std::collections::HashMap<String, String>>,
+    /// EndpointID is unused, maintained exclusively for compatibility.
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    /// Gateway is the IP address of the gateway this network will use.
+    #[serde(rename = "Gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    /// GlobalIPv6Address is the global-scope IPv6 Address for this network.
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_address: Option<String>,
+    /// GlobalIPv6PrefixLen is the length of the subnet mask of this network.
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_prefix_len: Option<i64>,
+    /// IPAMConfig is presently unused and maintained exclusively for compatibility.
+    #[serde(rename = "IPAMConfig", skip_serializing_if = "Option::is_none")]
+    pub ipam_config: Option<std::collections::HashMap<String, String>>,
+    /// IPAddress is the IP address for this network.
+    #[serde(rename = "IPAddress", skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// IPPrefixLen is the length of the subnet mask of this network.
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "Option::is_none")]
+    pub ip_prefix_len: Option<i64>,
+    /// IPv6Gateway is the IPv6 gateway this network will use.
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "Option::is_none")]
+    pub ipv6_gateway: Option<String>,
+    /// Links is presently unused and maintained exclusively for compatibility.
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<String>>,
+    /// MacAddress is the MAC address for the interface in this network.
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// Name of the network we're connecting to.
+    #[serde(rename = "NetworkID", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    /// SecondaryIPAddresses is a list of extra IP Addresses that the container has been assigned in this network.
+    #[serde(rename = "SecondaryIPAddresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ip_addresses: Option<Vec<Address>>,
+    /// SecondaryIPv6Addresses is a list of extra IPv6 Addresses that the container has been assigned in this network.
+    #[serde(rename = "SecondaryIPv6Addresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ipv6_addresses: Option<Vec<Address>>,
+}
+impl InspectAdditionalNetwork {
+    /// InspectAdditionalNetwork holds information about non-default networks the container has been connected to. As with InspectNetworkSettings, many fields are unused and maintained only for compatibility with Docker.
+    pub fn new() -> InspectAdditionalNetwork {
+        InspectAdditionalNetwork {
+            additional_mac_addresses: None,
+            aliases: None,
+            driver_opts: None,
+            endpoint_id: None,
+            gateway: None,
+            global_ipv6_address: None,
+            global_ipv6_prefix_len: None,
+            ipam_config: None,
+            ip_address: None,
+            ip_prefix_len: None,
+            ipv6_gateway: None,
+            links: None,
+            mac_address: None,
+            network_id: None,
+            secondary_ip_addresses: None,
+            secondary_ipv6_addresses: None,
+        }
+    }
+}
+/// InspectBlkioThrottleDevice : InspectBlkioThrottleDevice holds information about a speed cap for a device node. This cap applies to a specific operation (read, write, etc) on the given node.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectBlkioThrottleDevice {
+    /// Path is the path to the device this applies to.
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Rate is the maximum rate. It is in either bytes per second or iops per second, determined by where it is used - documentation will indicate which is appropriate.
+    #[serde(rename = "Rate", skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+impl InspectBlkioThrottleDevice {
+    /// InspectBlkioThrottleDevice holds information about a speed cap for a device node. This cap applies to a specific operation (read, write, etc) on the given node.
+    pub fn new() -> InspectBlkioThrottleDevice {
+        InspectBlkioThrottleDevice {
+            path: None,
+            rate: None,
+        }
+    }
+}
+/// InspectBlkioWeightDevice : InspectBlkioWeightDevice holds information about the relative weight of an individual device node. Weights are used in the I/O scheduler to give relative priority to some accesses.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectBlkioWeightDevice {
+    /// Path is the path to the device this applies to.
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Weight is the relative weight the scheduler will use when scheduling I/O.
+    #[serde(rename = "Weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
+impl InspectBlkioWeightDevice {
+    /// InspectBlkioWeightDevice holds information about the relative weight of an individual device node. Weights are used in the I/O scheduler to give relative priority to some accesses.
+    pub fn new() -> InspectBlkioWeightDevice {
+        InspectBlkioWeightDevice {
+            path: None,
+            weight: None,
+        }
+    }
+}
+/// InspectContainerConfig : InspectContainerConfig holds further data about how a container was initially configured.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectContainerConfig {
+    /// Container annotations
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Unused, at present
+    #[serde(rename = "AttachStderr", skip_serializing_if = "Option::is_none")]
+    pub attach_stderr: Option<bool>,
+    /// Unused, at present
+    #[serde(rename = "AttachStdin", skip_serializing_if = "Option::is_none")]
+    pub attach_stdin: Option<bool>,
+    /// Unused, at present
+    #[serde(rename = "AttachStdout", skip_serializing_if = "Option::is_none")]
+    pub attach_stdout: Option<bool>,
+    /// ChrootDirs is an additional set of directories that need to be treated as root directories. Standard bind mounts will be mounted into paths relative to these directories.
+    #[serde(rename = "ChrootDirs", skip_serializing_if = "Option::is_none")]
+    pub chroot_dirs: Option<Vec<String>>,
+    /// Container command
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<Vec<String>>,
+    /// CreateCommand is the full command plus arguments of the process the container has been created with.
+    #[serde(rename = "CreateCommand", skip_serializing_if = "Option::is_none")]
+    pub create_command: Option<Vec<String>>,
+    /// Container domain name - unused at present
+    #[serde(rename = "Domainname", skip_serializing_if = "Option::is_none")]
+    pub domainname: Option<String>,
+    /// Container entrypoint
+    #[serde(rename = "Entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    /// Container environment variables
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
+    pub healthcheck: Option<Box<Schema2HealthConfig>>,
+    /// HealthcheckOnFailureAction defines an action to take once the container turns unhealthy.
+    #[serde(
+        rename = "HealthcheckOnFailureAction",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub healthcheck_on_failure_action: Option<String>,
+    /// Container hostname
+    #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// Container image
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Container labels
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// On-build arguments - presently unused. More of Buildah's domain.
+    #[serde(rename = "OnBuild", skip_serializing_if = "Option::is_none")]
+    pub on_build: Option<String>,
+    /// Whether the container leaves STDIN open
+    #[serde(rename = "OpenStdin", skip_serializing_if = "Option::is_none")]
+    pub open_stdin: Option<bool>,
+    /// Passwd determines whether or not podman can add entries to /etc/passwd and /etc/group
+    #[serde(rename = "Passwd", skip_serializing_if = "Option::is_none")]
+    pub passwd: Option<bool>,
+    /// Secrets are the secrets mounted in the container
+    #[serde(rename = "Secrets", skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<InspectSecret>>,
+    /// Whether STDIN is only left open once. Presently not supported by Podman, unused.
+    #[serde(rename = "StdinOnce", skip_serializing_if = "Option::is_none")]
+    pub stdin_once: Option<bool>,
+    /// Container stop signal
+    #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<String>,
+    /// StopTimeout is time before container is stopped when calling stop
+    #[serde(rename = "StopTimeout", skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<u64>,
+    /// SystemdMode is whether the container is running in systemd mode. In systemd mode, the container configuration is customized to optimize running systemd in the container.
+    #[serde(rename = "SystemdMode", skip_serializing_if = "Option::is_none")]
+    pub systemd_mode: Option<bool>,
+    /// Timeout is time before container is killed by conmon
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Timezone is the timezone inside the container. Local means it has the same timezone as the host machine
+    #[serde(rename = "Timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Whether the container creates a TTY
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    /// Umask is the umask inside the container.
+    #[serde(rename = "Umask", skip_serializing_if = "Option::is_none")]
+    pub umask: Option<String>,
+    /// User the container was launched with
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Unused, at present. I've never seen this field populated.
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Container working directory
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+    /// SdNotifyMode is the sd-notify mode of the container.
+    #[serde(rename = "sdNotifyMode", skip_serializing_if = "Option::is_none")]
+    pub sd_notify_mode: Option<String>,
+    /// SdNotifySocket is the NOTIFY_SOCKET in use by/configured for the container.
+    #[serde(rename = "sdNotifySocket", skip_serializing_if = "Option::is_none")]
+    pub sd_notify_socket: Option<String>,
+}
+impl InspectContainerConfig {
+    /// InspectContainerConfig holds further data about how a container was initially configured.
+    pub fn new() -> InspectContainerConfig {
+        InspectContainerConfig {
+            annotations: None,
+            attach_stderr: None,
+            attach_stdin: None,
+            attach_stdout: None,
+            chroot_dirs: None,
+            cmd: None,
+            create_command: None,
+            domainname: None,
+            entrypoint: None,
+            env: None,
+            healthcheck: None,
+            healthcheck_on_failure_action: None,
+            hostname: None,
+            image: None,
+            labels: None,
+            on_build: None,
+            open_stdin: None,
+            passwd: None,
+            secrets: None,
+            stdin_once: None,
+            stop_signal: None,
+            stop_timeout: None,
+            systemd_mode: None,
+            timeout: None,
+            timezone: None,
+            tty: None,
+            umask: None,
+            user: None,
+            volumes: None,
+            working_dir: None,
+            sd_notify_mode: None,
+            sd_notify_socket: None,
+        }
+    }
+}
+/// InspectContainerData : InspectContainerData provides a detailed record of a container's configuration and state as viewed by Libpod. Large portions of this structure are defined such that the output is compatible with `docker inspect` JSON, but additional fields have been added as required to share information not in the original output.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectContainerData {
+    #[serde(rename = "AppArmorProfile", skip_serializing_if = "Option::is_none")]
+    pub app_armor_profile: Option<String>,
+    #[serde(rename = "Args", skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    #[serde(rename = "BoundingCaps", skip_serializing_if = "Option::is_none")]
+    pub bounding_caps: Option<Vec<String>>,
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<InspectContainerConfig>>,
+    #[serde(rename = "ConmonPidFile", skip_serializing_if = "Option::is_none")]
+    pub conmon_pid_file: Option<String>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "Dependencies", skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<String>>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "EffectiveCaps", skip_serializing_if = "Option::is_none")]
+    pub effective_caps: Option<Vec<String>>,
+    #[serde(rename = "ExecIDs", skip_serializing_if = "Option::is_none")]
+    pub exec_ids: Option<Vec<String>>,
+    #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
+    pub graph_driver: Option<Box<DriverData>>,
+    #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
+    pub host_config: Option<Box<InspectContainerHostConfig>>,
+    #[serde(rename = "HostnamePath", skip_serializing_if = "Option::is_none")]
+    pub hostname_path: Option<String>,
+    #[serde(rename = "HostsPath", skip_serializing_if = "Option::is_none")]
+    pub hosts_path: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "ImageDigest", skip_serializing_if = "Option::is_none")]
+    pub image_digest: Option<String>,
+    #[serde(rename = "ImageName", skip_serializing_if = "Option::is_none")]
+    pub image_name: Option<String>,
+    #[serde(rename = "IsInfra", skip_serializing_if = "Option::is_none")]
+    pub is_infra: Option<bool>,
+    #[serde(rename = "IsService", skip_serializing_if = "Option::is_none")]
+    pub is_service: Option<bool>,
+    #[serde(rename = "KubeExitCodePropagation", skip_serializing_if = "Option::is_none")]
+    pub kube_exit_code_propagation: Option<String>,
+    #[serde(rename = "MountLabel", skip_serializing_if = "Option::is_none")]
+    pub mount_label: Option<String>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<InspectMount>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Namespace", skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(rename = "NetworkSettings", skip_serializing_if = "Option::is_none")]
+    pub network_settings: Option<Box<InspectNetworkSettings>>,
+    #[serde(rename = "OCIConfigPath", skip_serializing_if = "Option::is_none")]
+    pub oci_config_path: Option<String>,
+    #[serde(rename = "OCIRuntime", skip_serializing_if = "Option::is_none")]
+    pub oci_runtime: Option<String>,
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "PidFile", skip_serializing_if = "Option::is_none")]
+    pub pid_file: Option<String>,
+    #[serde(rename = "Pod", skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    #[serde(rename = "ProcessLabel", skip_serializing_if = "Option::is_none")]
+    pub process_label: Option<String>,
+    #[serde(rename = "ResolvConfPath", skip_serializing_if = "Option::is_none")]
+    pub resolv_conf_path: Option<String>,
+    #[serde(rename = "RestartCount", skip_serializing_if = "Option::is_none")]
+    pub restart_count: Option<i32>,
+    #[serde(rename = "Rootfs", skip_serializing_if = "Option::is_none")]
+    pub rootfs: Option<String>,
+    #[serde(rename = "SizeRootFs", skip_serializing_if = "Option::is_none")]
+    pub size_root_fs: Option<i64>,
+    #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none")]
+    pub size_rw: Option<i64>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<InspectContainerState>>,
+    #[serde(rename = "StaticDir", skip_serializing_if = "Option::is_none")]
+    pub static_dir: Option<String>,
+    #[serde(rename = "lockNumber", skip_serializing_if = "Option::is_none")]
+    pub lock_number: Option<u32>,
+}
+impl InspectContainerData {
+    /// InspectContainerData provides a detailed record of a container's configuration and state as viewed by Libpod. Large portions of this structure are defined such that the output is compatible with `docker inspect` JSON, but additional fields have been added as required to share information not in the original output.
+    pub fn new() -> InspectContainerData {
+        InspectContainerData {
+            app_armor_profile: None,
+            args: None,
+            bounding_caps: None,
+            config: None,
+            conmon_pid_file: None,
+            created: None,
+            dependencies: None,
+            driver: None,
+            effective_caps: None,
+            exec_ids: None,
+            graph_driver: None,
+            host_config: None,
+            hostname_path: None,
+            hosts_path: None,
+            id: None,
+            image: None,
+            image_digest: None,
+            image_name: None,
+            is_infra: None,
+            is_service: None,
+            kube_exit_code_propagation: None,
+            mount_label: None,
+            mounts: None,
+            name: None,
+            namespace: None,
+            network_settings: None,
+            oci_config_path: None,
+            oci_runtime: None,
+            path: None,
+            pid_file: None,
+            pod: None,
+            process_label: None,
+            resolv_conf_path: None,
+            restart_count: None,
+            rootfs: None,
+            size_root_fs: None,
+            size_rw: None,
+            state: None,
+            static_dir: None,
+            lock_number: None,
+        }
+    }
+}
+/// InspectContainerHostConfig : nolint:revive,stylecheck // Field names are fixed for compatibility and cannot be changed.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectContainerHostConfig {
+    /// Annotations are provided to the runtime when the container is started.
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// AutoRemove is whether the container will be automatically removed on exiting. It is not handled directly within libpod and is stored in an annotation.
+    #[serde(rename = "AutoRemove", skip_serializing_if = "Option::is_none")]
+    pub auto_remove: Option<bool>,
+    /// Binds contains an array of user-added mounts. Both volume mounts and named volumes are included. Tmpfs mounts are NOT included. In 'docker inspect' this is separated into 'Binds' and 'Mounts' based on how a mount was added. We do not make this distinction and do not include a Mounts field in inspect. Format: <src>:<destination>[:<comma-separated options>]
+    #[serde(rename = "Binds", skip_serializing_if = "Option::is_none")]
+    pub binds: Option<Vec<String>>,
+    /// BlkioDeviceReadBps is an array of I/O throttle parameters for individual device nodes. This specifically sets read rate cap in bytes per second for device nodes. As with BlkioWeightDevice, we pull the path from /sys/dev, and we don't guarantee the path will be identical to the original (though the node will be).
+    #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_bps: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// BlkioDeviceReadIOps is an array of I/O throttle parameters for individual device nodes. This specifically sets the read rate cap in iops per second for device nodes. As with BlkioWeightDevice, we pull the path from /sys/dev, and we don't guarantee the path will be identical to the original (though the node will be).
+    #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_i_ops: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// BlkioDeviceWriteBps is an array of I/O throttle parameters for individual device nodes. this specifically sets write rate cap in bytes per second for device nodes. as with BlkioWeightDevice, we pull the path from /sys/dev, and we don't guarantee the path will be identical to the original (though the node will be).
+    #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_bps: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// BlkioDeviceWriteIOps is an array of I/O throttle parameters for individual device nodes. This specifically sets the write rate cap in iops per second for device nodes. As with BlkioWeightDevice, we pull the path from /sys/dev, and we don't guarantee the path will be identical to the original (though the node will be).
+    #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_i_ops: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// BlkioWeight indicates the I/O resources allocated to the container. It is a relative weight in the scheduler for assigning I/O time versus other Cgroups.
+    #[serde(rename = "BlkioWeight", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u32>,
+    /// BlkioWeightDevice is an array of I/O resource priorities for individual device nodes. Unfortunately, the spec only stores the device's Major/Minor numbers and not the path, which is used here. Fortunately, the kernel provides an interface for retrieving the path of a given node by major:minor at /sys/dev/. However, the exact path in use may not be what was used in the original CLI invocation - though it is guaranteed that the device node will be the same, and using the given path will be functionally identical.
+    #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<InspectBlkioWeightDevice>>,
+    /// CapAdd is a list of capabilities added to the container. It is not directly stored by Libpod, and instead computed from the capabilities listed in the container's spec, compared against a set of default capabilities.
+    #[serde(rename = "CapAdd", skip_serializing_if = "Option::is_none")]
+    pub cap_add: Option<Vec<String>>,
+    /// CapDrop is a list of capabilities removed from the container. It is not directly stored by libpod, and instead computed from the capabilities listed in the container's spec, compared against a set of default capabilities.
+    #[serde(rename = "CapDrop", skip_serializing_if = "Option::is_none")]
+    pub cap_drop: Option<Vec<String>>,
+    /// Cgroup contains the container's cgroup. It is presently not populated. TODO.
+    #[serde(rename = "Cgroup", skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// CgroupConf is the configuration for cgroup v2.
+    #[serde(rename = "CgroupConf", skip_serializing_if = "Option::is_none")]
+    pub cgroup_conf: Option<std::collections::HashMap<String, String>>,
+    /// CgroupManager is the cgroup manager used by the container. At present, allowed values are either \"cgroupfs\" or \"systemd\".
+    #[serde(rename = "CgroupManager", skip_serializing_if = "Option::is_none")]
+    pub cgroup_manager: Option<String>,
+    /// CgroupMode is the configuration of the container's cgroup namespace. Populated as follows: private - a cgroup namespace has been created host - No cgroup namespace created container:<id> - Using another container's cgroup namespace ns:<path> - A path to a cgroup namespace has been specified
+    #[serde(rename = "CgroupMode", skip_serializing_if = "Option::is_none")]
+    pub cgroup_mode: Option<String>,
+    /// CgroupParent is the Cgroup parent of the container. Only set if not default.
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// Cgroups contains the container's Cgroup mode. Allowed values are \"default\" (container is creating Cgroups) and \"disabled\" (container is not creating Cgroups). This is Libpod-specific and not included in `docker inspect`.
+    #[serde(rename = "Cgroups", skip_serializing_if = "Option::is_none")]
+    pub cgroups: Option<String>,
+    /// ConsoleSize is an array of 2 integers showing the size of the container's console. It is only set if the container is creating a terminal. TODO.
+    #[serde(rename = "ConsoleSize", skip_serializing_if = "Option::is_none")]
+    pub console_size: Option<Vec<u64>>,
+    /// ContainerIDFile is a file created during container creation to hold the ID of the created container. This is not handled within libpod and is stored in an annotation.
+    #[serde(rename = "ContainerIDFile", skip_serializing_if = "Option::is_none")]
+    pub container_id_file: Option<String>,
+    /// CpuCount is Windows-only and not presently implemented.
+    #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
+    pub cpu_count: Option<u64>,
+    /// CpuPercent is Windows-only and not presently implemented.
+    #[serde(rename = "CpuPercent", skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<u64>,
+    /// CpuPeriod is the length of a CPU period in microseconds. It relates directly to CpuQuota.
+    #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<u64>,
+    /// CpuPeriod is the amount of time (in microseconds) that a container can use the CPU in every CpuPeriod.
+    #[serde(rename = "CpuQuota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    /// CpuRealtimePeriod is the length of time (in microseconds) of the CPU realtime period. If set to 0, no time will be allocated to realtime tasks.
+    #[serde(rename = "CpuRealtimePeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_period: Option<u64>,
+    /// CpuRealtimeRuntime is the length of time (in microseconds) allocated for realtime tasks within every CpuRealtimePeriod.
+    #[serde(rename = "CpuRealtimeRuntime", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_runtime: Option<i64>,
+    /// CpuShares indicates the CPU resources allocated to the container. It is a relative weight in the scheduler for assigning CPU time versus other Cgroups.
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<u64>,
+    /// CpusetCpus is the set of CPUs that the container will execute on. Formatted as `0-3` or `0,2`. Default (if unset) is all CPUs.
+    #[serde(rename = "CpusetCpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    /// CpusetMems is the set of memory nodes the container will use. Formatted as `0-3` or `0,2`. Default (if unset) is all memory nodes.
+    #[serde(rename = "CpusetMems", skip_serializing_if = "Option::is_none")]
+    pub cpuset_mems: Option<String>,
+    /// Devices is a list of device nodes that will be added to the container. These are stored in the OCI spec only as type, major, minor while we display the host path. We convert this with /sys/dev, but we cannot guarantee that the host path will be identical - only that the actual device will be.
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<InspectDevice>>,
+    /// DiskQuota is the maximum amount of disk space the container may use (in bytes). Presently not populated. TODO.
+    #[serde(rename = "DiskQuota", skip_serializing_if = "Option::is_none")]
+    pub disk_quota: Option<u64>,
+    /// Dns is a list of DNS nameservers that will be added to the container's resolv.conf
+    #[serde(rename = "Dns", skip_serializing_if = "Option::is_none")]
+    pub dns: Option<Vec<String>>,
+    /// DnsOptions is a list of DNS options that will be set in the container's resolv.conf
+    #[serde(rename = "DnsOptions", skip_serializing_if = "Option::is_none")]
+    pub dns_options: Option<Vec<String>>,
+    /// DnsSearch is a list of DNS search domains that will be set in the container's resolv.conf
+    #[serde(rename = "DnsSearch", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// ExtraHosts contains hosts that will be added to the container's etc/hosts.
+    #[serde(rename = "ExtraHosts", skip_serializing_if = "Option::is_none")]
+    pub extra_hosts: Option<Vec<String>>,
+    /// GroupAdd contains groups that the user inside the container will be added to.
+    #[serde(rename = "GroupAdd", skip_serializing_if = "Option::is_none")]
+    pub group_add: Option<Vec<String>>,
+    #[serde(rename = "IDMappings", skip_serializing_if = "Option::is_none")]
+    pub id_mappings: Option<Box<InspectIDMappings>>,
+    /// IOMaximumBandwidth is Windows-only and not presently implemented.
+    #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_bandwidth: Option<u64>,
+    /// IOMaximumIOps is Windows-only and not presently implemented.
+    #[serde(rename = "IOMaximumIOps", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_i_ops: Option<u64>,
+    /// Init indicates whether the container has an init mounted into it.
+    #[serde(rename = "Init", skip_serializing_if = "Option::is_none")]
+    pub init: Option<bool>,
+    /// IntelRdtClosID defines the Intel RDT CAT Class Of Service (COS) that all processes of the container should run in.
+    #[serde(rename = "IntelRdtClosID", skip_serializing_if = "Option::is_none")]
+    pub intel_rdt_clos_id: Option<String>,
+    /// IpcMode represents the configuration of the container's IPC namespace. Populated as follows: \"\" (empty string) - Default, an IPC namespace will be created host - No IPC namespace created container:<id> - Using another container's IPC namespace ns:<path> - A path to an IPC namespace has been specified
+    #[serde(rename = "IpcMode", skip_serializing_if = "Option::is_none")]
+    pub ipc_mode: Option<String>,
+    /// Isolation is presently unused and provided solely for Docker compatibility.
+    #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<String>,
+    /// KernelMemory is the maximum amount of memory the kernel will devote to the container.
+    #[serde(rename = "KernelMemory", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory: Option<i64>,
+    /// Links is unused, and provided purely for Docker compatibility.
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<String>>,
+    #[serde(rename = "LogConfig", skip_serializing_if = "Option::is_none")]
+    pub log_config: Option<Box<InspectLogConfig>>,
+    /// Memory indicates the memory resources allocated to the container. This is the limit (in bytes) of RAM the container may use.
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    /// MemoryReservation is the reservation (soft limit) of memory available to the container. Soft limits are warnings only and can be exceeded.
+    #[serde(rename = "MemoryReservation", skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    /// MemorySwap is the total limit for all memory available to the container, including swap. 0 indicates that there is no limit to the amount of memory available.
+    #[serde(rename = "MemorySwap", skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<i64>,
+    /// MemorySwappiness is the willingness of the kernel to page container memory to swap. It is an integer from 0 to 100, with low numbers being more likely to be put into swap. 1, the default, will not set swappiness and use the system defaults.
+    #[serde(rename = "MemorySwappiness", skip_serializing_if = "Option::is_none")]
+    pub memory_swappiness: Option<i64>,
+    /// NanoCpus indicates number of CPUs allocated to the container. It is an integer where one full CPU is indicated by 1000000000 (one billion). Thus, 2.5 CPUs (fractional portions of CPUs are allowed) would be 2500000000 (2.5 billion). In 'docker inspect' this is set exclusively of two further options in the output (CpuPeriod and CpuQuota) which are both used to implement this functionality. We can't distinguish here, so if CpuQuota is set to the default of 100000, we will set both CpuQuota, CpuPeriod, and NanoCpus. If CpuQuota is not the default, we will not set NanoCpus.
+    #[serde(rename = "NanoCpus", skip_serializing_if = "Option::is_none")]
+    pub nano_cpus: Option<i64>,
+    /// NetworkMode is the configuration of the container's network namespace. Populated as follows: default - A network namespace is being created and configured via CNI none - A network namespace is being created, not configured via CNI host - No network namespace created container:<id> - Using another container's network namespace ns:<path> - A path to a network namespace has been specified
+    #[serde(rename = "NetworkMode", skip_serializing_if = "Option::is_none")]
+    pub network_mode: Option<String>,
+    /// OomKillDisable indicates whether the kernel OOM killer is disabled for the container.
+    #[serde(rename = "OomKillDisable", skip_serializing_if = "Option::is_none")]
+    pub oom_kill_disable: Option<bool>,
+    /// OOMScoreAdj is an adjustment that will be made to the container's OOM score.
+    #[serde(rename = "OomScoreAdj", skip_serializing_if = "Option::is_none")]
+    pub oom_score_adj: Option<i64>,
+    /// PidMode represents the configuration of the container's PID namespace. Populated as follows: \"\" (empty string) - Default, a PID namespace will be created host - No PID namespace created container:<id> - Using another container's PID namespace ns:<path> - A path to a PID namespace has been specified
+    #[serde(rename = "PidMode", skip_serializing_if = "Option::is_none")]
+    pub pid_mode: Option<String>,
+    /// PidsLimit is the maximum number of PIDs that may be created within the container. 0, the default, indicates no limit.
+    #[serde(rename = "PidsLimit", skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+    /// PortBindings contains the container's port bindings. It is formatted as map[string][]InspectHostPort. The string key here is formatted as <integer port number>/<protocol> and represents the container port. A single container port may be bound to multiple host ports (on different IPs).
+    #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none")]
+    pub port_bindings: Option<std::collections::HashMap<String, Vec<InspectHostPort>>>,
+    /// Privileged indicates whether the container is running with elevated privileges. This has a very specific meaning in the Docker sense, so it's very difficult to decode from the spec and config, and so is stored as an annotation.
+    #[serde(rename = "Privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// PublishAllPorts indicates whether image ports are being published. This is not directly stored in libpod and is saved as an annotation.
+    #[serde(rename = "PublishAllPorts", skip_serializing_if = "Option::is_none")]
+    pub publish_all_ports: Option<bool>,
+    /// ReadonlyRootfs is whether the container will be mounted read-only.
+    #[serde(rename = "ReadonlyRootfs", skip_serializing_if = "Option::is_none")]
+    pub readonly_rootfs: Option<bool>,
+    #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<Box<InspectRestartPolicy>>,
+    /// Runtime is provided purely for Docker compatibility. It is set unconditionally to \"oci\" as Podman does not presently support non-OCI runtimes.
+    #[serde(rename = "Runtime", skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<String>,
+    /// SecurityOpt is a list of security-related options that are set in the container.
+    #[serde(rename = "SecurityOpt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+    #[serde(rename = "ShmSize", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    /// Tmpfs is a list of tmpfs filesystems that will be mounted into the container. It is a map of destination path to options for the mount.
+    #[serde(rename = "Tmpfs", skip_serializing_if = "Option::is_none")]
+    pub tmpfs: Option<std::collections::HashMap<String, String>>,
+    /// UTSMode represents the configuration of the container's UID namespace. Populated as follows: \"\" (empty string) - Default, a UTS namespace will be created host - no UTS namespace created container:<id> - Using another container's UTS namespace ns:<path> - A path to a UTS namespace has been specified
+    #[serde(rename = "UTSMode", skip_serializing_if = "Option::is_none")]
+    pub uts_mode: Option<String>,
+    /// Ulimits is a set of ulimits that will be set within the container.
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
+    pub ulimits: Option<Vec<InspectUlimit>>,
+    /// UsernsMode represents the configuration of the container's user namespace. When running rootless, a user namespace is created outside of libpod to allow some privileged operations. This will not be reflected here. Populated as follows: \"\" (empty string) - No user namespace will be created private - The container will be run in a user namespace container:<id> - Using another container's user namespace ns:<path> - A path to a user namespace has been specified TODO Rootless has an additional 'keep-id' option, presently not reflected here.
+    #[serde(rename = "UsernsMode", skip_serializing_if = "Option::is_none")]
+    pub userns_mode: Option<String>,
+    /// VolumeDriver is presently unused and is retained for Docker compatibility.
+    #[serde(rename = "VolumeDriver", skip_serializing_if = "Option::is_none")]
+    pub volume_driver: Option<String>,
+    /// VolumesFrom is a list of containers which this container uses volumes from. This is not handled directly within libpod and is stored in an annotation. It is formatted as an array of container names and IDs.
+    #[serde(rename = "VolumesFrom", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl InspectContainerHostConfig {
+    /// nolint:revive,stylecheck // Field names are fixed for compatibility and cannot be changed.
+    pub fn new() -> InspectContainerHostConfig {
+        InspectContainerHostConfig {
+            annotations: None,
+            auto_remove: None,
+            binds: None,
+            blkio_device_read_bps: None,
+            blkio_device_read_i_ops: None,
+            blkio_device_write_bps: None,
+            blkio_device_write_i_ops: None,
+            blkio_weight: None,
+            blkio_weight_device: None,
+            cap_add: None,
+            cap_drop: None,
+            cgroup: None,
+            cgroup_conf: None,
+            cgroup_manager: None,
+            cgroup_mode: None,
+            cgroup_parent: None,
+            cgroups: None,
+            console_size: None,
+            container_id_file: None,
+            cpu_count: None,
+            cpu_percent: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpu_realtime_period: None,
+            cpu_realtime_runtime: None,
+            cpu_shares: None,
+            cpuset_cpus: None,
+            cpuset_mems: None,
+            devices: None,
+            disk_quota: None,
+            dns: None,
+            dns_options: None,
+            dns_search: None,
+            extra_hosts: None,
+            group_add: None,
+            id_mappings: None,
+            io_maximum_bandwidth: None,
+            io_maximum_i_ops: None,
+            init: None,
+            intel_rdt_clos_id: None,
+            ipc_mode: None,
+            isolation: None,
+            kernel_memory: None,
+            links: None,
+            log_config: None,
+            memory: None,
+            memory_reservation: None,
+            memory_swap: None,
+            memory_swappiness: None,
+            nano_cpus: None,
+            network_mode: None,
+            oom_kill_disable: None,
+            oom_score_adj: None,
+            pid_mode: None,
+            pids_limit: None,
+            port_bindings: None,
+            privileged: None,
+            publish_all_ports: None,
+            readonly_rootfs: None,
+            restart_policy: None,
+            runtime: None,
+            security_opt: None,
+            shm_size: None,
+            tmpfs: None,
+            uts_mode: None,
+            ulimits: None,
+            userns_mode: None,
+            volume_driver: None,
+            volumes_from: None,
+        }
+    }
+}
+/// InspectContainerState : InspectContainerState provides a detailed record of a container's current state. It is returned as part of InspectContainerData. As with InspectContainerData, many portions of this struct are matched to Docker, but here we see more fields that are unused (nonsensical in the context of Libpod).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectContainerState {
+    #[serde(rename = "CgroupPath", skip_serializing_if = "Option::is_none")]
+    pub cgroup_path: Option<String>,
+    #[serde(rename = "CheckpointLog", skip_serializing_if = "Option::is_none")]
+    pub checkpoint_log: Option<String>,
+    #[serde(rename = "CheckpointPath", skip_serializing_if = "Option::is_none")]
+    pub checkpoint_path: Option<String>,
+    #[serde(rename = "Checkpointed", skip_serializing_if = "Option::is_none")]
+    pub checkpointed: Option<bool>,
+    #[serde(rename = "CheckpointedAt", skip_serializing_if = "Option::is_none")]
+    pub checkpointed_at: Option<String>,
+    #[serde(rename = "ConmonPid", skip_serializing_if = "Option::is_none")]
+    pub conmon_pid: Option<i64>,
+    #[serde(rename = "Dead", skip_serializing_if = "Option::is_none")]
+    pub dead: Option<bool>,
+    #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    #[serde(rename = "FinishedAt", skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    #[serde(rename = "Health", skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<HealthCheckResults>>,
+    #[serde(rename = "OOMKilled", skip_serializing_if = "Option::is_none")]
+    pub oom_killed: Option<bool>,
+    #[serde(rename = "OciVersion", skip_serializing_if = "Option::is_none")]
+    pub oci_version: Option<String>,
+    #[serde(rename = "Paused", skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<i64>,
+    #[serde(rename = "Restarting", skip_serializing_if = "Option::is_none")]
+    pub restarting: Option<bool>,
+    #[serde(rename = "RestoreLog", skip_serializing_if = "Option::is_none")]
+    pub restore_log: Option<String>,
+    #[serde(rename = "Restored", skip_serializing_if = "Option::is_none")]
+    pub restored: Option<bool>,
+    #[serde(rename = "RestoredAt", skip_serializing_if = "Option::is_none")]
+    pub restored_at: Option<String>,
+    #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
+    pub running: Option<bool>,
+    #[serde(rename = "StartedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(rename = "StoppedByUser", skip_serializing_if = "Option::is_none")]
+    pub stopped_by_user: Option<bool>,
+}
+impl InspectContainerState {
+    /// InspectContainerState provides a detailed record of a container's current state. It is returned as part of InspectContainerData. As with InspectContainerData, many portions of this struct are matched to Docker, but here we see more fields that are unused (nonsensical in the context of Libpod).
+    pub fn new() -> InspectContainerState {
+        InspectContainerState {
+            cgroup_path: None,
+            checkpoint_log: None,
+            checkpoint_path: None,
+            checkpointed: None,
+            checkpointed_at: None,
+            conmon_pid: None,
+            dead: None,
+            error: None,
+            exit_code: None,
+            finished_at: None,
+            health: None,
+            oom_killed: None,
+            oci_version: None,
+            paused: None,
+            pid: None,
+            restarting: None,
+            restore_log: None,
+            restored: None,
+            restored_at: None,
+            running: None,
+            started_at: None,
+            status: None,
+            stopped_by_user: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectDevice {
+    /// CgroupPermissions is the permissions of the mounted device. Presently not populated. TODO.
+    #[serde(rename = "CgroupPermissions", skip_serializing_if = "Option::is_none")]
+    pub cgroup_permissions: Option<String>,
+    /// PathInContainer is the path of the device within the container.
+    #[serde(rename = "PathInContainer", skip_serializing_if = "Option::is_none")]
+    pub path_in_container: Option<String>,
+    /// PathOnHost is the path of the device on the host.
+    #[serde(rename = "PathOnHost", skip_serializing_if = "Option::is_none")]
+    pub path_on_host: Option<String>,
+}
+impl InspectDevice {
+    pub fn new() -> InspectDevice {
+        InspectDevice {
+            cgroup_permissions: None,
+            path_in_container: None,
+            path_on_host: None,
+        }
+    }
+}
+/// InspectExecProcess : InspectExecProcess contains information about the process in a given exec session.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectExecProcess {
+    /// Arguments are the arguments to the entrypoint command of the exec session.
+    #[serde(rename = "arguments", skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<Vec<String>>,
+    /// Entrypoint is the entrypoint for the exec session (the command that will be executed in the container).
+    #[serde(rename = "entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<String>,
+    /// Privileged is whether the exec session will be started with elevated privileges.
+    #[serde(rename = "privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// Tty is whether the exec session created a terminal.
+    #[serde(rename = "tty", skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    /// User is the user the exec session was started as.
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+impl InspectExecProcess {
+    /// InspectExecProcess contains information about the process in a given exec session.
+    pub fn new() -> InspectExecProcess {
+        InspectExecProcess {
+            arguments: None,
+            entrypoint: None,
+            privileged: None,
+            tty: None,
+            user: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectExecSession {
+    /// CanRemove is legacy and used purely for compatibility reasons. Will always be set to true, unless the exec session is running.
+    #[serde(rename = "CanRemove", skip_serializing_if = "Option::is_none")]
+    pub can_remove: Option<bool>,
+    /// ContainerID is the ID of the container this exec session is attached to.
+    #[serde(rename = "ContainerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// DetachKeys are the detach keys used by the exec session. If set to \"\" the default keys are being used. Will show \"<none>\" if no detach keys are set.
+    #[serde(rename = "DetachKeys", skip_serializing_if = "Option::is_none")]
+    pub detach_keys: Option<String>,
+    /// ExitCode is the exit code of the exec session. Will be set to 0 if the exec session has not yet exited.
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// ID is the ID of the exec session.
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// OpenStderr is whether the container's STDERR stream will be attached. Always set to true if the exec session created a TTY.
+    #[serde(rename = "OpenStderr", skip_serializing_if = "Option::is_none")]
+    pub open_stderr: Option<bool>,
+    /// OpenStdin is whether the container's STDIN stream will be attached to.
+    #[serde(rename = "OpenStdin", skip_serializing_if = "Option::is_none")]
+    pub open_stdin: Option<bool>,
+    /// OpenStdout is whether the container's STDOUT stream will be attached. Always set to true if the exec session created a TTY.
+    #[serde(rename = "OpenStdout", skip_serializing_if = "Option::is_none")]
+    pub open_stdout: Option<bool>,
+    /// Pid is the PID of the exec session's process. Will be set to 0 if the exec session is not running.
+    #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<i64>,
+    #[serde(rename = "ProcessConfig", skip_serializing_if = "Option::is_none")]
+    pub process_config: Option<Box<InspectExecProcess>>,
+    /// Running is whether the exec session is running.
+    #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
+    pub running: Option<bool>,
+}
+impl InspectExecSession {
+    pub fn new() -> InspectExecSession {
+        InspectExecSession {
+            can_remove: None,
+            container_id: None,
+            detach_keys: None,
+            exit_code: None,
+            id: None,
+            open_stderr: None,
+            open_stdin: None,
+            open_stdout: None,
+            pid: None,
+            process_config: None,
+            running: None,
+        }
+    }
+}
+/// InspectHostPort : InspectHostPort provides information on a port on the host that a container's port is bound to.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectHostPort {
+    /// IP on the host we are bound to. \"\" if not specified (binding to all IPs).
+    #[serde(rename = "HostIp", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// Port on the host we are bound to. No special formatting - just an integer stuffed into a string.
+    #[serde(rename = "HostPort", skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<String>,
+}
+impl InspectHostPort {
+    /// InspectHostPort provides information on a port on the host that a container's port is bound to.
+    pub fn new() -> InspectHostPort {
+        InspectHostPort {
+            host_ip: None,
+            host_port: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectIDMappings {
+    #[serde(rename = "GidMap", skip_serializing_if = "Option::is_none")]
+    pub gid_map: Option<Vec<String>>,
+    #[serde(rename = "UidMap", skip_serializing_if = "Option::is_none")]
+    pub uid_map: Option<Vec<String>>,
+}
+impl InspectIDMappings {
+    pub fn new() -> InspectIDMappings {
+        InspectIDMappings {
+            gid_map: None,
+            uid_map: None,
+        }
+    }
+}
+/// InspectLogConfig : InspectLogConfig holds information about a container's configured log driver
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectLogConfig {
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<std::collections::HashMap<String, String>>,
+    /// Path specifies a path to the log file
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Size specifies a maximum size of the container log
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// Tag specifies a custom log tag for the container
+    #[serde(rename = "Tag", skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl InspectLogConfig {
+    /// InspectLogConfig holds information about a container's configured log driver
+    pub fn new() -> InspectLogConfig {
+        InspectLogConfig {
+            config: None,
+            path: None,
+            size: None,
+            tag: None,
+            r#type: None,
+        }
+    }
+}
+/// InspectMount : InspectMount provides a record of a single mount in a container. It contains fields for both named and normal volumes. Only user-specified volumes will be included, and tmpfs volumes are not included even if the user specified them.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectMount {
+    /// The destination directory for the volume. Specified as a path within the container, as it would be passed into the OCI runtime.
+    #[serde(rename = "Destination", skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// The driver used for the named volume. Empty for bind mounts.
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// Contains SELinux :z/:Z mount options. Unclear what, if anything, else goes in here.
+    #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The name of the volume. Empty for bind mounts.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// All remaining mount options. Additional data, not present in the original output.
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<String>>,
+    /// Mount propagation for the mount. Can be empty if not specified, but is always printed - no omitempty.
+    #[serde(rename = "Propagation", skip_serializing_if = "Option::is_none")]
+    pub propagation: Option<String>,
+    /// Whether the volume is read-write
+    #[serde(rename = "RW", skip_serializing_if = "Option::is_none")]
+    pub rw: Option<bool>,
+    /// The source directory for the volume.
+    #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Whether the mount is a volume or bind mount. Allowed values are \"volume\" and \"bind\".
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl InspectMount {
+    /// InspectMount provides a record of a single mount in a container. It contains fields for both named and normal volumes. Only user-specified volumes will be included, and tmpfs volumes are not included even if the user specified them.
+    pub fn new() -> InspectMount {
+        InspectMount {
+            destination: None,
+            driver: None,
+            mode: None,
+            name: None,
+            options: None,
+            propagation: None,
+            rw: None,
+            source: None,
+            r#type: None,
+        }
+    }
+}
+/// InspectNetworkSettings : InspectNetworkSettings holds information about the network settings of the container. Many fields are maintained only for compatibility with `docker inspect` and are unused within Libpod.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectNetworkSettings {
+    /// AdditionalMacAddresses is a set of additional MAC Addresses beyond the first. CNI may configure more than one interface for a single network, which can cause this.
+    #[serde(rename = "AdditionalMACAddresses", skip_serializing_if = "Option::is_none")]
+    pub additional_mac_addresses: Option<Vec<String>>,
+    #[serde(rename = "Bridge", skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
+    /// EndpointID is unused, maintained exclusively for compatibility.
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    /// Gateway is the IP address of the gateway this network will use.
+    #[serde(rename = "Gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    /// GlobalIPv6Address is the global-scope IPv6 Address for this network.
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_address: Option<String>,
+    /// GlobalIPv6PrefixLen is the length of the subnet mask of this network.
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_prefix_len: Option<i64>,
+    #[serde(rename = "HairpinMode", skip_serializing_if = "Option::is_none")]
+    pub hairpin_mode: Option<bool>,
+    /// IPAddress is the IP address for this network.
+    #[serde(rename = "IPAddress", skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// IPPrefixLen is the length of the subnet mask of this network.
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "Option::is_none")]
+    pub ip_prefix_len: Option<i64>,
+    /// IPv6Gateway is the IPv6 gateway this network will use.
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "Option::is_none")]
+    pub ipv6_gateway: Option<String>,
+    #[serde(rename = "LinkLocalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub link_local_ipv6_address: Option<String>,
+    #[serde(rename = "LinkLocalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub link_local_ipv6_prefix_len: Option<i64>,
+    /// MacAddress is the MAC address for the interface in this network.
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// Networks contains information on non-default networks this container has joined. It is a map of network name to network information.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, InspectAdditionalNetwork>>,
+    #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<std::collections::HashMap<String, Vec<InspectHostPort>>>,
+    #[serde(rename = "SandboxID", skip_serializing_if = "Option::is_none")]
+    pub sandbox_id: Option<String>,
+    #[serde(rename = "SandboxKey", skip_serializing_if = "Option::is_none")]
+    pub sandbox_key: Option<String>,
+    /// SecondaryIPAddresses is a list of extra IP Addresses that the container has been assigned in this network.
+    #[serde(rename = "SecondaryIPAddresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ip_addresses: Option<Vec<Address>>,
+    /// SecondaryIPv6Addresses is a list of extra IPv6 Addresses that the container has been assigned in this network.
+    #[serde(rename = "SecondaryIPv6Addresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ipv6_addresses: Option<Vec<Address>>,
+}
+impl InspectNetworkSettings {
+    /// InspectNetworkSettings holds information about the network settings of the container. Many fields are maintained only for compatibility with `docker inspect` and are unused within Libpod.
+    pub fn new() -> InspectNetworkSettings {
+        InspectNetworkSettings {
+            additional_mac_addresses: None,
+            bridge: None,
+            endpoint_id: None,
+            gateway: None,
+            global_ipv6_address: None,
+            global_ipv6_prefix_len: None,
+            hairpin_mode: None,
+            ip_address: None,
+            ip_prefix_len: None,
+            ipv6_gateway: None,
+            link_local_ipv6_address: None,
+            link_local_ipv6_prefix_len: None,
+            mac_address: None,
+            networks: None,
+            ports: None,
+            sandbox_id: None,
+            sandbox_key: None,
+            secondary_ip_addresses: None,
+            secondary_ipv6_addresses: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectPodContainerInfo {
+    /// ID is the ID of the container.
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name is the name of the container.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// State is the current status of the container.
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+impl InspectPodContainerInfo {
+    pub fn new() -> InspectPodContainerInfo {
+        InspectPodContainerInfo {
+            id: None,
+            name: None,
+            state: None,
+        }
+    }
+}
+/// InspectPodData : InspectPodData contains detailed information on a pod's configuration and state. It is used as the output of Inspect on pods.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectPodData {
+    /// CgroupParent is the parent of the pod's Cgroup.
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// CgroupPath is the path to the pod's Cgroup.
+    #[serde(rename = "CgroupPath", skip_serializing_if = "Option::is_none")]
+    pub cgroup_path: Option<String>,
+    /// Containers gives a brief summary of all containers in the pod and their current status.
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<Vec<InspectPodContainerInfo>>,
+    /// CreateCgroup is whether this pod will create its own Cgroup to group containers under.
+    #[serde(rename = "CreateCgroup", skip_serializing_if = "Option::is_none")]
+    pub create_cgroup: Option<bool>,
+    /// CreateCommand is the full command plus arguments of the process the container has been created with.
+    #[serde(rename = "CreateCommand", skip_serializing_if = "Option::is_none")]
+    pub create_command: Option<Vec<String>>,
+    /// CreateInfra is whether this pod will create an infra container to share namespaces.
+    #[serde(rename = "CreateInfra", skip_serializing_if = "Option::is_none")]
+    pub create_infra: Option<bool>,
+    /// Created is the time when the pod was created.
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// ExitPolicy of the pod.
+    #[serde(rename = "ExitPolicy", skip_serializing_if = "Option::is_none")]
+    pub exit_policy: Option<String>,
+    /// Hostname is the hostname that the pod will set.
+    #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// ID is the ID of the pod.
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "InfraConfig", skip_serializing_if = "Option::is_none")]
+    pub infra_config: Option<Box<InspectPodInfraConfig>>,
+    /// InfraContainerID is the ID of the pod's infra container, if one is present.
+    #[serde(rename = "InfraContainerID", skip_serializing_if = "Option::is_none")]
+    pub infra_container_id: Option<String>,
+    /// Labels is a set of key-value labels that have been applied to the pod.
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Number of the pod's Libpod lock.
+    #[serde(rename = "LockNumber", skip_serializing_if = "Option::is_none")]
+    pub lock_number: Option<u32>,
+    /// Name is the name of the pod.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace is the Libpod namespace the pod is placed in.
+    #[serde(rename = "Namespace", skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// NumContainers is the number of containers in the pod, including the infra container.
+    #[serde(rename = "NumContainers", skip_serializing_if = "Option::is_none")]
+    pub num_containers: Option<u64>,
+    /// RestartPolicy of the pod.
+    #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// SharedNamespaces contains a list of namespaces that will be shared by containers within the pod. Can only be set if CreateInfra is true.
+    #[serde(rename = "SharedNamespaces", skip_serializing_if = "Option::is_none")]
+    pub shared_namespaces: Option<Vec<String>>,
+    /// State represents the current state of the pod.
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// BlkioWeight contains the blkio weight limit for the pod
+    #[serde(rename = "blkio_weight", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u64>,
+    /// BlkioWeightDevice contains the blkio weight device limits for the pod
+    #[serde(rename = "blkio_weight_device", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<InspectBlkioWeightDevice>>,
+    /// CPUPeriod contains the CPU period of the pod
+    #[serde(rename = "cpu_period", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<u64>,
+    /// CPUQuota contains the CPU quota of the pod
+    #[serde(rename = "cpu_quota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    /// CPUShares contains the cpu shares for the pod
+    #[serde(rename = "cpu_shares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<u64>,
+    /// CPUSetCPUs contains linux specific CPU data for the pod
+    #[serde(rename = "cpuset_cpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    /// CPUSetMems contains linux specific CPU data for the pod
+    #[serde(rename = "cpuset_mems", skip_serializing_if = "Option::is_none")]
+    pub cpuset_mems: Option<String>,
+    /// BlkioDeviceReadBps contains the Read/Access limit for the pod's devices
+    #[serde(rename = "device_read_bps", skip_serializing_if = "Option::is_none")]
+    pub device_read_bps: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// BlkioDeviceReadBps contains the Read/Access limit for the pod's devices
+    #[serde(rename = "device_write_bps", skip_serializing_if = "Option::is_none")]
+    pub device_write_bps: Option<Vec<InspectBlkioThrottleDevice>>,
+    /// Devices contains the specified host devices
+    #[serde(rename = "devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<InspectDevice>>,
+    /// MemoryLimit contains the specified cgroup memory limit for the pod
+    #[serde(rename = "memory_limit", skip_serializing_if = "Option::is_none")]
+    pub memory_limit: Option<u64>,
+    /// MemorySwap contains the specified memory swap limit for the pod
+    #[serde(rename = "memory_swap", skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<u64>,
+    /// Mounts contains volume related information for the pod
+    #[serde(rename = "mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<InspectMount>>,
+    /// SecurityOpt contains the specified security labels and related SELinux information
+    #[serde(rename = "security_opt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+    /// VolumesFrom contains the containers that the pod inherits mounts from
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl InspectPodData {
+    /// InspectPodData contains detailed information on a pod's configuration and state. It is used as the output of Inspect on pods.
+    pub fn new() -> InspectPodData {
+        InspectPodData {
+            cgroup_parent: None,
+            cgroup_path: None,
+            containers: None,
+            create_cgroup: None,
+            create_command: None,
+            create_infra: None,
+            created: None,
+            exit_policy: None,
+            hostname: None,
+            id: None,
+            infra_config: None,
+            infra_container_id: None,
+            labels: None,
+            lock_number: None,
+            name: None,
+            namespace: None,
+            num_containers: None,
+            restart_policy: None,
+            shared_namespaces: None,
+            state: None,
+            blkio_weight: None,
+            blkio_weight_device: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpu_shares: None,
+            cpuset_cpus: None,
+            cpuset_mems: None,
+            device_read_bps: None,
+            device_write_bps: None,
+            devices: None,
+            memory_limit: None,
+            memory_swap: None,
+            mounts: None,
+            security_opt: None,
+            volumes_from: None,
+        }
+    }
+}
+/// InspectPodInfraConfig : InspectPodInfraConfig contains the configuration of the pod's infra container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectPodInfraConfig {
+    /// DNSOption is a set of DNS options that will be used by the infra container's resolv.conf and shared with the remainder of the pod.
+    #[serde(rename = "DNSOption", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    /// DNSSearch is a set of DNS search domains that will be used by the infra container's resolv.conf and shared with the remainder of the pod.
+    #[serde(rename = "DNSSearch", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// DNSServer is a set of DNS Servers that will be used by the infra container's resolv.conf and shared with the remainder of the pod.
+    #[serde(rename = "DNSServer", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    /// HostAdd adds a number of hosts to the infra container's resolv.conf which will be shared with the rest of the pod.
+    #[serde(rename = "HostAdd", skip_serializing_if = "Option::is_none")]
+    pub host_add: Option<Vec<String>>,
+    /// HostNetwork is whether the infra container (and thus the whole pod) will use the host's network and not create a network namespace.
+    #[serde(rename = "HostNetwork", skip_serializing_if = "Option::is_none")]
+    pub host_network: Option<bool>,
+    /// NetworkOptions are additional options for each network
+    #[serde(rename = "NetworkOptions", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    /// Networks is a list of networks the pod will join.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<String>>,
+    /// NoManageHosts indicates that the pod will not manage /etc/hosts and instead each container will handle their own.
+    #[serde(rename = "NoManageHosts", skip_serializing_if = "Option::is_none")]
+    pub no_manage_hosts: Option<bool>,
+    /// NoManageResolvConf indicates that the pod will not manage resolv.conf and instead each container will handle their own.
+    #[serde(rename = "NoManageResolvConf", skip_serializing_if = "Option::is_none")]
+    pub no_manage_resolv_conf: Option<bool>,
+    /// PortBindings are ports that will be forwarded to the infra container and then shared with the pod.
+    #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none")]
+    pub port_bindings: Option<std::collections::HashMap<String, Vec<InspectHostPort>>>,
+    /// StaticIP is a static IPv4 that will be assigned to the infra container and then used by the pod.
+    #[serde(rename = "StaticIP", skip_serializing_if = "Option::is_none")]
+    pub static_ip: Option<String>,
+    /// StaticMAC is a static MAC address that will be assigned to the infra container and then used by the pod.
+    #[serde(rename = "StaticMAC", skip_serializing_if = "Option::is_none")]
+    pub static_mac: Option<String>,
+    /// CPUPeriod contains the CPU period of the pod
+    #[serde(rename = "cpu_period", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<u64>,
+    /// CPUQuota contains the CPU quota of the pod
+    #[serde(rename = "cpu_quota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    /// CPUSetCPUs contains linux specific CPU data for the container
+    #[serde(rename = "cpuset_cpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    /// Pid is the PID namespace mode of the pod's infra container
+    #[serde(rename = "pid_ns", skip_serializing_if = "Option::is_none")]
+    pub pid_ns: Option<String>,
+    /// UserNS is the usernamespace that all the containers in the pod will join.
+    #[serde(rename = "userns", skip_serializing_if = "Option::is_none")]
+    pub userns: Option<String>,
+    /// UtsNS is the uts namespace that all containers in the pod will join
+    #[serde(rename = "uts_ns", skip_serializing_if = "Option::is_none")]
+    pub uts_ns: Option<String>,
+}
+impl InspectPodInfraConfig {
+    /// InspectPodInfraConfig contains the configuration of the pod's infra container.
+    pub fn new() -> InspectPodInfraConfig {
+        InspectPodInfraConfig {
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            host_add: None,
+            host_network: None,
+            network_options: None,
+            networks: None,
+            no_manage_hosts: None,
+            no_manage_resolv_conf: None,
+            port_bindings: None,
+            static_ip: None,
+            static_mac: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpuset_cpus: None,
+            pid_ns: None,
+            userns: None,
+            uts_ns: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectRestartPolicy {
+    /// MaximumRetryCount is the maximum number of retries allowed if the \"on-failure\" restart policy is in use. Not used if \"on-failure\" is not set.
+    #[serde(rename = "MaximumRetryCount", skip_serializing_if = "Option::is_none")]
+    pub maximum_retry_count: Option<u64>,
+    /// Name contains the container's restart policy. Allowable values are \"no\" or \"\" (take no action), \"on-failure\" (restart on non-zero exit code, with an optional max retry count), and \"always\" (always restart on container stop, unless explicitly requested by API). Note that this is NOT actually a name of any sort - the poor naming is for Docker compatibility.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl InspectRestartPolicy {
+    pub fn new() -> InspectRestartPolicy {
+        InspectRestartPolicy {
+            maximum_retry_count: None,
+            name: None,
+        }
+    }
+}
+/// InspectSecret : InspectSecret contains information on secrets mounted inside the container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectSecret {
+    /// ID is the GID of the mounted secret file
+    #[serde(rename = "GID", skip_serializing_if = "Option::is_none")]
+    pub gid: Option<u32>,
+    /// ID is the ID of the secret
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// ID is the ID of the mode of the mounted secret file
+    #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+    /// Name is the name of the secret
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// ID is the UID of the mounted secret file
+    #[serde(rename = "UID", skip_serializing_if = "Option::is_none")]
+    pub uid: Option<u32>,
+}
+impl InspectSecret {
+    /// InspectSecret contains information on secrets mounted inside the container
+    pub fn new() -> InspectSecret {
+        InspectSecret {
+            gid: None,
+            id: None,
+            mode: None,
+            name: None,
+            uid: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InspectUlimit {
+    /// Hard is the hard limit that will be applied.
+    #[serde(rename = "Hard", skip_serializing_if = "Option::is_none")]
+    pub hard: Option<i64>,
+    /// Name is the name (type) of the ulimit.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Soft is the soft limit that will be applied.
+    #[serde(rename = "Soft", skip_serializing_if = "Option::is_none")]
+    pub soft: Option<i64>,
+}
+impl InspectUlimit {
+    pub fn new() -> InspectUlimit {
+        InspectUlimit {
+            hard: None,
+            name: None,
+            soft: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IPNet {
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// See type [IPNet] and func [ParseCIDR] for details.
+    #[serde(rename = "Mask", skip_serializing_if = "Option::is_none")]
+    pub mask: Option<Vec<u32>>,
+}
+impl IPNet {
+    pub fn new() -> IPNet {
+        IPNet { ip: None, mask: None }
+    }
+}
+/// IPAM : IPAM represents IP Address Management
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IPAM {
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Vec<IPAMConfig>>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+}
+impl IPAM {
+    /// IPAM represents IP Address Management
+    pub fn new() -> IPAM {
+        IPAM {
+            config: None,
+            driver: None,
+            options: None,
+        }
+    }
+}
+/// IPAMConfig : IPAMConfig represents IPAM configurations
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IPAMConfig {
+    #[serde(rename = "AuxiliaryAddresses", skip_serializing_if = "Option::is_none")]
+    pub auxiliary_addresses: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(rename = "IPRange", skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    #[serde(rename = "Subnet", skip_serializing_if = "Option::is_none")]
+    pub subnet: Option<String>,
+}
+impl IPAMConfig {
+    /// IPAMConfig represents IPAM configurations
+    pub fn new() -> IPAMConfig {
+        IPAMConfig {
+            auxiliary_addresses: None,
+            gateway: None,
+            ip_range: None,
+            subnet: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LeaseRange {
+    /// EndIP last IP in the subnet which should be used to assign ips.
+    #[serde(rename = "end_ip", skip_serializing_if = "Option::is_none")]
+    pub end_ip: Option<String>,
+    /// StartIP first IP in the subnet which should be used to assign ips.
+    #[serde(rename = "start_ip", skip_serializing_if = "Option::is_none")]
+    pub start_ip: Option<String>,
+}
+impl LeaseRange {
+    pub fn new() -> LeaseRange {
+        LeaseRange {
+            end_ip: None,
+            start_ip: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LibpodContainersRmReport {
+    /// Error which occurred during Rm operation (if any). This field is optional and may be omitted if no error occurred.
+    #[serde(
+        rename = "Err",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub err: Option<Option<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl LibpodContainersRmReport {
+    pub fn new() -> LibpodContainersRmReport {
+        LibpodContainersRmReport {
+            err: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LibpodImageSummary {
+    /// Podman extensions
+    #[serde(rename = "Arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<i64>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    #[serde(rename = "Dangling", skip_serializing_if = "Option::is_none")]
+    pub dangling: Option<bool>,
+    #[serde(rename = "Digest", skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(rename = "History", skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// IsManifestList is a ptr so we can distinguish between a true json empty response and false.  the docker compat side needs to return empty; where as the libpod side needs a value of true or false
+    #[serde(rename = "IsManifestList", skip_serializing_if = "Option::is_none")]
+    pub is_manifest_list: Option<bool>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(rename = "Os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    #[serde(rename = "ParentId", skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(rename = "RepoDigests", skip_serializing_if = "Option::is_none")]
+    pub repo_digests: Option<Vec<String>>,
+    #[serde(rename = "RepoTags", skip_serializing_if = "Option::is_none")]
+    pub repo_tags: Option<Vec<String>>,
+    #[serde(rename = "SharedSize", skip_serializing_if = "Option::is_none")]
+    pub shared_size: Option<i64>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "VirtualSize", skip_serializing_if = "Option::is_none")]
+    pub virtual_size: Option<i64>,
+}
+impl LibpodImageSummary {
+    pub fn new() -> LibpodImageSummary {
+        LibpodImageSummary {
+            arch: None,
+            containers: None,
+            created: None,
+            dangling: None,
+            digest: None,
+            history: None,
+            id: None,
+            is_manifest_list: None,
+            labels: None,
+            names: None,
+            os: None,
+            parent_id: None,
+            read_only: None,
+            repo_digests: None,
+            repo_tags: None,
+            shared_size: None,
+            size: None,
+            virtual_size: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LibpodImagesPullReport {
+    /// Error contains text of errors from c/image
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// ID contains image id (retained for backwards compatibility)
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Images contains the ID's of the images pulled
+    #[serde(rename = "images", skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+    /// Stream used to provide output from c/image
+    #[serde(rename = "stream", skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+}
+impl LibpodImagesPullReport {
+    pub fn new() -> LibpodImagesPullReport {
+        LibpodImagesPullReport {
+            error: None,
+            id: None,
+            images: None,
+            stream: None,
+        }
+    }
+}
+/// LibpodImagesRemoveReport : LibpodImagesRemoveReport is the return type for image removal via the rest api.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LibpodImagesRemoveReport {
+    /// Deleted images.
+    #[serde(rename = "Deleted", skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<Vec<String>>,
+    /// Image removal requires is to return data and an error.
+    #[serde(rename = "Errors", skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<String>>,
+    /// ExitCode describes the exit codes as described in the `podman rmi` man page.
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// Untagged images. Can be longer than Deleted.
+    #[serde(rename = "Untagged", skip_serializing_if = "Option::is_none")]
+    pub untagged: Option<Vec<String>>,
+}
+impl LibpodImagesRemoveReport {
+    /// LibpodImagesRemoveReport is the return type for image removal via the rest api.
+    pub fn new() -> LibpodImagesRemoveReport {
+        LibpodImagesRemoveReport {
+            deleted: None,
+            errors: None,
+            exit_code: None,
+            untagged: None,
+        }
+    }
+}
+/// LibpodInfo : Info is the overall struct that describes the host system running libpod/podman
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LibpodInfo {
+    #[serde(rename = "host", skip_serializing_if = "Option::is_none")]
+    pub host: Option<Box<HostInfo>>,
+    #[serde(rename = "plugins", skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<Box<Plugins>>,
+    #[serde(rename = "registries", skip_serializing_if = "Option::is_none")]
+    pub registries: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "store", skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<StoreInfo>>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<Box<Version>>,
+}
+impl LibpodInfo {
+    /// Info is the overall struct that describes the host system running libpod/podman
+    pub fn new() -> LibpodInfo {
+        LibpodInfo {
+            host: None,
+            plugins: None,
+            registries: None,
+            store: None,
+            version: None,
+        }
+    }
+}
+/// LinuxBlockIO : LinuxBlockIO for Linux cgroup 'blkio' resource management
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxBlockIO {
+    /// Specifies tasks' weight in the given cgroup while competing with the cgroup's child cgroups, CFQ scheduler only
+    #[serde(rename = "leafWeight", skip_serializing_if = "Option::is_none")]
+    pub leaf_weight: Option<u32>,
+    /// IO read rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleReadBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_bps_device: Option<Vec<LinuxThrottleDevice>>,
+    /// IO read rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleReadIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_iops_device: Option<Vec<LinuxThrottleDevice>>,
+    /// IO write rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleWriteBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_bps_device: Option<Vec<LinuxThrottleDevice>>,
+    /// IO write rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleWriteIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_iops_device: Option<Vec<LinuxThrottleDevice>>,
+    /// Specifies per cgroup weight
+    #[serde(rename = "weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+    /// Weight per cgroup per device, can override BlkioWeight
+    #[serde(rename = "weightDevice", skip_serializing_if = "Option::is_none")]
+    pub weight_device: Option<Vec<LinuxWeightDevice>>,
+}
+impl LinuxBlockIO {
+    /// LinuxBlockIO for Linux cgroup 'blkio' resource management
+    pub fn new() -> LinuxBlockIO {
+        LinuxBlockIO {
+            leaf_weight: None,
+            throttle_read_bps_device: None,
+            throttle_read_iops_device: None,
+            throttle_write_bps_device: None,
+            throttle_write_iops_device: None,
+            weight: None,
+            weight_device: None,
+        }
+    }
+}
+/// LinuxBlockIODevice : LinuxBlockIODevice holds major:minor format supported in blkio cgroup
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxBlockIODevice {
+    /// Major is the device's major number.
+    #[serde(rename = "major", skip_serializing_if = "Option::is_none")]
+    pub major: Option<i64>,
+    /// Minor is the device's minor number.
+    #[serde(rename = "minor", skip_serializing_if = "Option::is_none")]
+    pub minor: Option<i64>,
+}
+impl LinuxBlockIODevice {
+    /// LinuxBlockIODevice holds major:minor format supported in blkio cgroup
+    pub fn new() -> LinuxBlockIODevice {
+        LinuxBlockIODevice {
+            major: None,
+            minor: None,
+        }
+    }
+}
+/// LinuxCPU : LinuxCPU for Linux cgroup 'cpu' resource management
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxCPU {
+    /// CPU hardcap burst limit (in usecs). Allowed accumulated cpu time additionally for burst in a given period.
+    #[serde(rename = "burst", skip_serializing_if = "Option::is_none")]
+    pub burst: Option<u64>,
+    /// CPUs to use within the cpuset. Default is to use any CPU available.
+    #[serde(rename = "cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<String>,
+    /// cgroups are configured with minimum weight, 0: default behavior, 1: SCHED_IDLE.
+    #[serde(rename = "idle", skip_serializing_if = "Option::is_none")]
+    pub idle: Option<i64>,
+    /// List of memory nodes in the cpuset. Default is to use any available memory node.
+    #[serde(rename = "mems", skip_serializing_if = "Option::is_none")]
+    pub mems: Option<String>,
+    /// CPU period to be used for hardcapping (in usecs).
+    #[serde(rename = "period", skip_serializing_if = "Option::is_none")]
+    pub period: Option<u64>,
+    /// CPU hardcap limit (in usecs). Allowed cpu time in a given period.
+    #[serde(rename = "quota", skip_serializing_if = "Option::is_none")]
+    pub quota: Option<i64>,
+    /// CPU period to be used for realtime scheduling (in usecs).
+    #[serde(rename = "realtimePeriod", skip_serializing_if = "Option::is_none")]
+    pub realtime_period: Option<u64>,
+    /// How much time realtime scheduling may use (in usecs).
+    #[serde(rename = "realtimeRuntime", skip_serializing_if = "Option::is_none")]
+    pub realtime_runtime: Option<i64>,
+    /// CPU shares (relative weight (ratio) vs. other cgroups with cpu shares).
+    #[serde(rename = "shares", skip_serializing_if = "Option::is_none")]
+    pub shares: Option<u64>,
+}
+impl LinuxCPU {
+    /// LinuxCPU for Linux cgroup 'cpu' resource management
+    pub fn new() -> LinuxCPU {
+        LinuxCPU {
+            burst: None,
+            cpus: None,
+            idle: None,
+            mems: None,
+            period: None,
+            quota: None,
+            realtime_period: None,
+            realtime_runtime: None,
+            shares: None,
+        }
+    }
+}
+/// LinuxDevice : LinuxDevice represents the mknod information for a Linux special device file
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxDevice {
+    /// The bits have the same definition on all systems, so that information about files can be moved from one system to another portably. Not all bits apply to all systems. The only required bit is [ModeDir] for directories.
+    #[serde(rename = "fileMode", skip_serializing_if = "Option::is_none")]
+    pub file_mode: Option<u32>,
+    /// Gid of the device.
+    #[serde(rename = "gid", skip_serializing_if = "Option::is_none")]
+    pub gid: Option<u32>,
+    /// Major is the device's major number.
+    #[serde(rename = "major", skip_serializing_if = "Option::is_none")]
+    pub major: Option<i64>,
+    /// Minor is the device's minor number.
+    #[serde(rename = "minor", skip_serializing_if = "Option::is_none")]
+    pub minor: Option<i64>,
+    /// Path to the device.
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Device type, block, char, etc.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// UID of the device.
+    #[serde(rename = "uid", skip_serializing_if = "Option::is_none")]
+    pub uid: Option<u32>,
+}
+impl LinuxDevice {
+    /// LinuxDevice represents the mknod information for a Linux special device file
+    pub fn new() -> LinuxDevice {
+        LinuxDevice {
+            file_mode: None,
+            gid: None,
+            major: None,
+            minor: None,
+            path: None,
+            r#type: None,
+            uid: None,
+        }
+    }
+}
+/// LinuxDeviceCgroup : LinuxDeviceCgroup represents a device rule for the devices specified to the device controller
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxDeviceCgroup {
+    /// Cgroup access permissions format, rwm.
+    #[serde(rename = "access", skip_serializing_if = "Option::is_none")]
+    pub access: Option<String>,
+    /// Allow or deny
+    #[serde(rename = "allow", skip_serializing_if = "Option::is_none")]
+    pub allow: Option<bool>,
+    /// Major is the device's major number.
+    #[serde(rename = "major", skip_serializing_if = "Option::is_none")]
+    pub major: Option<i64>,
+    /// Minor is the device's minor number.
+    #[serde(rename = "minor", skip_serializing_if = "Option::is_none")]
+    pub minor: Option<i64>,
+    /// Device type, block, char, etc.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl LinuxDeviceCgroup {
+    /// LinuxDeviceCgroup represents a device rule for the devices specified to the device controller
+    pub fn new() -> LinuxDeviceCgroup {
+        LinuxDeviceCgroup {
+            access: None,
+            allow: None,
+            major: None,
+            minor: None,
+            r#type: None,
+        }
+    }
+}
+/// LinuxHugepageLimit : Default to reservation limits if supported. Otherwise fallback to page fault limits.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxHugepageLimit {
+    /// Limit is the limit of \"hugepagesize\" hugetlb reservations (if supported) or usage.
+    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// Pagesize is the hugepage size. Format: \"<size><unit-prefix>B' (e.g. 64KB, 2MB, 1GB, etc.).
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<String>,
+}
+impl LinuxHugepageLimit {
+    /// Default to reservation limits if supported. Otherwise fallback to page fault limits.
+    pub fn new() -> LinuxHugepageLimit {
+        LinuxHugepageLimit {
+            limit: None,
+            page_size: None,
+        }
+    }
+}
+/// LinuxIDMapping : LinuxIDMapping specifies UID/GID mappings
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxIDMapping {
+    /// ContainerID is the starting UID/GID in the container
+    #[serde(rename = "containerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<u32>,
+    /// HostID is the starting UID/GID on the host to be mapped to 'ContainerID'
+    #[serde(rename = "hostID", skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<u32>,
+    /// Size is the number of IDs to be mapped
+    #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+impl LinuxIDMapping {
+    /// LinuxIDMapping specifies UID/GID mappings
+    pub fn new() -> LinuxIDMapping {
+        LinuxIDMapping {
+            container_id: None,
+            host_id: None,
+            size: None,
+        }
+    }
+}
+/// LinuxIntelRdt : LinuxIntelRdt has container runtime resource constraints for Intel RDT CAT and MBA features and flags enabling Intel RDT CMT and MBM features. Intel RDT features are available in Linux 4.14 and newer kernel versions.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxIntelRdt {
+    /// The identity for RDT Class of Service
+    #[serde(rename = "closID", skip_serializing_if = "Option::is_none")]
+    pub clos_id: Option<String>,
+    /// EnableCMT is the flag to indicate if the Intel RDT CMT is enabled. CMT (Cache Monitoring Technology) supports monitoring of the last-level cache (LLC) occupancy for the container.
+    #[serde(rename = "enableCMT", skip_serializing_if = "Option::is_none")]
+    pub enable_cmt: Option<bool>,
+    /// EnableMBM is the flag to indicate if the Intel RDT MBM is enabled. MBM (Memory Bandwidth Monitoring) supports monitoring of total and local memory bandwidth for the container.
+    #[serde(rename = "enableMBM", skip_serializing_if = "Option::is_none")]
+    pub enable_mbm: Option<bool>,
+    /// The schema for L3 cache id and capacity bitmask (CBM) Format: \"L3:<cache_id0>=<cbm0>;<cache_id1>=<cbm1>;...\"
+    #[serde(rename = "l3CacheSchema", skip_serializing_if = "Option::is_none")]
+    pub l3_cache_schema: Option<String>,
+    /// The schema of memory bandwidth per L3 cache id Format: \"MB:<cache_id0>=bandwidth0;<cache_id1>=bandwidth1;...\" The unit of memory bandwidth is specified in \"percentages\" by default, and in \"MBps\" if MBA Software Controller is enabled.
+    #[serde(rename = "memBwSchema", skip_serializing_if = "Option::is_none")]
+    pub mem_bw_schema: Option<String>,
+}
+impl LinuxIntelRdt {
+    /// LinuxIntelRdt has container runtime resource constraints for Intel RDT CAT and MBA features and flags enabling Intel RDT CMT and MBM features. Intel RDT features are available in Linux 4.14 and newer kernel versions.
+    pub fn new() -> LinuxIntelRdt {
+        LinuxIntelRdt {
+            clos_id: None,
+            enable_cmt: None,
+            enable_mbm: None,
+            l3_cache_schema: None,
+            mem_bw_schema: None,
+        }
+    }
+}
+/// LinuxInterfacePriority : LinuxInterfacePriority for network interfaces
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxInterfacePriority {
+    /// Name is the name of the network interface
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Priority for the interface
+    #[serde(rename = "priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+}
+impl LinuxInterfacePriority {
+    /// LinuxInterfacePriority for network interfaces
+    pub fn new() -> LinuxInterfacePriority {
+        LinuxInterfacePriority {
+            name: None,
+            priority: None,
+        }
+    }
+}
+/// LinuxMemory : LinuxMemory for Linux cgroup 'memory' resource management
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxMemory {
+    /// CheckBeforeUpdate enables checking if a new memory limit is lower than the current usage during update, and if so, rejecting the new limit.
+    #[serde(rename = "checkBeforeUpdate", skip_serializing_if = "Option::is_none")]
+    pub check_before_update: Option<bool>,
+    /// DisableOOMKiller disables the OOM killer for out of memory conditions
+    #[serde(rename = "disableOOMKiller", skip_serializing_if = "Option::is_none")]
+    pub disable_oom_killer: Option<bool>,
+    /// Kernel memory limit (in bytes).  Deprecated: kernel-memory limits are not supported in cgroups v2, and were obsoleted in [kernel v5.4]. This field should no longer be used, as it may be ignored by runtimes.  [kernel v5.4]: https://github.com/torvalds/linux/commit/0158115f702b0ba208ab0
+    #[serde(rename = "kernel", skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<i64>,
+    /// Kernel memory limit for tcp (in bytes)
+    #[serde(rename = "kernelTCP", skip_serializing_if = "Option::is_none")]
+    pub kernel_tcp: Option<i64>,
+    /// Memory limit (in bytes).
+    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Memory reservation or soft_limit (in bytes).
+    #[serde(rename = "reservation", skip_serializing_if = "Option::is_none")]
+    pub reservation: Option<i64>,
+    /// Total memory limit (memory + swap).
+    #[serde(rename = "swap", skip_serializing_if = "Option::is_none")]
+    pub swap: Option<i64>,
+    /// How aggressive the kernel will swap memory pages.
+    #[serde(rename = "swappiness", skip_serializing_if = "Option::is_none")]
+    pub swappiness: Option<u64>,
+    /// Enables hierarchical memory accounting
+    #[serde(rename = "useHierarchy", skip_serializing_if = "Option::is_none")]
+    pub use_hierarchy: Option<bool>,
+}
+impl LinuxMemory {
+    /// LinuxMemory for Linux cgroup 'memory' resource management
+    pub fn new() -> LinuxMemory {
+        LinuxMemory {
+            check_before_update: None,
+            disable_oom_killer: None,
+            kernel: None,
+            kernel_tcp: None,
+            limit: None,
+            reservation: None,
+            swap: None,
+            swappiness: None,
+            use_hierarchy: None,
+        }
+    }
+}
+/// LinuxNetwork : LinuxNetwork identification and priority configuration
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxNetwork {
+    /// Set class identifier for container's network packets
+    #[serde(rename = "classID", skip_serializing_if = "Option::is_none")]
+    pub class_id: Option<u32>,
+    /// Set priority of network traffic for container
+    #[serde(rename = "priorities", skip_serializing_if = "Option::is_none")]
+    pub priorities: Option<Vec<LinuxInterfacePriority>>,
+}
+impl LinuxNetwork {
+    /// LinuxNetwork identification and priority configuration
+    pub fn new() -> LinuxNetwork {
+        LinuxNetwork {
+            class_id: None,
+            priorities: None,
+        }
+    }
+}
+/// LinuxPersonality : LinuxPersonality represents the Linux personality syscall input
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxPersonality {
+    #[serde(rename = "domain", skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Additional flags
+    #[serde(rename = "flags", skip_serializing_if = "Option::is_none")]
+    pub flags: Option<Vec<String>>,
+}
+impl LinuxPersonality {
+    /// LinuxPersonality represents the Linux personality syscall input
+    pub fn new() -> LinuxPersonality {
+        LinuxPersonality {
+            domain: None,
+            flags: None,
+        }
+    }
+}
+/// LinuxPids : LinuxPids for Linux cgroup 'pids' resource management (Linux 4.3)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxPids {
+    /// Maximum number of PIDs. Default is \"no limit\".
+    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+impl LinuxPids {
+    /// LinuxPids for Linux cgroup 'pids' resource management (Linux 4.3)
+    pub fn new() -> LinuxPids {
+        LinuxPids { limit: None }
+    }
+}
+/// LinuxRdma : LinuxRdma for Linux cgroup 'rdma' resource management (Linux 4.11)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxRdma {
+    /// Maximum number of HCA handles that can be opened. Default is \"no limit\".
+    #[serde(rename = "hcaHandles", skip_serializing_if = "Option::is_none")]
+    pub hca_handles: Option<u32>,
+    /// Maximum number of HCA objects that can be created. Default is \"no limit\".
+    #[serde(rename = "hcaObjects", skip_serializing_if = "Option::is_none")]
+    pub hca_objects: Option<u32>,
+}
+impl LinuxRdma {
+    /// LinuxRdma for Linux cgroup 'rdma' resource management (Linux 4.11)
+    pub fn new() -> LinuxRdma {
+        LinuxRdma {
+            hca_handles: None,
+            hca_objects: None,
+        }
+    }
+}
+/// LinuxResources : LinuxResources has container runtime resource constraints
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxResources {
+    #[serde(rename = "blockIO", skip_serializing_if = "Option::is_none")]
+    pub block_io: Option<Box<LinuxBlockIO>>,
+    #[serde(rename = "cpu", skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<Box<LinuxCPU>>,
+    /// Devices configures the device allowlist.
+    #[serde(rename = "devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<LinuxDeviceCgroup>>,
+    /// Hugetlb limits (in bytes). Default to reservation limits if supported.
+    #[serde(rename = "hugepageLimits", skip_serializing_if = "Option::is_none")]
+    pub hugepage_limits: Option<Vec<LinuxHugepageLimit>>,
+    #[serde(rename = "memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Box<LinuxMemory>>,
+    #[serde(rename = "network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<Box<LinuxNetwork>>,
+    #[serde(rename = "pids", skip_serializing_if = "Option::is_none")]
+    pub pids: Option<Box<LinuxPids>>,
+    /// Rdma resource restriction configuration. Limits are a set of key value pairs that define RDMA resource limits, where the key is device name and value is resource limits.
+    #[serde(rename = "rdma", skip_serializing_if = "Option::is_none")]
+    pub rdma: Option<std::collections::HashMap<String, LinuxRdma>>,
+    /// Unified resources.
+    #[serde(rename = "unified", skip_serializing_if = "Option::is_none")]
+    pub unified: Option<std::collections::HashMap<String, String>>,
+}
+impl LinuxResources {
+    /// LinuxResources has container runtime resource constraints
+    pub fn new() -> LinuxResources {
+        LinuxResources {
+            block_io: None,
+            cpu: None,
+            devices: None,
+            hugepage_limits: None,
+            memory: None,
+            network: None,
+            pids: None,
+            rdma: None,
+            unified: None,
+        }
+    }
+}
+/// LinuxThrottleDevice : LinuxThrottleDevice struct holds a `major:minor rate_per_second` pair
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxThrottleDevice {
+    /// Major is the device's major number.
+    #[serde(rename = "major", skip_serializing_if = "Option::is_none")]
+    pub major: Option<i64>,
+    /// Minor is the device's minor number.
+    #[serde(rename = "minor", skip_serializing_if = "Option::is_none")]
+    pub minor: Option<i64>,
+    /// Rate is the IO rate limit per cgroup per device
+    #[serde(rename = "rate", skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+impl LinuxThrottleDevice {
+    /// LinuxThrottleDevice struct holds a `major:minor rate_per_second` pair
+    pub fn new() -> LinuxThrottleDevice {
+        LinuxThrottleDevice {
+            major: None,
+            minor: None,
+            rate: None,
+        }
+    }
+}
+/// LinuxWeightDevice : LinuxWeightDevice struct holds a `major:minor weight` pair for weightDevice
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinuxWeightDevice {
+    /// LeafWeight is the bandwidth rate for the device while competing with the cgroup's child cgroups, CFQ scheduler only
+    #[serde(rename = "leafWeight", skip_serializing_if = "Option::is_none")]
+    pub leaf_weight: Option<u32>,
+    /// Major is the device's major number.
+    #[serde(rename = "major", skip_serializing_if = "Option::is_none")]
+    pub major: Option<i64>,
+    /// Minor is the device's minor number.
+    #[serde(rename = "minor", skip_serializing_if = "Option::is_none")]
+    pub minor: Option<i64>,
+    /// Weight is the bandwidth rate for the device.
+    #[serde(rename = "weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
+impl LinuxWeightDevice {
+    /// LinuxWeightDevice struct holds a `major:minor weight` pair for weightDevice
+    pub fn new() -> LinuxWeightDevice {
+        LinuxWeightDevice {
+            leaf_weight: None,
+            major: None,
+            minor: None,
+            weight: None,
+        }
+    }
+}
+/// ListContainer : ListContainer describes a container suitable for listing
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListContainer {
+    /// AutoRemove
+    #[serde(rename = "AutoRemove", skip_serializing_if = "Option::is_none")]
+    pub auto_remove: Option<bool>,
+    /// CIDFile specified at creation time.
+    #[serde(rename = "CIDFile", skip_serializing_if = "Option::is_none")]
+    pub cid_file: Option<String>,
+    /// Container command
+    #[serde(rename = "Command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// Container creation time
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Human-readable container creation time.
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// If container has exited, the return code from the command
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// If container has exited/stopped
+    #[serde(rename = "Exited", skip_serializing_if = "Option::is_none")]
+    pub exited: Option<bool>,
+    /// Time container exited
+    #[serde(rename = "ExitedAt", skip_serializing_if = "Option::is_none")]
+    pub exited_at: Option<i64>,
+    /// ExposedPorts contains the ports that are exposed but not forwarded, see Ports for forwarded ports. The key is the port number and the string slice contains the protocols, i.e. \"tcp\", \"udp\" and \"sctp\".
+    #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none")]
+    pub exposed_ports: Option<serde_json::Value>,
+    /// The unique identifier for the container
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Container image
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Container image ID
+    #[serde(rename = "ImageID", skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    /// If this container is a Pod infra container
+    #[serde(rename = "IsInfra", skip_serializing_if = "Option::is_none")]
+    pub is_infra: Option<bool>,
+    /// Labels for container
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// User volume mounts
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<String>>,
+    /// The names assigned to the container
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(rename = "Namespaces", skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Box<ListContainerNamespaces>>,
+    /// The network names assigned to the container
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<String>>,
+    /// The process id of the container
+    #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<i64>,
+    /// If the container is part of Pod, the Pod ID. Requires the pod boolean to be set
+    #[serde(rename = "Pod", skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    /// If the container is part of Pod, the Pod name. Requires the pod boolean to be set
+    #[serde(rename = "PodName", skip_serializing_if = "Option::is_none")]
+    pub pod_name: Option<String>,
+    /// Port mappings
+    #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<PortMapping>>,
+    /// Restarts is how many times the container was restarted by its restart policy. This is NOT incremented by normal container restarts (only by restart policy).
+    #[serde(rename = "Restarts", skip_serializing_if = "Option::is_none")]
+    pub restarts: Option<u64>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<Box<ContainerSize>>,
+    /// Time when container started
+    #[serde(rename = "StartedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<i64>,
+    /// State of container
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Status is a human-readable approximation of a duration for json output
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl ListContainer {
+    /// ListContainer describes a container suitable for listing
+    pub fn new() -> ListContainer {
+        ListContainer {
+            auto_remove: None,
+            cid_file: None,
+            command: None,
+            created: None,
+            created_at: None,
+            exit_code: None,
+            exited: None,
+            exited_at: None,
+            exposed_ports: None,
+            id: None,
+            image: None,
+            image_id: None,
+            is_infra: None,
+            labels: None,
+            mounts: None,
+            names: None,
+            namespaces: None,
+            networks: None,
+            pid: None,
+            pod: None,
+            pod_name: None,
+            ports: None,
+            restarts: None,
+            size: None,
+            started_at: None,
+            state: None,
+            status: None,
+        }
+    }
+}
+/// ListContainerNamespaces : ListContainerNamespaces contains the identifiers of the container's Linux namespaces
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListContainerNamespaces {
+    /// Cgroup namespace
+    #[serde(rename = "Cgroup", skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// IPC namespace
+    #[serde(rename = "Ipc", skip_serializing_if = "Option::is_none")]
+    pub ipc: Option<String>,
+    /// Mount namespace
+    #[serde(rename = "Mnt", skip_serializing_if = "Option::is_none")]
+    pub mnt: Option<String>,
+    /// Network namespace
+    #[serde(rename = "Net", skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+    /// PID namespace
+    #[serde(rename = "Pidns", skip_serializing_if = "Option::is_none")]
+    pub pidns: Option<String>,
+    /// User namespace
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// UTS namespace
+    #[serde(rename = "Uts", skip_serializing_if = "Option::is_none")]
+    pub uts: Option<String>,
+}
+impl ListContainerNamespaces {
+    /// ListContainerNamespaces contains the identifiers of the container's Linux namespaces
+    pub fn new() -> ListContainerNamespaces {
+        ListContainerNamespaces {
+            cgroup: None,
+            ipc: None,
+            mnt: None,
+            net: None,
+            pidns: None,
+            user: None,
+            uts: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListPodContainer {
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<String>,
+    #[serde(rename = "RestartCount", skip_serializing_if = "Option::is_none")]
+    pub restart_count: Option<u64>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl ListPodContainer {
+    pub fn new() -> ListPodContainer {
+        ListPodContainer {
+            id: None,
+            names: None,
+            restart_count: None,
+            status: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListPodsReport {
+    #[serde(rename = "Cgroup", skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<Vec<ListPodContainer>>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "InfraId", skip_serializing_if = "Option::is_none")]
+    pub infra_id: Option<String>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Namespace", skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Network names connected to infra container
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<String>>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl ListPodsReport {
+    pub fn new() -> ListPodsReport {
+        ListPodsReport {
+            cgroup: None,
+            containers: None,
+            created: None,
+            id: None,
+            infra_id: None,
+            labels: None,
+            name: None,
+            namespace: None,
+            networks: None,
+            status: None,
+        }
+    }
+}
+/// ListResponse : Volume list response
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListResponse {
+    /// List of volumes
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<Volume>>,
+    /// Warnings that occurred when fetching the list of volumes.
+    #[serde(rename = "Warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+impl ListResponse {
+    /// Volume list response
+    pub fn new() -> ListResponse {
+        ListResponse {
+            volumes: None,
+            warnings: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogConfig {
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl LogConfig {
+    pub fn new() -> LogConfig {
+        LogConfig {
+            config: None,
+            r#type: None,
+        }
+    }
+}
+/// LogConfigLibpod : LogConfig describes the logging characteristics for a container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogConfigLibpod {
+    /// LogDriver is the container's log driver. Optional.
+    #[serde(rename = "driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// A set of options to accompany the log driver. Optional.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    /// LogPath is the path the container's logs will be stored at. Only available if LogDriver is set to \"json-file\" or \"k8s-file\". Optional.
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Size is the maximum size of the log file Optional.
+    #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+impl LogConfigLibpod {
+    /// LogConfig describes the logging characteristics for a container
+    pub fn new() -> LogConfigLibpod {
+        LogConfigLibpod {
+            driver: None,
+            options: None,
+            path: None,
+            size: None,
+        }
+    }
+}
+/// ManifestAddArtifactOptions : ManifestAddArtifactOptions provides the model for creating artifact manifests for files and adding those manifests to a manifest list
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestAddArtifactOptions {
+    /// Annotation to add to the item in the manifest list
+    #[serde(rename = "annotation", skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<Vec<String>>,
+    /// Annotations to add to the item in the manifest list by a map which is preferred over Annotation
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Arch overrides the architecture for the item in the manifest list
+    #[serde(rename = "arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "artifact_annotations", skip_serializing_if = "Option::is_none")]
+    pub artifact_annotations: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "artifact_config", skip_serializing_if = "Option::is_none")]
+    pub artifact_config: Option<String>,
+    #[serde(rename = "artifact_config_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_config_type: Option<String>,
+    #[serde(rename = "artifact_exclude_titles", skip_serializing_if = "Option::is_none")]
+    pub artifact_exclude_titles: Option<bool>,
+    #[serde(rename = "artifact_files", skip_serializing_if = "Option::is_none")]
+    pub artifact_files: Option<Vec<String>>,
+    #[serde(rename = "artifact_layer_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_layer_type: Option<String>,
+    #[serde(rename = "artifact_subject", skip_serializing_if = "Option::is_none")]
+    pub artifact_subject: Option<String>,
+    /// Note to future maintainers: keep these fields synchronized with ManifestModifyOptions!
+    #[serde(rename = "artifact_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_type: Option<String>,
+    /// Feature list for the item in the manifest list
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    /// IndexAnnotation is a slice of key=value annotations to add to the manifest list itself
+    #[serde(rename = "index_annotation", skip_serializing_if = "Option::is_none")]
+    pub index_annotation: Option<Vec<String>>,
+    /// IndexAnnotations is a map of key:value annotations to add to the manifest list itself, by a map which is preferred over IndexAnnotation
+    #[serde(rename = "index_annotations", skip_serializing_if = "Option::is_none")]
+    pub index_annotations: Option<std::collections::HashMap<String, String>>,
+    /// OS overrides the operating system for the item in the manifest list
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OS features for the item in the manifest list
+    #[serde(rename = "os_features", skip_serializing_if = "Option::is_none")]
+    pub os_features: Option<Vec<String>>,
+    /// OSVersion overrides the operating system for the item in the manifest list
+    #[serde(rename = "os_version", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// IndexSubject is a subject value to set in the manifest list itself
+    #[serde(rename = "subject", skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Variant for the item in the manifest list
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl ManifestAddArtifactOptions {
+    /// ManifestAddArtifactOptions provides the model for creating artifact manifests for files and adding those manifests to a manifest list
+    pub fn new() -> ManifestAddArtifactOptions {
+        ManifestAddArtifactOptions {
+            annotation: None,
+            annotations: None,
+            arch: None,
+            artifact_annotations: None,
+            artifact_config: None,
+            artifact_config_type: None,
+            artifact_exclude_titles: None,
+            artifact_files: None,
+            artifact_layer_type: None,
+            artifact_subject: None,
+            artifact_type: None,
+            features: None,
+            index_annotation: None,
+            index_annotations: None,
+            os: None,
+            os_features: None,
+            os_version: None,
+            subject: None,
+            variant: None,
+        }
+    }
+}
+/// ManifestAddOptions : ManifestAddOptions provides model for adding digests to manifest list
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestAddOptions {
+    /// True when operating on a list to include all images
+    #[serde(rename = "all", skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    /// Annotation to add to the item in the manifest list
+    #[serde(rename = "annotation", skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<Vec<String>>,
+    /// Annotations to add to the item in the manifest list by a map which is preferred over Annotation
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Arch overrides the architecture for the item in the manifest list
+    #[serde(rename = "arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    /// Feature list for the item in the manifest list
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    /// Images is an optional list of image references to add to manifest list
+    #[serde(rename = "images", skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+    /// IndexAnnotation is a slice of key=value annotations to add to the manifest list itself
+    #[serde(rename = "index_annotation", skip_serializing_if = "Option::is_none")]
+    pub index_annotation: Option<Vec<String>>,
+    /// IndexAnnotations is a map of key:value annotations to add to the manifest list itself, by a map which is preferred over IndexAnnotation
+    #[serde(rename = "index_annotations", skip_serializing_if = "Option::is_none")]
+    pub index_annotations: Option<std::collections::HashMap<String, String>>,
+    /// OS overrides the operating system for the item in the manifest list
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OS features for the item in the manifest list
+    #[serde(rename = "os_features", skip_serializing_if = "Option::is_none")]
+    pub os_features: Option<Vec<String>>,
+    /// OSVersion overrides the operating system for the item in the manifest list
+    #[serde(rename = "os_version", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// IndexSubject is a subject value to set in the manifest list itself
+    #[serde(rename = "subject", skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Variant for the item in the manifest list
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl ManifestAddOptions {
+    /// ManifestAddOptions provides model for adding digests to manifest list
+    pub fn new() -> ManifestAddOptions {
+        ManifestAddOptions {
+            all: None,
+            annotation: None,
+            annotations: None,
+            arch: None,
+            features: None,
+            images: None,
+            index_annotation: None,
+            index_annotations: None,
+            os: None,
+            os_features: None,
+            os_version: None,
+            subject: None,
+            variant: None,
+        }
+    }
+}
+/// ManifestAnnotateOptions : ManifestAnnotateOptions provides model for annotating manifest list
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestAnnotateOptions {
+    /// Annotation to add to the item in the manifest list
+    #[serde(rename = "annotation", skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<Vec<String>>,
+    /// Annotations to add to the item in the manifest list by a map which is preferred over Annotation
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Arch overrides the architecture for the item in the manifest list
+    #[serde(rename = "arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    /// Feature list for the item in the manifest list
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    /// IndexAnnotation is a slice of key=value annotations to add to the manifest list itself
+    #[serde(rename = "index_annotation", skip_serializing_if = "Option::is_none")]
+    pub index_annotation: Option<Vec<String>>,
+    /// IndexAnnotations is a map of key:value annotations to add to the manifest list itself, by a map which is preferred over IndexAnnotation
+    #[serde(rename = "index_annotations", skip_serializing_if = "Option::is_none")]
+    pub index_annotations: Option<std::collections::HashMap<String, String>>,
+    /// OS overrides the operating system for the item in the manifest list
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OS features for the item in the manifest list
+    #[serde(rename = "os_features", skip_serializing_if = "Option::is_none")]
+    pub os_features: Option<Vec<String>>,
+    /// OSVersion overrides the operating system for the item in the manifest list
+    #[serde(rename = "os_version", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// IndexSubject is a subject value to set in the manifest list itself
+    #[serde(rename = "subject", skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Variant for the item in the manifest list
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl ManifestAnnotateOptions {
+    /// ManifestAnnotateOptions provides model for annotating manifest list
+    pub fn new() -> ManifestAnnotateOptions {
+        ManifestAnnotateOptions {
+            annotation: None,
+            annotations: None,
+            arch: None,
+            features: None,
+            index_annotation: None,
+            index_annotations: None,
+            os: None,
+            os_features: None,
+            os_version: None,
+            subject: None,
+            variant: None,
+        }
+    }
+}
+/// ManifestModifyOptions : swagger 2.0 does not support oneOf for schema validation.  Operation \"update\" uses all fields. Operation \"remove\" uses fields: Operation and Images Operation \"annotate\" uses fields: Operation and Annotations
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestModifyOptions {
+    /// True when operating on a list to include all images
+    #[serde(rename = "all", skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    /// Annotation to add to the item in the manifest list
+    #[serde(rename = "annotation", skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<Vec<String>>,
+    /// Annotations to add to the item in the manifest list by a map which is preferred over Annotation
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// Arch overrides the architecture for the item in the manifest list
+    #[serde(rename = "arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "artifact_annotations", skip_serializing_if = "Option::is_none")]
+    pub artifact_annotations: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "artifact_config", skip_serializing_if = "Option::is_none")]
+    pub artifact_config: Option<String>,
+    #[serde(rename = "artifact_config_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_config_type: Option<String>,
+    #[serde(rename = "artifact_exclude_titles", skip_serializing_if = "Option::is_none")]
+    pub artifact_exclude_titles: Option<bool>,
+    #[serde(rename = "artifact_files", skip_serializing_if = "Option::is_none")]
+    pub artifact_files: Option<Vec<String>>,
+    #[serde(rename = "artifact_layer_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_layer_type: Option<String>,
+    #[serde(rename = "artifact_subject", skip_serializing_if = "Option::is_none")]
+    pub artifact_subject: Option<String>,
+    /// The following are all of the fields from ManifestAddArtifactOptions. We can't just embed the whole structure because it embeds a ManifestAnnotateOptions, which would conflict with the one that ManifestAddOptions embeds.
+    #[serde(rename = "artifact_type", skip_serializing_if = "Option::is_none")]
+    pub artifact_type: Option<String>,
+    /// Feature list for the item in the manifest list
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    /// Images is an optional list of image references to add to manifest list
+    #[serde(rename = "images", skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+    /// IndexAnnotation is a slice of key=value annotations to add to the manifest list itself
+    #[serde(rename = "index_annotation", skip_serializing_if = "Option::is_none")]
+    pub index_annotation: Option<Vec<String>>,
+    /// IndexAnnotations is a map of key:value annotations to add to the manifest list itself, by a map which is preferred over IndexAnnotation
+    #[serde(rename = "index_annotations", skip_serializing_if = "Option::is_none")]
+    pub index_annotations: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "operation", skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// OS overrides the operating system for the item in the manifest list
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OS features for the item in the manifest list
+    #[serde(rename = "os_features", skip_serializing_if = "Option::is_none")]
+    pub os_features: Option<Vec<String>>,
+    /// OSVersion overrides the operating system for the item in the manifest list
+    #[serde(rename = "os_version", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// IndexSubject is a subject value to set in the manifest list itself
+    #[serde(rename = "subject", skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Variant for the item in the manifest list
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl ManifestModifyOptions {
+    /// swagger 2.0 does not support oneOf for schema validation.  Operation \"update\" uses all fields. Operation \"remove\" uses fields: Operation and Images Operation \"annotate\" uses fields: Operation and Annotations
+    pub fn new() -> ManifestModifyOptions {
+        ManifestModifyOptions {
+            all: None,
+            annotation: None,
+            annotations: None,
+            arch: None,
+            artifact_annotations: None,
+            artifact_config: None,
+            artifact_config_type: None,
+            artifact_exclude_titles: None,
+            artifact_files: None,
+            artifact_layer_type: None,
+            artifact_subject: None,
+            artifact_type: None,
+            features: None,
+            images: None,
+            index_annotation: None,
+            index_annotations: None,
+            operation: None,
+            os: None,
+            os_features: None,
+            os_version: None,
+            subject: None,
+            variant: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestModifyReport {
+    /// Manifest List ID
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Errors associated with operation
+    #[serde(rename = "errors", skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<String>>,
+    /// Files added to manifest list, otherwise not provided.
+    #[serde(rename = "files", skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+    /// Images added to or removed from manifest list, otherwise not provided.
+    #[serde(rename = "images", skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+}
+impl ManifestModifyReport {
+    pub fn new() -> ManifestModifyReport {
+        ManifestModifyReport {
+            id: None,
+            errors: None,
+            files: None,
+            images: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestPushReport {
+    /// ID of the pushed manifest
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Error contains text of errors from pushing
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Stream used to provide push progress
+    #[serde(rename = "stream", skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+}
+impl ManifestPushReport {
+    pub fn new() -> ManifestPushReport {
+        ManifestPushReport {
+            id: None,
+            error: None,
+            stream: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManifestRemoveReport {
+    /// Deleted manifest list.
+    #[serde(rename = "Deleted", skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<Vec<String>>,
+    /// Errors associated with operation
+    #[serde(rename = "Errors", skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<String>>,
+    /// ExitCode describes the exit codes as described in the `podman rmi` man page.
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i64>,
+    /// Untagged images. Can be longer than Deleted.
+    #[serde(rename = "Untagged", skip_serializing_if = "Option::is_none")]
+    pub untagged: Option<Vec<String>>,
+}
+impl ManifestRemoveReport {
+    pub fn new() -> ManifestRemoveReport {
+        ManifestRemoveReport {
+            deleted: None,
+            errors: None,
+            exit_code: None,
+            untagged: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Meta {
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<Box<Version>>,
+}
+impl Meta {
+    pub fn new() -> Meta {
+        Meta {
+            created_at: None,
+            updated_at: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// LastTagTime is the date and time at which the image was last tagged.
+    #[serde(rename = "LastTagTime", skip_serializing_if = "Option::is_none")]
+    pub last_tag_time: Option<String>,
+}
+impl Metadata {
+    pub fn new() -> Metadata {
+        Metadata { last_tag_time: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mount {
+    #[serde(rename = "BindOptions", skip_serializing_if = "Option::is_none")]
+    pub bind_options: Option<Box<BindOptions>>,
+    #[serde(rename = "ClusterOptions", skip_serializing_if = "Option::is_none")]
+    pub cluster_options: Option<serde_json::Value>,
+    #[serde(rename = "Consistency", skip_serializing_if = "Option::is_none")]
+    pub consistency: Option<String>,
+    #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Source specifies the name of the mount. Depending on mount type, this may be a volume name or a host path, or even ignored. Source is not supported for tmpfs (must be an empty value)
+    #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(rename = "Target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(rename = "TmpfsOptions", skip_serializing_if = "Option::is_none")]
+    pub tmpfs_options: Option<Box<TmpfsOptions>>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "VolumeOptions", skip_serializing_if = "Option::is_none")]
+    pub volume_options: Option<Box<VolumeOptions>>,
+}
+impl Mount {
+    pub fn new() -> Mount {
+        Mount {
+            bind_options: None,
+            cluster_options: None,
+            consistency: None,
+            read_only: None,
+            source: None,
+            target: None,
+            tmpfs_options: None,
+            r#type: None,
+            volume_options: None,
+        }
+    }
+}
+/// MountPoint : This is used for reporting the mountpoints in use by a container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MountPoint {
+    /// Destination is the path relative to the container root (`/`) where the Source is mounted inside the container.
+    #[serde(rename = "Destination", skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// Driver is the volume driver used to create the volume (if it is a volume).
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// Mode is a comma separated list of options supplied by the user when creating the bind/volume mount.  The default is platform-specific (`\"z\"` on Linux, empty on Windows).
+    #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// Name is the name reference to the underlying data defined by `Source` e.g., the volume name.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Propagation", skip_serializing_if = "Option::is_none")]
+    pub propagation: Option<String>,
+    /// RW indicates whether the mount is mounted writable (read-write).
+    #[serde(rename = "RW", skip_serializing_if = "Option::is_none")]
+    pub rw: Option<bool>,
+    /// Source is the source location of the mount.  For volumes, this contains the storage location of the volume (within `/var/lib/docker/volumes/`). For bind-mounts, and `npipe`, this contains the source (host) part of the bind-mount. For `tmpfs` mount points, this field is empty.
+    #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl MountPoint {
+    /// This is used for reporting the mountpoints in use by a container.
+    pub fn new() -> MountPoint {
+        MountPoint {
+            destination: None,
+            driver: None,
+            mode: None,
+            name: None,
+            propagation: None,
+            rw: None,
+            source: None,
+            r#type: None,
+        }
+    }
+}
+/// NamedVolume : NamedVolume holds information about a named volume that will be mounted into the container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamedVolume {
+    /// Destination to mount the named volume within the container. Must be an absolute path. Path will be created if it does not exist.
+    #[serde(rename = "Dest", skip_serializing_if = "Option::is_none")]
+    pub dest: Option<String>,
+    /// IsAnonymous sets the named volume as anonymous even if it has a name This is used for emptyDir volumes from a kube yaml
+    #[serde(rename = "IsAnonymous", skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    /// Name is the name of the named volume to be mounted. May be empty. If empty, a new named volume with a pseudorandomly generated name will be mounted at the given destination.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Options are options that the named volume will be mounted with.
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<String>>,
+    /// SubPath stores the sub directory of the named volume to be mounted in the container
+    #[serde(rename = "SubPath", skip_serializing_if = "Option::is_none")]
+    pub sub_path: Option<String>,
+}
+impl NamedVolume {
+    /// NamedVolume holds information about a named volume that will be mounted into the container.
+    pub fn new() -> NamedVolume {
+        NamedVolume {
+            dest: None,
+            is_anonymous: None,
+            name: None,
+            options: None,
+            sub_path: None,
+        }
+    }
+}
+/// Namespace : Namespace describes the namespace
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Namespace {
+    #[serde(rename = "nsmode", skip_serializing_if = "Option::is_none")]
+    pub nsmode: Option<String>,
+    #[serde(rename = "value", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+impl Namespace {
+    /// Namespace describes the namespace
+    pub fn new() -> Namespace {
+        Namespace {
+            nsmode: None,
+            value: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetAddress {
+    /// Gateway for the network. This can be empty if there is no gateway, e.g. internal network.
+    #[serde(rename = "gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(rename = "ipnet", skip_serializing_if = "Option::is_none")]
+    pub ipnet: Option<Box<IPNet>>,
+}
+impl NetAddress {
+    pub fn new() -> NetAddress {
+        NetAddress {
+            gateway: None,
+            ipnet: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetInterface {
+    /// MacAddress for this Interface.
+    #[serde(rename = "mac_address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// Subnets list of assigned subnets with their gateway.
+    #[serde(rename = "subnets", skip_serializing_if = "Option::is_none")]
+    pub subnets: Option<Vec<NetAddress>>,
+}
+impl NetInterface {
+    pub fn new() -> NetInterface {
+        NetInterface {
+            mac_address: None,
+            subnets: None,
+        }
+    }
+}
+/// NetOptions : NetOptions reflect the shared network options between pods and containers
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetOptions {
+    #[serde(rename = "dns_option", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    #[serde(rename = "dns_search", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    #[serde(rename = "dns_server", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    #[serde(rename = "hostadd", skip_serializing_if = "Option::is_none")]
+    pub hostadd: Option<Vec<String>>,
+    #[serde(rename = "netns", skip_serializing_if = "Option::is_none")]
+    pub netns: Option<Box<Namespace>>,
+    #[serde(rename = "network_alias", skip_serializing_if = "Option::is_none")]
+    pub network_alias: Option<Vec<String>>,
+    /// NetworkOptions are additional options for each network
+    #[serde(rename = "network_options", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    #[serde(rename = "networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, PerNetworkOptions>>,
+    #[serde(rename = "no_manage_hosts", skip_serializing_if = "Option::is_none")]
+    pub no_manage_hosts: Option<bool>,
+    #[serde(rename = "no_manage_resolv_conf", skip_serializing_if = "Option::is_none")]
+    pub no_manage_resolv_conf: Option<bool>,
+    #[serde(rename = "portmappings", skip_serializing_if = "Option::is_none")]
+    pub portmappings: Option<Vec<PortMapping>>,
+}
+impl NetOptions {
+    /// NetOptions reflect the shared network options between pods and containers
+    pub fn new() -> NetOptions {
+        NetOptions {
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            hostadd: None,
+            netns: None,
+            network_alias: None,
+            network_options: None,
+            networks: None,
+            no_manage_hosts: None,
+            no_manage_resolv_conf: None,
+            portmappings: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Network {
+    /// Created contains the timestamp when this network was created.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// DNSEnabled is whether name resolution is active for container on this Network. Only supported with the bridge driver.
+    #[serde(rename = "dns_enabled", skip_serializing_if = "Option::is_none")]
+    pub dns_enabled: Option<bool>,
+    /// Driver for this Network, e.g. bridge, macvlan...
+    #[serde(rename = "driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// ID of the Network.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Internal is whether the Network should not have external routes to public or other Networks.
+    #[serde(rename = "internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// IPAMOptions contains options used for the ip assignment.
+    #[serde(rename = "ipam_options", skip_serializing_if = "Option::is_none")]
+    pub ipam_options: Option<std::collections::HashMap<String, String>>,
+    /// IPv6Enabled if set to true an ipv6 subnet should be created for this net.
+    #[serde(rename = "ipv6_enabled", skip_serializing_if = "Option::is_none")]
+    pub ipv6_enabled: Option<bool>,
+    /// Labels is a set of key-value labels that have been applied to the Network.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Name of the Network.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of custom DNS server for podman's DNS resolver at network level, all the containers attached to this network will consider resolvers configured at network level.
+    #[serde(rename = "network_dns_servers", skip_serializing_if = "Option::is_none")]
+    pub network_dns_servers: Option<Vec<String>>,
+    /// NetworkInterface is the network interface name on the host.
+    #[serde(rename = "network_interface", skip_serializing_if = "Option::is_none")]
+    pub network_interface: Option<String>,
+    /// Options is a set of key-value options that have been applied to the Network.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    /// Routes to use for this network.
+    #[serde(rename = "routes", skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<Route>>,
+    /// Subnets to use for this network.
+    #[serde(rename = "subnets", skip_serializing_if = "Option::is_none")]
+    pub subnets: Option<Vec<Subnet>>,
+}
+impl Network {
+    pub fn new() -> Network {
+        Network {
+            created: None,
+            dns_enabled: None,
+            driver: None,
+            id: None,
+            internal: None,
+            ipam_options: None,
+            ipv6_enabled: None,
+            labels: None,
+            name: None,
+            network_dns_servers: None,
+            network_interface: None,
+            options: None,
+            routes: None,
+            subnets: None,
+        }
+    }
+}
+/// NetworkConnect : NetworkConnect represents the data to be used to connect a container to the network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConnect {
+    #[serde(rename = "Container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(rename = "EndpointConfig", skip_serializing_if = "Option::is_none")]
+    pub endpoint_config: Option<Box<EndpointSettings>>,
+}
+impl NetworkConnect {
+    /// NetworkConnect represents the data to be used to connect a container to the network
+    pub fn new() -> NetworkConnect {
+        NetworkConnect {
+            container: None,
+            endpoint_config: None,
+        }
+    }
+}
+/// NetworkConnectOptions : NetworkConnectOptions describes options for connecting a container to a network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConnectOptions {
+    /// Aliases contains a list of names which the dns server should resolve to this container. Should only be set when DNSEnabled is true on the Network. If aliases are set but there is no dns support for this network the network interface implementation should ignore this and NOT error. Optional.
+    #[serde(rename = "aliases", skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    #[serde(rename = "container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// InterfaceName for this container. Required in the backend. Optional in the frontend. Will be filled with ethX (where X is a integer) when empty.
+    #[serde(rename = "interface_name", skip_serializing_if = "Option::is_none")]
+    pub interface_name: Option<String>,
+    /// StaticIPs for this container. Optional.
+    #[serde(rename = "static_ips", skip_serializing_if = "Option::is_none")]
+    pub static_ips: Option<Vec<String>>,
+    /// StaticMac for this container. Optional.
+    #[serde(rename = "static_mac", skip_serializing_if = "Option::is_none")]
+    pub static_mac: Option<String>,
+}
+impl NetworkConnectOptions {
+    /// NetworkConnectOptions describes options for connecting a container to a network
+    pub fn new() -> NetworkConnectOptions {
+        NetworkConnectOptions {
+            aliases: None,
+            container: None,
+            interface_name: None,
+            static_ips: None,
+            static_mac: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkContainerInfo {
+    /// Interfaces configured for this container with their addresses
+    #[serde(rename = "interfaces", skip_serializing_if = "Option::is_none")]
+    pub interfaces: Option<std::collections::HashMap<String, NetInterface>>,
+    /// Name of the container
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl NetworkContainerInfo {
+    pub fn new() -> NetworkContainerInfo {
+        NetworkContainerInfo {
+            interfaces: None,
+            name: None,
+        }
+    }
+}
+/// NetworkCreate : NetworkCreate is the expected body of the \"create network\" http request message
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkCreate {
+    #[serde(rename = "Attachable", skip_serializing_if = "Option::is_none")]
+    pub attachable: Option<bool>,
+    /// Deprecated: CheckDuplicate is deprecated since API v1.44, but it defaults to true when sent by the client package to older daemons.
+    #[serde(rename = "CheckDuplicate", skip_serializing_if = "Option::is_none")]
+    pub check_duplicate: Option<bool>,
+    #[serde(rename = "ConfigFrom", skip_serializing_if = "Option::is_none")]
+    pub config_from: Option<Box<ConfigReference>>,
+    #[serde(rename = "ConfigOnly", skip_serializing_if = "Option::is_none")]
+    pub config_only: Option<bool>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "EnableIPv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+    #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
+    pub ipam: Option<Box<IPAM>>,
+    #[serde(rename = "Ingress", skip_serializing_if = "Option::is_none")]
+    pub ingress: Option<bool>,
+    #[serde(rename = "Internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+impl NetworkCreate {
+    /// NetworkCreate is the expected body of the \"create network\" http request message
+    pub fn new() -> NetworkCreate {
+        NetworkCreate {
+            attachable: None,
+            check_duplicate: None,
+            config_from: None,
+            config_only: None,
+            driver: None,
+            enable_ipv6: None,
+            ipam: None,
+            ingress: None,
+            internal: None,
+            labels: None,
+            options: None,
+            scope: None,
+        }
+    }
+}
+/// NetworkCreateLibpod : Network create
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkCreateLibpod {
+    /// Created contains the timestamp when this network was created.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// DNSEnabled is whether name resolution is active for container on this Network. Only supported with the bridge driver.
+    #[serde(rename = "dns_enabled", skip_serializing_if = "Option::is_none")]
+    pub dns_enabled: Option<bool>,
+    /// Driver for this Network, e.g. bridge, macvlan...
+    #[serde(rename = "driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// ID of the Network.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Internal is whether the Network should not have external routes to public or other Networks.
+    #[serde(rename = "internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// IPAMOptions contains options used for the ip assignment.
+    #[serde(rename = "ipam_options", skip_serializing_if = "Option::is_none")]
+    pub ipam_options: Option<std::collections::HashMap<String, String>>,
+    /// IPv6Enabled if set to true an ipv6 subnet should be created for this net.
+    #[serde(rename = "ipv6_enabled", skip_serializing_if = "Option::is_none")]
+    pub ipv6_enabled: Option<bool>,
+    /// Labels is a set of key-value labels that have been applied to the Network.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Name of the Network.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of custom DNS server for podman's DNS resolver at network level, all the containers attached to this network will consider resolvers configured at network level.
+    #[serde(rename = "network_dns_servers", skip_serializing_if = "Option::is_none")]
+    pub network_dns_servers: Option<Vec<String>>,
+    /// NetworkInterface is the network interface name on the host.
+    #[serde(rename = "network_interface", skip_serializing_if = "Option::is_none")]
+    pub network_interface: Option<String>,
+    /// Options is a set of key-value options that have been applied to the Network.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    /// Routes to use for this network.
+    #[serde(rename = "routes", skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<Route>>,
+    /// Subnets to use for this network.
+    #[serde(rename = "subnets", skip_serializing_if = "Option::is_none")]
+    pub subnets: Option<Vec<Subnet>>,
+}
+impl NetworkCreateLibpod {
+    /// Network create
+    pub fn new() -> NetworkCreateLibpod {
+        NetworkCreateLibpod {
+            created: None,
+            dns_enabled: None,
+            driver: None,
+            id: None,
+            internal: None,
+            ipam_options: None,
+            ipv6_enabled: None,
+            labels: None,
+            name: None,
+            network_dns_servers: None,
+            network_interface: None,
+            options: None,
+            routes: None,
+            subnets: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkCreateRequest {
+    #[serde(rename = "Attachable", skip_serializing_if = "Option::is_none")]
+    pub attachable: Option<bool>,
+    /// Deprecated: CheckDuplicate is deprecated since API v1.44, but it defaults to true when sent by the client package to older daemons.
+    #[serde(rename = "CheckDuplicate", skip_serializing_if = "Option::is_none")]
+    pub check_duplicate: Option<bool>,
+    #[serde(rename = "ConfigFrom", skip_serializing_if = "Option::is_none")]
+    pub config_from: Option<Box<ConfigReference>>,
+    #[serde(rename = "ConfigOnly", skip_serializing_if = "Option::is_none")]
+    pub config_only: Option<bool>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "EnableIPv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+    #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
+    pub ipam: Option<Box<IPAM>>,
+    #[serde(rename = "Ingress", skip_serializing_if = "Option::is_none")]
+    pub ingress: Option<bool>,
+    #[serde(rename = "Internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+impl NetworkCreateRequest {
+    pub fn new() -> NetworkCreateRequest {
+        NetworkCreateRequest {
+            attachable: None,
+            check_duplicate: None,
+            config_from: None,
+            config_only: None,
+            driver: None,
+            enable_ipv6: None,
+            ipam: None,
+            ingress: None,
+            internal: None,
+            labels: None,
+            name: None,
+            options: None,
+            scope: None,
+        }
+    }
+}
+/// NetworkDisconnect : NetworkDisconnect represents the data to be used to disconnect a container from the network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkDisconnect {
+    #[serde(rename = "Container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(rename = "Force", skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+impl NetworkDisconnect {
+    /// NetworkDisconnect represents the data to be used to disconnect a container from the network
+    pub fn new() -> NetworkDisconnect {
+        NetworkDisconnect {
+            container: None,
+            force: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    #[serde(rename = "backend", skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    #[serde(rename = "dns", skip_serializing_if = "Option::is_none")]
+    pub dns: Option<Box<DNSNetworkInfo>>,
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl NetworkInfo {
+    pub fn new() -> NetworkInfo {
+        NetworkInfo {
+            backend: None,
+            dns: None,
+            package: None,
+            path: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInspectReport {
+    #[serde(rename = "containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<std::collections::HashMap<String, NetworkContainerInfo>>,
+    /// Created contains the timestamp when this network was created.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// DNSEnabled is whether name resolution is active for container on this Network. Only supported with the bridge driver.
+    #[serde(rename = "dns_enabled", skip_serializing_if = "Option::is_none")]
+    pub dns_enabled: Option<bool>,
+    /// Driver for this Network, e.g. bridge, macvlan...
+    #[serde(rename = "driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// ID of the Network.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Internal is whether the Network should not have external routes to public or other Networks.
+    #[serde(rename = "internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// IPAMOptions contains options used for the ip assignment.
+    #[serde(rename = "ipam_options", skip_serializing_if = "Option::is_none")]
+    pub ipam_options: Option<std::collections::HashMap<String, String>>,
+    /// IPv6Enabled if set to true an ipv6 subnet should be created for this net.
+    #[serde(rename = "ipv6_enabled", skip_serializing_if = "Option::is_none")]
+    pub ipv6_enabled: Option<bool>,
+    /// Labels is a set of key-value labels that have been applied to the Network.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Name of the Network.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of custom DNS server for podman's DNS resolver at network level, all the containers attached to this network will consider resolvers configured at network level.
+    #[serde(rename = "network_dns_servers", skip_serializing_if = "Option::is_none")]
+    pub network_dns_servers: Option<Vec<String>>,
+    /// NetworkInterface is the network interface name on the host.
+    #[serde(rename = "network_interface", skip_serializing_if = "Option::is_none")]
+    pub network_interface: Option<String>,
+    /// Options is a set of key-value options that have been applied to the Network.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    /// Routes to use for this network.
+    #[serde(rename = "routes", skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<Route>>,
+    /// Subnets to use for this network.
+    #[serde(rename = "subnets", skip_serializing_if = "Option::is_none")]
+    pub subnets: Option<Vec<Subnet>>,
+}
+impl NetworkInspectReport {
+    pub fn new() -> NetworkInspectReport {
+        NetworkInspectReport {
+            containers: None,
+            created: None,
+            dns_enabled: None,
+            driver: None,
+            id: None,
+            internal: None,
+            ipam_options: None,
+            ipv6_enabled: None,
+            labels: None,
+            name: None,
+            network_dns_servers: None,
+            network_interface: None,
+            options: None,
+            routes: None,
+            subnets: None,
+        }
+    }
+}
+/// NetworkPruneReport : NetworkPruneReport containers the name of network and an error associated in its pruning (removal)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkPruneReport {
+    #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl NetworkPruneReport {
+    /// NetworkPruneReport containers the name of network and an error associated in its pruning (removal)
+    pub fn new() -> NetworkPruneReport {
+        NetworkPruneReport {
+            error: None,
+            name: None,
+        }
+    }
+}
+/// NetworkResource : NetworkResource is the body of the \"get network\" http response message
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkResource {
+    #[serde(rename = "Attachable", skip_serializing_if = "Option::is_none")]
+    pub attachable: Option<bool>,
+    #[serde(rename = "ConfigFrom", skip_serializing_if = "Option::is_none")]
+    pub config_from: Option<Box<ConfigReference>>,
+    #[serde(rename = "ConfigOnly", skip_serializing_if = "Option::is_none")]
+    pub config_only: Option<bool>,
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<std::collections::HashMap<String, EndpointResource>>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    #[serde(rename = "EnableIPv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+    #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
+    pub ipam: Option<Box<IPAM>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Ingress", skip_serializing_if = "Option::is_none")]
+    pub ingress: Option<bool>,
+    #[serde(rename = "Internal", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Peers", skip_serializing_if = "Option::is_none")]
+    pub peers: Option<Vec<PeerInfo>>,
+    #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(rename = "Services", skip_serializing_if = "Option::is_none")]
+    pub services: Option<std::collections::HashMap<String, ServiceInfo>>,
+}
+impl NetworkResource {
+    /// NetworkResource is the body of the \"get network\" http response message
+    pub fn new() -> NetworkResource {
+        NetworkResource {
+            attachable: None,
+            config_from: None,
+            config_only: None,
+            containers: None,
+            created: None,
+            driver: None,
+            enable_ipv6: None,
+            ipam: None,
+            id: None,
+            ingress: None,
+            internal: None,
+            labels: None,
+            name: None,
+            options: None,
+            peers: None,
+            scope: None,
+            services: None,
+        }
+    }
+}
+/// NetworkRmReport : NetworkRmReport describes the results of network removal
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkRmReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl NetworkRmReport {
+    /// NetworkRmReport describes the results of network removal
+    pub fn new() -> NetworkRmReport {
+        NetworkRmReport {
+            err: None,
+            name: None,
+        }
+    }
+}
+/// NetworkSettings : NetworkSettings exposes the network settings in the api
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkSettings {
+    #[serde(rename = "Bridge", skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    #[serde(rename = "Gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_address: Option<String>,
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub global_ipv6_prefix_len: Option<i64>,
+    /// HairpinMode specifies if hairpin NAT should be enabled on the virtual interface  Deprecated: This field is never set and will be removed in a future release.
+    #[serde(rename = "HairpinMode", skip_serializing_if = "Option::is_none")]
+    pub hairpin_mode: Option<bool>,
+    #[serde(rename = "IPAddress", skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "Option::is_none")]
+    pub ip_prefix_len: Option<i64>,
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "Option::is_none")]
+    pub ipv6_gateway: Option<String>,
+    /// LinkLocalIPv6Address is an IPv6 unicast address using the link-local prefix  Deprecated: This field is never set and will be removed in a future release.
+    #[serde(rename = "LinkLocalIPv6Address", skip_serializing_if = "Option::is_none")]
+    pub link_local_ipv6_address: Option<String>,
+    /// LinkLocalIPv6PrefixLen is the prefix length of an IPv6 unicast address  Deprecated: This field is never set and will be removed in a future release.
+    #[serde(rename = "LinkLocalIPv6PrefixLen", skip_serializing_if = "Option::is_none")]
+    pub link_local_ipv6_prefix_len: Option<i64>,
+    #[serde(rename = "MacAddress", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, EndpointSettings>>,
+    /// PortMap is a collection of PortBinding indexed by Port
+    #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<std::collections::HashMap<String, Vec<PortBinding>>>,
+    #[serde(rename = "SandboxID", skip_serializing_if = "Option::is_none")]
+    pub sandbox_id: Option<String>,
+    #[serde(rename = "SandboxKey", skip_serializing_if = "Option::is_none")]
+    pub sandbox_key: Option<String>,
+    #[serde(rename = "SecondaryIPAddresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ip_addresses: Option<Vec<Address>>,
+    #[serde(rename = "SecondaryIPv6Addresses", skip_serializing_if = "Option::is_none")]
+    pub secondary_ipv6_addresses: Option<Vec<Address>>,
+}
+impl NetworkSettings {
+    /// NetworkSettings exposes the network settings in the api
+    pub fn new() -> NetworkSettings {
+        NetworkSettings {
+            bridge: None,
+            endpoint_id: None,
+            gateway: None,
+            global_ipv6_address: None,
+            global_ipv6_prefix_len: None,
+            hairpin_mode: None,
+            ip_address: None,
+            ip_prefix_len: None,
+            ipv6_gateway: None,
+            link_local_ipv6_address: None,
+            link_local_ipv6_prefix_len: None,
+            mac_address: None,
+            networks: None,
+            ports: None,
+            sandbox_id: None,
+            sandbox_key: None,
+            secondary_ip_addresses: None,
+            secondary_ipv6_addresses: None,
+        }
+    }
+}
+/// NetworkUpdateOptions : NetworkUpdateOptions describes options to update a network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkUpdateOptions {
+    #[serde(rename = "adddnsservers", skip_serializing_if = "Option::is_none")]
+    pub adddnsservers: Option<Vec<String>>,
+    #[serde(rename = "removednsservers", skip_serializing_if = "Option::is_none")]
+    pub removednsservers: Option<Vec<String>>,
+}
+impl NetworkUpdateOptions {
+    /// NetworkUpdateOptions describes options to update a network
+    pub fn new() -> NetworkUpdateOptions {
+        NetworkUpdateOptions {
+            adddnsservers: None,
+            removednsservers: None,
+        }
+    }
+}
+/// NetworkingConfig : NetworkingConfig represents the container's networking configuration for each of its interfaces Carries the networking configs specified in the `docker run` and `docker network connect` commands
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkingConfig {
+    #[serde(rename = "EndpointsConfig", skip_serializing_if = "Option::is_none")]
+    pub endpoints_config: Option<std::collections::HashMap<String, EndpointSettings>>,
+}
+impl NetworkingConfig {
+    /// NetworkingConfig represents the container's networking configuration for each of its interfaces Carries the networking configs specified in the `docker run` and `docker network connect` commands
+    pub fn new() -> NetworkingConfig {
+        NetworkingConfig {
+            endpoints_config: None,
+        }
+    }
+}
+/// OCIRuntimeInfo : OCIRuntimeInfo describes the runtime (crun or runc) being used with podman
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OCIRuntimeInfo {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl OCIRuntimeInfo {
+    /// OCIRuntimeInfo describes the runtime (crun or runc) being used with podman
+    pub fn new() -> OCIRuntimeInfo {
+        OCIRuntimeInfo {
+            name: None,
+            package: None,
+            path: None,
+            version: None,
+        }
+    }
+}
+/// OverlayVolume : OverlayVolume holds information about an overlay volume that will be mounted into the container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OverlayVolume {
+    /// Destination is the absolute path where the mount will be placed in the container.
+    #[serde(rename = "destination", skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// Options holds overlay volume options.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<String>>,
+    /// Source specifies the source path of the mount.
+    #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+impl OverlayVolume {
+    /// OverlayVolume holds information about an overlay volume that will be mounted into the container.
+    pub fn new() -> OverlayVolume {
+        OverlayVolume {
+            destination: None,
+            options: None,
+            source: None,
+        }
+    }
+}
+/// PastaInfo : PastaInfo describes the pasta executable that is being used
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PastaInfo {
+    #[serde(rename = "executable", skip_serializing_if = "Option::is_none")]
+    pub executable: Option<String>,
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl PastaInfo {
+    /// PastaInfo describes the pasta executable that is being used
+    pub fn new() -> PastaInfo {
+        PastaInfo {
+            executable: None,
+            package: None,
+            version: None,
+        }
+    }
+}
+/// PeerInfo : PeerInfo represents one peer of an overlay network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PeerInfo {
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl PeerInfo {
+    /// PeerInfo represents one peer of an overlay network
+    pub fn new() -> PeerInfo {
+        PeerInfo { ip: None, name: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PerNetworkOptions {
+    /// Aliases contains a list of names which the dns server should resolve to this container. Should only be set when DNSEnabled is true on the Network. If aliases are set but there is no dns support for this network the network interface implementation should ignore this and NOT error. Optional.
+    #[serde(rename = "aliases", skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    /// InterfaceName for this container. Required in the backend. Optional in the frontend. Will be filled with ethX (where X is a integer) when empty.
+    #[serde(rename = "interface_name", skip_serializing_if = "Option::is_none")]
+    pub interface_name: Option<String>,
+    /// StaticIPs for this container. Optional.
+    #[serde(rename = "static_ips", skip_serializing_if = "Option::is_none")]
+    pub static_ips: Option<Vec<String>>,
+    /// StaticMac for this container. Optional.
+    #[serde(rename = "static_mac", skip_serializing_if = "Option::is_none")]
+    pub static_mac: Option<String>,
+}
+impl PerNetworkOptions {
+    pub fn new() -> PerNetworkOptions {
+        PerNetworkOptions {
+            aliases: None,
+            interface_name: None,
+            static_ips: None,
+            static_mac: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Platform {
+    /// Architecture field specifies the CPU architecture, for example `amd64` or `ppc64le`.
+    #[serde(rename = "architecture", skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    /// OS specifies the operating system, for example `linux` or `windows`.
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// OSFeatures is an optional field specifying an array of strings, each listing a required OS feature (for example on Windows `win32k`).
+    #[serde(rename = "os.features", skip_serializing_if = "Option::is_none")]
+    pub os_period_features: Option<Vec<String>>,
+    /// OSVersion is an optional field specifying the operating system version, for example on Windows `10.0.14393.1066`.
+    #[serde(rename = "os.version", skip_serializing_if = "Option::is_none")]
+    pub os_period_version: Option<String>,
+    /// Variant is an optional field specifying a variant of the CPU, for example `v7` to specify ARMv7 when architecture is `arm`.
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl Platform {
+    pub fn new() -> Platform {
+        Platform {
+            architecture: None,
+            os: None,
+            os_period_features: None,
+            os_period_version: None,
+            variant: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayKubePod {
+    /// ContainerErrors - any errors that occurred while starting containers in the pod.
+    #[serde(rename = "ContainerErrors", skip_serializing_if = "Option::is_none")]
+    pub container_errors: Option<Vec<String>>,
+    /// Containers - the IDs of the containers running in the created pod.
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<Vec<String>>,
+    /// ID - ID of the pod created as a result of play kube.
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// InitContainers - the IDs of the init containers to be run in the created pod.
+    #[serde(rename = "InitContainers", skip_serializing_if = "Option::is_none")]
+    pub init_containers: Option<Vec<String>>,
+    /// Logs - non-fatal errors and log messages while processing.
+    #[serde(rename = "Logs", skip_serializing_if = "Option::is_none")]
+    pub logs: Option<Vec<String>>,
+}
+impl PlayKubePod {
+    pub fn new() -> PlayKubePod {
+        PlayKubePod {
+            container_errors: None,
+            containers: None,
+            id: None,
+            init_containers: None,
+            logs: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayKubeReport {
+    /// If set, exit with the specified exit code.
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Pods - pods created by play kube.
+    #[serde(rename = "Pods", skip_serializing_if = "Option::is_none")]
+    pub pods: Option<Vec<PlayKubePod>>,
+    #[serde(rename = "RmReport", skip_serializing_if = "Option::is_none")]
+    pub rm_report: Option<Vec<PodRmReport>>,
+    #[serde(rename = "SecretRmReport", skip_serializing_if = "Option::is_none")]
+    pub secret_rm_report: Option<Vec<SecretRmReport>>,
+    /// Secrets - secrets created by play kube
+    #[serde(rename = "Secrets", skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<PlaySecret>>,
+    /// ServiceContainerID - ID of the service container if one is created
+    #[serde(rename = "ServiceContainerID", skip_serializing_if = "Option::is_none")]
+    pub service_container_id: Option<String>,
+    #[serde(rename = "StopReport", skip_serializing_if = "Option::is_none")]
+    pub stop_report: Option<Vec<PodStopReport>>,
+    #[serde(rename = "VolumeRmReport", skip_serializing_if = "Option::is_none")]
+    pub volume_rm_report: Option<Vec<VolumeRmReport>>,
+    /// Volumes - volumes created by play kube.
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<PlayKubeVolume>>,
+}
+impl PlayKubeReport {
+    pub fn new() -> PlayKubeReport {
+        PlayKubeReport {
+            exit_code: None,
+            pods: None,
+            rm_report: None,
+            secret_rm_report: None,
+            secrets: None,
+            service_container_id: None,
+            stop_report: None,
+            volume_rm_report: None,
+            volumes: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayKubeVolume {
+    /// Name - Name of the volume created by play kube.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl PlayKubeVolume {
+    pub fn new() -> PlayKubeVolume {
+        PlayKubeVolume { name: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlaySecret {
+    #[serde(rename = "CreateReport", skip_serializing_if = "Option::is_none")]
+    pub create_report: Option<Box<SecretCreateReport>>,
+}
+impl PlaySecret {
+    pub fn new() -> PlaySecret {
+        PlaySecret { create_report: None }
+    }
+}
+/// Plugin : Plugin A plugin for the Engine API
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plugin {
+    #[serde(rename = "Config")]
+    pub config: Box<PluginConfig>,
+    /// True if the plugin is running. False if the plugin is not running, only installed.
+    #[serde(rename = "Enabled")]
+    pub enabled: bool,
+    /// Id
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// name
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// plugin remote reference used to push/pull the plugin
+    #[serde(rename = "PluginReference", skip_serializing_if = "Option::is_none")]
+    pub plugin_reference: Option<String>,
+    #[serde(rename = "Settings")]
+    pub settings: Box<PluginSettings>,
+}
+impl Plugin {
+    /// Plugin A plugin for the Engine API
+    pub fn new(
+        config: PluginConfig,
+        enabled: bool,
+        name: String,
+        settings: PluginSettings,
+    ) -> Plugin {
+        Plugin {
+            config: Box::new(config),
+            enabled,
+            id: None,
+            name,
+            plugin_reference: None,
+            settings: Box::new(settings),
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfig {
+    #[serde(rename = "Args")]
+    pub args: Box<PluginConfigArgs>,
+    /// description
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// Docker Version used to create the plugin
+    #[serde(rename = "DockerVersion", skip_serializing_if = "Option::is_none")]
+    pub docker_version: Option<String>,
+    /// documentation
+    #[serde(rename = "Documentation")]
+    pub documentation: String,
+    /// entrypoint
+    #[serde(rename = "Entrypoint")]
+    pub entrypoint: Vec<String>,
+    /// env
+    #[serde(rename = "Env")]
+    pub env: Vec<PluginEnv>,
+    #[serde(rename = "Interface")]
+    pub interface: Box<PluginConfigInterface>,
+    /// ipc host
+    #[serde(rename = "IpcHost")]
+    pub ipc_host: bool,
+    #[serde(rename = "Linux")]
+    pub linux: Box<PluginConfigLinux>,
+    /// mounts
+    #[serde(rename = "Mounts")]
+    pub mounts: Vec<PluginMount>,
+    #[serde(rename = "Network")]
+    pub network: Box<PluginConfigNetwork>,
+    /// pid host
+    #[serde(rename = "PidHost")]
+    pub pid_host: bool,
+    /// propagated mount
+    #[serde(rename = "PropagatedMount")]
+    pub propagated_mount: String,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<PluginConfigUser>>,
+    /// work dir
+    #[serde(rename = "WorkDir")]
+    pub work_dir: String,
+    #[serde(rename = "rootfs", skip_serializing_if = "Option::is_none")]
+    pub rootfs: Option<Box<PluginConfigRootfs>>,
+}
+impl PluginConfig {
+    pub fn new(
+        args: PluginConfigArgs,
+        description: String,
+        documentation: String,
+        entrypoint: Vec<String>,
+        env: Vec<PluginEnv>,
+        interface: PluginConfigInterface,
+        ipc_host: bool,
+        linux: PluginConfigLinux,
+        mounts: Vec<PluginMount>,
+        network: PluginConfigNetwork,
+        pid_host: bool,
+        propagated_mount: String,
+        work_dir: String,
+    ) -> PluginConfig {
+        PluginConfig {
+            args: Box::new(args),
+            description,
+            docker_version: None,
+            documentation,
+            entrypoint,
+            env,
+            interface: Box::new(interface),
+            ipc_host,
+            linux: Box::new(linux),
+            mounts,
+            network: Box::new(network),
+            pid_host,
+            propagated_mount,
+            user: None,
+            work_dir,
+            rootfs: None,
+        }
+    }
+}
+/// PluginConfigArgs : PluginConfigArgs plugin config args
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigArgs {
+    /// description
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// name
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// settable
+    #[serde(rename = "Settable")]
+    pub settable: Vec<String>,
+    /// value
+    #[serde(rename = "Value")]
+    pub value: Vec<String>,
+}
+impl PluginConfigArgs {
+    /// PluginConfigArgs plugin config args
+    pub fn new(
+        description: String,
+        name: String,
+        settable: Vec<String>,
+        value: Vec<String>,
+    ) -> PluginConfigArgs {
+        PluginConfigArgs {
+            description,
+            name,
+            settable,
+            value,
+        }
+    }
+}
+/// PluginConfigInterface : PluginConfigInterface The interface between Docker and the plugin
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigInterface {
+    /// Protocol to use for clients connecting to the plugin.
+    #[serde(rename = "ProtocolScheme", skip_serializing_if = "Option::is_none")]
+    pub protocol_scheme: Option<String>,
+    /// socket
+    #[serde(rename = "Socket")]
+    pub socket: String,
+    /// types
+    #[serde(rename = "Types")]
+    pub types: Vec<PluginInterfaceType>,
+}
+impl PluginConfigInterface {
+    /// PluginConfigInterface The interface between Docker and the plugin
+    pub fn new(
+        socket: String,
+        types: Vec<PluginInterfaceType>,
+    ) -> PluginConfigInterface {
+        PluginConfigInterface {
+            protocol_scheme: None,
+            socket,
+            types,
+        }
+    }
+}
+/// PluginConfigLinux : PluginConfigLinux plugin config linux
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigLinux {
+    /// allow all devices
+    #[serde(rename = "AllowAllDevices")]
+    pub allow_all_devices: bool,
+    /// capabilities
+    #[serde(rename = "Capabilities")]
+    pub capabilities: Vec<String>,
+    /// devices
+    #[serde(rename = "Devices")]
+    pub devices: Vec<PluginDevice>,
+}
+impl PluginConfigLinux {
+    /// PluginConfigLinux plugin config linux
+    pub fn new(
+        allow_all_devices: bool,
+        capabilities: Vec<String>,
+        devices: Vec<PluginDevice>,
+    ) -> PluginConfigLinux {
+        PluginConfigLinux {
+            allow_all_devices,
+            capabilities,
+            devices,
+        }
+    }
+}
+/// PluginConfigNetwork : PluginConfigNetwork plugin config network
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigNetwork {
+    /// type
+    #[serde(rename = "Type")]
+    pub r#type: String,
+}
+impl PluginConfigNetwork {
+    /// PluginConfigNetwork plugin config network
+    pub fn new(r#type: String) -> PluginConfigNetwork {
+        PluginConfigNetwork { r#type }
+    }
+}
+/// PluginConfigRootfs : PluginConfigRootfs plugin config rootfs
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigRootfs {
+    /// diff ids
+    #[serde(rename = "diff_ids", skip_serializing_if = "Option::is_none")]
+    pub diff_ids: Option<Vec<String>>,
+    /// type
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl PluginConfigRootfs {
+    /// PluginConfigRootfs plugin config rootfs
+    pub fn new() -> PluginConfigRootfs {
+        PluginConfigRootfs {
+            diff_ids: None,
+            r#type: None,
+        }
+    }
+}
+/// PluginConfigUser : PluginConfigUser plugin config user
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigUser {
+    /// g ID
+    #[serde(rename = "GID", skip_serializing_if = "Option::is_none")]
+    pub gid: Option<u32>,
+    /// UID
+    #[serde(rename = "UID", skip_serializing_if = "Option::is_none")]
+    pub uid: Option<u32>,
+}
+impl PluginConfigUser {
+    /// PluginConfigUser plugin config user
+    pub fn new() -> PluginConfigUser {
+        PluginConfigUser {
+            gid: None,
+            uid: None,
+        }
+    }
+}
+/// PluginDevice : PluginDevice plugin device
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginDevice {
+    /// description
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// name
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// path
+    #[serde(rename = "Path")]
+    pub path: String,
+    /// settable
+    #[serde(rename = "Settable")]
+    pub settable: Vec<String>,
+}
+impl PluginDevice {
+    /// PluginDevice plugin device
+    pub fn new(
+        description: String,
+        name: String,
+        path: String,
+        settable: Vec<String>,
+    ) -> PluginDevice {
+        PluginDevice {
+            description,
+            name,
+            path,
+            settable,
+        }
+    }
+}
+/// PluginEnv : PluginEnv plugin env
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginEnv {
+    /// description
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// name
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// settable
+    #[serde(rename = "Settable")]
+    pub settable: Vec<String>,
+    /// value
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+impl PluginEnv {
+    /// PluginEnv plugin env
+    pub fn new(
+        description: String,
+        name: String,
+        settable: Vec<String>,
+        value: String,
+    ) -> PluginEnv {
+        PluginEnv {
+            description,
+            name,
+            settable,
+            value,
+        }
+    }
+}
+/// PluginInterfaceType : PluginInterfaceType plugin interface type
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginInterfaceType {
+    /// capability
+    #[serde(rename = "Capability")]
+    pub capability: String,
+    /// prefix
+    #[serde(rename = "Prefix")]
+    pub prefix: String,
+    /// version
+    #[serde(rename = "Version")]
+    pub version: String,
+}
+impl PluginInterfaceType {
+    /// PluginInterfaceType plugin interface type
+    pub fn new(
+        capability: String,
+        prefix: String,
+        version: String,
+    ) -> PluginInterfaceType {
+        PluginInterfaceType {
+            capability,
+            prefix,
+            version,
+        }
+    }
+}
+/// PluginMount : PluginMount plugin mount
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginMount {
+    /// description
+    #[serde(rename = "Description")]
+    pub description: String,
+    /// destination
+    #[serde(rename = "Destination")]
+    pub destination: String,
+    /// name
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// options
+    #[serde(rename = "Options")]
+    pub options: Vec<String>,
+    /// settable
+    #[serde(rename = "Settable")]
+    pub settable: Vec<String>,
+    /// source
+    #[serde(rename = "Source")]
+    pub source: String,
+    /// type
+    #[serde(rename = "Type")]
+    pub r#type: String,
+}
+impl PluginMount {
+    /// PluginMount plugin mount
+    pub fn new(
+        description: String,
+        destination: String,
+        name: String,
+        options: Vec<String>,
+        settable: Vec<String>,
+        source: String,
+        r#type: String,
+    ) -> PluginMount {
+        PluginMount {
+            description,
+            destination,
+            name,
+            options,
+            settable,
+            source,
+            r#type,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PluginSettings {
+    /// args
+    #[serde(rename = "Args")]
+    pub args: Vec<String>,
+    /// devices
+    #[serde(rename = "Devices")]
+    pub devices: Vec<PluginDevice>,
+    /// env
+    #[serde(rename = "Env")]
+    pub env: Vec<String>,
+    /// mounts
+    #[serde(rename = "Mounts")]
+    pub mounts: Vec<PluginMount>,
+}
+impl PluginSettings {
+    pub fn new(
+        args: Vec<String>,
+        devices: Vec<PluginDevice>,
+        env: Vec<String>,
+        mounts: Vec<PluginMount>,
+    ) -> PluginSettings {
+        PluginSettings {
+            args,
+            devices,
+            env,
+            mounts,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plugins {
+    /// Authorization is provided for compatibility, will always be nil as Podman has no daemon
+    #[serde(rename = "authorization", skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<Vec<String>>,
+    #[serde(rename = "log", skip_serializing_if = "Option::is_none")]
+    pub log: Option<Vec<String>>,
+    #[serde(rename = "network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<Vec<String>>,
+    #[serde(rename = "volume", skip_serializing_if = "Option::is_none")]
+    pub volume: Option<Vec<String>>,
+}
+impl Plugins {
+    pub fn new() -> Plugins {
+        Plugins {
+            authorization: None,
+            log: None,
+            network: None,
+            volume: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodBasicConfig {
+    /// ExitPolicy determines the pod's exit and stop behaviour.
+    #[serde(rename = "exit_policy", skip_serializing_if = "Option::is_none")]
+    pub exit_policy: Option<String>,
+    /// Hostname is the pod's hostname. If not set, the name of the pod will be used (if a name was not provided here, the name auto-generated for the pod will be used). This will be used by the infra container and all containers in the pod as long as the UTS namespace is shared. Optional.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// InfraCommand sets the command that will be used to start the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_command", skip_serializing_if = "Option::is_none")]
+    pub infra_command: Option<Vec<String>>,
+    /// InfraConmonPidFile is a custom path to store the infra container's conmon PID.
+    #[serde(rename = "infra_conmon_pid_file", skip_serializing_if = "Option::is_none")]
+    pub infra_conmon_pid_file: Option<String>,
+    /// InfraImage is the image that will be used for the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_image", skip_serializing_if = "Option::is_none")]
+    pub infra_image: Option<String>,
+    /// InfraName is the name that will be used for the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_name", skip_serializing_if = "Option::is_none")]
+    pub infra_name: Option<String>,
+    #[serde(rename = "ipcns", skip_serializing_if = "Option::is_none")]
+    pub ipcns: Option<Box<Namespace>>,
+    /// Labels are key-value pairs that are used to add metadata to pods. Optional.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Name is the name of the pod. If not provided, a name will be generated when the pod is created. Optional.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// NoInfra tells the pod not to create an infra container. If this is done, many networking-related options will become unavailable. Conflicts with setting any options in PodNetworkConfig, and the InfraCommand and InfraImages in this struct. Optional.
+    #[serde(rename = "no_infra", skip_serializing_if = "Option::is_none")]
+    pub no_infra: Option<bool>,
+    #[serde(rename = "pidns", skip_serializing_if = "Option::is_none")]
+    pub pidns: Option<Box<Namespace>>,
+    #[serde(rename = "pod_create_command", skip_serializing_if = "Option::is_none")]
+    pub pod_create_command: Option<Vec<String>>,
+    /// Devices contains user specified Devices to be added to the Pod
+    #[serde(rename = "pod_devices", skip_serializing_if = "Option::is_none")]
+    pub pod_devices: Option<Vec<String>>,
+    /// RestartPolicy is the pod's restart policy - an action which will be taken when one or all the containers in the pod exits. If not given, the default policy will be set to Always, which restarts the containers in the pod when they exit indefinitely. Optional.
+    #[serde(rename = "restart_policy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// RestartRetries is the number of attempts that will be made to restart the container. Only available when RestartPolicy is set to \"on-failure\". Optional.
+    #[serde(rename = "restart_tries", skip_serializing_if = "Option::is_none")]
+    pub restart_tries: Option<u64>,
+    /// PodCreateCommand is the command used to create this pod. This will be shown in the output of Inspect() on the pod, and may also be used by some tools that wish to recreate the pod (e.g. `podman generate systemd --new`). Optional. ShareParent determines if all containers in the pod will share the pod's cgroup as the cgroup parent
+    #[serde(rename = "share_parent", skip_serializing_if = "Option::is_none")]
+    pub share_parent: Option<bool>,
+    /// SharedNamespaces instructs the pod to share a set of namespaces. Shared namespaces will be joined (by default) by every container which joins the pod. If not set and NoInfra is false, the pod will set a default set of namespaces to share. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "shared_namespaces", skip_serializing_if = "Option::is_none")]
+    pub shared_namespaces: Option<Vec<String>>,
+    /// Sysctl sets kernel parameters for the pod
+    #[serde(rename = "sysctl", skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "userns", skip_serializing_if = "Option::is_none")]
+    pub userns: Option<Box<Namespace>>,
+    #[serde(rename = "utsns", skip_serializing_if = "Option::is_none")]
+    pub utsns: Option<Box<Namespace>>,
+}
+impl PodBasicConfig {
+    pub fn new() -> PodBasicConfig {
+        PodBasicConfig {
+            exit_policy: None,
+            hostname: None,
+            infra_command: None,
+            infra_conmon_pid_file: None,
+            infra_image: None,
+            infra_name: None,
+            ipcns: None,
+            labels: None,
+            name: None,
+            no_infra: None,
+            pidns: None,
+            pod_create_command: None,
+            pod_devices: None,
+            restart_policy: None,
+            restart_tries: None,
+            share_parent: None,
+            shared_namespaces: None,
+            sysctl: None,
+            userns: None,
+            utsns: None,
+        }
+    }
+}
+/// PodCgroupConfig : This will be expanded in future updates to pods.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodCgroupConfig {
+    /// CgroupParent is the parent for the Cgroup that the pod will create. This pod cgroup will, in turn, be the default cgroup parent for all containers in the pod. Optional.
+    #[serde(rename = "cgroup_parent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+}
+impl PodCgroupConfig {
+    /// This will be expanded in future updates to pods.
+    pub fn new() -> PodCgroupConfig {
+        PodCgroupConfig {
+            cgroup_parent: None,
+        }
+    }
+}
+/// PodCreateOptions : The JSON tags below are made to match the respective field in ContainerCreateOptions for the purpose of mapping.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodCreateOptions {
+    #[serde(rename = "cgroup_parent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    #[serde(rename = "container_command", skip_serializing_if = "Option::is_none")]
+    pub container_command: Option<String>,
+    #[serde(
+        rename = "container_conmon_pidfile",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub container_conmon_pidfile: Option<String>,
+    #[serde(rename = "container_name", skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+    #[serde(rename = "cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f64>,
+    #[serde(rename = "cpuset_cpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    #[serde(rename = "create_command", skip_serializing_if = "Option::is_none")]
+    pub create_command: Option<Vec<String>>,
+    #[serde(rename = "device_read_bps", skip_serializing_if = "Option::is_none")]
+    pub device_read_bps: Option<Vec<String>>,
+    #[serde(rename = "devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<String>>,
+    #[serde(rename = "exit_policy", skip_serializing_if = "Option::is_none")]
+    pub exit_policy: Option<String>,
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(rename = "infra", skip_serializing_if = "Option::is_none")]
+    pub infra: Option<bool>,
+    #[serde(rename = "infra_image", skip_serializing_if = "Option::is_none")]
+    pub infra_image: Option<String>,
+    #[serde(rename = "ipc", skip_serializing_if = "Option::is_none")]
+    pub ipc: Option<String>,
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "net", skip_serializing_if = "Option::is_none")]
+    pub net: Option<Box<NetOptions>>,
+    #[serde(rename = "pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<String>,
+    #[serde(rename = "restart", skip_serializing_if = "Option::is_none")]
+    pub restart: Option<String>,
+    #[serde(rename = "security_opt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+    #[serde(rename = "share", skip_serializing_if = "Option::is_none")]
+    pub share: Option<Vec<String>>,
+    #[serde(rename = "share_parent", skip_serializing_if = "Option::is_none")]
+    pub share_parent: Option<bool>,
+    #[serde(rename = "sysctl", skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<Vec<String>>,
+    #[serde(rename = "uts", skip_serializing_if = "Option::is_none")]
+    pub uts: Option<String>,
+    #[serde(rename = "volume", skip_serializing_if = "Option::is_none")]
+    pub volume: Option<Vec<String>>,
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl PodCreateOptions {
+    /// The JSON tags below are made to match the respective field in ContainerCreateOptions for the purpose of mapping.
+    pub fn new() -> PodCreateOptions {
+        PodCreateOptions {
+            cgroup_parent: None,
+            container_command: None,
+            container_conmon_pidfile: None,
+            container_name: None,
+            cpus: None,
+            cpuset_cpus: None,
+            create_command: None,
+            device_read_bps: None,
+            devices: None,
+            exit_policy: None,
+            hostname: None,
+            infra: None,
+            infra_image: None,
+            ipc: None,
+            labels: None,
+            name: None,
+            net: None,
+            pid: None,
+            restart: None,
+            security_opt: None,
+            share: None,
+            share_parent: None,
+            sysctl: None,
+            uts: None,
+            volume: None,
+            volumes_from: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodKillReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodKillReport {
+    pub fn new() -> PodKillReport {
+        PodKillReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodNetworkConfig {
+    /// Map of networks names to ids the container should join to. You can request additional settings for each network, you can set network aliases, static ips, static mac address  and the network interface name for this container on the specific network. If the map is empty and the bridge network mode is set the container will be joined to the default network.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, PerNetworkOptions>>,
+    /// CNINetworks is a list of CNI networks to join the container to. If this list is empty, the default CNI network will be joined instead. If at least one entry is present, we will not join the default network (unless it is part of this list). Only available if NetNS is set to bridge. Optional. Deprecated: as of podman 4.0 use \"Networks\" instead.
+    #[serde(rename = "cni_networks", skip_serializing_if = "Option::is_none")]
+    pub cni_networks: Option<Vec<String>>,
+    /// DNSOption is a set of DNS options that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_option", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    /// DNSSearch is a set of DNS search domains that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. If not provided, DNS search domains from the host's resolv.conf will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_search", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// DNSServer is a set of DNS servers that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. If not provided, the host's DNS servers will be used, unless the only server set is a localhost address. As the container cannot connect to the host's localhost, a default server will instead be set. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_server", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    /// HostAdd is a set of hosts that will be added to the infra container's etc/hosts that will, by default, be shared with all containers in the pod. Conflicts with NoInfra=true and NoManageHosts. Optional.
+    #[serde(rename = "hostadd", skip_serializing_if = "Option::is_none")]
+    pub hostadd: Option<Vec<String>>,
+    #[serde(rename = "netns", skip_serializing_if = "Option::is_none")]
+    pub netns: Option<Box<Namespace>>,
+    /// NetworkOptions are additional options for each network Optional.
+    #[serde(rename = "network_options", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    /// NoManageHosts indicates that /etc/hosts should not be managed by the pod. Instead, each container will create a separate /etc/hosts as they would if not in a pod. Conflicts with HostAdd.
+    #[serde(rename = "no_manage_hosts", skip_serializing_if = "Option::is_none")]
+    pub no_manage_hosts: Option<bool>,
+    /// NoManageResolvConf indicates that /etc/resolv.conf should not be managed by the pod. Instead, each container will create and manage a separate resolv.conf as if they had not joined a pod. Conflicts with NoInfra=true and DNSServer, DNSSearch, DNSOption. Optional.
+    #[serde(rename = "no_manage_resolv_conf", skip_serializing_if = "Option::is_none")]
+    pub no_manage_resolv_conf: Option<bool>,
+    /// PortMappings is a set of ports to map into the infra container. As, by default, containers share their network with the infra container, this will forward the ports to the entire pod. Only available if NetNS is set to Bridge, Slirp, or Pasta. Optional.
+    #[serde(rename = "portmappings", skip_serializing_if = "Option::is_none")]
+    pub portmappings: Option<Vec<PortMapping>>,
+}
+impl PodNetworkConfig {
+    pub fn new() -> PodNetworkConfig {
+        PodNetworkConfig {
+            networks: None,
+            cni_networks: None,
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            hostadd: None,
+            netns: None,
+            network_options: None,
+            no_manage_hosts: None,
+            no_manage_resolv_conf: None,
+            portmappings: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodPauseReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodPauseReport {
+    pub fn new() -> PodPauseReport {
+        PodPauseReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodPruneReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodPruneReport {
+    pub fn new() -> PodPruneReport {
+        PodPruneReport {
+            err: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodResourceConfig {
+    /// CPU period of the cpuset, determined by --cpus
+    #[serde(rename = "cpu_period", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<u64>,
+    /// CPU quota of the cpuset, determined by --cpus
+    #[serde(rename = "cpu_quota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    #[serde(rename = "resource_limits", skip_serializing_if = "Option::is_none")]
+    pub resource_limits: Option<Box<LinuxResources>>,
+    /// ThrottleReadBpsDevice contains the rate at which the devices in the pod can be read from/accessed
+    #[serde(rename = "throttleReadBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+}
+impl PodResourceConfig {
+    pub fn new() -> PodResourceConfig {
+        PodResourceConfig {
+            cpu_period: None,
+            cpu_quota: None,
+            resource_limits: None,
+            throttle_read_bps_device: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodRestartReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodRestartReport {
+    pub fn new() -> PodRestartReport {
+        PodRestartReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodRmReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "RemovedCtrs", skip_serializing_if = "Option::is_none")]
+    pub removed_ctrs: Option<std::collections::HashMap<String, String>>,
+}
+impl PodRmReport {
+    pub fn new() -> PodRmReport {
+        PodRmReport {
+            err: None,
+            id: None,
+            removed_ctrs: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodSecurityConfig {
+    #[serde(rename = "idmappings", skip_serializing_if = "Option::is_none")]
+    pub idmappings: Option<Box<IDMappingOptions>>,
+    #[serde(rename = "security_opt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+}
+impl PodSecurityConfig {
+    pub fn new() -> PodSecurityConfig {
+        PodSecurityConfig {
+            idmappings: None,
+            security_opt: None,
+        }
+    }
+}
+/// PodSpecGenerator : PodSpecGenerator describes options to create a pod
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodSpecGenerator {
+    /// Map of networks names to ids the container should join to. You can request additional settings for each network, you can set network aliases, static ips, static mac address  and the network interface name for this container on the specific network. If the map is empty and the bridge network mode is set the container will be joined to the default network.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, PerNetworkOptions>>,
+    /// CgroupParent is the parent for the Cgroup that the pod will create. This pod cgroup will, in turn, be the default cgroup parent for all containers in the pod. Optional.
+    #[serde(rename = "cgroup_parent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// CNINetworks is a list of CNI networks to join the container to. If this list is empty, the default CNI network will be joined instead. If at least one entry is present, we will not join the default network (unless it is part of this list). Only available if NetNS is set to bridge. Optional. Deprecated: as of podman 4.0 use \"Networks\" instead.
+    #[serde(rename = "cni_networks", skip_serializing_if = "Option::is_none")]
+    pub cni_networks: Option<Vec<String>>,
+    /// CPU period of the cpuset, determined by --cpus
+    #[serde(rename = "cpu_period", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<u64>,
+    /// CPU quota of the cpuset, determined by --cpus
+    #[serde(rename = "cpu_quota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    /// DNSOption is a set of DNS options that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_option", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    /// DNSSearch is a set of DNS search domains that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. If not provided, DNS search domains from the host's resolv.conf will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_search", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// DNSServer is a set of DNS servers that will be used in the infra container's resolv.conf, which will, by default, be shared with all containers in the pod. If not provided, the host's DNS servers will be used, unless the only server set is a localhost address. As the container cannot connect to the host's localhost, a default server will instead be set. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "dns_server", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    /// ExitPolicy determines the pod's exit and stop behaviour.
+    #[serde(rename = "exit_policy", skip_serializing_if = "Option::is_none")]
+    pub exit_policy: Option<String>,
+    /// HostAdd is a set of hosts that will be added to the infra container's etc/hosts that will, by default, be shared with all containers in the pod. Conflicts with NoInfra=true and NoManageHosts. Optional.
+    #[serde(rename = "hostadd", skip_serializing_if = "Option::is_none")]
+    pub hostadd: Option<Vec<String>>,
+    /// Hostname is the pod's hostname. If not set, the name of the pod will be used (if a name was not provided here, the name auto-generated for the pod will be used). This will be used by the infra container and all containers in the pod as long as the UTS namespace is shared. Optional.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(rename = "idmappings", skip_serializing_if = "Option::is_none")]
+    pub idmappings: Option<Box<IDMappingOptions>>,
+    /// Image volumes bind-mount a container-image mount into the pod's infra container. Optional.
+    #[serde(rename = "image_volumes", skip_serializing_if = "Option::is_none")]
+    pub image_volumes: Option<Vec<ImageVolume>>,
+    /// InfraCommand sets the command that will be used to start the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_command", skip_serializing_if = "Option::is_none")]
+    pub infra_command: Option<Vec<String>>,
+    /// InfraConmonPidFile is a custom path to store the infra container's conmon PID.
+    #[serde(rename = "infra_conmon_pid_file", skip_serializing_if = "Option::is_none")]
+    pub infra_conmon_pid_file: Option<String>,
+    /// InfraImage is the image that will be used for the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_image", skip_serializing_if = "Option::is_none")]
+    pub infra_image: Option<String>,
+    /// InfraName is the name that will be used for the infra container. If not set, the default set in the Libpod configuration file will be used. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "infra_name", skip_serializing_if = "Option::is_none")]
+    pub infra_name: Option<String>,
+    #[serde(rename = "ipcns", skip_serializing_if = "Option::is_none")]
+    pub ipcns: Option<Box<Namespace>>,
+    /// Labels are key-value pairs that are used to add metadata to pods. Optional.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Mounts are mounts that will be added to the pod. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    /// Name is the name of the pod. If not provided, a name will be generated when the pod is created. Optional.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "netns", skip_serializing_if = "Option::is_none")]
+    pub netns: Option<Box<Namespace>>,
+    /// NetworkOptions are additional options for each network Optional.
+    #[serde(rename = "network_options", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    /// NoInfra tells the pod not to create an infra container. If this is done, many networking-related options will become unavailable. Conflicts with setting any options in PodNetworkConfig, and the InfraCommand and InfraImages in this struct. Optional.
+    #[serde(rename = "no_infra", skip_serializing_if = "Option::is_none")]
+    pub no_infra: Option<bool>,
+    /// NoManageHosts indicates that /etc/hosts should not be managed by the pod. Instead, each container will create a separate /etc/hosts as they would if not in a pod. Conflicts with HostAdd.
+    #[serde(rename = "no_manage_hosts", skip_serializing_if = "Option::is_none")]
+    pub no_manage_hosts: Option<bool>,
+    /// NoManageResolvConf indicates that /etc/resolv.conf should not be managed by the pod. Instead, each container will create and manage a separate resolv.conf as if they had not joined a pod. Conflicts with NoInfra=true and DNSServer, DNSSearch, DNSOption. Optional.
+    #[serde(rename = "no_manage_resolv_conf", skip_serializing_if = "Option::is_none")]
+    pub no_manage_resolv_conf: Option<bool>,
+    /// Overlay volumes are named volumes that will be added to the pod. Optional.
+    #[serde(rename = "overlay_volumes", skip_serializing_if = "Option::is_none")]
+    pub overlay_volumes: Option<Vec<OverlayVolume>>,
+    #[serde(rename = "pidns", skip_serializing_if = "Option::is_none")]
+    pub pidns: Option<Box<Namespace>>,
+    #[serde(rename = "pod_create_command", skip_serializing_if = "Option::is_none")]
+    pub pod_create_command: Option<Vec<String>>,
+    /// Devices contains user specified Devices to be added to the Pod
+    #[serde(rename = "pod_devices", skip_serializing_if = "Option::is_none")]
+    pub pod_devices: Option<Vec<String>>,
+    /// PortMappings is a set of ports to map into the infra container. As, by default, containers share their network with the infra container, this will forward the ports to the entire pod. Only available if NetNS is set to Bridge, Slirp, or Pasta. Optional.
+    #[serde(rename = "portmappings", skip_serializing_if = "Option::is_none")]
+    pub portmappings: Option<Vec<PortMapping>>,
+    #[serde(rename = "resource_limits", skip_serializing_if = "Option::is_none")]
+    pub resource_limits: Option<Box<LinuxResources>>,
+    /// RestartPolicy is the pod's restart policy - an action which will be taken when one or all the containers in the pod exits. If not given, the default policy will be set to Always, which restarts the containers in the pod when they exit indefinitely. Optional.
+    #[serde(rename = "restart_policy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// RestartRetries is the number of attempts that will be made to restart the container. Only available when RestartPolicy is set to \"on-failure\". Optional.
+    #[serde(rename = "restart_tries", skip_serializing_if = "Option::is_none")]
+    pub restart_tries: Option<u64>,
+    #[serde(rename = "security_opt", skip_serializing_if = "Option::is_none")]
+    pub security_opt: Option<Vec<String>>,
+    /// The ID of the pod's service container.
+    #[serde(rename = "serviceContainerID", skip_serializing_if = "Option::is_none")]
+    pub service_container_id: Option<String>,
+    /// PodCreateCommand is the command used to create this pod. This will be shown in the output of Inspect() on the pod, and may also be used by some tools that wish to recreate the pod (e.g. `podman generate systemd --new`). Optional. ShareParent determines if all containers in the pod will share the pod's cgroup as the cgroup parent
+    #[serde(rename = "share_parent", skip_serializing_if = "Option::is_none")]
+    pub share_parent: Option<bool>,
+    /// SharedNamespaces instructs the pod to share a set of namespaces. Shared namespaces will be joined (by default) by every container which joins the pod. If not set and NoInfra is false, the pod will set a default set of namespaces to share. Conflicts with NoInfra=true. Optional.
+    #[serde(rename = "shared_namespaces", skip_serializing_if = "Option::is_none")]
+    pub shared_namespaces: Option<Vec<String>>,
+    /// ShmSize is the size of the tmpfs to mount in at /dev/shm, in bytes. Conflicts with ShmSize if IpcNS is not private. Optional.
+    #[serde(rename = "shm_size", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    /// ShmSizeSystemd is the size of systemd-specific tmpfs mounts specifically /run, /run/lock, /var/log/journal and /tmp. Optional
+    #[serde(rename = "shm_size_systemd", skip_serializing_if = "Option::is_none")]
+    pub shm_size_systemd: Option<i64>,
+    /// Sysctl sets kernel parameters for the pod
+    #[serde(rename = "sysctl", skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<std::collections::HashMap<String, String>>,
+    /// ThrottleReadBpsDevice contains the rate at which the devices in the pod can be read from/accessed
+    #[serde(rename = "throttleReadBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    #[serde(rename = "userns", skip_serializing_if = "Option::is_none")]
+    pub userns: Option<Box<Namespace>>,
+    #[serde(rename = "utsns", skip_serializing_if = "Option::is_none")]
+    pub utsns: Option<Box<Namespace>>,
+    /// Volumes are named volumes that will be added to the pod. These will supersede Image Volumes and VolumesFrom  volumes where there are conflicts. Optional.
+    #[serde(rename = "volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<NamedVolume>>,
+    /// VolumesFrom is a set of containers whose volumes will be added to this pod. The name or ID of the container must be provided, and may optionally be followed by a : and then one or more comma-separated options. Valid options are 'ro', 'rw', and 'z'. Options will be used for all volumes sourced from the container.
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl PodSpecGenerator {
+    /// PodSpecGenerator describes options to create a pod
+    pub fn new() -> PodSpecGenerator {
+        PodSpecGenerator {
+            networks: None,
+            cgroup_parent: None,
+            cni_networks: None,
+            cpu_period: None,
+            cpu_quota: None,
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            exit_policy: None,
+            hostadd: None,
+            hostname: None,
+            idmappings: None,
+            image_volumes: None,
+            infra_command: None,
+            infra_conmon_pid_file: None,
+            infra_image: None,
+            infra_name: None,
+            ipcns: None,
+            labels: None,
+            mounts: None,
+            name: None,
+            netns: None,
+            network_options: None,
+            no_infra: None,
+            no_manage_hosts: None,
+            no_manage_resolv_conf: None,
+            overlay_volumes: None,
+            pidns: None,
+            pod_create_command: None,
+            pod_devices: None,
+            portmappings: None,
+            resource_limits: None,
+            restart_policy: None,
+            restart_tries: None,
+            security_opt: None,
+            service_container_id: None,
+            share_parent: None,
+            shared_namespaces: None,
+            shm_size: None,
+            shm_size_systemd: None,
+            sysctl: None,
+            throttle_read_bps_device: None,
+            userns: None,
+            utsns: None,
+            volumes: None,
+            volumes_from: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodStartReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodStartReport {
+    pub fn new() -> PodStartReport {
+        PodStartReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodStatsReport {
+    /// Humanized disk usage read + write
+    #[serde(rename = "BlockIO", skip_serializing_if = "Option::is_none")]
+    pub block_io: Option<String>,
+    /// Container ID
+    #[serde(rename = "CID", skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    /// Percentage of CPU utilized by pod
+    #[serde(rename = "CPU", skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// Percentage of Memory utilized by pod
+    #[serde(rename = "Mem", skip_serializing_if = "Option::is_none")]
+    pub mem: Option<String>,
+    /// Humanized Memory usage and maximum
+    #[serde(rename = "MemUsage", skip_serializing_if = "Option::is_none")]
+    pub mem_usage: Option<String>,
+    /// Memory usage and maximum in bytes
+    #[serde(rename = "MemUsageBytes", skip_serializing_if = "Option::is_none")]
+    pub mem_usage_bytes: Option<String>,
+    /// Pod Name
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Network usage inbound + outbound
+    #[serde(rename = "NetIO", skip_serializing_if = "Option::is_none")]
+    pub net_io: Option<String>,
+    /// Container PID
+    #[serde(rename = "PIDS", skip_serializing_if = "Option::is_none")]
+    pub pids: Option<String>,
+    /// Pod ID
+    #[serde(rename = "Pod", skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+}
+impl PodStatsReport {
+    pub fn new() -> PodStatsReport {
+        PodStatsReport {
+            block_io: None,
+            cid: None,
+            cpu: None,
+            mem: None,
+            mem_usage: None,
+            mem_usage_bytes: None,
+            name: None,
+            net_io: None,
+            pids: None,
+            pod: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodStopReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodStopReport {
+    pub fn new() -> PodStopReport {
+        PodStopReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodStorageConfig {
+    /// Image volumes bind-mount a container-image mount into the pod's infra container. Optional.
+    #[serde(rename = "image_volumes", skip_serializing_if = "Option::is_none")]
+    pub image_volumes: Option<Vec<ImageVolume>>,
+    /// Mounts are mounts that will be added to the pod. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    /// Overlay volumes are named volumes that will be added to the pod. Optional.
+    #[serde(rename = "overlay_volumes", skip_serializing_if = "Option::is_none")]
+    pub overlay_volumes: Option<Vec<OverlayVolume>>,
+    /// ShmSize is the size of the tmpfs to mount in at /dev/shm, in bytes. Conflicts with ShmSize if IpcNS is not private. Optional.
+    #[serde(rename = "shm_size", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    /// ShmSizeSystemd is the size of systemd-specific tmpfs mounts specifically /run, /run/lock, /var/log/journal and /tmp. Optional
+    #[serde(rename = "shm_size_systemd", skip_serializing_if = "Option::is_none")]
+    pub shm_size_systemd: Option<i64>,
+    /// Volumes are named volumes that will be added to the pod. These will supersede Image Volumes and VolumesFrom  volumes where there are conflicts. Optional.
+    #[serde(rename = "volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<NamedVolume>>,
+    /// VolumesFrom is a set of containers whose volumes will be added to this pod. The name or ID of the container must be provided, and may optionally be followed by a : and then one or more comma-separated options. Valid options are 'ro', 'rw', and 'z'. Options will be used for all volumes sourced from the container.
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+}
+impl PodStorageConfig {
+    pub fn new() -> PodStorageConfig {
+        PodStorageConfig {
+            image_volumes: None,
+            mounts: None,
+            overlay_volumes: None,
+            shm_size: None,
+            shm_size_systemd: None,
+            volumes: None,
+            volumes_from: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodTopOKBody {
+    /// Each process running in the container, where each is process is an array of values corresponding to the titles.
+    #[serde(rename = "Processes")]
+    pub processes: Vec<Vec<String>>,
+    /// The ps column titles
+    #[serde(rename = "Titles")]
+    pub titles: Vec<String>,
+}
+impl PodTopOKBody {
+    pub fn new(processes: Vec<Vec<String>>, titles: Vec<String>) -> PodTopOKBody {
+        PodTopOKBody { processes, titles }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PodUnpauseReport {
+    #[serde(rename = "Errs", skip_serializing_if = "Option::is_none")]
+    pub errs: Option<Vec<String>>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl PodUnpauseReport {
+    pub fn new() -> PodUnpauseReport {
+        PodUnpauseReport {
+            errs: None,
+            id: None,
+        }
+    }
+}
+/// Port : Port An open port on a container
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Port {
+    /// Host IP address that the container's port is mapped to
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Port on the container
+    #[serde(rename = "PrivatePort")]
+    pub private_port: u32,
+    /// Port exposed on the host
+    #[serde(rename = "PublicPort", skip_serializing_if = "Option::is_none")]
+    pub public_port: Option<u32>,
+    /// type
+    #[serde(rename = "Type")]
+    pub r#type: String,
+}
+impl Port {
+    /// Port An open port on a container
+    pub fn new(private_port: u32, r#type: String) -> Port {
+        Port {
+            ip: None,
+            private_port,
+            public_port: None,
+            r#type,
+        }
+    }
+}
+/// PortBinding : PortBinding represents a binding between a Host IP address and a Host Port
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortBinding {
+    /// HostIP is the host IP Address
+    #[serde(rename = "HostIp", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// HostPort is the host port number
+    #[serde(rename = "HostPort", skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<String>,
+}
+impl PortBinding {
+    /// PortBinding represents a binding between a Host IP address and a Host Port
+    pub fn new() -> PortBinding {
+        PortBinding {
+            host_ip: None,
+            host_port: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortMapping {
+    /// ContainerPort is the port number that will be exposed from the container. Mandatory.
+    #[serde(rename = "container_port", skip_serializing_if = "Option::is_none")]
+    pub container_port: Option<u32>,
+    /// HostIP is the IP that we will bind to on the host. If unset, assumed to be 0.0.0.0 (all interfaces).
+    #[serde(rename = "host_ip", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// HostPort is the port number that will be forwarded from the host into the container. If omitted, a random port on the host (guaranteed to be over 1024) will be assigned.
+    #[serde(rename = "host_port", skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<u32>,
+    /// Protocol is the protocol forward. Must be either \"tcp\", \"udp\", and \"sctp\", or some combination of these separated by commas. If unset, assumed to be TCP.
+    #[serde(rename = "protocol", skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Range is the number of ports that will be forwarded, starting at HostPort and ContainerPort and counting up. This is 1-indexed, so 1 is assumed to be a single port (only the Hostport:Containerport mapping will be added), 2 is two ports (both Hostport:Containerport and Hostport+1:Containerport+1), etc. If unset, assumed to be 1 (a single port). Both hostport + range and containerport + range must be less than 65536.
+    #[serde(rename = "range", skip_serializing_if = "Option::is_none")]
+    pub range: Option<u32>,
+}
+impl PortMapping {
+    pub fn new() -> PortMapping {
+        PortMapping {
+            container_port: None,
+            host_ip: None,
+            host_port: None,
+            protocol: None,
+            range: None,
+        }
+    }
+}
+/// POSIXRlimit : POSIXRlimit type and restrictions
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct POSIXRlimit {
+    /// Hard is the hard limit for the specified type
+    #[serde(rename = "hard", skip_serializing_if = "Option::is_none")]
+    pub hard: Option<u64>,
+    /// Soft is the soft limit for the specified type
+    #[serde(rename = "soft", skip_serializing_if = "Option::is_none")]
+    pub soft: Option<u64>,
+    /// Type of the rlimit to set
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl POSIXRlimit {
+    /// POSIXRlimit type and restrictions
+    pub fn new() -> POSIXRlimit {
+        POSIXRlimit {
+            hard: None,
+            soft: None,
+            r#type: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PruneReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+impl PruneReport {
+    pub fn new() -> PruneReport {
+        PruneReport {
+            err: None,
+            id: None,
+            size: None,
+        }
+    }
+}
+/// PublishStatus : PublishStatus represents the status of the volume as published to an individual node
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PublishStatus {
+    /// NodeID is the ID of the swarm node this Volume is published to.
+    #[serde(rename = "NodeID", skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    /// PublishContext is the PublishContext returned by the CSI plugin when a volume is published.
+    #[serde(rename = "PublishContext", skip_serializing_if = "Option::is_none")]
+    pub publish_context: Option<std::collections::HashMap<String, String>>,
+    /// PublishState represents the state of a Volume as it pertains to its use on a particular Node.
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+impl PublishStatus {
+    /// PublishStatus represents the status of the volume as published to an individual node
+    pub fn new() -> PublishStatus {
+        PublishStatus {
+            node_id: None,
+            publish_context: None,
+            state: None,
+        }
+    }
+}
+/// RemoteSocket : RemoteSocket describes information about the API socket
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RemoteSocket {
+    #[serde(rename = "exists", skip_serializing_if = "Option::is_none")]
+    pub exists: Option<bool>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+impl RemoteSocket {
+    /// RemoteSocket describes information about the API socket
+    pub fn new() -> RemoteSocket {
+        RemoteSocket {
+            exists: None,
+            path: None,
+        }
+    }
+}
+/// Resources : Resources contains container's resources (cgroups config, ulimits...)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Resources {
+    #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioWeight", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u32>,
+    #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<WeightDevice>>,
+    /// Applicable to UNIX platforms
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// Applicable to Windows
+    #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
+    pub cpu_count: Option<i64>,
+    #[serde(rename = "CpuPercent", skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<i64>,
+    #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<i64>,
+    #[serde(rename = "CpuQuota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    #[serde(rename = "CpuRealtimePeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_period: Option<i64>,
+    #[serde(rename = "CpuRealtimeRuntime", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_runtime: Option<i64>,
+    /// Applicable to all platforms
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<i64>,
+    #[serde(rename = "CpusetCpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    #[serde(rename = "CpusetMems", skip_serializing_if = "Option::is_none")]
+    pub cpuset_mems: Option<String>,
+    #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
+    pub device_cgroup_rules: Option<Vec<String>>,
+    #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
+    pub device_requests: Option<Vec<DeviceRequest>>,
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<DeviceMapping>>,
+    #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_bandwidth: Option<u64>,
+    #[serde(rename = "IOMaximumIOps", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_i_ops: Option<u64>,
+    /// KernelMemory specifies the kernel memory limit (in bytes) for the container. Deprecated: kernel 5.4 deprecated kmem.limit_in_bytes.
+    #[serde(rename = "KernelMemory", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory: Option<i64>,
+    #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory_tcp: Option<i64>,
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    #[serde(rename = "MemoryReservation", skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    #[serde(rename = "MemorySwap", skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<i64>,
+    #[serde(rename = "MemorySwappiness", skip_serializing_if = "Option::is_none")]
+    pub memory_swappiness: Option<i64>,
+    #[serde(rename = "NanoCpus", skip_serializing_if = "Option::is_none")]
+    pub nano_cpus: Option<i64>,
+    #[serde(rename = "OomKillDisable", skip_serializing_if = "Option::is_none")]
+    pub oom_kill_disable: Option<bool>,
+    #[serde(rename = "PidsLimit", skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
+    pub ulimits: Option<Vec<Ulimit>>,
+}
+impl Resources {
+    /// Resources contains container's resources (cgroups config, ulimits...)
+    pub fn new() -> Resources {
+        Resources {
+            blkio_device_read_bps: None,
+            blkio_device_read_i_ops: None,
+            blkio_device_write_bps: None,
+            blkio_device_write_i_ops: None,
+            blkio_weight: None,
+            blkio_weight_device: None,
+            cgroup_parent: None,
+            cpu_count: None,
+            cpu_percent: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpu_realtime_period: None,
+            cpu_realtime_runtime: None,
+            cpu_shares: None,
+            cpuset_cpus: None,
+            cpuset_mems: None,
+            device_cgroup_rules: None,
+            device_requests: None,
+            devices: None,
+            io_maximum_bandwidth: None,
+            io_maximum_i_ops: None,
+            kernel_memory: None,
+            kernel_memory_tcp: None,
+            memory: None,
+            memory_reservation: None,
+            memory_swap: None,
+            memory_swappiness: None,
+            nano_cpus: None,
+            oom_kill_disable: None,
+            pids_limit: None,
+            ulimits: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RestartPolicy {
+    #[serde(rename = "MaximumRetryCount", skip_serializing_if = "Option::is_none")]
+    pub maximum_retry_count: Option<i64>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl RestartPolicy {
+    pub fn new() -> RestartPolicy {
+        RestartPolicy {
+            maximum_retry_count: None,
+            name: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RootFS {
+    #[serde(rename = "Layers", skip_serializing_if = "Option::is_none")]
+    pub layers: Option<Vec<String>>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+impl RootFS {
+    pub fn new() -> RootFS {
+        RootFS {
+            layers: None,
+            r#type: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// Destination for this route in CIDR form.
+    #[serde(rename = "destination", skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// Gateway IP for this route.
+    #[serde(rename = "gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    /// Metric for this route. Optional.
+    #[serde(rename = "metric", skip_serializing_if = "Option::is_none")]
+    pub metric: Option<u32>,
+}
+impl Route {
+    pub fn new() -> Route {
+        Route {
+            destination: None,
+            gateway: None,
+            metric: None,
+        }
+    }
+}
+/// Schema2HealthConfig : Schema2HealthConfig is a HealthConfig, which holds configuration settings for the HEALTHCHECK feature, from docker/docker/api/types/container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Schema2HealthConfig {
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Interval", skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// Retries is the number of consecutive failures needed to consider a container as unhealthy. Zero means inherit.
+    #[serde(rename = "Retries", skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartInterval", skip_serializing_if = "Option::is_none")]
+    pub start_interval: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartPeriod", skip_serializing_if = "Option::is_none")]
+    pub start_period: Option<i64>,
+    /// Test is the test to perform to check that the container is healthy. An empty slice means to inherit the default. The options are: {} : inherit healthcheck {\"NONE\"} : disable healthcheck {\"CMD\", args...} : exec arguments directly {\"CMD-SHELL\", command} : run command with system's default shell
+    #[serde(rename = "Test", skip_serializing_if = "Option::is_none")]
+    pub test: Option<Vec<String>>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+}
+impl Schema2HealthConfig {
+    /// Schema2HealthConfig is a HealthConfig, which holds configuration settings for the HEALTHCHECK feature, from docker/docker/api/types/container.
+    pub fn new() -> Schema2HealthConfig {
+        Schema2HealthConfig {
+            interval: None,
+            retries: None,
+            start_interval: None,
+            start_period: None,
+            test: None,
+            timeout: None,
+        }
+    }
+}
+/// Schema2ListPublic : This is publicly visible as c/image/manifest.Schema2List. Internal users should usually use Schema2List instead.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Schema2ListPublic {
+    #[serde(rename = "manifests", skip_serializing_if = "Option::is_none")]
+    pub manifests: Option<Vec<Schema2ManifestDescriptor>>,
+    #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    #[serde(rename = "schemaVersion", skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<i64>,
+}
+impl Schema2ListPublic {
+    /// This is publicly visible as c/image/manifest.Schema2List. Internal users should usually use Schema2List instead.
+    pub fn new() -> Schema2ListPublic {
+        Schema2ListPublic {
+            manifests: None,
+            media_type: None,
+            schema_version: None,
+        }
+    }
+}
+/// Schema2ManifestDescriptor : This is publicly visible as c/image/manifest.Schema2ManifestDescriptor.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Schema2ManifestDescriptor {
+    /// The following is an example of the contents of Digest types:  sha256:7173b809ca12ec5dee4506cd86be934c4596dd234ee82c0662eac04a8c2c71dc  This allows to abstract the digest behind this type and work only in those terms.
+    #[serde(rename = "digest", skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    #[serde(rename = "platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Box<Schema2PlatformSpec>>,
+    #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "urls", skip_serializing_if = "Option::is_none")]
+    pub urls: Option<Vec<String>>,
+}
+impl Schema2ManifestDescriptor {
+    /// This is publicly visible as c/image/manifest.Schema2ManifestDescriptor.
+    pub fn new() -> Schema2ManifestDescriptor {
+        Schema2ManifestDescriptor {
+            digest: None,
+            media_type: None,
+            platform: None,
+            size: None,
+            urls: None,
+        }
+    }
+}
+/// Schema2PlatformSpec : Schema2PlatformSpec describes the platform which a particular manifest is specialized for. This is publicly visible as c/image/manifest.Schema2PlatformSpec.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Schema2PlatformSpec {
+    #[serde(rename = "architecture", skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    #[serde(rename = "os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    #[serde(rename = "os.features", skip_serializing_if = "Option::is_none")]
+    pub os_period_features: Option<Vec<String>>,
+    #[serde(rename = "os.version", skip_serializing_if = "Option::is_none")]
+    pub os_period_version: Option<String>,
+    #[serde(rename = "variant", skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+impl Schema2PlatformSpec {
+    /// Schema2PlatformSpec describes the platform which a particular manifest is specialized for. This is publicly visible as c/image/manifest.Schema2PlatformSpec.
+    pub fn new() -> Schema2PlatformSpec {
+        Schema2PlatformSpec {
+            architecture: None,
+            features: None,
+            os: None,
+            os_period_features: None,
+            os_period_version: None,
+            variant: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScpReport {
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl ScpReport {
+    pub fn new() -> ScpReport {
+        ScpReport { id: None }
+    }
+}
+/// Secret : Secret represents a Swarm Secret value that must be passed to the CSI storage plugin when operating on this Volume. It represents one key-value pair of possibly many.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Secret {
+    /// Key is the name of the key of the key-value pair passed to the plugin.
+    #[serde(rename = "Key", skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Secret is the swarm Secret object from which to read data. This can be a Secret name or ID. The Secret data is retrieved by Swarm and used as the value of the key-value pair passed to the plugin.
+    #[serde(rename = "Secret", skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+impl Secret {
+    /// Secret represents a Swarm Secret value that must be passed to the CSI storage plugin when operating on this Volume. It represents one key-value pair of possibly many.
+    pub fn new() -> Secret {
+        Secret { key: None, secret: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretCreate {
+    /// Base64-url-safe-encoded (RFC 4648) data to store as secret.
+    #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<Box<SecretDriverSpec>>,
+    /// Labels are labels on the secret
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// User-defined name of the secret.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl SecretCreate {
+    pub fn new() -> SecretCreate {
+        SecretCreate {
+            data: None,
+            driver: None,
+            labels: None,
+            name: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretCreateLibpod201Response {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl SecretCreateLibpod201Response {
+    pub fn new() -> SecretCreateLibpod201Response {
+        SecretCreateLibpod201Response {
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretCreateReport {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl SecretCreateReport {
+    pub fn new() -> SecretCreateReport {
+        SecretCreateReport { id: None }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretDeleteLibpod404Response {
+    /// API root cause formatted for automated parsing
+    #[serde(rename = "cause", skip_serializing_if = "Option::is_none")]
+    pub cause: Option<String>,
+    /// human error message, formatted for a human to read
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// HTTP response code
+    #[serde(rename = "response", skip_serializing_if = "Option::is_none")]
+    pub response: Option<u64>,
+}
+impl SecretDeleteLibpod404Response {
+    pub fn new() -> SecretDeleteLibpod404Response {
+        SecretDeleteLibpod404Response {
+            cause: None,
+            message: None,
+            response: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretDriverSpec {
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+}
+impl SecretDriverSpec {
+    pub fn new() -> SecretDriverSpec {
+        SecretDriverSpec {
+            name: None,
+            options: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretInfoReport {
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "SecretData", skip_serializing_if = "Option::is_none")]
+    pub secret_data: Option<String>,
+    #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<Box<SecretSpec>>,
+    #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+impl SecretInfoReport {
+    pub fn new() -> SecretInfoReport {
+        SecretInfoReport {
+            created_at: None,
+            id: None,
+            secret_data: None,
+            spec: None,
+            updated_at: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretInfoReportCompat {
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "SecretData", skip_serializing_if = "Option::is_none")]
+    pub secret_data: Option<String>,
+    #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<Box<SecretSpec>>,
+    #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<Box<SecretVersion>>,
+}
+impl SecretInfoReportCompat {
+    pub fn new() -> SecretInfoReportCompat {
+        SecretInfoReportCompat {
+            created_at: None,
+            id: None,
+            secret_data: None,
+            spec: None,
+            updated_at: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretRmReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl SecretRmReport {
+    pub fn new() -> SecretRmReport {
+        SecretRmReport {
+            err: None,
+            id: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretSpec {
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<Box<SecretDriverSpec>>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl SecretSpec {
+    pub fn new() -> SecretSpec {
+        SecretSpec {
+            driver: None,
+            labels: None,
+            name: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecretVersion {
+    #[serde(rename = "Index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<i64>,
+}
+impl SecretVersion {
+    pub fn new() -> SecretVersion {
+        SecretVersion { index: None }
+    }
+}
+/// SecurityInfo : SecurityInfo describes the libpod host
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecurityInfo {
+    #[serde(rename = "apparmorEnabled", skip_serializing_if = "Option::is_none")]
+    pub apparmor_enabled: Option<bool>,
+    #[serde(rename = "capabilities", skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<String>,
+    #[serde(rename = "rootless", skip_serializing_if = "Option::is_none")]
+    pub rootless: Option<bool>,
+    #[serde(rename = "seccompEnabled", skip_serializing_if = "Option::is_none")]
+    pub seccomp_enabled: Option<bool>,
+    #[serde(rename = "seccompProfilePath", skip_serializing_if = "Option::is_none")]
+    pub seccomp_profile_path: Option<String>,
+    #[serde(rename = "selinuxEnabled", skip_serializing_if = "Option::is_none")]
+    pub selinux_enabled: Option<bool>,
+}
+impl SecurityInfo {
+    /// SecurityInfo describes the libpod host
+    pub fn new() -> SecurityInfo {
+        SecurityInfo {
+            apparmor_enabled: None,
+            capabilities: None,
+            rootless: None,
+            seccomp_enabled: None,
+            seccomp_profile_path: None,
+            selinux_enabled: None,
+        }
+    }
+}
+/// ServiceCreateResponse : ServiceCreateResponse contains the information returned to a client on the creation of a new service.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServiceCreateResponse {
+    /// The ID of the created service.
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Optional warning message.  FIXME(thaJeztah): this should have \"omitempty\" in the generated type.
+    #[serde(rename = "Warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+impl ServiceCreateResponse {
+    /// ServiceCreateResponse contains the information returned to a client on the creation of a new service.
+    pub fn new() -> ServiceCreateResponse {
+        ServiceCreateResponse {
+            id: None,
+            warnings: None,
+        }
+    }
+}
+/// ServiceInfo : ServiceInfo represents service parameters with the list of service's tasks
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServiceInfo {
+    #[serde(rename = "LocalLBIndex", skip_serializing_if = "Option::is_none")]
+    pub local_lb_index: Option<i64>,
+    #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<String>>,
+    #[serde(rename = "Tasks", skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<Vec<Task>>,
+    #[serde(rename = "VIP", skip_serializing_if = "Option::is_none")]
+    pub vip: Option<String>,
+}
+impl ServiceInfo {
+    /// ServiceInfo represents service parameters with the list of service's tasks
+    pub fn new() -> ServiceInfo {
+        ServiceInfo {
+            local_lb_index: None,
+            ports: None,
+            tasks: None,
+            vip: None,
+        }
+    }
+}
+/// ServiceUpdateResponse : ServiceUpdateResponse service update response
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServiceUpdateResponse {
+    /// Optional warning messages
+    #[serde(rename = "Warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+impl ServiceUpdateResponse {
+    /// ServiceUpdateResponse service update response
+    pub fn new() -> ServiceUpdateResponse {
+        ServiceUpdateResponse {
+            warnings: None,
+        }
+    }
+}
+/// SlirpInfo : SlirpInfo describes the slirp executable that is being used
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SlirpInfo {
+    #[serde(rename = "executable", skip_serializing_if = "Option::is_none")]
+    pub executable: Option<String>,
+    #[serde(rename = "package", skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl SlirpInfo {
+    /// SlirpInfo describes the slirp executable that is being used
+    pub fn new() -> SlirpInfo {
+        SlirpInfo {
+            executable: None,
+            package: None,
+            version: None,
+        }
+    }
+}
+/// SpecGenerator : SpecGenerator creates an OCI spec and Libpod configuration options to create a container based on the given configuration.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpecGenerator {
+    /// Map of networks names or ids that the container should join. You can request additional settings for each network, you can set network aliases, static ips, static mac address  and the network interface name for this container on the specific network. If the map is empty and the bridge network mode is set the container will be joined to the default network. Optional.
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, PerNetworkOptions>>,
+    /// Annotations are key-value options passed into the container runtime that can be used to trigger special behavior. Optional.
+    #[serde(rename = "annotations", skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::HashMap<String, String>>,
+    /// ApparmorProfile is the name of the Apparmor profile the container will use. Optional.
+    #[serde(rename = "apparmor_profile", skip_serializing_if = "Option::is_none")]
+    pub apparmor_profile: Option<String>,
+    /// BaseHostsFile is the path to a hosts file, the entries from this file are added to the containers hosts file. As special value \"image\" is allowed which uses the /etc/hosts file from within the image and \"none\" which uses no base file at all. If it is empty we should default to the base_hosts_file configuration in containers.conf. Optional.
+    #[serde(rename = "base_hosts_file", skip_serializing_if = "Option::is_none")]
+    pub base_hosts_file: Option<String>,
+    /// CapAdd are capabilities which will be added to the container. Conflicts with Privileged. Optional.
+    #[serde(rename = "cap_add", skip_serializing_if = "Option::is_none")]
+    pub cap_add: Option<Vec<String>>,
+    /// CapDrop are capabilities which will be removed from the container. Conflicts with Privileged. Optional.
+    #[serde(rename = "cap_drop", skip_serializing_if = "Option::is_none")]
+    pub cap_drop: Option<Vec<String>>,
+    /// CgroupParent is the container's Cgroup parent. If not set, the default for the current cgroup driver will be used. Optional.
+    #[serde(rename = "cgroup_parent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    #[serde(rename = "cgroupns", skip_serializing_if = "Option::is_none")]
+    pub cgroupns: Option<Box<Namespace>>,
+    /// CgroupsMode sets a policy for how cgroups will be created for the container, including the ability to disable creation entirely. Optional.
+    #[serde(rename = "cgroups_mode", skip_serializing_if = "Option::is_none")]
+    pub cgroups_mode: Option<String>,
+    /// ChrootDirs is an additional set of directories that need to be treated as root directories. Standard bind mounts will be mounted into paths relative to these directories. Optional.
+    #[serde(rename = "chroot_directories", skip_serializing_if = "Option::is_none")]
+    pub chroot_directories: Option<Vec<String>>,
+    /// CNINetworks is a list of CNI networks to join the container to. If this list is empty, the default CNI network will be joined instead. If at least one entry is present, we will not join the default network (unless it is part of this list). Only available if NetNS is set to bridge. Optional. Deprecated: as of podman 4.0 use \"Networks\" instead.
+    #[serde(rename = "cni_networks", skip_serializing_if = "Option::is_none")]
+    pub cni_networks: Option<Vec<String>>,
+    /// Command is the container's command. If not given and Image is specified, this will be populated by the image's configuration. Optional.
+    #[serde(rename = "command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// ConmonPidFile is a path at which a PID file for Conmon will be placed. If not given, a default location will be used. Optional.
+    #[serde(rename = "conmon_pid_file", skip_serializing_if = "Option::is_none")]
+    pub conmon_pid_file: Option<String>,
+    /// ContainerCreateCommand is the command that was used to create this container. This will be shown in the output of Inspect() on the container, and may also be used by some tools that wish to recreate the container (e.g. `podman generate systemd --new`). Optional.
+    #[serde(rename = "containerCreateCommand", skip_serializing_if = "Option::is_none")]
+    pub container_create_command: Option<Vec<String>>,
+    /// Create the working directory if it doesn't exist. If unset, it doesn't create it. Optional.
+    #[serde(rename = "create_working_dir", skip_serializing_if = "Option::is_none")]
+    pub create_working_dir: Option<bool>,
+    /// DependencyContainers is an array of containers this container depends on. Dependency containers must be started before this container. Dependencies can be specified by name or full/partial ID. Optional.
+    #[serde(rename = "dependencyContainers", skip_serializing_if = "Option::is_none")]
+    pub dependency_containers: Option<Vec<String>>,
+    /// DeviceCgroupRule are device cgroup rules that allow containers to use additional types of devices.
+    #[serde(rename = "device_cgroup_rule", skip_serializing_if = "Option::is_none")]
+    pub device_cgroup_rule: Option<Vec<LinuxDeviceCgroup>>,
+    /// Devices are devices that will be added to the container. Optional.
+    #[serde(rename = "devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<LinuxDevice>>,
+    /// DevicesFrom specifies that this container will mount the device(s) from other container(s). Optional.
+    #[serde(rename = "devices_from", skip_serializing_if = "Option::is_none")]
+    pub devices_from: Option<Vec<String>>,
+    /// DNSOptions is a set of DNS options that will be used in the container's resolv.conf, replacing the host's DNS options which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_option", skip_serializing_if = "Option::is_none")]
+    pub dns_option: Option<Vec<String>>,
+    /// DNSSearch is a set of DNS search domains that will be used in the container's resolv.conf, replacing the host's DNS search domains which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_search", skip_serializing_if = "Option::is_none")]
+    pub dns_search: Option<Vec<String>>,
+    /// DNSServers is a set of DNS servers that will be used in the container's resolv.conf, replacing the host's DNS Servers which are used by default. Conflicts with UseImageResolvConf. Optional.
+    #[serde(rename = "dns_server", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<Vec<String>>,
+    /// Entrypoint is the container's entrypoint. If not given and Image is specified, this will be populated by the image's configuration. Optional.
+    #[serde(rename = "entrypoint", skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Vec<String>>,
+    /// Env is a set of environment variables that will be set in the container. Optional.
+    #[serde(rename = "env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<std::collections::HashMap<String, String>>,
+    /// EnvHost indicates that the host environment should be added to container Optional.
+    #[serde(rename = "env_host", skip_serializing_if = "Option::is_none")]
+    pub env_host: Option<bool>,
+    /// EnvMerge takes the specified environment variables from image and preprocess them before injecting them into the container. Optional.
+    #[serde(rename = "envmerge", skip_serializing_if = "Option::is_none")]
+    pub envmerge: Option<Vec<String>>,
+    /// Expose is a number of ports that will be forwarded to the container if PublishExposedPorts is set. Expose is a map of uint16 (port number) to a string representing protocol i.e map[uint16]string. Allowed protocols are \"tcp\", \"udp\", and \"sctp\", or some combination of the three separated by commas. If protocol is set to \"\" we will assume TCP. Only available if NetNS is set to Bridge or Slirp, and PublishExposedPorts is set. Optional.
+    #[serde(rename = "expose", skip_serializing_if = "Option::is_none")]
+    pub expose: Option<serde_json::Value>,
+    /// GroupEntry specifies an arbitrary string to append to the container's /etc/group file. Optional.
+    #[serde(rename = "group_entry", skip_serializing_if = "Option::is_none")]
+    pub group_entry: Option<String>,
+    /// Groups are a list of supplemental groups the container's user will be granted access to. Optional.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// HealthCheckOnFailureAction defines how Podman reacts when a container's health status turns unhealthy.
+    #[serde(
+        rename = "health_check_on_failure_action",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub health_check_on_failure_action: Option<i64>,
+    #[serde(rename = "healthconfig", skip_serializing_if = "Option::is_none")]
+    pub healthconfig: Option<Box<Schema2HealthConfig>>,
+    /// HostDeviceList is used to recreate the mounted device on inherited containers
+    #[serde(rename = "host_device_list", skip_serializing_if = "Option::is_none")]
+    pub host_device_list: Option<Vec<LinuxDevice>>,
+    /// HostAdd is a set of hosts which will be added to the container's etc/hosts file. Conflicts with UseImageHosts. Optional.
+    #[serde(rename = "hostadd", skip_serializing_if = "Option::is_none")]
+    pub hostadd: Option<Vec<String>>,
+    /// Hostname is the container's hostname. If not set, the hostname will not be modified (if UtsNS is not private) or will be set to the container ID (if UtsNS is private). Conflicts with UtsNS if UtsNS is not set to private. Optional.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// HostUsers is a list of host usernames or UIDs to add to the container etc/passwd file
+    #[serde(rename = "hostusers", skip_serializing_if = "Option::is_none")]
+    pub hostusers: Option<Vec<String>>,
+    /// EnvHTTPProxy indicates that the http host proxy environment variables should be added to container Optional.
+    #[serde(rename = "httpproxy", skip_serializing_if = "Option::is_none")]
+    pub httpproxy: Option<bool>,
+    #[serde(rename = "idmappings", skip_serializing_if = "Option::is_none")]
+    pub idmappings: Option<Box<IDMappingOptions>>,
+    /// Image is the image the container will be based on. The image will be used as the container's root filesystem, and its environment vars, volumes, and other configuration will be applied to the container. Conflicts with Rootfs. At least one of Image or Rootfs must be specified.
+    #[serde(rename = "image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// ImageArch is the user-specified image architecture. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_arch", skip_serializing_if = "Option::is_none")]
+    pub image_arch: Option<String>,
+    /// ImageOS is the user-specified OS of the image. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_os", skip_serializing_if = "Option::is_none")]
+    pub image_os: Option<String>,
+    /// ImageVariant is the user-specified image variant. Used to select a different variant from a manifest list. Optional.
+    #[serde(rename = "image_variant", skip_serializing_if = "Option::is_none")]
+    pub image_variant: Option<String>,
+    /// ImageVolumeMode indicates how image volumes will be created. Supported modes are \"ignore\" (do not create), \"tmpfs\" (create as tmpfs), and \"anonymous\" (create as anonymous volumes). The default if unset is anonymous. Optional.
+    #[serde(rename = "image_volume_mode", skip_serializing_if = "Option::is_none")]
+    pub image_volume_mode: Option<String>,
+    /// Image volumes bind-mount a container-image mount into the container. Optional.
+    #[serde(rename = "image_volumes", skip_serializing_if = "Option::is_none")]
+    pub image_volumes: Option<Vec<ImageVolume>>,
+    /// Init specifies that an init binary will be mounted into the container, and will be used as PID1. Optional.
+    #[serde(rename = "init", skip_serializing_if = "Option::is_none")]
+    pub init: Option<bool>,
+    /// InitContainerType describes if this container is an init container and if so, what type: always or once. Optional.
+    #[serde(rename = "init_container_type", skip_serializing_if = "Option::is_none")]
+    pub init_container_type: Option<String>,
+    /// InitPath specifies the path to the init binary that will be added if Init is specified above. If not specified, the default set in the Libpod config will be used. Ignored if Init above is not set. Optional.
+    #[serde(rename = "init_path", skip_serializing_if = "Option::is_none")]
+    pub init_path: Option<String>,
+    #[serde(rename = "intelRdt", skip_serializing_if = "Option::is_none")]
+    pub intel_rdt: Option<Box<LinuxIntelRdt>>,
+    #[serde(rename = "ipcns", skip_serializing_if = "Option::is_none")]
+    pub ipcns: Option<Box<Namespace>>,
+    /// LabelNested indicates whether or not the container is allowed to run fully nested containers including SELinux labelling. Optional.
+    #[serde(rename = "label_nested", skip_serializing_if = "Option::is_none")]
+    pub label_nested: Option<bool>,
+    /// Labels are key-value pairs that are used to add metadata to containers. Optional.
+    #[serde(rename = "labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "log_configuration", skip_serializing_if = "Option::is_none")]
+    pub log_configuration: Option<Box<LogConfigLibpod>>,
+    /// Passwd is a container run option that determines if we are validating users/groups before running the container
+    #[serde(rename = "manage_password", skip_serializing_if = "Option::is_none")]
+    pub manage_password: Option<bool>,
+    /// Mask is the path we want to mask in the container. This masks the paths given in addition to the default list. Optional
+    #[serde(rename = "mask", skip_serializing_if = "Option::is_none")]
+    pub mask: Option<Vec<String>>,
+    /// Mounts are mounts that will be added to the container. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    /// Name is the name the container will be given. If no name is provided, one will be randomly generated. Optional.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "netns", skip_serializing_if = "Option::is_none")]
+    pub netns: Option<Box<Namespace>>,
+    /// NetworkOptions are additional options for each network Optional.
+    #[serde(rename = "network_options", skip_serializing_if = "Option::is_none")]
+    pub network_options: Option<std::collections::HashMap<String, Vec<String>>>,
+    /// NoNewPrivileges is whether the container will set the no new privileges flag on create, which disables gaining additional privileges (e.g. via setuid) in the container. Optional.
+    #[serde(rename = "no_new_privileges", skip_serializing_if = "Option::is_none")]
+    pub no_new_privileges: Option<bool>,
+    /// OCIRuntime is the name of the OCI runtime that will be used to create the container. If not specified, the default will be used. Optional.
+    #[serde(rename = "oci_runtime", skip_serializing_if = "Option::is_none")]
+    pub oci_runtime: Option<String>,
+    /// OOMScoreAdj adjusts the score used by the OOM killer to determine processes to kill for the container's process. Optional.
+    #[serde(rename = "oom_score_adj", skip_serializing_if = "Option::is_none")]
+    pub oom_score_adj: Option<i64>,
+    /// Overlay volumes are named volumes that will be added to the container. Optional.
+    #[serde(rename = "overlay_volumes", skip_serializing_if = "Option::is_none")]
+    pub overlay_volumes: Option<Vec<OverlayVolume>>,
+    /// PasswdEntry specifies an arbitrary string to append to the container's /etc/passwd file. Optional.
+    #[serde(rename = "passwd_entry", skip_serializing_if = "Option::is_none")]
+    pub passwd_entry: Option<String>,
+    #[serde(rename = "personality", skip_serializing_if = "Option::is_none")]
+    pub personality: Option<Box<LinuxPersonality>>,
+    #[serde(rename = "pidns", skip_serializing_if = "Option::is_none")]
+    pub pidns: Option<Box<Namespace>>,
+    /// Pod is the ID of the pod the container will join. Optional.
+    #[serde(rename = "pod", skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    /// PortBindings is a set of ports to map into the container. Only available if NetNS is set to bridge, slirp, or pasta. Optional.
+    #[serde(rename = "portmappings", skip_serializing_if = "Option::is_none")]
+    pub portmappings: Option<Vec<PortMapping>>,
+    /// Privileged is whether the container is privileged. Privileged does the following: Adds all devices on the system to the container. Adds all capabilities to the container. Disables Seccomp, SELinux, and Apparmor confinement. (Though SELinux can be manually re-enabled). TODO: this conflicts with things. TODO: this does more. Optional.
+    #[serde(rename = "privileged", skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// ProcOpts are the options used for the proc mount.
+    #[serde(rename = "procfs_opts", skip_serializing_if = "Option::is_none")]
+    pub procfs_opts: Option<Vec<String>>,
+    /// PublishExposedPorts will publish ports specified in the image to random unused ports (guaranteed to be above 1024) on the host. This is based on ports set in Expose below, and any ports specified by the Image (if one is given). Only available if NetNS is set to Bridge or Slirp. Optional.
+    #[serde(rename = "publish_image_ports", skip_serializing_if = "Option::is_none")]
+    pub publish_image_ports: Option<bool>,
+    /// Rlimits are POSIX rlimits to apply to the container. Optional.
+    #[serde(rename = "r_limits", skip_serializing_if = "Option::is_none")]
+    pub r_limits: Option<Vec<POSIXRlimit>>,
+    /// RawImageName is the user-specified and unprocessed input referring to a local or a remote image. Optional, but strongly encouraged to be set if Image is set.
+    #[serde(rename = "raw_image_name", skip_serializing_if = "Option::is_none")]
+    pub raw_image_name: Option<String>,
+    /// ReadOnlyFilesystem indicates that everything will be mounted as read-only. Optional.
+    #[serde(rename = "read_only_filesystem", skip_serializing_if = "Option::is_none")]
+    pub read_only_filesystem: Option<bool>,
+    /// ReadWriteTmpfs indicates that when running with a ReadOnlyFilesystem mount temporary file systems. Optional.
+    #[serde(rename = "read_write_tmpfs", skip_serializing_if = "Option::is_none")]
+    pub read_write_tmpfs: Option<bool>,
+    /// Remove indicates if the container should be removed once it has been started and exits. Optional.
+    #[serde(rename = "remove", skip_serializing_if = "Option::is_none")]
+    pub remove: Option<bool>,
+    #[serde(rename = "resource_limits", skip_serializing_if = "Option::is_none")]
+    pub resource_limits: Option<Box<LinuxResources>>,
+    /// RestartPolicy is the container's restart policy - an action which will be taken when the container exits. If not given, the default policy, which does nothing, will be used. Optional.
+    #[serde(rename = "restart_policy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// RestartRetries is the number of attempts that will be made to restart the container. Only available when RestartPolicy is set to \"on-failure\". Optional.
+    #[serde(rename = "restart_tries", skip_serializing_if = "Option::is_none")]
+    pub restart_tries: Option<u64>,
+    /// Rootfs is the path to a directory that will be used as the container's root filesystem. No modification will be made to the directory, it will be directly mounted into the container as root. Conflicts with Image. At least one of Image or Rootfs must be specified.
+    #[serde(rename = "rootfs", skip_serializing_if = "Option::is_none")]
+    pub rootfs: Option<String>,
+    /// RootfsMapping specifies if there are UID/GID mappings to apply to the rootfs. Optional.
+    #[serde(rename = "rootfs_mapping", skip_serializing_if = "Option::is_none")]
+    pub rootfs_mapping: Option<String>,
+    /// RootfsOverlay tells if rootfs is actually an overlay on top of base path. Optional.
+    #[serde(rename = "rootfs_overlay", skip_serializing_if = "Option::is_none")]
+    pub rootfs_overlay: Option<bool>,
+    /// RootfsPropagation is the rootfs propagation mode for the container. If not set, the default of rslave will be used. Optional.
+    #[serde(rename = "rootfs_propagation", skip_serializing_if = "Option::is_none")]
+    pub rootfs_propagation: Option<String>,
+    /// Determine how to handle the NOTIFY_SOCKET - do we participate or pass it through \"container\" - let the OCI runtime deal with it, advertise conmon's MAINPID \"conmon-only\" - advertise conmon's MAINPID, send READY when started, don't pass to OCI \"ignore\" - unset NOTIFY_SOCKET Optional.
+    #[serde(rename = "sdnotifyMode", skip_serializing_if = "Option::is_none")]
+    pub sdnotify_mode: Option<String>,
+    /// SeccompPolicy determines which seccomp profile gets applied the container. valid values: empty,default,image
+    #[serde(rename = "seccomp_policy", skip_serializing_if = "Option::is_none")]
+    pub seccomp_policy: Option<String>,
+    /// SeccompProfilePath is the path to a JSON file containing the container's Seccomp profile. If not specified, no Seccomp profile will be used. Optional.
+    #[serde(rename = "seccomp_profile_path", skip_serializing_if = "Option::is_none")]
+    pub seccomp_profile_path: Option<String>,
+    /// EnvSecrets are secrets that will be set as environment variables Optional.
+    #[serde(rename = "secret_env", skip_serializing_if = "Option::is_none")]
+    pub secret_env: Option<std::collections::HashMap<String, String>>,
+    /// Secrets are the secrets that will be added to the container Optional.
+    #[serde(rename = "secrets", skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<Secret>>,
+    /// SelinuxProcessLabel is the process label the container will use. If SELinux is enabled and this is not specified, a label will be automatically generated if not specified. Optional.
+    #[serde(rename = "selinux_opts", skip_serializing_if = "Option::is_none")]
+    pub selinux_opts: Option<Vec<String>>,
+    /// ShmSize is the size of the tmpfs to mount in at /dev/shm, in bytes. Conflicts with ShmSize if IpcNS is not private. Optional.
+    #[serde(rename = "shm_size", skip_serializing_if = "Option::is_none")]
+    pub shm_size: Option<i64>,
+    /// ShmSizeSystemd is the size of systemd-specific tmpfs mounts specifically /run, /run/lock, /var/log/journal and /tmp. Optional
+    #[serde(rename = "shm_size_systemd", skip_serializing_if = "Option::is_none")]
+    pub shm_size_systemd: Option<i64>,
+    #[serde(rename = "startupHealthConfig", skip_serializing_if = "Option::is_none")]
+    pub startup_health_config: Option<Box<StartupHealthCheck>>,
+    /// Stdin is whether the container will keep its STDIN open. Optional.
+    #[serde(rename = "stdin", skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<bool>,
+    /// It implements the os.Signal interface.
+    #[serde(rename = "stop_signal", skip_serializing_if = "Option::is_none")]
+    pub stop_signal: Option<i64>,
+    /// StopTimeout is a timeout between the container's stop signal being sent and SIGKILL being sent. If not provided, the default will be used. If 0 is used, stop signal will not be sent, and SIGKILL will be sent instead. Optional.
+    #[serde(rename = "stop_timeout", skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<u64>,
+    /// StorageOpts is the container's storage options Optional.
+    #[serde(rename = "storage_opts", skip_serializing_if = "Option::is_none")]
+    pub storage_opts: Option<std::collections::HashMap<String, String>>,
+    /// Sysctl sets kernel parameters for the container
+    #[serde(rename = "sysctl", skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<std::collections::HashMap<String, String>>,
+    /// Systemd is whether the container will be started in systemd mode. Valid options are \"true\", \"false\", and \"always\". \"true\" enables this mode only if the binary run in the container is sbin/init or systemd. \"always\" unconditionally enables systemd mode. \"false\" unconditionally disables systemd mode. If enabled, mounts and stop signal will be modified. If set to \"always\" or set to \"true\" and conditionally triggered, conflicts with StopSignal. If not specified, \"false\" will be assumed. Optional.
+    #[serde(rename = "systemd", skip_serializing_if = "Option::is_none")]
+    pub systemd: Option<String>,
+    /// Terminal is whether the container will create a PTY. Optional.
+    #[serde(rename = "terminal", skip_serializing_if = "Option::is_none")]
+    pub terminal: Option<bool>,
+    /// IO read rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleReadBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO read rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleReadIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_read_iops_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO write rate limit per cgroup per device, bytes per second
+    #[serde(rename = "throttleWriteBpsDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_bps_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// IO write rate limit per cgroup per device, IO per second
+    #[serde(rename = "throttleWriteIOPSDevice", skip_serializing_if = "Option::is_none")]
+    pub throttle_write_iops_device: Option<
+        std::collections::HashMap<String, LinuxThrottleDevice>,
+    >,
+    /// Timeout is a maximum time in seconds the container will run before main process is sent SIGKILL. If 0 is used, signal will not be sent. Container can run indefinitely if they do not stop after the default termination signal. Optional.
+    #[serde(rename = "timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Timezone is the timezone inside the container. Local means it has the same timezone as the host machine Optional.
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Umask is the umask the init process of the container will be run with.
+    #[serde(rename = "umask", skip_serializing_if = "Option::is_none")]
+    pub umask: Option<String>,
+    /// CgroupConf are key-value options passed into the container runtime that are used to configure cgroup v2. Optional.
+    #[serde(rename = "unified", skip_serializing_if = "Option::is_none")]
+    pub unified: Option<std::collections::HashMap<String, String>>,
+    /// Unmask a path in the container. Some paths are masked by default, preventing them from being accessed within the container; this undoes that masking. If ALL is passed, all paths will be unmasked. Optional.
+    #[serde(rename = "unmask", skip_serializing_if = "Option::is_none")]
+    pub unmask: Option<Vec<String>>,
+    /// UnsetEnv unsets the specified default environment variables from the image or from buildin or containers.conf Optional.
+    #[serde(rename = "unsetenv", skip_serializing_if = "Option::is_none")]
+    pub unsetenv: Option<Vec<String>>,
+    /// UnsetEnvAll unsetall default environment variables from the image or from buildin or containers.conf UnsetEnvAll unsets all default environment variables from the image or from buildin Optional.
+    #[serde(rename = "unsetenvall", skip_serializing_if = "Option::is_none")]
+    pub unsetenvall: Option<bool>,
+    /// UseImageHosts indicates that /etc/hosts should not be managed by Podman, and instead sourced from the image. Conflicts with HostAdd. Optional.
+    #[serde(rename = "use_image_hosts", skip_serializing_if = "Option::is_none")]
+    pub use_image_hosts: Option<bool>,
+    /// UseImageResolvConf indicates that resolv.conf should not be managed by Podman, but instead sourced from the image. Conflicts with DNSServer, DNSSearch, DNSOption. Optional.
+    #[serde(rename = "use_image_resolve_conf", skip_serializing_if = "Option::is_none")]
+    pub use_image_resolve_conf: Option<bool>,
+    /// User is the user the container will be run as. Can be given as a UID or a username; if a username, it will be resolved within the container, using the container's /etc/passwd. If unset, the container will be run as root. Optional.
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(rename = "userns", skip_serializing_if = "Option::is_none")]
+    pub userns: Option<Box<Namespace>>,
+    #[serde(rename = "utsns", skip_serializing_if = "Option::is_none")]
+    pub utsns: Option<Box<Namespace>>,
+    /// Volatile specifies whether the container storage can be optimized at the cost of not syncing all the dirty files in memory. Optional.
+    #[serde(rename = "volatile", skip_serializing_if = "Option::is_none")]
+    pub volatile: Option<bool>,
+    /// Volumes are named volumes that will be added to the container. These will supersede Image Volumes and VolumesFrom volumes where there are conflicts. Optional.
+    #[serde(rename = "volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<NamedVolume>>,
+    /// VolumesFrom is a set of containers whose volumes will be added to this container. The name or ID of the container must be provided, and may optionally be followed by a : and then one or more comma-separated options. Valid options are 'ro', 'rw', and 'z'. Options will be used for all volumes sourced from the container. Optional.
+    #[serde(rename = "volumes_from", skip_serializing_if = "Option::is_none")]
+    pub volumes_from: Option<Vec<String>>,
+    /// Weight per cgroup per device, can override BlkioWeight
+    #[serde(rename = "weightDevice", skip_serializing_if = "Option::is_none")]
+    pub weight_device: Option<std::collections::HashMap<String, LinuxWeightDevice>>,
+    /// WorkDir is the container's working directory. If unset, the default, /, will be used. Optional.
+    #[serde(rename = "work_dir", skip_serializing_if = "Option::is_none")]
+    pub work_dir: Option<String>,
+}
+impl SpecGenerator {
+    /// SpecGenerator creates an OCI spec and Libpod configuration options to create a container based on the given configuration.
+    pub fn new() -> SpecGenerator {
+        SpecGenerator {
+            networks: None,
+            annotations: None,
+            apparmor_profile: None,
+            base_hosts_file: None,
+            cap_add: None,
+            cap_drop: None,
+            cgroup_parent: None,
+            cgroupns: None,
+            cgroups_mode: None,
+            chroot_directories: None,
+            cni_networks: None,
+            command: None,
+            conmon_pid_file: None,
+            container_create_command: None,
+            create_working_dir: None,
+            dependency_containers: None,
+            device_cgroup_rule: None,
+            devices: None,
+            devices_from: None,
+            dns_option: None,
+            dns_search: None,
+            dns_server: None,
+            entrypoint: None,
+            env: None,
+            env_host: None,
+            envmerge: None,
+            expose: None,
+            group_entry: None,
+            groups: None,
+            health_check_on_failure_action: None,
+            healthconfig: None,
+            host_device_list: None,
+            hostadd: None,
+            hostname: None,
+            hostusers: None,
+            httpproxy: None,
+            idmappings: None,
+            image: None,
+            image_arch: None,
+            image_os: None,
+            image_variant: None,
+            image_volume_mode: None,
+            image_volumes: None,
+            init: None,
+            init_container_type: None,
+            init_path: None,
+            intel_rdt: None,
+            ipcns: None,
+            label_nested: None,
+            labels: None,
+            log_configuration: None,
+            manage_password: None,
+            mask: None,
+            mounts: None,
+            name: None,
+            netns: None,
+            network_options: None,
+            no_new_privileges: None,
+            oci_runtime: None,
+            oom_score_adj: None,
+            overlay_volumes: None,
+            passwd_entry: None,
+            personality: None,
+            pidns: None,
+            pod: None,
+            portmappings: None,
+            privileged: None,
+            procfs_opts: None,
+            publish_image_ports: None,
+            r_limits: None,
+            raw_image_name: None,
+            read_only_filesystem: None,
+            read_write_tmpfs: None,
+            remove: None,
+            resource_limits: None,
+            restart_policy: None,
+            restart_tries: None,
+            rootfs: None,
+            rootfs_mapping: None,
+            rootfs_overlay: None,
+            rootfs_propagation: None,
+            sdnotify_mode: None,
+            seccomp_policy: None,
+            seccomp_profile_path: None,
+            secret_env: None,
+            secrets: None,
+            selinux_opts: None,
+            shm_size: None,
+            shm_size_systemd: None,
+            startup_health_config: None,
+            stdin: None,
+            stop_signal: None,
+            stop_timeout: None,
+            storage_opts: None,
+            sysctl: None,
+            systemd: None,
+            terminal: None,
+            throttle_read_bps_device: None,
+            throttle_read_iops_device: None,
+            throttle_write_bps_device: None,
+            throttle_write_iops_device: None,
+            timeout: None,
+            timezone: None,
+            umask: None,
+            unified: None,
+            unmask: None,
+            unsetenv: None,
+            unsetenvall: None,
+            use_image_hosts: None,
+            use_image_resolve_conf: None,
+            user: None,
+            userns: None,
+            utsns: None,
+            volatile: None,
+            volumes: None,
+            volumes_from: None,
+            weight_device: None,
+            work_dir: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StartupHealthCheck {
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Interval", skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// Retries is the number of consecutive failures needed to consider a container as unhealthy. Zero means inherit.
+    #[serde(rename = "Retries", skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartInterval", skip_serializing_if = "Option::is_none")]
+    pub start_interval: Option<i64>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "StartPeriod", skip_serializing_if = "Option::is_none")]
+    pub start_period: Option<i64>,
+    /// Successes are the number of successes required to mark the startup HC as passed. If set to 0, a single success will mark the HC as passed.
+    #[serde(rename = "Successes", skip_serializing_if = "Option::is_none")]
+    pub successes: Option<i64>,
+    /// Test is the test to perform to check that the container is healthy. An empty slice means to inherit the default. The options are: {} : inherit healthcheck {\"NONE\"} : disable healthcheck {\"CMD\", args...} : exec arguments directly {\"CMD-SHELL\", command} : run command with system's default shell
+    #[serde(rename = "Test", skip_serializing_if = "Option::is_none")]
+    pub test: Option<Vec<String>>,
+    /// A Duration represents the elapsed time between two instants as an int64 nanosecond count. The representation limits the largest representable duration to approximately 290 years.
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+}
+impl StartupHealthCheck {
+    pub fn new() -> StartupHealthCheck {
+        StartupHealthCheck {
+            interval: None,
+            retries: None,
+            start_interval: None,
+            start_period: None,
+            successes: None,
+            test: None,
+            timeout: None,
+        }
+    }
+}
+/// StoreInfo : StoreInfo describes the container storage and its attributes
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoreInfo {
+    #[serde(rename = "configFile", skip_serializing_if = "Option::is_none")]
+    pub config_file: Option<String>,
+    #[serde(rename = "containerStore", skip_serializing_if = "Option::is_none")]
+    pub container_store: Option<Box<ContainerStore>>,
+    #[serde(rename = "graphDriverName", skip_serializing_if = "Option::is_none")]
+    pub graph_driver_name: Option<String>,
+    #[serde(rename = "graphOptions", skip_serializing_if = "Option::is_none")]
+    pub graph_options: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "graphRoot", skip_serializing_if = "Option::is_none")]
+    pub graph_root: Option<String>,
+    /// GraphRootAllocated is how much space the graphroot has in bytes
+    #[serde(rename = "graphRootAllocated", skip_serializing_if = "Option::is_none")]
+    pub graph_root_allocated: Option<u64>,
+    /// GraphRootUsed is how much of graphroot is used in bytes
+    #[serde(rename = "graphRootUsed", skip_serializing_if = "Option::is_none")]
+    pub graph_root_used: Option<u64>,
+    #[serde(rename = "graphStatus", skip_serializing_if = "Option::is_none")]
+    pub graph_status: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "imageCopyTmpDir", skip_serializing_if = "Option::is_none")]
+    pub image_copy_tmp_dir: Option<String>,
+    #[serde(rename = "imageStore", skip_serializing_if = "Option::is_none")]
+    pub image_store: Option<Box<ImageStore>>,
+    #[serde(rename = "runRoot", skip_serializing_if = "Option::is_none")]
+    pub run_root: Option<String>,
+    #[serde(rename = "transientStore", skip_serializing_if = "Option::is_none")]
+    pub transient_store: Option<bool>,
+    #[serde(rename = "volumePath", skip_serializing_if = "Option::is_none")]
+    pub volume_path: Option<String>,
+}
+impl StoreInfo {
+    /// StoreInfo describes the container storage and its attributes
+    pub fn new() -> StoreInfo {
+        StoreInfo {
+            config_file: None,
+            container_store: None,
+            graph_driver_name: None,
+            graph_options: None,
+            graph_root: None,
+            graph_root_allocated: None,
+            graph_root_used: None,
+            graph_status: None,
+            image_copy_tmp_dir: None,
+            image_store: None,
+            run_root: None,
+            transient_store: None,
+            volume_path: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Subnet {
+    /// Gateway IP for this Network.
+    #[serde(rename = "gateway", skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(rename = "lease_range", skip_serializing_if = "Option::is_none")]
+    pub lease_range: Option<Box<LeaseRange>>,
+    /// Subnet for this Network in CIDR form.
+    #[serde(rename = "subnet", skip_serializing_if = "Option::is_none")]
+    pub subnet: Option<String>,
+}
+impl Subnet {
+    pub fn new() -> Subnet {
+        Subnet {
+            gateway: None,
+            lease_range: None,
+            subnet: None,
+        }
+    }
+}
+/// Summary : Summary summary
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    /// Number of containers using this image. Includes both stopped and running containers.  This size is not calculated by default, and depends on which API endpoint is used. `-1` indicates that the value has not been set / calculated.
+    #[serde(rename = "Containers")]
+    pub containers: i64,
+    /// Date and time at which the image was created as a Unix timestamp (number of seconds sinds EPOCH).
+    #[serde(rename = "Created")]
+    pub created: i64,
+    /// ID is the content-addressable ID of an image.  This identifier is a content-addressable digest calculated from the image's configuration (which includes the digests of layers used by the image).  Note that this digest differs from the `RepoDigests` below, which holds digests of image manifests that reference the image.
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels")]
+    pub labels: std::collections::HashMap<String, String>,
+    /// ID of the parent image.  Depending on how the image was created, this field may be empty and is only set for images that were built/created locally. This field is empty if the image was pulled from an image registry.
+    #[serde(rename = "ParentId")]
+    pub parent_id: String,
+    /// List of content-addressable digests of locally available image manifests that the image is referenced from. Multiple manifests can refer to the same image.  These digests are usually only available if the image was either pulled from a registry, or if the image was pushed to a registry, which is when the manifest is generated and its digest calculated.
+    #[serde(rename = "RepoDigests")]
+    pub repo_digests: Vec<String>,
+    /// List of image names/tags in the local image cache that reference this image.  Multiple image tags can refer to the same image, and this list may be empty if no tags reference the image, in which case the image is \"untagged\", in which case it can still be referenced by its ID.
+    #[serde(rename = "RepoTags")]
+    pub repo_tags: Vec<String>,
+    /// Total size of image layers that are shared between this image and other images.  This size is not calculated by default. `-1` indicates that the value has not been set / calculated.
+    #[serde(rename = "SharedSize")]
+    pub shared_size: i64,
+    /// Total size of the image including all layers it is composed of.
+    #[serde(rename = "Size")]
+    pub size: i64,
+    /// Total size of the image including all layers it is composed of.  Deprecated: this field is omitted in API v1.44, but kept for backward compatibility. Use Size instead.
+    #[serde(rename = "VirtualSize", skip_serializing_if = "Option::is_none")]
+    pub virtual_size: Option<i64>,
+}
+impl Summary {
+    /// Summary summary
+    pub fn new(
+        containers: i64,
+        created: i64,
+        id: String,
+        labels: std::collections::HashMap<String, String>,
+        parent_id: String,
+        repo_digests: Vec<String>,
+        repo_tags: Vec<String>,
+        shared_size: i64,
+        size: i64,
+    ) -> Summary {
+        Summary {
+            containers,
+            created,
+            id,
+            labels,
+            parent_id,
+            repo_digests,
+            repo_tags,
+            shared_size,
+            size,
+            virtual_size: None,
+        }
+    }
+}
+/// SummaryNetworkSettings : SummaryNetworkSettings provides a summary of container's networks in /containers/json
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SummaryNetworkSettings {
+    #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<std::collections::HashMap<String, EndpointSettings>>,
+}
+impl SummaryNetworkSettings {
+    /// SummaryNetworkSettings provides a summary of container's networks in /containers/json
+    pub fn new() -> SummaryNetworkSettings {
+        SummaryNetworkSettings {
+            networks: None,
+        }
+    }
+}
+/// SystemComponentVersion : SystemComponentVersion is the type used by pkg/domain/entities
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemComponentVersion {
+    #[serde(rename = "ApiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    #[serde(rename = "Arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "BuildTime", skip_serializing_if = "Option::is_none")]
+    pub build_time: Option<String>,
+    #[serde(rename = "Components", skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ComponentVersion>>,
+    #[serde(rename = "Experimental", skip_serializing_if = "Option::is_none")]
+    pub experimental: Option<bool>,
+    #[serde(rename = "GitCommit", skip_serializing_if = "Option::is_none")]
+    pub git_commit: Option<String>,
+    #[serde(rename = "GoVersion", skip_serializing_if = "Option::is_none")]
+    pub go_version: Option<String>,
+    #[serde(rename = "KernelVersion", skip_serializing_if = "Option::is_none")]
+    pub kernel_version: Option<String>,
+    #[serde(rename = "MinAPIVersion", skip_serializing_if = "Option::is_none")]
+    pub min_api_version: Option<String>,
+    #[serde(rename = "Os", skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Box<SystemComponentVersionPlatform>>,
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl SystemComponentVersion {
+    /// SystemComponentVersion is the type used by pkg/domain/entities
+    pub fn new() -> SystemComponentVersion {
+        SystemComponentVersion {
+            api_version: None,
+            arch: None,
+            build_time: None,
+            components: None,
+            experimental: None,
+            git_commit: None,
+            go_version: None,
+            kernel_version: None,
+            min_api_version: None,
+            os: None,
+            platform: None,
+            version: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemComponentVersionPlatform {
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl SystemComponentVersionPlatform {
+    pub fn new() -> SystemComponentVersionPlatform {
+        SystemComponentVersionPlatform {
+            name: None,
+        }
+    }
+}
+/// SystemDfContainerReport : SystemDfContainerReport describes a container for use with df
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemDfContainerReport {
+    #[serde(rename = "Command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    #[serde(rename = "ContainerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "LocalVolumes", skip_serializing_if = "Option::is_none")]
+    pub local_volumes: Option<i64>,
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<String>,
+    #[serde(rename = "RWSize", skip_serializing_if = "Option::is_none")]
+    pub rw_size: Option<i64>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+impl SystemDfContainerReport {
+    /// SystemDfContainerReport describes a container for use with df
+    pub fn new() -> SystemDfContainerReport {
+        SystemDfContainerReport {
+            command: None,
+            container_id: None,
+            created: None,
+            image: None,
+            local_volumes: None,
+            names: None,
+            rw_size: None,
+            size: None,
+            status: None,
+        }
+    }
+}
+/// SystemDfImageReport : SystemDfImageReport describes an image for use with df
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemDfImageReport {
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<i64>,
+    #[serde(rename = "Created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(rename = "ImageID", skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    #[serde(rename = "Repository", skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    #[serde(rename = "SharedSize", skip_serializing_if = "Option::is_none")]
+    pub shared_size: Option<i64>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "Tag", skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(rename = "UniqueSize", skip_serializing_if = "Option::is_none")]
+    pub unique_size: Option<i64>,
+}
+impl SystemDfImageReport {
+    /// SystemDfImageReport describes an image for use with df
+    pub fn new() -> SystemDfImageReport {
+        SystemDfImageReport {
+            containers: None,
+            created: None,
+            image_id: None,
+            repository: None,
+            shared_size: None,
+            size: None,
+            tag: None,
+            unique_size: None,
+        }
+    }
+}
+/// SystemDfReport : SystemDfReport describes the response for df information
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemDfReport {
+    #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
+    pub containers: Option<Vec<SystemDfContainerReport>>,
+    #[serde(rename = "Images", skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<SystemDfImageReport>>,
+    #[serde(rename = "ImagesSize", skip_serializing_if = "Option::is_none")]
+    pub images_size: Option<i64>,
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<SystemDfVolumeReport>>,
+}
+impl SystemDfReport {
+    /// SystemDfReport describes the response for df information
+    pub fn new() -> SystemDfReport {
+        SystemDfReport {
+            containers: None,
+            images: None,
+            images_size: None,
+            volumes: None,
+        }
+    }
+}
+/// SystemDfVolumeReport : SystemDfVolumeReport describes a volume and its size
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemDfVolumeReport {
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<i64>,
+    #[serde(rename = "ReclaimableSize", skip_serializing_if = "Option::is_none")]
+    pub reclaimable_size: Option<i64>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(rename = "VolumeName", skip_serializing_if = "Option::is_none")]
+    pub volume_name: Option<String>,
+}
+impl SystemDfVolumeReport {
+    /// SystemDfVolumeReport describes a volume and its size
+    pub fn new() -> SystemDfVolumeReport {
+        SystemDfVolumeReport {
+            links: None,
+            reclaimable_size: None,
+            size: None,
+            volume_name: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemPruneReport {
+    #[serde(rename = "ContainerPruneReports", skip_serializing_if = "Option::is_none")]
+    pub container_prune_reports: Option<Vec<PruneReport>>,
+    #[serde(rename = "ImagePruneReports", skip_serializing_if = "Option::is_none")]
+    pub image_prune_reports: Option<Vec<PruneReport>>,
+    #[serde(rename = "NetworkPruneReports", skip_serializing_if = "Option::is_none")]
+    pub network_prune_reports: Option<Vec<NetworkPruneReport>>,
+    #[serde(rename = "PodPruneReport", skip_serializing_if = "Option::is_none")]
+    pub pod_prune_report: Option<Vec<PodPruneReport>>,
+    #[serde(rename = "ReclaimedSpace", skip_serializing_if = "Option::is_none")]
+    pub reclaimed_space: Option<u64>,
+    #[serde(rename = "VolumePruneReports", skip_serializing_if = "Option::is_none")]
+    pub volume_prune_reports: Option<Vec<PruneReport>>,
+}
+impl SystemPruneReport {
+    pub fn new() -> SystemPruneReport {
+        SystemPruneReport {
+            container_prune_reports: None,
+            image_prune_reports: None,
+            network_prune_reports: None,
+            pod_prune_report: None,
+            reclaimed_space: None,
+            volume_prune_reports: None,
+        }
+    }
+}
+/// Task : Task carries the information about one backend task
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Task {
+    #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    #[serde(rename = "EndpointIP", skip_serializing_if = "Option::is_none")]
+    pub endpoint_ip: Option<String>,
+    #[serde(rename = "Info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl Task {
+    /// Task carries the information about one backend task
+    pub fn new() -> Task {
+        Task {
+            endpoint_id: None,
+            endpoint_ip: None,
+            info: None,
+            name: None,
+        }
+    }
+}
+/// ThrottleDevice : ThrottleDevice is a structure that holds device:rate_per_second pair
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThrottleDevice {
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "Rate", skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+impl ThrottleDevice {
+    /// ThrottleDevice is a structure that holds device:rate_per_second pair
+    pub fn new() -> ThrottleDevice {
+        ThrottleDevice {
+            path: None,
+            rate: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TmpfsOptions {
+    /// The bits have the same definition on all systems, so that information about files can be moved from one system to another portably. Not all bits apply to all systems. The only required bit is [ModeDir] for directories.
+    #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+    /// Size sets the size of the tmpfs, in bytes.  This will be converted to an operating system specific value depending on the host. For example, on linux, it will be converted to use a 'k', 'm' or 'g' syntax. BSD, though not widely supported with docker, uses a straight byte value.  Percentages are not supported.
+    #[serde(rename = "SizeBytes", skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+}
+impl TmpfsOptions {
+    pub fn new() -> TmpfsOptions {
+        TmpfsOptions {
+            mode: None,
+            size_bytes: None,
+        }
+    }
+}
+/// Topology : This description is taken verbatim from the CSI Spec:  A topological domain is a sub-division of a cluster, like \"region\", \"zone\", \"rack\", etc. A topological segment is a specific instance of a topological domain, like \"zone3\", \"rack3\", etc. For example {\"com.company/zone\": \"Z1\", \"com.company/rack\": \"R3\"} Valid keys have two segments: an OPTIONAL prefix and name, separated by a slash (/), for example: \"com.company.example/zone\". The key name segment is REQUIRED. The prefix is OPTIONAL. The key name MUST be 63 characters or less, begin and end with an alphanumeric character ([a-z0-9A-Z]), and contain only dashes (-), underscores (_), dots (.), or alphanumerics in between, for example \"zone\". The key prefix MUST be 63 characters or less, begin and end with a lower-case alphanumeric character ([a-z0-9]), contain only dashes (-), dots (.), or lower-case alphanumerics in between, and follow domain name notation format (https://tools.ietf.org/html/rfc1035#section-2.3.1). The key prefix SHOULD include the plugin's host company name and/or the plugin name, to minimize the possibility of collisions with keys from other plugins. If a key prefix is specified, it MUST be identical across all topology keys returned by the SP (across all RPCs). Keys MUST be case-insensitive. Meaning the keys \"Zone\" and \"zone\" MUST not both exist. Each value (topological segment) MUST contain 1 or more strings. Each string MUST be 63 characters or less and begin and end with an alphanumeric character with '-', '_', '.', or alphanumerics in between.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Topology {
+    #[serde(rename = "Segments", skip_serializing_if = "Option::is_none")]
+    pub segments: Option<std::collections::HashMap<String, String>>,
+}
+impl Topology {
+    /// This description is taken verbatim from the CSI Spec:  A topological domain is a sub-division of a cluster, like \"region\", \"zone\", \"rack\", etc. A topological segment is a specific instance of a topological domain, like \"zone3\", \"rack3\", etc. For example {\"com.company/zone\": \"Z1\", \"com.company/rack\": \"R3\"} Valid keys have two segments: an OPTIONAL prefix and name, separated by a slash (/), for example: \"com.company.example/zone\". The key name segment is REQUIRED. The prefix is OPTIONAL. The key name MUST be 63 characters or less, begin and end with an alphanumeric character ([a-z0-9A-Z]), and contain only dashes (-), underscores (_), dots (.), or alphanumerics in between, for example \"zone\". The key prefix MUST be 63 characters or less, begin and end with a lower-case alphanumeric character ([a-z0-9]), contain only dashes (-), dots (.), or lower-case alphanumerics in between, and follow domain name notation format (https://tools.ietf.org/html/rfc1035#section-2.3.1). The key prefix SHOULD include the plugin's host company name and/or the plugin name, to minimize the possibility of collisions with keys from other plugins. If a key prefix is specified, it MUST be identical across all topology keys returned by the SP (across all RPCs). Keys MUST be case-insensitive. Meaning the keys \"Zone\" and \"zone\" MUST not both exist. Each value (topological segment) MUST contain 1 or more strings. Each string MUST be 63 characters or less and begin and end with an alphanumeric character with '-', '_', '.', or alphanumerics in between.
+    pub fn new() -> Topology {
+        Topology { segments: None }
+    }
+}
+/// TopologyRequirement : TopologyRequirement expresses the user's requirements for a volume's accessible topology.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TopologyRequirement {
+    /// Preferred is a list of Topologies that the volume should attempt to be provisioned in.  Taken from the CSI spec:  Specifies the list of topologies the CO would prefer the volume to be provisioned in.  This field is OPTIONAL. If TopologyRequirement is specified either requisite or preferred or both MUST be specified.  An SP MUST attempt to make the provisioned volume available using the preferred topologies in order from first to last.  If requisite is specified, all topologies in preferred list MUST also be present in the list of requisite topologies.  If the SP is unable to make the provisioned volume available from any of the preferred topologies, the SP MAY choose a topology from the list of requisite topologies. If the list of requisite topologies is not specified, then the SP MAY choose from the list of all possible topologies. If the list of requisite topologies is specified and the SP is unable to make the provisioned volume available from any of the requisite topologies it MUST fail the CreateVolume call.  Example 1: Given a volume should be accessible from a single zone, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"} preferred = {\"region\": \"R1\", \"zone\": \"Z3\"} then the SP SHOULD first attempt to make the provisioned volume available from \"zone\" \"Z3\" in the \"region\" \"R1\" and fall back to \"zone\" \"Z2\" in the \"region\" \"R1\" if that is not possible.  Example 2: Given a volume should be accessible from a single zone, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"}, {\"region\": \"R1\", \"zone\": \"Z4\"}, {\"region\": \"R1\", \"zone\": \"Z5\"} preferred = {\"region\": \"R1\", \"zone\": \"Z4\"}, {\"region\": \"R1\", \"zone\": \"Z2\"} then the SP SHOULD first attempt to make the provisioned volume accessible from \"zone\" \"Z4\" in the \"region\" \"R1\" and fall back to \"zone\" \"Z2\" in the \"region\" \"R1\" if that is not possible. If that is not possible, the SP may choose between either the \"zone\" \"Z3\" or \"Z5\" in the \"region\" \"R1\".  Example 3: Given a volume should be accessible from TWO zones (because an opaque parameter in CreateVolumeRequest, for example, specifies the volume is accessible from two zones, aka synchronously replicated), and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"}, {\"region\": \"R1\", \"zone\": \"Z4\"}, {\"region\": \"R1\", \"zone\": \"Z5\"} preferred = {\"region\": \"R1\", \"zone\": \"Z5\"}, {\"region\": \"R1\", \"zone\": \"Z3\"} then the SP SHOULD first attempt to make the provisioned volume accessible from the combination of the two \"zones\" \"Z5\" and \"Z3\" in the \"region\" \"R1\". If that's not possible, it should fall back to a combination of \"Z5\" and other possibilities from the list of requisite. If that's not possible, it should fall back  to a combination of \"Z3\" and other possibilities from the list of requisite. If that's not possible, it should fall back  to a combination of other possibilities from the list of requisite.
+    #[serde(rename = "Preferred", skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<Vec<Topology>>,
+    /// Requisite specifies a list of Topologies, at least one of which the volume must be accessible from.  Taken verbatim from the CSI Spec:  Specifies the list of topologies the provisioned volume MUST be accessible from. This field is OPTIONAL. If TopologyRequirement is specified either requisite or preferred or both MUST be specified.  If requisite is specified, the provisioned volume MUST be accessible from at least one of the requisite topologies.  Given x = number of topologies provisioned volume is accessible from n = number of requisite topologies The CO MUST ensure n >= 1. The SP MUST ensure x >= 1 If x==n, then the SP MUST make the provisioned volume available to all topologies from the list of requisite topologies. If it is unable to do so, the SP MUST fail the CreateVolume call. For example, if a volume should be accessible from a single zone, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"} then the provisioned volume MUST be accessible from the \"region\" \"R1\" and the \"zone\" \"Z2\". Similarly, if a volume should be accessible from two zones, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"} then the provisioned volume MUST be accessible from the \"region\" \"R1\" and both \"zone\" \"Z2\" and \"zone\" \"Z3\".  If x<n, then the SP SHALL choose x unique topologies from the list of requisite topologies. If it is unable to do so, the SP MUST fail the CreateVolume call. For example, if a volume should be accessible from a single zone, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"} then the SP may choose to make the provisioned volume available in either the \"zone\" \"Z2\" or the \"zone\" \"Z3\" in the \"region\" \"R1\". Similarly, if a volume should be accessible from two zones, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"}, {\"region\": \"R1\", \"zone\": \"Z3\"}, {\"region\": \"R1\", \"zone\": \"Z4\"} then the provisioned volume MUST be accessible from any combination of two unique topologies: e.g. \"R1/Z2\" and \"R1/Z3\", or \"R1/Z2\" and \"R1/Z4\", or \"R1/Z3\" and \"R1/Z4\".  If x>n, then the SP MUST make the provisioned volume available from all topologies from the list of requisite topologies and MAY choose the remaining x-n unique topologies from the list of all possible topologies. If it is unable to do so, the SP MUST fail the CreateVolume call. For example, if a volume should be accessible from two zones, and requisite = {\"region\": \"R1\", \"zone\": \"Z2\"} then the provisioned volume MUST be accessible from the \"region\" \"R1\" and the \"zone\" \"Z2\" and the SP may select the second zone independently, e.g. \"R1/Z4\".
+    #[serde(rename = "Requisite", skip_serializing_if = "Option::is_none")]
+    pub requisite: Option<Vec<Topology>>,
+}
+impl TopologyRequirement {
+    /// TopologyRequirement expresses the user's requirements for a volume's accessible topology.
+    pub fn new() -> TopologyRequirement {
+        TopologyRequirement {
+            preferred: None,
+            requisite: None,
+        }
+    }
+}
+/// TypeMount : TypeMount contains options for using a volume as a Mount-type volume.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TypeMount {
+    /// FsType specifies the filesystem type for the mount volume. Optional.
+    #[serde(rename = "FsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// MountFlags defines flags to pass when mounting the volume. Optional.
+    #[serde(rename = "MountFlags", skip_serializing_if = "Option::is_none")]
+    pub mount_flags: Option<Vec<String>>,
+}
+impl TypeMount {
+    /// TypeMount contains options for using a volume as a Mount-type volume.
+    pub fn new() -> TypeMount {
+        TypeMount {
+            fs_type: None,
+            mount_flags: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ulimit {
+    #[serde(rename = "Hard", skip_serializing_if = "Option::is_none")]
+    pub hard: Option<i64>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Soft", skip_serializing_if = "Option::is_none")]
+    pub soft: Option<i64>,
+}
+impl Ulimit {
+    pub fn new() -> Ulimit {
+        Ulimit {
+            hard: None,
+            name: None,
+            soft: None,
+        }
+    }
+}
+/// UpdateConfig : Those attributes can be updated at runtime.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateConfig {
+    #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
+    #[serde(rename = "BlkioWeight", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u32>,
+    #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<WeightDevice>>,
+    /// Applicable to UNIX platforms
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none")]
+    pub cgroup_parent: Option<String>,
+    /// Applicable to Windows
+    #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
+    pub cpu_count: Option<i64>,
+    #[serde(rename = "CpuPercent", skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<i64>,
+    #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<i64>,
+    #[serde(rename = "CpuQuota", skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+    #[serde(rename = "CpuRealtimePeriod", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_period: Option<i64>,
+    #[serde(rename = "CpuRealtimeRuntime", skip_serializing_if = "Option::is_none")]
+    pub cpu_realtime_runtime: Option<i64>,
+    /// Applicable to all platforms
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<i64>,
+    #[serde(rename = "CpusetCpus", skip_serializing_if = "Option::is_none")]
+    pub cpuset_cpus: Option<String>,
+    #[serde(rename = "CpusetMems", skip_serializing_if = "Option::is_none")]
+    pub cpuset_mems: Option<String>,
+    #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
+    pub device_cgroup_rules: Option<Vec<String>>,
+    #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
+    pub device_requests: Option<Vec<DeviceRequest>>,
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Vec<DeviceMapping>>,
+    #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_bandwidth: Option<u64>,
+    #[serde(rename = "IOMaximumIOps", skip_serializing_if = "Option::is_none")]
+    pub io_maximum_i_ops: Option<u64>,
+    /// KernelMemory specifies the kernel memory limit (in bytes) for the container. Deprecated: kernel 5.4 deprecated kmem.limit_in_bytes.
+    #[serde(rename = "KernelMemory", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory: Option<i64>,
+    #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
+    pub kernel_memory_tcp: Option<i64>,
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    #[serde(rename = "MemoryReservation", skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    #[serde(rename = "MemorySwap", skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<i64>,
+    #[serde(rename = "MemorySwappiness", skip_serializing_if = "Option::is_none")]
+    pub memory_swappiness: Option<i64>,
+    #[serde(rename = "NanoCpus", skip_serializing_if = "Option::is_none")]
+    pub nano_cpus: Option<i64>,
+    #[serde(rename = "OomKillDisable", skip_serializing_if = "Option::is_none")]
+    pub oom_kill_disable: Option<bool>,
+    #[serde(rename = "PidsLimit", skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+    #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<Box<RestartPolicy>>,
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
+    pub ulimits: Option<Vec<Ulimit>>,
+}
+impl UpdateConfig {
+    /// Those attributes can be updated at runtime.
+    pub fn new() -> UpdateConfig {
+        UpdateConfig {
+            blkio_device_read_bps: None,
+            blkio_device_read_i_ops: None,
+            blkio_device_write_bps: None,
+            blkio_device_write_i_ops: None,
+            blkio_weight: None,
+            blkio_weight_device: None,
+            cgroup_parent: None,
+            cpu_count: None,
+            cpu_percent: None,
+            cpu_period: None,
+            cpu_quota: None,
+            cpu_realtime_period: None,
+            cpu_realtime_runtime: None,
+            cpu_shares: None,
+            cpuset_cpus: None,
+            cpuset_mems: None,
+            device_cgroup_rules: None,
+            device_requests: None,
+            devices: None,
+            io_maximum_bandwidth: None,
+            io_maximum_i_ops: None,
+            kernel_memory: None,
+            kernel_memory_tcp: None,
+            memory: None,
+            memory_reservation: None,
+            memory_swap: None,
+            memory_swappiness: None,
+            nano_cpus: None,
+            oom_kill_disable: None,
+            pids_limit: None,
+            restart_policy: None,
+            ulimits: None,
+        }
+    }
+}
+/// UpdateEntities : UpdateEntities used to wrap the oci resource spec in a swagger model
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateEntities {
+    #[serde(rename = "Resources", skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Box<LinuxResources>>,
+}
+impl UpdateEntities {
+    /// UpdateEntities used to wrap the oci resource spec in a swagger model
+    pub fn new() -> UpdateEntities {
+        UpdateEntities { resources: None }
+    }
+}
+/// UsageData : UsageData Usage details about the volume. This information is used by the `GET /system/df` endpoint, and omitted in other endpoints.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UsageData {
+    /// The number of containers referencing this volume. This field is set to `-1` if the reference-count is not available.
+    #[serde(rename = "RefCount")]
+    pub ref_count: i64,
+    /// Amount of disk space used by the volume (in bytes). This information is only available for volumes created with the `\"local\"` volume driver. For volumes created with other volume drivers, this field is set to `-1` (\"not available\")
+    #[serde(rename = "Size")]
+    pub size: i64,
+}
+impl UsageData {
+    /// UsageData Usage details about the volume. This information is used by the `GET /system/df` endpoint, and omitted in other endpoints.
+    pub fn new(ref_count: i64, size: i64) -> UsageData {
+        UsageData { ref_count, size }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Version {
+    #[serde(rename = "Index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<u64>,
+}
+impl Version {
+    pub fn new() -> Version {
+        Version { index: None }
+    }
+}
+/// Volume : Volume volume
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Volume {
+    #[serde(rename = "ClusterVolume", skip_serializing_if = "Option::is_none")]
+    pub cluster_volume: Option<Box<ClusterVolume>>,
+    /// Date/Time the volume was created.
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Name of the volume driver used by the volume.
+    #[serde(rename = "Driver")]
+    pub driver: String,
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels")]
+    pub labels: std::collections::HashMap<String, String>,
+    /// Mount path of the volume on the host.
+    #[serde(rename = "Mountpoint")]
+    pub mountpoint: String,
+    /// Name of the volume.
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// The driver specific options used when creating the volume.
+    #[serde(rename = "Options")]
+    pub options: std::collections::HashMap<String, String>,
+    /// The level at which the volume exists. Either `global` for cluster-wide, or `local` for machine level.
+    #[serde(rename = "Scope")]
+    pub scope: String,
+    /// Low-level details about the volume, provided by the volume driver. Details are returned as a map with key/value pairs: `{\"key\":\"value\",\"key2\":\"value2\"}`.  The `Status` field is optional, and is omitted if the volume driver does not support this feature.
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(rename = "UsageData", skip_serializing_if = "Option::is_none")]
+    pub usage_data: Option<Box<UsageData>>,
+}
+impl Volume {
+    /// Volume volume
+    pub fn new(
+        driver: String,
+        labels: std::collections::HashMap<String, String>,
+        mountpoint: String,
+        name: String,
+        options: std::collections::HashMap<String, String>,
+        scope: String,
+    ) -> Volume {
+        Volume {
+            cluster_volume: None,
+            created_at: None,
+            driver,
+            labels,
+            mountpoint,
+            name,
+            options,
+            scope,
+            status: None,
+            usage_data: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumeConfigResponse {
+    /// Anonymous indicates that the volume was created as an anonymous volume for a specific container, and will be removed when any container using it is removed.
+    #[serde(rename = "Anonymous", skip_serializing_if = "Option::is_none")]
+    pub anonymous: Option<bool>,
+    /// CreatedAt is the date and time the volume was created at. This is not stored for older Libpod volumes; if so, it will be omitted.
+    #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Driver is the driver used to create the volume. If set to \"local\" or \"\", the Local driver (Podman built-in code) is used to service the volume; otherwise, a volume plugin with the given name is used to mount and manage the volume.
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// GID is the GID that the volume was created with.
+    #[serde(rename = "GID", skip_serializing_if = "Option::is_none")]
+    pub gid: Option<i64>,
+    /// Labels includes the volume's configured labels, key:value pairs that can be passed during volume creation to provide information for third party tools.
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// LockNumber is the number of the volume's Libpod lock.
+    #[serde(rename = "LockNumber", skip_serializing_if = "Option::is_none")]
+    pub lock_number: Option<u32>,
+    /// MountCount is the number of times this volume has been mounted.
+    #[serde(rename = "MountCount", skip_serializing_if = "Option::is_none")]
+    pub mount_count: Option<u64>,
+    /// Mountpoint is the path on the host where the volume is mounted.
+    #[serde(rename = "Mountpoint", skip_serializing_if = "Option::is_none")]
+    pub mountpoint: Option<String>,
+    /// Name is the name of the volume.
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// NeedsChown indicates that the next time the volume is mounted into a container, the container will chown the volume to the container process UID/GID.
+    #[serde(rename = "NeedsChown", skip_serializing_if = "Option::is_none")]
+    pub needs_chown: Option<bool>,
+    /// NeedsCopyUp indicates that the next time the volume is mounted into
+    #[serde(rename = "NeedsCopyUp", skip_serializing_if = "Option::is_none")]
+    pub needs_copy_up: Option<bool>,
+    /// Options is a set of options that were used when creating the volume. For the Local driver, these are mount options that will be used to determine how a local filesystem is mounted; they are handled as parameters to Mount in a manner described in the volume create manpage. For non-local drivers, these are passed as-is to the volume plugin.
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+    /// Scope is unused and provided solely for Docker compatibility. It is unconditionally set to \"local\".
+    #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Status is used to return information on the volume's current state, if the volume was created using a volume plugin (uses a Driver that is not the local driver). Status is provided to us by an external program, so no guarantees are made about its format or contents. Further, it is an optional field, so it may not be set even in cases where a volume plugin is in use.
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// StorageID is the ID of the container backing the volume in c/storage. Only used with Image Volumes.
+    #[serde(rename = "StorageID", skip_serializing_if = "Option::is_none")]
+    pub storage_id: Option<String>,
+    /// Timeout is the specified driver timeout if given
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// UID is the UID that the volume was created with.
+    #[serde(rename = "UID", skip_serializing_if = "Option::is_none")]
+    pub uid: Option<i64>,
+}
+impl VolumeConfigResponse {
+    pub fn new() -> VolumeConfigResponse {
+        VolumeConfigResponse {
+            anonymous: None,
+            created_at: None,
+            driver: None,
+            gid: None,
+            labels: None,
+            lock_number: None,
+            mount_count: None,
+            mountpoint: None,
+            name: None,
+            needs_chown: None,
+            needs_copy_up: None,
+            options: None,
+            scope: None,
+            status: None,
+            storage_id: None,
+            timeout: None,
+            uid: None,
+        }
+    }
+}
+/// VolumeCreate : Details for creating a volume
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumeCreate {
+    /// Name of the volume driver to use.
+    #[serde(rename = "Driver")]
+    pub driver: String,
+    /// A mapping of driver options and values. These options are passed directly to the driver and are driver specific.
+    #[serde(rename = "DriverOpts")]
+    pub driver_opts: std::collections::HashMap<String, String>,
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels")]
+    pub labels: std::collections::HashMap<String, String>,
+    /// The new volume's name. If not specified, Docker generates a name.
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+impl VolumeCreate {
+    /// Details for creating a volume
+    pub fn new(
+        driver: String,
+        driver_opts: std::collections::HashMap<String, String>,
+        labels: std::collections::HashMap<String, String>,
+        name: String,
+    ) -> VolumeCreate {
+        VolumeCreate {
+            driver,
+            driver_opts,
+            labels,
+            name,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumeCreateOptions {
+    /// Volume driver to use
+    #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+    /// Ignore existing volumes
+    #[serde(rename = "IgnoreIfExists", skip_serializing_if = "Option::is_none")]
+    pub ignore_if_exists: Option<bool>,
+    /// User-defined key/value metadata. Provided for compatibility
+    #[serde(rename = "Label", skip_serializing_if = "Option::is_none")]
+    pub label: Option<std::collections::HashMap<String, String>>,
+    /// User-defined key/value metadata. Preferred field, will override Label
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    /// New volume's name. Can be left blank
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Mapping of driver options and values.
+    #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::HashMap<String, String>>,
+}
+impl VolumeCreateOptions {
+    pub fn new() -> VolumeCreateOptions {
+        VolumeCreateOptions {
+            driver: None,
+            ignore_if_exists: None,
+            label: None,
+            labels: None,
+            name: None,
+            options: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumeOptions {
+    #[serde(rename = "DriverConfig", skip_serializing_if = "Option::is_none")]
+    pub driver_config: Option<Box<Driver>>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "NoCopy", skip_serializing_if = "Option::is_none")]
+    pub no_copy: Option<bool>,
+    #[serde(rename = "Subpath", skip_serializing_if = "Option::is_none")]
+    pub subpath: Option<String>,
+}
+impl VolumeOptions {
+    pub fn new() -> VolumeOptions {
+        VolumeOptions {
+            driver_config: None,
+            labels: None,
+            no_copy: None,
+            subpath: None,
+        }
+    }
+}
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumeRmReport {
+    #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl VolumeRmReport {
+    pub fn new() -> VolumeRmReport {
+        VolumeRmReport {
+            err: None,
+            id: None,
+        }
+    }
+}
+/// VolumesPruneReport : POST \"/volumes/prune\"
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolumesPruneReport {
+    #[serde(rename = "SpaceReclaimed", skip_serializing_if = "Option::is_none")]
+    pub space_reclaimed: Option<u64>,
+    #[serde(rename = "VolumesDeleted", skip_serializing_if = "Option::is_none")]
+    pub volumes_deleted: Option<Vec<String>>,
+}
+impl VolumesPruneReport {
+    /// POST \"/volumes/prune\"
+    pub fn new() -> VolumesPruneReport {
+        VolumesPruneReport {
+            space_reclaimed: None,
+            volumes_deleted: None,
+        }
+    }
+}
+/// WaitExitError : WaitExitError container waiting error, if any
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaitExitError {
+    /// Details of an error
+    #[serde(rename = "Message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+impl WaitExitError {
+    /// WaitExitError container waiting error, if any
+    pub fn new() -> WaitExitError {
+        WaitExitError { message: None }
+    }
+}
+/// WaitResponse : OK response to ContainerWait operation
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaitResponse {
+    #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<Box<WaitExitError>>,
+    /// Exit code of the container
+    #[serde(rename = "StatusCode")]
+    pub status_code: i64,
+}
+impl WaitResponse {
+    /// OK response to ContainerWait operation
+    pub fn new(status_code: i64) -> WaitResponse {
+        WaitResponse {
+            error: None,
+            status_code,
+        }
+    }
+}
+/// WeightDevice : WeightDevice is a structure that holds device:weight pair
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WeightDevice {
+    #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "Weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
+impl WeightDevice {
+    /// WeightDevice is a structure that holds device:weight pair
+    pub fn new() -> WeightDevice {
+        WeightDevice {
+            path: None,
+            weight: None,
+        }
+    }
+}
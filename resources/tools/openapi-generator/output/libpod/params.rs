@@ -0,0 +1,1099 @@
+use crate::libpod::models;
+use bytes::Bytes;
+/// struct for passing parameters to the method [`container_archive_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerArchiveLibpodParams {
+    /// container name or id
+    pub name: String,
+    /// Path to a directory in the container to extract
+    pub path: String,
+    /// JSON encoded map[string]string to translate paths
+    pub rename: Option<String>,
+}
+/// struct for passing parameters to the method [`container_attach_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerAttachLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// keys to use for detaching from the container
+    pub detach_keys: Option<String>,
+    /// Stream all logs from the container across the connection. Happens before streaming attach (if requested). At least one of logs or stream must be set
+    pub logs: Option<bool>,
+    /// Attach to the container. If unset, and logs is set, only the container's logs will be sent. At least one of stream or logs must be set
+    pub stream: Option<bool>,
+    /// Attach to container STDOUT
+    pub stdout: Option<bool>,
+    /// Attach to container STDERR
+    pub stderr: Option<bool>,
+    /// Attach to container STDIN
+    pub stdin: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_changes_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerChangesLibpodParams {
+    /// the name or id of the container
+    pub name: String,
+    /// specify a second layer which is used to compare against it instead of the parent layer
+    pub parent: Option<String>,
+    /// select what you want to match, default is all
+    pub diff_type: Option<String>,
+}
+/// struct for passing parameters to the method [`container_checkpoint_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerCheckpointLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// keep all temporary checkpoint files
+    pub keep: Option<bool>,
+    /// leave the container running after writing checkpoint to disk
+    pub leave_running: Option<bool>,
+    /// checkpoint a container with established TCP connections
+    pub tcp_established: Option<bool>,
+    /// export the checkpoint image to a tar.gz
+    pub export: Option<bool>,
+    /// do not include root file-system changes when exporting. can only be used with export
+    pub ignore_root_fs: Option<bool>,
+    /// do not include associated volumes. can only be used with export
+    pub ignore_volumes: Option<bool>,
+    /// dump the container's memory information only, leaving the container running. only works on runc 1.0-rc or higher
+    pub pre_checkpoint: Option<bool>,
+    /// check out the container with previous criu image files in pre-dump. only works on runc 1.0-rc or higher
+    pub with_previous: Option<bool>,
+    /// checkpoint a container with filelocks
+    pub file_locks: Option<bool>,
+    /// add checkpoint statistics to the returned CheckpointReport
+    pub print_stats: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerCreateLibpodParams {
+    /// attributes for creating a container
+    pub create: models::SpecGenerator,
+}
+/// struct for passing parameters to the method [`container_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerDeleteLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// additionally remove containers that depend on the container to be removed
+    pub depend: Option<bool>,
+    /// force stop container if running
+    pub force: Option<bool>,
+    /// ignore errors when the container to be removed does not existxo
+    pub ignore: Option<bool>,
+    /// number of seconds to wait before killing container when force removing
+    pub timeout: Option<i32>,
+    /// delete volumes
+    pub v: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_exec_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerExecLibpodParams {
+    /// name of container
+    pub name: String,
+    /// Attributes for create
+    pub control: Option<models::ContainerExecLibpodRequest>,
+}
+/// struct for passing parameters to the method [`container_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerExistsLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_export_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerExportLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_healthcheck_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerHealthcheckLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_init_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerInitLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerInspectLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// display filesystem usage
+    pub size: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_kill_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerKillLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// signal to be sent to container, either by integer or SIG_ name
+    pub signal: Option<String>,
+}
+/// struct for passing parameters to the method [`container_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerListLibpodParams {
+    /// Return all containers. By default, only running containers are shown
+    pub all: Option<bool>,
+    /// Return this number of most recently created containers, including non-running ones.
+    pub limit: Option<i32>,
+    /// Include namespace information
+    pub namespace: Option<bool>,
+    /// Ignored. Previously included details on pod name and ID that are currently included by default.
+    pub pod: Option<bool>,
+    /// Return the size of container as fields SizeRw and SizeRootFs.
+    pub size: Option<bool>,
+    /// Sync container state with OCI runtime
+    pub sync: Option<bool>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the containers list. Available filters: - `ancestor`=(`<image-name>[:<tag>]`, `<image id>`, or `<image@digest>`) - `before`=(`<container id>` or `<container name>`) - `expose`=(`<port>[/<proto>]` or `<startport-endport>/[<proto>]`) - `exited=<int>` containers with exit code of `<int>` - `health`=(`starting`, `healthy`, `unhealthy` or `none`) - `id=<ID>` a container's ID - `is-task`=(`true` or `false`) - `label`=(`key` or `\"key=value\"`) of a container label - `name=<name>` a container's name - `network`=(`<network id>` or `<network name>`) - `pod`=(`<pod id>` or `<pod name>`) - `publish`=(`<port>[/<proto>]` or `<startport-endport>/[<proto>]`) - `since`=(`<container id>` or `<container name>`) - `status`=(`created`, `restarting`, `running`, `removing`, `paused`, `exited` or `dead`) - `volume`=(`<volume name>` or `<mount point destination>`)
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`container_logs_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerLogsLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// Keep connection after returning logs.
+    pub follow: Option<bool>,
+    /// Return logs from stdout
+    pub stdout: Option<bool>,
+    /// Return logs from stderr
+    pub stderr: Option<bool>,
+    /// Only return logs since this time, as a UNIX timestamp
+    pub since: Option<String>,
+    /// Only return logs before this time, as a UNIX timestamp
+    pub until: Option<String>,
+    /// Add timestamps to every log line
+    pub timestamps: Option<bool>,
+    /// Only return this number of log lines from the end of the logs
+    pub tail: Option<String>,
+}
+/// struct for passing parameters to the method [`container_mount_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerMountLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_pause_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerPauseLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_prune_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerPruneLibpodParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters:  - `until=<timestamp>` Prune containers created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.  - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune containers with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`container_rename_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerRenameLibpodParams {
+    /// Full or partial ID or full name of the container to rename
+    pub name: String,
+    /// New name for the container
+    pub name2: String,
+}
+/// struct for passing parameters to the method [`container_resize_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerResizeLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// Height to set for the terminal, in characters
+    pub h: Option<i32>,
+    /// Width to set for the terminal, in characters
+    pub w: Option<i32>,
+}
+/// struct for passing parameters to the method [`container_restart_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerRestartLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// number of seconds to wait before killing container
+    pub t: Option<i32>,
+}
+/// struct for passing parameters to the method [`container_restore_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerRestoreLibpodParams {
+    /// the name or id of the container
+    pub name: String,
+    /// the name of the container when restored from a tar. can only be used with import
+    pub name2: Option<String>,
+    /// keep all temporary checkpoint files
+    pub keep: Option<bool>,
+    /// checkpoint a container with established TCP connections
+    pub tcp_established: Option<bool>,
+    /// import the restore from a checkpoint tar.gz
+    pub import: Option<bool>,
+    /// do not include root file-system changes when exporting. can only be used with import
+    pub ignore_root_fs: Option<bool>,
+    /// do not restore associated volumes. can only be used with import
+    pub ignore_volumes: Option<bool>,
+    /// ignore IP address if set statically
+    pub ignore_static_ip: Option<bool>,
+    /// ignore MAC address if set statically
+    pub ignore_static_mac: Option<bool>,
+    /// restore a container with file locks
+    pub file_locks: Option<bool>,
+    /// add restore statistics to the returned RestoreReport
+    pub print_stats: Option<bool>,
+    /// pod to restore into
+    pub pod: Option<String>,
+}
+/// struct for passing parameters to the method [`container_start_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerStartLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// Override the key sequence for detaching a container. Format is a single character [a-Z] or ctrl-<value> where <value> is one of: a-z, @, ^, [, , or _.
+    pub detach_keys: Option<String>,
+}
+/// struct for passing parameters to the method [`container_stats_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerStatsLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// Stream the output
+    pub stream: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_stop_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerStopLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// number of seconds to wait before killing container
+    pub timeout: Option<i32>,
+    /// do not return error if container is already stopped
+    pub ignore: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_top_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerTopLibpodParams {
+    /// Name of container to query for processes (As of version 1.xx)
+    pub name: String,
+    /// when true, repeatedly stream the latest output (As of version 4.0)
+    pub stream: Option<bool>,
+    /// if streaming, delay in seconds between updates. Must be >1. (As of version 4.0)
+    pub delay: Option<i32>,
+    /// arguments to pass to ps such as aux.
+    pub ps_args: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`container_unmount_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerUnmountLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_unpause_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerUnpauseLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_update_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerUpdateLibpodParams {
+    /// Full or partial ID or full name of the container to update
+    pub name: String,
+    /// New restart policy for the container.
+    pub restart_policy: Option<String>,
+    /// New amount of retries for the container's restart policy. Only allowed if restartPolicy is set to on-failure
+    pub restart_retries: Option<i32>,
+    /// attributes for updating the container
+    pub config: Option<models::UpdateEntities>,
+}
+/// struct for passing parameters to the method [`container_wait_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainerWaitLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// Conditions to wait for. If no condition provided the 'exited' condition is assumed.
+    pub condition: Option<Vec<String>>,
+    /// Time Interval to wait before polling for completion.
+    pub interval: Option<String>,
+}
+/// struct for passing parameters to the method [`containers_stats_all_libpod`]
+#[derive(Clone, Debug)]
+pub struct ContainersStatsAllLibpodParams {
+    /// names or IDs of containers
+    pub containers: Option<Vec<String>>,
+    /// Stream the output
+    pub stream: Option<bool>,
+    /// Time in seconds between stats reports
+    pub interval: Option<i32>,
+}
+/// struct for passing parameters to the method [`exec_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct ExecInspectLibpodParams {
+    /// Exec instance ID
+    pub id: String,
+}
+/// struct for passing parameters to the method [`exec_resize_libpod`]
+#[derive(Clone, Debug)]
+pub struct ExecResizeLibpodParams {
+    /// Exec instance ID
+    pub id: String,
+    /// Height of the TTY session in characters
+    pub h: Option<i32>,
+    /// Width of the TTY session in characters
+    pub w: Option<i32>,
+}
+/// struct for passing parameters to the method [`exec_start_libpod`]
+#[derive(Clone, Debug)]
+pub struct ExecStartLibpodParams {
+    /// Exec instance ID
+    pub id: String,
+    /// Attributes for start
+    pub control: Option<models::ExecStartLibpodRequest>,
+}
+/// struct for passing parameters to the method [`generate_kube_libpod`]
+#[derive(Clone, Debug)]
+pub struct GenerateKubeLibpodParams {
+    /// Name or ID of the container or pod.
+    pub names: Vec<String>,
+    /// Generate YAML for a Kubernetes service object.
+    pub service: Option<bool>,
+    /// Generate YAML for the given Kubernetes kind.
+    pub r#type: Option<String>,
+    /// Set the replica number for Deployment kind.
+    pub replicas: Option<i32>,
+    /// don't truncate annotations to the Kubernetes maximum length of 63 characters
+    pub no_trunc: Option<bool>,
+    /// add podman-only reserved annotations in generated YAML file (cannot be used by Kubernetes)
+    pub podman_only: Option<bool>,
+}
+/// struct for passing parameters to the method [`generate_systemd_libpod`]
+#[derive(Clone, Debug)]
+pub struct GenerateSystemdLibpodParams {
+    /// Name or ID of the container or pod.
+    pub name: String,
+    /// Use container/pod names instead of IDs.
+    pub use_name: Option<bool>,
+    /// Create a new container instead of starting an existing one.
+    pub new: Option<bool>,
+    /// Do not generate the header including the Podman version and the timestamp.
+    pub no_header: Option<bool>,
+    /// Start timeout in seconds.
+    pub start_timeout: Option<i32>,
+    /// Stop timeout in seconds.
+    pub stop_timeout: Option<i32>,
+    /// Systemd restart-policy.
+    pub restart_policy: Option<String>,
+    /// Systemd unit name prefix for containers.
+    pub container_prefix: Option<String>,
+    /// Systemd unit name prefix for pods.
+    pub pod_prefix: Option<String>,
+    /// Systemd unit name separator between name/id and prefix.
+    pub separator: Option<String>,
+    /// Configures the time to sleep before restarting a service.
+    pub restart_sec: Option<i32>,
+    /// Systemd Wants list for the container or pods.
+    pub wants: Option<Vec<String>>,
+    /// Systemd After list for the container or pods.
+    pub after: Option<Vec<String>>,
+    /// Systemd Requires list for the container or pods.
+    pub requires: Option<Vec<String>>,
+    /// Set environment variables to the systemd unit files.
+    pub additional_env_variables: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`image_build_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageBuildLibpodParams {
+    /// Path within the build context to the `Dockerfile`. This is ignored if remote is specified and points to an external `Dockerfile`.
+    pub dockerfile: Option<String>,
+    /// A name and optional tag to apply to the image in the `name:tag` format.  If you omit the tag, the default latest value is assumed. You can provide several t parameters.
+    pub t: Option<String>,
+    /// Instead of building for a set of platforms specified using the platform option, inspect the build's base images, and build for all of the platforms that are available.  Stages that use *scratch* as a starting point can not be inspected, so at least one non-*scratch* stage must be present for detection to work usefully.
+    pub allplatforms: Option<bool>,
+    /// TBD Extra hosts to add to /etc/hosts (As of version 1.xx)
+    pub extrahosts: Option<String>,
+    /// A Git repository URI or HTTP/HTTPS context URI. If the URI points to a single text file, the file’s contents are placed into a file called Dockerfile and the image is built from that file. If the URI points to a tarball, the file is downloaded by the daemon and the contents therein used as the context for the build. If the URI points to a tarball and the dockerfile parameter is also specified, there must be a file with the corresponding path inside the tarball. (As of version 1.xx)
+    pub remote: Option<String>,
+    /// Suppress verbose build output
+    pub q: Option<bool>,
+    /// Do not use the cache when building the image (As of version 1.xx)
+    pub nocache: Option<bool>,
+    /// JSON array of images used to build cache resolution (As of version 1.xx)
+    pub cachefrom: Option<String>,
+    /// Attempt to pull the image even if an older image exists locally (As of version 1.xx)
+    pub pull: Option<bool>,
+    /// Remove intermediate containers after a successful build (As of version 1.xx)
+    pub rm: Option<bool>,
+    /// Always remove intermediate containers, even upon failure (As of version 1.xx)
+    pub forcerm: Option<bool>,
+    /// Memory is the upper limit (in bytes) on how much memory running containers can use (As of version 1.xx)
+    pub memory: Option<i32>,
+    /// MemorySwap limits the amount of memory and swap together (As of version 1.xx)
+    pub memswap: Option<i32>,
+    /// CPUShares (relative weight (As of version 1.xx)
+    pub cpushares: Option<i32>,
+    /// CPUSetCPUs in which to allow execution (0-3, 0,1) (As of version 1.xx)
+    pub cpusetcpus: Option<String>,
+    /// CPUPeriod limits the CPU CFS (Completely Fair Scheduler) period (As of version 1.xx)
+    pub cpuperiod: Option<i32>,
+    /// CPUQuota limits the CPU CFS (Completely Fair Scheduler) quota (As of version 1.xx)
+    pub cpuquota: Option<i32>,
+    /// JSON map of string pairs denoting build-time variables. For example, the build argument `Foo` with the value of `bar` would be encoded in JSON as `[\"Foo\":\"bar\"]`.  For example, buildargs={\"Foo\":\"bar\"}.  Note(s): * This should not be used to pass secrets. * The value of buildargs should be URI component encoded before being passed to the API.  (As of version 1.xx)
+    pub buildargs: Option<String>,
+    /// ShmSize is the \"size\" value to use when mounting an shmfs on the container's /dev/shm directory. Default is 64MB (As of version 1.xx)
+    pub shmsize: Option<i32>,
+    /// Silently ignored. Squash the resulting images layers into a single layer (As of version 1.xx)
+    pub squash: Option<bool>,
+    /// JSON map of key, value pairs to set as labels on the new image (As of version 1.xx)
+    pub labels: Option<String>,
+    /// Add an intermediate image *label* (e.g. label=*value*) to the intermediate image metadata.
+    pub layer_label: Option<Vec<String>>,
+    /// Cache intermediate layers during build. (As of version 1.xx)
+    pub layers: Option<bool>,
+    /// Sets the networking mode for the run commands during build. Supported standard values are:   * `bridge` limited to containers within a single host, port mapping required for external access   * `host` no isolation between host and containers on this network   * `none` disable all networking for this container   * container:<nameOrID> share networking with given container   ---All other values are assumed to be a custom network's name (As of version 1.xx)
+    pub networkmode: Option<String>,
+    /// Platform format os[/arch[/variant]] (As of version 1.xx)
+    pub platform: Option<String>,
+    /// Target build stage (As of version 1.xx)
+    pub target: Option<String>,
+    /// output configuration TBD (As of version 1.xx)
+    pub outputs: Option<String>,
+    /// Inject http proxy environment variables into container (As of version 2.0.0)
+    pub httpproxy: Option<bool>,
+    /// Unset environment variables from the final image.
+    pub unsetenv: Option<Vec<String>>,
+    /// Unset the image label, causing the label not to be inherited from the base image.
+    pub unsetlabel: Option<Vec<String>>,
+    /// Extra volumes that should be mounted in the build container.
+    pub volume: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`image_changes_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageChangesLibpodParams {
+    /// the name or id of the image
+    pub name: String,
+    /// specify a second layer which is used to compare against it instead of the parent layer
+    pub parent: Option<String>,
+    /// select what you want to match, default is all
+    pub diff_type: Option<String>,
+}
+/// struct for passing parameters to the method [`image_commit_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageCommitLibpodParams {
+    /// the name or ID of a container
+    pub container: String,
+    /// author of the image
+    pub author: Option<String>,
+    /// instructions to apply while committing in Dockerfile format (i.e. \"CMD=/bin/foo\")
+    pub changes: Option<Vec<String>>,
+    /// commit message
+    pub comment: Option<String>,
+    /// format of the image manifest and metadata (default \"oci\")
+    pub format: Option<String>,
+    /// pause the container before committing it
+    pub pause: Option<bool>,
+    /// squash the container before committing it
+    pub squash: Option<bool>,
+    /// the repository name for the created image
+    pub repo: Option<String>,
+    /// output from commit process
+    pub stream: Option<bool>,
+    /// tag name for the created image
+    pub tag: Option<String>,
+}
+/// struct for passing parameters to the method [`image_delete_all_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageDeleteAllLibpodParams {
+    /// Images IDs or names to remove.
+    pub images: Option<Vec<String>>,
+    /// Remove all images.
+    pub all: Option<bool>,
+    /// Force image removal (including containers using the images).
+    pub force: Option<bool>,
+    /// Ignore if a specified image does not exist and do not throw an error.
+    pub ignore: Option<bool>,
+    /// Resolves to manifest list instead of image.
+    pub lookup_manifest: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageDeleteLibpodParams {
+    /// name or ID of image to remove
+    pub name: String,
+    /// remove the image even if used by containers or has other tags
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageExistsLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_export_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageExportLibpodParams {
+    /// format for exported image (only docker-archive is supported)
+    pub format: Option<String>,
+    /// references to images to export
+    pub references: Option<Vec<String>>,
+    /// use compression on image
+    pub compress: Option<bool>,
+    /// accept uncompressed layers when copying OCI images
+    pub oci_accept_uncompressed_layers: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_get_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageGetLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// format for exported image
+    pub format: Option<String>,
+    /// use compression on image
+    pub compress: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_history_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageHistoryLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_import_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageImportLibpodParams {
+    /// tarball for imported image
+    pub upload: Bytes,
+    pub content_type: Option<String>,
+    /// Apply the following possible instructions to the created image: CMD | ENTRYPOINT | ENV | EXPOSE | LABEL | STOPSIGNAL | USER | VOLUME | WORKDIR.  JSON encoded string
+    pub changes: Option<Vec<String>>,
+    /// Set commit message for imported image
+    pub message: Option<String>,
+    /// Optional Name[:TAG] for the image
+    pub reference: Option<String>,
+    /// Load image from the specified URL
+    pub url: Option<String>,
+}
+/// struct for passing parameters to the method [`image_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageInspectLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageListLibpodParams {
+    /// Show all images. Only images from a final layer (no children) are shown by default.
+    pub all: Option<bool>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list. Available filters: - `before`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`) - `dangling=true` - `label=key` or `label=\"key=value\"` of an image label - `reference`=(`<image-name>[:<tag>]`) - `id`=(`<image-id>`) - `since`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`)
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`image_load_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageLoadLibpodParams {
+    /// tarball of container image
+    pub upload: Bytes,
+}
+/// struct for passing parameters to the method [`image_prune_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImagePruneLibpodParams {
+    /// Remove all images not in use by containers, not just dangling ones
+    pub all: Option<bool>,
+    /// Remove images even when they are used by external containers (e.g, by build containers)
+    pub external: Option<bool>,
+    /// filters to apply to image pruning, encoded as JSON (map[string][]string). Available filters:   - `dangling=<boolean>` When set to `true` (or `1`), prune only      unused *and* untagged images. When set to `false`      (or `0`), all unused images are pruned.   - `until=<string>` Prune images created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.   - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune images with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`image_pull_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImagePullLibpodParams {
+    /// Mandatory reference to the image (e.g., quay.io/image/name:tag)
+    pub reference: Option<String>,
+    /// silences extra stream data on pull
+    pub quiet: Option<bool>,
+    /// Return the same JSON payload as the Docker-compat endpoint.
+    pub compat_mode: Option<bool>,
+    /// Pull image for the specified architecture.
+    pub arch: Option<String>,
+    /// Pull image for the specified operating system.
+    pub os: Option<String>,
+    /// Pull image for the specified variant.
+    pub variant: Option<String>,
+    /// Pull policy, \"always\" (default), \"missing\", \"newer\", \"never\".
+    pub policy: Option<String>,
+    /// Require TLS verification.
+    pub tls_verify: Option<bool>,
+    /// Pull all tagged images in the repository.
+    pub all_tags: Option<bool>,
+    /// base-64 encoded auth config. Must include the following four values: username, password, email and server address OR simply just an identity token.
+    pub x_registry_auth: Option<String>,
+}
+/// struct for passing parameters to the method [`image_push_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImagePushLibpodParams {
+    /// Name of image to push.
+    pub name: String,
+    /// Allows for pushing the image to a different destination than the image refers to.
+    pub destination: Option<String>,
+    /// Enforce compressing the layers with the specified --compression and do not reuse differently compressed blobs on the registry.
+    pub force_compression_format: Option<bool>,
+    /// Require TLS verification.
+    pub tls_verify: Option<bool>,
+    /// silences extra stream data on push
+    pub quiet: Option<bool>,
+    /// A base64-encoded auth configuration.
+    pub x_registry_auth: Option<String>,
+}
+/// struct for passing parameters to the method [`image_resolve_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageResolveLibpodParams {
+    /// the (short) name to resolve
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_scp_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageScpLibpodParams {
+    /// source connection/image
+    pub name: String,
+    /// dest connection/image
+    pub destination: Option<String>,
+    /// quiet output
+    pub quiet: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_search_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageSearchLibpodParams {
+    /// term to search
+    pub term: Option<String>,
+    /// maximum number of results
+    pub limit: Option<i32>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list. Available filters: - `is-automated=(true|false)` - `is-official=(true|false)` - `stars=<number>` Matches images that have at least 'number' stars.
+    pub filters: Option<String>,
+    /// Require HTTPS and verify signatures when contacting registries.
+    pub tls_verify: Option<bool>,
+    /// list the available tags in the repository
+    pub list_tags: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_tag_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageTagLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// the repository to tag in
+    pub repo: Option<String>,
+    /// the name of the new tag
+    pub tag: Option<String>,
+}
+/// struct for passing parameters to the method [`image_tree_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageTreeLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// show all child images and layers of the specified image
+    pub whatrequires: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_untag_libpod`]
+#[derive(Clone, Debug)]
+pub struct ImageUntagLibpodParams {
+    /// the name or ID of the container
+    pub name: String,
+    /// the repository to untag
+    pub repo: Option<String>,
+    /// the name of the tag to untag
+    pub tag: Option<String>,
+}
+/// struct for passing parameters to the method [`kube_apply_libpod`]
+#[derive(Clone, Debug)]
+pub struct KubeApplyLibpodParams {
+    /// Kubernetes YAML file.
+    pub request: Bytes,
+    /// Path to the CA cert file for the Kubernetes cluster.
+    pub ca_cert_file: Option<String>,
+    /// Path to the kubeconfig file for the Kubernetes cluster.
+    pub kube_config: Option<String>,
+    /// The namespace to deploy the workload to on the Kubernetes cluster.
+    pub namespace: Option<String>,
+    /// Create a service object for the container being deployed.
+    pub service: Option<bool>,
+    /// Path to the Kubernetes yaml file to deploy.
+    pub file: Option<String>,
+}
+/// struct for passing parameters to the method [`manifest_add_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestAddLibpodParams {
+    /// the name or ID of the manifest
+    pub name: String,
+    /// options for creating a manifest
+    pub options: Option<models::ManifestAddOptions>,
+}
+/// struct for passing parameters to the method [`manifest_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestCreateLibpodParams {
+    /// manifest list or index name to create
+    pub name: String,
+    /// One or more names of an image or a manifest list. Repeat parameter as needed.  Support for multiple images, as of version 4.0.0 Alias of `image` is support for compatibility with < 4.0.0 Response status code is 200 with < 4.0.0 for compatibility
+    pub images: String,
+    /// add all contents if given list
+    pub all: Option<bool>,
+    /// modify an existing list if one with the desired name already exists
+    pub amend: Option<bool>,
+    /// options for new manifest
+    pub options: Option<models::ManifestModifyOptions>,
+}
+/// struct for passing parameters to the method [`manifest_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestDeleteLibpodParams {
+    /// The name or ID of the  list to be deleted
+    pub name: String,
+}
+/// struct for passing parameters to the method [`manifest_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestExistsLibpodParams {
+    /// the name or ID of the manifest list
+    pub name: String,
+}
+/// struct for passing parameters to the method [`manifest_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestInspectLibpodParams {
+    /// the name or ID of the manifest list
+    pub name: String,
+    /// Require HTTPS and verify signatures when contacting registries.
+    pub tls_verify: Option<bool>,
+}
+/// struct for passing parameters to the method [`manifest_modify_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestModifyLibpodParams {
+    /// the name or ID of the manifest
+    pub name: String,
+    /// options for mutating a manifest
+    pub options: models::ManifestModifyOptions,
+    /// Require HTTPS and verify signatures when contacting registries.
+    pub tls_verify: Option<bool>,
+}
+/// struct for passing parameters to the method [`manifest_push_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestPushLibpodParams {
+    /// the name or ID of the manifest list
+    pub name: String,
+    /// the registry for the manifest list
+    pub destination: String,
+    /// add existing instances with requested compression algorithms to manifest list
+    pub add_compression: Option<Vec<String>>,
+    /// Enforce compressing the layers with the specified --compression and do not reuse differently compressed blobs on the registry.
+    pub force_compression_format: Option<bool>,
+    /// push all images
+    pub all: Option<bool>,
+    /// Require HTTPS and verify signatures when contacting registries.
+    pub tls_verify: Option<bool>,
+    /// silences extra stream data on push
+    pub quiet: Option<bool>,
+}
+/// struct for passing parameters to the method [`manifest_push_v3_libpod`]
+#[derive(Clone, Debug)]
+pub struct ManifestPushV3LibpodParams {
+    /// the name or ID of the manifest
+    pub name: String,
+    /// the destination for the manifest
+    pub destination: String,
+    /// push all images
+    pub all: Option<bool>,
+}
+/// struct for passing parameters to the method [`network_connect_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkConnectLibpodParams {
+    /// the name of the network
+    pub name: String,
+    /// attributes for connecting a container to a network
+    pub create: Option<models::NetworkConnectOptions>,
+}
+/// struct for passing parameters to the method [`network_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkCreateLibpodParams {
+    /// attributes for creating a network
+    pub create: Option<models::NetworkCreateLibpod>,
+}
+/// struct for passing parameters to the method [`network_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkDeleteLibpodParams {
+    /// the name of the network
+    pub name: String,
+    /// remove containers associated with network
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`network_disconnect_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkDisconnectLibpodParams {
+    /// the name of the network
+    pub name: String,
+    /// attributes for disconnecting a container from a network
+    pub create: Option<models::NetworkDisconnect>,
+}
+/// struct for passing parameters to the method [`network_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkExistsLibpodParams {
+    /// the name or ID of the network
+    pub name: String,
+}
+/// struct for passing parameters to the method [`network_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkInspectLibpodParams {
+    /// the name of the network
+    pub name: String,
+}
+/// struct for passing parameters to the method [`network_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkListLibpodParams {
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the network list. Available filters:   - `name=[name]` Matches network name (accepts regex).   - `id=[id]` Matches for full or partial ID.   - `driver=[driver]` Only bridge is supported.   - `label=[key]` or `label=[key=value]` Matches networks based on the presence of a label alone or a label and a value.   - `until=[timestamp]` Matches all networks that were created before the given timestamp.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`network_prune_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkPruneLibpodParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`). Available filters:   - `until=<timestamp>` Prune networks created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.   - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune networks with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`network_update_libpod`]
+#[derive(Clone, Debug)]
+pub struct NetworkUpdateLibpodParams {
+    /// the name or ID of the network
+    pub name: String,
+    /// attributes for updating a netavark network
+    pub update: Option<models::NetworkUpdateOptions>,
+}
+/// struct for passing parameters to the method [`play_kube_down_libpod`]
+#[derive(Clone, Debug)]
+pub struct PlayKubeDownLibpodParams {
+    /// Remove volumes.
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`play_kube_libpod`]
+#[derive(Clone, Debug)]
+pub struct PlayKubeLibpodParams {
+    /// Kubernetes YAML file.
+    pub request: Bytes,
+    /// JSON encoded value of annotations (a map[string]string).
+    pub annotations: Option<String>,
+    /// Logging driver for the containers in the pod.
+    pub log_driver: Option<String>,
+    /// logging driver options
+    pub log_options: Option<Vec<String>>,
+    /// USe the network mode or specify an array of networks.
+    pub network: Option<Vec<String>>,
+    /// do not setup /etc/hosts file in container
+    pub no_hosts: Option<bool>,
+    /// use annotations that are not truncated to the Kubernetes maximum length of 63 characters
+    pub no_trunc: Option<bool>,
+    /// publish a container's port, or a range of ports, to the host
+    pub publish_ports: Option<Vec<String>>,
+    /// Whether to publish all ports defined in the K8S YAML file (containerPort, hostPort), if false only hostPort will be published
+    pub publish_all_ports: Option<bool>,
+    /// replace existing pods and containers
+    pub replace: Option<bool>,
+    /// Starts a service container before all pods.
+    pub service_container: Option<bool>,
+    /// Start the pod after creating it.
+    pub start: Option<bool>,
+    /// Static IPs used for the pods.
+    pub static_ips: Option<Vec<String>>,
+    /// Static MACs used for the pods.
+    pub static_macs: Option<Vec<String>>,
+    /// Require HTTPS and verify signatures when contacting registries.
+    pub tls_verify: Option<bool>,
+    /// Set the user namespace mode for the pods.
+    pub userns: Option<String>,
+    /// Clean up all objects created when a SIGTERM is received or pods exit.
+    pub wait: Option<bool>,
+}
+/// struct for passing parameters to the method [`pod_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodCreateLibpodParams {
+    /// attributes for creating a pod
+    pub create: Option<models::PodSpecGenerator>,
+}
+/// struct for passing parameters to the method [`pod_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodDeleteLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+    /// force removal of a running pod by first stopping all containers, then removing all containers in the pod
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`pod_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodExistsLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`pod_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodInspectLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`pod_kill_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodKillLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+    /// signal to be sent to pod
+    pub signal: Option<String>,
+}
+/// struct for passing parameters to the method [`pod_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodListLibpodParams {
+    /// JSON encoded value of the filters (a map[string][]string) to process on the pods list. Available filters:   - `id=<pod-id>` Matches all of pod id.   - `label=<key>` or `label=<key>:<value>` Matches pods based on the presence of a label alone or a label and a value.   - `name=<pod-name>` Matches all of pod name.   - `until=<timestamp>` List pods created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.   - `status=<pod-status>` Pod's status: `stopped`, `running`, `paused`, `exited`, `dead`, `created`, `degraded`.   - `network=<pod-network>` Name or full ID of network.   - `ctr-names=<pod-ctr-names>` Container name within the pod.   - `ctr-ids=<pod-ctr-ids>` Container ID within the pod.   - `ctr-status=<pod-ctr-status>` Container status within the pod.   - `ctr-number=<pod-ctr-number>` Number of containers in the pod.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`pod_pause_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodPauseLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`pod_restart_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodRestartLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`pod_start_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodStartLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`pod_stats_all_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodStatsAllLibpodParams {
+    /// Provide statistics for all running pods.
+    pub all: Option<bool>,
+    /// Names or IDs of pods.
+    pub names_or_ids: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`pod_stop_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodStopLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+    /// timeout
+    pub t: Option<i32>,
+}
+/// struct for passing parameters to the method [`pod_top_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodTopLibpodParams {
+    /// Name of pod to query for processes
+    pub name: String,
+    /// when true, repeatedly stream the latest output (As of version 4.0)
+    pub stream: Option<bool>,
+    /// if streaming, delay in seconds between updates. Must be >1. (As of version 4.0)
+    pub delay: Option<i32>,
+    /// arguments to pass to ps such as aux.
+    pub ps_args: Option<String>,
+}
+/// struct for passing parameters to the method [`pod_unpause_libpod`]
+#[derive(Clone, Debug)]
+pub struct PodUnpauseLibpodParams {
+    /// the name or ID of the pod
+    pub name: String,
+}
+/// struct for passing parameters to the method [`put_container_archive_libpod`]
+#[derive(Clone, Debug)]
+pub struct PutContainerArchiveLibpodParams {
+    /// container name or id
+    pub name: String,
+    /// Path to a directory in the container to extract
+    pub path: String,
+    /// tarfile of files to copy into the container
+    pub request: Bytes,
+    /// pause the container while copying (defaults to true)
+    pub pause: Option<bool>,
+}
+/// struct for passing parameters to the method [`secret_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct SecretCreateLibpodParams {
+    /// User-defined name of the secret.
+    pub name: String,
+    /// Secret
+    pub request: Bytes,
+    /// Secret driver
+    pub driver: Option<String>,
+    /// Secret driver options
+    pub driveropts: Option<String>,
+    /// Labels on the secret
+    pub labels: Option<String>,
+}
+/// struct for passing parameters to the method [`secret_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct SecretDeleteLibpodParams {
+    /// the name or ID of the secret
+    pub name: String,
+    /// Remove all secrets
+    pub all: Option<bool>,
+}
+/// struct for passing parameters to the method [`secret_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct SecretExistsLibpodParams {
+    /// the name or ID of the secret
+    pub name: String,
+}
+/// struct for passing parameters to the method [`secret_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct SecretInspectLibpodParams {
+    /// the name or ID of the secret
+    pub name: String,
+    /// Display Secret
+    pub showsecret: Option<bool>,
+}
+/// struct for passing parameters to the method [`secret_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct SecretListLibpodParams {
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the secrets list. Currently available filters:   - `name=[name]` Matches secrets name (accepts regex).   - `id=[id]` Matches for full or partial ID.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`system_events_libpod`]
+#[derive(Clone, Debug)]
+pub struct SystemEventsLibpodParams {
+    /// start streaming events from this time
+    pub since: Option<String>,
+    /// stop streaming events later than this
+    pub until: Option<String>,
+    /// JSON encoded map[string][]string of constraints
+    pub filters: Option<String>,
+    /// when false, do not follow events
+    pub stream: Option<bool>,
+}
+/// struct for passing parameters to the method [`volume_create_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumeCreateLibpodParams {
+    /// attributes for creating a volume
+    pub create: Option<models::VolumeCreateOptions>,
+}
+/// struct for passing parameters to the method [`volume_delete_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumeDeleteLibpodParams {
+    /// the name or ID of the volume
+    pub name: String,
+    /// force removal
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`volume_exists_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumeExistsLibpodParams {
+    /// the name of the volume
+    pub name: String,
+}
+/// struct for passing parameters to the method [`volume_inspect_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumeInspectLibpodParams {
+    /// the name or ID of the volume
+    pub name: String,
+}
+/// struct for passing parameters to the method [`volume_list_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumeListLibpodParams {
+    /// JSON encoded value of the filters (a map[string][]string) to process on the volumes list. Available filters:   - driver=<volume-driver-name> Matches volumes based on their driver.   - label=<key> or label=<key>:<value> Matches volumes based on the presence of a label alone or a label and a value.   - name=<volume-name> Matches all of volume name.   - opt=<driver-option> Matches a storage driver options   - `until=<timestamp>` List volumes created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`volume_prune_libpod`]
+#[derive(Clone, Debug)]
+pub struct VolumePruneLibpodParams {
+    /// JSON encoded value of filters (a map[string][]string) to match volumes against before pruning. Available filters:   - `until=<timestamp>` Prune volumes created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.   - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune volumes with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
@@ -0,0 +1,5231 @@
+use crate::models;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::error;
+use std::fmt;
+use crate::api::transport::AsyncTransport;
+/// struct for typed errors of method [`config_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigCreateError {
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`config_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigDeleteError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`config_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`config_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigListError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`config_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigUpdateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct ConfigApi {
+    configuration: Arc<Configuration>,
+}
+impl ConfigApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn create(
+        &self,
+        params: ConfigCreateParams,
+    ) -> Result<models::IdResponse, Error<ConfigCreateError>> {
+        let uri_str = format!("{}/configs/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ConfigCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: ConfigDeleteParams,
+    ) -> Result<(), Error<ConfigDeleteError>> {
+        let uri_str = format!(
+            "{}/configs/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ConfigDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: ConfigInspectParams,
+    ) -> Result<models::Config, Error<ConfigInspectError>> {
+        let uri_str = format!(
+            "{}/configs/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ConfigInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: ConfigListParams,
+    ) -> Result<Vec<models::Config>, Error<ConfigListError>> {
+        let uri_str = format!("{}/configs", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ConfigListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: ConfigUpdateParams,
+    ) -> Result<(), Error<ConfigUpdateError>> {
+        let uri_str = format!(
+            "{}/configs/{id}/update", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ConfigUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`container_archive`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerArchiveError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_archive_info`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerArchiveInfoError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_attach`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerAttachError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_attach_websocket`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerAttachWebsocketError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_changes`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerChangesError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerCreateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerDeleteError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_export`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerExportError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_kill`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerKillError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerListError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_logs`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerLogsError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_pause`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerPauseError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_prune`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerPruneError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_rename`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerRenameError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_resize`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerResizeError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_restart`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerRestartError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_start`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStartError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_stats`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStatsError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_stop`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerStopError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_top`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerTopError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_unpause`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerUnpauseError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerUpdateError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`container_wait`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerWaitError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`put_container_archive`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PutContainerArchiveError {
+    Status400(models::ErrorResponse),
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct ContainerApi {
+    configuration: Arc<Configuration>,
+}
+impl ContainerApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Get a tar archive of a resource in the filesystem of container id.
+    pub async fn archive(
+        &self,
+        params: ContainerArchiveParams,
+    ) -> Result<(), Error<ContainerArchiveError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/archive", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        req_builder = req_builder.query(&[("path", &params.path.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerArchiveError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// A response header `X-Docker-Container-Path-Stat` is returned, containing a base64 - encoded JSON object with some filesystem header information about the path.
+    pub async fn archive_info(
+        &self,
+        params: ContainerArchiveInfoParams,
+    ) -> Result<(), Error<ContainerArchiveInfoError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/archive", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::HEAD, &uri_str);
+        req_builder = req_builder.query(&[("path", &params.path.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerArchiveInfoError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Attach to a container to read its output or send it input. You can attach to the same container multiple times and you can reattach to containers that have been detached.  Either the `stream` or `logs` parameter must be `true` for this endpoint to do anything.  See the [documentation for the `docker attach` command](https://docs.docker.com/engine/reference/commandline/attach/) for more details.  ### Hijacking  This endpoint hijacks the HTTP connection to transport `stdin`, `stdout`, and `stderr` on the same socket.  This is the response from the daemon for an attach request:  ``` HTTP/1.1 200 OK Content-Type: application/vnd.docker.raw-stream  [STREAM] ```  After the headers and two new lines, the TCP connection can now be used for raw, bidirectional communication between the client and server.  To hint potential proxies about connection hijacking, the Docker client can also optionally send connection upgrade headers.  For example, the client sends this request to upgrade the connection:  ``` POST /containers/16253994b7c4/attach?stream=1&stdout=1 HTTP/1.1 Upgrade: tcp Connection: Upgrade ```  The Docker daemon will respond with a `101 UPGRADED` response, and will similarly follow with the raw stream:  ``` HTTP/1.1 101 UPGRADED Content-Type: application/vnd.docker.raw-stream Connection: Upgrade Upgrade: tcp  [STREAM] ```  ### Stream format  When the TTY setting is disabled in [`POST /containers/create`](#operation/ContainerCreate), the HTTP Content-Type header is set to application/vnd.docker.multiplexed-stream and the stream over the hijacked connected is multiplexed to separate out `stdout` and `stderr`. The stream consists of a series of frames, each containing a header and a payload.  The header contains the information which the stream writes (`stdout` or `stderr`). It also contains the size of the associated frame encoded in the last four bytes (`uint32`).  It is encoded on the first eight bytes like this:  ```go header := [8]byte{STREAM_TYPE, 0, 0, 0, SIZE1, SIZE2, SIZE3, SIZE4} ```  `STREAM_TYPE` can be:  - 0: `stdin` (is written on `stdout`) - 1: `stdout` - 2: `stderr`  `SIZE1, SIZE2, SIZE3, SIZE4` are the four bytes of the `uint32` size encoded as big endian.  Following the header is the payload, which is the specified number of bytes of `STREAM_TYPE`.  The simplest way to implement this protocol is the following:  1. Read 8 bytes. 2. Choose `stdout` or `stderr` depending on the first byte. 3. Extract the frame size from the last four bytes. 4. Read the extracted size and output it on the correct output. 5. Goto 1.  ### Stream format when using a TTY  When the TTY setting is enabled in [`POST /containers/create`](#operation/ContainerCreate), the stream is not multiplexed. The data exchanged over the hijacked connection is simply the raw data from the process PTY and client's `stdin`.
+    pub async fn attach(
+        &self,
+        params: ContainerAttachParams,
+    ) -> Result<crate::api::stream::AsyncLogStream, Error<ContainerAttachError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/attach", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.detach_keys {
+            req_builder = req_builder.query(&[("detachKeys", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.logs {
+            req_builder = req_builder.query(&[("logs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdin {
+            req_builder = req_builder.query(&[("stdin", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncLogStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerAttachError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn attach_websocket(
+        &self,
+        params: ContainerAttachWebsocketParams,
+    ) -> Result<(), Error<ContainerAttachWebsocketError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/attach/ws", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.detach_keys {
+            req_builder = req_builder.query(&[("detachKeys", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.logs {
+            req_builder = req_builder.query(&[("logs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdin {
+            req_builder = req_builder.query(&[("stdin", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerAttachWebsocketError> = serde_json::from_str(
+                    &content,
+                )
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns which files in a container's filesystem have been added, deleted, or modified. The `Kind` of modification can be one of:  - `0`: Modified (\"C\") - `1`: Added (\"A\") - `2`: Deleted (\"D\")
+    pub async fn changes(
+        &self,
+        params: ContainerChangesParams,
+    ) -> Result<Vec<models::FilesystemChange>, Error<ContainerChangesError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/changes", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerChangesError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn create(
+        &self,
+        params: ContainerCreateParams,
+    ) -> Result<models::ContainerCreateResponse, Error<ContainerCreateError>> {
+        let uri_str = format!("{}/containers/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.name {
+            req_builder = req_builder.query(&[("name", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.platform {
+            req_builder = req_builder.query(&[("platform", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerCreateError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: ContainerDeleteParams,
+    ) -> Result<(), Error<ContainerDeleteError>> {
+        let uri_str = format!(
+            "{}/containers/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.v {
+            req_builder = req_builder.query(&[("v", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.link {
+            req_builder = req_builder.query(&[("link", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerDeleteError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Export the contents of a container as a tarball.
+    pub async fn export(
+        &self,
+        params: ContainerExportParams,
+    ) -> Result<(), Error<ContainerExportError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/export", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerExportError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return low-level information about a container.
+    pub async fn inspect(
+        &self,
+        params: ContainerInspectParams,
+    ) -> Result<models::ContainerInspectResponse, Error<ContainerInspectError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/json", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.size {
+            req_builder = req_builder.query(&[("size", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerInspectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Send a POSIX signal to a container, defaulting to killing to the container.
+    pub async fn kill(
+        &self,
+        params: ContainerKillParams,
+    ) -> Result<(), Error<ContainerKillError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/kill", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.signal {
+            req_builder = req_builder.query(&[("signal", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerKillError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of containers. For details on the format, see the [inspect endpoint](#operation/ContainerInspect).  Note that it uses a different, smaller representation of a container than inspecting a single container. For example, the list of linked containers is not propagated .
+    pub async fn list(
+        &self,
+        params: ContainerListParams,
+    ) -> Result<Vec<models::ContainerSummary>, Error<ContainerListError>> {
+        let uri_str = format!("{}/containers/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.limit {
+            req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.size {
+            req_builder = req_builder.query(&[("size", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get `stdout` and `stderr` logs from a container.  Note: This endpoint works only for containers with the `json-file` or `journald` logging driver.
+    pub async fn logs(
+        &self,
+        params: ContainerLogsParams,
+    ) -> Result<crate::api::stream::AsyncLogStream, Error<ContainerLogsError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/logs", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.follow {
+            req_builder = req_builder.query(&[("follow", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.until {
+            req_builder = req_builder.query(&[("until", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.timestamps {
+            req_builder = req_builder.query(&[("timestamps", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tail {
+            req_builder = req_builder.query(&[("tail", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncLogStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerLogsError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Use the freezer cgroup to suspend all processes in a container.  Traditionally, when suspending a process the `SIGSTOP` signal is used, which is observable by the process being suspended. With the freezer cgroup the process is unaware, and unable to capture, that it is being suspended, and subsequently resumed.
+    pub async fn pause(
+        &self,
+        params: ContainerPauseParams,
+    ) -> Result<(), Error<ContainerPauseError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/pause", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerPauseError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn prune(
+        &self,
+        params: ContainerPruneParams,
+    ) -> Result<models::ContainerPruneResponse, Error<ContainerPruneError>> {
+        let uri_str = format!("{}/containers/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerPruneError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn rename(
+        &self,
+        params: ContainerRenameParams,
+    ) -> Result<(), Error<ContainerRenameError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/rename", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("name", &params.name.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerRenameError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resize the TTY for a container.
+    pub async fn resize(
+        &self,
+        params: ContainerResizeParams,
+    ) -> Result<(), Error<ContainerResizeError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/resize", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("h", &params.h.to_string())]);
+        req_builder = req_builder.query(&[("w", &params.w.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerResizeError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn restart(
+        &self,
+        params: ContainerRestartParams,
+    ) -> Result<(), Error<ContainerRestartError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/restart", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.signal {
+            req_builder = req_builder.query(&[("signal", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerRestartError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn start(
+        &self,
+        params: ContainerStartParams,
+    ) -> Result<(), Error<ContainerStartError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/start", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.detach_keys {
+            req_builder = req_builder.query(&[("detachKeys", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerStartError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// This endpoint returns a live stream of a container’s resource usage statistics.  The `precpu_stats` is the CPU statistic of the *previous* read, and is used to calculate the CPU usage percentage. It is not an exact copy of the `cpu_stats` field.  If either `precpu_stats.online_cpus` or `cpu_stats.online_cpus` is nil then for compatibility with older daemons the length of the corresponding `cpu_usage.percpu_usage` array should be used.  On a cgroup v2 host, the following fields are not set * `blkio_stats`: all fields other than `io_service_bytes_recursive` * `cpu_stats`: `cpu_usage.percpu_usage` * `memory_stats`: `max_usage` and `failcnt` Also, `memory_stats.stats` fields are incompatible with cgroup v1.  To calculate the values shown by the `stats` command of the docker cli tool the following formulas can be used: * used_memory = `memory_stats.usage - memory_stats.stats.cache` * available_memory = `memory_stats.limit` * Memory usage % = `(used_memory / available_memory) * 100.0` * cpu_delta = `cpu_stats.cpu_usage.total_usage - precpu_stats.cpu_usage.total_usage` * system_cpu_delta = `cpu_stats.system_cpu_usage - precpu_stats.system_cpu_usage` * number_cpus = `length(cpu_stats.cpu_usage.percpu_usage)` or `cpu_stats.online_cpus` * CPU usage % = `(cpu_delta / system_cpu_delta) * number_cpus * 100.0`
+    pub async fn stats(
+        &self,
+        params: ContainerStatsParams,
+    ) -> Result<
+        crate::api::stream::AsyncJsonStream<crate::api::stats::ContainerStatsResponse>,
+        Error<ContainerStatsError>,
+    > {
+        let uri_str = format!(
+            "{}/containers/{id}/stats", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.stream {
+            req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.one_shot {
+            req_builder = req_builder.query(&[("one-shot", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncJsonStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerStatsError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn stop(
+        &self,
+        params: ContainerStopParams,
+    ) -> Result<(), Error<ContainerStopError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/stop", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.signal {
+            req_builder = req_builder.query(&[("signal", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerStopError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// On Unix systems, this is done by running the `ps` command. This endpoint is not supported on Windows.
+    pub async fn top(
+        &self,
+        params: ContainerTopParams,
+    ) -> Result<models::ContainerTopResponse, Error<ContainerTopError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/top", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.ps_args {
+            req_builder = req_builder.query(&[("ps_args", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerTopError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resume a container which has been paused.
+    pub async fn unpause(
+        &self,
+        params: ContainerUnpauseParams,
+    ) -> Result<(), Error<ContainerUnpauseError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/unpause", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerUnpauseError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Change various configuration options of a container without having to recreate it.
+    pub async fn update(
+        &self,
+        params: ContainerUpdateParams,
+    ) -> Result<models::ContainerUpdateResponse, Error<ContainerUpdateError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/update", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.update);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerUpdateError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Block until a container stops, then returns the exit code.
+    pub async fn wait(
+        &self,
+        params: ContainerWaitParams,
+    ) -> Result<models::ContainerWaitResponse, Error<ContainerWaitError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/wait", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.condition {
+            req_builder = req_builder.query(&[("condition", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerWaitError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Upload a tar archive to be extracted to a path in the filesystem of container id. `path` parameter is asserted to be a directory. If it exists as a file, 400 error will be returned with message \"not a directory\".
+    pub async fn put_archive(
+        &self,
+        params: PutContainerArchiveParams,
+    ) -> Result<(), Error<PutContainerArchiveError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/archive", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::PUT, &uri_str);
+        req_builder = req_builder.query(&[("path", &params.path.to_string())]);
+        if let Some(ref param_value) = params.no_overwrite_dir_non_dir {
+            req_builder = req_builder
+                .query(&[("noOverwriteDirNonDir", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.copy_uidgid {
+            req_builder = req_builder.query(&[("copyUIDGID", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.input_stream);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PutContainerArchiveError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`distribution_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DistributionInspectError {
+    Status401(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct DistributionApi {
+    configuration: Arc<Configuration>,
+}
+impl DistributionApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Return image digest and platform information by contacting the registry.
+    pub async fn inspect(
+        &self,
+        params: DistributionInspectParams,
+    ) -> Result<models::DistributionInspect, Error<DistributionInspectError>> {
+        let uri_str = format!(
+            "{}/distribution/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<DistributionInspectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`container_exec`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContainerExecError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_resize`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecResizeError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`exec_start`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExecStartError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct ExecApi {
+    configuration: Arc<Configuration>,
+}
+impl ExecApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Run a command inside a running container.
+    pub async fn container_exec(
+        &self,
+        params: ContainerExecParams,
+    ) -> Result<models::IdResponse, Error<ContainerExecError>> {
+        let uri_str = format!(
+            "{}/containers/{id}/exec", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.exec_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ContainerExecError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return low-level information about an exec instance.
+    pub async fn inspect(
+        &self,
+        params: ExecInspectParams,
+    ) -> Result<models::ExecInspectResponse, Error<ExecInspectError>> {
+        let uri_str = format!(
+            "{}/exec/{id}/json", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ExecInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Resize the TTY session used by an exec instance. This endpoint only works if `tty` was specified as part of creating and starting the exec instance.
+    pub async fn resize(
+        &self,
+        params: ExecResizeParams,
+    ) -> Result<(), Error<ExecResizeError>> {
+        let uri_str = format!(
+            "{}/exec/{id}/resize", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("h", &params.h.to_string())]);
+        req_builder = req_builder.query(&[("w", &params.w.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ExecResizeError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Starts a previously set up exec instance. If detach is true, this endpoint returns immediately after starting the command. Otherwise, it sets up an interactive session with the command.
+    pub async fn start(
+        &self,
+        params: ExecStartParams,
+    ) -> Result<crate::api::stream::AsyncLogStream, Error<ExecStartError>> {
+        let uri_str = format!(
+            "{}/exec/{id}/start", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.exec_start_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncLogStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ExecStartError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`build_prune`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BuildPruneError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_build`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageBuildError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_commit`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageCommitError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageCreateError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageDeleteError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageGetError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_get_all`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageGetAllError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_history`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageHistoryError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageListError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_load`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageLoadError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_prune`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImagePruneError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_push`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImagePushError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_search`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageSearchError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`image_tag`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageTagError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub async fn build_prune(
+    configuration: &Configuration,
+    params: BuildPruneParams,
+) -> Result<models::BuildPruneResponse, Error<BuildPruneError>> {
+    let uri_str = format!("{}/build/prune", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+    if let Some(ref param_value) = params.keep_storage {
+        req_builder = req_builder.query(&[("keep-storage", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = params.all {
+        req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = params.filters {
+        req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder
+            .header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+    let status = resp.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        serde_json::from_str(&content).map_err(Error::from)
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<BuildPruneError> = serde_json::from_str(&content).ok();
+        Err(
+            Error::ResponseError(ResponseContent {
+                status,
+                content,
+                entity,
+            }),
+        )
+    }
+}
+pub struct ImageApi {
+    configuration: Arc<Configuration>,
+}
+impl ImageApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Build an image from a tar archive with a `Dockerfile` in it.  The `Dockerfile` specifies how the image is built from the tar archive. It is typically in the archive's root, but can be at a different path or have a different name by specifying the `dockerfile` parameter. [See the `Dockerfile` reference for more information](https://docs.docker.com/engine/reference/builder/).  The Docker daemon performs a preliminary validation of the `Dockerfile` before starting the build, and returns an error if the syntax is incorrect. After that, each instruction is run one-by-one until the ID of the new image is output.  The build is canceled if the client drops the connection by quitting or being killed.
+    pub async fn build(
+        &self,
+        params: ImageBuildParams,
+    ) -> Result<
+        crate::api::stream::AsyncJsonStream<models::BuildInfo>,
+        Error<ImageBuildError>,
+    > {
+        let uri_str = format!("{}/build", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.dockerfile {
+            req_builder = req_builder.query(&[("dockerfile", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.t {
+            req_builder = req_builder.query(&[("t", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.extrahosts {
+            req_builder = req_builder.query(&[("extrahosts", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.remote {
+            req_builder = req_builder.query(&[("remote", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.q {
+            req_builder = req_builder.query(&[("q", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.nocache {
+            req_builder = req_builder.query(&[("nocache", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cachefrom {
+            req_builder = req_builder.query(&[("cachefrom", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pull {
+            req_builder = req_builder.query(&[("pull", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.rm {
+            req_builder = req_builder.query(&[("rm", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.forcerm {
+            req_builder = req_builder.query(&[("forcerm", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.memory {
+            req_builder = req_builder.query(&[("memory", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.memswap {
+            req_builder = req_builder.query(&[("memswap", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpushares {
+            req_builder = req_builder.query(&[("cpushares", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpusetcpus {
+            req_builder = req_builder.query(&[("cpusetcpus", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpuperiod {
+            req_builder = req_builder.query(&[("cpuperiod", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.cpuquota {
+            req_builder = req_builder.query(&[("cpuquota", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.buildargs {
+            req_builder = req_builder.query(&[("buildargs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.shmsize {
+            req_builder = req_builder.query(&[("shmsize", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.squash {
+            req_builder = req_builder.query(&[("squash", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.labels {
+            req_builder = req_builder.query(&[("labels", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.networkmode {
+            req_builder = req_builder
+                .query(&[("networkmode", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.platform {
+            req_builder = req_builder.query(&[("platform", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.target {
+            req_builder = req_builder.query(&[("target", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.outputs {
+            req_builder = req_builder.query(&[("outputs", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.version {
+            req_builder = req_builder.query(&[("version", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.content_type {
+            req_builder = req_builder.header("Content-type", param_value.to_string());
+        }
+        if let Some(param_value) = params.x_registry_config {
+            req_builder = req_builder
+                .header("X-Registry-Config", param_value.to_string());
+        }
+        req_builder = req_builder.body(params.input_stream);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncJsonStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageBuildError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn commit(
+        &self,
+        params: ImageCommitParams,
+    ) -> Result<models::IdResponse, Error<ImageCommitError>> {
+        let uri_str = format!("{}/commit", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.container {
+            req_builder = req_builder.query(&[("container", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.comment {
+            req_builder = req_builder.query(&[("comment", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.author {
+            req_builder = req_builder.query(&[("author", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.pause {
+            req_builder = req_builder.query(&[("pause", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.changes {
+            req_builder = req_builder.query(&[("changes", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.container_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageCommitError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Pull or import an image.
+    pub async fn create(
+        &self,
+        params: ImageCreateParams,
+    ) -> Result<
+        crate::api::stream::AsyncJsonStream<models::CreateImageInfo>,
+        Error<ImageCreateError>,
+    > {
+        let uri_str = format!("{}/images/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.from_image {
+            req_builder = req_builder.query(&[("fromImage", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.from_src {
+            req_builder = req_builder.query(&[("fromSrc", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.message {
+            req_builder = req_builder.query(&[("message", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.changes {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("changes".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "changes",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref param_value) = params.platform {
+            req_builder = req_builder.query(&[("platform", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        req_builder = req_builder.json(&params.input_image);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncJsonStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Remove an image, along with any untagged parent images that were referenced by that image.  Images can't be removed if they have descendant images, are being used by a running container or are being used by a build.
+    pub async fn delete(
+        &self,
+        params: ImageDeleteParams,
+    ) -> Result<Vec<models::ImageDeleteResponseItem>, Error<ImageDeleteError>> {
+        let uri_str = format!(
+            "{}/images/{name}", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.noprune {
+            req_builder = req_builder.query(&[("noprune", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get a tarball containing all images and metadata for a repository.  If `name` is a specific name and tag (e.g. `ubuntu:latest`), then only that image (and its parents) are returned. If `name` is an image ID, similarly only that image (and its parents) are returned, but with the exclusion of the `repositories` file in the tarball, as there were no image names referenced.  ### Image tarball format  An image tarball contains one directory per image layer (named using its long ID), each containing these files:  - `VERSION`: currently `1.0` - the file format version - `json`: detailed layer information, similar to `docker inspect layer_id` - `layer.tar`: A tarfile containing the filesystem changes in this layer  The `layer.tar` file contains `aufs` style `.wh..wh.aufs` files and directories for storing attribute changes and deletions.  If the tarball defines a repository, the tarball should also include a `repositories` file at the root that contains a list of repository and tag names mapped to layer IDs.  ```json {   \"hello-world\": {     \"latest\": \"565a9d68a73f6706862bfe8409a7f659776d4d60a8d096eb4a3cbce6999cc2a1\"   } } ```
+    pub async fn get(
+        &self,
+        params: ImageGetParams,
+    ) -> Result<reqwest::Response, Error<ImageGetError>> {
+        let uri_str = format!(
+            "{}/images/{name}/get", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageGetError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get a tarball containing all images and metadata for several image repositories.  For each value of the `names` parameter: if it is a specific name and tag (e.g. `ubuntu:latest`), then only that image (and its parents) are returned; if it is an image ID, similarly only that image (and its parents) are returned and there would be no names referenced in the 'repositories' file for this image ID.  For details on the format, see the [export image endpoint](#operation/ImageGet).
+    pub async fn get_all(
+        &self,
+        params: ImageGetAllParams,
+    ) -> Result<reqwest::Response, Error<ImageGetAllError>> {
+        let uri_str = format!("{}/images/get", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.names {
+            req_builder = match "csv" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("names".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "names",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageGetAllError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return parent layers of an image.
+    pub async fn history(
+        &self,
+        params: ImageHistoryParams,
+    ) -> Result<Vec<models::HistoryResponseItem>, Error<ImageHistoryError>> {
+        let uri_str = format!(
+            "{}/images/{name}/history", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageHistoryError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Return low-level information about an image.
+    pub async fn inspect(
+        &self,
+        params: ImageInspectParams,
+    ) -> Result<models::ImageInspect, Error<ImageInspectError>> {
+        let uri_str = format!(
+            "{}/images/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of images on the server. Note that it uses a different, smaller representation of an image than inspecting a single image.
+    pub async fn list(
+        &self,
+        params: ImageListParams,
+    ) -> Result<Vec<models::ImageSummary>, Error<ImageListError>> {
+        let uri_str = format!("{}/images/json", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.all {
+            req_builder = req_builder.query(&[("all", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.shared_size {
+            req_builder = req_builder
+                .query(&[("shared-size", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.digests {
+            req_builder = req_builder.query(&[("digests", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.manifests {
+            req_builder = req_builder.query(&[("manifests", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Load a set of images and tags into a repository.  For details on the format, see the [export image endpoint](#operation/ImageGet).
+    pub async fn load(
+        &self,
+        params: ImageLoadParams,
+    ) -> Result<(), Error<ImageLoadError>> {
+        let uri_str = format!("{}/images/load", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.quiet {
+            req_builder = req_builder.query(&[("quiet", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.images_tarball);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageLoadError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn prune(
+        &self,
+        params: ImagePruneParams,
+    ) -> Result<models::ImagePruneResponse, Error<ImagePruneError>> {
+        let uri_str = format!("{}/images/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImagePruneError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Push an image to a registry.  If you wish to push an image on to a private registry, that image must already have a tag which references the registry. For example, `registry.example.com/myimage:latest`.  The push is cancelled if the HTTP connection is closed.
+    pub async fn push_stream(
+        &self,
+        params: ImagePushParams,
+    ) -> Result<
+        crate::api::stream::AsyncJsonStream<crate::api::push::PushMessage>,
+        Error<ImagePushError>,
+    > {
+        let uri_str = format!(
+            "{}/images/{name}/push", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.platform {
+            req_builder = req_builder.query(&[("platform", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder
+            .header("X-Registry-Auth", params.x_registry_auth.to_string());
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncJsonStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImagePushError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Search for an image on Docker Hub.
+    pub async fn search(
+        &self,
+        params: ImageSearchParams,
+    ) -> Result<Vec<models::ImageSearchResponseItem>, Error<ImageSearchError>> {
+        let uri_str = format!("{}/images/search", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        req_builder = req_builder.query(&[("term", &params.term.to_string())]);
+        if let Some(ref param_value) = params.limit {
+            req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageSearchError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Tag an image so that it becomes part of a repository.
+    pub async fn tag(&self, params: ImageTagParams) -> Result<(), Error<ImageTagError>> {
+        let uri_str = format!(
+            "{}/images/{name}/tag", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.repo {
+            req_builder = req_builder.query(&[("repo", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tag {
+            req_builder = req_builder.query(&[("tag", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ImageTagError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`network_connect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkConnectError {
+    Status400(models::ErrorResponse),
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkCreateError {
+    Status400(models::ErrorResponse),
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkDeleteError {
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_disconnect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkDisconnectError {
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkListError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`network_prune`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NetworkPruneError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct NetworkApi {
+    configuration: Arc<Configuration>,
+}
+impl NetworkApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// The network must be either a local-scoped network or a swarm-scoped network with the `attachable` option set. A network cannot be re-attached to a running container
+    pub async fn connect(
+        &self,
+        params: NetworkConnectParams,
+    ) -> Result<(), Error<NetworkConnectError>> {
+        let uri_str = format!(
+            "{}/networks/{id}/connect", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.container);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkConnectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn create(
+        &self,
+        params: NetworkCreateParams,
+    ) -> Result<models::NetworkCreateResponse, Error<NetworkCreateError>> {
+        let uri_str = format!("{}/networks/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.network_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: NetworkDeleteParams,
+    ) -> Result<(), Error<NetworkDeleteError>> {
+        let uri_str = format!(
+            "{}/networks/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn disconnect(
+        &self,
+        params: NetworkDisconnectParams,
+    ) -> Result<(), Error<NetworkDisconnectError>> {
+        let uri_str = format!(
+            "{}/networks/{id}/disconnect", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.container);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkDisconnectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: NetworkInspectParams,
+    ) -> Result<models::Network, Error<NetworkInspectError>> {
+        let uri_str = format!(
+            "{}/networks/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.verbose {
+            req_builder = req_builder.query(&[("verbose", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.scope {
+            req_builder = req_builder.query(&[("scope", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkInspectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns a list of networks. For details on the format, see the [network inspect endpoint](#operation/NetworkInspect).  Note that it uses a different, smaller representation of a network than inspecting a single network. For example, the list of containers attached to the network is not propagated in API versions 1.28 and up.
+    pub async fn list(
+        &self,
+        params: NetworkListParams,
+    ) -> Result<Vec<models::Network>, Error<NetworkListError>> {
+        let uri_str = format!("{}/networks", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn prune(
+        &self,
+        params: NetworkPruneParams,
+    ) -> Result<models::NetworkPruneResponse, Error<NetworkPruneError>> {
+        let uri_str = format!("{}/networks/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NetworkPruneError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`node_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NodeDeleteError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`node_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NodeInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`node_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NodeListError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`node_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NodeUpdateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct NodeApi {
+    configuration: Arc<Configuration>,
+}
+impl NodeApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn delete(
+        &self,
+        params: NodeDeleteParams,
+    ) -> Result<(), Error<NodeDeleteError>> {
+        let uri_str = format!(
+            "{}/nodes/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NodeDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: NodeInspectParams,
+    ) -> Result<models::Node, Error<NodeInspectError>> {
+        let uri_str = format!(
+            "{}/nodes/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NodeInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: NodeListParams,
+    ) -> Result<Vec<models::Node>, Error<NodeListError>> {
+        let uri_str = format!("{}/nodes", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NodeListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: NodeUpdateParams,
+    ) -> Result<(), Error<NodeUpdateError>> {
+        let uri_str = format!(
+            "{}/nodes/{id}/update", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<NodeUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`get_plugin_privileges`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetPluginPrivilegesError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginCreateError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginDeleteError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_disable`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginDisableError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_enable`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginEnableError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginListError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_pull`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginPullError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_push`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginPushError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_set`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginSetError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`plugin_upgrade`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PluginUpgradeError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct PluginApi {
+    configuration: Arc<Configuration>,
+}
+impl PluginApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn get_privileges(
+        &self,
+        params: GetPluginPrivilegesParams,
+    ) -> Result<Vec<models::PluginPrivilege>, Error<GetPluginPrivilegesError>> {
+        let uri_str = format!("{}/plugins/privileges", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        req_builder = req_builder.query(&[("remote", &params.remote.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<GetPluginPrivilegesError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn create(
+        &self,
+        params: PluginCreateParams,
+    ) -> Result<(), Error<PluginCreateError>> {
+        let uri_str = format!("{}/plugins/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("name", &params.name.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.body(params.tar_context);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: PluginDeleteParams,
+    ) -> Result<models::Plugin, Error<PluginDeleteError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn disable(
+        &self,
+        params: PluginDisableParams,
+    ) -> Result<(), Error<PluginDisableError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/disable", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginDisableError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn enable(
+        &self,
+        params: PluginEnableParams,
+    ) -> Result<(), Error<PluginEnableError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/enable", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.timeout {
+            req_builder = req_builder.query(&[("timeout", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginEnableError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: PluginInspectParams,
+    ) -> Result<models::Plugin, Error<PluginInspectError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/json", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns information about installed plugins.
+    pub async fn list(
+        &self,
+        params: PluginListParams,
+    ) -> Result<Vec<models::Plugin>, Error<PluginListError>> {
+        let uri_str = format!("{}/plugins", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Pulls and installs a plugin. After the plugin is installed, it can be enabled using the [`POST /plugins/{name}/enable` endpoint](#operation/PostPluginsEnable).
+    pub async fn pull(
+        &self,
+        params: PluginPullParams,
+    ) -> Result<(), Error<PluginPullError>> {
+        let uri_str = format!("{}/plugins/pull", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("remote", &params.remote.to_string())]);
+        if let Some(ref param_value) = params.name {
+            req_builder = req_builder.query(&[("name", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginPullError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Push a plugin to the registry.
+    pub async fn push(
+        &self,
+        params: PluginPushParams,
+    ) -> Result<(), Error<PluginPushError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/push", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginPushError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn set(
+        &self,
+        params: PluginSetParams,
+    ) -> Result<(), Error<PluginSetError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/set", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginSetError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn upgrade(
+        &self,
+        params: PluginUpgradeParams,
+    ) -> Result<(), Error<PluginUpgradeError>> {
+        let uri_str = format!(
+            "{}/plugins/{name}/upgrade", self.configuration.base_path, name =
+            urlencode(params.name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("remote", &params.remote.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<PluginUpgradeError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`secret_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretCreateError {
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretDeleteError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretListError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`secret_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretUpdateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct SecretApi {
+    configuration: Arc<Configuration>,
+}
+impl SecretApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn create(
+        &self,
+        params: SecretCreateParams,
+    ) -> Result<models::IdResponse, Error<SecretCreateError>> {
+        let uri_str = format!("{}/secrets/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SecretCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: SecretDeleteParams,
+    ) -> Result<(), Error<SecretDeleteError>> {
+        let uri_str = format!(
+            "{}/secrets/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SecretDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: SecretInspectParams,
+    ) -> Result<models::Secret, Error<SecretInspectError>> {
+        let uri_str = format!(
+            "{}/secrets/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SecretInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: SecretListParams,
+    ) -> Result<Vec<models::Secret>, Error<SecretListError>> {
+        let uri_str = format!("{}/secrets", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SecretListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: SecretUpdateParams,
+    ) -> Result<(), Error<SecretUpdateError>> {
+        let uri_str = format!(
+            "{}/secrets/{id}/update", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SecretUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`service_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceCreateError {
+    Status400(models::ErrorResponse),
+    Status403(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`service_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceDeleteError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`service_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`service_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceListError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`service_logs`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceLogsError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`service_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServiceUpdateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct ServiceApi {
+    configuration: Arc<Configuration>,
+}
+impl ServiceApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn create(
+        &self,
+        params: ServiceCreateParams,
+    ) -> Result<models::ServiceCreateResponse, Error<ServiceCreateError>> {
+        let uri_str = format!("{}/services/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn delete(
+        &self,
+        params: ServiceDeleteParams,
+    ) -> Result<(), Error<ServiceDeleteError>> {
+        let uri_str = format!(
+            "{}/services/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: ServiceInspectParams,
+    ) -> Result<models::Service, Error<ServiceInspectError>> {
+        let uri_str = format!(
+            "{}/services/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.insert_defaults {
+            req_builder = req_builder
+                .query(&[("insertDefaults", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceInspectError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: ServiceListParams,
+    ) -> Result<Vec<models::Service>, Error<ServiceListError>> {
+        let uri_str = format!("{}/services", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.status {
+            req_builder = req_builder.query(&[("status", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get `stdout` and `stderr` logs from a service. See also [`/containers/{id}/logs`](#operation/ContainerLogs).  **Note**: This endpoint works only for services with the `local`, `json-file` or `journald` logging drivers.
+    pub async fn logs(
+        &self,
+        params: ServiceLogsParams,
+    ) -> Result<reqwest::Response, Error<ServiceLogsError>> {
+        let uri_str = format!(
+            "{}/services/{id}/logs", self.configuration.base_path, id = urlencode(params
+            .id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.details {
+            req_builder = req_builder.query(&[("details", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.follow {
+            req_builder = req_builder.query(&[("follow", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.timestamps {
+            req_builder = req_builder.query(&[("timestamps", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tail {
+            req_builder = req_builder.query(&[("tail", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceLogsError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: ServiceUpdateParams,
+    ) -> Result<models::ServiceUpdateResponse, Error<ServiceUpdateError>> {
+        let uri_str = format!(
+            "{}/services/{id}/update", self.configuration.base_path, id =
+            urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref param_value) = params.registry_auth_from {
+            req_builder = req_builder
+                .query(&[("registryAuthFrom", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.rollback {
+            req_builder = req_builder.query(&[("rollback", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        if let Some(param_value) = params.x_registry_auth {
+            req_builder = req_builder.header("X-Registry-Auth", param_value.to_string());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<ServiceUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`session`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SessionError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct SessionApi {
+    configuration: Arc<Configuration>,
+}
+impl SessionApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Start a new interactive session with a server. Session allows server to call back to the client for advanced capabilities.  ### Hijacking  This endpoint hijacks the HTTP connection to HTTP2 transport that allows the client to expose gPRC services on that connection.  For example, the client sends this request to upgrade the connection:  ``` POST /session HTTP/1.1 Upgrade: h2c Connection: Upgrade ```  The Docker daemon responds with a `101 UPGRADED` response follow with the raw stream:  ``` HTTP/1.1 101 UPGRADED Connection: Upgrade Upgrade: h2c ```
+    pub async fn session(&self) -> Result<(), Error<SessionError>> {
+        let uri_str = format!("{}/session", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SessionError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`swarm_init`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmInitError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_join`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmJoinError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_leave`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmLeaveError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_unlock`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmUnlockError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_unlockkey`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmUnlockkeyError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`swarm_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SwarmUpdateError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct SwarmApi {
+    configuration: Arc<Configuration>,
+}
+impl SwarmApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn init(
+        &self,
+        params: SwarmInitParams,
+    ) -> Result<String, Error<SwarmInitError>> {
+        let uri_str = format!("{}/swarm/init", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmInitError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(&self) -> Result<models::Swarm, Error<SwarmInspectError>> {
+        let uri_str = format!("{}/swarm", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn join(
+        &self,
+        params: SwarmJoinParams,
+    ) -> Result<(), Error<SwarmJoinError>> {
+        let uri_str = format!("{}/swarm/join", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmJoinError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn leave(
+        &self,
+        params: SwarmLeaveParams,
+    ) -> Result<(), Error<SwarmLeaveError>> {
+        let uri_str = format!("{}/swarm/leave", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmLeaveError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn unlock(
+        &self,
+        params: SwarmUnlockParams,
+    ) -> Result<(), Error<SwarmUnlockError>> {
+        let uri_str = format!("{}/swarm/unlock", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmUnlockError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn unlockkey(
+        &self,
+    ) -> Result<models::UnlockKeyResponse, Error<SwarmUnlockkeyError>> {
+        let uri_str = format!("{}/swarm/unlockkey", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmUnlockkeyError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: SwarmUpdateParams,
+    ) -> Result<(), Error<SwarmUpdateError>> {
+        let uri_str = format!("{}/swarm/update", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref param_value) = params.rotate_worker_token {
+            req_builder = req_builder
+                .query(&[("rotateWorkerToken", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.rotate_manager_token {
+            req_builder = req_builder
+                .query(&[("rotateManagerToken", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.rotate_manager_unlock_key {
+            req_builder = req_builder
+                .query(&[("rotateManagerUnlockKey", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SwarmUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`system_auth`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemAuthError {
+    Status401(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_data_usage`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemDataUsageError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_events`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemEventsError {
+    Status400(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_info`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemInfoError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_ping`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPingError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_ping_head`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPingHeadError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`system_version`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemVersionError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct SystemApi {
+    configuration: Arc<Configuration>,
+}
+impl SystemApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    /// Validate credentials for a registry and, if available, get an identity token for accessing the registry without password.
+    pub async fn auth(
+        &self,
+        params: SystemAuthParams,
+    ) -> Result<models::SystemAuthResponse, Error<SystemAuthError>> {
+        let uri_str = format!("{}/auth", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.auth_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemAuthError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn data_usage(
+        &self,
+        params: SystemDataUsageParams,
+    ) -> Result<models::SystemDataUsageResponse, Error<SystemDataUsageError>> {
+        let uri_str = format!("{}/system/df", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.r#type {
+            req_builder = match "multi" {
+                "multi" => {
+                    req_builder
+                        .query(
+                            &param_value
+                                .into_iter()
+                                .map(|p| ("type".to_owned(), p.to_string()))
+                                .collect::<
+                                    Vec<(std::string::String, std::string::String)>,
+                                >(),
+                        )
+                }
+                _ => {
+                    req_builder
+                        .query(
+                            &[
+                                (
+                                    "type",
+                                    &param_value
+                                        .into_iter()
+                                        .map(|p| p.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                        .to_string(),
+                                ),
+                            ],
+                        )
+                }
+            };
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemDataUsageError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Stream real-time events from the server.  Various objects within Docker report events when something happens to them.  Containers report these events: `attach`, `commit`, `copy`, `create`, `destroy`, `detach`, `die`, `exec_create`, `exec_detach`, `exec_start`, `exec_die`, `export`, `health_status`, `kill`, `oom`, `pause`, `rename`, `resize`, `restart`, `start`, `stop`, `top`, `unpause`, `update`, and `prune`  Images report these events: `create`, `delete`, `import`, `load`, `pull`, `push`, `save`, `tag`, `untag`, and `prune`  Volumes report these events: `create`, `mount`, `unmount`, `destroy`, and `prune`  Networks report these events: `create`, `connect`, `disconnect`, `destroy`, `update`, `remove`, and `prune`  The Docker daemon reports these events: `reload`  Services report these events: `create`, `update`, and `remove`  Nodes report these events: `create`, `update`, and `remove`  Secrets report these events: `create`, `update`, and `remove`  Configs report these events: `create`, `update`, and `remove`  The Builder reports `prune` events
+    pub async fn events(
+        &self,
+        params: SystemEventsParams,
+    ) -> Result<
+        crate::api::stream::AsyncJsonStream<models::EventMessage>,
+        Error<SystemEventsError>,
+    > {
+        let uri_str = format!("{}/events", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.until {
+            req_builder = req_builder.query(&[("until", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(crate::api::stream::AsyncJsonStream::new(resp))
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemEventsError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn info(&self) -> Result<models::SystemInfo, Error<SystemInfoError>> {
+        let uri_str = format!("{}/info", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemInfoError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// This is a dummy endpoint you can use to test if the server is accessible.
+    pub async fn ping(&self) -> Result<String, Error<SystemPingError>> {
+        let uri_str = format!("{}/_ping", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemPingError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// This is a dummy endpoint you can use to test if the server is accessible.
+    pub async fn ping_head(&self) -> Result<String, Error<SystemPingHeadError>> {
+        let uri_str = format!("{}/_ping", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::HEAD, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemPingHeadError> = serde_json::from_str(&content)
+                .ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Returns the version of Docker that is running and various information about the system that Docker is running on.
+    pub async fn version(
+        &self,
+    ) -> Result<models::SystemVersion, Error<SystemVersionError>> {
+        let uri_str = format!("{}/version", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<SystemVersionError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`task_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TaskInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`task_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TaskListError {
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`task_logs`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TaskLogsError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct TaskApi {
+    configuration: Arc<Configuration>,
+}
+impl TaskApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn inspect(
+        &self,
+        params: TaskInspectParams,
+    ) -> Result<models::Task, Error<TaskInspectError>> {
+        let uri_str = format!(
+            "{}/tasks/{id}", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<TaskInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: TaskListParams,
+    ) -> Result<Vec<models::Task>, Error<TaskListError>> {
+        let uri_str = format!("{}/tasks", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<TaskListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Get `stdout` and `stderr` logs from a task. See also [`/containers/{id}/logs`](#operation/ContainerLogs).  **Note**: This endpoint works only for services with the `local`, `json-file` or `journald` logging drivers.
+    pub async fn logs(
+        &self,
+        params: TaskLogsParams,
+    ) -> Result<reqwest::Response, Error<TaskLogsError>> {
+        let uri_str = format!(
+            "{}/tasks/{id}/logs", self.configuration.base_path, id = urlencode(params.id)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.details {
+            req_builder = req_builder.query(&[("details", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.follow {
+            req_builder = req_builder.query(&[("follow", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stdout {
+            req_builder = req_builder.query(&[("stdout", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.stderr {
+            req_builder = req_builder.query(&[("stderr", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.since {
+            req_builder = req_builder.query(&[("since", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.timestamps {
+            req_builder = req_builder.query(&[("timestamps", &param_value.to_string())]);
+        }
+        if let Some(ref param_value) = params.tail {
+            req_builder = req_builder.query(&[("tail", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(resp)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<TaskLogsError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+/// struct for typed errors of method [`volume_create`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeCreateError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeDeleteError {
+    Status404(models::ErrorResponse),
+    Status409(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_inspect`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeInspectError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_list`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeListError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_prune`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumePruneError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+/// struct for typed errors of method [`volume_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VolumeUpdateError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    Status503(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+pub struct VolumeApi {
+    configuration: Arc<Configuration>,
+}
+impl VolumeApi {
+    pub fn new(configuration: Arc<Configuration>) -> Self {
+        Self { configuration }
+    }
+    pub async fn create(
+        &self,
+        params: VolumeCreateParams,
+    ) -> Result<models::Volume, Error<VolumeCreateError>> {
+        let uri_str = format!("{}/volumes/create", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.volume_config);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumeCreateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    /// Instruct the driver to remove the volume.
+    pub async fn delete(
+        &self,
+        params: VolumeDeleteParams,
+    ) -> Result<(), Error<VolumeDeleteError>> {
+        let uri_str = format!(
+            "{}/volumes/{name}", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::DELETE, &uri_str);
+        if let Some(ref param_value) = params.force {
+            req_builder = req_builder.query(&[("force", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumeDeleteError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn inspect(
+        &self,
+        params: VolumeInspectParams,
+    ) -> Result<models::Volume, Error<VolumeInspectError>> {
+        let uri_str = format!(
+            "{}/volumes/{name}", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumeInspectError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn list(
+        &self,
+        params: VolumeListParams,
+    ) -> Result<models::VolumeListResponse, Error<VolumeListError>> {
+        let uri_str = format!("{}/volumes", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::GET, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumeListError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn prune(
+        &self,
+        params: VolumePruneParams,
+    ) -> Result<models::VolumePruneResponse, Error<VolumePruneError>> {
+        let uri_str = format!("{}/volumes/prune", self.configuration.base_path);
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::POST, &uri_str);
+        if let Some(ref param_value) = params.filters {
+            req_builder = req_builder.query(&[("filters", &param_value.to_string())]);
+        }
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            let content = resp.text().await?;
+            serde_json::from_str(&content).map_err(Error::from)
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumePruneError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+    pub async fn update(
+        &self,
+        params: VolumeUpdateParams,
+    ) -> Result<(), Error<VolumeUpdateError>> {
+        let uri_str = format!(
+            "{}/volumes/{name}", self.configuration.base_path, name = urlencode(params
+            .name)
+        );
+        let mut req_builder = self
+            .configuration
+            .client
+            .request(reqwest::Method::PUT, &uri_str);
+        req_builder = req_builder.query(&[("version", &params.version.to_string())]);
+        if let Some(ref user_agent) = self.configuration.user_agent {
+            req_builder = req_builder
+                .header(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        req_builder = req_builder.json(&params.body);
+        let req = req_builder.build()?;
+        let resp = self.configuration.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            Ok(())
+        } else {
+            let content = resp.text().await?;
+            let entity: Option<VolumeUpdateError> = serde_json::from_str(&content).ok();
+            Err(
+                Error::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity,
+                }),
+            )
+        }
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ResponseContent<T> {
+    pub status: reqwest::StatusCode,
+    pub content: String,
+    pub entity: Option<T>,
+}
+#[derive(Debug)]
+pub enum Error<T> {
+    Reqwest(reqwest::Error),
+    Serde(serde_json::Error),
+    Io(std::io::Error),
+    ResponseError(ResponseContent<T>),
+}
+impl<T> fmt::Display for Error<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (module, e) = match self {
+            Error::Reqwest(e) => ("reqwest", e.to_string()),
+            Error::Serde(e) => ("serde", e.to_string()),
+            Error::Io(e) => ("IO", e.to_string()),
+            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+        };
+        write!(f, "error in {}: {}", module, e)
+    }
+}
+impl<T: fmt::Debug> error::Error for Error<T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(
+            match self {
+                Error::Reqwest(e) => e,
+                Error::Serde(e) => e,
+                Error::Io(e) => e,
+                Error::ResponseError(_) => return None,
+            },
+        )
+    }
+}
+impl<T> From<reqwest::Error> for Error<T> {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Reqwest(e)
+    }
+}
+impl<T> From<serde_json::Error> for Error<T> {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serde(e)
+    }
+}
+impl<T> From<std::io::Error> for Error<T> {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+pub fn urlencode<T: AsRef<str>>(s: T) -> String {
+    ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
+}
+pub fn parse_deep_object(
+    prefix: &str,
+    value: &serde_json::Value,
+) -> Vec<(String, String)> {
+    if let serde_json::Value::Object(object) = value {
+        let mut params = vec![];
+        for (key, value) in object {
+            match value {
+                serde_json::Value::Object(_) => {
+                    params
+                        .append(
+                            &mut parse_deep_object(
+                                &format!("{}[{}]", prefix, key),
+                                value,
+                            ),
+                        )
+                }
+                serde_json::Value::Array(array) => {
+                    for (i, value) in array.iter().enumerate() {
+                        params
+                            .append(
+                                &mut parse_deep_object(
+                                    &format!("{}[{}][{}]", prefix, key, i),
+                                    value,
+                                ),
+                            );
+                    }
+                }
+                serde_json::Value::String(s) => {
+                    params.push((format!("{}[{}]", prefix, key), s.clone()))
+                }
+                _ => params.push((format!("{}[{}]", prefix, key), value.to_string())),
+            }
+        }
+        return params;
+    }
+    unimplemented!("Only objects are supported with style=deepObject")
+}
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: AsyncTransport,
+}
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+}
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "http://localhost/v1.47".to_owned(),
+            user_agent: Some("container-api-async-0.1.0".to_owned()),
+            client: AsyncTransport::default(),
+        }
+    }
+}
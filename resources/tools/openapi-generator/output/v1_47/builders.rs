@@ -0,0 +1,7979 @@
+use crate::models::*;
+use crate::params::*;
+use crate::filters::*;
+use crate::api::reference::ImageReference;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::path::PathBuf;
+/// Builder of [`AuthConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct AuthConfigBuilder {
+    username: Option<String>,
+    password: Option<String>,
+    email: Option<String>,
+    serveraddress: Option<String>,
+}
+impl AuthConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn username(&mut self, value: impl Into<String>) -> &mut Self {
+        self.username = Some(value.into());
+        self
+    }
+    pub fn password(&mut self, value: impl Into<String>) -> &mut Self {
+        self.password = Some(value.into());
+        self
+    }
+    pub fn email(&mut self, value: impl Into<String>) -> &mut Self {
+        self.email = Some(value.into());
+        self
+    }
+    pub fn serveraddress(&mut self, value: impl Into<String>) -> &mut Self {
+        self.serveraddress = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> AuthConfig {
+        AuthConfig {
+            username: self.username.clone(),
+            password: self.password.clone(),
+            email: self.email.clone(),
+            serveraddress: self.serveraddress.clone(),
+        }
+    }
+}
+/// Builder of [`BuildPruneParams`].
+#[derive(Clone, Debug, Default)]
+pub struct BuildPruneParamsBuilder {
+    keep_storage: Option<i64>,
+    all: Option<bool>,
+    filters: Option<String>,
+}
+impl BuildPruneParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Amount of disk space in bytes to keep for cache
+    pub fn keep_storage(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.keep_storage = Some(value.into());
+        self
+    }
+    /// Remove all types of build cache
+    pub fn all(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.all = Some(value.into());
+        self
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the list of build cache objects.  Available filters:  - `until=<timestamp>` remove cache older than `<timestamp>`. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon's local time. - `id=<id>` - `parent=<id>` - `type=<string>` - `description=<string>` - `inuse` - `shared` - `private`
+    pub fn filters(&mut self, value: BuildPruneFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> BuildPruneParams {
+        BuildPruneParams {
+            keep_storage: self.keep_storage.clone(),
+            all: self.all.clone(),
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ClusterVolumeSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct ClusterVolumeSpecBuilder {
+    group: Option<String>,
+    access_mode: Option<Box<ClusterVolumeSpecAccessMode>>,
+}
+impl ClusterVolumeSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Group defines the volume group of this volume. Volumes belonging to the same group can be referred to by group name when creating Services.  Referring to a volume by group instructs Swarm to treat volumes in that group interchangeably for the purpose of scheduling. Volumes with an empty string for a group technically all belong to the same, emptystring group.
+    pub fn group(&mut self, value: impl Into<String>) -> &mut Self {
+        self.group = Some(value.into());
+        self
+    }
+    pub fn access_mode(
+        &mut self,
+        value: impl Into<Box<ClusterVolumeSpecAccessMode>>,
+    ) -> &mut Self {
+        self.access_mode = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ClusterVolumeSpec {
+        ClusterVolumeSpec {
+            group: self.group.clone(),
+            access_mode: self.access_mode.clone(),
+        }
+    }
+}
+/// Builder of [`ClusterVolumeSpecAccessMode`].
+#[derive(Clone, Debug, Default)]
+pub struct ClusterVolumeSpecAccessModeBuilder {
+    scope: Option<ClusterVolumeSpecAccessModeScope>,
+    sharing: Option<ClusterVolumeSpecAccessModeSharing>,
+    mount_volume: Option<serde_json::Value>,
+    secrets: Option<Vec<ClusterVolumeSpecAccessModeSecretsInner>>,
+    accessibility_requirements: Option<
+        Box<ClusterVolumeSpecAccessModeAccessibilityRequirements>,
+    >,
+    capacity_range: Option<Box<ClusterVolumeSpecAccessModeCapacityRange>>,
+    availability: Option<ClusterVolumeSpecAccessModeAvailability>,
+}
+impl ClusterVolumeSpecAccessModeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The set of nodes this volume can be used on at one time. - `single` The volume may only be scheduled to one node at a time. - `multi` the volume may be scheduled to any supported number of nodes at a time.
+    pub fn scope(
+        &mut self,
+        value: impl Into<ClusterVolumeSpecAccessModeScope>,
+    ) -> &mut Self {
+        self.scope = Some(value.into());
+        self
+    }
+    /// The number and way that different tasks can use this volume at one time. - `none` The volume may only be used by one task at a time. - `readonly` The volume may be used by any number of tasks, but they all must mount the volume as readonly - `onewriter` The volume may be used by any number of tasks, but only one may mount it as read/write. - `all` The volume may have any number of readers and writers.
+    pub fn sharing(
+        &mut self,
+        value: impl Into<ClusterVolumeSpecAccessModeSharing>,
+    ) -> &mut Self {
+        self.sharing = Some(value.into());
+        self
+    }
+    /// Options for using this volume as a Mount-type volume.      Either MountVolume or BlockVolume, but not both, must be     present.   properties:     FsType:       type: \"string\"       description: |         Specifies the filesystem type for the mount volume.         Optional.     MountFlags:       type: \"array\"       description: |         Flags to pass when mounting the volume. Optional.       items:         type: \"string\" BlockVolume:   type: \"object\"   description: |     Options for using this volume as a Block-type volume.     Intentionally empty.
+    pub fn mount_volume(&mut self, value: String) -> &mut Self {
+        self.mount_volume = Some(value.into());
+        self
+    }
+    /// Swarm Secrets that are passed to the CSI storage plugin when operating on this volume.
+    pub fn secrets(
+        &mut self,
+        value: impl Into<Vec<ClusterVolumeSpecAccessModeSecretsInner>>,
+    ) -> &mut Self {
+        self.secrets = Some(value.into());
+        self
+    }
+    pub fn accessibility_requirements(
+        &mut self,
+        value: impl Into<Box<ClusterVolumeSpecAccessModeAccessibilityRequirements>>,
+    ) -> &mut Self {
+        self.accessibility_requirements = Some(value.into());
+        self
+    }
+    pub fn capacity_range(
+        &mut self,
+        value: impl Into<Box<ClusterVolumeSpecAccessModeCapacityRange>>,
+    ) -> &mut Self {
+        self.capacity_range = Some(value.into());
+        self
+    }
+    /// The availability of the volume for use in tasks. - `active` The volume is fully available for scheduling on the cluster - `pause` No new workloads should use the volume, but existing workloads are not stopped. - `drain` All workloads using this volume should be stopped and rescheduled, and no new ones should be started.
+    pub fn availability(
+        &mut self,
+        value: impl Into<ClusterVolumeSpecAccessModeAvailability>,
+    ) -> &mut Self {
+        self.availability = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ClusterVolumeSpecAccessMode {
+        ClusterVolumeSpecAccessMode {
+            scope: self.scope.clone(),
+            sharing: self.sharing.clone(),
+            mount_volume: self.mount_volume.clone(),
+            secrets: self.secrets.clone(),
+            accessibility_requirements: self.accessibility_requirements.clone(),
+            capacity_range: self.capacity_range.clone(),
+            availability: self.availability.clone(),
+        }
+    }
+}
+/// Builder of [`ClusterVolumeSpecAccessModeAccessibilityRequirements`].
+#[derive(Clone, Debug, Default)]
+pub struct ClusterVolumeSpecAccessModeAccessibilityRequirementsBuilder {
+    requisite: Option<Vec<std::collections::HashMap<String, String>>>,
+    preferred: Option<Vec<std::collections::HashMap<String, String>>>,
+}
+impl ClusterVolumeSpecAccessModeAccessibilityRequirementsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A list of required topologies, at least one of which the volume must be accessible from.
+    pub fn requisite(
+        &mut self,
+        value: impl Into<Vec<std::collections::HashMap<String, String>>>,
+    ) -> &mut Self {
+        self.requisite = Some(value.into());
+        self
+    }
+    /// A list of topologies that the volume should attempt to be provisioned in.
+    pub fn preferred(
+        &mut self,
+        value: impl Into<Vec<std::collections::HashMap<String, String>>>,
+    ) -> &mut Self {
+        self.preferred = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ClusterVolumeSpecAccessModeAccessibilityRequirements {
+        ClusterVolumeSpecAccessModeAccessibilityRequirements {
+            requisite: self.requisite.clone(),
+            preferred: self.preferred.clone(),
+        }
+    }
+}
+/// Builder of [`ClusterVolumeSpecAccessModeCapacityRange`].
+#[derive(Clone, Debug, Default)]
+pub struct ClusterVolumeSpecAccessModeCapacityRangeBuilder {
+    required_bytes: Option<i64>,
+    limit_bytes: Option<i64>,
+}
+impl ClusterVolumeSpecAccessModeCapacityRangeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The volume must be at least this big. The value of 0 indicates an unspecified minimum
+    pub fn required_bytes(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.required_bytes = Some(value.into());
+        self
+    }
+    /// The volume must not be bigger than this. The value of 0 indicates an unspecified maximum.
+    pub fn limit_bytes(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.limit_bytes = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ClusterVolumeSpecAccessModeCapacityRange {
+        ClusterVolumeSpecAccessModeCapacityRange {
+            required_bytes: self.required_bytes.clone(),
+            limit_bytes: self.limit_bytes.clone(),
+        }
+    }
+}
+/// Builder of [`ClusterVolumeSpecAccessModeSecretsInner`].
+#[derive(Clone, Debug, Default)]
+pub struct ClusterVolumeSpecAccessModeSecretsInnerBuilder {
+    key: Option<String>,
+    secret: Option<String>,
+}
+impl ClusterVolumeSpecAccessModeSecretsInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Key is the name of the key of the key-value pair passed to the plugin.
+    pub fn key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.key = Some(value.into());
+        self
+    }
+    /// Secret is the swarm Secret object from which to read data. This can be a Secret name or ID. The Secret data is retrieved by swarm and used as the value of the key-value pair passed to the plugin.
+    pub fn secret(&mut self, value: impl Into<String>) -> &mut Self {
+        self.secret = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ClusterVolumeSpecAccessModeSecretsInner {
+        ClusterVolumeSpecAccessModeSecretsInner {
+            key: self.key.clone(),
+            secret: self.secret.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigCreateParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigCreateParamsBuilder {
+    body: Option<ConfigCreateRequest>,
+}
+impl ConfigCreateParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn body(&mut self, value: impl Into<ConfigCreateRequest>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigCreateParams {
+        ConfigCreateParams {
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigCreateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigCreateRequestBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    data: Option<String>,
+    templating: Option<Box<Driver>>,
+}
+impl ConfigCreateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// User-defined name of the config.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Base64-url-safe-encoded ([RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)) config data.
+    pub fn data(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data = Some(value.into());
+        self
+    }
+    pub fn templating(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.templating = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigCreateRequest {
+        ConfigCreateRequest {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            data: self.data.clone(),
+            templating: self.templating.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct ConfigDeleteParamsBuilder {
+    id: String,
+}
+impl ConfigDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ConfigDeleteParams {
+        ConfigDeleteParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigInspectParams`].
+#[derive(Clone, Debug)]
+pub struct ConfigInspectParamsBuilder {
+    id: String,
+}
+impl ConfigInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ConfigInspectParams {
+        ConfigInspectParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigListParamsBuilder {
+    filters: Option<String>,
+}
+impl ConfigListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the configs list.  Available filters:  - `id=<config id>` - `label=<key> or label=<key>=value` - `name=<config name>` - `names=<config name>`
+    pub fn filters(&mut self, value: ConfigListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigListParams {
+        ConfigListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigReference`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigReferenceBuilder {
+    network: Option<String>,
+}
+impl ConfigReferenceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The name of the config-only network that provides the network's configuration. The specified network must be an existing config-only network. Only network names are allowed, not network IDs.
+    pub fn network(&mut self, value: impl Into<String>) -> &mut Self {
+        self.network = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigReference {
+        ConfigReference {
+            network: self.network.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigSpecBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    data: Option<String>,
+    templating: Option<Box<Driver>>,
+}
+impl ConfigSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// User-defined name of the config.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Base64-url-safe-encoded ([RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)) config data.
+    pub fn data(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data = Some(value.into());
+        self
+    }
+    pub fn templating(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.templating = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigSpec {
+        ConfigSpec {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            data: self.data.clone(),
+            templating: self.templating.clone(),
+        }
+    }
+}
+/// Builder of [`ConfigUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct ConfigUpdateParamsBuilder {
+    id: String,
+    version: i64,
+    body: Option<ConfigSpec>,
+}
+impl ConfigUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, version: impl Into<i64>) -> Self {
+        Self {
+            id: id.into(),
+            version: version.into(),
+            body: None,
+        }
+    }
+    /// The spec of the config to update. Currently, only the Labels field can be updated. All other fields must remain unchanged from the [ConfigInspect endpoint](#operation/ConfigInspect) response values.
+    pub fn body(&mut self, value: impl Into<ConfigSpec>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ConfigUpdateParams {
+        ConfigUpdateParams {
+            id: self.id.clone(),
+            version: self.version.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerArchiveInfoParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerArchiveInfoParamsBuilder {
+    id: String,
+    path: String,
+}
+impl ContainerArchiveInfoParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            path: path.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerArchiveInfoParams {
+        ContainerArchiveInfoParams {
+            id: self.id.clone(),
+            path: self.path.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerArchiveParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerArchiveParamsBuilder {
+    id: String,
+    path: String,
+}
+impl ContainerArchiveParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            path: path.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerArchiveParams {
+        ContainerArchiveParams {
+            id: self.id.clone(),
+            path: self.path.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerAttachParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerAttachParamsBuilder {
+    id: String,
+    detach_keys: Option<String>,
+    logs: Option<bool>,
+    stream: Option<bool>,
+    stdin: Option<bool>,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+}
+impl ContainerAttachParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            detach_keys: None,
+            logs: None,
+            stream: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        }
+    }
+    /// Override the key sequence for detaching a container.Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    pub fn detach_keys(&mut self, value: impl Into<String>) -> &mut Self {
+        self.detach_keys = Some(value.into());
+        self
+    }
+    /// Replay previous logs from the container.  This is useful for attaching to a container that has started and you want to output everything since the container started.  If `stream` is also enabled, once all the previous output has been returned, it will seamlessly transition into streaming current output.
+    pub fn logs(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.logs = Some(value.into());
+        self
+    }
+    /// Stream attached streams from the time the request was made onwards.
+    pub fn stream(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stream = Some(value.into());
+        self
+    }
+    /// Attach to `stdin`
+    pub fn stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdin = Some(value.into());
+        self
+    }
+    /// Attach to `stdout`
+    pub fn stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdout = Some(value.into());
+        self
+    }
+    /// Attach to `stderr`
+    pub fn stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stderr = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerAttachParams {
+        ContainerAttachParams {
+            id: self.id.clone(),
+            detach_keys: self.detach_keys.clone(),
+            logs: self.logs.clone(),
+            stream: self.stream.clone(),
+            stdin: self.stdin.clone(),
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerAttachWebsocketParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerAttachWebsocketParamsBuilder {
+    id: String,
+    detach_keys: Option<String>,
+    logs: Option<bool>,
+    stream: Option<bool>,
+    stdin: Option<bool>,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+}
+impl ContainerAttachWebsocketParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            detach_keys: None,
+            logs: None,
+            stream: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        }
+    }
+    /// Override the key sequence for detaching a container.Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,`, or `_`.
+    pub fn detach_keys(&mut self, value: impl Into<String>) -> &mut Self {
+        self.detach_keys = Some(value.into());
+        self
+    }
+    /// Return logs
+    pub fn logs(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.logs = Some(value.into());
+        self
+    }
+    /// Return stream
+    pub fn stream(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stream = Some(value.into());
+        self
+    }
+    /// Attach to `stdin`
+    pub fn stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdin = Some(value.into());
+        self
+    }
+    /// Attach to `stdout`
+    pub fn stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdout = Some(value.into());
+        self
+    }
+    /// Attach to `stderr`
+    pub fn stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stderr = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerAttachWebsocketParams {
+        ContainerAttachWebsocketParams {
+            id: self.id.clone(),
+            detach_keys: self.detach_keys.clone(),
+            logs: self.logs.clone(),
+            stream: self.stream.clone(),
+            stdin: self.stdin.clone(),
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerChangesParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerChangesParamsBuilder {
+    id: String,
+}
+impl ContainerChangesParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ContainerChangesParams {
+        ContainerChangesParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct ContainerConfigBuilder {
+    hostname: Option<String>,
+    domainname: Option<String>,
+    user: Option<String>,
+    attach_stdin: Option<bool>,
+    attach_stdout: Option<bool>,
+    attach_stderr: Option<bool>,
+    exposed_ports: Option<Option<std::collections::HashMap<String, serde_json::Value>>>,
+    tty: Option<bool>,
+    open_stdin: Option<bool>,
+    stdin_once: Option<bool>,
+    env: Option<Vec<String>>,
+    cmd: Option<Vec<String>>,
+    healthcheck: Option<Box<HealthConfig>>,
+    args_escaped: Option<Option<bool>>,
+    image: Option<String>,
+    volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    working_dir: Option<String>,
+    entrypoint: Option<Vec<String>>,
+    network_disabled: Option<Option<bool>>,
+    mac_address: Option<Option<String>>,
+    on_build: Option<Option<Vec<String>>>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    stop_signal: Option<Option<String>>,
+    stop_timeout: Option<Option<i32>>,
+    shell: Option<Option<Vec<String>>>,
+}
+impl ContainerConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The hostname to use for the container, as a valid RFC 1123 hostname.
+    pub fn hostname(&mut self, value: impl Into<String>) -> &mut Self {
+        self.hostname = Some(value.into());
+        self
+    }
+    /// The domain name to use for the container.
+    pub fn domainname(&mut self, value: impl Into<String>) -> &mut Self {
+        self.domainname = Some(value.into());
+        self
+    }
+    /// The user that commands are run as inside the container.
+    pub fn user(&mut self, value: impl Into<String>) -> &mut Self {
+        self.user = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stdin`.
+    pub fn attach_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdin = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stdout`.
+    pub fn attach_stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdout = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stderr`.
+    pub fn attach_stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stderr = Some(value.into());
+        self
+    }
+    /// An object mapping ports to an empty object in the form:  `{\"<port>/<tcp|udp|sctp>\": {}}`
+    pub fn exposed_ports(&mut self, value: Vec<ExposedPort>) -> &mut Self {
+        let value = value
+            .into_iter()
+            .map(|item| (
+                String::from(item),
+                serde_json::Value::Object(Default::default()),
+            ))
+            .collect::<HashMap<_, _>>();
+        self.exposed_ports = Some(value.into());
+        self
+    }
+    /// Attach standard streams to a TTY, including `stdin` if it is not closed.
+    pub fn tty(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.tty = Some(value.into());
+        self
+    }
+    /// Open `stdin`
+    pub fn open_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.open_stdin = Some(value.into());
+        self
+    }
+    /// Close `stdin` after one attached client disconnects
+    pub fn stdin_once(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdin_once = Some(value.into());
+        self
+    }
+    /// A list of environment variables to set inside the container in the form `[\"VAR=value\", ...]`. A variable without `=` is removed from the environment, rather than to have an empty value.
+    pub fn env(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.env = Some(value.into());
+        self
+    }
+    /// Command to run specified as a string or an array of strings.
+    pub fn cmd(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.cmd = Some(value.into());
+        self
+    }
+    pub fn healthcheck(&mut self, value: impl Into<Box<HealthConfig>>) -> &mut Self {
+        self.healthcheck = Some(value.into());
+        self
+    }
+    /// Command is already escaped (Windows only)
+    pub fn args_escaped(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.args_escaped = Some(value.into());
+        self
+    }
+    /// The name (or reference) of the image to use when creating the container, or which was used when the container was created.
+    pub fn image(&mut self, value: impl Into<String>) -> &mut Self {
+        self.image = Some(value.into());
+        self
+    }
+    /// An object mapping mount point paths inside the container to empty objects.
+    pub fn volumes(&mut self, value: Vec<PathBuf>) -> &mut Self {
+        let value = value
+            .into_iter()
+            .map(|item| (
+                item.to_string_lossy().into_owned(),
+                serde_json::Value::Object(Default::default()),
+            ))
+            .collect::<HashMap<_, _>>();
+        self.volumes = Some(value.into());
+        self
+    }
+    /// The working directory for commands to run in.
+    pub fn working_dir(&mut self, value: impl Into<String>) -> &mut Self {
+        self.working_dir = Some(value.into());
+        self
+    }
+    /// The entry point for the container as a string or an array of strings.  If the array consists of exactly one empty string (`[\"\"]`) then the entry point is reset to system default (i.e., the entry point used by docker when there is no `ENTRYPOINT` instruction in the `Dockerfile`).
+    pub fn entrypoint(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.entrypoint = Some(value.into());
+        self
+    }
+    /// Disable networking for the container.
+    pub fn network_disabled(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.network_disabled = Some(value.into());
+        self
+    }
+    /// MAC address of the container.  Deprecated: this field is deprecated in API v1.44 and up. Use EndpointSettings.MacAddress instead.
+    pub fn mac_address(&mut self, value: impl Into<Option<String>>) -> &mut Self {
+        self.mac_address = Some(value.into());
+        self
+    }
+    /// `ONBUILD` metadata that were defined in the image's `Dockerfile`.
+    pub fn on_build(&mut self, value: impl Into<Option<Vec<String>>>) -> &mut Self {
+        self.on_build = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Signal to stop a container as a string or unsigned integer.
+    pub fn stop_signal(&mut self, value: impl Into<Option<String>>) -> &mut Self {
+        self.stop_signal = Some(value.into());
+        self
+    }
+    /// Timeout to stop a container in seconds.
+    pub fn stop_timeout(&mut self, value: impl Into<Option<i32>>) -> &mut Self {
+        self.stop_timeout = Some(value.into());
+        self
+    }
+    /// Shell for when `RUN`, `CMD`, and `ENTRYPOINT` uses a shell.
+    pub fn shell(&mut self, value: impl Into<Option<Vec<String>>>) -> &mut Self {
+        self.shell = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerConfig {
+        ContainerConfig {
+            hostname: self.hostname.clone(),
+            domainname: self.domainname.clone(),
+            user: self.user.clone(),
+            attach_stdin: self.attach_stdin.clone(),
+            attach_stdout: self.attach_stdout.clone(),
+            attach_stderr: self.attach_stderr.clone(),
+            exposed_ports: self.exposed_ports.clone(),
+            tty: self.tty.clone(),
+            open_stdin: self.open_stdin.clone(),
+            stdin_once: self.stdin_once.clone(),
+            env: self.env.clone(),
+            cmd: self.cmd.clone(),
+            healthcheck: self.healthcheck.clone(),
+            args_escaped: self.args_escaped.clone(),
+            image: self.image.clone(),
+            volumes: self.volumes.clone(),
+            working_dir: self.working_dir.clone(),
+            entrypoint: self.entrypoint.clone(),
+            network_disabled: self.network_disabled.clone(),
+            mac_address: self.mac_address.clone(),
+            on_build: self.on_build.clone(),
+            labels: self.labels.clone(),
+            stop_signal: self.stop_signal.clone(),
+            stop_timeout: self.stop_timeout.clone(),
+            shell: self.shell.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerCreateParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerCreateParamsBuilder {
+    body: ContainerCreateRequest,
+    name: Option<String>,
+    platform: Option<String>,
+}
+impl ContainerCreateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(body: impl Into<ContainerCreateRequest>) -> Self {
+        Self {
+            body: body.into(),
+            name: None,
+            platform: None,
+        }
+    }
+    /// Assign the specified name to the container. Must match `/?[a-zA-Z0-9][a-zA-Z0-9_.-]+`.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// Platform in the format `os[/arch[/variant]]` used for image lookup.  When specified, the daemon checks if the requested image is present in the local image cache with the given OS and Architecture, and otherwise returns a `404` status.  If the option is not set, the host's native OS and Architecture are used to look up the image in the image cache. However, if no platform is passed and the given image does exist in the local image cache, but its OS or architecture does not match, the container is created with the available image, and a warning is added to the `Warnings` field in the response, for example;      WARNING: The requested image's platform (linux/arm64/v8) does not              match the detected host platform (linux/amd64) and no              specific platform was requested
+    pub fn platform(&mut self, value: impl Into<String>) -> &mut Self {
+        self.platform = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerCreateParams {
+        ContainerCreateParams {
+            body: self.body.clone(),
+            name: self.name.clone(),
+            platform: self.platform.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerCreateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct ContainerCreateRequestBuilder {
+    hostname: Option<String>,
+    domainname: Option<String>,
+    user: Option<String>,
+    attach_stdin: Option<bool>,
+    attach_stdout: Option<bool>,
+    attach_stderr: Option<bool>,
+    exposed_ports: Option<Option<std::collections::HashMap<String, serde_json::Value>>>,
+    tty: Option<bool>,
+    open_stdin: Option<bool>,
+    stdin_once: Option<bool>,
+    env: Option<Vec<String>>,
+    cmd: Option<Vec<String>>,
+    healthcheck: Option<Box<HealthConfig>>,
+    args_escaped: Option<Option<bool>>,
+    image: Option<String>,
+    volumes: Option<std::collections::HashMap<String, serde_json::Value>>,
+    working_dir: Option<String>,
+    entrypoint: Option<Vec<String>>,
+    network_disabled: Option<Option<bool>>,
+    mac_address: Option<Option<String>>,
+    on_build: Option<Option<Vec<String>>>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    stop_signal: Option<Option<String>>,
+    stop_timeout: Option<Option<i32>>,
+    shell: Option<Option<Vec<String>>>,
+    host_config: Option<Box<HostConfig>>,
+    networking_config: Option<Box<NetworkingConfig>>,
+}
+impl ContainerCreateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The hostname to use for the container, as a valid RFC 1123 hostname.
+    pub fn hostname(&mut self, value: impl Into<String>) -> &mut Self {
+        self.hostname = Some(value.into());
+        self
+    }
+    /// The domain name to use for the container.
+    pub fn domainname(&mut self, value: impl Into<String>) -> &mut Self {
+        self.domainname = Some(value.into());
+        self
+    }
+    /// The user that commands are run as inside the container.
+    pub fn user(&mut self, value: impl Into<String>) -> &mut Self {
+        self.user = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stdin`.
+    pub fn attach_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdin = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stdout`.
+    pub fn attach_stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdout = Some(value.into());
+        self
+    }
+    /// Whether to attach to `stderr`.
+    pub fn attach_stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stderr = Some(value.into());
+        self
+    }
+    /// An object mapping ports to an empty object in the form:  `{\"<port>/<tcp|udp|sctp>\": {}}`
+    pub fn exposed_ports(&mut self, value: Vec<ExposedPort>) -> &mut Self {
+        let value = value
+            .into_iter()
+            .map(|item| (
+                String::from(item),
+                serde_json::Value::Object(Default::default()),
+            ))
+            .collect::<HashMap<_, _>>();
+        self.exposed_ports = Some(value.into());
+        self
+    }
+    /// Attach standard streams to a TTY, including `stdin` if it is not closed.
+    pub fn tty(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.tty = Some(value.into());
+        self
+    }
+    /// Open `stdin`
+    pub fn open_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.open_stdin = Some(value.into());
+        self
+    }
+    /// Close `stdin` after one attached client disconnects
+    pub fn stdin_once(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdin_once = Some(value.into());
+        self
+    }
+    /// A list of environment variables to set inside the container in the form `[\"VAR=value\", ...]`. A variable without `=` is removed from the environment, rather than to have an empty value.
+    pub fn env(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.env = Some(value.into());
+        self
+    }
+    /// Command to run specified as a string or an array of strings.
+    pub fn cmd(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.cmd = Some(value.into());
+        self
+    }
+    pub fn healthcheck(&mut self, value: impl Into<Box<HealthConfig>>) -> &mut Self {
+        self.healthcheck = Some(value.into());
+        self
+    }
+    /// Command is already escaped (Windows only)
+    pub fn args_escaped(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.args_escaped = Some(value.into());
+        self
+    }
+    /// The name (or reference) of the image to use when creating the container, or which was used when the container was created.
+    pub fn image(&mut self, value: impl Into<String>) -> &mut Self {
+        self.image = Some(value.into());
+        self
+    }
+    /// An object mapping mount point paths inside the container to empty objects.
+    pub fn volumes(&mut self, value: Vec<PathBuf>) -> &mut Self {
+        let value = value
+            .into_iter()
+            .map(|item| (
+                item.to_string_lossy().into_owned(),
+                serde_json::Value::Object(Default::default()),
+            ))
+            .collect::<HashMap<_, _>>();
+        self.volumes = Some(value.into());
+        self
+    }
+    /// The working directory for commands to run in.
+    pub fn working_dir(&mut self, value: impl Into<String>) -> &mut Self {
+        self.working_dir = Some(value.into());
+        self
+    }
+    /// The entry point for the container as a string or an array of strings.  If the array consists of exactly one empty string (`[\"\"]`) then the entry point is reset to system default (i.e., the entry point used by docker when there is no `ENTRYPOINT` instruction in the `Dockerfile`).
+    pub fn entrypoint(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.entrypoint = Some(value.into());
+        self
+    }
+    /// Disable networking for the container.
+    pub fn network_disabled(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.network_disabled = Some(value.into());
+        self
+    }
+    /// MAC address of the container.  Deprecated: this field is deprecated in API v1.44 and up. Use EndpointSettings.MacAddress instead.
+    pub fn mac_address(&mut self, value: impl Into<Option<String>>) -> &mut Self {
+        self.mac_address = Some(value.into());
+        self
+    }
+    /// `ONBUILD` metadata that were defined in the image's `Dockerfile`.
+    pub fn on_build(&mut self, value: impl Into<Option<Vec<String>>>) -> &mut Self {
+        self.on_build = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Signal to stop a container as a string or unsigned integer.
+    pub fn stop_signal(&mut self, value: impl Into<Option<String>>) -> &mut Self {
+        self.stop_signal = Some(value.into());
+        self
+    }
+    /// Timeout to stop a container in seconds.
+    pub fn stop_timeout(&mut self, value: impl Into<Option<i32>>) -> &mut Self {
+        self.stop_timeout = Some(value.into());
+        self
+    }
+    /// Shell for when `RUN`, `CMD`, and `ENTRYPOINT` uses a shell.
+    pub fn shell(&mut self, value: impl Into<Option<Vec<String>>>) -> &mut Self {
+        self.shell = Some(value.into());
+        self
+    }
+    pub fn host_config(&mut self, value: impl Into<Box<HostConfig>>) -> &mut Self {
+        self.host_config = Some(value.into());
+        self
+    }
+    pub fn networking_config(
+        &mut self,
+        value: impl Into<Box<NetworkingConfig>>,
+    ) -> &mut Self {
+        self.networking_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerCreateRequest {
+        ContainerCreateRequest {
+            hostname: self.hostname.clone(),
+            domainname: self.domainname.clone(),
+            user: self.user.clone(),
+            attach_stdin: self.attach_stdin.clone(),
+            attach_stdout: self.attach_stdout.clone(),
+            attach_stderr: self.attach_stderr.clone(),
+            exposed_ports: self.exposed_ports.clone(),
+            tty: self.tty.clone(),
+            open_stdin: self.open_stdin.clone(),
+            stdin_once: self.stdin_once.clone(),
+            env: self.env.clone(),
+            cmd: self.cmd.clone(),
+            healthcheck: self.healthcheck.clone(),
+            args_escaped: self.args_escaped.clone(),
+            image: self.image.clone(),
+            volumes: self.volumes.clone(),
+            working_dir: self.working_dir.clone(),
+            entrypoint: self.entrypoint.clone(),
+            network_disabled: self.network_disabled.clone(),
+            mac_address: self.mac_address.clone(),
+            on_build: self.on_build.clone(),
+            labels: self.labels.clone(),
+            stop_signal: self.stop_signal.clone(),
+            stop_timeout: self.stop_timeout.clone(),
+            shell: self.shell.clone(),
+            host_config: self.host_config.clone(),
+            networking_config: self.networking_config.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerDeleteParamsBuilder {
+    id: String,
+    v: Option<bool>,
+    force: Option<bool>,
+    link: Option<bool>,
+}
+impl ContainerDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            v: None,
+            force: None,
+            link: None,
+        }
+    }
+    /// Remove anonymous volumes associated with the container.
+    pub fn v(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.v = Some(value.into());
+        self
+    }
+    /// If the container is running, kill it before removing it.
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    /// Remove the specified link associated with the container.
+    pub fn link(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.link = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerDeleteParams {
+        ContainerDeleteParams {
+            id: self.id.clone(),
+            v: self.v.clone(),
+            force: self.force.clone(),
+            link: self.link.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerExecParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerExecParamsBuilder {
+    id: String,
+    exec_config: ExecConfig,
+}
+impl ContainerExecParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, exec_config: impl Into<ExecConfig>) -> Self {
+        Self {
+            id: id.into(),
+            exec_config: exec_config.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerExecParams {
+        ContainerExecParams {
+            id: self.id.clone(),
+            exec_config: self.exec_config.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerExportParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerExportParamsBuilder {
+    id: String,
+}
+impl ContainerExportParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ContainerExportParams {
+        ContainerExportParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerInspectParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerInspectParamsBuilder {
+    id: String,
+    size: Option<bool>,
+}
+impl ContainerInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into(), size: None }
+    }
+    /// Return the size of container as fields `SizeRw` and `SizeRootFs`
+    pub fn size(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.size = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerInspectParams {
+        ContainerInspectParams {
+            id: self.id.clone(),
+            size: self.size.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerKillParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerKillParamsBuilder {
+    id: String,
+    signal: Option<String>,
+}
+impl ContainerKillParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            signal: None,
+        }
+    }
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub fn signal(&mut self, value: impl Into<String>) -> &mut Self {
+        self.signal = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerKillParams {
+        ContainerKillParams {
+            id: self.id.clone(),
+            signal: self.signal.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ContainerListParamsBuilder {
+    all: Option<bool>,
+    limit: Option<i32>,
+    size: Option<bool>,
+    filters: Option<String>,
+}
+impl ContainerListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Return all containers. By default, only running containers are shown.
+    pub fn all(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.all = Some(value.into());
+        self
+    }
+    /// Return this number of most recently created containers, including non-running ones.
+    pub fn limit(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.limit = Some(value.into());
+        self
+    }
+    /// Return the size of container as fields `SizeRw` and `SizeRootFs`.
+    pub fn size(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.size = Some(value.into());
+        self
+    }
+    /// Filters to process on the container list, encoded as JSON (a `map[string][]string`). For example, `{\"status\": [\"paused\"]}` will only return paused containers.  Available filters:  - `ancestor`=(`<image-name>[:<tag>]`, `<image id>`, or `<image@digest>`) - `before`=(`<container id>` or `<container name>`) - `expose`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`) - `exited=<int>` containers with exit code of `<int>` - `health`=(`starting`|`healthy`|`unhealthy`|`none`) - `id=<ID>` a container's ID - `isolation=`(`default`|`process`|`hyperv`) (Windows daemon only) - `is-task=`(`true`|`false`) - `label=key` or `label=\"key=value\"` of a container label - `name=<name>` a container's name - `network`=(`<network id>` or `<network name>`) - `publish`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`) - `since`=(`<container id>` or `<container name>`) - `status=`(`created`|`restarting`|`running`|`removing`|`paused`|`exited`|`dead`) - `volume`=(`<volume name>` or `<mount point destination>`)
+    pub fn filters(&mut self, value: ContainerListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerListParams {
+        ContainerListParams {
+            all: self.all.clone(),
+            limit: self.limit.clone(),
+            size: self.size.clone(),
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerLogsParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerLogsParamsBuilder {
+    id: String,
+    follow: Option<bool>,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+    since: Option<i32>,
+    until: Option<i32>,
+    timestamps: Option<bool>,
+    tail: Option<String>,
+}
+impl ContainerLogsParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            follow: None,
+            stdout: None,
+            stderr: None,
+            since: None,
+            until: None,
+            timestamps: None,
+            tail: None,
+        }
+    }
+    /// Keep connection after returning logs.
+    pub fn follow(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.follow = Some(value.into());
+        self
+    }
+    /// Return logs from `stdout`
+    pub fn stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdout = Some(value.into());
+        self
+    }
+    /// Return logs from `stderr`
+    pub fn stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stderr = Some(value.into());
+        self
+    }
+    /// Only return logs since this time, as a UNIX timestamp
+    pub fn since(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.since = Some(value.into());
+        self
+    }
+    /// Only return logs before this time, as a UNIX timestamp
+    pub fn until(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.until = Some(value.into());
+        self
+    }
+    /// Add timestamps to every log line
+    pub fn timestamps(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.timestamps = Some(value.into());
+        self
+    }
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub fn tail(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tail = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerLogsParams {
+        ContainerLogsParams {
+            id: self.id.clone(),
+            follow: self.follow.clone(),
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+            since: self.since.clone(),
+            until: self.until.clone(),
+            timestamps: self.timestamps.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerPauseParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerPauseParamsBuilder {
+    id: String,
+}
+impl ContainerPauseParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ContainerPauseParams {
+        ContainerPauseParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerPruneParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ContainerPruneParamsBuilder {
+    filters: Option<String>,
+}
+impl ContainerPruneParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `until=<timestamp>` Prune containers created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune containers with (or without, in case `label!=...` is used) the specified labels.
+    pub fn filters(&mut self, value: ContainerPruneFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerPruneParams {
+        ContainerPruneParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerRenameParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerRenameParamsBuilder {
+    id: String,
+    name: String,
+}
+impl ContainerRenameParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerRenameParams {
+        ContainerRenameParams {
+            id: self.id.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerResizeParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerResizeParamsBuilder {
+    id: String,
+    h: i32,
+    w: i32,
+}
+impl ContainerResizeParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, h: impl Into<i32>, w: impl Into<i32>) -> Self {
+        Self {
+            id: id.into(),
+            h: h.into(),
+            w: w.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerResizeParams {
+        ContainerResizeParams {
+            id: self.id.clone(),
+            h: self.h.clone(),
+            w: self.w.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerRestartParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerRestartParamsBuilder {
+    id: String,
+    signal: Option<String>,
+    t: Option<i32>,
+}
+impl ContainerRestartParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            signal: None,
+            t: None,
+        }
+    }
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub fn signal(&mut self, value: impl Into<String>) -> &mut Self {
+        self.signal = Some(value.into());
+        self
+    }
+    /// Number of seconds to wait before killing the container
+    pub fn t(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.t = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerRestartParams {
+        ContainerRestartParams {
+            id: self.id.clone(),
+            signal: self.signal.clone(),
+            t: self.t.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerStartParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerStartParamsBuilder {
+    id: String,
+    detach_keys: Option<String>,
+}
+impl ContainerStartParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            detach_keys: None,
+        }
+    }
+    /// Override the key sequence for detaching a container. Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    pub fn detach_keys(&mut self, value: impl Into<String>) -> &mut Self {
+        self.detach_keys = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerStartParams {
+        ContainerStartParams {
+            id: self.id.clone(),
+            detach_keys: self.detach_keys.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerStatsParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerStatsParamsBuilder {
+    id: String,
+    stream: Option<bool>,
+    one_shot: Option<bool>,
+}
+impl ContainerStatsParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            stream: None,
+            one_shot: None,
+        }
+    }
+    /// Stream the output. If false, the stats will be output once and then it will disconnect.
+    pub fn stream(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stream = Some(value.into());
+        self
+    }
+    /// Only get a single stat instead of waiting for 2 cycles. Must be used with `stream=false`.
+    pub fn one_shot(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.one_shot = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerStatsParams {
+        ContainerStatsParams {
+            id: self.id.clone(),
+            stream: self.stream.clone(),
+            one_shot: self.one_shot.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerStopParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerStopParamsBuilder {
+    id: String,
+    signal: Option<String>,
+    t: Option<i32>,
+}
+impl ContainerStopParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            signal: None,
+            t: None,
+        }
+    }
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub fn signal(&mut self, value: impl Into<String>) -> &mut Self {
+        self.signal = Some(value.into());
+        self
+    }
+    /// Number of seconds to wait before killing the container
+    pub fn t(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.t = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerStopParams {
+        ContainerStopParams {
+            id: self.id.clone(),
+            signal: self.signal.clone(),
+            t: self.t.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerTopParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerTopParamsBuilder {
+    id: String,
+    ps_args: Option<String>,
+}
+impl ContainerTopParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ps_args: None,
+        }
+    }
+    /// The arguments to pass to `ps`. For example, `aux`
+    pub fn ps_args(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ps_args = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerTopParams {
+        ContainerTopParams {
+            id: self.id.clone(),
+            ps_args: self.ps_args.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerUnpauseParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerUnpauseParamsBuilder {
+    id: String,
+}
+impl ContainerUnpauseParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ContainerUnpauseParams {
+        ContainerUnpauseParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerUpdateParamsBuilder {
+    id: String,
+    update: ContainerUpdateRequest,
+}
+impl ContainerUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<String>,
+        update: impl Into<ContainerUpdateRequest>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            update: update.into(),
+        }
+    }
+    pub fn build(&self) -> ContainerUpdateParams {
+        ContainerUpdateParams {
+            id: self.id.clone(),
+            update: self.update.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerUpdateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct ContainerUpdateRequestBuilder {
+    cpu_shares: Option<i32>,
+    memory: Option<i64>,
+    cgroup_parent: Option<String>,
+    blkio_weight: Option<u32>,
+    blkio_weight_device: Option<Vec<ResourcesBlkioWeightDeviceInner>>,
+    blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+    blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+    blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
+    blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
+    cpu_period: Option<i64>,
+    cpu_quota: Option<i64>,
+    cpu_realtime_period: Option<i64>,
+    cpu_realtime_runtime: Option<i64>,
+    cpuset_cpus: Option<String>,
+    cpuset_mems: Option<String>,
+    devices: Option<Vec<DeviceMapping>>,
+    device_cgroup_rules: Option<Vec<String>>,
+    device_requests: Option<Vec<DeviceRequest>>,
+    kernel_memory_tcp: Option<i64>,
+    memory_reservation: Option<i64>,
+    memory_swap: Option<i64>,
+    memory_swappiness: Option<u64>,
+    nano_cpus: Option<i64>,
+    oom_kill_disable: Option<bool>,
+    init: Option<Option<bool>>,
+    pids_limit: Option<Option<i64>>,
+    ulimits: Option<Vec<ResourcesUlimitsInner>>,
+    cpu_count: Option<i64>,
+    cpu_percent: Option<i64>,
+    io_maximum_i_ops: Option<i64>,
+    io_maximum_bandwidth: Option<i64>,
+    restart_policy: Option<Box<RestartPolicy>>,
+}
+impl ContainerUpdateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// An integer value representing this container's relative CPU weight versus other containers.
+    pub fn cpu_shares(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.cpu_shares = Some(value.into());
+        self
+    }
+    /// Memory limit in bytes.
+    pub fn memory(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory = Some(value.into());
+        self
+    }
+    /// Path to `cgroups` under which the container's `cgroup` is created. If the path is not absolute, the path is considered to be relative to the `cgroups` path of the init process. Cgroups are created if they do not already exist.
+    pub fn cgroup_parent(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cgroup_parent = Some(value.into());
+        self
+    }
+    /// Block IO weight (relative weight).
+    pub fn blkio_weight(&mut self, value: impl Into<u32>) -> &mut Self {
+        self.blkio_weight = Some(value.into());
+        self
+    }
+    /// Block IO weight (relative device weight) in the form:  ``` [{\"Path\": \"device_path\", \"Weight\": weight}] ```
+    pub fn blkio_weight_device(
+        &mut self,
+        value: impl Into<Vec<ResourcesBlkioWeightDeviceInner>>,
+    ) -> &mut Self {
+        self.blkio_weight_device = Some(value.into());
+        self
+    }
+    /// Limit read rate (bytes per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_read_bps(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_read_bps = Some(value.into());
+        self
+    }
+    /// Limit write rate (bytes per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_write_bps(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_write_bps = Some(value.into());
+        self
+    }
+    /// Limit read rate (IO per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_read_i_ops(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_read_i_ops = Some(value.into());
+        self
+    }
+    /// Limit write rate (IO per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_write_i_ops(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_write_i_ops = Some(value.into());
+        self
+    }
+    /// The length of a CPU period in microseconds.
+    pub fn cpu_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_period = Some(value.into());
+        self
+    }
+    /// Microseconds of CPU time that the container can get in a CPU period.
+    pub fn cpu_quota(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_quota = Some(value.into());
+        self
+    }
+    /// The length of a CPU real-time period in microseconds. Set to 0 to allocate no time allocated to real-time tasks.
+    pub fn cpu_realtime_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_realtime_period = Some(value.into());
+        self
+    }
+    /// The length of a CPU real-time runtime in microseconds. Set to 0 to allocate no time allocated to real-time tasks.
+    pub fn cpu_realtime_runtime(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_realtime_runtime = Some(value.into());
+        self
+    }
+    /// CPUs in which to allow execution (e.g., `0-3`, `0,1`).
+    pub fn cpuset_cpus(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cpuset_cpus = Some(value.into());
+        self
+    }
+    /// Memory nodes (MEMs) in which to allow execution (0-3, 0,1). Only effective on NUMA systems.
+    pub fn cpuset_mems(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cpuset_mems = Some(value.into());
+        self
+    }
+    /// A list of devices to add to the container.
+    pub fn devices(&mut self, value: impl Into<Vec<DeviceMapping>>) -> &mut Self {
+        self.devices = Some(value.into());
+        self
+    }
+    /// a list of cgroup rules to apply to the container
+    pub fn device_cgroup_rules(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.device_cgroup_rules = Some(value.into());
+        self
+    }
+    /// A list of requests for devices to be sent to device drivers.
+    pub fn device_requests(
+        &mut self,
+        value: impl Into<Vec<DeviceRequest>>,
+    ) -> &mut Self {
+        self.device_requests = Some(value.into());
+        self
+    }
+    /// Hard limit for kernel TCP buffer memory (in bytes). Depending on the OCI runtime in use, this option may be ignored. It is no longer supported by the default (runc) runtime.  This field is omitted when empty.
+    pub fn kernel_memory_tcp(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.kernel_memory_tcp = Some(value.into());
+        self
+    }
+    /// Memory soft limit in bytes.
+    pub fn memory_reservation(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_reservation = Some(value.into());
+        self
+    }
+    /// Total memory limit (memory + swap). Set as `-1` to enable unlimited swap.
+    pub fn memory_swap(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_swap = Some(value.into());
+        self
+    }
+    /// Tune a container's memory swappiness behavior. Accepts an integer between 0 and 100.
+    pub fn memory_swappiness(&mut self, value: impl Into<u64>) -> &mut Self {
+        self.memory_swappiness = Some(value.into());
+        self
+    }
+    /// CPU quota in units of 10<sup>-9</sup> CPUs.
+    pub fn nano_cpus(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.nano_cpus = Some(value.into());
+        self
+    }
+    /// Disable OOM Killer for the container.
+    pub fn oom_kill_disable(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.oom_kill_disable = Some(value.into());
+        self
+    }
+    /// Run an init inside the container that forwards signals and reaps processes. This field is omitted if empty, and the default (as configured on the daemon) is used.
+    pub fn init(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.init = Some(value.into());
+        self
+    }
+    /// Tune a container's PIDs limit. Set `0` or `-1` for unlimited, or `null` to not change.
+    pub fn pids_limit(&mut self, value: impl Into<Option<i64>>) -> &mut Self {
+        self.pids_limit = Some(value.into());
+        self
+    }
+    /// A list of resource limits to set in the container. For example:  ``` {\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048} ```
+    pub fn ulimits(
+        &mut self,
+        value: impl Into<Vec<ResourcesUlimitsInner>>,
+    ) -> &mut Self {
+        self.ulimits = Some(value.into());
+        self
+    }
+    /// The number of usable CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
+    pub fn cpu_count(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_count = Some(value.into());
+        self
+    }
+    /// The usable percentage of the available CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
+    pub fn cpu_percent(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_percent = Some(value.into());
+        self
+    }
+    /// Maximum IOps for the container system drive (Windows only)
+    pub fn io_maximum_i_ops(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.io_maximum_i_ops = Some(value.into());
+        self
+    }
+    /// Maximum IO in bytes per second for the container system drive (Windows only).
+    pub fn io_maximum_bandwidth(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.io_maximum_bandwidth = Some(value.into());
+        self
+    }
+    pub fn restart_policy(&mut self, value: impl Into<Box<RestartPolicy>>) -> &mut Self {
+        self.restart_policy = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerUpdateRequest {
+        ContainerUpdateRequest {
+            cpu_shares: self.cpu_shares.clone(),
+            memory: self.memory.clone(),
+            cgroup_parent: self.cgroup_parent.clone(),
+            blkio_weight: self.blkio_weight.clone(),
+            blkio_weight_device: self.blkio_weight_device.clone(),
+            blkio_device_read_bps: self.blkio_device_read_bps.clone(),
+            blkio_device_write_bps: self.blkio_device_write_bps.clone(),
+            blkio_device_read_i_ops: self.blkio_device_read_i_ops.clone(),
+            blkio_device_write_i_ops: self.blkio_device_write_i_ops.clone(),
+            cpu_period: self.cpu_period.clone(),
+            cpu_quota: self.cpu_quota.clone(),
+            cpu_realtime_period: self.cpu_realtime_period.clone(),
+            cpu_realtime_runtime: self.cpu_realtime_runtime.clone(),
+            cpuset_cpus: self.cpuset_cpus.clone(),
+            cpuset_mems: self.cpuset_mems.clone(),
+            devices: self.devices.clone(),
+            device_cgroup_rules: self.device_cgroup_rules.clone(),
+            device_requests: self.device_requests.clone(),
+            kernel_memory_tcp: self.kernel_memory_tcp.clone(),
+            memory_reservation: self.memory_reservation.clone(),
+            memory_swap: self.memory_swap.clone(),
+            memory_swappiness: self.memory_swappiness.clone(),
+            nano_cpus: self.nano_cpus.clone(),
+            oom_kill_disable: self.oom_kill_disable.clone(),
+            init: self.init.clone(),
+            pids_limit: self.pids_limit.clone(),
+            ulimits: self.ulimits.clone(),
+            cpu_count: self.cpu_count.clone(),
+            cpu_percent: self.cpu_percent.clone(),
+            io_maximum_i_ops: self.io_maximum_i_ops.clone(),
+            io_maximum_bandwidth: self.io_maximum_bandwidth.clone(),
+            restart_policy: self.restart_policy.clone(),
+        }
+    }
+}
+/// Builder of [`ContainerWaitParams`].
+#[derive(Clone, Debug)]
+pub struct ContainerWaitParamsBuilder {
+    id: String,
+    condition: Option<String>,
+}
+impl ContainerWaitParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            condition: None,
+        }
+    }
+    /// Wait until a container state reaches the given condition.  Defaults to `not-running` if omitted or empty.
+    pub fn condition(&mut self, value: impl Into<String>) -> &mut Self {
+        self.condition = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ContainerWaitParams {
+        ContainerWaitParams {
+            id: self.id.clone(),
+            condition: self.condition.clone(),
+        }
+    }
+}
+/// Builder of [`DeviceMapping`].
+#[derive(Clone, Debug, Default)]
+pub struct DeviceMappingBuilder {
+    path_on_host: Option<String>,
+    path_in_container: Option<String>,
+    cgroup_permissions: Option<String>,
+}
+impl DeviceMappingBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn path_on_host(&mut self, value: impl Into<String>) -> &mut Self {
+        self.path_on_host = Some(value.into());
+        self
+    }
+    pub fn path_in_container(&mut self, value: impl Into<String>) -> &mut Self {
+        self.path_in_container = Some(value.into());
+        self
+    }
+    pub fn cgroup_permissions(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cgroup_permissions = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> DeviceMapping {
+        DeviceMapping {
+            path_on_host: self.path_on_host.clone(),
+            path_in_container: self.path_in_container.clone(),
+            cgroup_permissions: self.cgroup_permissions.clone(),
+        }
+    }
+}
+/// Builder of [`DeviceRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct DeviceRequestBuilder {
+    driver: Option<String>,
+    count: Option<i32>,
+    device_ids: Option<Vec<String>>,
+    capabilities: Option<Vec<Vec<String>>>,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl DeviceRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    pub fn count(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.count = Some(value.into());
+        self
+    }
+    pub fn device_ids(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.device_ids = Some(value.into());
+        self
+    }
+    /// A list of capabilities; an OR list of AND lists of capabilities.
+    pub fn capabilities(&mut self, value: impl Into<Vec<Vec<String>>>) -> &mut Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+    /// Driver-specific options, specified as a key/value pairs. These options are passed directly to the driver.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> DeviceRequest {
+        DeviceRequest {
+            driver: self.driver.clone(),
+            count: self.count.clone(),
+            device_ids: self.device_ids.clone(),
+            capabilities: self.capabilities.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`DistributionInspectParams`].
+#[derive(Clone, Debug)]
+pub struct DistributionInspectParamsBuilder {
+    name: String,
+}
+impl DistributionInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> DistributionInspectParams {
+        DistributionInspectParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`Driver`].
+#[derive(Clone, Debug)]
+pub struct DriverBuilder {
+    name: String,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl DriverBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            options: None,
+        }
+    }
+    /// Key/value map of driver-specific options.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> Driver {
+        Driver {
+            name: self.name.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`EndpointIpamConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct EndpointIpamConfigBuilder {
+    ipv4_address: Option<String>,
+    ipv6_address: Option<String>,
+    link_local_ips: Option<Vec<String>>,
+}
+impl EndpointIpamConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn ipv4_address(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ipv4_address = Some(value.into());
+        self
+    }
+    pub fn ipv6_address(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ipv6_address = Some(value.into());
+        self
+    }
+    pub fn link_local_ips(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.link_local_ips = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> EndpointIpamConfig {
+        EndpointIpamConfig {
+            ipv4_address: self.ipv4_address.clone(),
+            ipv6_address: self.ipv6_address.clone(),
+            link_local_ips: self.link_local_ips.clone(),
+        }
+    }
+}
+/// Builder of [`EndpointPortConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct EndpointPortConfigBuilder {
+    name: Option<String>,
+    protocol: Option<EndpointPortConfigProtocol>,
+    target_port: Option<i32>,
+    published_port: Option<i32>,
+    publish_mode: Option<EndpointPortConfigPublishMode>,
+}
+impl EndpointPortConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    pub fn protocol(
+        &mut self,
+        value: impl Into<EndpointPortConfigProtocol>,
+    ) -> &mut Self {
+        self.protocol = Some(value.into());
+        self
+    }
+    /// The port inside the container.
+    pub fn target_port(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.target_port = Some(value.into());
+        self
+    }
+    /// The port on the swarm hosts.
+    pub fn published_port(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.published_port = Some(value.into());
+        self
+    }
+    /// The mode in which port is published.  <p><br /></p>  - \"ingress\" makes the target port accessible on every node,   regardless of whether there is a task for the service running on   that node or not. - \"host\" bypasses the routing mesh and publish the port directly on   the swarm node where that service is running.
+    pub fn publish_mode(
+        &mut self,
+        value: impl Into<EndpointPortConfigPublishMode>,
+    ) -> &mut Self {
+        self.publish_mode = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> EndpointPortConfig {
+        EndpointPortConfig {
+            name: self.name.clone(),
+            protocol: self.protocol.clone(),
+            target_port: self.target_port.clone(),
+            published_port: self.published_port.clone(),
+            publish_mode: self.publish_mode.clone(),
+        }
+    }
+}
+/// Builder of [`EndpointSettings`].
+#[derive(Clone, Debug, Default)]
+pub struct EndpointSettingsBuilder {
+    ipam_config: Option<Option<Box<EndpointIpamConfig>>>,
+    links: Option<Vec<String>>,
+    mac_address: Option<String>,
+    aliases: Option<Vec<String>>,
+    driver_opts: Option<Option<std::collections::HashMap<String, String>>>,
+    network_id: Option<String>,
+    endpoint_id: Option<String>,
+    gateway: Option<String>,
+    ip_address: Option<String>,
+    ip_prefix_len: Option<i32>,
+    ipv6_gateway: Option<String>,
+    global_ipv6_address: Option<String>,
+    global_ipv6_prefix_len: Option<i64>,
+    dns_names: Option<Vec<String>>,
+}
+impl EndpointSettingsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn ipam_config(
+        &mut self,
+        value: impl Into<Option<Box<EndpointIpamConfig>>>,
+    ) -> &mut Self {
+        self.ipam_config = Some(value.into());
+        self
+    }
+    pub fn links(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.links = Some(value.into());
+        self
+    }
+    /// MAC address for the endpoint on this network. The network driver might ignore this parameter.
+    pub fn mac_address(&mut self, value: impl Into<String>) -> &mut Self {
+        self.mac_address = Some(value.into());
+        self
+    }
+    pub fn aliases(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.aliases = Some(value.into());
+        self
+    }
+    /// DriverOpts is a mapping of driver options and values. These options are passed directly to the driver and are driver specific.
+    pub fn driver_opts(
+        &mut self,
+        value: impl Into<Option<std::collections::HashMap<String, String>>>,
+    ) -> &mut Self {
+        self.driver_opts = Some(value.into());
+        self
+    }
+    /// Unique ID of the network.
+    pub fn network_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.network_id = Some(value.into());
+        self
+    }
+    /// Unique ID for the service endpoint in a Sandbox.
+    pub fn endpoint_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.endpoint_id = Some(value.into());
+        self
+    }
+    /// Gateway address for this network.
+    pub fn gateway(&mut self, value: impl Into<String>) -> &mut Self {
+        self.gateway = Some(value.into());
+        self
+    }
+    /// IPv4 address.
+    pub fn ip_address(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ip_address = Some(value.into());
+        self
+    }
+    /// Mask length of the IPv4 address.
+    pub fn ip_prefix_len(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.ip_prefix_len = Some(value.into());
+        self
+    }
+    /// IPv6 gateway address.
+    pub fn ipv6_gateway(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ipv6_gateway = Some(value.into());
+        self
+    }
+    /// Global IPv6 address.
+    pub fn global_ipv6_address(&mut self, value: impl Into<String>) -> &mut Self {
+        self.global_ipv6_address = Some(value.into());
+        self
+    }
+    /// Mask length of the global IPv6 address.
+    pub fn global_ipv6_prefix_len(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.global_ipv6_prefix_len = Some(value.into());
+        self
+    }
+    /// List of all DNS names an endpoint has on a specific network. This list is based on the container name, network aliases, container short ID, and hostname.  These DNS names are non-fully qualified but can contain several dots. You can get fully qualified DNS names by appending `.<network-name>`. For instance, if container name is `my.ctr` and the network is named `testnet`, `DNSNames` will contain `my.ctr` and the FQDN will be `my.ctr.testnet`.
+    pub fn dns_names(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.dns_names = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> EndpointSettings {
+        EndpointSettings {
+            ipam_config: self.ipam_config.clone(),
+            links: self.links.clone(),
+            mac_address: self.mac_address.clone(),
+            aliases: self.aliases.clone(),
+            driver_opts: self.driver_opts.clone(),
+            network_id: self.network_id.clone(),
+            endpoint_id: self.endpoint_id.clone(),
+            gateway: self.gateway.clone(),
+            ip_address: self.ip_address.clone(),
+            ip_prefix_len: self.ip_prefix_len.clone(),
+            ipv6_gateway: self.ipv6_gateway.clone(),
+            global_ipv6_address: self.global_ipv6_address.clone(),
+            global_ipv6_prefix_len: self.global_ipv6_prefix_len.clone(),
+            dns_names: self.dns_names.clone(),
+        }
+    }
+}
+/// Builder of [`EndpointSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct EndpointSpecBuilder {
+    mode: Option<EndpointSpecMode>,
+    ports: Option<Vec<EndpointPortConfig>>,
+}
+impl EndpointSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The mode of resolution to use for internal load balancing between tasks.
+    pub fn mode(&mut self, value: impl Into<EndpointSpecMode>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    /// List of exposed ports that this service is accessible on from the outside. Ports can only be provided if `vip` resolution mode is used.
+    pub fn ports(&mut self, value: impl Into<Vec<EndpointPortConfig>>) -> &mut Self {
+        self.ports = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> EndpointSpec {
+        EndpointSpec {
+            mode: self.mode.clone(),
+            ports: self.ports.clone(),
+        }
+    }
+}
+/// Builder of [`ExecConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct ExecConfigBuilder {
+    attach_stdin: Option<bool>,
+    attach_stdout: Option<bool>,
+    attach_stderr: Option<bool>,
+    console_size: Option<Option<Vec<u32>>>,
+    detach_keys: Option<String>,
+    tty: Option<bool>,
+    env: Option<Vec<String>>,
+    cmd: Option<Vec<String>>,
+    privileged: Option<bool>,
+    user: Option<String>,
+    working_dir: Option<String>,
+}
+impl ExecConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Attach to `stdin` of the exec command.
+    pub fn attach_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdin = Some(value.into());
+        self
+    }
+    /// Attach to `stdout` of the exec command.
+    pub fn attach_stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stdout = Some(value.into());
+        self
+    }
+    /// Attach to `stderr` of the exec command.
+    pub fn attach_stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attach_stderr = Some(value.into());
+        self
+    }
+    /// Initial console size, as an `[height, width]` array.
+    pub fn console_size(&mut self, value: impl Into<Option<Vec<u32>>>) -> &mut Self {
+        self.console_size = Some(value.into());
+        self
+    }
+    /// Override the key sequence for detaching a container. Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    pub fn detach_keys(&mut self, value: impl Into<String>) -> &mut Self {
+        self.detach_keys = Some(value.into());
+        self
+    }
+    /// Allocate a pseudo-TTY.
+    pub fn tty(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.tty = Some(value.into());
+        self
+    }
+    /// A list of environment variables in the form `[\"VAR=value\", ...]`.
+    pub fn env(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.env = Some(value.into());
+        self
+    }
+    /// Command to run, as a string or array of strings.
+    pub fn cmd(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.cmd = Some(value.into());
+        self
+    }
+    /// Runs the exec process with extended privileges.
+    pub fn privileged(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.privileged = Some(value.into());
+        self
+    }
+    /// The user, and optionally, group to run the exec process inside the container. Format is one of: `user`, `user:group`, `uid`, or `uid:gid`.
+    pub fn user(&mut self, value: impl Into<String>) -> &mut Self {
+        self.user = Some(value.into());
+        self
+    }
+    /// The working directory for the exec process inside the container.
+    pub fn working_dir(&mut self, value: impl Into<String>) -> &mut Self {
+        self.working_dir = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ExecConfig {
+        ExecConfig {
+            attach_stdin: self.attach_stdin.clone(),
+            attach_stdout: self.attach_stdout.clone(),
+            attach_stderr: self.attach_stderr.clone(),
+            console_size: self.console_size.clone(),
+            detach_keys: self.detach_keys.clone(),
+            tty: self.tty.clone(),
+            env: self.env.clone(),
+            cmd: self.cmd.clone(),
+            privileged: self.privileged.clone(),
+            user: self.user.clone(),
+            working_dir: self.working_dir.clone(),
+        }
+    }
+}
+/// Builder of [`ExecInspectParams`].
+#[derive(Clone, Debug)]
+pub struct ExecInspectParamsBuilder {
+    id: String,
+}
+impl ExecInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ExecInspectParams {
+        ExecInspectParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ExecResizeParams`].
+#[derive(Clone, Debug)]
+pub struct ExecResizeParamsBuilder {
+    id: String,
+    h: i32,
+    w: i32,
+}
+impl ExecResizeParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, h: impl Into<i32>, w: impl Into<i32>) -> Self {
+        Self {
+            id: id.into(),
+            h: h.into(),
+            w: w.into(),
+        }
+    }
+    pub fn build(&self) -> ExecResizeParams {
+        ExecResizeParams {
+            id: self.id.clone(),
+            h: self.h.clone(),
+            w: self.w.clone(),
+        }
+    }
+}
+/// Builder of [`ExecStartConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct ExecStartConfigBuilder {
+    detach: Option<bool>,
+    tty: Option<bool>,
+    console_size: Option<Option<Vec<u32>>>,
+}
+impl ExecStartConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Detach from the command.
+    pub fn detach(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.detach = Some(value.into());
+        self
+    }
+    /// Allocate a pseudo-TTY.
+    pub fn tty(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.tty = Some(value.into());
+        self
+    }
+    /// Initial console size, as an `[height, width]` array.
+    pub fn console_size(&mut self, value: impl Into<Option<Vec<u32>>>) -> &mut Self {
+        self.console_size = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ExecStartConfig {
+        ExecStartConfig {
+            detach: self.detach.clone(),
+            tty: self.tty.clone(),
+            console_size: self.console_size.clone(),
+        }
+    }
+}
+/// Builder of [`ExecStartParams`].
+#[derive(Clone, Debug)]
+pub struct ExecStartParamsBuilder {
+    id: String,
+    exec_start_config: Option<ExecStartConfig>,
+}
+impl ExecStartParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            exec_start_config: None,
+        }
+    }
+    pub fn exec_start_config(&mut self, value: impl Into<ExecStartConfig>) -> &mut Self {
+        self.exec_start_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ExecStartParams {
+        ExecStartParams {
+            id: self.id.clone(),
+            exec_start_config: self.exec_start_config.clone(),
+        }
+    }
+}
+/// Builder of [`GenericResourcesInner`].
+#[derive(Clone, Debug, Default)]
+pub struct GenericResourcesInnerBuilder {
+    named_resource_spec: Option<Box<GenericResourcesInnerNamedResourceSpec>>,
+    discrete_resource_spec: Option<Box<GenericResourcesInnerDiscreteResourceSpec>>,
+}
+impl GenericResourcesInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn named_resource_spec(
+        &mut self,
+        value: impl Into<Box<GenericResourcesInnerNamedResourceSpec>>,
+    ) -> &mut Self {
+        self.named_resource_spec = Some(value.into());
+        self
+    }
+    pub fn discrete_resource_spec(
+        &mut self,
+        value: impl Into<Box<GenericResourcesInnerDiscreteResourceSpec>>,
+    ) -> &mut Self {
+        self.discrete_resource_spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> GenericResourcesInner {
+        GenericResourcesInner {
+            named_resource_spec: self.named_resource_spec.clone(),
+            discrete_resource_spec: self.discrete_resource_spec.clone(),
+        }
+    }
+}
+/// Builder of [`GenericResourcesInnerDiscreteResourceSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct GenericResourcesInnerDiscreteResourceSpecBuilder {
+    kind: Option<String>,
+    value: Option<i64>,
+}
+impl GenericResourcesInnerDiscreteResourceSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn kind(&mut self, value: impl Into<String>) -> &mut Self {
+        self.kind = Some(value.into());
+        self
+    }
+    pub fn value(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.value = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> GenericResourcesInnerDiscreteResourceSpec {
+        GenericResourcesInnerDiscreteResourceSpec {
+            kind: self.kind.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+/// Builder of [`GenericResourcesInnerNamedResourceSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct GenericResourcesInnerNamedResourceSpecBuilder {
+    kind: Option<String>,
+    value: Option<String>,
+}
+impl GenericResourcesInnerNamedResourceSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn kind(&mut self, value: impl Into<String>) -> &mut Self {
+        self.kind = Some(value.into());
+        self
+    }
+    pub fn value(&mut self, value: impl Into<String>) -> &mut Self {
+        self.value = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> GenericResourcesInnerNamedResourceSpec {
+        GenericResourcesInnerNamedResourceSpec {
+            kind: self.kind.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+/// Builder of [`GetPluginPrivilegesParams`].
+#[derive(Clone, Debug)]
+pub struct GetPluginPrivilegesParamsBuilder {
+    remote: String,
+}
+impl GetPluginPrivilegesParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(remote: impl Into<String>) -> Self {
+        Self { remote: remote.into() }
+    }
+    pub fn build(&self) -> GetPluginPrivilegesParams {
+        GetPluginPrivilegesParams {
+            remote: self.remote.clone(),
+        }
+    }
+}
+/// Builder of [`HealthConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct HealthConfigBuilder {
+    test: Option<Vec<String>>,
+    interval: Option<i64>,
+    timeout: Option<i64>,
+    retries: Option<i32>,
+    start_period: Option<i64>,
+    start_interval: Option<i64>,
+}
+impl HealthConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The test to perform. Possible values are:  - `[]` inherit healthcheck from image or parent image - `[\"NONE\"]` disable healthcheck - `[\"CMD\", args...]` exec arguments directly - `[\"CMD-SHELL\", command]` run command with system's default shell
+    pub fn test(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.test = Some(value.into());
+        self
+    }
+    /// The time to wait between checks in nanoseconds. It should be 0 or at least 1000000 (1 ms). 0 means inherit.
+    pub fn interval(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.interval = Some(value.into());
+        self
+    }
+    /// The time to wait before considering the check to have hung. It should be 0 or at least 1000000 (1 ms). 0 means inherit.
+    pub fn timeout(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.timeout = Some(value.into());
+        self
+    }
+    /// The number of consecutive failures needed to consider a container as unhealthy. 0 means inherit.
+    pub fn retries(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.retries = Some(value.into());
+        self
+    }
+    /// Start period for the container to initialize before starting health-retries countdown in nanoseconds. It should be 0 or at least 1000000 (1 ms). 0 means inherit.
+    pub fn start_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.start_period = Some(value.into());
+        self
+    }
+    /// The time to wait between checks in nanoseconds during the start period. It should be 0 or at least 1000000 (1 ms). 0 means inherit.
+    pub fn start_interval(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.start_interval = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> HealthConfig {
+        HealthConfig {
+            test: self.test.clone(),
+            interval: self.interval.clone(),
+            timeout: self.timeout.clone(),
+            retries: self.retries.clone(),
+            start_period: self.start_period.clone(),
+            start_interval: self.start_interval.clone(),
+        }
+    }
+}
+/// Builder of [`HostConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct HostConfigBuilder {
+    cpu_shares: Option<i32>,
+    memory: Option<i64>,
+    cgroup_parent: Option<String>,
+    blkio_weight: Option<u32>,
+    blkio_weight_device: Option<Vec<ResourcesBlkioWeightDeviceInner>>,
+    blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+    blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+    blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
+    blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
+    cpu_period: Option<i64>,
+    cpu_quota: Option<i64>,
+    cpu_realtime_period: Option<i64>,
+    cpu_realtime_runtime: Option<i64>,
+    cpuset_cpus: Option<String>,
+    cpuset_mems: Option<String>,
+    devices: Option<Vec<DeviceMapping>>,
+    device_cgroup_rules: Option<Vec<String>>,
+    device_requests: Option<Vec<DeviceRequest>>,
+    kernel_memory_tcp: Option<i64>,
+    memory_reservation: Option<i64>,
+    memory_swap: Option<i64>,
+    memory_swappiness: Option<u64>,
+    nano_cpus: Option<i64>,
+    oom_kill_disable: Option<bool>,
+    init: Option<Option<bool>>,
+    pids_limit: Option<Option<i64>>,
+    ulimits: Option<Vec<ResourcesUlimitsInner>>,
+    cpu_count: Option<i64>,
+    cpu_percent: Option<i64>,
+    io_maximum_i_ops: Option<i64>,
+    io_maximum_bandwidth: Option<i64>,
+    binds: Option<Vec<String>>,
+    container_id_file: Option<String>,
+    log_config: Option<Box<HostConfigAllOfLogConfig>>,
+    network_mode: Option<String>,
+    port_bindings: Option<std::collections::HashMap<String, Vec<PortBinding>>>,
+    restart_policy: Option<Box<RestartPolicy>>,
+    auto_remove: Option<bool>,
+    volume_driver: Option<String>,
+    volumes_from: Option<Vec<String>>,
+    mounts: Option<Vec<Mount>>,
+    console_size: Option<Option<Vec<u32>>>,
+    annotations: Option<std::collections::HashMap<String, String>>,
+    cap_add: Option<Vec<String>>,
+    cap_drop: Option<Vec<String>>,
+    cgroupns_mode: Option<HostConfigCgroupnsMode>,
+    dns: Option<Vec<String>>,
+    dns_options: Option<Vec<String>>,
+    dns_search: Option<Vec<String>>,
+    extra_hosts: Option<Vec<String>>,
+    group_add: Option<Vec<String>>,
+    ipc_mode: Option<String>,
+    cgroup: Option<String>,
+    links: Option<Vec<String>>,
+    oom_score_adj: Option<i32>,
+    pid_mode: Option<String>,
+    privileged: Option<bool>,
+    publish_all_ports: Option<bool>,
+    readonly_rootfs: Option<bool>,
+    security_opt: Option<Vec<String>>,
+    storage_opt: Option<std::collections::HashMap<String, String>>,
+    tmpfs: Option<std::collections::HashMap<String, String>>,
+    uts_mode: Option<String>,
+    userns_mode: Option<String>,
+    shm_size: Option<u64>,
+    sysctls: Option<std::collections::HashMap<String, String>>,
+    runtime: Option<String>,
+    isolation: Option<HostConfigIsolation>,
+    masked_paths: Option<Vec<String>>,
+    readonly_paths: Option<Vec<String>>,
+}
+impl HostConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// An integer value representing this container's relative CPU weight versus other containers.
+    pub fn cpu_shares(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.cpu_shares = Some(value.into());
+        self
+    }
+    /// Memory limit in bytes.
+    pub fn memory(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory = Some(value.into());
+        self
+    }
+    /// Path to `cgroups` under which the container's `cgroup` is created. If the path is not absolute, the path is considered to be relative to the `cgroups` path of the init process. Cgroups are created if they do not already exist.
+    pub fn cgroup_parent(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cgroup_parent = Some(value.into());
+        self
+    }
+    /// Block IO weight (relative weight).
+    pub fn blkio_weight(&mut self, value: impl Into<u32>) -> &mut Self {
+        self.blkio_weight = Some(value.into());
+        self
+    }
+    /// Block IO weight (relative device weight) in the form:  ``` [{\"Path\": \"device_path\", \"Weight\": weight}] ```
+    pub fn blkio_weight_device(
+        &mut self,
+        value: impl Into<Vec<ResourcesBlkioWeightDeviceInner>>,
+    ) -> &mut Self {
+        self.blkio_weight_device = Some(value.into());
+        self
+    }
+    /// Limit read rate (bytes per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_read_bps(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_read_bps = Some(value.into());
+        self
+    }
+    /// Limit write rate (bytes per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_write_bps(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_write_bps = Some(value.into());
+        self
+    }
+    /// Limit read rate (IO per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_read_i_ops(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_read_i_ops = Some(value.into());
+        self
+    }
+    /// Limit write rate (IO per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
+    pub fn blkio_device_write_i_ops(
+        &mut self,
+        value: impl Into<Vec<ThrottleDevice>>,
+    ) -> &mut Self {
+        self.blkio_device_write_i_ops = Some(value.into());
+        self
+    }
+    /// The length of a CPU period in microseconds.
+    pub fn cpu_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_period = Some(value.into());
+        self
+    }
+    /// Microseconds of CPU time that the container can get in a CPU period.
+    pub fn cpu_quota(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_quota = Some(value.into());
+        self
+    }
+    /// The length of a CPU real-time period in microseconds. Set to 0 to allocate no time allocated to real-time tasks.
+    pub fn cpu_realtime_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_realtime_period = Some(value.into());
+        self
+    }
+    /// The length of a CPU real-time runtime in microseconds. Set to 0 to allocate no time allocated to real-time tasks.
+    pub fn cpu_realtime_runtime(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_realtime_runtime = Some(value.into());
+        self
+    }
+    /// CPUs in which to allow execution (e.g., `0-3`, `0,1`).
+    pub fn cpuset_cpus(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cpuset_cpus = Some(value.into());
+        self
+    }
+    /// Memory nodes (MEMs) in which to allow execution (0-3, 0,1). Only effective on NUMA systems.
+    pub fn cpuset_mems(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cpuset_mems = Some(value.into());
+        self
+    }
+    /// A list of devices to add to the container.
+    pub fn devices(&mut self, value: impl Into<Vec<DeviceMapping>>) -> &mut Self {
+        self.devices = Some(value.into());
+        self
+    }
+    /// a list of cgroup rules to apply to the container
+    pub fn device_cgroup_rules(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.device_cgroup_rules = Some(value.into());
+        self
+    }
+    /// A list of requests for devices to be sent to device drivers.
+    pub fn device_requests(
+        &mut self,
+        value: impl Into<Vec<DeviceRequest>>,
+    ) -> &mut Self {
+        self.device_requests = Some(value.into());
+        self
+    }
+    /// Hard limit for kernel TCP buffer memory (in bytes). Depending on the OCI runtime in use, this option may be ignored. It is no longer supported by the default (runc) runtime.  This field is omitted when empty.
+    pub fn kernel_memory_tcp(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.kernel_memory_tcp = Some(value.into());
+        self
+    }
+    /// Memory soft limit in bytes.
+    pub fn memory_reservation(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_reservation = Some(value.into());
+        self
+    }
+    /// Total memory limit (memory + swap). Set as `-1` to enable unlimited swap.
+    pub fn memory_swap(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_swap = Some(value.into());
+        self
+    }
+    /// Tune a container's memory swappiness behavior. Accepts an integer between 0 and 100.
+    pub fn memory_swappiness(&mut self, value: impl Into<u64>) -> &mut Self {
+        self.memory_swappiness = Some(value.into());
+        self
+    }
+    /// CPU quota in units of 10<sup>-9</sup> CPUs.
+    pub fn nano_cpus(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.nano_cpus = Some(value.into());
+        self
+    }
+    /// Disable OOM Killer for the container.
+    pub fn oom_kill_disable(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.oom_kill_disable = Some(value.into());
+        self
+    }
+    /// Run an init inside the container that forwards signals and reaps processes. This field is omitted if empty, and the default (as configured on the daemon) is used.
+    pub fn init(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.init = Some(value.into());
+        self
+    }
+    /// Tune a container's PIDs limit. Set `0` or `-1` for unlimited, or `null` to not change.
+    pub fn pids_limit(&mut self, value: impl Into<Option<i64>>) -> &mut Self {
+        self.pids_limit = Some(value.into());
+        self
+    }
+    /// A list of resource limits to set in the container. For example:  ``` {\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048} ```
+    pub fn ulimits(
+        &mut self,
+        value: impl Into<Vec<ResourcesUlimitsInner>>,
+    ) -> &mut Self {
+        self.ulimits = Some(value.into());
+        self
+    }
+    /// The number of usable CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
+    pub fn cpu_count(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_count = Some(value.into());
+        self
+    }
+    /// The usable percentage of the available CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
+    pub fn cpu_percent(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.cpu_percent = Some(value.into());
+        self
+    }
+    /// Maximum IOps for the container system drive (Windows only)
+    pub fn io_maximum_i_ops(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.io_maximum_i_ops = Some(value.into());
+        self
+    }
+    /// Maximum IO in bytes per second for the container system drive (Windows only).
+    pub fn io_maximum_bandwidth(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.io_maximum_bandwidth = Some(value.into());
+        self
+    }
+    /// A list of volume bindings for this container. Each volume binding is a string in one of these forms:  - `host-src:container-dest[:options]` to bind-mount a host path   into the container. Both `host-src`, and `container-dest` must   be an _absolute_ path. - `volume-name:container-dest[:options]` to bind-mount a volume   managed by a volume driver into the container. `container-dest`   must be an _absolute_ path.  `options` is an optional, comma-delimited list of:  - `nocopy` disables automatic copying of data from the container   path to the volume. The `nocopy` flag only applies to named volumes. - `[ro|rw]` mounts a volume read-only or read-write, respectively.   If omitted or set to `rw`, volumes are mounted read-write. - `[z|Z]` applies SELinux labels to allow or deny multiple containers   to read and write to the same volume.     - `z`: a _shared_ content label is applied to the content. This       label indicates that multiple containers can share the volume       content, for both reading and writing.     - `Z`: a _private unshared_ label is applied to the content.       This label indicates that only the current container can use       a private volume. Labeling systems such as SELinux require       proper labels to be placed on volume content that is mounted       into a container. Without a label, the security system can       prevent a container's processes from using the content. By       default, the labels set by the host operating system are not       modified. - `[[r]shared|[r]slave|[r]private]` specifies mount   [propagation behavior](https://www.kernel.org/doc/Documentation/filesystems/sharedsubtree.txt).   This only applies to bind-mounted volumes, not internal volumes   or named volumes. Mount propagation requires the source mount   point (the location where the source directory is mounted in the   host operating system) to have the correct propagation properties.   For shared volumes, the source mount point must be set to `shared`.   For slave volumes, the mount must be set to either `shared` or   `slave`.
+    pub fn binds(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.binds = Some(value.into());
+        self
+    }
+    /// Path to a file where the container ID is written
+    pub fn container_id_file(&mut self, value: impl Into<String>) -> &mut Self {
+        self.container_id_file = Some(value.into());
+        self
+    }
+    pub fn log_config(
+        &mut self,
+        value: impl Into<Box<HostConfigAllOfLogConfig>>,
+    ) -> &mut Self {
+        self.log_config = Some(value.into());
+        self
+    }
+    /// Network mode to use for this container. Supported standard values are: `bridge`, `host`, `none`, and `container:<name|id>`. Any other value is taken as a custom network's name to which this container should connect to.
+    pub fn network_mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.network_mode = Some(value.into());
+        self
+    }
+    /// PortMap describes the mapping of container ports to host ports, using the container's port-number and protocol as key in the format `<port>/<protocol>`, for example, `80/udp`.  If a container's port is mapped for multiple protocols, separate entries are added to the mapping table.
+    pub fn port_bindings(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, Vec<PortBinding>>>,
+    ) -> &mut Self {
+        self.port_bindings = Some(value.into());
+        self
+    }
+    pub fn restart_policy(&mut self, value: impl Into<Box<RestartPolicy>>) -> &mut Self {
+        self.restart_policy = Some(value.into());
+        self
+    }
+    /// Automatically remove the container when the container's process exits. This has no effect if `RestartPolicy` is set.
+    pub fn auto_remove(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.auto_remove = Some(value.into());
+        self
+    }
+    /// Driver that this container uses to mount volumes.
+    pub fn volume_driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.volume_driver = Some(value.into());
+        self
+    }
+    /// A list of volumes to inherit from another container, specified in the form `<container name>[:<ro|rw>]`.
+    pub fn volumes_from(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.volumes_from = Some(value.into());
+        self
+    }
+    /// Specification for mounts to be added to the container.
+    pub fn mounts(&mut self, value: impl Into<Vec<Mount>>) -> &mut Self {
+        self.mounts = Some(value.into());
+        self
+    }
+    /// Initial console size, as an `[height, width]` array.
+    pub fn console_size(&mut self, value: impl Into<Option<Vec<u32>>>) -> &mut Self {
+        self.console_size = Some(value.into());
+        self
+    }
+    /// Arbitrary non-identifying metadata attached to container and provided to the runtime when the container is started.
+    pub fn annotations(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.annotations = Some(value.into());
+        self
+    }
+    /// A list of kernel capabilities to add to the container. Conflicts with option 'Capabilities'.
+    pub fn cap_add(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.cap_add = Some(value.into());
+        self
+    }
+    /// A list of kernel capabilities to drop from the container. Conflicts with option 'Capabilities'.
+    pub fn cap_drop(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.cap_drop = Some(value.into());
+        self
+    }
+    /// cgroup namespace mode for the container. Possible values are:  - `\"private\"`: the container runs in its own private cgroup namespace - `\"host\"`: use the host system's cgroup namespace  If not specified, the daemon default is used, which can either be `\"private\"` or `\"host\"`, depending on daemon version, kernel support and configuration.
+    pub fn cgroupns_mode(
+        &mut self,
+        value: impl Into<HostConfigCgroupnsMode>,
+    ) -> &mut Self {
+        self.cgroupns_mode = Some(value.into());
+        self
+    }
+    /// A list of DNS servers for the container to use.
+    pub fn dns(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.dns = Some(value.into());
+        self
+    }
+    /// A list of DNS options.
+    pub fn dns_options(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.dns_options = Some(value.into());
+        self
+    }
+    /// A list of DNS search domains.
+    pub fn dns_search(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.dns_search = Some(value.into());
+        self
+    }
+    /// A list of hostnames/IP mappings to add to the container's `/etc/hosts` file. Specified in the form `[\"hostname:IP\"]`.
+    pub fn extra_hosts(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.extra_hosts = Some(value.into());
+        self
+    }
+    /// A list of additional groups that the container process will run as.
+    pub fn group_add(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.group_add = Some(value.into());
+        self
+    }
+    /// IPC sharing mode for the container. Possible values are:  - `\"none\"`: own private IPC namespace, with /dev/shm not mounted - `\"private\"`: own private IPC namespace - `\"shareable\"`: own private IPC namespace, with a possibility to share it with other containers - `\"container:<name|id>\"`: join another (shareable) container's IPC namespace - `\"host\"`: use the host system's IPC namespace  If not specified, daemon default is used, which can either be `\"private\"` or `\"shareable\"`, depending on daemon version and configuration.
+    pub fn ipc_mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ipc_mode = Some(value.into());
+        self
+    }
+    /// Cgroup to use for the container.
+    pub fn cgroup(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cgroup = Some(value.into());
+        self
+    }
+    /// A list of links for the container in the form `container_name:alias`.
+    pub fn links(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.links = Some(value.into());
+        self
+    }
+    /// An integer value containing the score given to the container in order to tune OOM killer preferences.
+    pub fn oom_score_adj(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+    /// Set the PID (Process) Namespace mode for the container. It can be either:  - `\"container:<name|id>\"`: joins another container's PID namespace - `\"host\"`: use the host's PID namespace inside the container
+    pub fn pid_mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.pid_mode = Some(value.into());
+        self
+    }
+    /// Gives the container full access to the host.
+    pub fn privileged(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.privileged = Some(value.into());
+        self
+    }
+    /// Allocates an ephemeral host port for all of a container's exposed ports.  Ports are de-allocated when the container stops and allocated when the container starts. The allocated port might be changed when restarting the container.  The port is selected from the ephemeral port range that depends on the kernel. For example, on Linux the range is defined by `/proc/sys/net/ipv4/ip_local_port_range`.
+    pub fn publish_all_ports(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.publish_all_ports = Some(value.into());
+        self
+    }
+    /// Mount the container's root filesystem as read only.
+    pub fn readonly_rootfs(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.readonly_rootfs = Some(value.into());
+        self
+    }
+    /// A list of string values to customize labels for MLS systems, such as SELinux.
+    pub fn security_opt(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.security_opt = Some(value.into());
+        self
+    }
+    /// Storage driver options for this container, in the form `{\"size\": \"120G\"}`.
+    pub fn storage_opt(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.storage_opt = Some(value.into());
+        self
+    }
+    /// A map of container directories which should be replaced by tmpfs mounts, and their corresponding mount options. For example:  ``` { \"/run\": \"rw,noexec,nosuid,size=65536k\" } ```
+    pub fn tmpfs(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.tmpfs = Some(value.into());
+        self
+    }
+    /// UTS namespace to use for the container.
+    pub fn uts_mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.uts_mode = Some(value.into());
+        self
+    }
+    /// Sets the usernamespace mode for the container when usernamespace remapping option is enabled.
+    pub fn userns_mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.userns_mode = Some(value.into());
+        self
+    }
+    /// Size of `/dev/shm` in bytes. If omitted, the system uses 64MB.
+    pub fn shm_size(&mut self, value: impl Into<u64>) -> &mut Self {
+        self.shm_size = Some(value.into());
+        self
+    }
+    /// A list of kernel parameters (sysctls) to set in the container. For example:  ``` {\"net.ipv4.ip_forward\": \"1\"} ```
+    pub fn sysctls(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.sysctls = Some(value.into());
+        self
+    }
+    /// Runtime to use with this container.
+    pub fn runtime(&mut self, value: impl Into<String>) -> &mut Self {
+        self.runtime = Some(value.into());
+        self
+    }
+    /// Isolation technology of the container. (Windows only)
+    pub fn isolation(&mut self, value: impl Into<HostConfigIsolation>) -> &mut Self {
+        self.isolation = Some(value.into());
+        self
+    }
+    /// The list of paths to be masked inside the container (this overrides the default set of paths).
+    pub fn masked_paths(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.masked_paths = Some(value.into());
+        self
+    }
+    /// The list of paths to be set as read-only inside the container (this overrides the default set of paths).
+    pub fn readonly_paths(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.readonly_paths = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> HostConfig {
+        HostConfig {
+            cpu_shares: self.cpu_shares.clone(),
+            memory: self.memory.clone(),
+            cgroup_parent: self.cgroup_parent.clone(),
+            blkio_weight: self.blkio_weight.clone(),
+            blkio_weight_device: self.blkio_weight_device.clone(),
+            blkio_device_read_bps: self.blkio_device_read_bps.clone(),
+            blkio_device_write_bps: self.blkio_device_write_bps.clone(),
+            blkio_device_read_i_ops: self.blkio_device_read_i_ops.clone(),
+            blkio_device_write_i_ops: self.blkio_device_write_i_ops.clone(),
+            cpu_period: self.cpu_period.clone(),
+            cpu_quota: self.cpu_quota.clone(),
+            cpu_realtime_period: self.cpu_realtime_period.clone(),
+            cpu_realtime_runtime: self.cpu_realtime_runtime.clone(),
+            cpuset_cpus: self.cpuset_cpus.clone(),
+            cpuset_mems: self.cpuset_mems.clone(),
+            devices: self.devices.clone(),
+            device_cgroup_rules: self.device_cgroup_rules.clone(),
+            device_requests: self.device_requests.clone(),
+            kernel_memory_tcp: self.kernel_memory_tcp.clone(),
+            memory_reservation: self.memory_reservation.clone(),
+            memory_swap: self.memory_swap.clone(),
+            memory_swappiness: self.memory_swappiness.clone(),
+            nano_cpus: self.nano_cpus.clone(),
+            oom_kill_disable: self.oom_kill_disable.clone(),
+            init: self.init.clone(),
+            pids_limit: self.pids_limit.clone(),
+            ulimits: self.ulimits.clone(),
+            cpu_count: self.cpu_count.clone(),
+            cpu_percent: self.cpu_percent.clone(),
+            io_maximum_i_ops: self.io_maximum_i_ops.clone(),
+            io_maximum_bandwidth: self.io_maximum_bandwidth.clone(),
+            binds: self.binds.clone(),
+            container_id_file: self.container_id_file.clone(),
+            log_config: self.log_config.clone(),
+            network_mode: self.network_mode.clone(),
+            port_bindings: self.port_bindings.clone(),
+            restart_policy: self.restart_policy.clone(),
+            auto_remove: self.auto_remove.clone(),
+            volume_driver: self.volume_driver.clone(),
+            volumes_from: self.volumes_from.clone(),
+            mounts: self.mounts.clone(),
+            console_size: self.console_size.clone(),
+            annotations: self.annotations.clone(),
+            cap_add: self.cap_add.clone(),
+            cap_drop: self.cap_drop.clone(),
+            cgroupns_mode: self.cgroupns_mode.clone(),
+            dns: self.dns.clone(),
+            dns_options: self.dns_options.clone(),
+            dns_search: self.dns_search.clone(),
+            extra_hosts: self.extra_hosts.clone(),
+            group_add: self.group_add.clone(),
+            ipc_mode: self.ipc_mode.clone(),
+            cgroup: self.cgroup.clone(),
+            links: self.links.clone(),
+            oom_score_adj: self.oom_score_adj.clone(),
+            pid_mode: self.pid_mode.clone(),
+            privileged: self.privileged.clone(),
+            publish_all_ports: self.publish_all_ports.clone(),
+            readonly_rootfs: self.readonly_rootfs.clone(),
+            security_opt: self.security_opt.clone(),
+            storage_opt: self.storage_opt.clone(),
+            tmpfs: self.tmpfs.clone(),
+            uts_mode: self.uts_mode.clone(),
+            userns_mode: self.userns_mode.clone(),
+            shm_size: self.shm_size.clone(),
+            sysctls: self.sysctls.clone(),
+            runtime: self.runtime.clone(),
+            isolation: self.isolation.clone(),
+            masked_paths: self.masked_paths.clone(),
+            readonly_paths: self.readonly_paths.clone(),
+        }
+    }
+}
+/// Builder of [`HostConfigAllOfLogConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct HostConfigAllOfLogConfigBuilder {
+    r#type: Option<HostConfigAllOfLogConfigType>,
+    config: Option<std::collections::HashMap<String, String>>,
+}
+impl HostConfigAllOfLogConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn r#type(
+        &mut self,
+        value: impl Into<HostConfigAllOfLogConfigType>,
+    ) -> &mut Self {
+        self.r#type = Some(value.into());
+        self
+    }
+    pub fn config(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> HostConfigAllOfLogConfig {
+        HostConfigAllOfLogConfig {
+            r#type: self.r#type.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+/// Builder of [`ImageBuildParams`].
+#[derive(Clone, Debug)]
+pub struct ImageBuildParamsBuilder {
+    dockerfile: Option<String>,
+    t: Option<String>,
+    extrahosts: Option<String>,
+    remote: Option<String>,
+    q: Option<bool>,
+    nocache: Option<bool>,
+    cachefrom: Option<String>,
+    pull: Option<String>,
+    rm: Option<bool>,
+    forcerm: Option<bool>,
+    memory: Option<i32>,
+    memswap: Option<i32>,
+    cpushares: Option<i32>,
+    cpusetcpus: Option<String>,
+    cpuperiod: Option<i32>,
+    cpuquota: Option<i32>,
+    buildargs: Option<String>,
+    shmsize: Option<i32>,
+    squash: Option<bool>,
+    labels: Option<String>,
+    networkmode: Option<String>,
+    content_type: Option<String>,
+    x_registry_config: Option<String>,
+    platform: Option<String>,
+    target: Option<String>,
+    outputs: Option<String>,
+    version: Option<String>,
+    input_stream: Bytes,
+}
+impl ImageBuildParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(input_stream: impl Into<Bytes>) -> Self {
+        Self {
+            input_stream: input_stream.into(),
+            dockerfile: None,
+            t: None,
+            extrahosts: None,
+            remote: None,
+            q: None,
+            nocache: None,
+            cachefrom: None,
+            pull: None,
+            rm: None,
+            forcerm: None,
+            memory: None,
+            memswap: None,
+            cpushares: None,
+            cpusetcpus: None,
+            cpuperiod: None,
+            cpuquota: None,
+            buildargs: None,
+            shmsize: None,
+            squash: None,
+            labels: None,
+            networkmode: None,
+            content_type: None,
+            x_registry_config: None,
+            platform: None,
+            target: None,
+            outputs: None,
+            version: None,
+        }
+    }
+    /// Path within the build context to the `Dockerfile`. This is ignored if `remote` is specified and points to an external `Dockerfile`.
+    pub fn dockerfile(&mut self, value: impl Into<String>) -> &mut Self {
+        self.dockerfile = Some(value.into());
+        self
+    }
+    /// A name and optional tag to apply to the image in the `name:tag` format. If you omit the tag the default `latest` value is assumed. You can provide several `t` parameters.
+    pub fn t(&mut self, value: ImageReference) -> &mut Self {
+        self.t = Some(value.into());
+        self
+    }
+    /// Extra hosts to add to /etc/hosts
+    pub fn extrahosts(&mut self, value: impl Into<String>) -> &mut Self {
+        self.extrahosts = Some(value.into());
+        self
+    }
+    /// A Git repository URI or HTTP/HTTPS context URI. If the URI points to a single text file, the file’s contents are placed into a file called `Dockerfile` and the image is built from that file. If the URI points to a tarball, the file is downloaded by the daemon and the contents therein used as the context for the build. If the URI points to a tarball and the `dockerfile` parameter is also specified, there must be a file with the corresponding path inside the tarball.
+    pub fn remote(&mut self, value: impl Into<String>) -> &mut Self {
+        self.remote = Some(value.into());
+        self
+    }
+    /// Suppress verbose build output.
+    pub fn q(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.q = Some(value.into());
+        self
+    }
+    /// Do not use the cache when building the image.
+    pub fn nocache(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.nocache = Some(value.into());
+        self
+    }
+    /// JSON array of images used for build cache resolution.
+    pub fn cachefrom(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cachefrom = Some(value.into());
+        self
+    }
+    /// Attempt to pull the image even if an older image exists locally.
+    pub fn pull(&mut self, value: impl Into<String>) -> &mut Self {
+        self.pull = Some(value.into());
+        self
+    }
+    /// Remove intermediate containers after a successful build.
+    pub fn rm(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.rm = Some(value.into());
+        self
+    }
+    /// Always remove intermediate containers, even upon failure.
+    pub fn forcerm(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.forcerm = Some(value.into());
+        self
+    }
+    /// Set memory limit for build.
+    pub fn memory(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.memory = Some(value.into());
+        self
+    }
+    /// Total memory (memory + swap). Set as `-1` to disable swap.
+    pub fn memswap(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.memswap = Some(value.into());
+        self
+    }
+    /// CPU shares (relative weight).
+    pub fn cpushares(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.cpushares = Some(value.into());
+        self
+    }
+    /// CPUs in which to allow execution (e.g., `0-3`, `0,1`).
+    pub fn cpusetcpus(&mut self, value: impl Into<String>) -> &mut Self {
+        self.cpusetcpus = Some(value.into());
+        self
+    }
+    /// The length of a CPU period in microseconds.
+    pub fn cpuperiod(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.cpuperiod = Some(value.into());
+        self
+    }
+    /// Microseconds of CPU time that the container can get in a CPU period.
+    pub fn cpuquota(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.cpuquota = Some(value.into());
+        self
+    }
+    /// JSON map of string pairs for build-time variables. Users pass these values at build-time. Docker uses the buildargs as the environment context for commands run via the `Dockerfile` RUN instruction, or for variable expansion in other `Dockerfile` instructions. This is not meant for passing secret values.  For example, the build arg `FOO=bar` would become `{\"FOO\":\"bar\"}` in JSON. This would result in the query parameter `buildargs={\"FOO\":\"bar\"}`. Note that `{\"FOO\":\"bar\"}` should be URI component encoded.  [Read more about the buildargs instruction.](https://docs.docker.com/engine/reference/builder/#arg)
+    pub fn buildargs(&mut self, value: impl Into<String>) -> &mut Self {
+        self.buildargs = Some(value.into());
+        self
+    }
+    /// Size of `/dev/shm` in bytes. The size must be greater than 0. If omitted the system uses 64MB.
+    pub fn shmsize(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.shmsize = Some(value.into());
+        self
+    }
+    /// Squash the resulting images layers into a single layer. *(Experimental release only.)*
+    pub fn squash(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.squash = Some(value.into());
+        self
+    }
+    /// Arbitrary key/value labels to set on the image, as a JSON map of string pairs.
+    pub fn labels(&mut self, value: impl Into<String>) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Sets the networking mode for the run commands during build. Supported standard values are: `bridge`, `host`, `none`, and `container:<name|id>`. Any other value is taken as a custom network's name or ID to which this container should connect to.
+    pub fn networkmode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.networkmode = Some(value.into());
+        self
+    }
+    pub fn content_type(&mut self, value: impl Into<String>) -> &mut Self {
+        self.content_type = Some(value.into());
+        self
+    }
+    /// This is a base64-encoded JSON object with auth configurations for multiple registries that a build may refer to.  The key is a registry URL, and the value is an auth configuration object, [as described in the authentication section](#section/Authentication). For example:  ``` {   \"docker.example.com\": {     \"username\": \"janedoe\",     \"password\": \"hunter2\"   },   \"https://index.docker.io/v1/\": {     \"username\": \"mobydock\",     \"password\": \"conta1n3rize14\"   } } ```  Only the registry domain name (and port if not the default 443) are required. However, for legacy reasons, the Docker Hub registry must be specified with both a `https://` prefix and a `/v1/` suffix even though Docker will prefer to use the v2 registry API.
+    pub fn x_registry_config(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_config = Some(value.into());
+        self
+    }
+    /// Platform in the format os[/arch[/variant]]
+    pub fn platform(&mut self, value: impl Into<String>) -> &mut Self {
+        self.platform = Some(value.into());
+        self
+    }
+    /// Target build stage
+    pub fn target(&mut self, value: impl Into<String>) -> &mut Self {
+        self.target = Some(value.into());
+        self
+    }
+    /// BuildKit output configuration
+    pub fn outputs(&mut self, value: impl Into<String>) -> &mut Self {
+        self.outputs = Some(value.into());
+        self
+    }
+    /// Version of the builder backend to use.  - `1` is the first generation classic (deprecated) builder in the Docker daemon (default) - `2` is [BuildKit](https://github.com/moby/buildkit)
+    pub fn version(&mut self, value: impl Into<String>) -> &mut Self {
+        self.version = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageBuildParams {
+        ImageBuildParams {
+            dockerfile: self.dockerfile.clone(),
+            t: self.t.clone(),
+            extrahosts: self.extrahosts.clone(),
+            remote: self.remote.clone(),
+            q: self.q.clone(),
+            nocache: self.nocache.clone(),
+            cachefrom: self.cachefrom.clone(),
+            pull: self.pull.clone(),
+            rm: self.rm.clone(),
+            forcerm: self.forcerm.clone(),
+            memory: self.memory.clone(),
+            memswap: self.memswap.clone(),
+            cpushares: self.cpushares.clone(),
+            cpusetcpus: self.cpusetcpus.clone(),
+            cpuperiod: self.cpuperiod.clone(),
+            cpuquota: self.cpuquota.clone(),
+            buildargs: self.buildargs.clone(),
+            shmsize: self.shmsize.clone(),
+            squash: self.squash.clone(),
+            labels: self.labels.clone(),
+            networkmode: self.networkmode.clone(),
+            content_type: self.content_type.clone(),
+            x_registry_config: self.x_registry_config.clone(),
+            platform: self.platform.clone(),
+            target: self.target.clone(),
+            outputs: self.outputs.clone(),
+            version: self.version.clone(),
+            input_stream: self.input_stream.clone(),
+        }
+    }
+}
+/// Builder of [`ImageCommitParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ImageCommitParamsBuilder {
+    container: Option<String>,
+    repo: Option<String>,
+    tag: Option<String>,
+    comment: Option<String>,
+    author: Option<String>,
+    pause: Option<bool>,
+    changes: Option<String>,
+    container_config: Option<ContainerConfig>,
+}
+impl ImageCommitParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The ID or name of the container to commit
+    pub fn container(&mut self, value: impl Into<String>) -> &mut Self {
+        self.container = Some(value.into());
+        self
+    }
+    /// Repository name for the created image
+    pub fn repo(&mut self, value: impl Into<String>) -> &mut Self {
+        self.repo = Some(value.into());
+        self
+    }
+    /// Tag name for the create image
+    pub fn tag(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tag = Some(value.into());
+        self
+    }
+    /// Commit message
+    pub fn comment(&mut self, value: impl Into<String>) -> &mut Self {
+        self.comment = Some(value.into());
+        self
+    }
+    /// Author of the image (e.g., `John Hannibal Smith <hannibal@a-team.com>`)
+    pub fn author(&mut self, value: impl Into<String>) -> &mut Self {
+        self.author = Some(value.into());
+        self
+    }
+    /// Whether to pause the container before committing
+    pub fn pause(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.pause = Some(value.into());
+        self
+    }
+    /// `Dockerfile` instructions to apply while committing
+    pub fn changes(&mut self, value: impl Into<String>) -> &mut Self {
+        self.changes = Some(value.into());
+        self
+    }
+    /// The container configuration
+    pub fn container_config(&mut self, value: impl Into<ContainerConfig>) -> &mut Self {
+        self.container_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageCommitParams {
+        ImageCommitParams {
+            container: self.container.clone(),
+            repo: self.repo.clone(),
+            tag: self.tag.clone(),
+            comment: self.comment.clone(),
+            author: self.author.clone(),
+            pause: self.pause.clone(),
+            changes: self.changes.clone(),
+            container_config: self.container_config.clone(),
+        }
+    }
+}
+/// Builder of [`ImageCreateParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ImageCreateParamsBuilder {
+    from_image: Option<String>,
+    from_src: Option<String>,
+    repo: Option<String>,
+    tag: Option<String>,
+    message: Option<String>,
+    x_registry_auth: Option<String>,
+    changes: Option<Vec<String>>,
+    platform: Option<String>,
+    input_image: Option<String>,
+}
+impl ImageCreateParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the image to pull. The name may include a tag or digest. This parameter may only be used when pulling an image. The pull is cancelled if the HTTP connection is closed.
+    pub fn from_image(&mut self, value: ImageReference) -> &mut Self {
+        self.from_image = Some(value.into());
+        self
+    }
+    /// Source to import. The value may be a URL from which the image can be retrieved or `-` to read the image from the request body. This parameter may only be used when importing an image.
+    pub fn from_src(&mut self, value: impl Into<String>) -> &mut Self {
+        self.from_src = Some(value.into());
+        self
+    }
+    /// Repository name given to an image when it is imported. The repo may include a tag. This parameter may only be used when importing an image.
+    pub fn repo(&mut self, value: impl Into<String>) -> &mut Self {
+        self.repo = Some(value.into());
+        self
+    }
+    /// Tag or digest. If empty when pulling an image, this causes all tags for the given image to be pulled.
+    pub fn tag(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tag = Some(value.into());
+        self
+    }
+    /// Set commit message for imported image.
+    pub fn message(&mut self, value: impl Into<String>) -> &mut Self {
+        self.message = Some(value.into());
+        self
+    }
+    /// A base64url-encoded auth configuration.  Refer to the [authentication section](#section/Authentication) for details.
+    pub fn x_registry_auth(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_auth = Some(value.into());
+        self
+    }
+    /// Apply `Dockerfile` instructions to the image that is created, for example: `changes=ENV DEBUG=true`. Note that `ENV DEBUG=true` should be URI component encoded.  Supported `Dockerfile` instructions: `CMD`|`ENTRYPOINT`|`ENV`|`EXPOSE`|`ONBUILD`|`USER`|`VOLUME`|`WORKDIR`
+    pub fn changes(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.changes = Some(value.into());
+        self
+    }
+    /// Platform in the format os[/arch[/variant]].  When used in combination with the `fromImage` option, the daemon checks if the given image is present in the local image cache with the given OS and Architecture, and otherwise attempts to pull the image. If the option is not set, the host's native OS and Architecture are used. If the given image does not exist in the local image cache, the daemon attempts to pull the image with the host's native OS and Architecture. If the given image does exists in the local image cache, but its OS or architecture does not match, a warning is produced.  When used with the `fromSrc` option to import an image from an archive, this option sets the platform information for the imported image. If the option is not set, the host's native OS and Architecture are used for the imported image.
+    pub fn platform(&mut self, value: impl Into<String>) -> &mut Self {
+        self.platform = Some(value.into());
+        self
+    }
+    /// Image content if the value `-` has been specified in fromSrc query parameter
+    pub fn input_image(&mut self, value: impl Into<String>) -> &mut Self {
+        self.input_image = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageCreateParams {
+        ImageCreateParams {
+            from_image: self.from_image.clone(),
+            from_src: self.from_src.clone(),
+            repo: self.repo.clone(),
+            tag: self.tag.clone(),
+            message: self.message.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+            changes: self.changes.clone(),
+            platform: self.platform.clone(),
+            input_image: self.input_image.clone(),
+        }
+    }
+}
+/// Builder of [`ImageDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct ImageDeleteParamsBuilder {
+    name: String,
+    force: Option<bool>,
+    noprune: Option<bool>,
+}
+impl ImageDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference) -> Self {
+        Self {
+            name: name.into(),
+            force: None,
+            noprune: None,
+        }
+    }
+    /// Remove the image even if it is being used by stopped containers or has other tags
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    /// Do not delete untagged parent images
+    pub fn noprune(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.noprune = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageDeleteParams {
+        ImageDeleteParams {
+            name: self.name.clone(),
+            force: self.force.clone(),
+            noprune: self.noprune.clone(),
+        }
+    }
+}
+/// Builder of [`ImageGetAllParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ImageGetAllParamsBuilder {
+    names: Option<Vec<String>>,
+}
+impl ImageGetAllParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Image names to filter by
+    pub fn names(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.names = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageGetAllParams {
+        ImageGetAllParams {
+            names: self.names.clone(),
+        }
+    }
+}
+/// Builder of [`ImageGetParams`].
+#[derive(Clone, Debug)]
+pub struct ImageGetParamsBuilder {
+    name: String,
+}
+impl ImageGetParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> ImageGetParams {
+        ImageGetParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`ImageHistoryParams`].
+#[derive(Clone, Debug)]
+pub struct ImageHistoryParamsBuilder {
+    name: String,
+}
+impl ImageHistoryParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> ImageHistoryParams {
+        ImageHistoryParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`ImageInspectParams`].
+#[derive(Clone, Debug)]
+pub struct ImageInspectParamsBuilder {
+    name: String,
+}
+impl ImageInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> ImageInspectParams {
+        ImageInspectParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`ImageListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ImageListParamsBuilder {
+    all: Option<bool>,
+    filters: Option<String>,
+    shared_size: Option<bool>,
+    digests: Option<bool>,
+    manifests: Option<bool>,
+}
+impl ImageListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Show all images. Only images from a final layer (no children) are shown by default.
+    pub fn all(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.all = Some(value.into());
+        self
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list.  Available filters:  - `before`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`) - `dangling=true` - `label=key` or `label=\"key=value\"` of an image label - `reference`=(`<image-name>[:<tag>]`) - `since`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`) - `until=<timestamp>`
+    pub fn filters(&mut self, value: ImageListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    /// Compute and show shared size as a `SharedSize` field on each image.
+    pub fn shared_size(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.shared_size = Some(value.into());
+        self
+    }
+    /// Show digest information as a `RepoDigests` field on each image.
+    pub fn digests(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.digests = Some(value.into());
+        self
+    }
+    /// Include `Manifests` in the image summary.
+    pub fn manifests(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.manifests = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageListParams {
+        ImageListParams {
+            all: self.all.clone(),
+            filters: self.filters.clone(),
+            shared_size: self.shared_size.clone(),
+            digests: self.digests.clone(),
+            manifests: self.manifests.clone(),
+        }
+    }
+}
+/// Builder of [`ImageLoadParams`].
+#[derive(Clone, Debug)]
+pub struct ImageLoadParamsBuilder {
+    quiet: Option<bool>,
+    images_tarball: Bytes,
+}
+impl ImageLoadParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(images_tarball: impl Into<Bytes>) -> Self {
+        Self {
+            images_tarball: images_tarball.into(),
+            quiet: None,
+        }
+    }
+    /// Suppress progress details during load.
+    pub fn quiet(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.quiet = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageLoadParams {
+        ImageLoadParams {
+            quiet: self.quiet.clone(),
+            images_tarball: self.images_tarball.clone(),
+        }
+    }
+}
+/// Builder of [`ImagePruneParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ImagePruneParamsBuilder {
+    filters: Option<String>,
+}
+impl ImagePruneParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`). Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), prune only    unused *and* untagged images. When set to `false`    (or `0`), all unused images are pruned. - `until=<string>` Prune images created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune images with (or without, in case `label!=...` is used) the specified labels.
+    pub fn filters(&mut self, value: ImagePruneFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImagePruneParams {
+        ImagePruneParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ImagePushParams`].
+#[derive(Clone, Debug)]
+pub struct ImagePushParamsBuilder {
+    name: String,
+    x_registry_auth: String,
+    tag: Option<String>,
+    platform: Option<String>,
+}
+impl ImagePushParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: ImageReference, x_registry_auth: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            x_registry_auth: x_registry_auth.into(),
+            tag: None,
+            platform: None,
+        }
+    }
+    /// Tag of the image to push. For example, `latest`. If no tag is provided, all tags of the given image that are present in the local image store are pushed.
+    pub fn tag(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tag = Some(value.into());
+        self
+    }
+    /// JSON-encoded OCI platform to select the platform-variant to push. If not provided, all available variants will attempt to be pushed.  If the daemon provides a multi-platform image store, this selects the platform-variant to push to the registry. If the image is a single-platform image, or if the multi-platform image does not provide a variant matching the given platform, an error is returned.  Example: `{\"os\": \"linux\", \"architecture\": \"arm\", \"variant\": \"v5\"}`
+    pub fn platform(&mut self, value: impl Into<String>) -> &mut Self {
+        self.platform = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImagePushParams {
+        ImagePushParams {
+            name: self.name.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+            tag: self.tag.clone(),
+            platform: self.platform.clone(),
+        }
+    }
+}
+/// Builder of [`ImageSearchParams`].
+#[derive(Clone, Debug)]
+pub struct ImageSearchParamsBuilder {
+    term: String,
+    limit: Option<i32>,
+    filters: Option<String>,
+}
+impl ImageSearchParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(term: impl Into<String>) -> Self {
+        Self {
+            term: term.into(),
+            limit: None,
+            filters: None,
+        }
+    }
+    /// Maximum number of results to return
+    pub fn limit(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.limit = Some(value.into());
+        self
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list. Available filters:  - `is-official=(true|false)` - `stars=<number>` Matches images that has at least 'number' stars.
+    pub fn filters(&mut self, value: ImageSearchFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageSearchParams {
+        ImageSearchParams {
+            term: self.term.clone(),
+            limit: self.limit.clone(),
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`ImageTagParams`].
+#[derive(Clone, Debug)]
+pub struct ImageTagParamsBuilder {
+    name: String,
+    repo: Option<String>,
+    tag: Option<String>,
+}
+impl ImageTagParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            repo: None,
+            tag: None,
+        }
+    }
+    /// The repository to tag in. For example, `someuser/someimage`.
+    pub fn repo(&mut self, value: impl Into<String>) -> &mut Self {
+        self.repo = Some(value.into());
+        self
+    }
+    /// The name of the new tag.
+    pub fn tag(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tag = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ImageTagParams {
+        ImageTagParams {
+            name: self.name.clone(),
+            repo: self.repo.clone(),
+            tag: self.tag.clone(),
+        }
+    }
+}
+/// Builder of [`Ipam`].
+#[derive(Clone, Debug, Default)]
+pub struct IpamBuilder {
+    driver: Option<String>,
+    config: Option<Vec<IpamConfig>>,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl IpamBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the IPAM driver to use.
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    /// List of IPAM configuration options, specified as a map:  ``` {\"Subnet\": <CIDR>, \"IPRange\": <CIDR>, \"Gateway\": <IP address>, \"AuxAddress\": <device_name:IP address>} ```
+    pub fn config(&mut self, value: impl Into<Vec<IpamConfig>>) -> &mut Self {
+        self.config = Some(value.into());
+        self
+    }
+    /// Driver-specific options, specified as a map.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> Ipam {
+        Ipam {
+            driver: self.driver.clone(),
+            config: self.config.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`IpamConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct IpamConfigBuilder {
+    subnet: Option<String>,
+    ip_range: Option<String>,
+    gateway: Option<String>,
+    auxiliary_addresses: Option<std::collections::HashMap<String, String>>,
+}
+impl IpamConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn subnet(&mut self, value: impl Into<String>) -> &mut Self {
+        self.subnet = Some(value.into());
+        self
+    }
+    pub fn ip_range(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ip_range = Some(value.into());
+        self
+    }
+    pub fn gateway(&mut self, value: impl Into<String>) -> &mut Self {
+        self.gateway = Some(value.into());
+        self
+    }
+    pub fn auxiliary_addresses(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.auxiliary_addresses = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> IpamConfig {
+        IpamConfig {
+            subnet: self.subnet.clone(),
+            ip_range: self.ip_range.clone(),
+            gateway: self.gateway.clone(),
+            auxiliary_addresses: self.auxiliary_addresses.clone(),
+        }
+    }
+}
+/// Builder of [`Limit`].
+#[derive(Clone, Debug, Default)]
+pub struct LimitBuilder {
+    nano_cpus: Option<i64>,
+    memory_bytes: Option<i64>,
+    pids: Option<i64>,
+}
+impl LimitBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn nano_cpus(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.nano_cpus = Some(value.into());
+        self
+    }
+    pub fn memory_bytes(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_bytes = Some(value.into());
+        self
+    }
+    /// Limits the maximum number of PIDs in the container. Set `0` for unlimited.
+    pub fn pids(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.pids = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> Limit {
+        Limit {
+            nano_cpus: self.nano_cpus.clone(),
+            memory_bytes: self.memory_bytes.clone(),
+            pids: self.pids.clone(),
+        }
+    }
+}
+/// Builder of [`Mount`].
+#[derive(Clone, Debug, Default)]
+pub struct MountBuilder {
+    target: Option<String>,
+    source: Option<String>,
+    r#type: Option<MountType>,
+    read_only: Option<bool>,
+    consistency: Option<String>,
+    bind_options: Option<Box<MountBindOptions>>,
+    volume_options: Option<Box<MountVolumeOptions>>,
+    tmpfs_options: Option<Box<MountTmpfsOptions>>,
+}
+impl MountBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Container path.
+    pub fn target(&mut self, value: impl Into<String>) -> &mut Self {
+        self.target = Some(value.into());
+        self
+    }
+    /// Mount source (e.g. a volume name, a host path).
+    pub fn source(&mut self, value: impl Into<String>) -> &mut Self {
+        self.source = Some(value.into());
+        self
+    }
+    /// The mount type. Available types:  - `bind` Mounts a file or directory from the host into the container. Must exist prior to creating the container. - `volume` Creates a volume with the given name and options (or uses a pre-existing volume with the same name and options). These are **not** removed when the container is removed. - `tmpfs` Create a tmpfs with the given options. The mount source cannot be specified for tmpfs. - `npipe` Mounts a named pipe from the host into the container. Must exist prior to creating the container. - `cluster` a Swarm cluster volume
+    pub fn r#type(&mut self, value: impl Into<MountType>) -> &mut Self {
+        self.r#type = Some(value.into());
+        self
+    }
+    /// Whether the mount should be read-only.
+    pub fn read_only(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.read_only = Some(value.into());
+        self
+    }
+    /// The consistency requirement for the mount: `default`, `consistent`, `cached`, or `delegated`.
+    pub fn consistency(&mut self, value: impl Into<String>) -> &mut Self {
+        self.consistency = Some(value.into());
+        self
+    }
+    pub fn bind_options(
+        &mut self,
+        value: impl Into<Box<MountBindOptions>>,
+    ) -> &mut Self {
+        self.bind_options = Some(value.into());
+        self
+    }
+    pub fn volume_options(
+        &mut self,
+        value: impl Into<Box<MountVolumeOptions>>,
+    ) -> &mut Self {
+        self.volume_options = Some(value.into());
+        self
+    }
+    pub fn tmpfs_options(
+        &mut self,
+        value: impl Into<Box<MountTmpfsOptions>>,
+    ) -> &mut Self {
+        self.tmpfs_options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> Mount {
+        Mount {
+            target: self.target.clone(),
+            source: self.source.clone(),
+            r#type: self.r#type.clone(),
+            read_only: self.read_only.clone(),
+            consistency: self.consistency.clone(),
+            bind_options: self.bind_options.clone(),
+            volume_options: self.volume_options.clone(),
+            tmpfs_options: self.tmpfs_options.clone(),
+        }
+    }
+}
+/// Builder of [`MountBindOptions`].
+#[derive(Clone, Debug, Default)]
+pub struct MountBindOptionsBuilder {
+    propagation: Option<MountBindOptionsPropagation>,
+    non_recursive: Option<bool>,
+    create_mountpoint: Option<bool>,
+    read_only_non_recursive: Option<bool>,
+    read_only_force_recursive: Option<bool>,
+}
+impl MountBindOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A propagation mode with the value `[r]private`, `[r]shared`, or `[r]slave`.
+    pub fn propagation(
+        &mut self,
+        value: impl Into<MountBindOptionsPropagation>,
+    ) -> &mut Self {
+        self.propagation = Some(value.into());
+        self
+    }
+    /// Disable recursive bind mount.
+    pub fn non_recursive(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.non_recursive = Some(value.into());
+        self
+    }
+    /// Create mount point on host if missing
+    pub fn create_mountpoint(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.create_mountpoint = Some(value.into());
+        self
+    }
+    /// Make the mount non-recursively read-only, but still leave the mount recursive (unless NonRecursive is set to `true` in conjunction).  Added in v1.44, before that version all read-only mounts were non-recursive by default. To match the previous behaviour this will default to `true` for clients on versions prior to v1.44.
+    pub fn read_only_non_recursive(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.read_only_non_recursive = Some(value.into());
+        self
+    }
+    /// Raise an error if the mount cannot be made recursively read-only.
+    pub fn read_only_force_recursive(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.read_only_force_recursive = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> MountBindOptions {
+        MountBindOptions {
+            propagation: self.propagation.clone(),
+            non_recursive: self.non_recursive.clone(),
+            create_mountpoint: self.create_mountpoint.clone(),
+            read_only_non_recursive: self.read_only_non_recursive.clone(),
+            read_only_force_recursive: self.read_only_force_recursive.clone(),
+        }
+    }
+}
+/// Builder of [`MountTmpfsOptions`].
+#[derive(Clone, Debug, Default)]
+pub struct MountTmpfsOptionsBuilder {
+    size_bytes: Option<i64>,
+    mode: Option<i32>,
+    options: Option<Vec<Vec<String>>>,
+}
+impl MountTmpfsOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The size for the tmpfs mount in bytes.
+    pub fn size_bytes(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.size_bytes = Some(value.into());
+        self
+    }
+    /// The permission mode for the tmpfs mount in an integer.
+    pub fn mode(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    /// The options to be passed to the tmpfs mount. An array of arrays. Flag options should be provided as 1-length arrays. Other types should be provided as as 2-length arrays, where the first item is the key and the second the value.
+    pub fn options(&mut self, value: impl Into<Vec<Vec<String>>>) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> MountTmpfsOptions {
+        MountTmpfsOptions {
+            size_bytes: self.size_bytes.clone(),
+            mode: self.mode.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`MountVolumeOptions`].
+#[derive(Clone, Debug, Default)]
+pub struct MountVolumeOptionsBuilder {
+    no_copy: Option<bool>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    driver_config: Option<Box<MountVolumeOptionsDriverConfig>>,
+    subpath: Option<String>,
+}
+impl MountVolumeOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Populate volume with data from the target.
+    pub fn no_copy(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.no_copy = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn driver_config(
+        &mut self,
+        value: impl Into<Box<MountVolumeOptionsDriverConfig>>,
+    ) -> &mut Self {
+        self.driver_config = Some(value.into());
+        self
+    }
+    /// Source path inside the volume. Must be relative without any back traversals.
+    pub fn subpath(&mut self, value: impl Into<String>) -> &mut Self {
+        self.subpath = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> MountVolumeOptions {
+        MountVolumeOptions {
+            no_copy: self.no_copy.clone(),
+            labels: self.labels.clone(),
+            driver_config: self.driver_config.clone(),
+            subpath: self.subpath.clone(),
+        }
+    }
+}
+/// Builder of [`MountVolumeOptionsDriverConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct MountVolumeOptionsDriverConfigBuilder {
+    name: Option<String>,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl MountVolumeOptionsDriverConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the driver to use to create the volume.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// key/value map of driver specific options.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> MountVolumeOptionsDriverConfig {
+        MountVolumeOptionsDriverConfig {
+            name: self.name.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkAttachmentConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkAttachmentConfigBuilder {
+    target: Option<String>,
+    aliases: Option<Vec<String>>,
+    driver_opts: Option<std::collections::HashMap<String, String>>,
+}
+impl NetworkAttachmentConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The target network for attachment. Must be a network name or ID.
+    pub fn target(&mut self, value: impl Into<String>) -> &mut Self {
+        self.target = Some(value.into());
+        self
+    }
+    /// Discoverable alternate names for the service on this network.
+    pub fn aliases(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.aliases = Some(value.into());
+        self
+    }
+    /// Driver attachment options for the network target.
+    pub fn driver_opts(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.driver_opts = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkAttachmentConfig {
+        NetworkAttachmentConfig {
+            target: self.target.clone(),
+            aliases: self.aliases.clone(),
+            driver_opts: self.driver_opts.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkConnectParams`].
+#[derive(Clone, Debug)]
+pub struct NetworkConnectParamsBuilder {
+    id: String,
+    container: NetworkConnectRequest,
+}
+impl NetworkConnectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<String>,
+        container: impl Into<NetworkConnectRequest>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            container: container.into(),
+        }
+    }
+    pub fn build(&self) -> NetworkConnectParams {
+        NetworkConnectParams {
+            id: self.id.clone(),
+            container: self.container.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkConnectRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkConnectRequestBuilder {
+    container: Option<String>,
+    endpoint_config: Option<Box<EndpointSettings>>,
+}
+impl NetworkConnectRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The ID or name of the container to connect to the network.
+    pub fn container(&mut self, value: impl Into<String>) -> &mut Self {
+        self.container = Some(value.into());
+        self
+    }
+    pub fn endpoint_config(
+        &mut self,
+        value: impl Into<Box<EndpointSettings>>,
+    ) -> &mut Self {
+        self.endpoint_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkConnectRequest {
+        NetworkConnectRequest {
+            container: self.container.clone(),
+            endpoint_config: self.endpoint_config.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkCreateParams`].
+#[derive(Clone, Debug)]
+pub struct NetworkCreateParamsBuilder {
+    network_config: NetworkCreateRequest,
+}
+impl NetworkCreateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(network_config: impl Into<NetworkCreateRequest>) -> Self {
+        Self {
+            network_config: network_config.into(),
+        }
+    }
+    pub fn build(&self) -> NetworkCreateParams {
+        NetworkCreateParams {
+            network_config: self.network_config.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkCreateRequest`].
+#[derive(Clone, Debug)]
+pub struct NetworkCreateRequestBuilder {
+    name: String,
+    driver: Option<String>,
+    scope: Option<String>,
+    internal: Option<bool>,
+    attachable: Option<bool>,
+    ingress: Option<bool>,
+    config_only: Option<bool>,
+    config_from: Option<Box<ConfigReference>>,
+    ipam: Option<Box<Ipam>>,
+    enable_ipv4: Option<bool>,
+    enable_ipv6: Option<bool>,
+    options: Option<std::collections::HashMap<String, String>>,
+    labels: Option<std::collections::HashMap<String, String>>,
+}
+impl NetworkCreateRequestBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            driver: None,
+            scope: None,
+            internal: None,
+            attachable: None,
+            ingress: None,
+            config_only: None,
+            config_from: None,
+            ipam: None,
+            enable_ipv4: None,
+            enable_ipv6: None,
+            options: None,
+            labels: None,
+        }
+    }
+    /// Name of the network driver plugin to use.
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    /// The level at which the network exists (e.g. `swarm` for cluster-wide or `local` for machine level).
+    pub fn scope(&mut self, value: impl Into<String>) -> &mut Self {
+        self.scope = Some(value.into());
+        self
+    }
+    /// Restrict external access to the network.
+    pub fn internal(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.internal = Some(value.into());
+        self
+    }
+    /// Globally scoped network is manually attachable by regular containers from workers in swarm mode.
+    pub fn attachable(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.attachable = Some(value.into());
+        self
+    }
+    /// Ingress network is the network which provides the routing-mesh in swarm mode.
+    pub fn ingress(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.ingress = Some(value.into());
+        self
+    }
+    /// Creates a config-only network. Config-only networks are placeholder networks for network configurations to be used by other networks. Config-only networks cannot be used directly to run containers or services.
+    pub fn config_only(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.config_only = Some(value.into());
+        self
+    }
+    pub fn config_from(&mut self, value: impl Into<Box<ConfigReference>>) -> &mut Self {
+        self.config_from = Some(value.into());
+        self
+    }
+    pub fn ipam(&mut self, value: impl Into<Box<Ipam>>) -> &mut Self {
+        self.ipam = Some(value.into());
+        self
+    }
+    /// Enable IPv4 on the network. To disable IPv4, the daemon must be started with experimental features enabled.
+    pub fn enable_ipv4(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.enable_ipv4 = Some(value.into());
+        self
+    }
+    /// Enable IPv6 on the network.
+    pub fn enable_ipv6(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.enable_ipv6 = Some(value.into());
+        self
+    }
+    /// Network specific options to be used by the drivers.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkCreateRequest {
+        NetworkCreateRequest {
+            name: self.name.clone(),
+            driver: self.driver.clone(),
+            scope: self.scope.clone(),
+            internal: self.internal.clone(),
+            attachable: self.attachable.clone(),
+            ingress: self.ingress.clone(),
+            config_only: self.config_only.clone(),
+            config_from: self.config_from.clone(),
+            ipam: self.ipam.clone(),
+            enable_ipv4: self.enable_ipv4.clone(),
+            enable_ipv6: self.enable_ipv6.clone(),
+            options: self.options.clone(),
+            labels: self.labels.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct NetworkDeleteParamsBuilder {
+    id: String,
+}
+impl NetworkDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> NetworkDeleteParams {
+        NetworkDeleteParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkDisconnectParams`].
+#[derive(Clone, Debug)]
+pub struct NetworkDisconnectParamsBuilder {
+    id: String,
+    container: NetworkDisconnectRequest,
+}
+impl NetworkDisconnectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<String>,
+        container: impl Into<NetworkDisconnectRequest>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            container: container.into(),
+        }
+    }
+    pub fn build(&self) -> NetworkDisconnectParams {
+        NetworkDisconnectParams {
+            id: self.id.clone(),
+            container: self.container.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkDisconnectRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkDisconnectRequestBuilder {
+    container: Option<String>,
+    force: Option<bool>,
+}
+impl NetworkDisconnectRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The ID or name of the container to disconnect from the network.
+    pub fn container(&mut self, value: impl Into<String>) -> &mut Self {
+        self.container = Some(value.into());
+        self
+    }
+    /// Force the container to disconnect from the network.
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkDisconnectRequest {
+        NetworkDisconnectRequest {
+            container: self.container.clone(),
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkInspectParams`].
+#[derive(Clone, Debug)]
+pub struct NetworkInspectParamsBuilder {
+    id: String,
+    verbose: Option<bool>,
+    scope: Option<String>,
+}
+impl NetworkInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            verbose: None,
+            scope: None,
+        }
+    }
+    /// Detailed inspect output for troubleshooting
+    pub fn verbose(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.verbose = Some(value.into());
+        self
+    }
+    /// Filter the network by scope (swarm, global, or local)
+    pub fn scope(&mut self, value: impl Into<String>) -> &mut Self {
+        self.scope = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkInspectParams {
+        NetworkInspectParams {
+            id: self.id.clone(),
+            verbose: self.verbose.clone(),
+            scope: self.scope.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkListParamsBuilder {
+    filters: Option<String>,
+}
+impl NetworkListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the networks list.  Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), returns all    networks that are not in use by a container. When set to `false`    (or `0`), only networks that are in use by one or more    containers are returned. - `driver=<driver-name>` Matches a network's driver. - `id=<network-id>` Matches all or part of a network ID. - `label=<key>` or `label=<key>=<value>` of a network label. - `name=<network-name>` Matches all or part of a network name. - `scope=[\"swarm\"|\"global\"|\"local\"]` Filters networks by scope (`swarm`, `global`, or `local`). - `type=[\"custom\"|\"builtin\"]` Filters networks by type. The `custom` keyword returns all user-defined networks.
+    pub fn filters(&mut self, value: NetworkListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkListParams {
+        NetworkListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkPruneParams`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkPruneParamsBuilder {
+    filters: Option<String>,
+}
+impl NetworkPruneParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `until=<timestamp>` Prune networks created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune networks with (or without, in case `label!=...` is used) the specified labels.
+    pub fn filters(&mut self, value: NetworkPruneFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkPruneParams {
+        NetworkPruneParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`NetworkingConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkingConfigBuilder {
+    endpoints_config: Option<std::collections::HashMap<String, EndpointSettings>>,
+}
+impl NetworkingConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A mapping of network name to endpoint configuration for that network. The endpoint configuration can be left empty to connect to that network with no particular endpoint configuration.
+    pub fn endpoints_config(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, EndpointSettings>>,
+    ) -> &mut Self {
+        self.endpoints_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NetworkingConfig {
+        NetworkingConfig {
+            endpoints_config: self.endpoints_config.clone(),
+        }
+    }
+}
+/// Builder of [`NodeDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct NodeDeleteParamsBuilder {
+    id: String,
+    force: Option<bool>,
+}
+impl NodeDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into(), force: None }
+    }
+    /// Force remove a node from the swarm
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NodeDeleteParams {
+        NodeDeleteParams {
+            id: self.id.clone(),
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`NodeInspectParams`].
+#[derive(Clone, Debug)]
+pub struct NodeInspectParamsBuilder {
+    id: String,
+}
+impl NodeInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> NodeInspectParams {
+        NodeInspectParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`NodeListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct NodeListParamsBuilder {
+    filters: Option<String>,
+}
+impl NodeListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Filters to process on the nodes list, encoded as JSON (a `map[string][]string`).  Available filters: - `id=<node id>` - `label=<engine label>` - `membership=`(`accepted`|`pending`)` - `name=<node name>` - `node.label=<node label>` - `role=`(`manager`|`worker`)`
+    pub fn filters(&mut self, value: NodeListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NodeListParams {
+        NodeListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`NodeSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct NodeSpecBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    role: Option<NodeSpecRole>,
+    availability: Option<NodeSpecAvailability>,
+}
+impl NodeSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name for the node.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Role of the node.
+    pub fn role(&mut self, value: impl Into<NodeSpecRole>) -> &mut Self {
+        self.role = Some(value.into());
+        self
+    }
+    /// Availability of the node.
+    pub fn availability(&mut self, value: impl Into<NodeSpecAvailability>) -> &mut Self {
+        self.availability = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NodeSpec {
+        NodeSpec {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            role: self.role.clone(),
+            availability: self.availability.clone(),
+        }
+    }
+}
+/// Builder of [`NodeUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct NodeUpdateParamsBuilder {
+    id: String,
+    version: i64,
+    body: Option<NodeSpec>,
+}
+impl NodeUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, version: impl Into<i64>) -> Self {
+        Self {
+            id: id.into(),
+            version: version.into(),
+            body: None,
+        }
+    }
+    pub fn body(&mut self, value: impl Into<NodeSpec>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> NodeUpdateParams {
+        NodeUpdateParams {
+            id: self.id.clone(),
+            version: self.version.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`Platform`].
+#[derive(Clone, Debug, Default)]
+pub struct PlatformBuilder {
+    architecture: Option<String>,
+    os: Option<String>,
+}
+impl PlatformBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Architecture represents the hardware architecture (for example, `x86_64`).
+    pub fn architecture(&mut self, value: impl Into<String>) -> &mut Self {
+        self.architecture = Some(value.into());
+        self
+    }
+    /// OS represents the Operating System (for example, `linux` or `windows`).
+    pub fn os(&mut self, value: impl Into<String>) -> &mut Self {
+        self.os = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> Platform {
+        Platform {
+            architecture: self.architecture.clone(),
+            os: self.os.clone(),
+        }
+    }
+}
+/// Builder of [`PluginCreateParams`].
+#[derive(Clone, Debug)]
+pub struct PluginCreateParamsBuilder {
+    name: String,
+    tar_context: Bytes,
+}
+impl PluginCreateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>, tar_context: impl Into<Bytes>) -> Self {
+        Self {
+            name: name.into(),
+            tar_context: tar_context.into(),
+        }
+    }
+    pub fn build(&self) -> PluginCreateParams {
+        PluginCreateParams {
+            name: self.name.clone(),
+            tar_context: self.tar_context.clone(),
+        }
+    }
+}
+/// Builder of [`PluginDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct PluginDeleteParamsBuilder {
+    name: String,
+    force: Option<bool>,
+}
+impl PluginDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            force: None,
+        }
+    }
+    /// Disable the plugin before removing. This may result in issues if the plugin is in use by a container.
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginDeleteParams {
+        PluginDeleteParams {
+            name: self.name.clone(),
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`PluginDisableParams`].
+#[derive(Clone, Debug)]
+pub struct PluginDisableParamsBuilder {
+    name: String,
+    force: Option<bool>,
+}
+impl PluginDisableParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            force: None,
+        }
+    }
+    /// Force disable a plugin even if still in use.
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginDisableParams {
+        PluginDisableParams {
+            name: self.name.clone(),
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`PluginEnableParams`].
+#[derive(Clone, Debug)]
+pub struct PluginEnableParamsBuilder {
+    name: String,
+    timeout: Option<i32>,
+}
+impl PluginEnableParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            timeout: None,
+        }
+    }
+    /// Set the HTTP client timeout (in seconds)
+    pub fn timeout(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.timeout = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginEnableParams {
+        PluginEnableParams {
+            name: self.name.clone(),
+            timeout: self.timeout.clone(),
+        }
+    }
+}
+/// Builder of [`PluginInspectParams`].
+#[derive(Clone, Debug)]
+pub struct PluginInspectParamsBuilder {
+    name: String,
+}
+impl PluginInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> PluginInspectParams {
+        PluginInspectParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`PluginListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct PluginListParamsBuilder {
+    filters: Option<String>,
+}
+impl PluginListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the plugin list.  Available filters:  - `capability=<capability name>` - `enable=<true>|<false>`
+    pub fn filters(&mut self, value: PluginListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginListParams {
+        PluginListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`PluginPrivilege`].
+#[derive(Clone, Debug, Default)]
+pub struct PluginPrivilegeBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    value: Option<Vec<String>>,
+}
+impl PluginPrivilegeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    pub fn description(&mut self, value: impl Into<String>) -> &mut Self {
+        self.description = Some(value.into());
+        self
+    }
+    pub fn value(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.value = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginPrivilege {
+        PluginPrivilege {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+/// Builder of [`PluginPullParams`].
+#[derive(Clone, Debug)]
+pub struct PluginPullParamsBuilder {
+    remote: String,
+    name: Option<String>,
+    x_registry_auth: Option<String>,
+    body: Option<Vec<PluginPrivilege>>,
+}
+impl PluginPullParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(remote: impl Into<String>) -> Self {
+        Self {
+            remote: remote.into(),
+            name: None,
+            x_registry_auth: None,
+            body: None,
+        }
+    }
+    /// Local name for the pulled plugin.  The `:latest` tag is optional, and is used as the default if omitted.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// A base64url-encoded auth configuration to use when pulling a plugin from a registry.  Refer to the [authentication section](#section/Authentication) for details.
+    pub fn x_registry_auth(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_auth = Some(value.into());
+        self
+    }
+    pub fn body(&mut self, value: impl Into<Vec<PluginPrivilege>>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginPullParams {
+        PluginPullParams {
+            remote: self.remote.clone(),
+            name: self.name.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`PluginPushParams`].
+#[derive(Clone, Debug)]
+pub struct PluginPushParamsBuilder {
+    name: String,
+}
+impl PluginPushParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> PluginPushParams {
+        PluginPushParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`PluginSetParams`].
+#[derive(Clone, Debug)]
+pub struct PluginSetParamsBuilder {
+    name: String,
+    body: Option<Vec<String>>,
+}
+impl PluginSetParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            body: None,
+        }
+    }
+    pub fn body(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginSetParams {
+        PluginSetParams {
+            name: self.name.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`PluginUpgradeParams`].
+#[derive(Clone, Debug)]
+pub struct PluginUpgradeParamsBuilder {
+    name: String,
+    remote: String,
+    x_registry_auth: Option<String>,
+    body: Option<Vec<PluginPrivilege>>,
+}
+impl PluginUpgradeParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>, remote: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            remote: remote.into(),
+            x_registry_auth: None,
+            body: None,
+        }
+    }
+    /// A base64url-encoded auth configuration to use when pulling a plugin from a registry.  Refer to the [authentication section](#section/Authentication) for details.
+    pub fn x_registry_auth(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_auth = Some(value.into());
+        self
+    }
+    pub fn body(&mut self, value: impl Into<Vec<PluginPrivilege>>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PluginUpgradeParams {
+        PluginUpgradeParams {
+            name: self.name.clone(),
+            remote: self.remote.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`PortBinding`].
+#[derive(Clone, Debug, Default)]
+pub struct PortBindingBuilder {
+    host_ip: Option<String>,
+    host_port: Option<String>,
+}
+impl PortBindingBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Host IP address that the container's port is mapped to.
+    pub fn host_ip(&mut self, value: impl Into<String>) -> &mut Self {
+        self.host_ip = Some(value.into());
+        self
+    }
+    /// Host port number that the container's port is mapped to.
+    pub fn host_port(&mut self, value: impl Into<String>) -> &mut Self {
+        self.host_port = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PortBinding {
+        PortBinding {
+            host_ip: self.host_ip.clone(),
+            host_port: self.host_port.clone(),
+        }
+    }
+}
+/// Builder of [`PutContainerArchiveParams`].
+#[derive(Clone, Debug)]
+pub struct PutContainerArchiveParamsBuilder {
+    id: String,
+    path: String,
+    input_stream: Bytes,
+    no_overwrite_dir_non_dir: Option<String>,
+    copy_uidgid: Option<String>,
+}
+impl PutContainerArchiveParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<String>,
+        path: impl Into<String>,
+        input_stream: impl Into<Bytes>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            path: path.into(),
+            input_stream: input_stream.into(),
+            no_overwrite_dir_non_dir: None,
+            copy_uidgid: None,
+        }
+    }
+    /// If `1`, `true`, or `True` then it will be an error if unpacking the given content would cause an existing directory to be replaced with a non-directory and vice versa.
+    pub fn no_overwrite_dir_non_dir(&mut self, value: impl Into<String>) -> &mut Self {
+        self.no_overwrite_dir_non_dir = Some(value.into());
+        self
+    }
+    /// If `1`, `true`, then it will copy UID/GID maps to the dest file or dir
+    pub fn copy_uidgid(&mut self, value: impl Into<String>) -> &mut Self {
+        self.copy_uidgid = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> PutContainerArchiveParams {
+        PutContainerArchiveParams {
+            id: self.id.clone(),
+            path: self.path.clone(),
+            input_stream: self.input_stream.clone(),
+            no_overwrite_dir_non_dir: self.no_overwrite_dir_non_dir.clone(),
+            copy_uidgid: self.copy_uidgid.clone(),
+        }
+    }
+}
+/// Builder of [`ResourceObject`].
+#[derive(Clone, Debug, Default)]
+pub struct ResourceObjectBuilder {
+    nano_cpus: Option<i64>,
+    memory_bytes: Option<i64>,
+    generic_resources: Option<Vec<GenericResourcesInner>>,
+}
+impl ResourceObjectBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn nano_cpus(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.nano_cpus = Some(value.into());
+        self
+    }
+    pub fn memory_bytes(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.memory_bytes = Some(value.into());
+        self
+    }
+    /// User-defined resources can be either Integer resources (e.g, `SSD=3`) or String resources (e.g, `GPU=UUID1`).
+    pub fn generic_resources(
+        &mut self,
+        value: impl Into<Vec<GenericResourcesInner>>,
+    ) -> &mut Self {
+        self.generic_resources = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ResourceObject {
+        ResourceObject {
+            nano_cpus: self.nano_cpus.clone(),
+            memory_bytes: self.memory_bytes.clone(),
+            generic_resources: self.generic_resources.clone(),
+        }
+    }
+}
+/// Builder of [`ResourcesBlkioWeightDeviceInner`].
+#[derive(Clone, Debug, Default)]
+pub struct ResourcesBlkioWeightDeviceInnerBuilder {
+    path: Option<String>,
+    weight: Option<u32>,
+}
+impl ResourcesBlkioWeightDeviceInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn path(&mut self, value: impl Into<String>) -> &mut Self {
+        self.path = Some(value.into());
+        self
+    }
+    pub fn weight(&mut self, value: impl Into<u32>) -> &mut Self {
+        self.weight = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ResourcesBlkioWeightDeviceInner {
+        ResourcesBlkioWeightDeviceInner {
+            path: self.path.clone(),
+            weight: self.weight.clone(),
+        }
+    }
+}
+/// Builder of [`ResourcesUlimitsInner`].
+#[derive(Clone, Debug, Default)]
+pub struct ResourcesUlimitsInnerBuilder {
+    name: Option<String>,
+    soft: Option<i32>,
+    hard: Option<i32>,
+}
+impl ResourcesUlimitsInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of ulimit
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// Soft limit
+    pub fn soft(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.soft = Some(value.into());
+        self
+    }
+    /// Hard limit
+    pub fn hard(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.hard = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ResourcesUlimitsInner {
+        ResourcesUlimitsInner {
+            name: self.name.clone(),
+            soft: self.soft.clone(),
+            hard: self.hard.clone(),
+        }
+    }
+}
+/// Builder of [`RestartPolicy`].
+#[derive(Clone, Debug, Default)]
+pub struct RestartPolicyBuilder {
+    name: Option<RestartPolicyName>,
+    maximum_retry_count: Option<i32>,
+}
+impl RestartPolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// - Empty string means not to restart - `no` Do not automatically restart - `always` Always restart - `unless-stopped` Restart always except when the user has manually stopped the container - `on-failure` Restart only when the container exit code is non-zero
+    pub fn name(&mut self, value: impl Into<RestartPolicyName>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// If `on-failure` is used, the number of times to retry before giving up.
+    pub fn maximum_retry_count(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.maximum_retry_count = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> RestartPolicy {
+        RestartPolicy {
+            name: self.name.clone(),
+            maximum_retry_count: self.maximum_retry_count.clone(),
+        }
+    }
+}
+/// Builder of [`SecretCreateParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SecretCreateParamsBuilder {
+    body: Option<SecretCreateRequest>,
+}
+impl SecretCreateParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn body(&mut self, value: impl Into<SecretCreateRequest>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SecretCreateParams {
+        SecretCreateParams {
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`SecretCreateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct SecretCreateRequestBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    data: Option<String>,
+    driver: Option<Box<Driver>>,
+    templating: Option<Box<Driver>>,
+}
+impl SecretCreateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// User-defined name of the secret.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Base64-url-safe-encoded ([RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)) data to store as secret.  This field is only used to _create_ a secret, and is not returned by other endpoints.
+    pub fn data(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data = Some(value.into());
+        self
+    }
+    pub fn driver(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    pub fn templating(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.templating = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SecretCreateRequest {
+        SecretCreateRequest {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            data: self.data.clone(),
+            driver: self.driver.clone(),
+            templating: self.templating.clone(),
+        }
+    }
+}
+/// Builder of [`SecretDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct SecretDeleteParamsBuilder {
+    id: String,
+}
+impl SecretDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> SecretDeleteParams {
+        SecretDeleteParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`SecretInspectParams`].
+#[derive(Clone, Debug)]
+pub struct SecretInspectParamsBuilder {
+    id: String,
+}
+impl SecretInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> SecretInspectParams {
+        SecretInspectParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`SecretListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SecretListParamsBuilder {
+    filters: Option<String>,
+}
+impl SecretListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the secrets list.  Available filters:  - `id=<secret id>` - `label=<key> or label=<key>=value` - `name=<secret name>` - `names=<secret name>`
+    pub fn filters(&mut self, value: SecretListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SecretListParams {
+        SecretListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`SecretSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct SecretSpecBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    data: Option<String>,
+    driver: Option<Box<Driver>>,
+    templating: Option<Box<Driver>>,
+}
+impl SecretSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// User-defined name of the secret.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// Base64-url-safe-encoded ([RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)) data to store as secret.  This field is only used to _create_ a secret, and is not returned by other endpoints.
+    pub fn data(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data = Some(value.into());
+        self
+    }
+    pub fn driver(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    pub fn templating(&mut self, value: impl Into<Box<Driver>>) -> &mut Self {
+        self.templating = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SecretSpec {
+        SecretSpec {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            data: self.data.clone(),
+            driver: self.driver.clone(),
+            templating: self.templating.clone(),
+        }
+    }
+}
+/// Builder of [`SecretUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct SecretUpdateParamsBuilder {
+    id: String,
+    version: i64,
+    body: Option<SecretSpec>,
+}
+impl SecretUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>, version: impl Into<i64>) -> Self {
+        Self {
+            id: id.into(),
+            version: version.into(),
+            body: None,
+        }
+    }
+    /// The spec of the secret to update. Currently, only the Labels field can be updated. All other fields must remain unchanged from the [SecretInspect endpoint](#operation/SecretInspect) response values.
+    pub fn body(&mut self, value: impl Into<SecretSpec>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SecretUpdateParams {
+        SecretUpdateParams {
+            id: self.id.clone(),
+            version: self.version.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceCreateParams`].
+#[derive(Clone, Debug)]
+pub struct ServiceCreateParamsBuilder {
+    body: ServiceCreateRequest,
+    x_registry_auth: Option<String>,
+}
+impl ServiceCreateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(body: impl Into<ServiceCreateRequest>) -> Self {
+        Self {
+            body: body.into(),
+            x_registry_auth: None,
+        }
+    }
+    /// A base64url-encoded auth configuration for pulling from private registries.  Refer to the [authentication section](#section/Authentication) for details.
+    pub fn x_registry_auth(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_auth = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceCreateParams {
+        ServiceCreateParams {
+            body: self.body.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceCreateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceCreateRequestBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    task_template: Option<Box<TaskSpec>>,
+    mode: Option<Box<ServiceSpecMode>>,
+    update_config: Option<Box<ServiceSpecUpdateConfig>>,
+    rollback_config: Option<Box<ServiceSpecRollbackConfig>>,
+    networks: Option<Vec<NetworkAttachmentConfig>>,
+    endpoint_spec: Option<Box<EndpointSpec>>,
+}
+impl ServiceCreateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the service.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn task_template(&mut self, value: impl Into<Box<TaskSpec>>) -> &mut Self {
+        self.task_template = Some(value.into());
+        self
+    }
+    pub fn mode(&mut self, value: impl Into<Box<ServiceSpecMode>>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    pub fn update_config(
+        &mut self,
+        value: impl Into<Box<ServiceSpecUpdateConfig>>,
+    ) -> &mut Self {
+        self.update_config = Some(value.into());
+        self
+    }
+    pub fn rollback_config(
+        &mut self,
+        value: impl Into<Box<ServiceSpecRollbackConfig>>,
+    ) -> &mut Self {
+        self.rollback_config = Some(value.into());
+        self
+    }
+    /// Specifies which networks the service should attach to.  Deprecated: This field is deprecated since v1.44. The Networks field in TaskSpec should be used instead.
+    pub fn networks(
+        &mut self,
+        value: impl Into<Vec<NetworkAttachmentConfig>>,
+    ) -> &mut Self {
+        self.networks = Some(value.into());
+        self
+    }
+    pub fn endpoint_spec(&mut self, value: impl Into<Box<EndpointSpec>>) -> &mut Self {
+        self.endpoint_spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceCreateRequest {
+        ServiceCreateRequest {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            task_template: self.task_template.clone(),
+            mode: self.mode.clone(),
+            update_config: self.update_config.clone(),
+            rollback_config: self.rollback_config.clone(),
+            networks: self.networks.clone(),
+            endpoint_spec: self.endpoint_spec.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct ServiceDeleteParamsBuilder {
+    id: String,
+}
+impl ServiceDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> ServiceDeleteParams {
+        ServiceDeleteParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceInspectParams`].
+#[derive(Clone, Debug)]
+pub struct ServiceInspectParamsBuilder {
+    id: String,
+    insert_defaults: Option<bool>,
+}
+impl ServiceInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            insert_defaults: None,
+        }
+    }
+    /// Fill empty fields with default values.
+    pub fn insert_defaults(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.insert_defaults = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceInspectParams {
+        ServiceInspectParams {
+            id: self.id.clone(),
+            insert_defaults: self.insert_defaults.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceListParamsBuilder {
+    filters: Option<String>,
+    status: Option<bool>,
+}
+impl ServiceListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the services list.  Available filters:  - `id=<service id>` - `label=<service label>` - `mode=[\"replicated\"|\"global\"]` - `name=<service name>`
+    pub fn filters(&mut self, value: ServiceListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    /// Include service status, with count of running and desired tasks.
+    pub fn status(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.status = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceListParams {
+        ServiceListParams {
+            filters: self.filters.clone(),
+            status: self.status.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceLogsParams`].
+#[derive(Clone, Debug)]
+pub struct ServiceLogsParamsBuilder {
+    id: String,
+    details: Option<bool>,
+    follow: Option<bool>,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+    since: Option<i32>,
+    timestamps: Option<bool>,
+    tail: Option<String>,
+}
+impl ServiceLogsParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            details: None,
+            follow: None,
+            stdout: None,
+            stderr: None,
+            since: None,
+            timestamps: None,
+            tail: None,
+        }
+    }
+    /// Show service context and extra details provided to logs.
+    pub fn details(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.details = Some(value.into());
+        self
+    }
+    /// Keep connection after returning logs.
+    pub fn follow(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.follow = Some(value.into());
+        self
+    }
+    /// Return logs from `stdout`
+    pub fn stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdout = Some(value.into());
+        self
+    }
+    /// Return logs from `stderr`
+    pub fn stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stderr = Some(value.into());
+        self
+    }
+    /// Only return logs since this time, as a UNIX timestamp
+    pub fn since(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.since = Some(value.into());
+        self
+    }
+    /// Add timestamps to every log line
+    pub fn timestamps(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.timestamps = Some(value.into());
+        self
+    }
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub fn tail(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tail = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceLogsParams {
+        ServiceLogsParams {
+            id: self.id.clone(),
+            details: self.details.clone(),
+            follow: self.follow.clone(),
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+            since: self.since.clone(),
+            timestamps: self.timestamps.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceSpecMode`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceSpecModeBuilder {
+    replicated: Option<Box<ServiceSpecModeReplicated>>,
+    global: Option<serde_json::Value>,
+    replicated_job: Option<Box<ServiceSpecModeReplicatedJob>>,
+    global_job: Option<serde_json::Value>,
+}
+impl ServiceSpecModeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn replicated(
+        &mut self,
+        value: impl Into<Box<ServiceSpecModeReplicated>>,
+    ) -> &mut Self {
+        self.replicated = Some(value.into());
+        self
+    }
+    pub fn global(&mut self, value: String) -> &mut Self {
+        self.global = Some(value.into());
+        self
+    }
+    pub fn replicated_job(
+        &mut self,
+        value: impl Into<Box<ServiceSpecModeReplicatedJob>>,
+    ) -> &mut Self {
+        self.replicated_job = Some(value.into());
+        self
+    }
+    /// The mode used for services which run a task to the completed state on each valid node.
+    pub fn global_job(&mut self, value: String) -> &mut Self {
+        self.global_job = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceSpecMode {
+        ServiceSpecMode {
+            replicated: self.replicated.clone(),
+            global: self.global.clone(),
+            replicated_job: self.replicated_job.clone(),
+            global_job: self.global_job.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceSpecModeReplicated`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceSpecModeReplicatedBuilder {
+    replicas: Option<i64>,
+}
+impl ServiceSpecModeReplicatedBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn replicas(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.replicas = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceSpecModeReplicated {
+        ServiceSpecModeReplicated {
+            replicas: self.replicas.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceSpecModeReplicatedJob`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceSpecModeReplicatedJobBuilder {
+    max_concurrent: Option<i64>,
+    total_completions: Option<i64>,
+}
+impl ServiceSpecModeReplicatedJobBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The maximum number of replicas to run simultaneously.
+    pub fn max_concurrent(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.max_concurrent = Some(value.into());
+        self
+    }
+    /// The total number of replicas desired to reach the Completed state. If unset, will default to the value of `MaxConcurrent`
+    pub fn total_completions(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.total_completions = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceSpecModeReplicatedJob {
+        ServiceSpecModeReplicatedJob {
+            max_concurrent: self.max_concurrent.clone(),
+            total_completions: self.total_completions.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceSpecRollbackConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceSpecRollbackConfigBuilder {
+    parallelism: Option<i64>,
+    delay: Option<i64>,
+    failure_action: Option<ServiceSpecRollbackConfigFailureAction>,
+    monitor: Option<i64>,
+    max_failure_ratio: Option<f64>,
+    order: Option<ServiceSpecRollbackConfigOrder>,
+}
+impl ServiceSpecRollbackConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Maximum number of tasks to be rolled back in one iteration (0 means unlimited parallelism).
+    pub fn parallelism(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.parallelism = Some(value.into());
+        self
+    }
+    /// Amount of time between rollback iterations, in nanoseconds.
+    pub fn delay(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.delay = Some(value.into());
+        self
+    }
+    /// Action to take if an rolled back task fails to run, or stops running during the rollback.
+    pub fn failure_action(
+        &mut self,
+        value: impl Into<ServiceSpecRollbackConfigFailureAction>,
+    ) -> &mut Self {
+        self.failure_action = Some(value.into());
+        self
+    }
+    /// Amount of time to monitor each rolled back task for failures, in nanoseconds.
+    pub fn monitor(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.monitor = Some(value.into());
+        self
+    }
+    /// The fraction of tasks that may fail during a rollback before the failure action is invoked, specified as a floating point number between 0 and 1.
+    pub fn max_failure_ratio(&mut self, value: impl Into<f64>) -> &mut Self {
+        self.max_failure_ratio = Some(value.into());
+        self
+    }
+    /// The order of operations when rolling back a task. Either the old task is shut down before the new task is started, or the new task is started before the old task is shut down.
+    pub fn order(
+        &mut self,
+        value: impl Into<ServiceSpecRollbackConfigOrder>,
+    ) -> &mut Self {
+        self.order = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceSpecRollbackConfig {
+        ServiceSpecRollbackConfig {
+            parallelism: self.parallelism.clone(),
+            delay: self.delay.clone(),
+            failure_action: self.failure_action.clone(),
+            monitor: self.monitor.clone(),
+            max_failure_ratio: self.max_failure_ratio.clone(),
+            order: self.order.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceSpecUpdateConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceSpecUpdateConfigBuilder {
+    parallelism: Option<i64>,
+    delay: Option<i64>,
+    failure_action: Option<ServiceSpecUpdateConfigFailureAction>,
+    monitor: Option<i64>,
+    max_failure_ratio: Option<f64>,
+    order: Option<ServiceSpecUpdateConfigOrder>,
+}
+impl ServiceSpecUpdateConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Maximum number of tasks to be updated in one iteration (0 means unlimited parallelism).
+    pub fn parallelism(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.parallelism = Some(value.into());
+        self
+    }
+    /// Amount of time between updates, in nanoseconds.
+    pub fn delay(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.delay = Some(value.into());
+        self
+    }
+    /// Action to take if an updated task fails to run, or stops running during the update.
+    pub fn failure_action(
+        &mut self,
+        value: impl Into<ServiceSpecUpdateConfigFailureAction>,
+    ) -> &mut Self {
+        self.failure_action = Some(value.into());
+        self
+    }
+    /// Amount of time to monitor each updated task for failures, in nanoseconds.
+    pub fn monitor(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.monitor = Some(value.into());
+        self
+    }
+    /// The fraction of tasks that may fail during an update before the failure action is invoked, specified as a floating point number between 0 and 1.
+    pub fn max_failure_ratio(&mut self, value: impl Into<f64>) -> &mut Self {
+        self.max_failure_ratio = Some(value.into());
+        self
+    }
+    /// The order of operations when rolling out an updated task. Either the old task is shut down before the new task is started, or the new task is started before the old task is shut down.
+    pub fn order(
+        &mut self,
+        value: impl Into<ServiceSpecUpdateConfigOrder>,
+    ) -> &mut Self {
+        self.order = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceSpecUpdateConfig {
+        ServiceSpecUpdateConfig {
+            parallelism: self.parallelism.clone(),
+            delay: self.delay.clone(),
+            failure_action: self.failure_action.clone(),
+            monitor: self.monitor.clone(),
+            max_failure_ratio: self.max_failure_ratio.clone(),
+            order: self.order.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct ServiceUpdateParamsBuilder {
+    id: String,
+    version: i32,
+    body: ServiceUpdateRequest,
+    registry_auth_from: Option<String>,
+    rollback: Option<String>,
+    x_registry_auth: Option<String>,
+}
+impl ServiceUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<String>,
+        version: impl Into<i32>,
+        body: impl Into<ServiceUpdateRequest>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            version: version.into(),
+            body: body.into(),
+            registry_auth_from: None,
+            rollback: None,
+            x_registry_auth: None,
+        }
+    }
+    /// If the `X-Registry-Auth` header is not specified, this parameter indicates where to find registry authorization credentials.
+    pub fn registry_auth_from(&mut self, value: impl Into<String>) -> &mut Self {
+        self.registry_auth_from = Some(value.into());
+        self
+    }
+    /// Set to this parameter to `previous` to cause a server-side rollback to the previous service spec. The supplied spec will be ignored in this case.
+    pub fn rollback(&mut self, value: impl Into<String>) -> &mut Self {
+        self.rollback = Some(value.into());
+        self
+    }
+    /// A base64url-encoded auth configuration for pulling from private registries.  Refer to the [authentication section](#section/Authentication) for details.
+    pub fn x_registry_auth(&mut self, value: impl Into<String>) -> &mut Self {
+        self.x_registry_auth = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceUpdateParams {
+        ServiceUpdateParams {
+            id: self.id.clone(),
+            version: self.version.clone(),
+            body: self.body.clone(),
+            registry_auth_from: self.registry_auth_from.clone(),
+            rollback: self.rollback.clone(),
+            x_registry_auth: self.x_registry_auth.clone(),
+        }
+    }
+}
+/// Builder of [`ServiceUpdateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceUpdateRequestBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    task_template: Option<Box<TaskSpec>>,
+    mode: Option<Box<ServiceSpecMode>>,
+    update_config: Option<Box<ServiceSpecUpdateConfig>>,
+    rollback_config: Option<Box<ServiceSpecRollbackConfig>>,
+    networks: Option<Vec<NetworkAttachmentConfig>>,
+    endpoint_spec: Option<Box<EndpointSpec>>,
+}
+impl ServiceUpdateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the service.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn task_template(&mut self, value: impl Into<Box<TaskSpec>>) -> &mut Self {
+        self.task_template = Some(value.into());
+        self
+    }
+    pub fn mode(&mut self, value: impl Into<Box<ServiceSpecMode>>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    pub fn update_config(
+        &mut self,
+        value: impl Into<Box<ServiceSpecUpdateConfig>>,
+    ) -> &mut Self {
+        self.update_config = Some(value.into());
+        self
+    }
+    pub fn rollback_config(
+        &mut self,
+        value: impl Into<Box<ServiceSpecRollbackConfig>>,
+    ) -> &mut Self {
+        self.rollback_config = Some(value.into());
+        self
+    }
+    /// Specifies which networks the service should attach to.  Deprecated: This field is deprecated since v1.44. The Networks field in TaskSpec should be used instead.
+    pub fn networks(
+        &mut self,
+        value: impl Into<Vec<NetworkAttachmentConfig>>,
+    ) -> &mut Self {
+        self.networks = Some(value.into());
+        self
+    }
+    pub fn endpoint_spec(&mut self, value: impl Into<Box<EndpointSpec>>) -> &mut Self {
+        self.endpoint_spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ServiceUpdateRequest {
+        ServiceUpdateRequest {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            task_template: self.task_template.clone(),
+            mode: self.mode.clone(),
+            update_config: self.update_config.clone(),
+            rollback_config: self.rollback_config.clone(),
+            networks: self.networks.clone(),
+            endpoint_spec: self.endpoint_spec.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmInitParams`].
+#[derive(Clone, Debug)]
+pub struct SwarmInitParamsBuilder {
+    body: SwarmInitRequest,
+}
+impl SwarmInitParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(body: impl Into<SwarmInitRequest>) -> Self {
+        Self { body: body.into() }
+    }
+    pub fn build(&self) -> SwarmInitParams {
+        SwarmInitParams {
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmInitRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmInitRequestBuilder {
+    listen_addr: Option<String>,
+    advertise_addr: Option<String>,
+    data_path_addr: Option<String>,
+    data_path_port: Option<i32>,
+    default_addr_pool: Option<Vec<String>>,
+    force_new_cluster: Option<bool>,
+    subnet_size: Option<i32>,
+    spec: Option<Box<SwarmSpec>>,
+}
+impl SwarmInitRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Listen address used for inter-manager communication, as well as determining the networking interface used for the VXLAN Tunnel Endpoint (VTEP). This can either be an address/port combination in the form `192.168.1.1:4567`, or an interface followed by a port number, like `eth0:4567`. If the port number is omitted, the default swarm listening port is used.
+    pub fn listen_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.listen_addr = Some(value.into());
+        self
+    }
+    /// Externally reachable address advertised to other nodes. This can either be an address/port combination in the form `192.168.1.1:4567`, or an interface followed by a port number, like `eth0:4567`. If the port number is omitted, the port number from the listen address is used. If `AdvertiseAddr` is not specified, it will be automatically detected when possible.
+    pub fn advertise_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.advertise_addr = Some(value.into());
+        self
+    }
+    /// Address or interface to use for data path traffic (format: `<ip|interface>`), for example,  `192.168.1.1`, or an interface, like `eth0`. If `DataPathAddr` is unspecified, the same address as `AdvertiseAddr` is used.  The `DataPathAddr` specifies the address that global scope network drivers will publish towards other  nodes in order to reach the containers running on this node. Using this parameter it is possible to separate the container data traffic from the management traffic of the cluster.
+    pub fn data_path_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data_path_addr = Some(value.into());
+        self
+    }
+    /// DataPathPort specifies the data path port number for data traffic. Acceptable port range is 1024 to 49151. if no port is set or is set to 0, default port 4789 will be used.
+    pub fn data_path_port(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.data_path_port = Some(value.into());
+        self
+    }
+    /// Default Address Pool specifies default subnet pools for global scope networks.
+    pub fn default_addr_pool(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.default_addr_pool = Some(value.into());
+        self
+    }
+    /// Force creation of a new swarm.
+    pub fn force_new_cluster(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force_new_cluster = Some(value.into());
+        self
+    }
+    /// SubnetSize specifies the subnet size of the networks created from the default subnet pool.
+    pub fn subnet_size(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.subnet_size = Some(value.into());
+        self
+    }
+    pub fn spec(&mut self, value: impl Into<Box<SwarmSpec>>) -> &mut Self {
+        self.spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmInitRequest {
+        SwarmInitRequest {
+            listen_addr: self.listen_addr.clone(),
+            advertise_addr: self.advertise_addr.clone(),
+            data_path_addr: self.data_path_addr.clone(),
+            data_path_port: self.data_path_port.clone(),
+            default_addr_pool: self.default_addr_pool.clone(),
+            force_new_cluster: self.force_new_cluster.clone(),
+            subnet_size: self.subnet_size.clone(),
+            spec: self.spec.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmJoinParams`].
+#[derive(Clone, Debug)]
+pub struct SwarmJoinParamsBuilder {
+    body: SwarmJoinRequest,
+}
+impl SwarmJoinParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(body: impl Into<SwarmJoinRequest>) -> Self {
+        Self { body: body.into() }
+    }
+    pub fn build(&self) -> SwarmJoinParams {
+        SwarmJoinParams {
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmJoinRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmJoinRequestBuilder {
+    listen_addr: Option<String>,
+    advertise_addr: Option<String>,
+    data_path_addr: Option<String>,
+    remote_addrs: Option<Vec<String>>,
+    join_token: Option<String>,
+}
+impl SwarmJoinRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Listen address used for inter-manager communication if the node gets promoted to manager, as well as determining the networking interface used for the VXLAN Tunnel Endpoint (VTEP).
+    pub fn listen_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.listen_addr = Some(value.into());
+        self
+    }
+    /// Externally reachable address advertised to other nodes. This can either be an address/port combination in the form `192.168.1.1:4567`, or an interface followed by a port number, like `eth0:4567`. If the port number is omitted, the port number from the listen address is used. If `AdvertiseAddr` is not specified, it will be automatically detected when possible.
+    pub fn advertise_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.advertise_addr = Some(value.into());
+        self
+    }
+    /// Address or interface to use for data path traffic (format: `<ip|interface>`), for example,  `192.168.1.1`, or an interface, like `eth0`. If `DataPathAddr` is unspecified, the same address as `AdvertiseAddr` is used.  The `DataPathAddr` specifies the address that global scope network drivers will publish towards other nodes in order to reach the containers running on this node. Using this parameter it is possible to separate the container data traffic from the management traffic of the cluster.
+    pub fn data_path_addr(&mut self, value: impl Into<String>) -> &mut Self {
+        self.data_path_addr = Some(value.into());
+        self
+    }
+    /// Addresses of manager nodes already participating in the swarm.
+    pub fn remote_addrs(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.remote_addrs = Some(value.into());
+        self
+    }
+    /// Secret token for joining this swarm.
+    pub fn join_token(&mut self, value: impl Into<String>) -> &mut Self {
+        self.join_token = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmJoinRequest {
+        SwarmJoinRequest {
+            listen_addr: self.listen_addr.clone(),
+            advertise_addr: self.advertise_addr.clone(),
+            data_path_addr: self.data_path_addr.clone(),
+            remote_addrs: self.remote_addrs.clone(),
+            join_token: self.join_token.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmLeaveParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmLeaveParamsBuilder {
+    force: Option<bool>,
+}
+impl SwarmLeaveParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Force leave swarm, even if this is the last manager or that it will break the cluster.
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmLeaveParams {
+        SwarmLeaveParams {
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecBuilder {
+    name: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    orchestration: Option<Option<Box<SwarmSpecOrchestration>>>,
+    raft: Option<Box<SwarmSpecRaft>>,
+    dispatcher: Option<Option<Box<SwarmSpecDispatcher>>>,
+    ca_config: Option<Option<Box<SwarmSpecCaConfig>>>,
+    encryption_config: Option<Box<SwarmSpecEncryptionConfig>>,
+    task_defaults: Option<Box<SwarmSpecTaskDefaults>>,
+}
+impl SwarmSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name of the swarm.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn orchestration(
+        &mut self,
+        value: impl Into<Option<Box<SwarmSpecOrchestration>>>,
+    ) -> &mut Self {
+        self.orchestration = Some(value.into());
+        self
+    }
+    pub fn raft(&mut self, value: impl Into<Box<SwarmSpecRaft>>) -> &mut Self {
+        self.raft = Some(value.into());
+        self
+    }
+    pub fn dispatcher(
+        &mut self,
+        value: impl Into<Option<Box<SwarmSpecDispatcher>>>,
+    ) -> &mut Self {
+        self.dispatcher = Some(value.into());
+        self
+    }
+    pub fn ca_config(
+        &mut self,
+        value: impl Into<Option<Box<SwarmSpecCaConfig>>>,
+    ) -> &mut Self {
+        self.ca_config = Some(value.into());
+        self
+    }
+    pub fn encryption_config(
+        &mut self,
+        value: impl Into<Box<SwarmSpecEncryptionConfig>>,
+    ) -> &mut Self {
+        self.encryption_config = Some(value.into());
+        self
+    }
+    pub fn task_defaults(
+        &mut self,
+        value: impl Into<Box<SwarmSpecTaskDefaults>>,
+    ) -> &mut Self {
+        self.task_defaults = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpec {
+        SwarmSpec {
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            orchestration: self.orchestration.clone(),
+            raft: self.raft.clone(),
+            dispatcher: self.dispatcher.clone(),
+            ca_config: self.ca_config.clone(),
+            encryption_config: self.encryption_config.clone(),
+            task_defaults: self.task_defaults.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecCaConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecCaConfigBuilder {
+    node_cert_expiry: Option<i64>,
+    external_cas: Option<Vec<SwarmSpecCaConfigExternalCasInner>>,
+    signing_ca_cert: Option<String>,
+    signing_ca_key: Option<String>,
+    force_rotate: Option<i32>,
+}
+impl SwarmSpecCaConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The duration node certificates are issued for.
+    pub fn node_cert_expiry(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.node_cert_expiry = Some(value.into());
+        self
+    }
+    /// Configuration for forwarding signing requests to an external certificate authority.
+    pub fn external_cas(
+        &mut self,
+        value: impl Into<Vec<SwarmSpecCaConfigExternalCasInner>>,
+    ) -> &mut Self {
+        self.external_cas = Some(value.into());
+        self
+    }
+    /// The desired signing CA certificate for all swarm node TLS leaf certificates, in PEM format.
+    pub fn signing_ca_cert(&mut self, value: impl Into<String>) -> &mut Self {
+        self.signing_ca_cert = Some(value.into());
+        self
+    }
+    /// The desired signing CA key for all swarm node TLS leaf certificates, in PEM format.
+    pub fn signing_ca_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.signing_ca_key = Some(value.into());
+        self
+    }
+    /// An integer whose purpose is to force swarm to generate a new signing CA certificate and key, if none have been specified in `SigningCACert` and `SigningCAKey`
+    pub fn force_rotate(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.force_rotate = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecCaConfig {
+        SwarmSpecCaConfig {
+            node_cert_expiry: self.node_cert_expiry.clone(),
+            external_cas: self.external_cas.clone(),
+            signing_ca_cert: self.signing_ca_cert.clone(),
+            signing_ca_key: self.signing_ca_key.clone(),
+            force_rotate: self.force_rotate.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecCaConfigExternalCasInner`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecCaConfigExternalCasInnerBuilder {
+    protocol: Option<SwarmSpecCaConfigExternalCasInnerProtocol>,
+    url: Option<String>,
+    options: Option<std::collections::HashMap<String, String>>,
+    ca_cert: Option<String>,
+}
+impl SwarmSpecCaConfigExternalCasInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Protocol for communication with the external CA (currently only `cfssl` is supported).
+    pub fn protocol(
+        &mut self,
+        value: impl Into<SwarmSpecCaConfigExternalCasInnerProtocol>,
+    ) -> &mut Self {
+        self.protocol = Some(value.into());
+        self
+    }
+    /// URL where certificate signing requests should be sent.
+    pub fn url(&mut self, value: impl Into<String>) -> &mut Self {
+        self.url = Some(value.into());
+        self
+    }
+    /// An object with key/value pairs that are interpreted as protocol-specific options for the external CA driver.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    /// The root CA certificate (in PEM format) this external CA uses to issue TLS certificates (assumed to be to the current swarm root CA certificate if not provided).
+    pub fn ca_cert(&mut self, value: impl Into<String>) -> &mut Self {
+        self.ca_cert = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecCaConfigExternalCasInner {
+        SwarmSpecCaConfigExternalCasInner {
+            protocol: self.protocol.clone(),
+            url: self.url.clone(),
+            options: self.options.clone(),
+            ca_cert: self.ca_cert.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecDispatcher`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecDispatcherBuilder {
+    heartbeat_period: Option<i64>,
+}
+impl SwarmSpecDispatcherBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The delay for an agent to send a heartbeat to the dispatcher.
+    pub fn heartbeat_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.heartbeat_period = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecDispatcher {
+        SwarmSpecDispatcher {
+            heartbeat_period: self.heartbeat_period.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecEncryptionConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecEncryptionConfigBuilder {
+    auto_lock_managers: Option<bool>,
+}
+impl SwarmSpecEncryptionConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// If set, generate a key and use it to lock data stored on the managers.
+    pub fn auto_lock_managers(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.auto_lock_managers = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecEncryptionConfig {
+        SwarmSpecEncryptionConfig {
+            auto_lock_managers: self.auto_lock_managers.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecOrchestration`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecOrchestrationBuilder {
+    task_history_retention_limit: Option<i64>,
+}
+impl SwarmSpecOrchestrationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The number of historic tasks to keep per instance or node. If negative, never remove completed or failed tasks.
+    pub fn task_history_retention_limit(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.task_history_retention_limit = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecOrchestration {
+        SwarmSpecOrchestration {
+            task_history_retention_limit: self.task_history_retention_limit.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecRaft`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecRaftBuilder {
+    snapshot_interval: Option<i32>,
+    keep_old_snapshots: Option<i32>,
+    log_entries_for_slow_followers: Option<i32>,
+    election_tick: Option<i32>,
+    heartbeat_tick: Option<i32>,
+}
+impl SwarmSpecRaftBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The number of log entries between snapshots.
+    pub fn snapshot_interval(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.snapshot_interval = Some(value.into());
+        self
+    }
+    /// The number of snapshots to keep beyond the current snapshot.
+    pub fn keep_old_snapshots(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.keep_old_snapshots = Some(value.into());
+        self
+    }
+    /// The number of log entries to keep around to sync up slow followers after a snapshot is created.
+    pub fn log_entries_for_slow_followers(
+        &mut self,
+        value: impl Into<i32>,
+    ) -> &mut Self {
+        self.log_entries_for_slow_followers = Some(value.into());
+        self
+    }
+    /// The number of ticks that a follower will wait for a message from the leader before becoming a candidate and starting an election. `ElectionTick` must be greater than `HeartbeatTick`.  A tick currently defaults to one second, so these translate directly to seconds currently, but this is NOT guaranteed.
+    pub fn election_tick(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.election_tick = Some(value.into());
+        self
+    }
+    /// The number of ticks between heartbeats. Every HeartbeatTick ticks, the leader will send a heartbeat to the followers.  A tick currently defaults to one second, so these translate directly to seconds currently, but this is NOT guaranteed.
+    pub fn heartbeat_tick(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.heartbeat_tick = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecRaft {
+        SwarmSpecRaft {
+            snapshot_interval: self.snapshot_interval.clone(),
+            keep_old_snapshots: self.keep_old_snapshots.clone(),
+            log_entries_for_slow_followers: self.log_entries_for_slow_followers.clone(),
+            election_tick: self.election_tick.clone(),
+            heartbeat_tick: self.heartbeat_tick.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecTaskDefaults`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecTaskDefaultsBuilder {
+    log_driver: Option<Box<SwarmSpecTaskDefaultsLogDriver>>,
+}
+impl SwarmSpecTaskDefaultsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn log_driver(
+        &mut self,
+        value: impl Into<Box<SwarmSpecTaskDefaultsLogDriver>>,
+    ) -> &mut Self {
+        self.log_driver = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecTaskDefaults {
+        SwarmSpecTaskDefaults {
+            log_driver: self.log_driver.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmSpecTaskDefaultsLogDriver`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmSpecTaskDefaultsLogDriverBuilder {
+    name: Option<String>,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl SwarmSpecTaskDefaultsLogDriverBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The log driver to use as a default for new tasks.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// Driver-specific options for the selected log driver, specified as key/value pairs.
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmSpecTaskDefaultsLogDriver {
+        SwarmSpecTaskDefaultsLogDriver {
+            name: self.name.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmUnlockParams`].
+#[derive(Clone, Debug)]
+pub struct SwarmUnlockParamsBuilder {
+    body: SwarmUnlockRequest,
+}
+impl SwarmUnlockParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(body: impl Into<SwarmUnlockRequest>) -> Self {
+        Self { body: body.into() }
+    }
+    pub fn build(&self) -> SwarmUnlockParams {
+        SwarmUnlockParams {
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmUnlockRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct SwarmUnlockRequestBuilder {
+    unlock_key: Option<String>,
+}
+impl SwarmUnlockRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The swarm's unlock key.
+    pub fn unlock_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.unlock_key = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmUnlockRequest {
+        SwarmUnlockRequest {
+            unlock_key: self.unlock_key.clone(),
+        }
+    }
+}
+/// Builder of [`SwarmUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct SwarmUpdateParamsBuilder {
+    version: i64,
+    body: SwarmSpec,
+    rotate_worker_token: Option<bool>,
+    rotate_manager_token: Option<bool>,
+    rotate_manager_unlock_key: Option<bool>,
+}
+impl SwarmUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(version: impl Into<i64>, body: impl Into<SwarmSpec>) -> Self {
+        Self {
+            version: version.into(),
+            body: body.into(),
+            rotate_worker_token: None,
+            rotate_manager_token: None,
+            rotate_manager_unlock_key: None,
+        }
+    }
+    /// Rotate the worker join token.
+    pub fn rotate_worker_token(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.rotate_worker_token = Some(value.into());
+        self
+    }
+    /// Rotate the manager join token.
+    pub fn rotate_manager_token(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.rotate_manager_token = Some(value.into());
+        self
+    }
+    /// Rotate the manager unlock key.
+    pub fn rotate_manager_unlock_key(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.rotate_manager_unlock_key = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SwarmUpdateParams {
+        SwarmUpdateParams {
+            version: self.version.clone(),
+            body: self.body.clone(),
+            rotate_worker_token: self.rotate_worker_token.clone(),
+            rotate_manager_token: self.rotate_manager_token.clone(),
+            rotate_manager_unlock_key: self.rotate_manager_unlock_key.clone(),
+        }
+    }
+}
+/// Builder of [`SystemAuthParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SystemAuthParamsBuilder {
+    auth_config: Option<AuthConfig>,
+}
+impl SystemAuthParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Authentication to check
+    pub fn auth_config(&mut self, value: impl Into<AuthConfig>) -> &mut Self {
+        self.auth_config = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SystemAuthParams {
+        SystemAuthParams {
+            auth_config: self.auth_config.clone(),
+        }
+    }
+}
+/// Builder of [`SystemDataUsageParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SystemDataUsageParamsBuilder {
+    r#type: Option<Vec<String>>,
+}
+impl SystemDataUsageParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Object types, for which to compute and return data.
+    pub fn r#type(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.r#type = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SystemDataUsageParams {
+        SystemDataUsageParams {
+            r#type: self.r#type.clone(),
+        }
+    }
+}
+/// Builder of [`SystemEventsParams`].
+#[derive(Clone, Debug, Default)]
+pub struct SystemEventsParamsBuilder {
+    since: Option<String>,
+    until: Option<String>,
+    filters: Option<String>,
+}
+impl SystemEventsParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Show events created since this timestamp then stream new events.
+    pub fn since(&mut self, value: impl Into<String>) -> &mut Self {
+        self.since = Some(value.into());
+        self
+    }
+    /// Show events created until this timestamp then stop streaming.
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.until = Some(value.into());
+        self
+    }
+    /// A JSON encoded value of filters (a `map[string][]string`) to process on the event list. Available filters:  - `config=<string>` config name or ID - `container=<string>` container name or ID - `daemon=<string>` daemon name or ID - `event=<string>` event type - `image=<string>` image name or ID - `label=<string>` image or container label - `network=<string>` network name or ID - `node=<string>` node ID - `plugin`=<string> plugin name or ID - `scope`=<string> local or swarm - `secret=<string>` secret name or ID - `service=<string>` service name or ID - `type=<string>` object to filter by, one of `container`, `image`, `volume`, `network`, `daemon`, `plugin`, `node`, `service`, `secret` or `config` - `volume=<string>` volume name
+    pub fn filters(&mut self, value: SystemEventsFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> SystemEventsParams {
+        SystemEventsParams {
+            since: self.since.clone(),
+            until: self.until.clone(),
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`TaskInspectParams`].
+#[derive(Clone, Debug)]
+pub struct TaskInspectParamsBuilder {
+    id: String,
+}
+impl TaskInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+    pub fn build(&self) -> TaskInspectParams {
+        TaskInspectParams {
+            id: self.id.clone(),
+        }
+    }
+}
+/// Builder of [`TaskListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskListParamsBuilder {
+    filters: Option<String>,
+}
+impl TaskListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the tasks list.  Available filters:  - `desired-state=(running | shutdown | accepted)` - `id=<task id>` - `label=key` or `label=\"key=value\"` - `name=<task name>` - `node=<node id or name>` - `service=<service name>`
+    pub fn filters(&mut self, value: TaskListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskListParams {
+        TaskListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`TaskLogsParams`].
+#[derive(Clone, Debug)]
+pub struct TaskLogsParamsBuilder {
+    id: String,
+    details: Option<bool>,
+    follow: Option<bool>,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+    since: Option<i32>,
+    timestamps: Option<bool>,
+    tail: Option<String>,
+}
+impl TaskLogsParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            details: None,
+            follow: None,
+            stdout: None,
+            stderr: None,
+            since: None,
+            timestamps: None,
+            tail: None,
+        }
+    }
+    /// Show task context and extra details provided to logs.
+    pub fn details(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.details = Some(value.into());
+        self
+    }
+    /// Keep connection after returning logs.
+    pub fn follow(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.follow = Some(value.into());
+        self
+    }
+    /// Return logs from `stdout`
+    pub fn stdout(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stdout = Some(value.into());
+        self
+    }
+    /// Return logs from `stderr`
+    pub fn stderr(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.stderr = Some(value.into());
+        self
+    }
+    /// Only return logs since this time, as a UNIX timestamp
+    pub fn since(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.since = Some(value.into());
+        self
+    }
+    /// Add timestamps to every log line
+    pub fn timestamps(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.timestamps = Some(value.into());
+        self
+    }
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub fn tail(&mut self, value: impl Into<String>) -> &mut Self {
+        self.tail = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskLogsParams {
+        TaskLogsParams {
+            id: self.id.clone(),
+            details: self.details.clone(),
+            follow: self.follow.clone(),
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+            since: self.since.clone(),
+            timestamps: self.timestamps.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecBuilder {
+    plugin_spec: Option<Box<TaskSpecPluginSpec>>,
+    container_spec: Option<Box<TaskSpecContainerSpec>>,
+    network_attachment_spec: Option<Box<TaskSpecNetworkAttachmentSpec>>,
+    resources: Option<Box<TaskSpecResources>>,
+    restart_policy: Option<Box<TaskSpecRestartPolicy>>,
+    placement: Option<Box<TaskSpecPlacement>>,
+    force_update: Option<i32>,
+    runtime: Option<String>,
+    networks: Option<Vec<NetworkAttachmentConfig>>,
+    log_driver: Option<Box<TaskSpecLogDriver>>,
+}
+impl TaskSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn plugin_spec(
+        &mut self,
+        value: impl Into<Box<TaskSpecPluginSpec>>,
+    ) -> &mut Self {
+        self.plugin_spec = Some(value.into());
+        self
+    }
+    pub fn container_spec(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpec>>,
+    ) -> &mut Self {
+        self.container_spec = Some(value.into());
+        self
+    }
+    pub fn network_attachment_spec(
+        &mut self,
+        value: impl Into<Box<TaskSpecNetworkAttachmentSpec>>,
+    ) -> &mut Self {
+        self.network_attachment_spec = Some(value.into());
+        self
+    }
+    pub fn resources(&mut self, value: impl Into<Box<TaskSpecResources>>) -> &mut Self {
+        self.resources = Some(value.into());
+        self
+    }
+    pub fn restart_policy(
+        &mut self,
+        value: impl Into<Box<TaskSpecRestartPolicy>>,
+    ) -> &mut Self {
+        self.restart_policy = Some(value.into());
+        self
+    }
+    pub fn placement(&mut self, value: impl Into<Box<TaskSpecPlacement>>) -> &mut Self {
+        self.placement = Some(value.into());
+        self
+    }
+    /// A counter that triggers an update even if no relevant parameters have been changed.
+    pub fn force_update(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.force_update = Some(value.into());
+        self
+    }
+    /// Runtime is the type of runtime specified for the task executor.
+    pub fn runtime(&mut self, value: impl Into<String>) -> &mut Self {
+        self.runtime = Some(value.into());
+        self
+    }
+    /// Specifies which networks the service should attach to.
+    pub fn networks(
+        &mut self,
+        value: impl Into<Vec<NetworkAttachmentConfig>>,
+    ) -> &mut Self {
+        self.networks = Some(value.into());
+        self
+    }
+    pub fn log_driver(&mut self, value: impl Into<Box<TaskSpecLogDriver>>) -> &mut Self {
+        self.log_driver = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpec {
+        TaskSpec {
+            plugin_spec: self.plugin_spec.clone(),
+            container_spec: self.container_spec.clone(),
+            network_attachment_spec: self.network_attachment_spec.clone(),
+            resources: self.resources.clone(),
+            restart_policy: self.restart_policy.clone(),
+            placement: self.placement.clone(),
+            force_update: self.force_update.clone(),
+            runtime: self.runtime.clone(),
+            networks: self.networks.clone(),
+            log_driver: self.log_driver.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecBuilder {
+    image: Option<String>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    command: Option<Vec<String>>,
+    args: Option<Vec<String>>,
+    hostname: Option<String>,
+    env: Option<Vec<String>>,
+    dir: Option<String>,
+    user: Option<String>,
+    groups: Option<Vec<String>>,
+    privileges: Option<Box<TaskSpecContainerSpecPrivileges>>,
+    tty: Option<bool>,
+    open_stdin: Option<bool>,
+    read_only: Option<bool>,
+    mounts: Option<Vec<Mount>>,
+    stop_signal: Option<String>,
+    stop_grace_period: Option<i64>,
+    health_check: Option<Box<HealthConfig>>,
+    hosts: Option<Vec<String>>,
+    dns_config: Option<Box<TaskSpecContainerSpecDnsConfig>>,
+    secrets: Option<Vec<TaskSpecContainerSpecSecretsInner>>,
+    oom_score_adj: Option<i64>,
+    configs: Option<Vec<TaskSpecContainerSpecConfigsInner>>,
+    isolation: Option<TaskSpecContainerSpecIsolation>,
+    init: Option<Option<bool>>,
+    sysctls: Option<std::collections::HashMap<String, String>>,
+    capability_add: Option<Vec<String>>,
+    capability_drop: Option<Vec<String>>,
+    ulimits: Option<Vec<ResourcesUlimitsInner>>,
+}
+impl TaskSpecContainerSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The image name to use for the container
+    pub fn image(&mut self, value: impl Into<String>) -> &mut Self {
+        self.image = Some(value.into());
+        self
+    }
+    /// User-defined key/value data.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    /// The command to be run in the image.
+    pub fn command(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.command = Some(value.into());
+        self
+    }
+    /// Arguments to the command.
+    pub fn args(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.args = Some(value.into());
+        self
+    }
+    /// The hostname to use for the container, as a valid [RFC 1123](https://tools.ietf.org/html/rfc1123) hostname.
+    pub fn hostname(&mut self, value: impl Into<String>) -> &mut Self {
+        self.hostname = Some(value.into());
+        self
+    }
+    /// A list of environment variables in the form `VAR=value`.
+    pub fn env(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.env = Some(value.into());
+        self
+    }
+    /// The working directory for commands to run in.
+    pub fn dir(&mut self, value: impl Into<String>) -> &mut Self {
+        self.dir = Some(value.into());
+        self
+    }
+    /// The user inside the container.
+    pub fn user(&mut self, value: impl Into<String>) -> &mut Self {
+        self.user = Some(value.into());
+        self
+    }
+    /// A list of additional groups that the container process will run as.
+    pub fn groups(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.groups = Some(value.into());
+        self
+    }
+    pub fn privileges(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecPrivileges>>,
+    ) -> &mut Self {
+        self.privileges = Some(value.into());
+        self
+    }
+    /// Whether a pseudo-TTY should be allocated.
+    pub fn tty(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.tty = Some(value.into());
+        self
+    }
+    /// Open `stdin`
+    pub fn open_stdin(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.open_stdin = Some(value.into());
+        self
+    }
+    /// Mount the container's root filesystem as read only.
+    pub fn read_only(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.read_only = Some(value.into());
+        self
+    }
+    /// Specification for mounts to be added to containers created as part of the service.
+    pub fn mounts(&mut self, value: impl Into<Vec<Mount>>) -> &mut Self {
+        self.mounts = Some(value.into());
+        self
+    }
+    /// Signal to stop the container.
+    pub fn stop_signal(&mut self, value: impl Into<String>) -> &mut Self {
+        self.stop_signal = Some(value.into());
+        self
+    }
+    /// Amount of time to wait for the container to terminate before forcefully killing it.
+    pub fn stop_grace_period(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.stop_grace_period = Some(value.into());
+        self
+    }
+    pub fn health_check(&mut self, value: impl Into<Box<HealthConfig>>) -> &mut Self {
+        self.health_check = Some(value.into());
+        self
+    }
+    /// A list of hostname/IP mappings to add to the container's `hosts` file. The format of extra hosts is specified in the [hosts(5)](http://man7.org/linux/man-pages/man5/hosts.5.html) man page:      IP_address canonical_hostname [aliases...]
+    pub fn hosts(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.hosts = Some(value.into());
+        self
+    }
+    pub fn dns_config(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecDnsConfig>>,
+    ) -> &mut Self {
+        self.dns_config = Some(value.into());
+        self
+    }
+    /// Secrets contains references to zero or more secrets that will be exposed to the service.
+    pub fn secrets(
+        &mut self,
+        value: impl Into<Vec<TaskSpecContainerSpecSecretsInner>>,
+    ) -> &mut Self {
+        self.secrets = Some(value.into());
+        self
+    }
+    /// An integer value containing the score given to the container in order to tune OOM killer preferences.
+    pub fn oom_score_adj(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+    /// Configs contains references to zero or more configs that will be exposed to the service.
+    pub fn configs(
+        &mut self,
+        value: impl Into<Vec<TaskSpecContainerSpecConfigsInner>>,
+    ) -> &mut Self {
+        self.configs = Some(value.into());
+        self
+    }
+    /// Isolation technology of the containers running the service. (Windows only)
+    pub fn isolation(
+        &mut self,
+        value: impl Into<TaskSpecContainerSpecIsolation>,
+    ) -> &mut Self {
+        self.isolation = Some(value.into());
+        self
+    }
+    /// Run an init inside the container that forwards signals and reaps processes. This field is omitted if empty, and the default (as configured on the daemon) is used.
+    pub fn init(&mut self, value: impl Into<Option<bool>>) -> &mut Self {
+        self.init = Some(value.into());
+        self
+    }
+    /// Set kernel namedspaced parameters (sysctls) in the container. The Sysctls option on services accepts the same sysctls as the are supported on containers. Note that while the same sysctls are supported, no guarantees or checks are made about their suitability for a clustered environment, and it's up to the user to determine whether a given sysctl will work properly in a Service.
+    pub fn sysctls(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.sysctls = Some(value.into());
+        self
+    }
+    /// A list of kernel capabilities to add to the default set for the container.
+    pub fn capability_add(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.capability_add = Some(value.into());
+        self
+    }
+    /// A list of kernel capabilities to drop from the default set for the container.
+    pub fn capability_drop(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.capability_drop = Some(value.into());
+        self
+    }
+    /// A list of resource limits to set in the container. For example: `{\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048}`\"
+    pub fn ulimits(
+        &mut self,
+        value: impl Into<Vec<ResourcesUlimitsInner>>,
+    ) -> &mut Self {
+        self.ulimits = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpec {
+        TaskSpecContainerSpec {
+            image: self.image.clone(),
+            labels: self.labels.clone(),
+            command: self.command.clone(),
+            args: self.args.clone(),
+            hostname: self.hostname.clone(),
+            env: self.env.clone(),
+            dir: self.dir.clone(),
+            user: self.user.clone(),
+            groups: self.groups.clone(),
+            privileges: self.privileges.clone(),
+            tty: self.tty.clone(),
+            open_stdin: self.open_stdin.clone(),
+            read_only: self.read_only.clone(),
+            mounts: self.mounts.clone(),
+            stop_signal: self.stop_signal.clone(),
+            stop_grace_period: self.stop_grace_period.clone(),
+            health_check: self.health_check.clone(),
+            hosts: self.hosts.clone(),
+            dns_config: self.dns_config.clone(),
+            secrets: self.secrets.clone(),
+            oom_score_adj: self.oom_score_adj.clone(),
+            configs: self.configs.clone(),
+            isolation: self.isolation.clone(),
+            init: self.init.clone(),
+            sysctls: self.sysctls.clone(),
+            capability_add: self.capability_add.clone(),
+            capability_drop: self.capability_drop.clone(),
+            ulimits: self.ulimits.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecConfigsInner`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecConfigsInnerBuilder {
+    file: Option<Box<TaskSpecContainerSpecConfigsInnerFile>>,
+    runtime: Option<serde_json::Value>,
+    config_id: Option<String>,
+    config_name: Option<String>,
+}
+impl TaskSpecContainerSpecConfigsInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn file(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecConfigsInnerFile>>,
+    ) -> &mut Self {
+        self.file = Some(value.into());
+        self
+    }
+    /// Runtime represents a target that is not mounted into the container but is used by the task  <p><br /><p>  > **Note**: `Configs.File` and `Configs.Runtime` are mutually > exclusive
+    pub fn runtime(&mut self, value: impl Into<serde_json::Value>) -> &mut Self {
+        self.runtime = Some(value.into());
+        self
+    }
+    /// ConfigID represents the ID of the specific config that we're referencing.
+    pub fn config_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.config_id = Some(value.into());
+        self
+    }
+    /// ConfigName is the name of the config that this references, but this is just provided for lookup/display purposes. The config in the reference will be identified by its ID.
+    pub fn config_name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.config_name = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecConfigsInner {
+        TaskSpecContainerSpecConfigsInner {
+            file: self.file.clone(),
+            runtime: self.runtime.clone(),
+            config_id: self.config_id.clone(),
+            config_name: self.config_name.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecConfigsInnerFile`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecConfigsInnerFileBuilder {
+    name: Option<String>,
+    uid: Option<String>,
+    gid: Option<String>,
+    mode: Option<i32>,
+}
+impl TaskSpecContainerSpecConfigsInnerFileBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name represents the final filename in the filesystem.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// UID represents the file UID.
+    pub fn uid(&mut self, value: impl Into<String>) -> &mut Self {
+        self.uid = Some(value.into());
+        self
+    }
+    /// GID represents the file GID.
+    pub fn gid(&mut self, value: impl Into<String>) -> &mut Self {
+        self.gid = Some(value.into());
+        self
+    }
+    /// Mode represents the FileMode of the file.
+    pub fn mode(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecConfigsInnerFile {
+        TaskSpecContainerSpecConfigsInnerFile {
+            name: self.name.clone(),
+            uid: self.uid.clone(),
+            gid: self.gid.clone(),
+            mode: self.mode.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecDnsConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecDnsConfigBuilder {
+    nameservers: Option<Vec<String>>,
+    search: Option<Vec<String>>,
+    options: Option<Vec<String>>,
+}
+impl TaskSpecContainerSpecDnsConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The IP addresses of the name servers.
+    pub fn nameservers(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.nameservers = Some(value.into());
+        self
+    }
+    /// A search list for host-name lookup.
+    pub fn search(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.search = Some(value.into());
+        self
+    }
+    /// A list of internal resolver variables to be modified (e.g., `debug`, `ndots:3`, etc.).
+    pub fn options(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecDnsConfig {
+        TaskSpecContainerSpecDnsConfig {
+            nameservers: self.nameservers.clone(),
+            search: self.search.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecPrivileges`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecPrivilegesBuilder {
+    credential_spec: Option<Box<TaskSpecContainerSpecPrivilegesCredentialSpec>>,
+    se_linux_context: Option<Box<TaskSpecContainerSpecPrivilegesSeLinuxContext>>,
+    seccomp: Option<Box<TaskSpecContainerSpecPrivilegesSeccomp>>,
+    app_armor: Option<Box<TaskSpecContainerSpecPrivilegesAppArmor>>,
+    no_new_privileges: Option<bool>,
+}
+impl TaskSpecContainerSpecPrivilegesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn credential_spec(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecPrivilegesCredentialSpec>>,
+    ) -> &mut Self {
+        self.credential_spec = Some(value.into());
+        self
+    }
+    pub fn se_linux_context(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecPrivilegesSeLinuxContext>>,
+    ) -> &mut Self {
+        self.se_linux_context = Some(value.into());
+        self
+    }
+    pub fn seccomp(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecPrivilegesSeccomp>>,
+    ) -> &mut Self {
+        self.seccomp = Some(value.into());
+        self
+    }
+    pub fn app_armor(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecPrivilegesAppArmor>>,
+    ) -> &mut Self {
+        self.app_armor = Some(value.into());
+        self
+    }
+    /// Configuration of the no_new_privs bit in the container
+    pub fn no_new_privileges(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.no_new_privileges = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecPrivileges {
+        TaskSpecContainerSpecPrivileges {
+            credential_spec: self.credential_spec.clone(),
+            se_linux_context: self.se_linux_context.clone(),
+            seccomp: self.seccomp.clone(),
+            app_armor: self.app_armor.clone(),
+            no_new_privileges: self.no_new_privileges.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecPrivilegesAppArmor`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecPrivilegesAppArmorBuilder {
+    mode: Option<TaskSpecContainerSpecPrivilegesAppArmorMode>,
+}
+impl TaskSpecContainerSpecPrivilegesAppArmorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn mode(
+        &mut self,
+        value: impl Into<TaskSpecContainerSpecPrivilegesAppArmorMode>,
+    ) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecPrivilegesAppArmor {
+        TaskSpecContainerSpecPrivilegesAppArmor {
+            mode: self.mode.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecPrivilegesCredentialSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecPrivilegesCredentialSpecBuilder {
+    config: Option<String>,
+    file: Option<String>,
+    registry: Option<String>,
+}
+impl TaskSpecContainerSpecPrivilegesCredentialSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Load credential spec from a Swarm Config with the given ID. The specified config must also be present in the Configs field with the Runtime property set.  <p><br /></p>   > **Note**: `CredentialSpec.File`, `CredentialSpec.Registry`, > and `CredentialSpec.Config` are mutually exclusive.
+    pub fn config(&mut self, value: impl Into<String>) -> &mut Self {
+        self.config = Some(value.into());
+        self
+    }
+    /// Load credential spec from this file. The file is read by the daemon, and must be present in the `CredentialSpecs` subdirectory in the docker data directory, which defaults to `C:\\ProgramData\\Docker\\` on Windows.  For example, specifying `spec.json` loads `C:\\ProgramData\\Docker\\CredentialSpecs\\spec.json`.  <p><br /></p>  > **Note**: `CredentialSpec.File`, `CredentialSpec.Registry`, > and `CredentialSpec.Config` are mutually exclusive.
+    pub fn file(&mut self, value: impl Into<String>) -> &mut Self {
+        self.file = Some(value.into());
+        self
+    }
+    /// Load credential spec from this value in the Windows registry. The specified registry value must be located in:  `HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\Virtualization\\Containers\\CredentialSpecs`  <p><br /></p>   > **Note**: `CredentialSpec.File`, `CredentialSpec.Registry`, > and `CredentialSpec.Config` are mutually exclusive.
+    pub fn registry(&mut self, value: impl Into<String>) -> &mut Self {
+        self.registry = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecPrivilegesCredentialSpec {
+        TaskSpecContainerSpecPrivilegesCredentialSpec {
+            config: self.config.clone(),
+            file: self.file.clone(),
+            registry: self.registry.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecPrivilegesSeLinuxContext`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecPrivilegesSeLinuxContextBuilder {
+    disable: Option<bool>,
+    user: Option<String>,
+    role: Option<String>,
+    r#type: Option<String>,
+    level: Option<String>,
+}
+impl TaskSpecContainerSpecPrivilegesSeLinuxContextBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Disable SELinux
+    pub fn disable(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.disable = Some(value.into());
+        self
+    }
+    /// SELinux user label
+    pub fn user(&mut self, value: impl Into<String>) -> &mut Self {
+        self.user = Some(value.into());
+        self
+    }
+    /// SELinux role label
+    pub fn role(&mut self, value: impl Into<String>) -> &mut Self {
+        self.role = Some(value.into());
+        self
+    }
+    /// SELinux type label
+    pub fn r#type(&mut self, value: impl Into<String>) -> &mut Self {
+        self.r#type = Some(value.into());
+        self
+    }
+    /// SELinux level label
+    pub fn level(&mut self, value: impl Into<String>) -> &mut Self {
+        self.level = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecPrivilegesSeLinuxContext {
+        TaskSpecContainerSpecPrivilegesSeLinuxContext {
+            disable: self.disable.clone(),
+            user: self.user.clone(),
+            role: self.role.clone(),
+            r#type: self.r#type.clone(),
+            level: self.level.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecPrivilegesSeccomp`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecPrivilegesSeccompBuilder {
+    mode: Option<TaskSpecContainerSpecPrivilegesSeccompMode>,
+    profile: Option<String>,
+}
+impl TaskSpecContainerSpecPrivilegesSeccompBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn mode(
+        &mut self,
+        value: impl Into<TaskSpecContainerSpecPrivilegesSeccompMode>,
+    ) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    /// The custom seccomp profile as a json object
+    pub fn profile(&mut self, value: impl Into<String>) -> &mut Self {
+        self.profile = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecPrivilegesSeccomp {
+        TaskSpecContainerSpecPrivilegesSeccomp {
+            mode: self.mode.clone(),
+            profile: self.profile.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecSecretsInner`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecSecretsInnerBuilder {
+    file: Option<Box<TaskSpecContainerSpecSecretsInnerFile>>,
+    secret_id: Option<String>,
+    secret_name: Option<String>,
+}
+impl TaskSpecContainerSpecSecretsInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn file(
+        &mut self,
+        value: impl Into<Box<TaskSpecContainerSpecSecretsInnerFile>>,
+    ) -> &mut Self {
+        self.file = Some(value.into());
+        self
+    }
+    /// SecretID represents the ID of the specific secret that we're referencing.
+    pub fn secret_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+    /// SecretName is the name of the secret that this references, but this is just provided for lookup/display purposes. The secret in the reference will be identified by its ID.
+    pub fn secret_name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecSecretsInner {
+        TaskSpecContainerSpecSecretsInner {
+            file: self.file.clone(),
+            secret_id: self.secret_id.clone(),
+            secret_name: self.secret_name.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecContainerSpecSecretsInnerFile`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecContainerSpecSecretsInnerFileBuilder {
+    name: Option<String>,
+    uid: Option<String>,
+    gid: Option<String>,
+    mode: Option<i32>,
+}
+impl TaskSpecContainerSpecSecretsInnerFileBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Name represents the final filename in the filesystem.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// UID represents the file UID.
+    pub fn uid(&mut self, value: impl Into<String>) -> &mut Self {
+        self.uid = Some(value.into());
+        self
+    }
+    /// GID represents the file GID.
+    pub fn gid(&mut self, value: impl Into<String>) -> &mut Self {
+        self.gid = Some(value.into());
+        self
+    }
+    /// Mode represents the FileMode of the file.
+    pub fn mode(&mut self, value: impl Into<i32>) -> &mut Self {
+        self.mode = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecContainerSpecSecretsInnerFile {
+        TaskSpecContainerSpecSecretsInnerFile {
+            name: self.name.clone(),
+            uid: self.uid.clone(),
+            gid: self.gid.clone(),
+            mode: self.mode.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecLogDriver`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecLogDriverBuilder {
+    name: Option<String>,
+    options: Option<std::collections::HashMap<String, String>>,
+}
+impl TaskSpecLogDriverBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    pub fn options(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.options = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecLogDriver {
+        TaskSpecLogDriver {
+            name: self.name.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecNetworkAttachmentSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecNetworkAttachmentSpecBuilder {
+    container_id: Option<String>,
+}
+impl TaskSpecNetworkAttachmentSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// ID of the container represented by this task
+    pub fn container_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.container_id = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecNetworkAttachmentSpec {
+        TaskSpecNetworkAttachmentSpec {
+            container_id: self.container_id.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecPlacement`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecPlacementBuilder {
+    constraints: Option<Vec<String>>,
+    preferences: Option<Vec<TaskSpecPlacementPreferencesInner>>,
+    max_replicas: Option<i64>,
+    platforms: Option<Vec<Platform>>,
+}
+impl TaskSpecPlacementBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// An array of constraint expressions to limit the set of nodes where a task can be scheduled. Constraint expressions can either use a _match_ (`==`) or _exclude_ (`!=`) rule. Multiple constraints find nodes that satisfy every expression (AND match). Constraints can match node or Docker Engine labels as follows:  node attribute       | matches                        | example ---------------------|--------------------------------|----------------------------------------------- `node.id`            | Node ID                        | `node.id==2ivku8v2gvtg4` `node.hostname`      | Node hostname                  | `node.hostname!=node-2` `node.role`          | Node role (`manager`/`worker`) | `node.role==manager` `node.platform.os`   | Node operating system          | `node.platform.os==windows` `node.platform.arch` | Node architecture              | `node.platform.arch==x86_64` `node.labels`        | User-defined node labels       | `node.labels.security==high` `engine.labels`      | Docker Engine's labels         | `engine.labels.operatingsystem==ubuntu-24.04`  `engine.labels` apply to Docker Engine labels like operating system, drivers, etc. Swarm administrators add `node.labels` for operational purposes by using the [`node update endpoint`](#operation/NodeUpdate).
+    pub fn constraints(&mut self, value: impl Into<Vec<String>>) -> &mut Self {
+        self.constraints = Some(value.into());
+        self
+    }
+    /// Preferences provide a way to make the scheduler aware of factors such as topology. They are provided in order from highest to lowest precedence.
+    pub fn preferences(
+        &mut self,
+        value: impl Into<Vec<TaskSpecPlacementPreferencesInner>>,
+    ) -> &mut Self {
+        self.preferences = Some(value.into());
+        self
+    }
+    /// Maximum number of replicas for per node (default value is 0, which is unlimited)
+    pub fn max_replicas(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.max_replicas = Some(value.into());
+        self
+    }
+    /// Platforms stores all the platforms that the service's image can run on. This field is used in the platform filter for scheduling. If empty, then the platform filter is off, meaning there are no scheduling restrictions.
+    pub fn platforms(&mut self, value: impl Into<Vec<Platform>>) -> &mut Self {
+        self.platforms = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecPlacement {
+        TaskSpecPlacement {
+            constraints: self.constraints.clone(),
+            preferences: self.preferences.clone(),
+            max_replicas: self.max_replicas.clone(),
+            platforms: self.platforms.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecPlacementPreferencesInner`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecPlacementPreferencesInnerBuilder {
+    spread: Option<Box<TaskSpecPlacementPreferencesInnerSpread>>,
+}
+impl TaskSpecPlacementPreferencesInnerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn spread(
+        &mut self,
+        value: impl Into<Box<TaskSpecPlacementPreferencesInnerSpread>>,
+    ) -> &mut Self {
+        self.spread = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecPlacementPreferencesInner {
+        TaskSpecPlacementPreferencesInner {
+            spread: self.spread.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecPlacementPreferencesInnerSpread`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecPlacementPreferencesInnerSpreadBuilder {
+    spread_descriptor: Option<String>,
+}
+impl TaskSpecPlacementPreferencesInnerSpreadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// label descriptor, such as `engine.labels.az`.
+    pub fn spread_descriptor(&mut self, value: impl Into<String>) -> &mut Self {
+        self.spread_descriptor = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecPlacementPreferencesInnerSpread {
+        TaskSpecPlacementPreferencesInnerSpread {
+            spread_descriptor: self.spread_descriptor.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecPluginSpec`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecPluginSpecBuilder {
+    name: Option<String>,
+    remote: Option<String>,
+    disabled: Option<bool>,
+    plugin_privilege: Option<Vec<PluginPrivilege>>,
+}
+impl TaskSpecPluginSpecBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The name or 'alias' to use for the plugin.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// The plugin image reference to use.
+    pub fn remote(&mut self, value: impl Into<String>) -> &mut Self {
+        self.remote = Some(value.into());
+        self
+    }
+    /// Disable the plugin once scheduled.
+    pub fn disabled(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.disabled = Some(value.into());
+        self
+    }
+    pub fn plugin_privilege(
+        &mut self,
+        value: impl Into<Vec<PluginPrivilege>>,
+    ) -> &mut Self {
+        self.plugin_privilege = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecPluginSpec {
+        TaskSpecPluginSpec {
+            name: self.name.clone(),
+            remote: self.remote.clone(),
+            disabled: self.disabled.clone(),
+            plugin_privilege: self.plugin_privilege.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecResources`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecResourcesBuilder {
+    limits: Option<Box<Limit>>,
+    reservations: Option<Box<ResourceObject>>,
+}
+impl TaskSpecResourcesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn limits(&mut self, value: impl Into<Box<Limit>>) -> &mut Self {
+        self.limits = Some(value.into());
+        self
+    }
+    pub fn reservations(&mut self, value: impl Into<Box<ResourceObject>>) -> &mut Self {
+        self.reservations = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecResources {
+        TaskSpecResources {
+            limits: self.limits.clone(),
+            reservations: self.reservations.clone(),
+        }
+    }
+}
+/// Builder of [`TaskSpecRestartPolicy`].
+#[derive(Clone, Debug, Default)]
+pub struct TaskSpecRestartPolicyBuilder {
+    condition: Option<TaskSpecRestartPolicyCondition>,
+    delay: Option<i64>,
+    max_attempts: Option<i64>,
+    window: Option<i64>,
+}
+impl TaskSpecRestartPolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Condition for restart.
+    pub fn condition(
+        &mut self,
+        value: impl Into<TaskSpecRestartPolicyCondition>,
+    ) -> &mut Self {
+        self.condition = Some(value.into());
+        self
+    }
+    /// Delay between restart attempts.
+    pub fn delay(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.delay = Some(value.into());
+        self
+    }
+    /// Maximum attempts to restart a given container before giving up (default value is 0, which is ignored).
+    pub fn max_attempts(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.max_attempts = Some(value.into());
+        self
+    }
+    /// Windows is the time window used to evaluate the restart policy (default value is 0, which is unbounded).
+    pub fn window(&mut self, value: impl Into<i64>) -> &mut Self {
+        self.window = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> TaskSpecRestartPolicy {
+        TaskSpecRestartPolicy {
+            condition: self.condition.clone(),
+            delay: self.delay.clone(),
+            max_attempts: self.max_attempts.clone(),
+            window: self.window.clone(),
+        }
+    }
+}
+/// Builder of [`ThrottleDevice`].
+#[derive(Clone, Debug, Default)]
+pub struct ThrottleDeviceBuilder {
+    path: Option<String>,
+    rate: Option<u64>,
+}
+impl ThrottleDeviceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Device path
+    pub fn path(&mut self, value: impl Into<String>) -> &mut Self {
+        self.path = Some(value.into());
+        self
+    }
+    /// Rate
+    pub fn rate(&mut self, value: impl Into<u64>) -> &mut Self {
+        self.rate = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> ThrottleDevice {
+        ThrottleDevice {
+            path: self.path.clone(),
+            rate: self.rate.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeCreateOptions`].
+#[derive(Clone, Debug, Default)]
+pub struct VolumeCreateOptionsBuilder {
+    name: Option<String>,
+    driver: Option<String>,
+    driver_opts: Option<std::collections::HashMap<String, String>>,
+    labels: Option<std::collections::HashMap<String, String>>,
+    cluster_volume_spec: Option<Box<ClusterVolumeSpec>>,
+}
+impl VolumeCreateOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The new volume's name. If not specified, Docker generates a name.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.name = Some(value.into());
+        self
+    }
+    /// Name of the volume driver to use.
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.driver = Some(value.into());
+        self
+    }
+    /// A mapping of driver options and values. These options are passed directly to the driver and are driver specific.
+    pub fn driver_opts(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.driver_opts = Some(value.into());
+        self
+    }
+    /// User-defined key/value metadata.
+    pub fn labels(
+        &mut self,
+        value: impl Into<std::collections::HashMap<String, String>>,
+    ) -> &mut Self {
+        self.labels = Some(value.into());
+        self
+    }
+    pub fn cluster_volume_spec(
+        &mut self,
+        value: impl Into<Box<ClusterVolumeSpec>>,
+    ) -> &mut Self {
+        self.cluster_volume_spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumeCreateOptions {
+        VolumeCreateOptions {
+            name: self.name.clone(),
+            driver: self.driver.clone(),
+            driver_opts: self.driver_opts.clone(),
+            labels: self.labels.clone(),
+            cluster_volume_spec: self.cluster_volume_spec.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeCreateParams`].
+#[derive(Clone, Debug)]
+pub struct VolumeCreateParamsBuilder {
+    volume_config: VolumeCreateOptions,
+}
+impl VolumeCreateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(volume_config: impl Into<VolumeCreateOptions>) -> Self {
+        Self {
+            volume_config: volume_config.into(),
+        }
+    }
+    pub fn build(&self) -> VolumeCreateParams {
+        VolumeCreateParams {
+            volume_config: self.volume_config.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeDeleteParams`].
+#[derive(Clone, Debug)]
+pub struct VolumeDeleteParamsBuilder {
+    name: String,
+    force: Option<bool>,
+}
+impl VolumeDeleteParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            force: None,
+        }
+    }
+    /// Force the removal of the volume
+    pub fn force(&mut self, value: impl Into<bool>) -> &mut Self {
+        self.force = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumeDeleteParams {
+        VolumeDeleteParams {
+            name: self.name.clone(),
+            force: self.force.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeInspectParams`].
+#[derive(Clone, Debug)]
+pub struct VolumeInspectParamsBuilder {
+    name: String,
+}
+impl VolumeInspectParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+    pub fn build(&self) -> VolumeInspectParams {
+        VolumeInspectParams {
+            name: self.name.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeListParams`].
+#[derive(Clone, Debug, Default)]
+pub struct VolumeListParamsBuilder {
+    filters: Option<String>,
+}
+impl VolumeListParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the volumes list. Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), returns all    volumes that are not in use by a container. When set to `false`    (or `0`), only volumes that are in use by one or more    containers are returned. - `driver=<volume-driver-name>` Matches volumes based on their driver. - `label=<key>` or `label=<key>:<value>` Matches volumes based on    the presence of a `label` alone or a `label` and a value. - `name=<volume-name>` Matches all or part of a volume name.
+    pub fn filters(&mut self, value: VolumeListFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumeListParams {
+        VolumeListParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`VolumePruneParams`].
+#[derive(Clone, Debug, Default)]
+pub struct VolumePruneParamsBuilder {
+    filters: Option<String>,
+}
+impl VolumePruneParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune volumes with (or without, in case `label!=...` is used) the specified labels. - `all` (`all=true`) - Consider all (local) volumes for pruning and not just anonymous volumes.
+    pub fn filters(&mut self, value: VolumePruneFilters) -> &mut Self {
+        self.filters = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumePruneParams {
+        VolumePruneParams {
+            filters: self.filters.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeUpdateParams`].
+#[derive(Clone, Debug)]
+pub struct VolumeUpdateParamsBuilder {
+    name: String,
+    version: i64,
+    body: Option<VolumeUpdateRequest>,
+}
+impl VolumeUpdateParamsBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(name: impl Into<String>, version: impl Into<i64>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            body: None,
+        }
+    }
+    /// The spec of the volume to update. Currently, only Availability may change. All other fields must remain unchanged.
+    pub fn body(&mut self, value: impl Into<VolumeUpdateRequest>) -> &mut Self {
+        self.body = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumeUpdateParams {
+        VolumeUpdateParams {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+/// Builder of [`VolumeUpdateRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct VolumeUpdateRequestBuilder {
+    spec: Option<Box<ClusterVolumeSpec>>,
+}
+impl VolumeUpdateRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn spec(&mut self, value: impl Into<Box<ClusterVolumeSpec>>) -> &mut Self {
+        self.spec = Some(value.into());
+        self
+    }
+    pub fn build(&self) -> VolumeUpdateRequest {
+        VolumeUpdateRequest {
+            spec: self.spec.clone(),
+        }
+    }
+}
@@ -0,0 +1,876 @@
+///Typed `filters` of the `BuildPrune` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BuildPruneFilters {
+    filters: Filters,
+}
+impl BuildPruneFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`until=<timestamp>` remove cache older than `<timestamp>`. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon's local time.
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("until", value);
+        self
+    }
+    ///`id=<id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`parent=<id>`
+    pub fn parent(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("parent", value);
+        self
+    }
+    ///`type=<string>`
+    pub fn r#type(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("type", value);
+        self
+    }
+    ///`description=<string>`
+    pub fn description(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("description", value);
+        self
+    }
+    ///`inuse`
+    pub fn inuse(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("inuse", value);
+        self
+    }
+    ///`shared`
+    pub fn shared(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("shared", value);
+        self
+    }
+    ///`private`
+    pub fn private(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("private", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<BuildPruneFilters> for String {
+    fn from(value: BuildPruneFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ConfigList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigListFilters {
+    filters: Filters,
+}
+impl ConfigListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`id=<config id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=<key> or label=<key>=value`
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<config name>`
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`names=<config name>`
+    pub fn names(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("names", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ConfigListFilters> for String {
+    fn from(value: ConfigListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ContainerList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerListFilters {
+    filters: Filters,
+}
+impl ContainerListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`ancestor`=(`<image-name>[:<tag>]`, `<image id>`, or `<image@digest>`)
+    pub fn ancestor(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("ancestor", value);
+        self
+    }
+    ///`before`=(`<container id>` or `<container name>`)
+    pub fn before(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("before", value);
+        self
+    }
+    ///`expose`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`)
+    pub fn expose(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("expose", value);
+        self
+    }
+    ///`exited=<int>` containers with exit code of `<int>`
+    pub fn exited(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("exited", value);
+        self
+    }
+    ///`health`=(`starting`|`healthy`|`unhealthy`|`none`)
+    pub fn health(&mut self, value: ContainerListHealth) -> &mut Self {
+        self.filters.with("health", value.as_str());
+        self
+    }
+    ///`id=<ID>` a container's ID
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`isolation=`(`default`|`process`|`hyperv`) (Windows daemon only)
+    pub fn isolation(&mut self, value: ContainerListIsolation) -> &mut Self {
+        self.filters.with("isolation", value.as_str());
+        self
+    }
+    ///`is-task=`(`true`|`false`)
+    pub fn is_task(&mut self, value: bool) -> &mut Self {
+        self.filters.with("is-task", value.to_string());
+        self
+    }
+    ///`label=key` or `label="key=value"` of a container label
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<name>` a container's name
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`network`=(`<network id>` or `<network name>`)
+    pub fn network(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("network", value);
+        self
+    }
+    ///`publish`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`)
+    pub fn publish(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("publish", value);
+        self
+    }
+    ///`since`=(`<container id>` or `<container name>`)
+    pub fn since(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("since", value);
+        self
+    }
+    ///`status=`(`created`|`restarting`|`running`|`removing`|`paused`|`exited`|`dead`)
+    pub fn status(&mut self, value: ContainerListStatus) -> &mut Self {
+        self.filters.with("status", value.as_str());
+        self
+    }
+    ///`volume`=(`<volume name>` or `<mount point destination>`)
+    pub fn volume(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("volume", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ContainerListFilters> for String {
+    fn from(value: ContainerListFilters) -> Self {
+        value.to_json()
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerListHealth {
+    Starting,
+    Healthy,
+    Unhealthy,
+    None,
+}
+impl ContainerListHealth {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Starting => "starting",
+            Self::Healthy => "healthy",
+            Self::Unhealthy => "unhealthy",
+            Self::None => "none",
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerListIsolation {
+    Default,
+    Process,
+    Hyperv,
+}
+impl ContainerListIsolation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Process => "process",
+            Self::Hyperv => "hyperv",
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerListStatus {
+    Created,
+    Restarting,
+    Running,
+    Removing,
+    Paused,
+    Exited,
+    Dead,
+}
+impl ContainerListStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Restarting => "restarting",
+            Self::Running => "running",
+            Self::Removing => "removing",
+            Self::Paused => "paused",
+            Self::Exited => "exited",
+            Self::Dead => "dead",
+        }
+    }
+}
+///Typed `filters` of the `ContainerPrune` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerPruneFilters {
+    filters: Filters,
+}
+impl ContainerPruneFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`until=<timestamp>` Prune containers created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("until", value);
+        self
+    }
+    ///`label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune containers with (or without, in case `label!=...` is used) the specified labels.
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ContainerPruneFilters> for String {
+    fn from(value: ContainerPruneFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ImageList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImageListFilters {
+    filters: Filters,
+}
+impl ImageListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`before`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`)
+    pub fn before(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("before", value);
+        self
+    }
+    ///`dangling=true`
+    pub fn dangling(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("dangling", value);
+        self
+    }
+    ///`label=key` or `label="key=value"` of an image label
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`reference`=(`<image-name>[:<tag>]`)
+    pub fn reference(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("reference", value);
+        self
+    }
+    ///`since`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`)
+    pub fn since(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("since", value);
+        self
+    }
+    ///`until=<timestamp>`
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("until", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ImageListFilters> for String {
+    fn from(value: ImageListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ImagePrune` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImagePruneFilters {
+    filters: Filters,
+}
+impl ImagePruneFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`dangling=<boolean>` When set to `true` (or `1`), prune only
+    pub fn dangling(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("dangling", value);
+        self
+    }
+    ///`until=<string>` Prune images created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("until", value);
+        self
+    }
+    ///`label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune images with (or without, in case `label!=...` is used) the specified labels.
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ImagePruneFilters> for String {
+    fn from(value: ImagePruneFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ImageSearch` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImageSearchFilters {
+    filters: Filters,
+}
+impl ImageSearchFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`is-official=(true|false)`
+    pub fn is_official(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("is-official", value);
+        self
+    }
+    ///`stars=<number>` Matches images that has at least 'number' stars.
+    pub fn stars(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("stars", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ImageSearchFilters> for String {
+    fn from(value: ImageSearchFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `NetworkList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkListFilters {
+    filters: Filters,
+}
+impl NetworkListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`dangling=<boolean>` When set to `true` (or `1`), returns all
+    pub fn dangling(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("dangling", value);
+        self
+    }
+    ///`driver=<driver-name>` Matches a network's driver.
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("driver", value);
+        self
+    }
+    ///`id=<network-id>` Matches all or part of a network ID.
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=<key>` or `label=<key>=<value>` of a network label.
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<network-name>` Matches all or part of a network name.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`scope=["swarm"|"global"|"local"]` Filters networks by scope (`swarm`, `global`, or `local`).
+    pub fn scope(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("scope", value);
+        self
+    }
+    ///`type=["custom"|"builtin"]` Filters networks by type. The `custom` keyword returns all user-defined networks.
+    pub fn r#type(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("type", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<NetworkListFilters> for String {
+    fn from(value: NetworkListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `NetworkPrune` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkPruneFilters {
+    filters: Filters,
+}
+impl NetworkPruneFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`until=<timestamp>` Prune networks created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time.
+    pub fn until(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("until", value);
+        self
+    }
+    ///`label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune networks with (or without, in case `label!=...` is used) the specified labels.
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<NetworkPruneFilters> for String {
+    fn from(value: NetworkPruneFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `NodeList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeListFilters {
+    filters: Filters,
+}
+impl NodeListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`id=<node id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=<engine label>`
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`membership=`(`accepted`|`pending`)`
+    pub fn membership(&mut self, value: NodeListMembership) -> &mut Self {
+        self.filters.with("membership", value.as_str());
+        self
+    }
+    ///`name=<node name>`
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`node.label=<node label>`
+    pub fn node_label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("node.label", value);
+        self
+    }
+    ///`role=`(`manager`|`worker`)`
+    pub fn role(&mut self, value: NodeListRole) -> &mut Self {
+        self.filters.with("role", value.as_str());
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<NodeListFilters> for String {
+    fn from(value: NodeListFilters) -> Self {
+        value.to_json()
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeListMembership {
+    Accepted,
+    Pending,
+}
+impl NodeListMembership {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Pending => "pending",
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeListRole {
+    Manager,
+    Worker,
+}
+impl NodeListRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manager => "manager",
+            Self::Worker => "worker",
+        }
+    }
+}
+///Typed `filters` of the `PluginList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginListFilters {
+    filters: Filters,
+}
+impl PluginListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`capability=<capability name>`
+    pub fn capability(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("capability", value);
+        self
+    }
+    ///`enable=<true>|<false>`
+    pub fn enable(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("enable", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<PluginListFilters> for String {
+    fn from(value: PluginListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `SecretList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SecretListFilters {
+    filters: Filters,
+}
+impl SecretListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`id=<secret id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=<key> or label=<key>=value`
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<secret name>`
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`names=<secret name>`
+    pub fn names(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("names", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<SecretListFilters> for String {
+    fn from(value: SecretListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `ServiceList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServiceListFilters {
+    filters: Filters,
+}
+impl ServiceListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`id=<service id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=<service label>`
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`mode=["replicated"|"global"]`
+    pub fn mode(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("mode", value);
+        self
+    }
+    ///`name=<service name>`
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<ServiceListFilters> for String {
+    fn from(value: ServiceListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `SystemEvents` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemEventsFilters {
+    filters: Filters,
+}
+impl SystemEventsFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`config=<string>` config name or ID
+    pub fn config(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("config", value);
+        self
+    }
+    ///`container=<string>` container name or ID
+    pub fn container(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("container", value);
+        self
+    }
+    ///`daemon=<string>` daemon name or ID
+    pub fn daemon(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("daemon", value);
+        self
+    }
+    ///`event=<string>` event type
+    pub fn event(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("event", value);
+        self
+    }
+    ///`image=<string>` image name or ID
+    pub fn image(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("image", value);
+        self
+    }
+    ///`label=<string>` image or container label
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`network=<string>` network name or ID
+    pub fn network(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("network", value);
+        self
+    }
+    ///`node=<string>` node ID
+    pub fn node(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("node", value);
+        self
+    }
+    ///`plugin`=<string> plugin name or ID
+    pub fn plugin(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("plugin", value);
+        self
+    }
+    ///`scope`=<string> local or swarm
+    pub fn scope(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("scope", value);
+        self
+    }
+    ///`secret=<string>` secret name or ID
+    pub fn secret(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("secret", value);
+        self
+    }
+    ///`service=<string>` service name or ID
+    pub fn service(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("service", value);
+        self
+    }
+    ///`type=<string>` object to filter by, one of `container`, `image`, `volume`, `network`, `daemon`, `plugin`, `node`, `service`, `secret` or `config`
+    pub fn r#type(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("type", value);
+        self
+    }
+    ///`volume=<string>` volume name
+    pub fn volume(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("volume", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<SystemEventsFilters> for String {
+    fn from(value: SystemEventsFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `TaskList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskListFilters {
+    filters: Filters,
+}
+impl TaskListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`desired-state=(running | shutdown | accepted)`
+    pub fn desired_state(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("desired-state", value);
+        self
+    }
+    ///`id=<task id>`
+    pub fn id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("id", value);
+        self
+    }
+    ///`label=key` or `label="key=value"`
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<task name>`
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    ///`node=<node id or name>`
+    pub fn node(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("node", value);
+        self
+    }
+    ///`service=<service name>`
+    pub fn service(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("service", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<TaskListFilters> for String {
+    fn from(value: TaskListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `VolumeList` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeListFilters {
+    filters: Filters,
+}
+impl VolumeListFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`dangling=<boolean>` When set to `true` (or `1`), returns all
+    pub fn dangling(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("dangling", value);
+        self
+    }
+    ///`driver=<volume-driver-name>` Matches volumes based on their driver.
+    pub fn driver(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("driver", value);
+        self
+    }
+    ///`label=<key>` or `label=<key>:<value>` Matches volumes based on
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`name=<volume-name>` Matches all or part of a volume name.
+    pub fn name(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("name", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<VolumeListFilters> for String {
+    fn from(value: VolumeListFilters) -> Self {
+        value.to_json()
+    }
+}
+///Typed `filters` of the `VolumePrune` operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumePruneFilters {
+    filters: Filters,
+}
+impl VolumePruneFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///`label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune volumes with (or without, in case `label!=...` is used) the specified labels.
+    pub fn label(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("label", value);
+        self
+    }
+    ///`all` (`all=true`) - Consider all (local) volumes for pruning and not just anonymous volumes.
+    pub fn all(&mut self, value: impl Into<String>) -> &mut Self {
+        self.filters.with("all", value);
+        self
+    }
+    /// JSON value of the `filters` parameter.
+    pub fn to_json(&self) -> String {
+        self.filters.to_json()
+    }
+}
+/// Lets the `filters` field of the params builder take the typed filters.
+impl From<VolumePruneFilters> for String {
+    fn from(value: VolumePruneFilters) -> Self {
+        value.to_json()
+    }
+}
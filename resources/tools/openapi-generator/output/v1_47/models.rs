@@ -1,5 +1,5 @@
-use crate::models;
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 /// Address : Address represents an IPv4 or IPv6 IP address.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Address {
@@ -19,8 +19,6 @@ impl Address {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AuthConfig {
     #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
@@ -42,8 +40,6 @@ impl AuthConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// BuildCache : BuildCache contains information about a build cache record.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BuildCache {
@@ -68,7 +64,7 @@ pub struct BuildCache {
     pub parents: Option<Option<Vec<String>>>,
     /// Cache record type.
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<Type>,
+    pub r#type: Option<BuildCacheType>,
     /// Description of the build-step that produced the build cache.
     #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -126,7 +122,7 @@ impl BuildCache {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum BuildCacheType {
     #[serde(rename = "internal")]
     Internal,
     #[serde(rename = "frontend")]
@@ -140,13 +136,11 @@ pub enum Type {
     #[serde(rename = "regular")]
     Regular,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for BuildCacheType {
+    fn default() -> BuildCacheType {
         Self::Internal
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BuildInfo {
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
@@ -156,15 +150,15 @@ pub struct BuildInfo {
     #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     #[serde(rename = "errorDetail", skip_serializing_if = "Option::is_none")]
-    pub error_detail: Option<Box<models::ErrorDetail>>,
+    pub error_detail: Option<Box<ErrorDetail>>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     #[serde(rename = "progress", skip_serializing_if = "Option::is_none")]
     pub progress: Option<String>,
     #[serde(rename = "progressDetail", skip_serializing_if = "Option::is_none")]
-    pub progress_detail: Option<Box<models::ProgressDetail>>,
+    pub progress_detail: Option<Box<ProgressDetail>>,
     #[serde(rename = "aux", skip_serializing_if = "Option::is_none")]
-    pub aux: Option<Box<models::ImageId>>,
+    pub aux: Option<Box<ImageId>>,
 }
 impl BuildInfo {
     pub fn new() -> BuildInfo {
@@ -180,8 +174,6 @@ impl BuildInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BuildPruneResponse {
     #[serde(rename = "CachesDeleted", skip_serializing_if = "Option::is_none")]
@@ -198,9 +190,6 @@ impl BuildPruneResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 /// ChangeType : Kind of change  Can be one of:  - `0`: Modified (\"C\") - `1`: Added (\"A\") - `2`: Deleted (\"D\")
 /// Kind of change  Can be one of:  - `0`: Modified (\"C\") - `1`: Added (\"A\") - `2`: Deleted (\"D\")
 #[repr(i64)]
@@ -234,8 +223,6 @@ impl Default for ChangeType {
         Self::Variant0
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterInfo : ClusterInfo represents information about the swarm as is returned by the \"/info\" endpoint. Join-tokens are not included.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterInfo {
@@ -243,7 +230,7 @@ pub struct ClusterInfo {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     /// Date and time at which the swarm was initialised in [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) format with nano-seconds.
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
@@ -251,9 +238,9 @@ pub struct ClusterInfo {
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::SwarmSpec>>,
+    pub spec: Option<Box<SwarmSpec>>,
     #[serde(rename = "TLSInfo", skip_serializing_if = "Option::is_none")]
-    pub tls_info: Option<Box<models::TlsInfo>>,
+    pub tls_info: Option<Box<TlsInfo>>,
     /// Whether there is currently a root CA rotation in progress for the swarm
     #[serde(rename = "RootRotationInProgress", skip_serializing_if = "Option::is_none")]
     pub root_rotation_in_progress: Option<bool>,
@@ -284,8 +271,6 @@ impl ClusterInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolume : Options and information specific to, and only present on, Swarm CSI cluster volumes.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolume {
@@ -293,18 +278,18 @@ pub struct ClusterVolume {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::ClusterVolumeSpec>>,
+    pub spec: Option<Box<ClusterVolumeSpec>>,
     #[serde(rename = "Info", skip_serializing_if = "Option::is_none")]
-    pub info: Option<Box<models::ClusterVolumeInfo>>,
+    pub info: Option<Box<ClusterVolumeInfo>>,
     /// The status of the volume as it pertains to its publishing and use on specific nodes
     #[serde(rename = "PublishStatus", skip_serializing_if = "Option::is_none")]
-    pub publish_status: Option<Vec<models::ClusterVolumePublishStatusInner>>,
+    pub publish_status: Option<Vec<ClusterVolumePublishStatusInner>>,
 }
 impl ClusterVolume {
     /// Options and information specific to, and only present on, Swarm CSI cluster volumes.
@@ -320,8 +305,6 @@ impl ClusterVolume {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeInfo : Information about the global status of the volume.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeInfo {
@@ -349,8 +332,6 @@ impl ClusterVolumeInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumePublishStatusInner {
     /// The ID of the Swarm node the volume is published on.
@@ -358,7 +339,7 @@ pub struct ClusterVolumePublishStatusInner {
     pub node_id: Option<String>,
     /// The published state of the volume. * `pending-publish` The volume should be published to this node, but the call to the controller plugin to do so has not yet been successfully completed. * `published` The volume is published successfully to the node. * `pending-node-unpublish` The volume should be unpublished from the node, and the manager is awaiting confirmation from the worker that it has done so. * `pending-controller-unpublish` The volume is successfully unpublished from the node, but has not yet been successfully unpublished on the controller.
     #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
-    pub state: Option<State>,
+    pub state: Option<ClusterVolumePublishStatusInnerState>,
     /// A map of strings to strings returned by the CSI controller plugin when a volume is published.
     #[serde(rename = "PublishContext", skip_serializing_if = "Option::is_none")]
     pub publish_context: Option<std::collections::HashMap<String, String>>,
@@ -385,7 +366,7 @@ impl ClusterVolumePublishStatusInner {
     Serialize,
     Deserialize
 )]
-pub enum State {
+pub enum ClusterVolumePublishStatusInnerState {
     #[serde(rename = "pending-publish")]
     PendingPublish,
     #[serde(rename = "published")]
@@ -395,13 +376,11 @@ pub enum State {
     #[serde(rename = "pending-controller-unpublish")]
     PendingControllerUnpublish,
 }
-impl Default for State {
-    fn default() -> State {
+impl Default for ClusterVolumePublishStatusInnerState {
+    fn default() -> ClusterVolumePublishStatusInnerState {
         Self::PendingPublish
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeSpec : Cluster-specific options used to create the volume.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeSpec {
@@ -409,7 +388,7 @@ pub struct ClusterVolumeSpec {
     #[serde(rename = "Group", skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
     #[serde(rename = "AccessMode", skip_serializing_if = "Option::is_none")]
-    pub access_mode: Option<Box<models::ClusterVolumeSpecAccessMode>>,
+    pub access_mode: Option<Box<ClusterVolumeSpecAccessMode>>,
 }
 impl ClusterVolumeSpec {
     /// Cluster-specific options used to create the volume.
@@ -420,35 +399,33 @@ impl ClusterVolumeSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeSpecAccessMode : Defines how the volume is used by tasks.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeSpecAccessMode {
     /// The set of nodes this volume can be used on at one time. - `single` The volume may only be scheduled to one node at a time. - `multi` the volume may be scheduled to any supported number of nodes at a time.
     #[serde(rename = "Scope", skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Scope>,
+    pub scope: Option<ClusterVolumeSpecAccessModeScope>,
     /// The number and way that different tasks can use this volume at one time. - `none` The volume may only be used by one task at a time. - `readonly` The volume may be used by any number of tasks, but they all must mount the volume as readonly - `onewriter` The volume may be used by any number of tasks, but only one may mount it as read/write. - `all` The volume may have any number of readers and writers.
     #[serde(rename = "Sharing", skip_serializing_if = "Option::is_none")]
-    pub sharing: Option<Sharing>,
+    pub sharing: Option<ClusterVolumeSpecAccessModeSharing>,
     /// Options for using this volume as a Mount-type volume.      Either MountVolume or BlockVolume, but not both, must be     present.   properties:     FsType:       type: \"string\"       description: |         Specifies the filesystem type for the mount volume.         Optional.     MountFlags:       type: \"array\"       description: |         Flags to pass when mounting the volume. Optional.       items:         type: \"string\" BlockVolume:   type: \"object\"   description: |     Options for using this volume as a Block-type volume.     Intentionally empty.
     #[serde(rename = "MountVolume", skip_serializing_if = "Option::is_none")]
     pub mount_volume: Option<serde_json::Value>,
     /// Swarm Secrets that are passed to the CSI storage plugin when operating on this volume.
     #[serde(rename = "Secrets", skip_serializing_if = "Option::is_none")]
-    pub secrets: Option<Vec<models::ClusterVolumeSpecAccessModeSecretsInner>>,
+    pub secrets: Option<Vec<ClusterVolumeSpecAccessModeSecretsInner>>,
     #[serde(
         rename = "AccessibilityRequirements",
         skip_serializing_if = "Option::is_none"
     )]
     pub accessibility_requirements: Option<
-        Box<models::ClusterVolumeSpecAccessModeAccessibilityRequirements>,
+        Box<ClusterVolumeSpecAccessModeAccessibilityRequirements>,
     >,
     #[serde(rename = "CapacityRange", skip_serializing_if = "Option::is_none")]
-    pub capacity_range: Option<Box<models::ClusterVolumeSpecAccessModeCapacityRange>>,
+    pub capacity_range: Option<Box<ClusterVolumeSpecAccessModeCapacityRange>>,
     /// The availability of the volume for use in tasks. - `active` The volume is fully available for scheduling on the cluster - `pause` No new workloads should use the volume, but existing workloads are not stopped. - `drain` All workloads using this volume should be stopped and rescheduled, and no new ones should be started.
     #[serde(rename = "Availability", skip_serializing_if = "Option::is_none")]
-    pub availability: Option<Availability>,
+    pub availability: Option<ClusterVolumeSpecAccessModeAvailability>,
 }
 impl ClusterVolumeSpecAccessMode {
     /// Defines how the volume is used by tasks.
@@ -477,14 +454,14 @@ impl ClusterVolumeSpecAccessMode {
     Serialize,
     Deserialize
 )]
-pub enum Scope {
+pub enum ClusterVolumeSpecAccessModeScope {
     #[serde(rename = "single")]
     Single,
     #[serde(rename = "multi")]
     Multi,
 }
-impl Default for Scope {
-    fn default() -> Scope {
+impl Default for ClusterVolumeSpecAccessModeScope {
+    fn default() -> ClusterVolumeSpecAccessModeScope {
         Self::Single
     }
 }
@@ -501,7 +478,7 @@ impl Default for Scope {
     Serialize,
     Deserialize
 )]
-pub enum Sharing {
+pub enum ClusterVolumeSpecAccessModeSharing {
     #[serde(rename = "none")]
     None,
     #[serde(rename = "readonly")]
@@ -511,8 +488,8 @@ pub enum Sharing {
     #[serde(rename = "all")]
     All,
 }
-impl Default for Sharing {
-    fn default() -> Sharing {
+impl Default for ClusterVolumeSpecAccessModeSharing {
+    fn default() -> ClusterVolumeSpecAccessModeSharing {
         Self::None
     }
 }
@@ -529,7 +506,7 @@ impl Default for Sharing {
     Serialize,
     Deserialize
 )]
-pub enum Availability {
+pub enum ClusterVolumeSpecAccessModeAvailability {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "pause")]
@@ -537,13 +514,11 @@ pub enum Availability {
     #[serde(rename = "drain")]
     Drain,
 }
-impl Default for Availability {
-    fn default() -> Availability {
+impl Default for ClusterVolumeSpecAccessModeAvailability {
+    fn default() -> ClusterVolumeSpecAccessModeAvailability {
         Self::Active
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeSpecAccessModeAccessibilityRequirements : Requirements for the accessible topology of the volume. These fields are optional. For an in-depth description of what these fields mean, see the CSI specification.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeSpecAccessModeAccessibilityRequirements {
@@ -563,8 +538,6 @@ impl ClusterVolumeSpecAccessModeAccessibilityRequirements {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeSpecAccessModeCapacityRange : The desired capacity that the volume should be created with. If empty, the plugin will decide the capacity.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeSpecAccessModeCapacityRange {
@@ -584,8 +557,6 @@ impl ClusterVolumeSpecAccessModeCapacityRange {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ClusterVolumeSpecAccessModeSecretsInner : One cluster volume secret entry. Defines a key-value pair that is passed to the plugin.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterVolumeSpecAccessModeSecretsInner {
@@ -605,8 +576,6 @@ impl ClusterVolumeSpecAccessModeSecretsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Commit : Commit holds the Git-commit (SHA1) that a binary was built from, as reported in the version-string of external tools, such as `containerd`, or `runC`.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Commit {
@@ -623,20 +592,18 @@ impl Commit {
         Commit { id: None, expected: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Config {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::ConfigSpec>>,
+    pub spec: Option<Box<ConfigSpec>>,
 }
 impl Config {
     pub fn new() -> Config {
@@ -649,8 +616,6 @@ impl Config {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigCreateRequest {
     /// User-defined name of the config.
@@ -663,7 +628,7 @@ pub struct ConfigCreateRequest {
     #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     #[serde(rename = "Templating", skip_serializing_if = "Option::is_none")]
-    pub templating: Option<Box<models::Driver>>,
+    pub templating: Option<Box<Driver>>,
 }
 impl ConfigCreateRequest {
     pub fn new() -> ConfigCreateRequest {
@@ -675,8 +640,6 @@ impl ConfigCreateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ConfigReference : The config-only network source to provide the configuration for this network.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigReference {
@@ -690,8 +653,6 @@ impl ConfigReference {
         ConfigReference { network: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigSpec {
     /// User-defined name of the config.
@@ -704,7 +665,7 @@ pub struct ConfigSpec {
     #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     #[serde(rename = "Templating", skip_serializing_if = "Option::is_none")]
-    pub templating: Option<Box<models::Driver>>,
+    pub templating: Option<Box<Driver>>,
 }
 impl ConfigSpec {
     pub fn new() -> ConfigSpec {
@@ -716,8 +677,6 @@ impl ConfigSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerConfig : Configuration for a container that is portable between hosts.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerConfig {
@@ -765,7 +724,7 @@ pub struct ContainerConfig {
     #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
     pub cmd: Option<Vec<String>>,
     #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
-    pub healthcheck: Option<Box<models::HealthConfig>>,
+    pub healthcheck: Option<Box<HealthConfig>>,
     /// Command is already escaped (Windows only)
     #[serde(
         rename = "ArgsEscaped",
@@ -870,8 +829,6 @@ impl ContainerConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerCreateRequest {
     /// The hostname to use for the container, as a valid RFC 1123 hostname.
@@ -918,7 +875,7 @@ pub struct ContainerCreateRequest {
     #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
     pub cmd: Option<Vec<String>>,
     #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
-    pub healthcheck: Option<Box<models::HealthConfig>>,
+    pub healthcheck: Option<Box<HealthConfig>>,
     /// Command is already escaped (Windows only)
     #[serde(
         rename = "ArgsEscaped",
@@ -991,9 +948,9 @@ pub struct ContainerCreateRequest {
     )]
     pub shell: Option<Option<Vec<String>>>,
     #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
-    pub host_config: Option<Box<models::HostConfig>>,
+    pub host_config: Option<Box<HostConfig>>,
     #[serde(rename = "NetworkingConfig", skip_serializing_if = "Option::is_none")]
-    pub networking_config: Option<Box<models::NetworkingConfig>>,
+    pub networking_config: Option<Box<NetworkingConfig>>,
 }
 impl ContainerCreateRequest {
     pub fn new() -> ContainerCreateRequest {
@@ -1028,8 +985,6 @@ impl ContainerCreateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerCreateResponse : OK response to ContainerCreate operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerCreateResponse {
@@ -1049,8 +1004,6 @@ impl ContainerCreateResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerInspectResponse {
     /// The ID of the container
@@ -1071,7 +1024,7 @@ pub struct ContainerInspectResponse {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub state: Option<Option<Box<models::ContainerState>>>,
+    pub state: Option<Option<Box<ContainerState>>>,
     /// The container's image ID
     #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
     pub image: Option<String>,
@@ -1106,9 +1059,9 @@ pub struct ContainerInspectResponse {
     )]
     pub exec_ids: Option<Option<Vec<String>>>,
     #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
-    pub host_config: Option<Box<models::HostConfig>>,
+    pub host_config: Option<Box<HostConfig>>,
     #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
-    pub graph_driver: Option<Box<models::DriverData>>,
+    pub graph_driver: Option<Box<DriverData>>,
     /// The size of files that have been created or changed by this container.
     #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none")]
     pub size_rw: Option<i64>,
@@ -1116,11 +1069,11 @@ pub struct ContainerInspectResponse {
     #[serde(rename = "SizeRootFs", skip_serializing_if = "Option::is_none")]
     pub size_root_fs: Option<i64>,
     #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
-    pub mounts: Option<Vec<models::MountPoint>>,
+    pub mounts: Option<Vec<MountPoint>>,
     #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
-    pub config: Option<Box<models::ContainerConfig>>,
+    pub config: Option<Box<ContainerConfig>>,
     #[serde(rename = "NetworkSettings", skip_serializing_if = "Option::is_none")]
-    pub network_settings: Option<Box<models::NetworkSettings>>,
+    pub network_settings: Option<Box<NetworkSettings>>,
 }
 impl ContainerInspectResponse {
     pub fn new() -> ContainerInspectResponse {
@@ -1153,8 +1106,6 @@ impl ContainerInspectResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerPruneResponse {
     /// Container IDs that were deleted
@@ -1172,14 +1123,12 @@ impl ContainerPruneResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerState : ContainerState stores container's running state. It's part of ContainerJSONBase and will be returned by the \"inspect\" command.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerState {
     /// String representation of the container state. Can be one of \"created\", \"running\", \"paused\", \"restarting\", \"removing\", \"exited\", or \"dead\".
     #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
+    pub status: Option<ContainerStateStatus>,
     /// Whether this container is running.  Note that a running container can be _paused_. The `Running` and `Paused` booleans are not mutually exclusive:  When pausing a container (on Linux), the freezer cgroup is used to suspend all processes in the container. Freezing the process requires the process to be running. As a result, paused containers are both `Running` _and_ `Paused`.  Use the `Status` field instead to determine if a container's state is \"running\".
     #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
     pub running: Option<bool>,
@@ -1214,7 +1163,7 @@ pub struct ContainerState {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub health: Option<Option<Box<models::Health>>>,
+    pub health: Option<Option<Box<Health>>>,
 }
 impl ContainerState {
     /// ContainerState stores container's running state. It's part of ContainerJSONBase and will be returned by the \"inspect\" command.
@@ -1248,7 +1197,7 @@ impl ContainerState {
     Serialize,
     Deserialize
 )]
-pub enum Status {
+pub enum ContainerStateStatus {
     #[serde(rename = "created")]
     Created,
     #[serde(rename = "running")]
@@ -1264,13 +1213,11 @@ pub enum Status {
     #[serde(rename = "dead")]
     Dead,
 }
-impl Default for Status {
-    fn default() -> Status {
+impl Default for ContainerStateStatus {
+    fn default() -> ContainerStateStatus {
         Self::Created
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerStatus : represents the status of a container.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerStatus {
@@ -1291,8 +1238,6 @@ impl ContainerStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSummary {
     /// The ID of this container
@@ -1315,7 +1260,7 @@ pub struct ContainerSummary {
     pub created: Option<i64>,
     /// The ports exposed by this container
     #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
-    pub ports: Option<Vec<models::Port>>,
+    pub ports: Option<Vec<Port>>,
     /// The size of files that have been created or changed by this container
     #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none")]
     pub size_rw: Option<i64>,
@@ -1332,11 +1277,11 @@ pub struct ContainerSummary {
     #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
-    pub host_config: Option<Box<models::ContainerSummaryHostConfig>>,
+    pub host_config: Option<Box<ContainerSummaryHostConfig>>,
     #[serde(rename = "NetworkSettings", skip_serializing_if = "Option::is_none")]
-    pub network_settings: Option<Box<models::ContainerSummaryNetworkSettings>>,
+    pub network_settings: Option<Box<ContainerSummaryNetworkSettings>>,
     #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
-    pub mounts: Option<Vec<models::MountPoint>>,
+    pub mounts: Option<Vec<MountPoint>>,
 }
 impl ContainerSummary {
     pub fn new() -> ContainerSummary {
@@ -1359,8 +1304,6 @@ impl ContainerSummary {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSummaryHostConfig {
     #[serde(rename = "NetworkMode", skip_serializing_if = "Option::is_none")]
@@ -1382,13 +1325,11 @@ impl ContainerSummaryHostConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerSummaryNetworkSettings : A summary of the container's network settings
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSummaryNetworkSettings {
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<std::collections::HashMap<String, models::EndpointSettings>>,
+    pub networks: Option<std::collections::HashMap<String, EndpointSettings>>,
 }
 impl ContainerSummaryNetworkSettings {
     /// A summary of the container's network settings
@@ -1398,8 +1339,6 @@ impl ContainerSummaryNetworkSettings {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerTopResponse : OK response to ContainerTop operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerTopResponse {
@@ -1419,8 +1358,6 @@ impl ContainerTopResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerUpdateRequest {
     /// An integer value representing this container's relative CPU weight versus other containers.
@@ -1437,19 +1374,19 @@ pub struct ContainerUpdateRequest {
     pub blkio_weight: Option<u32>,
     /// Block IO weight (relative device weight) in the form:  ``` [{\"Path\": \"device_path\", \"Weight\": weight}] ```
     #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
-    pub blkio_weight_device: Option<Vec<models::ResourcesBlkioWeightDeviceInner>>,
+    pub blkio_weight_device: Option<Vec<ResourcesBlkioWeightDeviceInner>>,
     /// Limit read rate (bytes per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (bytes per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
     /// Limit read rate (IO per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (IO per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
     /// The length of a CPU period in microseconds.
     #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
     pub cpu_period: Option<i64>,
@@ -1470,13 +1407,13 @@ pub struct ContainerUpdateRequest {
     pub cpuset_mems: Option<String>,
     /// A list of devices to add to the container.
     #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
-    pub devices: Option<Vec<models::DeviceMapping>>,
+    pub devices: Option<Vec<DeviceMapping>>,
     /// a list of cgroup rules to apply to the container
     #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
     pub device_cgroup_rules: Option<Vec<String>>,
     /// A list of requests for devices to be sent to device drivers.
     #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
-    pub device_requests: Option<Vec<models::DeviceRequest>>,
+    pub device_requests: Option<Vec<DeviceRequest>>,
     /// Hard limit for kernel TCP buffer memory (in bytes). Depending on the OCI runtime in use, this option may be ignored. It is no longer supported by the default (runc) runtime.  This field is omitted when empty.
     #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
     pub kernel_memory_tcp: Option<i64>,
@@ -1513,7 +1450,7 @@ pub struct ContainerUpdateRequest {
     pub pids_limit: Option<Option<i64>>,
     /// A list of resource limits to set in the container. For example:  ``` {\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048} ```
     #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
-    pub ulimits: Option<Vec<models::ResourcesUlimitsInner>>,
+    pub ulimits: Option<Vec<ResourcesUlimitsInner>>,
     /// The number of usable CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
     #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
     pub cpu_count: Option<i64>,
@@ -1527,7 +1464,7 @@ pub struct ContainerUpdateRequest {
     #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none")]
     pub io_maximum_bandwidth: Option<i64>,
     #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
-    pub restart_policy: Option<Box<models::RestartPolicy>>,
+    pub restart_policy: Option<Box<RestartPolicy>>,
 }
 impl ContainerUpdateRequest {
     pub fn new() -> ContainerUpdateRequest {
@@ -1567,8 +1504,6 @@ impl ContainerUpdateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerUpdateResponse : OK response to ContainerUpdate operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerUpdateResponse {
@@ -1583,8 +1518,6 @@ impl ContainerUpdateResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerWaitExitError : container waiting error, if any
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerWaitExitError {
@@ -1600,8 +1533,6 @@ impl ContainerWaitExitError {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerWaitResponse : OK response to ContainerWait operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerWaitResponse {
@@ -1609,7 +1540,7 @@ pub struct ContainerWaitResponse {
     #[serde(rename = "StatusCode")]
     pub status_code: i64,
     #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
-    pub error: Option<Box<models::ContainerWaitExitError>>,
+    pub error: Option<Box<ContainerWaitExitError>>,
 }
 impl ContainerWaitResponse {
     /// OK response to ContainerWait operation
@@ -1620,8 +1551,6 @@ impl ContainerWaitResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerdInfo : Information for connecting to the containerd instance that is used by the daemon. This is included for debugging purposes only.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerdInfo {
@@ -1629,7 +1558,7 @@ pub struct ContainerdInfo {
     #[serde(rename = "Address", skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
     #[serde(rename = "Namespaces", skip_serializing_if = "Option::is_none")]
-    pub namespaces: Option<Box<models::ContainerdInfoNamespaces>>,
+    pub namespaces: Option<Box<ContainerdInfoNamespaces>>,
 }
 impl ContainerdInfo {
     /// Information for connecting to the containerd instance that is used by the daemon. This is included for debugging purposes only.
@@ -1640,8 +1569,6 @@ impl ContainerdInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ContainerdInfoNamespaces : The namespaces that the daemon uses for running containers and plugins in containerd. These namespaces can be configured in the daemon configuration, and are considered to be used exclusively by the daemon, Tampering with the containerd instance may cause unexpected behavior.  As these namespaces are considered to be exclusively accessed by the daemon, it is not recommended to change these values, or to change them to a value that is used by other systems, such as cri-containerd.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerdInfoNamespaces {
@@ -1661,8 +1588,6 @@ impl ContainerdInfoNamespaces {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateImageInfo {
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
@@ -1670,13 +1595,13 @@ pub struct CreateImageInfo {
     #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     #[serde(rename = "errorDetail", skip_serializing_if = "Option::is_none")]
-    pub error_detail: Option<Box<models::ErrorDetail>>,
+    pub error_detail: Option<Box<ErrorDetail>>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     #[serde(rename = "progress", skip_serializing_if = "Option::is_none")]
     pub progress: Option<String>,
     #[serde(rename = "progressDetail", skip_serializing_if = "Option::is_none")]
-    pub progress_detail: Option<Box<models::ProgressDetail>>,
+    pub progress_detail: Option<Box<ProgressDetail>>,
 }
 impl CreateImageInfo {
     pub fn new() -> CreateImageInfo {
@@ -1690,8 +1615,6 @@ impl CreateImageInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// DeviceMapping : A device mapping between the host and container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeviceMapping {
@@ -1712,8 +1635,6 @@ impl DeviceMapping {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// DeviceRequest : A request for devices to be sent to device drivers
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeviceRequest {
@@ -1742,22 +1663,20 @@ impl DeviceRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// DistributionInspect : Describes the result obtained from contacting the registry to retrieve image metadata.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DistributionInspect {
     #[serde(rename = "Descriptor")]
-    pub descriptor: Box<models::OciDescriptor>,
+    pub descriptor: Box<OciDescriptor>,
     /// An array containing all platforms supported by the image.
     #[serde(rename = "Platforms")]
-    pub platforms: Vec<models::OciPlatform>,
+    pub platforms: Vec<OciPlatform>,
 }
 impl DistributionInspect {
     /// Describes the result obtained from contacting the registry to retrieve image metadata.
     pub fn new(
-        descriptor: models::OciDescriptor,
-        platforms: Vec<models::OciPlatform>,
+        descriptor: OciDescriptor,
+        platforms: Vec<OciPlatform>,
     ) -> DistributionInspect {
         DistributionInspect {
             descriptor: Box::new(descriptor),
@@ -1765,8 +1684,6 @@ impl DistributionInspect {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Driver : Driver represents a driver (network, logging, secrets).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Driver {
@@ -1783,8 +1700,6 @@ impl Driver {
         Driver { name, options: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// DriverData : Information about the storage driver used to store the container's and image's filesystem.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DriverData {
@@ -1804,8 +1719,6 @@ impl DriverData {
         DriverData { name, data }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EndpointIpamConfig : EndpointIPAMConfig represents an endpoint's IPAM configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointIpamConfig {
@@ -1826,14 +1739,12 @@ impl EndpointIpamConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointPortConfig {
     #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(rename = "Protocol", skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<Protocol>,
+    pub protocol: Option<EndpointPortConfigProtocol>,
     /// The port inside the container.
     #[serde(rename = "TargetPort", skip_serializing_if = "Option::is_none")]
     pub target_port: Option<i32>,
@@ -1842,7 +1753,7 @@ pub struct EndpointPortConfig {
     pub published_port: Option<i32>,
     /// The mode in which port is published.  <p><br /></p>  - \"ingress\" makes the target port accessible on every node,   regardless of whether there is a task for the service running on   that node or not. - \"host\" bypasses the routing mesh and publish the port directly on   the swarm node where that service is running.
     #[serde(rename = "PublishMode", skip_serializing_if = "Option::is_none")]
-    pub publish_mode: Option<PublishMode>,
+    pub publish_mode: Option<EndpointPortConfigPublishMode>,
 }
 impl EndpointPortConfig {
     pub fn new() -> EndpointPortConfig {
@@ -1868,7 +1779,7 @@ impl EndpointPortConfig {
     Serialize,
     Deserialize
 )]
-pub enum Protocol {
+pub enum EndpointPortConfigProtocol {
     #[serde(rename = "tcp")]
     Tcp,
     #[serde(rename = "udp")]
@@ -1876,8 +1787,8 @@ pub enum Protocol {
     #[serde(rename = "sctp")]
     Sctp,
 }
-impl Default for Protocol {
-    fn default() -> Protocol {
+impl Default for EndpointPortConfigProtocol {
+    fn default() -> EndpointPortConfigProtocol {
         Self::Tcp
     }
 }
@@ -1894,19 +1805,17 @@ impl Default for Protocol {
     Serialize,
     Deserialize
 )]
-pub enum PublishMode {
+pub enum EndpointPortConfigPublishMode {
     #[serde(rename = "ingress")]
     Ingress,
     #[serde(rename = "host")]
     Host,
 }
-impl Default for PublishMode {
-    fn default() -> PublishMode {
+impl Default for EndpointPortConfigPublishMode {
+    fn default() -> EndpointPortConfigPublishMode {
         Self::Ingress
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EndpointSettings : Configuration for a network endpoint.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointSettings {
@@ -1916,7 +1825,7 @@ pub struct EndpointSettings {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub ipam_config: Option<Option<Box<models::EndpointIpamConfig>>>,
+    pub ipam_config: Option<Option<Box<EndpointIpamConfig>>>,
     #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<String>>,
     /// MAC address for the endpoint on this network. The network driver might ignore this parameter.
@@ -1981,17 +1890,15 @@ impl EndpointSettings {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EndpointSpec : Properties that can be configured to access and load balance a service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointSpec {
     /// The mode of resolution to use for internal load balancing between tasks.
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Mode>,
+    pub mode: Option<EndpointSpecMode>,
     /// List of exposed ports that this service is accessible on from the outside. Ports can only be provided if `vip` resolution mode is used.
     #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
-    pub ports: Option<Vec<models::EndpointPortConfig>>,
+    pub ports: Option<Vec<EndpointPortConfig>>,
 }
 impl EndpointSpec {
     /// Properties that can be configured to access and load balance a service.
@@ -2015,19 +1922,17 @@ impl EndpointSpec {
     Serialize,
     Deserialize
 )]
-pub enum Mode {
+pub enum EndpointSpecMode {
     #[serde(rename = "vip")]
     Vip,
     #[serde(rename = "dnsrr")]
     Dnsrr,
 }
-impl Default for Mode {
-    fn default() -> Mode {
+impl Default for EndpointSpecMode {
+    fn default() -> EndpointSpecMode {
         Self::Vip
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EngineDescription : EngineDescription provides information about an engine.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EngineDescription {
@@ -2036,7 +1941,7 @@ pub struct EngineDescription {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "Plugins", skip_serializing_if = "Option::is_none")]
-    pub plugins: Option<Vec<models::EngineDescriptionPluginsInner>>,
+    pub plugins: Option<Vec<EngineDescriptionPluginsInner>>,
 }
 impl EngineDescription {
     /// EngineDescription provides information about an engine.
@@ -2048,8 +1953,6 @@ impl EngineDescription {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EngineDescriptionPluginsInner {
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
@@ -2065,8 +1968,6 @@ impl EngineDescriptionPluginsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDetail {
     #[serde(rename = "code", skip_serializing_if = "Option::is_none")]
@@ -2082,8 +1983,6 @@ impl ErrorDetail {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ErrorResponse : Represents an error.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -2097,8 +1996,6 @@ impl ErrorResponse {
         ErrorResponse { message }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EventActor : Actor describes something that generates events, like a container, network, or a volume.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventActor {
@@ -2118,22 +2015,20 @@ impl EventActor {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// EventMessage : EventMessage represents the information an event contains.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventMessage {
     /// The type of object emitting the event
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<Type>,
+    pub r#type: Option<EventMessageType>,
     /// The type of event
     #[serde(rename = "Action", skip_serializing_if = "Option::is_none")]
     pub action: Option<String>,
     #[serde(rename = "Actor", skip_serializing_if = "Option::is_none")]
-    pub actor: Option<Box<models::EventActor>>,
+    pub actor: Option<Box<EventActor>>,
     /// Scope of the event. Engine events are `local` scope. Cluster (Swarm) events are `swarm` scope.
     #[serde(rename = "scope", skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Scope>,
+    pub scope: Option<EventMessageScope>,
     /// Timestamp of event
     #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
     pub time: Option<i64>,
@@ -2167,7 +2062,7 @@ impl EventMessage {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum EventMessageType {
     #[serde(rename = "builder")]
     Builder,
     #[serde(rename = "config")]
@@ -2191,8 +2086,8 @@ pub enum Type {
     #[serde(rename = "volume")]
     Volume,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for EventMessageType {
+    fn default() -> EventMessageType {
         Self::Builder
     }
 }
@@ -2209,19 +2104,17 @@ impl Default for Type {
     Serialize,
     Deserialize
 )]
-pub enum Scope {
+pub enum EventMessageScope {
     #[serde(rename = "local")]
     Local,
     #[serde(rename = "swarm")]
     Swarm,
 }
-impl Default for Scope {
-    fn default() -> Scope {
+impl Default for EventMessageScope {
+    fn default() -> EventMessageScope {
         Self::Local
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExecConfig {
     /// Attach to `stdin` of the exec command.
@@ -2280,8 +2173,6 @@ impl ExecConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExecInspectResponse {
     #[serde(rename = "CanRemove", skip_serializing_if = "Option::is_none")]
@@ -2295,7 +2186,7 @@ pub struct ExecInspectResponse {
     #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
     pub exit_code: Option<i32>,
     #[serde(rename = "ProcessConfig", skip_serializing_if = "Option::is_none")]
-    pub process_config: Option<Box<models::ProcessConfig>>,
+    pub process_config: Option<Box<ProcessConfig>>,
     #[serde(rename = "OpenStdin", skip_serializing_if = "Option::is_none")]
     pub open_stdin: Option<bool>,
     #[serde(rename = "OpenStderr", skip_serializing_if = "Option::is_none")]
@@ -2325,8 +2216,6 @@ impl ExecInspectResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExecStartConfig {
     /// Detach from the command.
@@ -2353,8 +2242,6 @@ impl ExecStartConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// FilesystemChange : Change in the container's filesystem.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FilesystemChange {
@@ -2362,24 +2249,20 @@ pub struct FilesystemChange {
     #[serde(rename = "Path")]
     pub path: String,
     #[serde(rename = "Kind")]
-    pub kind: models::ChangeType,
+    pub kind: ChangeType,
 }
 impl FilesystemChange {
     /// Change in the container's filesystem.
-    pub fn new(path: String, kind: models::ChangeType) -> FilesystemChange {
+    pub fn new(path: String, kind: ChangeType) -> FilesystemChange {
         FilesystemChange { path, kind }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GenericResourcesInner {
     #[serde(rename = "NamedResourceSpec", skip_serializing_if = "Option::is_none")]
-    pub named_resource_spec: Option<Box<models::GenericResourcesInnerNamedResourceSpec>>,
+    pub named_resource_spec: Option<Box<GenericResourcesInnerNamedResourceSpec>>,
     #[serde(rename = "DiscreteResourceSpec", skip_serializing_if = "Option::is_none")]
-    pub discrete_resource_spec: Option<
-        Box<models::GenericResourcesInnerDiscreteResourceSpec>,
-    >,
+    pub discrete_resource_spec: Option<Box<GenericResourcesInnerDiscreteResourceSpec>>,
 }
 impl GenericResourcesInner {
     pub fn new() -> GenericResourcesInner {
@@ -2389,8 +2272,6 @@ impl GenericResourcesInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GenericResourcesInnerDiscreteResourceSpec {
     #[serde(rename = "Kind", skip_serializing_if = "Option::is_none")]
@@ -2406,8 +2287,6 @@ impl GenericResourcesInnerDiscreteResourceSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GenericResourcesInnerNamedResourceSpec {
     #[serde(rename = "Kind", skip_serializing_if = "Option::is_none")]
@@ -2423,20 +2302,18 @@ impl GenericResourcesInnerNamedResourceSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Health : Health stores information about the container's healthcheck results.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Health {
     /// Status is one of `none`, `starting`, `healthy` or `unhealthy`  - \"none\"      Indicates there is no healthcheck - \"starting\"  Starting indicates that the container is not yet ready - \"healthy\"   Healthy indicates that the container is running correctly - \"unhealthy\" Unhealthy indicates that the container has a problem
     #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
+    pub status: Option<HealthStatus>,
     /// FailingStreak is the number of consecutive failures
     #[serde(rename = "FailingStreak", skip_serializing_if = "Option::is_none")]
     pub failing_streak: Option<i32>,
     /// Log contains the last few results (oldest first)
     #[serde(rename = "Log", skip_serializing_if = "Option::is_none")]
-    pub log: Option<Vec<models::HealthcheckResult>>,
+    pub log: Option<Vec<HealthcheckResult>>,
 }
 impl Health {
     /// Health stores information about the container's healthcheck results.
@@ -2461,7 +2338,7 @@ impl Health {
     Serialize,
     Deserialize
 )]
-pub enum Status {
+pub enum HealthStatus {
     #[serde(rename = "none")]
     None,
     #[serde(rename = "starting")]
@@ -2471,13 +2348,11 @@ pub enum Status {
     #[serde(rename = "unhealthy")]
     Unhealthy,
 }
-impl Default for Status {
-    fn default() -> Status {
+impl Default for HealthStatus {
+    fn default() -> HealthStatus {
         Self::None
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// HealthConfig : A test to perform to check that the container is healthy.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HealthConfig {
@@ -2513,8 +2388,6 @@ impl HealthConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// HealthcheckResult : HealthcheckResult stores information about a single run of a healthcheck probe
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HealthcheckResult {
@@ -2542,8 +2415,6 @@ impl HealthcheckResult {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// HistoryResponseItem : individual image layer information in response to ImageHistory operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HistoryResponseItem {
@@ -2580,8 +2451,6 @@ impl HistoryResponseItem {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// HostConfig : Container configuration that depends on the host we are running on
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HostConfig {
@@ -2599,19 +2468,19 @@ pub struct HostConfig {
     pub blkio_weight: Option<u32>,
     /// Block IO weight (relative device weight) in the form:  ``` [{\"Path\": \"device_path\", \"Weight\": weight}] ```
     #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
-    pub blkio_weight_device: Option<Vec<models::ResourcesBlkioWeightDeviceInner>>,
+    pub blkio_weight_device: Option<Vec<ResourcesBlkioWeightDeviceInner>>,
     /// Limit read rate (bytes per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (bytes per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
     /// Limit read rate (IO per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (IO per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
     /// The length of a CPU period in microseconds.
     #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
     pub cpu_period: Option<i64>,
@@ -2632,13 +2501,13 @@ pub struct HostConfig {
     pub cpuset_mems: Option<String>,
     /// A list of devices to add to the container.
     #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
-    pub devices: Option<Vec<models::DeviceMapping>>,
+    pub devices: Option<Vec<DeviceMapping>>,
     /// a list of cgroup rules to apply to the container
     #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
     pub device_cgroup_rules: Option<Vec<String>>,
     /// A list of requests for devices to be sent to device drivers.
     #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
-    pub device_requests: Option<Vec<models::DeviceRequest>>,
+    pub device_requests: Option<Vec<DeviceRequest>>,
     /// Hard limit for kernel TCP buffer memory (in bytes). Depending on the OCI runtime in use, this option may be ignored. It is no longer supported by the default (runc) runtime.  This field is omitted when empty.
     #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
     pub kernel_memory_tcp: Option<i64>,
@@ -2675,7 +2544,7 @@ pub struct HostConfig {
     pub pids_limit: Option<Option<i64>>,
     /// A list of resource limits to set in the container. For example:  ``` {\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048} ```
     #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
-    pub ulimits: Option<Vec<models::ResourcesUlimitsInner>>,
+    pub ulimits: Option<Vec<ResourcesUlimitsInner>>,
     /// The number of usable CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
     #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
     pub cpu_count: Option<i64>,
@@ -2695,17 +2564,15 @@ pub struct HostConfig {
     #[serde(rename = "ContainerIDFile", skip_serializing_if = "Option::is_none")]
     pub container_id_file: Option<String>,
     #[serde(rename = "LogConfig", skip_serializing_if = "Option::is_none")]
-    pub log_config: Option<Box<models::HostConfigAllOfLogConfig>>,
+    pub log_config: Option<Box<HostConfigAllOfLogConfig>>,
     /// Network mode to use for this container. Supported standard values are: `bridge`, `host`, `none`, and `container:<name|id>`. Any other value is taken as a custom network's name to which this container should connect to.
     #[serde(rename = "NetworkMode", skip_serializing_if = "Option::is_none")]
     pub network_mode: Option<String>,
     /// PortMap describes the mapping of container ports to host ports, using the container's port-number and protocol as key in the format `<port>/<protocol>`, for example, `80/udp`.  If a container's port is mapped for multiple protocols, separate entries are added to the mapping table.
     #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none")]
-    pub port_bindings: Option<
-        std::collections::HashMap<String, Vec<models::PortBinding>>,
-    >,
+    pub port_bindings: Option<std::collections::HashMap<String, Vec<PortBinding>>>,
     #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
-    pub restart_policy: Option<Box<models::RestartPolicy>>,
+    pub restart_policy: Option<Box<RestartPolicy>>,
     /// Automatically remove the container when the container's process exits. This has no effect if `RestartPolicy` is set.
     #[serde(rename = "AutoRemove", skip_serializing_if = "Option::is_none")]
     pub auto_remove: Option<bool>,
@@ -2717,7 +2584,7 @@ pub struct HostConfig {
     pub volumes_from: Option<Vec<String>>,
     /// Specification for mounts to be added to the container.
     #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
-    pub mounts: Option<Vec<models::Mount>>,
+    pub mounts: Option<Vec<Mount>>,
     /// Initial console size, as an `[height, width]` array.
     #[serde(
         rename = "ConsoleSize",
@@ -2737,7 +2604,7 @@ pub struct HostConfig {
     pub cap_drop: Option<Vec<String>>,
     /// cgroup namespace mode for the container. Possible values are:  - `\"private\"`: the container runs in its own private cgroup namespace - `\"host\"`: use the host system's cgroup namespace  If not specified, the daemon default is used, which can either be `\"private\"` or `\"host\"`, depending on daemon version, kernel support and configuration.
     #[serde(rename = "CgroupnsMode", skip_serializing_if = "Option::is_none")]
-    pub cgroupns_mode: Option<CgroupnsMode>,
+    pub cgroupns_mode: Option<HostConfigCgroupnsMode>,
     /// A list of DNS servers for the container to use.
     #[serde(rename = "Dns", skip_serializing_if = "Option::is_none")]
     pub dns: Option<Vec<String>>,
@@ -2803,7 +2670,7 @@ pub struct HostConfig {
     pub runtime: Option<String>,
     /// Isolation technology of the container. (Windows only)
     #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none")]
-    pub isolation: Option<Isolation>,
+    pub isolation: Option<HostConfigIsolation>,
     /// The list of paths to be masked inside the container (this overrides the default set of paths).
     #[serde(rename = "MaskedPaths", skip_serializing_if = "Option::is_none")]
     pub masked_paths: Option<Vec<String>>,
@@ -2901,14 +2768,14 @@ impl HostConfig {
     Serialize,
     Deserialize
 )]
-pub enum CgroupnsMode {
+pub enum HostConfigCgroupnsMode {
     #[serde(rename = "private")]
     Private,
     #[serde(rename = "host")]
     Host,
 }
-impl Default for CgroupnsMode {
-    fn default() -> CgroupnsMode {
+impl Default for HostConfigCgroupnsMode {
+    fn default() -> HostConfigCgroupnsMode {
         Self::Private
     }
 }
@@ -2925,7 +2792,7 @@ impl Default for CgroupnsMode {
     Serialize,
     Deserialize
 )]
-pub enum Isolation {
+pub enum HostConfigIsolation {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "process")]
@@ -2935,18 +2802,16 @@ pub enum Isolation {
     #[serde(rename = "")]
     Empty,
 }
-impl Default for Isolation {
-    fn default() -> Isolation {
+impl Default for HostConfigIsolation {
+    fn default() -> HostConfigIsolation {
         Self::Default
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// HostConfigAllOfLogConfig : The logging configuration for this container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HostConfigAllOfLogConfig {
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<Type>,
+    pub r#type: Option<HostConfigAllOfLogConfigType>,
     #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
     pub config: Option<std::collections::HashMap<String, String>>,
 }
@@ -2972,7 +2837,7 @@ impl HostConfigAllOfLogConfig {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum HostConfigAllOfLogConfigType {
     #[serde(rename = "json-file")]
     JsonFile,
     #[serde(rename = "syslog")]
@@ -2992,13 +2857,11 @@ pub enum Type {
     #[serde(rename = "none")]
     None,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for HostConfigAllOfLogConfigType {
+    fn default() -> HostConfigAllOfLogConfigType {
         Self::JsonFile
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// IdResponse : Response to an API call that returns just an Id
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IdResponse {
@@ -3012,8 +2875,6 @@ impl IdResponse {
         IdResponse { id }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageConfig : Configuration of the image. These fields are used as defaults when starting a container from the image.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageConfig {
@@ -3061,7 +2922,7 @@ pub struct ImageConfig {
     #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none")]
     pub cmd: Option<Vec<String>>,
     #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none")]
-    pub healthcheck: Option<Box<models::HealthConfig>>,
+    pub healthcheck: Option<Box<HealthConfig>>,
     /// Command is already escaped (Windows only)
     #[serde(
         rename = "ArgsEscaped",
@@ -3166,8 +3027,6 @@ impl ImageConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageDeleteResponseItem {
     /// The image ID of an image that was untagged
@@ -3185,8 +3044,6 @@ impl ImageDeleteResponseItem {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageId : Image ID or Digest
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageId {
@@ -3199,8 +3056,6 @@ impl ImageId {
         ImageId { id: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageInspect : Information about an image in the local image cache.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageInspect {
@@ -3234,7 +3089,7 @@ pub struct ImageInspect {
     #[serde(rename = "Author", skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
     #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
-    pub config: Option<Box<models::ImageConfig>>,
+    pub config: Option<Box<ImageConfig>>,
     /// Hardware CPU architecture that the image runs on.
     #[serde(rename = "Architecture", skip_serializing_if = "Option::is_none")]
     pub architecture: Option<String>,
@@ -3264,11 +3119,11 @@ pub struct ImageInspect {
     #[serde(rename = "VirtualSize", skip_serializing_if = "Option::is_none")]
     pub virtual_size: Option<i64>,
     #[serde(rename = "GraphDriver", skip_serializing_if = "Option::is_none")]
-    pub graph_driver: Option<Box<models::DriverData>>,
+    pub graph_driver: Option<Box<DriverData>>,
     #[serde(rename = "RootFS", skip_serializing_if = "Option::is_none")]
-    pub root_fs: Option<Box<models::ImageInspectRootFs>>,
+    pub root_fs: Option<Box<ImageInspectRootFs>>,
     #[serde(rename = "Metadata", skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Box<models::ImageInspectMetadata>>,
+    pub metadata: Option<Box<ImageInspectMetadata>>,
 }
 impl ImageInspect {
     /// Information about an image in the local image cache.
@@ -3295,8 +3150,6 @@ impl ImageInspect {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageInspectMetadata : Additional metadata of the image in the local cache. This information is local to the daemon, and not part of the image itself.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageInspectMetadata {
@@ -3317,8 +3170,6 @@ impl ImageInspectMetadata {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageInspectRootFs : Information about the image's RootFS, including the layer IDs.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageInspectRootFs {
@@ -3336,8 +3187,6 @@ impl ImageInspectRootFs {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageManifestSummary : ImageManifestSummary represents a summary of an image manifest.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageManifestSummary {
@@ -3345,40 +3194,38 @@ pub struct ImageManifestSummary {
     #[serde(rename = "ID")]
     pub id: String,
     #[serde(rename = "Descriptor")]
-    pub descriptor: Box<models::OciDescriptor>,
+    pub descriptor: Box<OciDescriptor>,
     /// Indicates whether all the child content (image config, layers) is fully available locally.
     #[serde(rename = "Available")]
     pub available: bool,
     #[serde(rename = "Size")]
-    pub size: Box<models::ImageManifestSummarySize>,
+    pub size: Box<ImageManifestSummarySize>,
     /// The kind of the manifest.  kind         | description -------------|----------------------------------------------------------- image        | Image manifest that can be used to start a container. attestation  | Attestation manifest produced by the Buildkit builder for a specific image manifest.
     #[serde(rename = "Kind")]
-    pub kind: Kind,
+    pub kind: ImageManifestSummaryKind,
     #[serde(
         rename = "ImageData",
         default,
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub image_data: Option<Option<Box<models::ImageManifestSummaryImageData>>>,
+    pub image_data: Option<Option<Box<ImageManifestSummaryImageData>>>,
     #[serde(
         rename = "AttestationData",
         default,
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub attestation_data: Option<
-        Option<Box<models::ImageManifestSummaryAttestationData>>,
-    >,
+    pub attestation_data: Option<Option<Box<ImageManifestSummaryAttestationData>>>,
 }
 impl ImageManifestSummary {
     /// ImageManifestSummary represents a summary of an image manifest.
     pub fn new(
         id: String,
-        descriptor: models::OciDescriptor,
+        descriptor: OciDescriptor,
         available: bool,
-        size: models::ImageManifestSummarySize,
-        kind: Kind,
+        size: ImageManifestSummarySize,
+        kind: ImageManifestSummaryKind,
     ) -> ImageManifestSummary {
         ImageManifestSummary {
             id,
@@ -3404,7 +3251,7 @@ impl ImageManifestSummary {
     Serialize,
     Deserialize
 )]
-pub enum Kind {
+pub enum ImageManifestSummaryKind {
     #[serde(rename = "image")]
     Image,
     #[serde(rename = "attestation")]
@@ -3412,13 +3259,11 @@ pub enum Kind {
     #[serde(rename = "unknown")]
     Unknown,
 }
-impl Default for Kind {
-    fn default() -> Kind {
+impl Default for ImageManifestSummaryKind {
+    fn default() -> ImageManifestSummaryKind {
         Self::Image
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageManifestSummaryAttestationData : The image data for the attestation manifest. This field is only populated when Kind is \"attestation\".
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageManifestSummaryAttestationData {
@@ -3434,25 +3279,23 @@ impl ImageManifestSummaryAttestationData {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ImageManifestSummaryImageData : The image data for the image manifest. This field is only populated when Kind is \"image\".
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageManifestSummaryImageData {
     #[serde(rename = "Platform")]
-    pub platform: Box<models::OciPlatform>,
+    pub platform: Box<OciPlatform>,
     /// The IDs of the containers that are using this image.
     #[serde(rename = "Containers")]
     pub containers: Vec<String>,
     #[serde(rename = "Size")]
-    pub size: Box<models::ImageManifestSummaryImageDataSize>,
+    pub size: Box<ImageManifestSummaryImageDataSize>,
 }
 impl ImageManifestSummaryImageData {
     /// The image data for the image manifest. This field is only populated when Kind is \"image\".
     pub fn new(
-        platform: models::OciPlatform,
+        platform: OciPlatform,
         containers: Vec<String>,
-        size: models::ImageManifestSummaryImageDataSize,
+        size: ImageManifestSummaryImageDataSize,
     ) -> ImageManifestSummaryImageData {
         ImageManifestSummaryImageData {
             platform: Box::new(platform),
@@ -3461,8 +3304,6 @@ impl ImageManifestSummaryImageData {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageManifestSummaryImageDataSize {
     /// Unpacked is the size (in bytes) of the locally unpacked (uncompressed) image content that's directly usable by the containers running this image. It's independent of the distributable content - e.g. the image might still have an unpacked data that's still used by some container even when the distributable/compressed content is already gone.
@@ -3476,8 +3317,6 @@ impl ImageManifestSummaryImageDataSize {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageManifestSummarySize {
     /// Total is the total size (in bytes) of all the locally present data (both distributable and non-distributable) that's related to this manifest and its children. This equal to the sum of [Content] size AND all the sizes in the [Size] struct present in the Kind-specific data struct. For example, for an image kind (Kind == \"image\") this would include the size of the image content and unpacked image snapshots ([Size.Content] + [ImageData.Size.Unpacked]).
@@ -3495,13 +3334,11 @@ impl ImageManifestSummarySize {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImagePruneResponse {
     /// Images that were deleted
     #[serde(rename = "ImagesDeleted", skip_serializing_if = "Option::is_none")]
-    pub images_deleted: Option<Vec<models::ImageDeleteResponseItem>>,
+    pub images_deleted: Option<Vec<ImageDeleteResponseItem>>,
     /// Disk space reclaimed in bytes
     #[serde(rename = "SpaceReclaimed", skip_serializing_if = "Option::is_none")]
     pub space_reclaimed: Option<i64>,
@@ -3514,8 +3351,6 @@ impl ImagePruneResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageSearchResponseItem {
     #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
@@ -3541,8 +3376,6 @@ impl ImageSearchResponseItem {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageSummary {
     /// ID is the content-addressable ID of an image.  This identifier is a content-addressable digest calculated from the image's configuration (which includes the digests of layers used by the image).  Note that this digest differs from the `RepoDigests` below, which holds digests of image manifests that reference the image.
@@ -3577,7 +3410,7 @@ pub struct ImageSummary {
     pub containers: i32,
     /// Manifests is a list of manifests available in this image. It provides a more detailed view of the platform-specific image manifests or other image-attached data like build attestations.  WARNING: This is experimental and may change at any time without any backward compatibility.
     #[serde(rename = "Manifests", skip_serializing_if = "Option::is_none")]
-    pub manifests: Option<Vec<models::ImageManifestSummary>>,
+    pub manifests: Option<Vec<ImageManifestSummary>>,
 }
 impl ImageSummary {
     pub fn new(
@@ -3606,8 +3439,6 @@ impl ImageSummary {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// IndexInfo : IndexInfo contains information about a registry.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IndexInfo {
@@ -3635,8 +3466,6 @@ impl IndexInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Ipam {
     /// Name of the IPAM driver to use.
@@ -3644,7 +3473,7 @@ pub struct Ipam {
     pub driver: Option<String>,
     /// List of IPAM configuration options, specified as a map:  ``` {\"Subnet\": <CIDR>, \"IPRange\": <CIDR>, \"Gateway\": <IP address>, \"AuxAddress\": <device_name:IP address>} ```
     #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
-    pub config: Option<Vec<models::IpamConfig>>,
+    pub config: Option<Vec<IpamConfig>>,
     /// Driver-specific options, specified as a map.
     #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
     pub options: Option<std::collections::HashMap<String, String>>,
@@ -3658,8 +3487,6 @@ impl Ipam {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IpamConfig {
     #[serde(rename = "Subnet", skip_serializing_if = "Option::is_none")]
@@ -3681,8 +3508,6 @@ impl IpamConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// JoinTokens : JoinTokens contains the tokens workers and managers need to join the swarm.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JoinTokens {
@@ -3702,8 +3527,6 @@ impl JoinTokens {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Limit : An object describing a limit on resources which can be requested by a task.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Limit {
@@ -3725,8 +3548,6 @@ impl Limit {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// LocalNodeState : Current local status of this node.
 /// Current local status of this node.
 #[derive(
@@ -3772,15 +3593,13 @@ impl Default for LocalNodeState {
         Self::Empty
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ManagerStatus : ManagerStatus represents the status of a manager.  It provides the current status of a node's manager component, if the node is a manager.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagerStatus {
     #[serde(rename = "Leader", skip_serializing_if = "Option::is_none")]
     pub leader: Option<bool>,
     #[serde(rename = "Reachability", skip_serializing_if = "Option::is_none")]
-    pub reachability: Option<models::Reachability>,
+    pub reachability: Option<Reachability>,
     /// The IP address and port at which the manager is reachable.
     #[serde(rename = "Addr", skip_serializing_if = "Option::is_none")]
     pub addr: Option<String>,
@@ -3795,8 +3614,6 @@ impl ManagerStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Mount {
     /// Container path.
@@ -3807,7 +3624,7 @@ pub struct Mount {
     pub source: Option<String>,
     /// The mount type. Available types:  - `bind` Mounts a file or directory from the host into the container. Must exist prior to creating the container. - `volume` Creates a volume with the given name and options (or uses a pre-existing volume with the same name and options). These are **not** removed when the container is removed. - `tmpfs` Create a tmpfs with the given options. The mount source cannot be specified for tmpfs. - `npipe` Mounts a named pipe from the host into the container. Must exist prior to creating the container. - `cluster` a Swarm cluster volume
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<Type>,
+    pub r#type: Option<MountType>,
     /// Whether the mount should be read-only.
     #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none")]
     pub read_only: Option<bool>,
@@ -3815,11 +3632,11 @@ pub struct Mount {
     #[serde(rename = "Consistency", skip_serializing_if = "Option::is_none")]
     pub consistency: Option<String>,
     #[serde(rename = "BindOptions", skip_serializing_if = "Option::is_none")]
-    pub bind_options: Option<Box<models::MountBindOptions>>,
+    pub bind_options: Option<Box<MountBindOptions>>,
     #[serde(rename = "VolumeOptions", skip_serializing_if = "Option::is_none")]
-    pub volume_options: Option<Box<models::MountVolumeOptions>>,
+    pub volume_options: Option<Box<MountVolumeOptions>>,
     #[serde(rename = "TmpfsOptions", skip_serializing_if = "Option::is_none")]
-    pub tmpfs_options: Option<Box<models::MountTmpfsOptions>>,
+    pub tmpfs_options: Option<Box<MountTmpfsOptions>>,
 }
 impl Mount {
     pub fn new() -> Mount {
@@ -3848,7 +3665,7 @@ impl Mount {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum MountType {
     #[serde(rename = "bind")]
     Bind,
     #[serde(rename = "volume")]
@@ -3860,19 +3677,17 @@ pub enum Type {
     #[serde(rename = "cluster")]
     Cluster,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for MountType {
+    fn default() -> MountType {
         Self::Bind
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// MountBindOptions : Optional configuration for the `bind` type.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountBindOptions {
     /// A propagation mode with the value `[r]private`, `[r]shared`, or `[r]slave`.
     #[serde(rename = "Propagation", skip_serializing_if = "Option::is_none")]
-    pub propagation: Option<Propagation>,
+    pub propagation: Option<MountBindOptionsPropagation>,
     /// Disable recursive bind mount.
     #[serde(rename = "NonRecursive", skip_serializing_if = "Option::is_none")]
     pub non_recursive: Option<bool>,
@@ -3911,7 +3726,7 @@ impl MountBindOptions {
     Serialize,
     Deserialize
 )]
-pub enum Propagation {
+pub enum MountBindOptionsPropagation {
     #[serde(rename = "private")]
     Private,
     #[serde(rename = "rprivate")]
@@ -3925,19 +3740,17 @@ pub enum Propagation {
     #[serde(rename = "rslave")]
     Rslave,
 }
-impl Default for Propagation {
-    fn default() -> Propagation {
+impl Default for MountBindOptionsPropagation {
+    fn default() -> MountBindOptionsPropagation {
         Self::Private
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// MountPoint : MountPoint represents a mount point configuration inside the container. This is used for reporting the mountpoints in use by a container.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountPoint {
     /// The mount type:  - `bind` a mount of a file or directory from the host into the container. - `volume` a docker volume with the given `Name`. - `tmpfs` a `tmpfs`. - `npipe` a named pipe from the host into the container. - `cluster` a Swarm cluster volume
     #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<Type>,
+    pub r#type: Option<MountPointType>,
     /// Name is the name reference to the underlying data defined by `Source` e.g., the volume name.
     #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -3988,7 +3801,7 @@ impl MountPoint {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum MountPointType {
     #[serde(rename = "bind")]
     Bind,
     #[serde(rename = "volume")]
@@ -4000,13 +3813,11 @@ pub enum Type {
     #[serde(rename = "cluster")]
     Cluster,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for MountPointType {
+    fn default() -> MountPointType {
         Self::Bind
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// MountTmpfsOptions : Optional configuration for the `tmpfs` type.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountTmpfsOptions {
@@ -4030,8 +3841,6 @@ impl MountTmpfsOptions {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// MountVolumeOptions : Optional configuration for the `volume` type.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountVolumeOptions {
@@ -4042,7 +3851,7 @@ pub struct MountVolumeOptions {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "DriverConfig", skip_serializing_if = "Option::is_none")]
-    pub driver_config: Option<Box<models::MountVolumeOptionsDriverConfig>>,
+    pub driver_config: Option<Box<MountVolumeOptionsDriverConfig>>,
     /// Source path inside the volume. Must be relative without any back traversals.
     #[serde(rename = "Subpath", skip_serializing_if = "Option::is_none")]
     pub subpath: Option<String>,
@@ -4058,8 +3867,6 @@ impl MountVolumeOptions {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// MountVolumeOptionsDriverConfig : Map of driver specific options
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountVolumeOptionsDriverConfig {
@@ -4079,8 +3886,6 @@ impl MountVolumeOptionsDriverConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Network {
     /// Name of the network.
@@ -4105,7 +3910,7 @@ pub struct Network {
     #[serde(rename = "EnableIPv6", skip_serializing_if = "Option::is_none")]
     pub enable_ipv6: Option<bool>,
     #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
-    pub ipam: Option<Box<models::Ipam>>,
+    pub ipam: Option<Box<Ipam>>,
     /// Whether the network is created to only allow internal networking connectivity.
     #[serde(rename = "Internal", skip_serializing_if = "Option::is_none")]
     pub internal: Option<bool>,
@@ -4116,13 +3921,13 @@ pub struct Network {
     #[serde(rename = "Ingress", skip_serializing_if = "Option::is_none")]
     pub ingress: Option<bool>,
     #[serde(rename = "ConfigFrom", skip_serializing_if = "Option::is_none")]
-    pub config_from: Option<Box<models::ConfigReference>>,
+    pub config_from: Option<Box<ConfigReference>>,
     /// Whether the network is a config-only network. Config-only networks are placeholder networks for network configurations to be used by other networks. Config-only networks cannot be used directly to run containers or services.
     #[serde(rename = "ConfigOnly", skip_serializing_if = "Option::is_none")]
     pub config_only: Option<bool>,
     /// Contains endpoints attached to the network.
     #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
-    pub containers: Option<std::collections::HashMap<String, models::NetworkContainer>>,
+    pub containers: Option<std::collections::HashMap<String, NetworkContainer>>,
     /// Network-specific options uses when creating the network.
     #[serde(rename = "Options", skip_serializing_if = "Option::is_none")]
     pub options: Option<std::collections::HashMap<String, String>>,
@@ -4136,7 +3941,7 @@ pub struct Network {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub peers: Option<Option<Vec<models::PeerInfo>>>,
+    pub peers: Option<Option<Vec<PeerInfo>>>,
 }
 impl Network {
     pub fn new() -> Network {
@@ -4161,8 +3966,6 @@ impl Network {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NetworkAttachmentConfig : Specifies how a service should be attached to a particular network.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkAttachmentConfig {
@@ -4186,15 +3989,13 @@ impl NetworkAttachmentConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkConnectRequest {
     /// The ID or name of the container to connect to the network.
     #[serde(rename = "Container", skip_serializing_if = "Option::is_none")]
     pub container: Option<String>,
     #[serde(rename = "EndpointConfig", skip_serializing_if = "Option::is_none")]
-    pub endpoint_config: Option<Box<models::EndpointSettings>>,
+    pub endpoint_config: Option<Box<EndpointSettings>>,
 }
 impl NetworkConnectRequest {
     pub fn new() -> NetworkConnectRequest {
@@ -4204,8 +4005,6 @@ impl NetworkConnectRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkContainer {
     #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
@@ -4230,8 +4029,6 @@ impl NetworkContainer {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkCreateRequest {
     /// The network's name.
@@ -4256,9 +4053,9 @@ pub struct NetworkCreateRequest {
     #[serde(rename = "ConfigOnly", skip_serializing_if = "Option::is_none")]
     pub config_only: Option<bool>,
     #[serde(rename = "ConfigFrom", skip_serializing_if = "Option::is_none")]
-    pub config_from: Option<Box<models::ConfigReference>>,
+    pub config_from: Option<Box<ConfigReference>>,
     #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
-    pub ipam: Option<Box<models::Ipam>>,
+    pub ipam: Option<Box<Ipam>>,
     /// Enable IPv4 on the network. To disable IPv4, the daemon must be started with experimental features enabled.
     #[serde(rename = "EnableIPv4", skip_serializing_if = "Option::is_none")]
     pub enable_ipv4: Option<bool>,
@@ -4291,8 +4088,6 @@ impl NetworkCreateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NetworkCreateResponse : OK response to NetworkCreate operation
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkCreateResponse {
@@ -4312,8 +4107,6 @@ impl NetworkCreateResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkDisconnectRequest {
     /// The ID or name of the container to disconnect from the network.
@@ -4331,8 +4124,6 @@ impl NetworkDisconnectRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkPruneResponse {
     /// Networks that were deleted
@@ -4346,8 +4137,6 @@ impl NetworkPruneResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NetworkSettings : NetworkSettings exposes the network settings in the API
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkSettings {
@@ -4368,7 +4157,7 @@ pub struct NetworkSettings {
     pub link_local_ipv6_prefix_len: Option<i32>,
     /// PortMap describes the mapping of container ports to host ports, using the container's port-number and protocol as key in the format `<port>/<protocol>`, for example, `80/udp`.  If a container's port is mapped for multiple protocols, separate entries are added to the mapping table.
     #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
-    pub ports: Option<std::collections::HashMap<String, Vec<models::PortBinding>>>,
+    pub ports: Option<std::collections::HashMap<String, Vec<PortBinding>>>,
     /// SandboxKey is the full path of the netns handle
     #[serde(rename = "SandboxKey", skip_serializing_if = "Option::is_none")]
     pub sandbox_key: Option<String>,
@@ -4379,7 +4168,7 @@ pub struct NetworkSettings {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub secondary_ip_addresses: Option<Option<Vec<models::Address>>>,
+    pub secondary_ip_addresses: Option<Option<Vec<Address>>>,
     /// Deprecated: This field is never set and will be removed in a future release.
     #[serde(
         rename = "SecondaryIPv6Addresses",
@@ -4387,7 +4176,7 @@ pub struct NetworkSettings {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub secondary_ipv6_addresses: Option<Option<Vec<models::Address>>>,
+    pub secondary_ipv6_addresses: Option<Option<Vec<Address>>>,
     /// EndpointID uniquely represents a service endpoint in a Sandbox.  <p><br /></p>  > **Deprecated**: This field is only propagated when attached to the > default \"bridge\" network. Use the information from the \"bridge\" > network inside the `Networks` map instead, which contains the same > information. This field was deprecated in Docker 1.9 and is scheduled > to be removed in Docker 17.12.0
     #[serde(rename = "EndpointID", skip_serializing_if = "Option::is_none")]
     pub endpoint_id: Option<String>,
@@ -4414,7 +4203,7 @@ pub struct NetworkSettings {
     pub mac_address: Option<String>,
     /// Information about all networks that the container is connected to.
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<std::collections::HashMap<String, models::EndpointSettings>>,
+    pub networks: Option<std::collections::HashMap<String, EndpointSettings>>,
 }
 impl NetworkSettings {
     /// NetworkSettings exposes the network settings in the API
@@ -4441,16 +4230,12 @@ impl NetworkSettings {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NetworkingConfig : NetworkingConfig represents the container's networking configuration for each of its interfaces. It is used for the networking configs specified in the `docker create` and `docker network connect` commands.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkingConfig {
     /// A mapping of network name to endpoint configuration for that network. The endpoint configuration can be left empty to connect to that network with no particular endpoint configuration.
     #[serde(rename = "EndpointsConfig", skip_serializing_if = "Option::is_none")]
-    pub endpoints_config: Option<
-        std::collections::HashMap<String, models::EndpointSettings>,
-    >,
+    pub endpoints_config: Option<std::collections::HashMap<String, EndpointSettings>>,
 }
 impl NetworkingConfig {
     /// NetworkingConfig represents the container's networking configuration for each of its interfaces. It is used for the networking configs specified in the `docker create` and `docker network connect` commands.
@@ -4460,14 +4245,12 @@ impl NetworkingConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Node {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     /// Date and time at which the node was added to the swarm in [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) format with nano-seconds.
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
@@ -4475,18 +4258,18 @@ pub struct Node {
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::NodeSpec>>,
+    pub spec: Option<Box<NodeSpec>>,
     #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
-    pub description: Option<Box<models::NodeDescription>>,
+    pub description: Option<Box<NodeDescription>>,
     #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
-    pub status: Option<Box<models::NodeStatus>>,
+    pub status: Option<Box<NodeStatus>>,
     #[serde(
         rename = "ManagerStatus",
         default,
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub manager_status: Option<Option<Box<models::ManagerStatus>>>,
+    pub manager_status: Option<Option<Box<ManagerStatus>>>,
 }
 impl Node {
     pub fn new() -> Node {
@@ -4502,21 +4285,19 @@ impl Node {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NodeDescription : NodeDescription encapsulates the properties of the Node as reported by the agent.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeDescription {
     #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none")]
     pub hostname: Option<String>,
     #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
-    pub platform: Option<Box<models::Platform>>,
+    pub platform: Option<Box<Platform>>,
     #[serde(rename = "Resources", skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Box<models::ResourceObject>>,
+    pub resources: Option<Box<ResourceObject>>,
     #[serde(rename = "Engine", skip_serializing_if = "Option::is_none")]
-    pub engine: Option<Box<models::EngineDescription>>,
+    pub engine: Option<Box<EngineDescription>>,
     #[serde(rename = "TLSInfo", skip_serializing_if = "Option::is_none")]
-    pub tls_info: Option<Box<models::TlsInfo>>,
+    pub tls_info: Option<Box<TlsInfo>>,
 }
 impl NodeDescription {
     /// NodeDescription encapsulates the properties of the Node as reported by the agent.
@@ -4530,8 +4311,6 @@ impl NodeDescription {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeSpec {
     /// Name for the node.
@@ -4542,10 +4321,10 @@ pub struct NodeSpec {
     pub labels: Option<std::collections::HashMap<String, String>>,
     /// Role of the node.
     #[serde(rename = "Role", skip_serializing_if = "Option::is_none")]
-    pub role: Option<Role>,
+    pub role: Option<NodeSpecRole>,
     /// Availability of the node.
     #[serde(rename = "Availability", skip_serializing_if = "Option::is_none")]
-    pub availability: Option<Availability>,
+    pub availability: Option<NodeSpecAvailability>,
 }
 impl NodeSpec {
     pub fn new() -> NodeSpec {
@@ -4570,14 +4349,14 @@ impl NodeSpec {
     Serialize,
     Deserialize
 )]
-pub enum Role {
+pub enum NodeSpecRole {
     #[serde(rename = "worker")]
     Worker,
     #[serde(rename = "manager")]
     Manager,
 }
-impl Default for Role {
-    fn default() -> Role {
+impl Default for NodeSpecRole {
+    fn default() -> NodeSpecRole {
         Self::Worker
     }
 }
@@ -4594,7 +4373,7 @@ impl Default for Role {
     Serialize,
     Deserialize
 )]
-pub enum Availability {
+pub enum NodeSpecAvailability {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "pause")]
@@ -4602,13 +4381,11 @@ pub enum Availability {
     #[serde(rename = "drain")]
     Drain,
 }
-impl Default for Availability {
-    fn default() -> Availability {
+impl Default for NodeSpecAvailability {
+    fn default() -> NodeSpecAvailability {
         Self::Active
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NodeState : NodeState represents the state of a node.
 /// NodeState represents the state of a node.
 #[derive(
@@ -4648,13 +4425,11 @@ impl Default for NodeState {
         Self::Unknown
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// NodeStatus : NodeStatus represents the status of a node.  It provides the current status of the node, as seen by the manager.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeStatus {
     #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
-    pub state: Option<models::NodeState>,
+    pub state: Option<NodeState>,
     #[serde(rename = "Message", skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
     /// IP address of the node.
@@ -4671,8 +4446,6 @@ impl NodeStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ObjectVersion : The version number of the object such as node, service, etc. This is needed to avoid conflicting writes. The client must send the version number along with the modified specification when updating these objects.  This approach ensures safe concurrency and determinism in that the change on the object may not be applied if the version number has changed from the last read. In other words, if two update requests specify the same base version, only one of the requests can succeed. As a result, two separate update requests that happen at the same time will not unintentionally overwrite each other.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ObjectVersion {
@@ -4685,8 +4458,6 @@ impl ObjectVersion {
         ObjectVersion { index: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// OciDescriptor : A descriptor struct containing digest, media type, and size, as defined in the [OCI Content Descriptors Specification](https://github.com/opencontainers/image-spec/blob/v1.0.1/descriptor.md).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OciDescriptor {
@@ -4710,8 +4481,6 @@ impl OciDescriptor {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// OciPlatform : Describes the platform which the image in the manifest runs on, as defined in the [OCI Image Index Specification](https://github.com/opencontainers/image-spec/blob/v1.0.1/image-index.md).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OciPlatform {
@@ -4743,8 +4512,6 @@ impl OciPlatform {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PeerInfo : PeerInfo represents one peer of an overlay network.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PeerInfo {
@@ -4761,8 +4528,6 @@ impl PeerInfo {
         PeerInfo { name: None, ip: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PeerNode : Represents a peer-node in the swarm
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PeerNode {
@@ -4782,8 +4547,6 @@ impl PeerNode {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Platform : Platform represents the platform (Arch/OS).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Platform {
@@ -4803,8 +4566,6 @@ impl Platform {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Plugin : A plugin for the Engine API
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Plugin {
@@ -4816,20 +4577,20 @@ pub struct Plugin {
     #[serde(rename = "Enabled")]
     pub enabled: bool,
     #[serde(rename = "Settings")]
-    pub settings: Box<models::PluginSettings>,
+    pub settings: Box<PluginSettings>,
     /// plugin remote reference used to push/pull the plugin
     #[serde(rename = "PluginReference", skip_serializing_if = "Option::is_none")]
     pub plugin_reference: Option<String>,
     #[serde(rename = "Config")]
-    pub config: Box<models::PluginConfig>,
+    pub config: Box<PluginConfig>,
 }
 impl Plugin {
     /// A plugin for the Engine API
     pub fn new(
         name: String,
         enabled: bool,
-        settings: models::PluginSettings,
-        config: models::PluginConfig,
+        settings: PluginSettings,
+        config: PluginConfig,
     ) -> Plugin {
         Plugin {
             id: None,
@@ -4841,8 +4602,6 @@ impl Plugin {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PluginConfig : The config of a plugin.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfig {
@@ -4854,17 +4613,17 @@ pub struct PluginConfig {
     #[serde(rename = "Documentation")]
     pub documentation: String,
     #[serde(rename = "Interface")]
-    pub interface: Box<models::PluginConfigInterface>,
+    pub interface: Box<PluginConfigInterface>,
     #[serde(rename = "Entrypoint")]
     pub entrypoint: Vec<String>,
     #[serde(rename = "WorkDir")]
     pub work_dir: String,
     #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
-    pub user: Option<Box<models::PluginConfigUser>>,
+    pub user: Option<Box<PluginConfigUser>>,
     #[serde(rename = "Network")]
-    pub network: Box<models::PluginConfigNetwork>,
+    pub network: Box<PluginConfigNetwork>,
     #[serde(rename = "Linux")]
-    pub linux: Box<models::PluginConfigLinux>,
+    pub linux: Box<PluginConfigLinux>,
     #[serde(rename = "PropagatedMount")]
     pub propagated_mount: String,
     #[serde(rename = "IpcHost")]
@@ -4872,30 +4631,30 @@ pub struct PluginConfig {
     #[serde(rename = "PidHost")]
     pub pid_host: bool,
     #[serde(rename = "Mounts")]
-    pub mounts: Vec<models::PluginMount>,
+    pub mounts: Vec<PluginMount>,
     #[serde(rename = "Env")]
-    pub env: Vec<models::PluginEnv>,
+    pub env: Vec<PluginEnv>,
     #[serde(rename = "Args")]
-    pub args: Box<models::PluginConfigArgs>,
+    pub args: Box<PluginConfigArgs>,
     #[serde(rename = "rootfs", skip_serializing_if = "Option::is_none")]
-    pub rootfs: Option<Box<models::PluginConfigRootfs>>,
+    pub rootfs: Option<Box<PluginConfigRootfs>>,
 }
 impl PluginConfig {
     /// The config of a plugin.
     pub fn new(
         description: String,
         documentation: String,
-        interface: models::PluginConfigInterface,
+        interface: PluginConfigInterface,
         entrypoint: Vec<String>,
         work_dir: String,
-        network: models::PluginConfigNetwork,
-        linux: models::PluginConfigLinux,
+        network: PluginConfigNetwork,
+        linux: PluginConfigLinux,
         propagated_mount: String,
         ipc_host: bool,
         pid_host: bool,
-        mounts: Vec<models::PluginMount>,
-        env: Vec<models::PluginEnv>,
-        args: models::PluginConfigArgs,
+        mounts: Vec<PluginMount>,
+        env: Vec<PluginEnv>,
+        args: PluginConfigArgs,
     ) -> PluginConfig {
         PluginConfig {
             docker_version: None,
@@ -4917,8 +4676,6 @@ impl PluginConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigArgs {
     #[serde(rename = "Name")]
@@ -4945,23 +4702,21 @@ impl PluginConfigArgs {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PluginConfigInterface : The interface between Docker and the plugin
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigInterface {
     #[serde(rename = "Types")]
-    pub types: Vec<models::PluginInterfaceType>,
+    pub types: Vec<PluginInterfaceType>,
     #[serde(rename = "Socket")]
     pub socket: String,
     /// Protocol to use for clients connecting to the plugin.
     #[serde(rename = "ProtocolScheme", skip_serializing_if = "Option::is_none")]
-    pub protocol_scheme: Option<ProtocolScheme>,
+    pub protocol_scheme: Option<PluginConfigInterfaceProtocolScheme>,
 }
 impl PluginConfigInterface {
     /// The interface between Docker and the plugin
     pub fn new(
-        types: Vec<models::PluginInterfaceType>,
+        types: Vec<PluginInterfaceType>,
         socket: String,
     ) -> PluginConfigInterface {
         PluginConfigInterface {
@@ -4984,19 +4739,17 @@ impl PluginConfigInterface {
     Serialize,
     Deserialize
 )]
-pub enum ProtocolScheme {
+pub enum PluginConfigInterfaceProtocolScheme {
     #[serde(rename = "")]
     Empty,
     #[serde(rename = "moby.plugins.http/v1")]
     MobyPeriodPluginsPeriodHttpSlashV1,
 }
-impl Default for ProtocolScheme {
-    fn default() -> ProtocolScheme {
+impl Default for PluginConfigInterfaceProtocolScheme {
+    fn default() -> PluginConfigInterfaceProtocolScheme {
         Self::Empty
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigLinux {
     #[serde(rename = "Capabilities")]
@@ -5004,13 +4757,13 @@ pub struct PluginConfigLinux {
     #[serde(rename = "AllowAllDevices")]
     pub allow_all_devices: bool,
     #[serde(rename = "Devices")]
-    pub devices: Vec<models::PluginDevice>,
+    pub devices: Vec<PluginDevice>,
 }
 impl PluginConfigLinux {
     pub fn new(
         capabilities: Vec<String>,
         allow_all_devices: bool,
-        devices: Vec<models::PluginDevice>,
+        devices: Vec<PluginDevice>,
     ) -> PluginConfigLinux {
         PluginConfigLinux {
             capabilities,
@@ -5019,8 +4772,6 @@ impl PluginConfigLinux {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigNetwork {
     #[serde(rename = "Type")]
@@ -5031,8 +4782,6 @@ impl PluginConfigNetwork {
         PluginConfigNetwork { r#type }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigRootfs {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
@@ -5048,8 +4797,6 @@ impl PluginConfigRootfs {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginConfigUser {
     #[serde(rename = "UID", skip_serializing_if = "Option::is_none")]
@@ -5065,8 +4812,6 @@ impl PluginConfigUser {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginDevice {
     #[serde(rename = "Name")]
@@ -5093,8 +4838,6 @@ impl PluginDevice {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginEnv {
     #[serde(rename = "Name")]
@@ -5121,8 +4864,6 @@ impl PluginEnv {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginInterfaceType {
     #[serde(rename = "Prefix")]
@@ -5145,8 +4886,6 @@ impl PluginInterfaceType {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginMount {
     #[serde(rename = "Name")]
@@ -5185,8 +4924,6 @@ impl PluginMount {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PluginPrivilege : Describes a permission the user has to accept upon installing the plugin.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginPrivilege {
@@ -5207,27 +4944,25 @@ impl PluginPrivilege {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PluginSettings : Settings that can be modified by users.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginSettings {
     #[serde(rename = "Mounts")]
-    pub mounts: Vec<models::PluginMount>,
+    pub mounts: Vec<PluginMount>,
     #[serde(rename = "Env")]
     pub env: Vec<String>,
     #[serde(rename = "Args")]
     pub args: Vec<String>,
     #[serde(rename = "Devices")]
-    pub devices: Vec<models::PluginDevice>,
+    pub devices: Vec<PluginDevice>,
 }
 impl PluginSettings {
     /// Settings that can be modified by users.
     pub fn new(
-        mounts: Vec<models::PluginMount>,
+        mounts: Vec<PluginMount>,
         env: Vec<String>,
         args: Vec<String>,
-        devices: Vec<models::PluginDevice>,
+        devices: Vec<PluginDevice>,
     ) -> PluginSettings {
         PluginSettings {
             mounts,
@@ -5237,8 +4972,6 @@ impl PluginSettings {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PluginsInfo : Available plugins per type.  <p><br /></p>  > **Note**: Only unmanaged (V1) plugins are included in this list. > V1 plugins are \"lazily\" loaded, and are not returned in this list > if there is no resource using the plugin.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PluginsInfo {
@@ -5266,8 +4999,6 @@ impl PluginsInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Port : An open port on a container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Port {
@@ -5281,11 +5012,11 @@ pub struct Port {
     #[serde(rename = "PublicPort", skip_serializing_if = "Option::is_none")]
     pub public_port: Option<i32>,
     #[serde(rename = "Type")]
-    pub r#type: Type,
+    pub r#type: PortType,
 }
 impl Port {
     /// An open port on a container
-    pub fn new(private_port: i32, r#type: Type) -> Port {
+    pub fn new(private_port: i32, r#type: PortType) -> Port {
         Port {
             ip: None,
             private_port,
@@ -5307,7 +5038,7 @@ impl Port {
     Serialize,
     Deserialize
 )]
-pub enum Type {
+pub enum PortType {
     #[serde(rename = "tcp")]
     Tcp,
     #[serde(rename = "udp")]
@@ -5315,13 +5046,11 @@ pub enum Type {
     #[serde(rename = "sctp")]
     Sctp,
 }
-impl Default for Type {
-    fn default() -> Type {
+impl Default for PortType {
+    fn default() -> PortType {
         Self::Tcp
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PortBinding : PortBinding represents a binding between a host IP address and a host port.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PortBinding {
@@ -5341,13 +5070,11 @@ impl PortBinding {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// PortStatus : represents the port status of a task's host ports whose service has published host ports
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PortStatus {
     #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
-    pub ports: Option<Vec<models::EndpointPortConfig>>,
+    pub ports: Option<Vec<EndpointPortConfig>>,
 }
 impl PortStatus {
     /// represents the port status of a task's host ports whose service has published host ports
@@ -5355,8 +5082,6 @@ impl PortStatus {
         PortStatus { ports: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProcessConfig {
     #[serde(rename = "privileged", skip_serializing_if = "Option::is_none")]
@@ -5381,8 +5106,6 @@ impl ProcessConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProgressDetail {
     #[serde(rename = "current", skip_serializing_if = "Option::is_none")]
@@ -5398,8 +5121,6 @@ impl ProgressDetail {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PushImageInfo {
     #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
@@ -5409,7 +5130,7 @@ pub struct PushImageInfo {
     #[serde(rename = "progress", skip_serializing_if = "Option::is_none")]
     pub progress: Option<String>,
     #[serde(rename = "progressDetail", skip_serializing_if = "Option::is_none")]
-    pub progress_detail: Option<Box<models::ProgressDetail>>,
+    pub progress_detail: Option<Box<ProgressDetail>>,
 }
 impl PushImageInfo {
     pub fn new() -> PushImageInfo {
@@ -5421,8 +5142,6 @@ impl PushImageInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Reachability : Reachability represents the reachability of a node.
 /// Reachability represents the reachability of a node.
 #[derive(
@@ -5459,8 +5178,6 @@ impl Default for Reachability {
         Self::Unknown
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// RegistryServiceConfig : RegistryServiceConfig stores daemon registry services configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RegistryServiceConfig {
@@ -5480,7 +5197,7 @@ pub struct RegistryServiceConfig {
     #[serde(rename = "InsecureRegistryCIDRs", skip_serializing_if = "Option::is_none")]
     pub insecure_registry_cidrs: Option<Vec<String>>,
     #[serde(rename = "IndexConfigs", skip_serializing_if = "Option::is_none")]
-    pub index_configs: Option<std::collections::HashMap<String, models::IndexInfo>>,
+    pub index_configs: Option<std::collections::HashMap<String, IndexInfo>>,
     /// List of registry URLs that act as a mirror for the official (`docker.io`) registry.
     #[serde(rename = "Mirrors", skip_serializing_if = "Option::is_none")]
     pub mirrors: Option<Vec<String>>,
@@ -5497,8 +5214,6 @@ impl RegistryServiceConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ResourceObject : An object describing the resources which can be advertised by a node and requested by a task.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceObject {
@@ -5508,7 +5223,7 @@ pub struct ResourceObject {
     pub memory_bytes: Option<i64>,
     /// User-defined resources can be either Integer resources (e.g, `SSD=3`) or String resources (e.g, `GPU=UUID1`).
     #[serde(rename = "GenericResources", skip_serializing_if = "Option::is_none")]
-    pub generic_resources: Option<Vec<models::GenericResourcesInner>>,
+    pub generic_resources: Option<Vec<GenericResourcesInner>>,
 }
 impl ResourceObject {
     /// An object describing the resources which can be advertised by a node and requested by a task.
@@ -5520,8 +5235,6 @@ impl ResourceObject {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Resources : A container's resources (cgroups config, ulimits, etc)
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resources {
@@ -5539,19 +5252,19 @@ pub struct Resources {
     pub blkio_weight: Option<u32>,
     /// Block IO weight (relative device weight) in the form:  ``` [{\"Path\": \"device_path\", \"Weight\": weight}] ```
     #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none")]
-    pub blkio_weight_device: Option<Vec<models::ResourcesBlkioWeightDeviceInner>>,
+    pub blkio_weight_device: Option<Vec<ResourcesBlkioWeightDeviceInner>>,
     /// Limit read rate (bytes per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (bytes per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_bps: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
     /// Limit read rate (IO per second) from a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_read_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_read_i_ops: Option<Vec<ThrottleDevice>>,
     /// Limit write rate (IO per second) to a device, in the form:  ``` [{\"Path\": \"device_path\", \"Rate\": rate}] ```
     #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none")]
-    pub blkio_device_write_i_ops: Option<Vec<models::ThrottleDevice>>,
+    pub blkio_device_write_i_ops: Option<Vec<ThrottleDevice>>,
     /// The length of a CPU period in microseconds.
     #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none")]
     pub cpu_period: Option<i64>,
@@ -5572,13 +5285,13 @@ pub struct Resources {
     pub cpuset_mems: Option<String>,
     /// A list of devices to add to the container.
     #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
-    pub devices: Option<Vec<models::DeviceMapping>>,
+    pub devices: Option<Vec<DeviceMapping>>,
     /// a list of cgroup rules to apply to the container
     #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none")]
     pub device_cgroup_rules: Option<Vec<String>>,
     /// A list of requests for devices to be sent to device drivers.
     #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none")]
-    pub device_requests: Option<Vec<models::DeviceRequest>>,
+    pub device_requests: Option<Vec<DeviceRequest>>,
     /// Hard limit for kernel TCP buffer memory (in bytes). Depending on the OCI runtime in use, this option may be ignored. It is no longer supported by the default (runc) runtime.  This field is omitted when empty.
     #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none")]
     pub kernel_memory_tcp: Option<i64>,
@@ -5615,7 +5328,7 @@ pub struct Resources {
     pub pids_limit: Option<Option<i64>>,
     /// A list of resource limits to set in the container. For example:  ``` {\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048} ```
     #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
-    pub ulimits: Option<Vec<models::ResourcesUlimitsInner>>,
+    pub ulimits: Option<Vec<ResourcesUlimitsInner>>,
     /// The number of usable CPUs (Windows only).  On Windows Server containers, the processor resource controls are mutually exclusive. The order of precedence is `CPUCount` first, then `CPUShares`, and `CPUPercent` last.
     #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none")]
     pub cpu_count: Option<i64>,
@@ -5667,8 +5380,6 @@ impl Resources {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourcesBlkioWeightDeviceInner {
     #[serde(rename = "Path", skip_serializing_if = "Option::is_none")]
@@ -5684,8 +5395,6 @@ impl ResourcesBlkioWeightDeviceInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourcesUlimitsInner {
     /// Name of ulimit
@@ -5707,14 +5416,12 @@ impl ResourcesUlimitsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// RestartPolicy : The behavior to apply when the container exits. The default is not to restart.  An ever increasing delay (double the previous delay, starting at 100ms) is added before each restart to prevent flooding the server.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RestartPolicy {
     /// - Empty string means not to restart - `no` Do not automatically restart - `always` Always restart - `unless-stopped` Restart always except when the user has manually stopped the container - `on-failure` Restart only when the container exit code is non-zero
     #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
-    pub name: Option<Name>,
+    pub name: Option<RestartPolicyName>,
     /// If `on-failure` is used, the number of times to retry before giving up.
     #[serde(rename = "MaximumRetryCount", skip_serializing_if = "Option::is_none")]
     pub maximum_retry_count: Option<i32>,
@@ -5741,7 +5448,7 @@ impl RestartPolicy {
     Serialize,
     Deserialize
 )]
-pub enum Name {
+pub enum RestartPolicyName {
     #[serde(rename = "")]
     Empty,
     #[serde(rename = "no")]
@@ -5753,13 +5460,11 @@ pub enum Name {
     #[serde(rename = "on-failure")]
     OnFailure,
 }
-impl Default for Name {
-    fn default() -> Name {
+impl Default for RestartPolicyName {
+    fn default() -> RestartPolicyName {
         Self::Empty
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// Runtime : Runtime describes an [OCI compliant](https://github.com/opencontainers/runtime-spec) runtime.  The runtime is invoked by the daemon via the `containerd` daemon. OCI runtimes act as an interface to the Linux kernel namespaces, cgroups, and SELinux.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Runtime {
@@ -5793,20 +5498,18 @@ impl Runtime {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Secret {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::SecretSpec>>,
+    pub spec: Option<Box<SecretSpec>>,
 }
 impl Secret {
     pub fn new() -> Secret {
@@ -5819,8 +5522,6 @@ impl Secret {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SecretCreateRequest {
     /// User-defined name of the secret.
@@ -5833,9 +5534,9 @@ pub struct SecretCreateRequest {
     #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
-    pub driver: Option<Box<models::Driver>>,
+    pub driver: Option<Box<Driver>>,
     #[serde(rename = "Templating", skip_serializing_if = "Option::is_none")]
-    pub templating: Option<Box<models::Driver>>,
+    pub templating: Option<Box<Driver>>,
 }
 impl SecretCreateRequest {
     pub fn new() -> SecretCreateRequest {
@@ -5848,8 +5549,6 @@ impl SecretCreateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SecretSpec {
     /// User-defined name of the secret.
@@ -5862,9 +5561,9 @@ pub struct SecretSpec {
     #[serde(rename = "Data", skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     #[serde(rename = "Driver", skip_serializing_if = "Option::is_none")]
-    pub driver: Option<Box<models::Driver>>,
+    pub driver: Option<Box<Driver>>,
     #[serde(rename = "Templating", skip_serializing_if = "Option::is_none")]
-    pub templating: Option<Box<models::Driver>>,
+    pub templating: Option<Box<Driver>>,
 }
 impl SecretSpec {
     pub fn new() -> SecretSpec {
@@ -5877,28 +5576,26 @@ impl SecretSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Service {
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::ServiceSpec>>,
+    pub spec: Option<Box<ServiceSpec>>,
     #[serde(rename = "Endpoint", skip_serializing_if = "Option::is_none")]
-    pub endpoint: Option<Box<models::ServiceEndpoint>>,
+    pub endpoint: Option<Box<ServiceEndpoint>>,
     #[serde(rename = "UpdateStatus", skip_serializing_if = "Option::is_none")]
-    pub update_status: Option<Box<models::ServiceUpdateStatus>>,
+    pub update_status: Option<Box<ServiceUpdateStatus>>,
     #[serde(rename = "ServiceStatus", skip_serializing_if = "Option::is_none")]
-    pub service_status: Option<Box<models::ServiceServiceStatus>>,
+    pub service_status: Option<Box<ServiceServiceStatus>>,
     #[serde(rename = "JobStatus", skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<Box<models::ServiceJobStatus>>,
+    pub job_status: Option<Box<ServiceJobStatus>>,
 }
 impl Service {
     pub fn new() -> Service {
@@ -5915,8 +5612,6 @@ impl Service {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceCreateRequest {
     /// Name of the service.
@@ -5926,18 +5621,18 @@ pub struct ServiceCreateRequest {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "TaskTemplate", skip_serializing_if = "Option::is_none")]
-    pub task_template: Option<Box<models::TaskSpec>>,
+    pub task_template: Option<Box<TaskSpec>>,
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Box<models::ServiceSpecMode>>,
+    pub mode: Option<Box<ServiceSpecMode>>,
     #[serde(rename = "UpdateConfig", skip_serializing_if = "Option::is_none")]
-    pub update_config: Option<Box<models::ServiceSpecUpdateConfig>>,
+    pub update_config: Option<Box<ServiceSpecUpdateConfig>>,
     #[serde(rename = "RollbackConfig", skip_serializing_if = "Option::is_none")]
-    pub rollback_config: Option<Box<models::ServiceSpecRollbackConfig>>,
+    pub rollback_config: Option<Box<ServiceSpecRollbackConfig>>,
     /// Specifies which networks the service should attach to.  Deprecated: This field is deprecated since v1.44. The Networks field in TaskSpec should be used instead.
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<Vec<models::NetworkAttachmentConfig>>,
+    pub networks: Option<Vec<NetworkAttachmentConfig>>,
     #[serde(rename = "EndpointSpec", skip_serializing_if = "Option::is_none")]
-    pub endpoint_spec: Option<Box<models::EndpointSpec>>,
+    pub endpoint_spec: Option<Box<EndpointSpec>>,
 }
 impl ServiceCreateRequest {
     pub fn new() -> ServiceCreateRequest {
@@ -5953,8 +5648,6 @@ impl ServiceCreateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceCreateResponse : contains the information returned to a client on the creation of a new service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceCreateResponse {
@@ -5979,16 +5672,14 @@ impl ServiceCreateResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceEndpoint {
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::EndpointSpec>>,
+    pub spec: Option<Box<EndpointSpec>>,
     #[serde(rename = "Ports", skip_serializing_if = "Option::is_none")]
-    pub ports: Option<Vec<models::EndpointPortConfig>>,
+    pub ports: Option<Vec<EndpointPortConfig>>,
     #[serde(rename = "VirtualIPs", skip_serializing_if = "Option::is_none")]
-    pub virtual_ips: Option<Vec<models::ServiceEndpointVirtualIpsInner>>,
+    pub virtual_ips: Option<Vec<ServiceEndpointVirtualIpsInner>>,
 }
 impl ServiceEndpoint {
     pub fn new() -> ServiceEndpoint {
@@ -5999,8 +5690,6 @@ impl ServiceEndpoint {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceEndpointVirtualIpsInner {
     #[serde(rename = "NetworkID", skip_serializing_if = "Option::is_none")]
@@ -6016,13 +5705,11 @@ impl ServiceEndpointVirtualIpsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceJobStatus : The status of the service when it is in one of ReplicatedJob or GlobalJob modes. Absent on Replicated and Global mode services. The JobIteration is an ObjectVersion, but unlike the Service's version, does not need to be sent with an update request.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceJobStatus {
     #[serde(rename = "JobIteration", skip_serializing_if = "Option::is_none")]
-    pub job_iteration: Option<Box<models::ObjectVersion>>,
+    pub job_iteration: Option<Box<ObjectVersion>>,
     /// The last time, as observed by the server, that this job was started.
     #[serde(rename = "LastExecution", skip_serializing_if = "Option::is_none")]
     pub last_execution: Option<String>,
@@ -6036,8 +5723,6 @@ impl ServiceJobStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceServiceStatus : The status of the service's tasks. Provided only when requested as part of a ServiceList operation.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceServiceStatus {
@@ -6061,8 +5746,6 @@ impl ServiceServiceStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceSpec : User modifiable configuration for a service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpec {
@@ -6073,18 +5756,18 @@ pub struct ServiceSpec {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "TaskTemplate", skip_serializing_if = "Option::is_none")]
-    pub task_template: Option<Box<models::TaskSpec>>,
+    pub task_template: Option<Box<TaskSpec>>,
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Box<models::ServiceSpecMode>>,
+    pub mode: Option<Box<ServiceSpecMode>>,
     #[serde(rename = "UpdateConfig", skip_serializing_if = "Option::is_none")]
-    pub update_config: Option<Box<models::ServiceSpecUpdateConfig>>,
+    pub update_config: Option<Box<ServiceSpecUpdateConfig>>,
     #[serde(rename = "RollbackConfig", skip_serializing_if = "Option::is_none")]
-    pub rollback_config: Option<Box<models::ServiceSpecRollbackConfig>>,
+    pub rollback_config: Option<Box<ServiceSpecRollbackConfig>>,
     /// Specifies which networks the service should attach to.  Deprecated: This field is deprecated since v1.44. The Networks field in TaskSpec should be used instead.
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<Vec<models::NetworkAttachmentConfig>>,
+    pub networks: Option<Vec<NetworkAttachmentConfig>>,
     #[serde(rename = "EndpointSpec", skip_serializing_if = "Option::is_none")]
-    pub endpoint_spec: Option<Box<models::EndpointSpec>>,
+    pub endpoint_spec: Option<Box<EndpointSpec>>,
 }
 impl ServiceSpec {
     /// User modifiable configuration for a service.
@@ -6101,17 +5784,15 @@ impl ServiceSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceSpecMode : Scheduling mode for the service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecMode {
     #[serde(rename = "Replicated", skip_serializing_if = "Option::is_none")]
-    pub replicated: Option<Box<models::ServiceSpecModeReplicated>>,
+    pub replicated: Option<Box<ServiceSpecModeReplicated>>,
     #[serde(rename = "Global", skip_serializing_if = "Option::is_none")]
     pub global: Option<serde_json::Value>,
     #[serde(rename = "ReplicatedJob", skip_serializing_if = "Option::is_none")]
-    pub replicated_job: Option<Box<models::ServiceSpecModeReplicatedJob>>,
+    pub replicated_job: Option<Box<ServiceSpecModeReplicatedJob>>,
     /// The mode used for services which run a task to the completed state on each valid node.
     #[serde(rename = "GlobalJob", skip_serializing_if = "Option::is_none")]
     pub global_job: Option<serde_json::Value>,
@@ -6127,8 +5808,6 @@ impl ServiceSpecMode {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecModeReplicated {
     #[serde(rename = "Replicas", skip_serializing_if = "Option::is_none")]
@@ -6141,8 +5820,6 @@ impl ServiceSpecModeReplicated {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceSpecModeReplicatedJob : The mode used for services with a finite number of tasks that run to a completed state.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecModeReplicatedJob {
@@ -6162,8 +5839,6 @@ impl ServiceSpecModeReplicatedJob {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceSpecRollbackConfig : Specification for the rollback strategy of the service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecRollbackConfig {
@@ -6175,7 +5850,7 @@ pub struct ServiceSpecRollbackConfig {
     pub delay: Option<i64>,
     /// Action to take if an rolled back task fails to run, or stops running during the rollback.
     #[serde(rename = "FailureAction", skip_serializing_if = "Option::is_none")]
-    pub failure_action: Option<FailureAction>,
+    pub failure_action: Option<ServiceSpecRollbackConfigFailureAction>,
     /// Amount of time to monitor each rolled back task for failures, in nanoseconds.
     #[serde(rename = "Monitor", skip_serializing_if = "Option::is_none")]
     pub monitor: Option<i64>,
@@ -6184,7 +5859,7 @@ pub struct ServiceSpecRollbackConfig {
     pub max_failure_ratio: Option<f64>,
     /// The order of operations when rolling back a task. Either the old task is shut down before the new task is started, or the new task is started before the old task is shut down.
     #[serde(rename = "Order", skip_serializing_if = "Option::is_none")]
-    pub order: Option<Order>,
+    pub order: Option<ServiceSpecRollbackConfigOrder>,
 }
 impl ServiceSpecRollbackConfig {
     /// Specification for the rollback strategy of the service.
@@ -6212,14 +5887,14 @@ impl ServiceSpecRollbackConfig {
     Serialize,
     Deserialize
 )]
-pub enum FailureAction {
+pub enum ServiceSpecRollbackConfigFailureAction {
     #[serde(rename = "continue")]
     Continue,
     #[serde(rename = "pause")]
     Pause,
 }
-impl Default for FailureAction {
-    fn default() -> FailureAction {
+impl Default for ServiceSpecRollbackConfigFailureAction {
+    fn default() -> ServiceSpecRollbackConfigFailureAction {
         Self::Continue
     }
 }
@@ -6236,19 +5911,17 @@ impl Default for FailureAction {
     Serialize,
     Deserialize
 )]
-pub enum Order {
+pub enum ServiceSpecRollbackConfigOrder {
     #[serde(rename = "stop-first")]
     StopFirst,
     #[serde(rename = "start-first")]
     StartFirst,
 }
-impl Default for Order {
-    fn default() -> Order {
+impl Default for ServiceSpecRollbackConfigOrder {
+    fn default() -> ServiceSpecRollbackConfigOrder {
         Self::StopFirst
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceSpecUpdateConfig : Specification for the update strategy of the service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecUpdateConfig {
@@ -6260,7 +5933,7 @@ pub struct ServiceSpecUpdateConfig {
     pub delay: Option<i64>,
     /// Action to take if an updated task fails to run, or stops running during the update.
     #[serde(rename = "FailureAction", skip_serializing_if = "Option::is_none")]
-    pub failure_action: Option<FailureAction>,
+    pub failure_action: Option<ServiceSpecUpdateConfigFailureAction>,
     /// Amount of time to monitor each updated task for failures, in nanoseconds.
     #[serde(rename = "Monitor", skip_serializing_if = "Option::is_none")]
     pub monitor: Option<i64>,
@@ -6269,7 +5942,7 @@ pub struct ServiceSpecUpdateConfig {
     pub max_failure_ratio: Option<f64>,
     /// The order of operations when rolling out an updated task. Either the old task is shut down before the new task is started, or the new task is started before the old task is shut down.
     #[serde(rename = "Order", skip_serializing_if = "Option::is_none")]
-    pub order: Option<Order>,
+    pub order: Option<ServiceSpecUpdateConfigOrder>,
 }
 impl ServiceSpecUpdateConfig {
     /// Specification for the update strategy of the service.
@@ -6297,7 +5970,7 @@ impl ServiceSpecUpdateConfig {
     Serialize,
     Deserialize
 )]
-pub enum FailureAction {
+pub enum ServiceSpecUpdateConfigFailureAction {
     #[serde(rename = "continue")]
     Continue,
     #[serde(rename = "pause")]
@@ -6305,8 +5978,8 @@ pub enum FailureAction {
     #[serde(rename = "rollback")]
     Rollback,
 }
-impl Default for FailureAction {
-    fn default() -> FailureAction {
+impl Default for ServiceSpecUpdateConfigFailureAction {
+    fn default() -> ServiceSpecUpdateConfigFailureAction {
         Self::Continue
     }
 }
@@ -6323,19 +5996,17 @@ impl Default for FailureAction {
     Serialize,
     Deserialize
 )]
-pub enum Order {
+pub enum ServiceSpecUpdateConfigOrder {
     #[serde(rename = "stop-first")]
     StopFirst,
     #[serde(rename = "start-first")]
     StartFirst,
 }
-impl Default for Order {
-    fn default() -> Order {
+impl Default for ServiceSpecUpdateConfigOrder {
+    fn default() -> ServiceSpecUpdateConfigOrder {
         Self::StopFirst
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceUpdateRequest {
     /// Name of the service.
@@ -6345,18 +6016,18 @@ pub struct ServiceUpdateRequest {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "TaskTemplate", skip_serializing_if = "Option::is_none")]
-    pub task_template: Option<Box<models::TaskSpec>>,
+    pub task_template: Option<Box<TaskSpec>>,
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Box<models::ServiceSpecMode>>,
+    pub mode: Option<Box<ServiceSpecMode>>,
     #[serde(rename = "UpdateConfig", skip_serializing_if = "Option::is_none")]
-    pub update_config: Option<Box<models::ServiceSpecUpdateConfig>>,
+    pub update_config: Option<Box<ServiceSpecUpdateConfig>>,
     #[serde(rename = "RollbackConfig", skip_serializing_if = "Option::is_none")]
-    pub rollback_config: Option<Box<models::ServiceSpecRollbackConfig>>,
+    pub rollback_config: Option<Box<ServiceSpecRollbackConfig>>,
     /// Specifies which networks the service should attach to.  Deprecated: This field is deprecated since v1.44. The Networks field in TaskSpec should be used instead.
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<Vec<models::NetworkAttachmentConfig>>,
+    pub networks: Option<Vec<NetworkAttachmentConfig>>,
     #[serde(rename = "EndpointSpec", skip_serializing_if = "Option::is_none")]
-    pub endpoint_spec: Option<Box<models::EndpointSpec>>,
+    pub endpoint_spec: Option<Box<EndpointSpec>>,
 }
 impl ServiceUpdateRequest {
     pub fn new() -> ServiceUpdateRequest {
@@ -6372,8 +6043,6 @@ impl ServiceUpdateRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceUpdateResponse {
     /// Optional warning messages
@@ -6387,13 +6056,11 @@ impl ServiceUpdateResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// ServiceUpdateStatus : The status of a service update.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServiceUpdateStatus {
     #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
-    pub state: Option<State>,
+    pub state: Option<ServiceUpdateStatusState>,
     #[serde(rename = "StartedAt", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<String>,
     #[serde(rename = "CompletedAt", skip_serializing_if = "Option::is_none")]
@@ -6425,7 +6092,7 @@ impl ServiceUpdateStatus {
     Serialize,
     Deserialize
 )]
-pub enum State {
+pub enum ServiceUpdateStatusState {
     #[serde(rename = "updating")]
     Updating,
     #[serde(rename = "paused")]
@@ -6433,20 +6100,18 @@ pub enum State {
     #[serde(rename = "completed")]
     Completed,
 }
-impl Default for State {
-    fn default() -> State {
+impl Default for ServiceUpdateStatusState {
+    fn default() -> ServiceUpdateStatusState {
         Self::Updating
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Swarm {
     /// The ID of the swarm.
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     /// Date and time at which the swarm was initialised in [RFC 3339](https://www.ietf.org/rfc/rfc3339.txt) format with nano-seconds.
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
@@ -6454,9 +6119,9 @@ pub struct Swarm {
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::SwarmSpec>>,
+    pub spec: Option<Box<SwarmSpec>>,
     #[serde(rename = "TLSInfo", skip_serializing_if = "Option::is_none")]
-    pub tls_info: Option<Box<models::TlsInfo>>,
+    pub tls_info: Option<Box<TlsInfo>>,
     /// Whether there is currently a root CA rotation in progress for the swarm
     #[serde(rename = "RootRotationInProgress", skip_serializing_if = "Option::is_none")]
     pub root_rotation_in_progress: Option<bool>,
@@ -6470,7 +6135,7 @@ pub struct Swarm {
     #[serde(rename = "SubnetSize", skip_serializing_if = "Option::is_none")]
     pub subnet_size: Option<i32>,
     #[serde(rename = "JoinTokens", skip_serializing_if = "Option::is_none")]
-    pub join_tokens: Option<Box<models::JoinTokens>>,
+    pub join_tokens: Option<Box<JoinTokens>>,
 }
 impl Swarm {
     pub fn new() -> Swarm {
@@ -6489,8 +6154,6 @@ impl Swarm {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmInfo : Represents generic information about swarm.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmInfo {
@@ -6501,7 +6164,7 @@ pub struct SwarmInfo {
     #[serde(rename = "NodeAddr", skip_serializing_if = "Option::is_none")]
     pub node_addr: Option<String>,
     #[serde(rename = "LocalNodeState", skip_serializing_if = "Option::is_none")]
-    pub local_node_state: Option<models::LocalNodeState>,
+    pub local_node_state: Option<LocalNodeState>,
     #[serde(rename = "ControlAvailable", skip_serializing_if = "Option::is_none")]
     pub control_available: Option<bool>,
     #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
@@ -6513,7 +6176,7 @@ pub struct SwarmInfo {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub remote_managers: Option<Option<Vec<models::PeerNode>>>,
+    pub remote_managers: Option<Option<Vec<PeerNode>>>,
     /// Total number of nodes in the swarm.
     #[serde(
         rename = "Nodes",
@@ -6536,7 +6199,7 @@ pub struct SwarmInfo {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cluster: Option<Option<Box<models::ClusterInfo>>>,
+    pub cluster: Option<Option<Box<ClusterInfo>>>,
 }
 impl SwarmInfo {
     /// Represents generic information about swarm.
@@ -6554,8 +6217,6 @@ impl SwarmInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmInitRequest {
     /// Listen address used for inter-manager communication, as well as determining the networking interface used for the VXLAN Tunnel Endpoint (VTEP). This can either be an address/port combination in the form `192.168.1.1:4567`, or an interface followed by a port number, like `eth0:4567`. If the port number is omitted, the default swarm listening port is used.
@@ -6580,7 +6241,7 @@ pub struct SwarmInitRequest {
     #[serde(rename = "SubnetSize", skip_serializing_if = "Option::is_none")]
     pub subnet_size: Option<i32>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::SwarmSpec>>,
+    pub spec: Option<Box<SwarmSpec>>,
 }
 impl SwarmInitRequest {
     pub fn new() -> SwarmInitRequest {
@@ -6596,8 +6257,6 @@ impl SwarmInitRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmJoinRequest {
     /// Listen address used for inter-manager communication if the node gets promoted to manager, as well as determining the networking interface used for the VXLAN Tunnel Endpoint (VTEP).
@@ -6627,8 +6286,6 @@ impl SwarmJoinRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpec : User modifiable swarm configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpec {
@@ -6644,27 +6301,27 @@ pub struct SwarmSpec {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub orchestration: Option<Option<Box<models::SwarmSpecOrchestration>>>,
+    pub orchestration: Option<Option<Box<SwarmSpecOrchestration>>>,
     #[serde(rename = "Raft", skip_serializing_if = "Option::is_none")]
-    pub raft: Option<Box<models::SwarmSpecRaft>>,
+    pub raft: Option<Box<SwarmSpecRaft>>,
     #[serde(
         rename = "Dispatcher",
         default,
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub dispatcher: Option<Option<Box<models::SwarmSpecDispatcher>>>,
+    pub dispatcher: Option<Option<Box<SwarmSpecDispatcher>>>,
     #[serde(
         rename = "CAConfig",
         default,
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub ca_config: Option<Option<Box<models::SwarmSpecCaConfig>>>,
+    pub ca_config: Option<Option<Box<SwarmSpecCaConfig>>>,
     #[serde(rename = "EncryptionConfig", skip_serializing_if = "Option::is_none")]
-    pub encryption_config: Option<Box<models::SwarmSpecEncryptionConfig>>,
+    pub encryption_config: Option<Box<SwarmSpecEncryptionConfig>>,
     #[serde(rename = "TaskDefaults", skip_serializing_if = "Option::is_none")]
-    pub task_defaults: Option<Box<models::SwarmSpecTaskDefaults>>,
+    pub task_defaults: Option<Box<SwarmSpecTaskDefaults>>,
 }
 impl SwarmSpec {
     /// User modifiable swarm configuration.
@@ -6681,8 +6338,6 @@ impl SwarmSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecCaConfig : CA configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecCaConfig {
@@ -6691,7 +6346,7 @@ pub struct SwarmSpecCaConfig {
     pub node_cert_expiry: Option<i64>,
     /// Configuration for forwarding signing requests to an external certificate authority.
     #[serde(rename = "ExternalCAs", skip_serializing_if = "Option::is_none")]
-    pub external_cas: Option<Vec<models::SwarmSpecCaConfigExternalCasInner>>,
+    pub external_cas: Option<Vec<SwarmSpecCaConfigExternalCasInner>>,
     /// The desired signing CA certificate for all swarm node TLS leaf certificates, in PEM format.
     #[serde(rename = "SigningCACert", skip_serializing_if = "Option::is_none")]
     pub signing_ca_cert: Option<String>,
@@ -6714,13 +6369,11 @@ impl SwarmSpecCaConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecCaConfigExternalCasInner {
     /// Protocol for communication with the external CA (currently only `cfssl` is supported).
     #[serde(rename = "Protocol", skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<Protocol>,
+    pub protocol: Option<SwarmSpecCaConfigExternalCasInnerProtocol>,
     /// URL where certificate signing requests should be sent.
     #[serde(rename = "URL", skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -6754,17 +6407,15 @@ impl SwarmSpecCaConfigExternalCasInner {
     Serialize,
     Deserialize
 )]
-pub enum Protocol {
+pub enum SwarmSpecCaConfigExternalCasInnerProtocol {
     #[serde(rename = "cfssl")]
     Cfssl,
 }
-impl Default for Protocol {
-    fn default() -> Protocol {
+impl Default for SwarmSpecCaConfigExternalCasInnerProtocol {
+    fn default() -> SwarmSpecCaConfigExternalCasInnerProtocol {
         Self::Cfssl
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecDispatcher : Dispatcher configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecDispatcher {
@@ -6780,8 +6431,6 @@ impl SwarmSpecDispatcher {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecEncryptionConfig : Parameters related to encryption-at-rest.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecEncryptionConfig {
@@ -6797,8 +6446,6 @@ impl SwarmSpecEncryptionConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecOrchestration : Orchestration configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecOrchestration {
@@ -6817,8 +6464,6 @@ impl SwarmSpecOrchestration {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecRaft : Raft configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecRaft {
@@ -6853,13 +6498,11 @@ impl SwarmSpecRaft {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecTaskDefaults : Defaults for creating tasks in this cluster.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecTaskDefaults {
     #[serde(rename = "LogDriver", skip_serializing_if = "Option::is_none")]
-    pub log_driver: Option<Box<models::SwarmSpecTaskDefaultsLogDriver>>,
+    pub log_driver: Option<Box<SwarmSpecTaskDefaultsLogDriver>>,
 }
 impl SwarmSpecTaskDefaults {
     /// Defaults for creating tasks in this cluster.
@@ -6869,8 +6512,6 @@ impl SwarmSpecTaskDefaults {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SwarmSpecTaskDefaultsLogDriver : The log driver to use for tasks created in the orchestrator if unspecified by a service.  Updating this value only affects new tasks. Existing tasks continue to use their previously configured log driver until recreated.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmSpecTaskDefaultsLogDriver {
@@ -6890,8 +6531,6 @@ impl SwarmSpecTaskDefaultsLogDriver {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SwarmUnlockRequest {
     /// The swarm's unlock key.
@@ -6905,8 +6544,6 @@ impl SwarmUnlockRequest {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemAuthResponse {
     /// The status of the authentication
@@ -6924,20 +6561,18 @@ impl SystemAuthResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemDataUsageResponse {
     #[serde(rename = "LayersSize", skip_serializing_if = "Option::is_none")]
     pub layers_size: Option<i64>,
     #[serde(rename = "Images", skip_serializing_if = "Option::is_none")]
-    pub images: Option<Vec<models::ImageSummary>>,
+    pub images: Option<Vec<ImageSummary>>,
     #[serde(rename = "Containers", skip_serializing_if = "Option::is_none")]
-    pub containers: Option<Vec<models::ContainerSummary>>,
+    pub containers: Option<Vec<ContainerSummary>>,
     #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
-    pub volumes: Option<Vec<models::Volume>>,
+    pub volumes: Option<Vec<Volume>>,
     #[serde(rename = "BuildCache", skip_serializing_if = "Option::is_none")]
-    pub build_cache: Option<Vec<models::BuildCache>>,
+    pub build_cache: Option<Vec<BuildCache>>,
 }
 impl SystemDataUsageResponse {
     pub fn new() -> SystemDataUsageResponse {
@@ -6950,8 +6585,6 @@ impl SystemDataUsageResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemInfo {
     /// Unique identifier of the daemon.  <p><br /></p>  > **Note**: The format of the ID itself is not part of the API, and > should not be considered stable.
@@ -6982,7 +6615,7 @@ pub struct SystemInfo {
     #[serde(rename = "DockerRootDir", skip_serializing_if = "Option::is_none")]
     pub docker_root_dir: Option<String>,
     #[serde(rename = "Plugins", skip_serializing_if = "Option::is_none")]
-    pub plugins: Option<Box<models::PluginsInfo>>,
+    pub plugins: Option<Box<PluginsInfo>>,
     /// Indicates if the host has memory limit support enabled.
     #[serde(rename = "MemoryLimit", skip_serializing_if = "Option::is_none")]
     pub memory_limit: Option<bool>,
@@ -7036,10 +6669,10 @@ pub struct SystemInfo {
     pub logging_driver: Option<String>,
     /// The driver to use for managing cgroups.
     #[serde(rename = "CgroupDriver", skip_serializing_if = "Option::is_none")]
-    pub cgroup_driver: Option<CgroupDriver>,
+    pub cgroup_driver: Option<SystemInfoCgroupDriver>,
     /// The version of the cgroup.
     #[serde(rename = "CgroupVersion", skip_serializing_if = "Option::is_none")]
-    pub cgroup_version: Option<CgroupVersion>,
+    pub cgroup_version: Option<SystemInfoCgroupVersion>,
     /// Number of event listeners subscribed.
     #[serde(rename = "NEventsListener", skip_serializing_if = "Option::is_none")]
     pub n_events_listener: Option<i32>,
@@ -7073,10 +6706,10 @@ pub struct SystemInfo {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub registry_config: Option<Option<Box<models::RegistryServiceConfig>>>,
+    pub registry_config: Option<Option<Box<RegistryServiceConfig>>>,
     /// User-defined resources can be either Integer resources (e.g, `SSD=3`) or String resources (e.g, `GPU=UUID1`).
     #[serde(rename = "GenericResources", skip_serializing_if = "Option::is_none")]
-    pub generic_resources: Option<Vec<models::GenericResourcesInner>>,
+    pub generic_resources: Option<Vec<GenericResourcesInner>>,
     /// HTTP-proxy configured for the daemon. This value is obtained from the [`HTTP_PROXY`](https://www.gnu.org/software/wget/manual/html_node/Proxies.html) environment variable. Credentials ([user info component](https://tools.ietf.org/html/rfc3986#section-3.2.1)) in the proxy URL are masked in the API response.  Containers do not automatically inherit this configuration.
     #[serde(rename = "HttpProxy", skip_serializing_if = "Option::is_none")]
     pub http_proxy: Option<String>,
@@ -7100,27 +6733,27 @@ pub struct SystemInfo {
     pub server_version: Option<String>,
     /// List of [OCI compliant](https://github.com/opencontainers/runtime-spec) runtimes configured on the daemon. Keys hold the \"name\" used to reference the runtime.  The Docker daemon relies on an OCI compliant runtime (invoked via the `containerd` daemon) as its interface to the Linux kernel namespaces, cgroups, and SELinux.  The default runtime is `runc`, and automatically configured. Additional runtimes can be configured by the user and will be listed here.
     #[serde(rename = "Runtimes", skip_serializing_if = "Option::is_none")]
-    pub runtimes: Option<std::collections::HashMap<String, models::Runtime>>,
+    pub runtimes: Option<std::collections::HashMap<String, Runtime>>,
     /// Name of the default OCI runtime that is used when starting containers.  The default can be overridden per-container at create time.
     #[serde(rename = "DefaultRuntime", skip_serializing_if = "Option::is_none")]
     pub default_runtime: Option<String>,
     #[serde(rename = "Swarm", skip_serializing_if = "Option::is_none")]
-    pub swarm: Option<Box<models::SwarmInfo>>,
+    pub swarm: Option<Box<SwarmInfo>>,
     /// Indicates if live restore is enabled.  If enabled, containers are kept running when the daemon is shutdown or upon daemon start if running containers are detected.
     #[serde(rename = "LiveRestoreEnabled", skip_serializing_if = "Option::is_none")]
     pub live_restore_enabled: Option<bool>,
     /// Represents the isolation technology to use as a default for containers. The supported values are platform-specific.  If no isolation value is specified on daemon start, on Windows client, the default is `hyperv`, and on Windows server, the default is `process`.  This option is currently not used on other platforms.
     #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none")]
-    pub isolation: Option<Isolation>,
+    pub isolation: Option<SystemInfoIsolation>,
     /// Name and, optional, path of the `docker-init` binary.  If the path is omitted, the daemon searches the host's `$PATH` for the binary and uses the first result.
     #[serde(rename = "InitBinary", skip_serializing_if = "Option::is_none")]
     pub init_binary: Option<String>,
     #[serde(rename = "ContainerdCommit", skip_serializing_if = "Option::is_none")]
-    pub containerd_commit: Option<Box<models::Commit>>,
+    pub containerd_commit: Option<Box<Commit>>,
     #[serde(rename = "RuncCommit", skip_serializing_if = "Option::is_none")]
-    pub runc_commit: Option<Box<models::Commit>>,
+    pub runc_commit: Option<Box<Commit>>,
     #[serde(rename = "InitCommit", skip_serializing_if = "Option::is_none")]
-    pub init_commit: Option<Box<models::Commit>>,
+    pub init_commit: Option<Box<Commit>>,
     /// List of security features that are enabled on the daemon, such as apparmor, seccomp, SELinux, user-namespaces (userns), rootless and no-new-privileges.  Additional configuration options for each security feature may be present, and are included as a comma-separated list of key/value pairs.
     #[serde(rename = "SecurityOptions", skip_serializing_if = "Option::is_none")]
     pub security_options: Option<Vec<String>>,
@@ -7129,7 +6762,7 @@ pub struct SystemInfo {
     pub product_license: Option<String>,
     /// List of custom default address pools for local networks, which can be specified in the daemon.json file or dockerd option.  Example: a Base \"10.10.0.0/16\" with Size 24 will define the set of 256 10.10.[0-255].0/24 address pools.
     #[serde(rename = "DefaultAddressPools", skip_serializing_if = "Option::is_none")]
-    pub default_address_pools: Option<Vec<models::SystemInfoDefaultAddressPoolsInner>>,
+    pub default_address_pools: Option<Vec<SystemInfoDefaultAddressPoolsInner>>,
     /// List of warnings / informational messages about missing features, or issues related to the daemon configuration.  These messages can be printed by the client as information to the user.
     #[serde(rename = "Warnings", skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
@@ -7142,7 +6775,7 @@ pub struct SystemInfo {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub containerd: Option<Option<Box<models::ContainerdInfo>>>,
+    pub containerd: Option<Option<Box<ContainerdInfo>>>,
 }
 impl SystemInfo {
     pub fn new() -> SystemInfo {
@@ -7225,7 +6858,7 @@ impl SystemInfo {
     Serialize,
     Deserialize
 )]
-pub enum CgroupDriver {
+pub enum SystemInfoCgroupDriver {
     #[serde(rename = "cgroupfs")]
     Cgroupfs,
     #[serde(rename = "systemd")]
@@ -7233,8 +6866,8 @@ pub enum CgroupDriver {
     #[serde(rename = "none")]
     None,
 }
-impl Default for CgroupDriver {
-    fn default() -> CgroupDriver {
+impl Default for SystemInfoCgroupDriver {
+    fn default() -> SystemInfoCgroupDriver {
         Self::Cgroupfs
     }
 }
@@ -7251,14 +6884,14 @@ impl Default for CgroupDriver {
     Serialize,
     Deserialize
 )]
-pub enum CgroupVersion {
+pub enum SystemInfoCgroupVersion {
     #[serde(rename = "1")]
     Variant1,
     #[serde(rename = "2")]
     Variant2,
 }
-impl Default for CgroupVersion {
-    fn default() -> CgroupVersion {
+impl Default for SystemInfoCgroupVersion {
+    fn default() -> SystemInfoCgroupVersion {
         Self::Variant1
     }
 }
@@ -7275,7 +6908,7 @@ impl Default for CgroupVersion {
     Serialize,
     Deserialize
 )]
-pub enum Isolation {
+pub enum SystemInfoIsolation {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "hyperv")]
@@ -7285,13 +6918,11 @@ pub enum Isolation {
     #[serde(rename = "")]
     Empty,
 }
-impl Default for Isolation {
-    fn default() -> Isolation {
+impl Default for SystemInfoIsolation {
+    fn default() -> SystemInfoIsolation {
         Self::Default
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemInfoDefaultAddressPoolsInner {
     /// The network address in CIDR format
@@ -7309,16 +6940,14 @@ impl SystemInfoDefaultAddressPoolsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// SystemVersion : Response of Engine API: GET \"/version\"
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemVersion {
     #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
-    pub platform: Option<Box<models::SystemVersionPlatform>>,
+    pub platform: Option<Box<SystemVersionPlatform>>,
     /// Information about system components
     #[serde(rename = "Components", skip_serializing_if = "Option::is_none")]
-    pub components: Option<Vec<models::SystemVersionComponentsInner>>,
+    pub components: Option<Vec<SystemVersionComponentsInner>>,
     /// The version of the daemon
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
@@ -7369,8 +6998,6 @@ impl SystemVersion {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemVersionComponentsInner {
     /// Name of the component
@@ -7397,8 +7024,6 @@ impl SystemVersionComponentsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemVersionPlatform {
     #[serde(rename = "Name")]
@@ -7409,15 +7034,13 @@ impl SystemVersionPlatform {
         SystemVersionPlatform { name }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     /// The ID of the task.
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
-    pub version: Option<Box<models::ObjectVersion>>,
+    pub version: Option<Box<ObjectVersion>>,
     #[serde(rename = "CreatedAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "UpdatedAt", skip_serializing_if = "Option::is_none")]
@@ -7429,7 +7052,7 @@ pub struct Task {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::TaskSpec>>,
+    pub spec: Option<Box<TaskSpec>>,
     /// The ID of the service this task is part of.
     #[serde(rename = "ServiceID", skip_serializing_if = "Option::is_none")]
     pub service_id: Option<String>,
@@ -7443,13 +7066,13 @@ pub struct Task {
         rename = "AssignedGenericResources",
         skip_serializing_if = "Option::is_none"
     )]
-    pub assigned_generic_resources: Option<Vec<models::GenericResourcesInner>>,
+    pub assigned_generic_resources: Option<Vec<GenericResourcesInner>>,
     #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
-    pub status: Option<Box<models::TaskStatus>>,
+    pub status: Option<Box<TaskStatus>>,
     #[serde(rename = "DesiredState", skip_serializing_if = "Option::is_none")]
-    pub desired_state: Option<models::TaskState>,
+    pub desired_state: Option<TaskState>,
     #[serde(rename = "JobIteration", skip_serializing_if = "Option::is_none")]
-    pub job_iteration: Option<Box<models::ObjectVersion>>,
+    pub job_iteration: Option<Box<ObjectVersion>>,
 }
 impl Task {
     pub fn new() -> Task {
@@ -7471,23 +7094,21 @@ impl Task {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpec : User modifiable task configuration.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpec {
     #[serde(rename = "PluginSpec", skip_serializing_if = "Option::is_none")]
-    pub plugin_spec: Option<Box<models::TaskSpecPluginSpec>>,
+    pub plugin_spec: Option<Box<TaskSpecPluginSpec>>,
     #[serde(rename = "ContainerSpec", skip_serializing_if = "Option::is_none")]
-    pub container_spec: Option<Box<models::TaskSpecContainerSpec>>,
+    pub container_spec: Option<Box<TaskSpecContainerSpec>>,
     #[serde(rename = "NetworkAttachmentSpec", skip_serializing_if = "Option::is_none")]
-    pub network_attachment_spec: Option<Box<models::TaskSpecNetworkAttachmentSpec>>,
+    pub network_attachment_spec: Option<Box<TaskSpecNetworkAttachmentSpec>>,
     #[serde(rename = "Resources", skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Box<models::TaskSpecResources>>,
+    pub resources: Option<Box<TaskSpecResources>>,
     #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none")]
-    pub restart_policy: Option<Box<models::TaskSpecRestartPolicy>>,
+    pub restart_policy: Option<Box<TaskSpecRestartPolicy>>,
     #[serde(rename = "Placement", skip_serializing_if = "Option::is_none")]
-    pub placement: Option<Box<models::TaskSpecPlacement>>,
+    pub placement: Option<Box<TaskSpecPlacement>>,
     /// A counter that triggers an update even if no relevant parameters have been changed.
     #[serde(rename = "ForceUpdate", skip_serializing_if = "Option::is_none")]
     pub force_update: Option<i32>,
@@ -7496,9 +7117,9 @@ pub struct TaskSpec {
     pub runtime: Option<String>,
     /// Specifies which networks the service should attach to.
     #[serde(rename = "Networks", skip_serializing_if = "Option::is_none")]
-    pub networks: Option<Vec<models::NetworkAttachmentConfig>>,
+    pub networks: Option<Vec<NetworkAttachmentConfig>>,
     #[serde(rename = "LogDriver", skip_serializing_if = "Option::is_none")]
-    pub log_driver: Option<Box<models::TaskSpecLogDriver>>,
+    pub log_driver: Option<Box<TaskSpecLogDriver>>,
 }
 impl TaskSpec {
     /// User modifiable task configuration.
@@ -7517,8 +7138,6 @@ impl TaskSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpec : Container spec for the service.  <p><br /></p>  > **Note**: ContainerSpec, NetworkAttachmentSpec, and PluginSpec are > mutually exclusive. PluginSpec is only used when the Runtime field > is set to `plugin`. NetworkAttachmentSpec is used when the Runtime > field is set to `attachment`.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpec {
@@ -7550,7 +7169,7 @@ pub struct TaskSpecContainerSpec {
     #[serde(rename = "Groups", skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<String>>,
     #[serde(rename = "Privileges", skip_serializing_if = "Option::is_none")]
-    pub privileges: Option<Box<models::TaskSpecContainerSpecPrivileges>>,
+    pub privileges: Option<Box<TaskSpecContainerSpecPrivileges>>,
     /// Whether a pseudo-TTY should be allocated.
     #[serde(rename = "TTY", skip_serializing_if = "Option::is_none")]
     pub tty: Option<bool>,
@@ -7562,7 +7181,7 @@ pub struct TaskSpecContainerSpec {
     pub read_only: Option<bool>,
     /// Specification for mounts to be added to containers created as part of the service.
     #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
-    pub mounts: Option<Vec<models::Mount>>,
+    pub mounts: Option<Vec<Mount>>,
     /// Signal to stop the container.
     #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none")]
     pub stop_signal: Option<String>,
@@ -7570,24 +7189,24 @@ pub struct TaskSpecContainerSpec {
     #[serde(rename = "StopGracePeriod", skip_serializing_if = "Option::is_none")]
     pub stop_grace_period: Option<i64>,
     #[serde(rename = "HealthCheck", skip_serializing_if = "Option::is_none")]
-    pub health_check: Option<Box<models::HealthConfig>>,
+    pub health_check: Option<Box<HealthConfig>>,
     /// A list of hostname/IP mappings to add to the container's `hosts` file. The format of extra hosts is specified in the [hosts(5)](http://man7.org/linux/man-pages/man5/hosts.5.html) man page:      IP_address canonical_hostname [aliases...]
     #[serde(rename = "Hosts", skip_serializing_if = "Option::is_none")]
     pub hosts: Option<Vec<String>>,
     #[serde(rename = "DNSConfig", skip_serializing_if = "Option::is_none")]
-    pub dns_config: Option<Box<models::TaskSpecContainerSpecDnsConfig>>,
+    pub dns_config: Option<Box<TaskSpecContainerSpecDnsConfig>>,
     /// Secrets contains references to zero or more secrets that will be exposed to the service.
     #[serde(rename = "Secrets", skip_serializing_if = "Option::is_none")]
-    pub secrets: Option<Vec<models::TaskSpecContainerSpecSecretsInner>>,
+    pub secrets: Option<Vec<TaskSpecContainerSpecSecretsInner>>,
     /// An integer value containing the score given to the container in order to tune OOM killer preferences.
     #[serde(rename = "OomScoreAdj", skip_serializing_if = "Option::is_none")]
     pub oom_score_adj: Option<i64>,
     /// Configs contains references to zero or more configs that will be exposed to the service.
     #[serde(rename = "Configs", skip_serializing_if = "Option::is_none")]
-    pub configs: Option<Vec<models::TaskSpecContainerSpecConfigsInner>>,
+    pub configs: Option<Vec<TaskSpecContainerSpecConfigsInner>>,
     /// Isolation technology of the containers running the service. (Windows only)
     #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none")]
-    pub isolation: Option<Isolation>,
+    pub isolation: Option<TaskSpecContainerSpecIsolation>,
     /// Run an init inside the container that forwards signals and reaps processes. This field is omitted if empty, and the default (as configured on the daemon) is used.
     #[serde(
         rename = "Init",
@@ -7607,7 +7226,7 @@ pub struct TaskSpecContainerSpec {
     pub capability_drop: Option<Vec<String>>,
     /// A list of resource limits to set in the container. For example: `{\"Name\": \"nofile\", \"Soft\": 1024, \"Hard\": 2048}`\"
     #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
-    pub ulimits: Option<Vec<models::ResourcesUlimitsInner>>,
+    pub ulimits: Option<Vec<ResourcesUlimitsInner>>,
 }
 impl TaskSpecContainerSpec {
     /// Container spec for the service.  <p><br /></p>  > **Note**: ContainerSpec, NetworkAttachmentSpec, and PluginSpec are > mutually exclusive. PluginSpec is only used when the Runtime field > is set to `plugin`. NetworkAttachmentSpec is used when the Runtime > field is set to `attachment`.
@@ -7657,7 +7276,7 @@ impl TaskSpecContainerSpec {
     Serialize,
     Deserialize
 )]
-pub enum Isolation {
+pub enum TaskSpecContainerSpecIsolation {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "process")]
@@ -7667,17 +7286,15 @@ pub enum Isolation {
     #[serde(rename = "")]
     Empty,
 }
-impl Default for Isolation {
-    fn default() -> Isolation {
+impl Default for TaskSpecContainerSpecIsolation {
+    fn default() -> TaskSpecContainerSpecIsolation {
         Self::Default
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecConfigsInner {
     #[serde(rename = "File", skip_serializing_if = "Option::is_none")]
-    pub file: Option<Box<models::TaskSpecContainerSpecConfigsInnerFile>>,
+    pub file: Option<Box<TaskSpecContainerSpecConfigsInnerFile>>,
     /// Runtime represents a target that is not mounted into the container but is used by the task  <p><br /><p>  > **Note**: `Configs.File` and `Configs.Runtime` are mutually > exclusive
     #[serde(rename = "Runtime", skip_serializing_if = "Option::is_none")]
     pub runtime: Option<serde_json::Value>,
@@ -7698,8 +7315,6 @@ impl TaskSpecContainerSpecConfigsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecConfigsInnerFile : File represents a specific target that is backed by a file.  <p><br /><p>  > **Note**: `Configs.File` and `Configs.Runtime` are mutually exclusive
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecConfigsInnerFile {
@@ -7727,8 +7342,6 @@ impl TaskSpecContainerSpecConfigsInnerFile {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecDnsConfig : Specification for DNS related configurations in resolver configuration file (`resolv.conf`).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecDnsConfig {
@@ -7752,23 +7365,17 @@ impl TaskSpecContainerSpecDnsConfig {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecPrivileges : Security options for the container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecPrivileges {
     #[serde(rename = "CredentialSpec", skip_serializing_if = "Option::is_none")]
-    pub credential_spec: Option<
-        Box<models::TaskSpecContainerSpecPrivilegesCredentialSpec>,
-    >,
+    pub credential_spec: Option<Box<TaskSpecContainerSpecPrivilegesCredentialSpec>>,
     #[serde(rename = "SELinuxContext", skip_serializing_if = "Option::is_none")]
-    pub se_linux_context: Option<
-        Box<models::TaskSpecContainerSpecPrivilegesSeLinuxContext>,
-    >,
+    pub se_linux_context: Option<Box<TaskSpecContainerSpecPrivilegesSeLinuxContext>>,
     #[serde(rename = "Seccomp", skip_serializing_if = "Option::is_none")]
-    pub seccomp: Option<Box<models::TaskSpecContainerSpecPrivilegesSeccomp>>,
+    pub seccomp: Option<Box<TaskSpecContainerSpecPrivilegesSeccomp>>,
     #[serde(rename = "AppArmor", skip_serializing_if = "Option::is_none")]
-    pub app_armor: Option<Box<models::TaskSpecContainerSpecPrivilegesAppArmor>>,
+    pub app_armor: Option<Box<TaskSpecContainerSpecPrivilegesAppArmor>>,
     /// Configuration of the no_new_privs bit in the container
     #[serde(rename = "NoNewPrivileges", skip_serializing_if = "Option::is_none")]
     pub no_new_privileges: Option<bool>,
@@ -7785,13 +7392,11 @@ impl TaskSpecContainerSpecPrivileges {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecPrivilegesAppArmor : Options for configuring AppArmor on the container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecPrivilegesAppArmor {
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Mode>,
+    pub mode: Option<TaskSpecContainerSpecPrivilegesAppArmorMode>,
 }
 impl TaskSpecContainerSpecPrivilegesAppArmor {
     /// Options for configuring AppArmor on the container
@@ -7814,19 +7419,17 @@ impl TaskSpecContainerSpecPrivilegesAppArmor {
     Serialize,
     Deserialize
 )]
-pub enum Mode {
+pub enum TaskSpecContainerSpecPrivilegesAppArmorMode {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "disabled")]
     Disabled,
 }
-impl Default for Mode {
-    fn default() -> Mode {
+impl Default for TaskSpecContainerSpecPrivilegesAppArmorMode {
+    fn default() -> TaskSpecContainerSpecPrivilegesAppArmorMode {
         Self::Default
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecPrivilegesCredentialSpec : CredentialSpec for managed service account (Windows only)
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecPrivilegesCredentialSpec {
@@ -7850,8 +7453,6 @@ impl TaskSpecContainerSpecPrivilegesCredentialSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecPrivilegesSeLinuxContext : SELinux labels of the container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecPrivilegesSeLinuxContext {
@@ -7883,13 +7484,11 @@ impl TaskSpecContainerSpecPrivilegesSeLinuxContext {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecPrivilegesSeccomp : Options for configuring seccomp on the container
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecPrivilegesSeccomp {
     #[serde(rename = "Mode", skip_serializing_if = "Option::is_none")]
-    pub mode: Option<Mode>,
+    pub mode: Option<TaskSpecContainerSpecPrivilegesSeccompMode>,
     /// The custom seccomp profile as a json object
     #[serde(rename = "Profile", skip_serializing_if = "Option::is_none")]
     pub profile: Option<String>,
@@ -7916,7 +7515,7 @@ impl TaskSpecContainerSpecPrivilegesSeccomp {
     Serialize,
     Deserialize
 )]
-pub enum Mode {
+pub enum TaskSpecContainerSpecPrivilegesSeccompMode {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "unconfined")]
@@ -7924,17 +7523,15 @@ pub enum Mode {
     #[serde(rename = "custom")]
     Custom,
 }
-impl Default for Mode {
-    fn default() -> Mode {
+impl Default for TaskSpecContainerSpecPrivilegesSeccompMode {
+    fn default() -> TaskSpecContainerSpecPrivilegesSeccompMode {
         Self::Default
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecSecretsInner {
     #[serde(rename = "File", skip_serializing_if = "Option::is_none")]
-    pub file: Option<Box<models::TaskSpecContainerSpecSecretsInnerFile>>,
+    pub file: Option<Box<TaskSpecContainerSpecSecretsInnerFile>>,
     /// SecretID represents the ID of the specific secret that we're referencing.
     #[serde(rename = "SecretID", skip_serializing_if = "Option::is_none")]
     pub secret_id: Option<String>,
@@ -7951,8 +7548,6 @@ impl TaskSpecContainerSpecSecretsInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecContainerSpecSecretsInnerFile : File represents a specific target that is backed by a file.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecContainerSpecSecretsInnerFile {
@@ -7980,8 +7575,6 @@ impl TaskSpecContainerSpecSecretsInnerFile {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecLogDriver : Specifies the log driver to use for tasks created from this spec. If not present, the default one for the swarm will be used, finally falling back to the engine default if not specified.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecLogDriver {
@@ -7999,8 +7592,6 @@ impl TaskSpecLogDriver {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecNetworkAttachmentSpec : Read-only spec type for non-swarm containers attached to swarm overlay networks.  <p><br /></p>  > **Note**: ContainerSpec, NetworkAttachmentSpec, and PluginSpec are > mutually exclusive. PluginSpec is only used when the Runtime field > is set to `plugin`. NetworkAttachmentSpec is used when the Runtime > field is set to `attachment`.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecNetworkAttachmentSpec {
@@ -8016,8 +7607,6 @@ impl TaskSpecNetworkAttachmentSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecPlacement {
     /// An array of constraint expressions to limit the set of nodes where a task can be scheduled. Constraint expressions can either use a _match_ (`==`) or _exclude_ (`!=`) rule. Multiple constraints find nodes that satisfy every expression (AND match). Constraints can match node or Docker Engine labels as follows:  node attribute       | matches                        | example ---------------------|--------------------------------|----------------------------------------------- `node.id`            | Node ID                        | `node.id==2ivku8v2gvtg4` `node.hostname`      | Node hostname                  | `node.hostname!=node-2` `node.role`          | Node role (`manager`/`worker`) | `node.role==manager` `node.platform.os`   | Node operating system          | `node.platform.os==windows` `node.platform.arch` | Node architecture              | `node.platform.arch==x86_64` `node.labels`        | User-defined node labels       | `node.labels.security==high` `engine.labels`      | Docker Engine's labels         | `engine.labels.operatingsystem==ubuntu-24.04`  `engine.labels` apply to Docker Engine labels like operating system, drivers, etc. Swarm administrators add `node.labels` for operational purposes by using the [`node update endpoint`](#operation/NodeUpdate).
@@ -8025,13 +7614,13 @@ pub struct TaskSpecPlacement {
     pub constraints: Option<Vec<String>>,
     /// Preferences provide a way to make the scheduler aware of factors such as topology. They are provided in order from highest to lowest precedence.
     #[serde(rename = "Preferences", skip_serializing_if = "Option::is_none")]
-    pub preferences: Option<Vec<models::TaskSpecPlacementPreferencesInner>>,
+    pub preferences: Option<Vec<TaskSpecPlacementPreferencesInner>>,
     /// Maximum number of replicas for per node (default value is 0, which is unlimited)
     #[serde(rename = "MaxReplicas", skip_serializing_if = "Option::is_none")]
     pub max_replicas: Option<i64>,
     /// Platforms stores all the platforms that the service's image can run on. This field is used in the platform filter for scheduling. If empty, then the platform filter is off, meaning there are no scheduling restrictions.
     #[serde(rename = "Platforms", skip_serializing_if = "Option::is_none")]
-    pub platforms: Option<Vec<models::Platform>>,
+    pub platforms: Option<Vec<Platform>>,
 }
 impl TaskSpecPlacement {
     pub fn new() -> TaskSpecPlacement {
@@ -8043,12 +7632,10 @@ impl TaskSpecPlacement {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecPlacementPreferencesInner {
     #[serde(rename = "Spread", skip_serializing_if = "Option::is_none")]
-    pub spread: Option<Box<models::TaskSpecPlacementPreferencesInnerSpread>>,
+    pub spread: Option<Box<TaskSpecPlacementPreferencesInnerSpread>>,
 }
 impl TaskSpecPlacementPreferencesInner {
     pub fn new() -> TaskSpecPlacementPreferencesInner {
@@ -8057,8 +7644,6 @@ impl TaskSpecPlacementPreferencesInner {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecPlacementPreferencesInnerSpread {
     /// label descriptor, such as `engine.labels.az`.
@@ -8072,8 +7657,6 @@ impl TaskSpecPlacementPreferencesInnerSpread {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecPluginSpec : Plugin spec for the service.  *(Experimental release only.)*  <p><br /></p>  > **Note**: ContainerSpec, NetworkAttachmentSpec, and PluginSpec are > mutually exclusive. PluginSpec is only used when the Runtime field > is set to `plugin`. NetworkAttachmentSpec is used when the Runtime > field is set to `attachment`.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecPluginSpec {
@@ -8087,7 +7670,7 @@ pub struct TaskSpecPluginSpec {
     #[serde(rename = "Disabled", skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
     #[serde(rename = "PluginPrivilege", skip_serializing_if = "Option::is_none")]
-    pub plugin_privilege: Option<Vec<models::PluginPrivilege>>,
+    pub plugin_privilege: Option<Vec<PluginPrivilege>>,
 }
 impl TaskSpecPluginSpec {
     /// Plugin spec for the service.  *(Experimental release only.)*  <p><br /></p>  > **Note**: ContainerSpec, NetworkAttachmentSpec, and PluginSpec are > mutually exclusive. PluginSpec is only used when the Runtime field > is set to `plugin`. NetworkAttachmentSpec is used when the Runtime > field is set to `attachment`.
@@ -8100,15 +7683,13 @@ impl TaskSpecPluginSpec {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecResources : Resource requirements which apply to each individual container created as part of the service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecResources {
     #[serde(rename = "Limits", skip_serializing_if = "Option::is_none")]
-    pub limits: Option<Box<models::Limit>>,
+    pub limits: Option<Box<Limit>>,
     #[serde(rename = "Reservations", skip_serializing_if = "Option::is_none")]
-    pub reservations: Option<Box<models::ResourceObject>>,
+    pub reservations: Option<Box<ResourceObject>>,
 }
 impl TaskSpecResources {
     /// Resource requirements which apply to each individual container created as part of the service.
@@ -8119,14 +7700,12 @@ impl TaskSpecResources {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskSpecRestartPolicy : Specification for the restart policy which applies to containers created as part of this service.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSpecRestartPolicy {
     /// Condition for restart.
     #[serde(rename = "Condition", skip_serializing_if = "Option::is_none")]
-    pub condition: Option<Condition>,
+    pub condition: Option<TaskSpecRestartPolicyCondition>,
     /// Delay between restart attempts.
     #[serde(rename = "Delay", skip_serializing_if = "Option::is_none")]
     pub delay: Option<i64>,
@@ -8161,7 +7740,7 @@ impl TaskSpecRestartPolicy {
     Serialize,
     Deserialize
 )]
-pub enum Condition {
+pub enum TaskSpecRestartPolicyCondition {
     #[serde(rename = "none")]
     None,
     #[serde(rename = "on-failure")]
@@ -8169,13 +7748,11 @@ pub enum Condition {
     #[serde(rename = "any")]
     Any,
 }
-impl Default for Condition {
-    fn default() -> Condition {
+impl Default for TaskSpecRestartPolicyCondition {
+    fn default() -> TaskSpecRestartPolicyCondition {
         Self::None
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 ///
 #[derive(
     Clone,
@@ -8247,23 +7824,21 @@ impl Default for TaskState {
         Self::New
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TaskStatus : represents the status of a task.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskStatus {
     #[serde(rename = "Timestamp", skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
     #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
-    pub state: Option<models::TaskState>,
+    pub state: Option<TaskState>,
     #[serde(rename = "Message", skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
     #[serde(rename = "Err", skip_serializing_if = "Option::is_none")]
     pub err: Option<String>,
     #[serde(rename = "ContainerStatus", skip_serializing_if = "Option::is_none")]
-    pub container_status: Option<Box<models::ContainerStatus>>,
+    pub container_status: Option<Box<ContainerStatus>>,
     #[serde(rename = "PortStatus", skip_serializing_if = "Option::is_none")]
-    pub port_status: Option<Box<models::PortStatus>>,
+    pub port_status: Option<Box<PortStatus>>,
 }
 impl TaskStatus {
     /// represents the status of a task.
@@ -8278,8 +7853,6 @@ impl TaskStatus {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ThrottleDevice {
     /// Device path
@@ -8297,8 +7870,6 @@ impl ThrottleDevice {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// TlsInfo : Information about the issuer of leaf TLS certificates and the trusted root CA certificate.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TlsInfo {
@@ -8322,8 +7893,6 @@ impl TlsInfo {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnlockKeyResponse {
     /// The swarm's unlock key.
@@ -8337,8 +7906,6 @@ impl UnlockKeyResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Volume {
     /// Name of the volume.
@@ -8361,9 +7928,9 @@ pub struct Volume {
     pub labels: std::collections::HashMap<String, String>,
     /// The level at which the volume exists. Either `global` for cluster-wide, or `local` for machine level.
     #[serde(rename = "Scope")]
-    pub scope: Scope,
+    pub scope: VolumeScope,
     #[serde(rename = "ClusterVolume", skip_serializing_if = "Option::is_none")]
-    pub cluster_volume: Option<Box<models::ClusterVolume>>,
+    pub cluster_volume: Option<Box<ClusterVolume>>,
     /// The driver specific options used when creating the volume.
     #[serde(rename = "Options")]
     pub options: std::collections::HashMap<String, String>,
@@ -8373,7 +7940,7 @@ pub struct Volume {
         with = "::serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    pub usage_data: Option<Option<Box<models::VolumeUsageData>>>,
+    pub usage_data: Option<Option<Box<VolumeUsageData>>>,
 }
 impl Volume {
     pub fn new(
@@ -8381,7 +7948,7 @@ impl Volume {
         driver: String,
         mountpoint: String,
         labels: std::collections::HashMap<String, String>,
-        scope: Scope,
+        scope: VolumeScope,
         options: std::collections::HashMap<String, String>,
     ) -> Volume {
         Volume {
@@ -8411,19 +7978,17 @@ impl Volume {
     Serialize,
     Deserialize
 )]
-pub enum Scope {
+pub enum VolumeScope {
     #[serde(rename = "local")]
     Local,
     #[serde(rename = "global")]
     Global,
 }
-impl Default for Scope {
-    fn default() -> Scope {
+impl Default for VolumeScope {
+    fn default() -> VolumeScope {
         Self::Local
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// VolumeCreateOptions : Volume configuration
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VolumeCreateOptions {
@@ -8440,7 +8005,7 @@ pub struct VolumeCreateOptions {
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "ClusterVolumeSpec", skip_serializing_if = "Option::is_none")]
-    pub cluster_volume_spec: Option<Box<models::ClusterVolumeSpec>>,
+    pub cluster_volume_spec: Option<Box<ClusterVolumeSpec>>,
 }
 impl VolumeCreateOptions {
     /// Volume configuration
@@ -8454,14 +8019,12 @@ impl VolumeCreateOptions {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// VolumeListResponse : Volume list response
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VolumeListResponse {
     /// List of volumes
     #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
-    pub volumes: Option<Vec<models::Volume>>,
+    pub volumes: Option<Vec<Volume>>,
     /// Warnings that occurred when fetching the list of volumes.
     #[serde(rename = "Warnings", skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
@@ -8475,8 +8038,6 @@ impl VolumeListResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VolumePruneResponse {
     /// Volumes that were deleted
@@ -8494,13 +8055,11 @@ impl VolumePruneResponse {
         }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// VolumeUpdateRequest : Volume configuration
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VolumeUpdateRequest {
     #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
-    pub spec: Option<Box<models::ClusterVolumeSpec>>,
+    pub spec: Option<Box<ClusterVolumeSpec>>,
 }
 impl VolumeUpdateRequest {
     /// Volume configuration
@@ -8508,8 +8067,6 @@ impl VolumeUpdateRequest {
         VolumeUpdateRequest { spec: None }
     }
 }
-use crate::models;
-use serde::{Deserialize, Serialize};
 /// VolumeUsageData : Usage details about the volume. This information is used by the `GET /system/df` endpoint, and omitted in other endpoints.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VolumeUsageData {
@@ -8526,435 +8083,3 @@ impl VolumeUsageData {
         VolumeUsageData { size, ref_count }
     }
 }
-pub mod address;
-pub use self::address::Address;
-pub mod auth_config;
-pub use self::auth_config::AuthConfig;
-pub mod build_cache;
-pub use self::build_cache::BuildCache;
-pub mod build_info;
-pub use self::build_info::BuildInfo;
-pub mod build_prune_response;
-pub use self::build_prune_response::BuildPruneResponse;
-pub mod change_type;
-pub use self::change_type::ChangeType;
-pub mod cluster_info;
-pub use self::cluster_info::ClusterInfo;
-pub mod cluster_volume;
-pub use self::cluster_volume::ClusterVolume;
-pub mod cluster_volume_info;
-pub use self::cluster_volume_info::ClusterVolumeInfo;
-pub mod cluster_volume_publish_status_inner;
-pub use self::cluster_volume_publish_status_inner::ClusterVolumePublishStatusInner;
-pub mod cluster_volume_spec;
-pub use self::cluster_volume_spec::ClusterVolumeSpec;
-pub mod cluster_volume_spec_access_mode;
-pub use self::cluster_volume_spec_access_mode::ClusterVolumeSpecAccessMode;
-pub mod cluster_volume_spec_access_mode_accessibility_requirements;
-pub use self::cluster_volume_spec_access_mode_accessibility_requirements::ClusterVolumeSpecAccessModeAccessibilityRequirements;
-pub mod cluster_volume_spec_access_mode_capacity_range;
-pub use self::cluster_volume_spec_access_mode_capacity_range::ClusterVolumeSpecAccessModeCapacityRange;
-pub mod cluster_volume_spec_access_mode_secrets_inner;
-pub use self::cluster_volume_spec_access_mode_secrets_inner::ClusterVolumeSpecAccessModeSecretsInner;
-pub mod commit;
-pub use self::commit::Commit;
-pub mod config;
-pub use self::config::Config;
-pub mod config_create_request;
-pub use self::config_create_request::ConfigCreateRequest;
-pub mod config_reference;
-pub use self::config_reference::ConfigReference;
-pub mod config_spec;
-pub use self::config_spec::ConfigSpec;
-pub mod container_config;
-pub use self::container_config::ContainerConfig;
-pub mod container_create_request;
-pub use self::container_create_request::ContainerCreateRequest;
-pub mod container_create_response;
-pub use self::container_create_response::ContainerCreateResponse;
-pub mod container_inspect_response;
-pub use self::container_inspect_response::ContainerInspectResponse;
-pub mod container_prune_response;
-pub use self::container_prune_response::ContainerPruneResponse;
-pub mod container_state;
-pub use self::container_state::ContainerState;
-pub mod container_status;
-pub use self::container_status::ContainerStatus;
-pub mod container_summary;
-pub use self::container_summary::ContainerSummary;
-pub mod container_summary_host_config;
-pub use self::container_summary_host_config::ContainerSummaryHostConfig;
-pub mod container_summary_network_settings;
-pub use self::container_summary_network_settings::ContainerSummaryNetworkSettings;
-pub mod container_top_response;
-pub use self::container_top_response::ContainerTopResponse;
-pub mod container_update_request;
-pub use self::container_update_request::ContainerUpdateRequest;
-pub mod container_update_response;
-pub use self::container_update_response::ContainerUpdateResponse;
-pub mod container_wait_exit_error;
-pub use self::container_wait_exit_error::ContainerWaitExitError;
-pub mod container_wait_response;
-pub use self::container_wait_response::ContainerWaitResponse;
-pub mod containerd_info;
-pub use self::containerd_info::ContainerdInfo;
-pub mod containerd_info_namespaces;
-pub use self::containerd_info_namespaces::ContainerdInfoNamespaces;
-pub mod create_image_info;
-pub use self::create_image_info::CreateImageInfo;
-pub mod device_mapping;
-pub use self::device_mapping::DeviceMapping;
-pub mod device_request;
-pub use self::device_request::DeviceRequest;
-pub mod distribution_inspect;
-pub use self::distribution_inspect::DistributionInspect;
-pub mod driver;
-pub use self::driver::Driver;
-pub mod driver_data;
-pub use self::driver_data::DriverData;
-pub mod endpoint_ipam_config;
-pub use self::endpoint_ipam_config::EndpointIpamConfig;
-pub mod endpoint_port_config;
-pub use self::endpoint_port_config::EndpointPortConfig;
-pub mod endpoint_settings;
-pub use self::endpoint_settings::EndpointSettings;
-pub mod endpoint_spec;
-pub use self::endpoint_spec::EndpointSpec;
-pub mod engine_description;
-pub use self::engine_description::EngineDescription;
-pub mod engine_description_plugins_inner;
-pub use self::engine_description_plugins_inner::EngineDescriptionPluginsInner;
-pub mod error_detail;
-pub use self::error_detail::ErrorDetail;
-pub mod error_response;
-pub use self::error_response::ErrorResponse;
-pub mod event_actor;
-pub use self::event_actor::EventActor;
-pub mod event_message;
-pub use self::event_message::EventMessage;
-pub mod exec_config;
-pub use self::exec_config::ExecConfig;
-pub mod exec_inspect_response;
-pub use self::exec_inspect_response::ExecInspectResponse;
-pub mod exec_start_config;
-pub use self::exec_start_config::ExecStartConfig;
-pub mod filesystem_change;
-pub use self::filesystem_change::FilesystemChange;
-pub mod generic_resources_inner;
-pub use self::generic_resources_inner::GenericResourcesInner;
-pub mod generic_resources_inner_discrete_resource_spec;
-pub use self::generic_resources_inner_discrete_resource_spec::GenericResourcesInnerDiscreteResourceSpec;
-pub mod generic_resources_inner_named_resource_spec;
-pub use self::generic_resources_inner_named_resource_spec::GenericResourcesInnerNamedResourceSpec;
-pub mod health;
-pub use self::health::Health;
-pub mod health_config;
-pub use self::health_config::HealthConfig;
-pub mod healthcheck_result;
-pub use self::healthcheck_result::HealthcheckResult;
-pub mod history_response_item;
-pub use self::history_response_item::HistoryResponseItem;
-pub mod host_config;
-pub use self::host_config::HostConfig;
-pub mod host_config_all_of_log_config;
-pub use self::host_config_all_of_log_config::HostConfigAllOfLogConfig;
-pub mod id_response;
-pub use self::id_response::IdResponse;
-pub mod image_config;
-pub use self::image_config::ImageConfig;
-pub mod image_delete_response_item;
-pub use self::image_delete_response_item::ImageDeleteResponseItem;
-pub mod image_id;
-pub use self::image_id::ImageId;
-pub mod image_inspect;
-pub use self::image_inspect::ImageInspect;
-pub mod image_inspect_metadata;
-pub use self::image_inspect_metadata::ImageInspectMetadata;
-pub mod image_inspect_root_fs;
-pub use self::image_inspect_root_fs::ImageInspectRootFs;
-pub mod image_manifest_summary;
-pub use self::image_manifest_summary::ImageManifestSummary;
-pub mod image_manifest_summary_attestation_data;
-pub use self::image_manifest_summary_attestation_data::ImageManifestSummaryAttestationData;
-pub mod image_manifest_summary_image_data;
-pub use self::image_manifest_summary_image_data::ImageManifestSummaryImageData;
-pub mod image_manifest_summary_image_data_size;
-pub use self::image_manifest_summary_image_data_size::ImageManifestSummaryImageDataSize;
-pub mod image_manifest_summary_size;
-pub use self::image_manifest_summary_size::ImageManifestSummarySize;
-pub mod image_prune_response;
-pub use self::image_prune_response::ImagePruneResponse;
-pub mod image_search_response_item;
-pub use self::image_search_response_item::ImageSearchResponseItem;
-pub mod image_summary;
-pub use self::image_summary::ImageSummary;
-pub mod index_info;
-pub use self::index_info::IndexInfo;
-pub mod ipam;
-pub use self::ipam::Ipam;
-pub mod ipam_config;
-pub use self::ipam_config::IpamConfig;
-pub mod join_tokens;
-pub use self::join_tokens::JoinTokens;
-pub mod limit;
-pub use self::limit::Limit;
-pub mod local_node_state;
-pub use self::local_node_state::LocalNodeState;
-pub mod manager_status;
-pub use self::manager_status::ManagerStatus;
-pub mod mount;
-pub use self::mount::Mount;
-pub mod mount_bind_options;
-pub use self::mount_bind_options::MountBindOptions;
-pub mod mount_point;
-pub use self::mount_point::MountPoint;
-pub mod mount_tmpfs_options;
-pub use self::mount_tmpfs_options::MountTmpfsOptions;
-pub mod mount_volume_options;
-pub use self::mount_volume_options::MountVolumeOptions;
-pub mod mount_volume_options_driver_config;
-pub use self::mount_volume_options_driver_config::MountVolumeOptionsDriverConfig;
-pub mod network;
-pub use self::network::Network;
-pub mod network_attachment_config;
-pub use self::network_attachment_config::NetworkAttachmentConfig;
-pub mod network_connect_request;
-pub use self::network_connect_request::NetworkConnectRequest;
-pub mod network_container;
-pub use self::network_container::NetworkContainer;
-pub mod network_create_request;
-pub use self::network_create_request::NetworkCreateRequest;
-pub mod network_create_response;
-pub use self::network_create_response::NetworkCreateResponse;
-pub mod network_disconnect_request;
-pub use self::network_disconnect_request::NetworkDisconnectRequest;
-pub mod network_prune_response;
-pub use self::network_prune_response::NetworkPruneResponse;
-pub mod network_settings;
-pub use self::network_settings::NetworkSettings;
-pub mod networking_config;
-pub use self::networking_config::NetworkingConfig;
-pub mod node;
-pub use self::node::Node;
-pub mod node_description;
-pub use self::node_description::NodeDescription;
-pub mod node_spec;
-pub use self::node_spec::NodeSpec;
-pub mod node_state;
-pub use self::node_state::NodeState;
-pub mod node_status;
-pub use self::node_status::NodeStatus;
-pub mod object_version;
-pub use self::object_version::ObjectVersion;
-pub mod oci_descriptor;
-pub use self::oci_descriptor::OciDescriptor;
-pub mod oci_platform;
-pub use self::oci_platform::OciPlatform;
-pub mod peer_info;
-pub use self::peer_info::PeerInfo;
-pub mod peer_node;
-pub use self::peer_node::PeerNode;
-pub mod platform;
-pub use self::platform::Platform;
-pub mod plugin;
-pub use self::plugin::Plugin;
-pub mod plugin_config;
-pub use self::plugin_config::PluginConfig;
-pub mod plugin_config_args;
-pub use self::plugin_config_args::PluginConfigArgs;
-pub mod plugin_config_interface;
-pub use self::plugin_config_interface::PluginConfigInterface;
-pub mod plugin_config_linux;
-pub use self::plugin_config_linux::PluginConfigLinux;
-pub mod plugin_config_network;
-pub use self::plugin_config_network::PluginConfigNetwork;
-pub mod plugin_config_rootfs;
-pub use self::plugin_config_rootfs::PluginConfigRootfs;
-pub mod plugin_config_user;
-pub use self::plugin_config_user::PluginConfigUser;
-pub mod plugin_device;
-pub use self::plugin_device::PluginDevice;
-pub mod plugin_env;
-pub use self::plugin_env::PluginEnv;
-pub mod plugin_interface_type;
-pub use self::plugin_interface_type::PluginInterfaceType;
-pub mod plugin_mount;
-pub use self::plugin_mount::PluginMount;
-pub mod plugin_privilege;
-pub use self::plugin_privilege::PluginPrivilege;
-pub mod plugin_settings;
-pub use self::plugin_settings::PluginSettings;
-pub mod plugins_info;
-pub use self::plugins_info::PluginsInfo;
-pub mod port;
-pub use self::port::Port;
-pub mod port_binding;
-pub use self::port_binding::PortBinding;
-pub mod port_status;
-pub use self::port_status::PortStatus;
-pub mod process_config;
-pub use self::process_config::ProcessConfig;
-pub mod progress_detail;
-pub use self::progress_detail::ProgressDetail;
-pub mod push_image_info;
-pub use self::push_image_info::PushImageInfo;
-pub mod reachability;
-pub use self::reachability::Reachability;
-pub mod registry_service_config;
-pub use self::registry_service_config::RegistryServiceConfig;
-pub mod resource_object;
-pub use self::resource_object::ResourceObject;
-pub mod resources;
-pub use self::resources::Resources;
-pub mod resources_blkio_weight_device_inner;
-pub use self::resources_blkio_weight_device_inner::ResourcesBlkioWeightDeviceInner;
-pub mod resources_ulimits_inner;
-pub use self::resources_ulimits_inner::ResourcesUlimitsInner;
-pub mod restart_policy;
-pub use self::restart_policy::RestartPolicy;
-pub mod runtime;
-pub use self::runtime::Runtime;
-pub mod secret;
-pub use self::secret::Secret;
-pub mod secret_create_request;
-pub use self::secret_create_request::SecretCreateRequest;
-pub mod secret_spec;
-pub use self::secret_spec::SecretSpec;
-pub mod service;
-pub use self::service::Service;
-pub mod service_create_request;
-pub use self::service_create_request::ServiceCreateRequest;
-pub mod service_create_response;
-pub use self::service_create_response::ServiceCreateResponse;
-pub mod service_endpoint;
-pub use self::service_endpoint::ServiceEndpoint;
-pub mod service_endpoint_virtual_ips_inner;
-pub use self::service_endpoint_virtual_ips_inner::ServiceEndpointVirtualIpsInner;
-pub mod service_job_status;
-pub use self::service_job_status::ServiceJobStatus;
-pub mod service_service_status;
-pub use self::service_service_status::ServiceServiceStatus;
-pub mod service_spec;
-pub use self::service_spec::ServiceSpec;
-pub mod service_spec_mode;
-pub use self::service_spec_mode::ServiceSpecMode;
-pub mod service_spec_mode_replicated;
-pub use self::service_spec_mode_replicated::ServiceSpecModeReplicated;
-pub mod service_spec_mode_replicated_job;
-pub use self::service_spec_mode_replicated_job::ServiceSpecModeReplicatedJob;
-pub mod service_spec_rollback_config;
-pub use self::service_spec_rollback_config::ServiceSpecRollbackConfig;
-pub mod service_spec_update_config;
-pub use self::service_spec_update_config::ServiceSpecUpdateConfig;
-pub mod service_update_request;
-pub use self::service_update_request::ServiceUpdateRequest;
-pub mod service_update_response;
-pub use self::service_update_response::ServiceUpdateResponse;
-pub mod service_update_status;
-pub use self::service_update_status::ServiceUpdateStatus;
-pub mod swarm;
-pub use self::swarm::Swarm;
-pub mod swarm_info;
-pub use self::swarm_info::SwarmInfo;
-pub mod swarm_init_request;
-pub use self::swarm_init_request::SwarmInitRequest;
-pub mod swarm_join_request;
-pub use self::swarm_join_request::SwarmJoinRequest;
-pub mod swarm_spec;
-pub use self::swarm_spec::SwarmSpec;
-pub mod swarm_spec_ca_config;
-pub use self::swarm_spec_ca_config::SwarmSpecCaConfig;
-pub mod swarm_spec_ca_config_external_cas_inner;
-pub use self::swarm_spec_ca_config_external_cas_inner::SwarmSpecCaConfigExternalCasInner;
-pub mod swarm_spec_dispatcher;
-pub use self::swarm_spec_dispatcher::SwarmSpecDispatcher;
-pub mod swarm_spec_encryption_config;
-pub use self::swarm_spec_encryption_config::SwarmSpecEncryptionConfig;
-pub mod swarm_spec_orchestration;
-pub use self::swarm_spec_orchestration::SwarmSpecOrchestration;
-pub mod swarm_spec_raft;
-pub use self::swarm_spec_raft::SwarmSpecRaft;
-pub mod swarm_spec_task_defaults;
-pub use self::swarm_spec_task_defaults::SwarmSpecTaskDefaults;
-pub mod swarm_spec_task_defaults_log_driver;
-pub use self::swarm_spec_task_defaults_log_driver::SwarmSpecTaskDefaultsLogDriver;
-pub mod swarm_unlock_request;
-pub use self::swarm_unlock_request::SwarmUnlockRequest;
-pub mod system_auth_response;
-pub use self::system_auth_response::SystemAuthResponse;
-pub mod system_data_usage_response;
-pub use self::system_data_usage_response::SystemDataUsageResponse;
-pub mod system_info;
-pub use self::system_info::SystemInfo;
-pub mod system_info_default_address_pools_inner;
-pub use self::system_info_default_address_pools_inner::SystemInfoDefaultAddressPoolsInner;
-pub mod system_version;
-pub use self::system_version::SystemVersion;
-pub mod system_version_components_inner;
-pub use self::system_version_components_inner::SystemVersionComponentsInner;
-pub mod system_version_platform;
-pub use self::system_version_platform::SystemVersionPlatform;
-pub mod task;
-pub use self::task::Task;
-pub mod task_spec;
-pub use self::task_spec::TaskSpec;
-pub mod task_spec_container_spec;
-pub use self::task_spec_container_spec::TaskSpecContainerSpec;
-pub mod task_spec_container_spec_configs_inner;
-pub use self::task_spec_container_spec_configs_inner::TaskSpecContainerSpecConfigsInner;
-pub mod task_spec_container_spec_configs_inner_file;
-pub use self::task_spec_container_spec_configs_inner_file::TaskSpecContainerSpecConfigsInnerFile;
-pub mod task_spec_container_spec_dns_config;
-pub use self::task_spec_container_spec_dns_config::TaskSpecContainerSpecDnsConfig;
-pub mod task_spec_container_spec_privileges;
-pub use self::task_spec_container_spec_privileges::TaskSpecContainerSpecPrivileges;
-pub mod task_spec_container_spec_privileges_app_armor;
-pub use self::task_spec_container_spec_privileges_app_armor::TaskSpecContainerSpecPrivilegesAppArmor;
-pub mod task_spec_container_spec_privileges_credential_spec;
-pub use self::task_spec_container_spec_privileges_credential_spec::TaskSpecContainerSpecPrivilegesCredentialSpec;
-pub mod task_spec_container_spec_privileges_se_linux_context;
-pub use self::task_spec_container_spec_privileges_se_linux_context::TaskSpecContainerSpecPrivilegesSeLinuxContext;
-pub mod task_spec_container_spec_privileges_seccomp;
-pub use self::task_spec_container_spec_privileges_seccomp::TaskSpecContainerSpecPrivilegesSeccomp;
-pub mod task_spec_container_spec_secrets_inner;
-pub use self::task_spec_container_spec_secrets_inner::TaskSpecContainerSpecSecretsInner;
-pub mod task_spec_container_spec_secrets_inner_file;
-pub use self::task_spec_container_spec_secrets_inner_file::TaskSpecContainerSpecSecretsInnerFile;
-pub mod task_spec_log_driver;
-pub use self::task_spec_log_driver::TaskSpecLogDriver;
-pub mod task_spec_network_attachment_spec;
-pub use self::task_spec_network_attachment_spec::TaskSpecNetworkAttachmentSpec;
-pub mod task_spec_placement;
-pub use self::task_spec_placement::TaskSpecPlacement;
-pub mod task_spec_placement_preferences_inner;
-pub use self::task_spec_placement_preferences_inner::TaskSpecPlacementPreferencesInner;
-pub mod task_spec_placement_preferences_inner_spread;
-pub use self::task_spec_placement_preferences_inner_spread::TaskSpecPlacementPreferencesInnerSpread;
-pub mod task_spec_plugin_spec;
-pub use self::task_spec_plugin_spec::TaskSpecPluginSpec;
-pub mod task_spec_resources;
-pub use self::task_spec_resources::TaskSpecResources;
-pub mod task_spec_restart_policy;
-pub use self::task_spec_restart_policy::TaskSpecRestartPolicy;
-pub mod task_state;
-pub use self::task_state::TaskState;
-pub mod task_status;
-pub use self::task_status::TaskStatus;
-pub mod throttle_device;
-pub use self::throttle_device::ThrottleDevice;
-pub mod tls_info;
-pub use self::tls_info::TlsInfo;
-pub mod unlock_key_response;
-pub use self::unlock_key_response::UnlockKeyResponse;
-pub mod volume;
-pub use self::volume::Volume;
-pub mod volume_create_options;
-pub use self::volume_create_options::VolumeCreateOptions;
-pub mod volume_list_response;
-pub use self::volume_list_response::VolumeListResponse;
-pub mod volume_prune_response;
-pub use self::volume_prune_response::VolumePruneResponse;
-pub mod volume_update_request;
-pub use self::volume_update_request::VolumeUpdateRequest;
-pub mod volume_usage_data;
-pub use self::volume_usage_data::VolumeUsageData;
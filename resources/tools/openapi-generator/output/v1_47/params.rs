@@ -0,0 +1,911 @@
+use crate::models;
+use bytes::Bytes;
+/// struct for passing parameters to the method [`build_prune`]
+#[derive(Clone, Debug)]
+pub struct BuildPruneParams {
+    /// Amount of disk space in bytes to keep for cache
+    pub keep_storage: Option<i64>,
+    /// Remove all types of build cache
+    pub all: Option<bool>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the list of build cache objects.  Available filters:  - `until=<timestamp>` remove cache older than `<timestamp>`. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon's local time. - `id=<id>` - `parent=<id>` - `type=<string>` - `description=<string>` - `inuse` - `shared` - `private`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`config_create`]
+#[derive(Clone, Debug)]
+pub struct ConfigCreateParams {
+    pub body: Option<models::ConfigCreateRequest>,
+}
+/// struct for passing parameters to the method [`config_delete`]
+#[derive(Clone, Debug)]
+pub struct ConfigDeleteParams {
+    /// ID of the config
+    pub id: String,
+}
+/// struct for passing parameters to the method [`config_inspect`]
+#[derive(Clone, Debug)]
+pub struct ConfigInspectParams {
+    /// ID of the config
+    pub id: String,
+}
+/// struct for passing parameters to the method [`config_list`]
+#[derive(Clone, Debug)]
+pub struct ConfigListParams {
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the configs list.  Available filters:  - `id=<config id>` - `label=<key> or label=<key>=value` - `name=<config name>` - `names=<config name>`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`config_update`]
+#[derive(Clone, Debug)]
+pub struct ConfigUpdateParams {
+    /// The ID or name of the config
+    pub id: String,
+    /// The version number of the config object being updated. This is required to avoid conflicting writes.
+    pub version: i64,
+    /// The spec of the config to update. Currently, only the Labels field can be updated. All other fields must remain unchanged from the [ConfigInspect endpoint](#operation/ConfigInspect) response values.
+    pub body: Option<models::ConfigSpec>,
+}
+/// struct for passing parameters to the method [`container_archive_info`]
+#[derive(Clone, Debug)]
+pub struct ContainerArchiveInfoParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Resource in the container’s filesystem to archive.
+    pub path: String,
+}
+/// struct for passing parameters to the method [`container_archive`]
+#[derive(Clone, Debug)]
+pub struct ContainerArchiveParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Resource in the container’s filesystem to archive.
+    pub path: String,
+}
+/// struct for passing parameters to the method [`container_attach`]
+#[derive(Clone, Debug)]
+pub struct ContainerAttachParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Override the key sequence for detaching a container.Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    pub detach_keys: Option<String>,
+    /// Replay previous logs from the container.  This is useful for attaching to a container that has started and you want to output everything since the container started.  If `stream` is also enabled, once all the previous output has been returned, it will seamlessly transition into streaming current output.
+    pub logs: Option<bool>,
+    /// Stream attached streams from the time the request was made onwards.
+    pub stream: Option<bool>,
+    /// Attach to `stdin`
+    pub stdin: Option<bool>,
+    /// Attach to `stdout`
+    pub stdout: Option<bool>,
+    /// Attach to `stderr`
+    pub stderr: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_attach_websocket`]
+#[derive(Clone, Debug)]
+pub struct ContainerAttachWebsocketParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Override the key sequence for detaching a container.Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,`, or `_`.
+    pub detach_keys: Option<String>,
+    /// Return logs
+    pub logs: Option<bool>,
+    /// Return stream
+    pub stream: Option<bool>,
+    /// Attach to `stdin`
+    pub stdin: Option<bool>,
+    /// Attach to `stdout`
+    pub stdout: Option<bool>,
+    /// Attach to `stderr`
+    pub stderr: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_changes`]
+#[derive(Clone, Debug)]
+pub struct ContainerChangesParams {
+    /// ID or name of the container
+    pub id: String,
+}
+/// struct for passing parameters to the method [`container_create`]
+#[derive(Clone, Debug)]
+pub struct ContainerCreateParams {
+    /// Container to create
+    pub body: models::ContainerCreateRequest,
+    /// Assign the specified name to the container. Must match `/?[a-zA-Z0-9][a-zA-Z0-9_.-]+`.
+    pub name: Option<String>,
+    /// Platform in the format `os[/arch[/variant]]` used for image lookup.  When specified, the daemon checks if the requested image is present in the local image cache with the given OS and Architecture, and otherwise returns a `404` status.  If the option is not set, the host's native OS and Architecture are used to look up the image in the image cache. However, if no platform is passed and the given image does exist in the local image cache, but its OS or architecture does not match, the container is created with the available image, and a warning is added to the `Warnings` field in the response, for example;      WARNING: The requested image's platform (linux/arm64/v8) does not              match the detected host platform (linux/amd64) and no              specific platform was requested
+    pub platform: Option<String>,
+}
+/// struct for passing parameters to the method [`container_delete`]
+#[derive(Clone, Debug)]
+pub struct ContainerDeleteParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Remove anonymous volumes associated with the container.
+    pub v: Option<bool>,
+    /// If the container is running, kill it before removing it.
+    pub force: Option<bool>,
+    /// Remove the specified link associated with the container.
+    pub link: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_exec`]
+#[derive(Clone, Debug)]
+pub struct ContainerExecParams {
+    /// ID or name of container
+    pub id: String,
+    /// Exec configuration
+    pub exec_config: models::ExecConfig,
+}
+/// struct for passing parameters to the method [`container_export`]
+#[derive(Clone, Debug)]
+pub struct ContainerExportParams {
+    /// ID or name of the container
+    pub id: String,
+}
+/// struct for passing parameters to the method [`container_inspect`]
+#[derive(Clone, Debug)]
+pub struct ContainerInspectParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Return the size of container as fields `SizeRw` and `SizeRootFs`
+    pub size: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_kill`]
+#[derive(Clone, Debug)]
+pub struct ContainerKillParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub signal: Option<String>,
+}
+/// struct for passing parameters to the method [`container_list`]
+#[derive(Clone, Debug)]
+pub struct ContainerListParams {
+    /// Return all containers. By default, only running containers are shown.
+    pub all: Option<bool>,
+    /// Return this number of most recently created containers, including non-running ones.
+    pub limit: Option<i32>,
+    /// Return the size of container as fields `SizeRw` and `SizeRootFs`.
+    pub size: Option<bool>,
+    /// Filters to process on the container list, encoded as JSON (a `map[string][]string`). For example, `{\"status\": [\"paused\"]}` will only return paused containers.  Available filters:  - `ancestor`=(`<image-name>[:<tag>]`, `<image id>`, or `<image@digest>`) - `before`=(`<container id>` or `<container name>`) - `expose`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`) - `exited=<int>` containers with exit code of `<int>` - `health`=(`starting`|`healthy`|`unhealthy`|`none`) - `id=<ID>` a container's ID - `isolation=`(`default`|`process`|`hyperv`) (Windows daemon only) - `is-task=`(`true`|`false`) - `label=key` or `label=\"key=value\"` of a container label - `name=<name>` a container's name - `network`=(`<network id>` or `<network name>`) - `publish`=(`<port>[/<proto>]`|`<startport-endport>/[<proto>]`) - `since`=(`<container id>` or `<container name>`) - `status=`(`created`|`restarting`|`running`|`removing`|`paused`|`exited`|`dead`) - `volume`=(`<volume name>` or `<mount point destination>`)
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`container_logs`]
+#[derive(Clone, Debug)]
+pub struct ContainerLogsParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Keep connection after returning logs.
+    pub follow: Option<bool>,
+    /// Return logs from `stdout`
+    pub stdout: Option<bool>,
+    /// Return logs from `stderr`
+    pub stderr: Option<bool>,
+    /// Only return logs since this time, as a UNIX timestamp
+    pub since: Option<i32>,
+    /// Only return logs before this time, as a UNIX timestamp
+    pub until: Option<i32>,
+    /// Add timestamps to every log line
+    pub timestamps: Option<bool>,
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub tail: Option<String>,
+}
+/// struct for passing parameters to the method [`container_pause`]
+#[derive(Clone, Debug)]
+pub struct ContainerPauseParams {
+    /// ID or name of the container
+    pub id: String,
+}
+/// struct for passing parameters to the method [`container_prune`]
+#[derive(Clone, Debug)]
+pub struct ContainerPruneParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `until=<timestamp>` Prune containers created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune containers with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`container_rename`]
+#[derive(Clone, Debug)]
+pub struct ContainerRenameParams {
+    /// ID or name of the container
+    pub id: String,
+    /// New name for the container
+    pub name: String,
+}
+/// struct for passing parameters to the method [`container_resize`]
+#[derive(Clone, Debug)]
+pub struct ContainerResizeParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Height of the TTY session in characters
+    pub h: i32,
+    /// Width of the TTY session in characters
+    pub w: i32,
+}
+/// struct for passing parameters to the method [`container_restart`]
+#[derive(Clone, Debug)]
+pub struct ContainerRestartParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub signal: Option<String>,
+    /// Number of seconds to wait before killing the container
+    pub t: Option<i32>,
+}
+/// struct for passing parameters to the method [`container_start`]
+#[derive(Clone, Debug)]
+pub struct ContainerStartParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Override the key sequence for detaching a container. Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>` is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    pub detach_keys: Option<String>,
+}
+/// struct for passing parameters to the method [`container_stats`]
+#[derive(Clone, Debug)]
+pub struct ContainerStatsParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Stream the output. If false, the stats will be output once and then it will disconnect.
+    pub stream: Option<bool>,
+    /// Only get a single stat instead of waiting for 2 cycles. Must be used with `stream=false`.
+    pub one_shot: Option<bool>,
+}
+/// struct for passing parameters to the method [`container_stop`]
+#[derive(Clone, Debug)]
+pub struct ContainerStopParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Signal to send to the container as an integer or string (e.g. `SIGINT`).
+    pub signal: Option<String>,
+    /// Number of seconds to wait before killing the container
+    pub t: Option<i32>,
+}
+/// struct for passing parameters to the method [`container_top`]
+#[derive(Clone, Debug)]
+pub struct ContainerTopParams {
+    /// ID or name of the container
+    pub id: String,
+    /// The arguments to pass to `ps`. For example, `aux`
+    pub ps_args: Option<String>,
+}
+/// struct for passing parameters to the method [`container_unpause`]
+#[derive(Clone, Debug)]
+pub struct ContainerUnpauseParams {
+    /// ID or name of the container
+    pub id: String,
+}
+/// struct for passing parameters to the method [`container_update`]
+#[derive(Clone, Debug)]
+pub struct ContainerUpdateParams {
+    /// ID or name of the container
+    pub id: String,
+    pub update: models::ContainerUpdateRequest,
+}
+/// struct for passing parameters to the method [`container_wait`]
+#[derive(Clone, Debug)]
+pub struct ContainerWaitParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Wait until a container state reaches the given condition.  Defaults to `not-running` if omitted or empty.
+    pub condition: Option<String>,
+}
+/// struct for passing parameters to the method [`distribution_inspect`]
+#[derive(Clone, Debug)]
+pub struct DistributionInspectParams {
+    /// Image name or id
+    pub name: String,
+}
+/// struct for passing parameters to the method [`exec_inspect`]
+#[derive(Clone, Debug)]
+pub struct ExecInspectParams {
+    /// Exec instance ID
+    pub id: String,
+}
+/// struct for passing parameters to the method [`exec_resize`]
+#[derive(Clone, Debug)]
+pub struct ExecResizeParams {
+    /// Exec instance ID
+    pub id: String,
+    /// Height of the TTY session in characters
+    pub h: i32,
+    /// Width of the TTY session in characters
+    pub w: i32,
+}
+/// struct for passing parameters to the method [`exec_start`]
+#[derive(Clone, Debug)]
+pub struct ExecStartParams {
+    /// Exec instance ID
+    pub id: String,
+    pub exec_start_config: Option<models::ExecStartConfig>,
+}
+/// struct for passing parameters to the method [`get_plugin_privileges`]
+#[derive(Clone, Debug)]
+pub struct GetPluginPrivilegesParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub remote: String,
+}
+/// struct for passing parameters to the method [`image_build`]
+#[derive(Clone, Debug)]
+pub struct ImageBuildParams {
+    /// Path within the build context to the `Dockerfile`. This is ignored if `remote` is specified and points to an external `Dockerfile`.
+    pub dockerfile: Option<String>,
+    /// A name and optional tag to apply to the image in the `name:tag` format. If you omit the tag the default `latest` value is assumed. You can provide several `t` parameters.
+    pub t: Option<String>,
+    /// Extra hosts to add to /etc/hosts
+    pub extrahosts: Option<String>,
+    /// A Git repository URI or HTTP/HTTPS context URI. If the URI points to a single text file, the file’s contents are placed into a file called `Dockerfile` and the image is built from that file. If the URI points to a tarball, the file is downloaded by the daemon and the contents therein used as the context for the build. If the URI points to a tarball and the `dockerfile` parameter is also specified, there must be a file with the corresponding path inside the tarball.
+    pub remote: Option<String>,
+    /// Suppress verbose build output.
+    pub q: Option<bool>,
+    /// Do not use the cache when building the image.
+    pub nocache: Option<bool>,
+    /// JSON array of images used for build cache resolution.
+    pub cachefrom: Option<String>,
+    /// Attempt to pull the image even if an older image exists locally.
+    pub pull: Option<String>,
+    /// Remove intermediate containers after a successful build.
+    pub rm: Option<bool>,
+    /// Always remove intermediate containers, even upon failure.
+    pub forcerm: Option<bool>,
+    /// Set memory limit for build.
+    pub memory: Option<i32>,
+    /// Total memory (memory + swap). Set as `-1` to disable swap.
+    pub memswap: Option<i32>,
+    /// CPU shares (relative weight).
+    pub cpushares: Option<i32>,
+    /// CPUs in which to allow execution (e.g., `0-3`, `0,1`).
+    pub cpusetcpus: Option<String>,
+    /// The length of a CPU period in microseconds.
+    pub cpuperiod: Option<i32>,
+    /// Microseconds of CPU time that the container can get in a CPU period.
+    pub cpuquota: Option<i32>,
+    /// JSON map of string pairs for build-time variables. Users pass these values at build-time. Docker uses the buildargs as the environment context for commands run via the `Dockerfile` RUN instruction, or for variable expansion in other `Dockerfile` instructions. This is not meant for passing secret values.  For example, the build arg `FOO=bar` would become `{\"FOO\":\"bar\"}` in JSON. This would result in the query parameter `buildargs={\"FOO\":\"bar\"}`. Note that `{\"FOO\":\"bar\"}` should be URI component encoded.  [Read more about the buildargs instruction.](https://docs.docker.com/engine/reference/builder/#arg)
+    pub buildargs: Option<String>,
+    /// Size of `/dev/shm` in bytes. The size must be greater than 0. If omitted the system uses 64MB.
+    pub shmsize: Option<i32>,
+    /// Squash the resulting images layers into a single layer. *(Experimental release only.)*
+    pub squash: Option<bool>,
+    /// Arbitrary key/value labels to set on the image, as a JSON map of string pairs.
+    pub labels: Option<String>,
+    /// Sets the networking mode for the run commands during build. Supported standard values are: `bridge`, `host`, `none`, and `container:<name|id>`. Any other value is taken as a custom network's name or ID to which this container should connect to.
+    pub networkmode: Option<String>,
+    pub content_type: Option<String>,
+    /// This is a base64-encoded JSON object with auth configurations for multiple registries that a build may refer to.  The key is a registry URL, and the value is an auth configuration object, [as described in the authentication section](#section/Authentication). For example:  ``` {   \"docker.example.com\": {     \"username\": \"janedoe\",     \"password\": \"hunter2\"   },   \"https://index.docker.io/v1/\": {     \"username\": \"mobydock\",     \"password\": \"conta1n3rize14\"   } } ```  Only the registry domain name (and port if not the default 443) are required. However, for legacy reasons, the Docker Hub registry must be specified with both a `https://` prefix and a `/v1/` suffix even though Docker will prefer to use the v2 registry API.
+    pub x_registry_config: Option<String>,
+    /// Platform in the format os[/arch[/variant]]
+    pub platform: Option<String>,
+    /// Target build stage
+    pub target: Option<String>,
+    /// BuildKit output configuration
+    pub outputs: Option<String>,
+    /// Version of the builder backend to use.  - `1` is the first generation classic (deprecated) builder in the Docker daemon (default) - `2` is [BuildKit](https://github.com/moby/buildkit)
+    pub version: Option<String>,
+    /// A tar archive compressed with one of the following algorithms: identity (no compression), gzip, bzip2, xz.
+    pub input_stream: Bytes,
+}
+/// struct for passing parameters to the method [`image_commit`]
+#[derive(Clone, Debug)]
+pub struct ImageCommitParams {
+    /// The ID or name of the container to commit
+    pub container: Option<String>,
+    /// Repository name for the created image
+    pub repo: Option<String>,
+    /// Tag name for the create image
+    pub tag: Option<String>,
+    /// Commit message
+    pub comment: Option<String>,
+    /// Author of the image (e.g., `John Hannibal Smith <hannibal@a-team.com>`)
+    pub author: Option<String>,
+    /// Whether to pause the container before committing
+    pub pause: Option<bool>,
+    /// `Dockerfile` instructions to apply while committing
+    pub changes: Option<String>,
+    /// The container configuration
+    pub container_config: Option<models::ContainerConfig>,
+}
+/// struct for passing parameters to the method [`image_create`]
+#[derive(Clone, Debug)]
+pub struct ImageCreateParams {
+    /// Name of the image to pull. The name may include a tag or digest. This parameter may only be used when pulling an image. The pull is cancelled if the HTTP connection is closed.
+    pub from_image: Option<String>,
+    /// Source to import. The value may be a URL from which the image can be retrieved or `-` to read the image from the request body. This parameter may only be used when importing an image.
+    pub from_src: Option<String>,
+    /// Repository name given to an image when it is imported. The repo may include a tag. This parameter may only be used when importing an image.
+    pub repo: Option<String>,
+    /// Tag or digest. If empty when pulling an image, this causes all tags for the given image to be pulled.
+    pub tag: Option<String>,
+    /// Set commit message for imported image.
+    pub message: Option<String>,
+    /// A base64url-encoded auth configuration.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: Option<String>,
+    /// Apply `Dockerfile` instructions to the image that is created, for example: `changes=ENV DEBUG=true`. Note that `ENV DEBUG=true` should be URI component encoded.  Supported `Dockerfile` instructions: `CMD`|`ENTRYPOINT`|`ENV`|`EXPOSE`|`ONBUILD`|`USER`|`VOLUME`|`WORKDIR`
+    pub changes: Option<Vec<String>>,
+    /// Platform in the format os[/arch[/variant]].  When used in combination with the `fromImage` option, the daemon checks if the given image is present in the local image cache with the given OS and Architecture, and otherwise attempts to pull the image. If the option is not set, the host's native OS and Architecture are used. If the given image does not exist in the local image cache, the daemon attempts to pull the image with the host's native OS and Architecture. If the given image does exists in the local image cache, but its OS or architecture does not match, a warning is produced.  When used with the `fromSrc` option to import an image from an archive, this option sets the platform information for the imported image. If the option is not set, the host's native OS and Architecture are used for the imported image.
+    pub platform: Option<String>,
+    /// Image content if the value `-` has been specified in fromSrc query parameter
+    pub input_image: Option<String>,
+}
+/// struct for passing parameters to the method [`image_delete`]
+#[derive(Clone, Debug)]
+pub struct ImageDeleteParams {
+    /// Image name or ID
+    pub name: String,
+    /// Remove the image even if it is being used by stopped containers or has other tags
+    pub force: Option<bool>,
+    /// Do not delete untagged parent images
+    pub noprune: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_get_all`]
+#[derive(Clone, Debug)]
+pub struct ImageGetAllParams {
+    /// Image names to filter by
+    pub names: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`image_get`]
+#[derive(Clone, Debug)]
+pub struct ImageGetParams {
+    /// Image name or ID
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_history`]
+#[derive(Clone, Debug)]
+pub struct ImageHistoryParams {
+    /// Image name or ID
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_inspect`]
+#[derive(Clone, Debug)]
+pub struct ImageInspectParams {
+    /// Image name or id
+    pub name: String,
+}
+/// struct for passing parameters to the method [`image_list`]
+#[derive(Clone, Debug)]
+pub struct ImageListParams {
+    /// Show all images. Only images from a final layer (no children) are shown by default.
+    pub all: Option<bool>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list.  Available filters:  - `before`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`) - `dangling=true` - `label=key` or `label=\"key=value\"` of an image label - `reference`=(`<image-name>[:<tag>]`) - `since`=(`<image-name>[:<tag>]`,  `<image id>` or `<image@digest>`) - `until=<timestamp>`
+    pub filters: Option<String>,
+    /// Compute and show shared size as a `SharedSize` field on each image.
+    pub shared_size: Option<bool>,
+    /// Show digest information as a `RepoDigests` field on each image.
+    pub digests: Option<bool>,
+    /// Include `Manifests` in the image summary.
+    pub manifests: Option<bool>,
+}
+/// struct for passing parameters to the method [`image_load`]
+#[derive(Clone, Debug)]
+pub struct ImageLoadParams {
+    /// Suppress progress details during load.
+    pub quiet: Option<bool>,
+    /// Tar archive containing images
+    pub images_tarball: Bytes,
+}
+/// struct for passing parameters to the method [`image_prune`]
+#[derive(Clone, Debug)]
+pub struct ImagePruneParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`). Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), prune only    unused *and* untagged images. When set to `false`    (or `0`), all unused images are pruned. - `until=<string>` Prune images created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune images with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`image_push`]
+#[derive(Clone, Debug)]
+pub struct ImagePushParams {
+    /// Name of the image to push. For example, `registry.example.com/myimage`. The image must be present in the local image store with the same name.  The name should be provided without tag; if a tag is provided, it is ignored. For example, `registry.example.com/myimage:latest` is considered equivalent to `registry.example.com/myimage`.  Use the `tag` parameter to specify the tag to push.
+    pub name: String,
+    /// A base64url-encoded auth configuration.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: String,
+    /// Tag of the image to push. For example, `latest`. If no tag is provided, all tags of the given image that are present in the local image store are pushed.
+    pub tag: Option<String>,
+    /// JSON-encoded OCI platform to select the platform-variant to push. If not provided, all available variants will attempt to be pushed.  If the daemon provides a multi-platform image store, this selects the platform-variant to push to the registry. If the image is a single-platform image, or if the multi-platform image does not provide a variant matching the given platform, an error is returned.  Example: `{\"os\": \"linux\", \"architecture\": \"arm\", \"variant\": \"v5\"}`
+    pub platform: Option<String>,
+}
+/// struct for passing parameters to the method [`image_search`]
+#[derive(Clone, Debug)]
+pub struct ImageSearchParams {
+    /// Term to search
+    pub term: String,
+    /// Maximum number of results to return
+    pub limit: Option<i32>,
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the images list. Available filters:  - `is-official=(true|false)` - `stars=<number>` Matches images that has at least 'number' stars.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`image_tag`]
+#[derive(Clone, Debug)]
+pub struct ImageTagParams {
+    /// Image name or ID to tag.
+    pub name: String,
+    /// The repository to tag in. For example, `someuser/someimage`.
+    pub repo: Option<String>,
+    /// The name of the new tag.
+    pub tag: Option<String>,
+}
+/// struct for passing parameters to the method [`network_connect`]
+#[derive(Clone, Debug)]
+pub struct NetworkConnectParams {
+    /// Network ID or name
+    pub id: String,
+    pub container: models::NetworkConnectRequest,
+}
+/// struct for passing parameters to the method [`network_create`]
+#[derive(Clone, Debug)]
+pub struct NetworkCreateParams {
+    /// Network configuration
+    pub network_config: models::NetworkCreateRequest,
+}
+/// struct for passing parameters to the method [`network_delete`]
+#[derive(Clone, Debug)]
+pub struct NetworkDeleteParams {
+    /// Network ID or name
+    pub id: String,
+}
+/// struct for passing parameters to the method [`network_disconnect`]
+#[derive(Clone, Debug)]
+pub struct NetworkDisconnectParams {
+    /// Network ID or name
+    pub id: String,
+    pub container: models::NetworkDisconnectRequest,
+}
+/// struct for passing parameters to the method [`network_inspect`]
+#[derive(Clone, Debug)]
+pub struct NetworkInspectParams {
+    /// Network ID or name
+    pub id: String,
+    /// Detailed inspect output for troubleshooting
+    pub verbose: Option<bool>,
+    /// Filter the network by scope (swarm, global, or local)
+    pub scope: Option<String>,
+}
+/// struct for passing parameters to the method [`network_list`]
+#[derive(Clone, Debug)]
+pub struct NetworkListParams {
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the networks list.  Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), returns all    networks that are not in use by a container. When set to `false`    (or `0`), only networks that are in use by one or more    containers are returned. - `driver=<driver-name>` Matches a network's driver. - `id=<network-id>` Matches all or part of a network ID. - `label=<key>` or `label=<key>=<value>` of a network label. - `name=<network-name>` Matches all or part of a network name. - `scope=[\"swarm\"|\"global\"|\"local\"]` Filters networks by scope (`swarm`, `global`, or `local`). - `type=[\"custom\"|\"builtin\"]` Filters networks by type. The `custom` keyword returns all user-defined networks.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`network_prune`]
+#[derive(Clone, Debug)]
+pub struct NetworkPruneParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `until=<timestamp>` Prune networks created before this timestamp. The `<timestamp>` can be Unix timestamps, date formatted timestamps, or Go duration strings (e.g. `10m`, `1h30m`) computed relative to the daemon machine’s time. - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune networks with (or without, in case `label!=...` is used) the specified labels.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`node_delete`]
+#[derive(Clone, Debug)]
+pub struct NodeDeleteParams {
+    /// The ID or name of the node
+    pub id: String,
+    /// Force remove a node from the swarm
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`node_inspect`]
+#[derive(Clone, Debug)]
+pub struct NodeInspectParams {
+    /// The ID or name of the node
+    pub id: String,
+}
+/// struct for passing parameters to the method [`node_list`]
+#[derive(Clone, Debug)]
+pub struct NodeListParams {
+    /// Filters to process on the nodes list, encoded as JSON (a `map[string][]string`).  Available filters: - `id=<node id>` - `label=<engine label>` - `membership=`(`accepted`|`pending`)` - `name=<node name>` - `node.label=<node label>` - `role=`(`manager`|`worker`)`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`node_update`]
+#[derive(Clone, Debug)]
+pub struct NodeUpdateParams {
+    /// The ID of the node
+    pub id: String,
+    /// The version number of the node object being updated. This is required to avoid conflicting writes.
+    pub version: i64,
+    pub body: Option<models::NodeSpec>,
+}
+/// struct for passing parameters to the method [`plugin_create`]
+#[derive(Clone, Debug)]
+pub struct PluginCreateParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    /// Path to tar containing plugin rootfs and manifest
+    pub tar_context: Bytes,
+}
+/// struct for passing parameters to the method [`plugin_delete`]
+#[derive(Clone, Debug)]
+pub struct PluginDeleteParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    /// Disable the plugin before removing. This may result in issues if the plugin is in use by a container.
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`plugin_disable`]
+#[derive(Clone, Debug)]
+pub struct PluginDisableParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    /// Force disable a plugin even if still in use.
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`plugin_enable`]
+#[derive(Clone, Debug)]
+pub struct PluginEnableParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    /// Set the HTTP client timeout (in seconds)
+    pub timeout: Option<i32>,
+}
+/// struct for passing parameters to the method [`plugin_inspect`]
+#[derive(Clone, Debug)]
+pub struct PluginInspectParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+}
+/// struct for passing parameters to the method [`plugin_list`]
+#[derive(Clone, Debug)]
+pub struct PluginListParams {
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the plugin list.  Available filters:  - `capability=<capability name>` - `enable=<true>|<false>`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`plugin_pull`]
+#[derive(Clone, Debug)]
+pub struct PluginPullParams {
+    /// Remote reference for plugin to install.  The `:latest` tag is optional, and is used as the default if omitted.
+    pub remote: String,
+    /// Local name for the pulled plugin.  The `:latest` tag is optional, and is used as the default if omitted.
+    pub name: Option<String>,
+    /// A base64url-encoded auth configuration to use when pulling a plugin from a registry.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: Option<String>,
+    pub body: Option<Vec<models::PluginPrivilege>>,
+}
+/// struct for passing parameters to the method [`plugin_push`]
+#[derive(Clone, Debug)]
+pub struct PluginPushParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+}
+/// struct for passing parameters to the method [`plugin_set`]
+#[derive(Clone, Debug)]
+pub struct PluginSetParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    pub body: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`plugin_upgrade`]
+#[derive(Clone, Debug)]
+pub struct PluginUpgradeParams {
+    /// The name of the plugin. The `:latest` tag is optional, and is the default if omitted.
+    pub name: String,
+    /// Remote reference to upgrade to.  The `:latest` tag is optional, and is used as the default if omitted.
+    pub remote: String,
+    /// A base64url-encoded auth configuration to use when pulling a plugin from a registry.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: Option<String>,
+    pub body: Option<Vec<models::PluginPrivilege>>,
+}
+/// struct for passing parameters to the method [`put_container_archive`]
+#[derive(Clone, Debug)]
+pub struct PutContainerArchiveParams {
+    /// ID or name of the container
+    pub id: String,
+    /// Path to a directory in the container to extract the archive’s contents into.
+    pub path: String,
+    /// The input stream must be a tar archive compressed with one of the following algorithms: `identity` (no compression), `gzip`, `bzip2`, or `xz`.
+    pub input_stream: Bytes,
+    /// If `1`, `true`, or `True` then it will be an error if unpacking the given content would cause an existing directory to be replaced with a non-directory and vice versa.
+    pub no_overwrite_dir_non_dir: Option<String>,
+    /// If `1`, `true`, then it will copy UID/GID maps to the dest file or dir
+    pub copy_uidgid: Option<String>,
+}
+/// struct for passing parameters to the method [`secret_create`]
+#[derive(Clone, Debug)]
+pub struct SecretCreateParams {
+    pub body: Option<models::SecretCreateRequest>,
+}
+/// struct for passing parameters to the method [`secret_delete`]
+#[derive(Clone, Debug)]
+pub struct SecretDeleteParams {
+    /// ID of the secret
+    pub id: String,
+}
+/// struct for passing parameters to the method [`secret_inspect`]
+#[derive(Clone, Debug)]
+pub struct SecretInspectParams {
+    /// ID of the secret
+    pub id: String,
+}
+/// struct for passing parameters to the method [`secret_list`]
+#[derive(Clone, Debug)]
+pub struct SecretListParams {
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the secrets list.  Available filters:  - `id=<secret id>` - `label=<key> or label=<key>=value` - `name=<secret name>` - `names=<secret name>`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`secret_update`]
+#[derive(Clone, Debug)]
+pub struct SecretUpdateParams {
+    /// The ID or name of the secret
+    pub id: String,
+    /// The version number of the secret object being updated. This is required to avoid conflicting writes.
+    pub version: i64,
+    /// The spec of the secret to update. Currently, only the Labels field can be updated. All other fields must remain unchanged from the [SecretInspect endpoint](#operation/SecretInspect) response values.
+    pub body: Option<models::SecretSpec>,
+}
+/// struct for passing parameters to the method [`service_create`]
+#[derive(Clone, Debug)]
+pub struct ServiceCreateParams {
+    pub body: models::ServiceCreateRequest,
+    /// A base64url-encoded auth configuration for pulling from private registries.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: Option<String>,
+}
+/// struct for passing parameters to the method [`service_delete`]
+#[derive(Clone, Debug)]
+pub struct ServiceDeleteParams {
+    /// ID or name of service.
+    pub id: String,
+}
+/// struct for passing parameters to the method [`service_inspect`]
+#[derive(Clone, Debug)]
+pub struct ServiceInspectParams {
+    /// ID or name of service.
+    pub id: String,
+    /// Fill empty fields with default values.
+    pub insert_defaults: Option<bool>,
+}
+/// struct for passing parameters to the method [`service_list`]
+#[derive(Clone, Debug)]
+pub struct ServiceListParams {
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the services list.  Available filters:  - `id=<service id>` - `label=<service label>` - `mode=[\"replicated\"|\"global\"]` - `name=<service name>`
+    pub filters: Option<String>,
+    /// Include service status, with count of running and desired tasks.
+    pub status: Option<bool>,
+}
+/// struct for passing parameters to the method [`service_logs`]
+#[derive(Clone, Debug)]
+pub struct ServiceLogsParams {
+    /// ID or name of the service
+    pub id: String,
+    /// Show service context and extra details provided to logs.
+    pub details: Option<bool>,
+    /// Keep connection after returning logs.
+    pub follow: Option<bool>,
+    /// Return logs from `stdout`
+    pub stdout: Option<bool>,
+    /// Return logs from `stderr`
+    pub stderr: Option<bool>,
+    /// Only return logs since this time, as a UNIX timestamp
+    pub since: Option<i32>,
+    /// Add timestamps to every log line
+    pub timestamps: Option<bool>,
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub tail: Option<String>,
+}
+/// struct for passing parameters to the method [`service_update`]
+#[derive(Clone, Debug)]
+pub struct ServiceUpdateParams {
+    /// ID or name of service.
+    pub id: String,
+    /// The version number of the service object being updated. This is required to avoid conflicting writes. This version number should be the value as currently set on the service *before* the update. You can find the current version by calling `GET /services/{id}`
+    pub version: i32,
+    pub body: models::ServiceUpdateRequest,
+    /// If the `X-Registry-Auth` header is not specified, this parameter indicates where to find registry authorization credentials.
+    pub registry_auth_from: Option<String>,
+    /// Set to this parameter to `previous` to cause a server-side rollback to the previous service spec. The supplied spec will be ignored in this case.
+    pub rollback: Option<String>,
+    /// A base64url-encoded auth configuration for pulling from private registries.  Refer to the [authentication section](#section/Authentication) for details.
+    pub x_registry_auth: Option<String>,
+}
+/// struct for passing parameters to the method [`swarm_init`]
+#[derive(Clone, Debug)]
+pub struct SwarmInitParams {
+    pub body: models::SwarmInitRequest,
+}
+/// struct for passing parameters to the method [`swarm_join`]
+#[derive(Clone, Debug)]
+pub struct SwarmJoinParams {
+    pub body: models::SwarmJoinRequest,
+}
+/// struct for passing parameters to the method [`swarm_leave`]
+#[derive(Clone, Debug)]
+pub struct SwarmLeaveParams {
+    /// Force leave swarm, even if this is the last manager or that it will break the cluster.
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`swarm_unlock`]
+#[derive(Clone, Debug)]
+pub struct SwarmUnlockParams {
+    pub body: models::SwarmUnlockRequest,
+}
+/// struct for passing parameters to the method [`swarm_update`]
+#[derive(Clone, Debug)]
+pub struct SwarmUpdateParams {
+    /// The version number of the swarm object being updated. This is required to avoid conflicting writes.
+    pub version: i64,
+    pub body: models::SwarmSpec,
+    /// Rotate the worker join token.
+    pub rotate_worker_token: Option<bool>,
+    /// Rotate the manager join token.
+    pub rotate_manager_token: Option<bool>,
+    /// Rotate the manager unlock key.
+    pub rotate_manager_unlock_key: Option<bool>,
+}
+/// struct for passing parameters to the method [`system_auth`]
+#[derive(Clone, Debug)]
+pub struct SystemAuthParams {
+    /// Authentication to check
+    pub auth_config: Option<models::AuthConfig>,
+}
+/// struct for passing parameters to the method [`system_data_usage`]
+#[derive(Clone, Debug)]
+pub struct SystemDataUsageParams {
+    /// Object types, for which to compute and return data.
+    pub r#type: Option<Vec<String>>,
+}
+/// struct for passing parameters to the method [`system_events`]
+#[derive(Clone, Debug)]
+pub struct SystemEventsParams {
+    /// Show events created since this timestamp then stream new events.
+    pub since: Option<String>,
+    /// Show events created until this timestamp then stop streaming.
+    pub until: Option<String>,
+    /// A JSON encoded value of filters (a `map[string][]string`) to process on the event list. Available filters:  - `config=<string>` config name or ID - `container=<string>` container name or ID - `daemon=<string>` daemon name or ID - `event=<string>` event type - `image=<string>` image name or ID - `label=<string>` image or container label - `network=<string>` network name or ID - `node=<string>` node ID - `plugin`=<string> plugin name or ID - `scope`=<string> local or swarm - `secret=<string>` secret name or ID - `service=<string>` service name or ID - `type=<string>` object to filter by, one of `container`, `image`, `volume`, `network`, `daemon`, `plugin`, `node`, `service`, `secret` or `config` - `volume=<string>` volume name
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`task_inspect`]
+#[derive(Clone, Debug)]
+pub struct TaskInspectParams {
+    /// ID of the task
+    pub id: String,
+}
+/// struct for passing parameters to the method [`task_list`]
+#[derive(Clone, Debug)]
+pub struct TaskListParams {
+    /// A JSON encoded value of the filters (a `map[string][]string`) to process on the tasks list.  Available filters:  - `desired-state=(running | shutdown | accepted)` - `id=<task id>` - `label=key` or `label=\"key=value\"` - `name=<task name>` - `node=<node id or name>` - `service=<service name>`
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`task_logs`]
+#[derive(Clone, Debug)]
+pub struct TaskLogsParams {
+    /// ID of the task
+    pub id: String,
+    /// Show task context and extra details provided to logs.
+    pub details: Option<bool>,
+    /// Keep connection after returning logs.
+    pub follow: Option<bool>,
+    /// Return logs from `stdout`
+    pub stdout: Option<bool>,
+    /// Return logs from `stderr`
+    pub stderr: Option<bool>,
+    /// Only return logs since this time, as a UNIX timestamp
+    pub since: Option<i32>,
+    /// Add timestamps to every log line
+    pub timestamps: Option<bool>,
+    /// Only return this number of log lines from the end of the logs. Specify as an integer or `all` to output all log lines.
+    pub tail: Option<String>,
+}
+/// struct for passing parameters to the method [`volume_create`]
+#[derive(Clone, Debug)]
+pub struct VolumeCreateParams {
+    /// Volume configuration
+    pub volume_config: models::VolumeCreateOptions,
+}
+/// struct for passing parameters to the method [`volume_delete`]
+#[derive(Clone, Debug)]
+pub struct VolumeDeleteParams {
+    /// Volume name or ID
+    pub name: String,
+    /// Force the removal of the volume
+    pub force: Option<bool>,
+}
+/// struct for passing parameters to the method [`volume_inspect`]
+#[derive(Clone, Debug)]
+pub struct VolumeInspectParams {
+    /// Volume name or ID
+    pub name: String,
+}
+/// struct for passing parameters to the method [`volume_list`]
+#[derive(Clone, Debug)]
+pub struct VolumeListParams {
+    /// JSON encoded value of the filters (a `map[string][]string`) to process on the volumes list. Available filters:  - `dangling=<boolean>` When set to `true` (or `1`), returns all    volumes that are not in use by a container. When set to `false`    (or `0`), only volumes that are in use by one or more    containers are returned. - `driver=<volume-driver-name>` Matches volumes based on their driver. - `label=<key>` or `label=<key>:<value>` Matches volumes based on    the presence of a `label` alone or a `label` and a value. - `name=<volume-name>` Matches all or part of a volume name.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`volume_prune`]
+#[derive(Clone, Debug)]
+pub struct VolumePruneParams {
+    /// Filters to process on the prune list, encoded as JSON (a `map[string][]string`).  Available filters: - `label` (`label=<key>`, `label=<key>=<value>`, `label!=<key>`, or `label!=<key>=<value>`) Prune volumes with (or without, in case `label!=...` is used) the specified labels. - `all` (`all=true`) - Consider all (local) volumes for pruning and not just anonymous volumes.
+    pub filters: Option<String>,
+}
+/// struct for passing parameters to the method [`volume_update`]
+#[derive(Clone, Debug)]
+pub struct VolumeUpdateParams {
+    /// The name or ID of the volume
+    pub name: String,
+    /// The version number of the volume being updated. This is required to avoid conflicting writes. Found in the volume's `ClusterVolume` field.
+    pub version: i64,
+    /// The spec of the volume to update. Currently, only Availability may change. All other fields must remain unchanged.
+    pub body: Option<models::VolumeUpdateRequest>,
+}
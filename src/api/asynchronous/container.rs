@@ -1,30 +1,50 @@
-use crate::api::options::{CreateContainerOptionsBuilder, StopContainerOptionsBuilder};
-use bollard::container::{ListContainersOptions, LogOutput, RestartContainerOptions, UploadToContainerOptions};
+use crate::api::asynchronous::image::ImageApi;
+use crate::api::frame::{LogFrame, StreamType};
+use crate::api::options::{CreateContainerOptionsBuilder, ExecOptions, PullPolicy, StopContainerOptionsBuilder};
+use crate::api::pull::{PullError, PullOptions};
+use crate::api::registry_auth::RegistryAuths;
+use bollard::container::{ListContainersOptions, RestartContainerOptions, UploadToContainerOptions};
 use bollard::errors::Error;
-use bollard::exec::{CreateExecOptions, StartExecOptions, StartExecResults};
-use bollard::models::{ContainerInspectResponse, ContainerState, ContainerSummary, Health, HealthStatusEnum};
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use bollard::models::{ContainerInspectResponse, ContainerState, ContainerSummary, HealthStatusEnum};
 use bollard::Docker;
 use bytes::Bytes;
 use futures::StreamExt;
-use pipewire_common::utils::Backoff;
 use std::collections::HashMap;
+use std::io;
 use std::sync::Arc;
-use tokio::runtime::Runtime;
+use std::time::Duration;
+
+const HEALTHY_INITIAL_DELAY: Duration = Duration::from_millis(100);
+const HEALTHY_MAXIMUM_DELAY: Duration = Duration::from_secs(5);
+const HEALTHY_MAXIMUM_ATTEMPTS: u32 = 30;
+const EXEC_INITIAL_DELAY: Duration = Duration::from_millis(10);
+const EXEC_MAXIMUM_DELAY: Duration = Duration::from_millis(500);
+
+/// Result of `ContainerApi::exec`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecOutput {
+    /// `None` when the daemon did not report an exit code.
+    pub exit_code: Option<i64>,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
 
 pub struct ContainerApi {
-    runtime: Arc<Runtime>,
-    api: Arc<Docker>
+    api: Arc<Docker>,
+    registry_auths: Arc<RegistryAuths>,
 }
 
 impl ContainerApi {
-    pub fn new(runtime: Arc<Runtime>, api: Arc<Docker>) -> Self {
+    /// `registry_auths` are used to pull missing images, see `ImageApi::new`.
+    pub fn new(api: Arc<Docker>, registry_auths: Arc<RegistryAuths>) -> Self {
         Self {
-            runtime,
             api,
+            registry_auths,
         }
     }
 
-    pub fn get_all(&self) -> Result<Vec<ContainerSummary>, Error>{
+    pub async fn get_all(&self) -> Result<Vec<ContainerSummary>, Error>{
         let mut filter = HashMap::new();
         filter.insert("label", vec!["test.container=true"]);
         let options = ListContainersOptions {
@@ -32,192 +52,195 @@ impl ContainerApi {
             filters: filter,
             ..Default::default()
         };
-        let call = self.api.list_containers(Some(options));
-        self.runtime.block_on(call)
+        self.api.list_containers(Some(options)).await
     }
 
-    pub fn clean(&self, id: &String, state: &ContainerState) {
+    pub async fn clean(&self, id: &String, state: &ContainerState) -> Result<(), Error> {
         println!("Clean container with id {}", id);
-        if state.running.unwrap() {
+        if state.running.unwrap_or(false) {
             let stop_options = StopContainerOptionsBuilder::default().build();
-            let call = self.api.stop_container(id, Some(stop_options));
-            self.runtime.block_on(call).unwrap();
+            self.api.stop_container(id, Some(stop_options)).await?;
         }
-        let call = self.api.remove_container(id, None);
-        self.runtime.block_on(call).unwrap();
+        self.api.remove_container(id, None).await
     }
 
-    pub fn create(&self, options: &mut CreateContainerOptionsBuilder) -> String {
+    /// Pulls the image first according to the pull policy of `options`.
+    pub async fn create(&self, options: &mut CreateContainerOptionsBuilder) -> Result<String, Error> {
+        let pull_policy = options.pull_policy();
         let options = options
             .with_label("test.container", true.to_string())
             .build();
-        println!("Create container with image {}", options.image.as_ref().unwrap());
-        let call = self.api.create_container::<String, String>(None, options);
-        let result = self.runtime.block_on(call).unwrap();
-        result.id
+        let image = options.image.clone().unwrap();
+        println!("Create container with image {}", image);
+        let pull_needed = match pull_policy {
+            PullPolicy::Always => true,
+            PullPolicy::IfNotPresent => match self.api.inspect_image(&image).await {
+                Ok(_) => false,
+                Err(Error::DockerResponseServerError { status_code: 404, .. }) => true,
+                Err(error) => return Err(error),
+            },
+            PullPolicy::Never => false,
+        };
+        if pull_needed {
+            ImageApi::new(self.api.clone(), self.registry_auths.clone())
+                .pull(&image, &mut PullOptions::default())
+                .await
+                .map_err(|error| match error {
+                    PullError::Request(error) => error,
+                    error => Error::DockerStreamError {
+                        error: error.to_string(),
+                    },
+                })?;
+        }
+        let result = self.api.create_container::<String, String>(None, options).await?;
+        Ok(result.id)
     }
 
-    pub fn start(&self, id: &String) {
+    pub async fn start(&self, id: &String) -> Result<(), Error> {
         println!("Start container with id {}", id);
-        let call = self.api.start_container::<String>(id, None);
-        self.runtime.block_on(call).unwrap();
+        self.api.start_container::<String>(id, None).await
     }
 
-    pub fn stop(&self, id: &String, options: &mut StopContainerOptionsBuilder) {
+    pub async fn stop(&self, id: &String, options: &mut StopContainerOptionsBuilder) -> Result<(), Error> {
         println!("Stop container with id {}", id);
         let options = options.build();
-        let call = self.api.stop_container(id, Some(options));
-        self.runtime.block_on(call).unwrap();
+        self.api.stop_container(id, Some(options)).await
     }
 
-    pub fn restart(&self, id: &String) {
+    pub async fn restart(&self, id: &String) -> Result<(), Error> {
         println!("Restart container with id {}", id);
         let options = RestartContainerOptions {
             t: 0,
         };
-        let call = self.api.restart_container(id, Some(options));
-        self.runtime.block_on(call).unwrap();
+        self.api.restart_container(id, Some(options)).await
     }
 
-    pub fn remove(&self, id: &String) {
+    pub async fn remove(&self, id: &String) -> Result<(), Error> {
         println!("Remove container with id {}", id);
-        let call = self.api.remove_container(id, None);
-        self.runtime.block_on(call).unwrap();
+        self.api.remove_container(id, None).await
     }
 
-    pub fn inspect(&self, id: &String) -> Result<ContainerInspectResponse, pipewire_common::error::Error> {
-        let call = self.api.inspect_container(id, None);
-        self.runtime.block_on(call).map_err(|error| {
-            pipewire_common::error::Error {
-                description: error.to_string(),
-            }
-        })
+    pub async fn inspect(&self, id: &String) -> Result<ContainerInspectResponse, Error> {
+        self.api.inspect_container(id, None).await
     }
 
-    pub fn upload(&self, id: &String, path: &str, archive: Bytes) {
+    pub async fn upload(&self, id: &String, path: &str, archive: Bytes) -> Result<(), Error> {
         let options = UploadToContainerOptions {
             path: path.to_string(),
             no_overwrite_dir_non_dir: true.to_string(),
         };
-        let call = self.api.upload_to_container(id, Some(options), archive);
-        self.runtime.block_on(call).unwrap();
+        self.api.upload_to_container(id, Some(options), archive).await
     }
 
-    pub fn wait_healthy(&self, id: &String) {
+    /// Polls the health status with an exponential backoff. Fails with a `TimedOut` error when
+    /// the container is not healthy after `HEALTHY_MAXIMUM_ATTEMPTS` attempts, and with an
+    /// `InvalidInput` one when the container has no healthcheck.
+    pub async fn wait_healthy(&self, id: &String) -> Result<(), Error> {
         println!("Wait container with id {} to be healthy", id);
-        let operation = || {
-            let response = self.inspect(id);
-            match response {
-                Ok(value) => {
-                    let state = value.state.unwrap();
-                    let health = state.health.unwrap();
-                    match health {
-                        Health { status, .. } => {
-                            match status.unwrap() {
-                                HealthStatusEnum::HEALTHY => Ok(()),
-                                _ => Err(pipewire_common::error::Error {
-                                    description: "Container not yet healthy".to_string(),
-                                })
-                            }
-                        }
-                    }
-                }
-                Err(value) => Err(pipewire_common::error::Error {
-                    description: format!("Container {} not ready: {}", id, value),
-                })
+        let mut delay = HEALTHY_INITIAL_DELAY;
+        let mut last_status = None;
+        for _ in 0..HEALTHY_MAXIMUM_ATTEMPTS {
+            match self.health_status(id).await? {
+                HealthStatusEnum::HEALTHY => return Ok(()),
+                status => last_status = Some(status),
             }
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(HEALTHY_MAXIMUM_DELAY);
+        }
+        Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "Container {} not healthy after {} attempts, last status: {}",
+                id,
+                HEALTHY_MAXIMUM_ATTEMPTS,
+                last_status.unwrap_or(HealthStatusEnum::EMPTY)
+            )
+        ).into())
+    }
+
+    async fn health_status(&self, id: &String) -> Result<HealthStatusEnum, Error> {
+        let response = self.inspect(id).await?;
+        let status = response.state
+            .and_then(|state| state.health)
+            .and_then(|health| health.status);
+        match status {
+            None | Some(HealthStatusEnum::NONE) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Container {} has no healthcheck", id)
+            ).into()),
+            Some(status) => Ok(status),
+        }
+    }
+
+    /// Runs a command to completion, the exit code policy is left to the caller.
+    pub async fn exec(&self, id: &String, options: &ExecOptions) -> Result<ExecOutput, Error> {
+        match options.timeout() {
+            Some(timeout) => tokio::time::timeout(timeout, self.run_exec(id, options.build())).await
+                .map_err(|_| Error::RequestTimeoutError)?,
+            None => self.run_exec(id, options.build()).await,
+        }
+    }
+
+    async fn run_exec(&self, id: &String, options: CreateExecOptions<String>) -> Result<ExecOutput, Error> {
+        let exec_id = self.api.create_exec(id.as_str(), options).await?.id;
+        let mut result = ExecOutput {
+            exit_code: None,
+            stdout: Vec::new(),
+            stderr: Vec::new(),
         };
-        let mut backoff = Backoff::default();
-        backoff.retry(operation).unwrap()
-    }
-
-    pub fn exec(
-        &self,
-        id: &String,
-        command: Vec<&str>,
-        detach: bool,
-        expected_exit_code: u32,
-    ) -> Result<Vec<String>, pipewire_common::error::Error> {
-        let create_exec_options = CreateExecOptions {
-            attach_stdout: Some(true),
-            attach_stderr: Some(true),
-            tty: Some(true),
-            cmd: Some(command),
-            ..Default::default()
-        };
-        let call = self.api.create_exec(id.as_str(), create_exec_options);
-        let create_exec_result = self.runtime.block_on(call).unwrap();
-        let exec_id = create_exec_result.id;
-        let start_exec_options = StartExecOptions {
-            detach,
-            tty: true,
-            ..Default::default()
-        };
-        let call = self.api.start_exec(exec_id.as_str(), Some(start_exec_options));
-        let start_exec_result = self.runtime.block_on(call).unwrap();
-        let mut output_result: Vec<String> = Vec::new();
-        if let StartExecResults::Attached { mut output, .. } = start_exec_result {
-            while let Some(Ok(message)) = self.runtime.block_on(output.next()) {
-                match message {
-                    LogOutput::StdOut { message } => {
-                        output_result.push(
-                            String::from_utf8(message.to_vec()).unwrap()
-                        )
-                    }
-                    LogOutput::StdErr { message } => {
-                        eprint!("{}", String::from_utf8(message.to_vec()).unwrap())
-                    }
-                    LogOutput::Console { message } => {
-                        output_result.push(
-                            String::from_utf8(message.to_vec()).unwrap()
-                        )
-                    }
-                    _ => {}
+        if let StartExecResults::Attached { mut output, .. } = self.api.start_exec(exec_id.as_str(), None).await? {
+            while let Some(message) = output.next().await {
+                let frame = LogFrame::from(message?);
+                match frame.stream {
+                    StreamType::Stdout => result.stdout.extend_from_slice(&frame.bytes),
+                    StreamType::Stderr => result.stderr.extend_from_slice(&frame.bytes),
+                    StreamType::Stdin => {}
                 }
             }
-            let call = self.api.inspect_exec(exec_id.as_str());
-            let exec_inspect_result = self.runtime.block_on(call).unwrap();
-            let exit_code = exec_inspect_result.exit_code.unwrap();
-            if exit_code != expected_exit_code as i64 {
-                return Err(pipewire_common::error::Error {
-                    description: format!("Unexpected exit code: {exit_code}"),
-                });
+        }
+        let mut delay = EXEC_INITIAL_DELAY;
+        loop {
+            let inspect_result = self.api.inspect_exec(exec_id.as_str()).await?;
+            if inspect_result.running != Some(true) {
+                result.exit_code = inspect_result.exit_code;
+                return Ok(result);
             }
-            let output_result = output_result.iter()
-                .flat_map(move |output| {
-                    output.split('\n')
-                        .map(move |line| line.trim().to_string())
-                        .collect::<Vec<_>>()
-                })
-                .collect::<Vec<_>>();
-            Ok(output_result)
-        } else {
-            Ok(output_result)
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(EXEC_MAXIMUM_DELAY);
         }
     }
 
-    pub fn top(&self, id: &String) -> HashMap<String, String> {
-        let call = self.api.top_processes::<&str>(id, None);
-        let result = self.runtime.block_on(call).unwrap();
-        let titles = result.titles.unwrap();
-        let pid_column_index = titles.iter().position(move |title| *title == "PID").unwrap();
-        let cmd_column_index = titles.iter().position(move |title| *title == "CMD").unwrap();
-        let processes = result.processes.unwrap().iter()
-            .map(|process| {
-                let pid = process.get(pid_column_index).unwrap();
-                let cmd = process.get(cmd_column_index).unwrap();
-                (cmd.clone(), pid.clone())
+    /// Process ids by command, fails with an `InvalidData` error when the daemon does not
+    /// report the `PID` and `CMD` columns.
+    pub async fn top(&self, id: &String) -> Result<HashMap<String, String>, Error> {
+        let result = self.api.top_processes::<&str>(id, None).await?;
+        let titles = result.titles.unwrap_or_default();
+        let column_index = |name: &str| {
+            titles.iter()
+                .position(|title| title == name)
+                .ok_or_else(|| io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Processes of container {} have no {} column", id, name)
+                ))
+        };
+        let pid_column_index = column_index("PID")?;
+        let cmd_column_index = column_index("CMD")?;
+        let processes = result.processes.unwrap_or_default().iter()
+            .filter_map(|process| {
+                let pid = process.get(pid_column_index)?;
+                let cmd = process.get(cmd_column_index)?;
+                Some((cmd.clone(), pid.clone()))
             })
             .collect::<HashMap<_, _>>();
-        processes
+        Ok(processes)
     }
 }
 
 impl Clone for ContainerApi {
     fn clone(&self) -> Self {
         Self {
-            runtime: self.runtime.clone(),
             api: self.api.clone(),
+            registry_auths: self.registry_auths.clone(),
         }
     }
-}
\ No newline at end of file
+}
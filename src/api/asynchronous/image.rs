@@ -1,10 +1,14 @@
+use crate::api::dockerignore::{DockerIgnore, DOCKERIGNORE_FILENAME};
+use crate::api::pull::{PullError, PullOptions, PullTracker, PulledImage};
+use crate::api::reference::ImageReference;
+use crate::api::registry_auth::{registry_host, RegistryAuths};
+use bollard::auth::DockerCredentials;
 use bollard::errors::Error;
-use bollard::image::{BuildImageOptions, BuilderVersion};
-use bollard::models::ImageInspect;
+use bollard::image::{BuildImageOptions, BuilderVersion, CreateImageOptions};
+use bollard::models::{BuildInfo, ImageInspect};
 use bollard::Docker;
 use bytes::Bytes;
-use futures::StreamExt;
-use pipewire_common::utils::HexSlice;
+use futures::{Stream, StreamExt};
 use sha2::{Digest, Sha256};
 use std::fs::File;
 use std::io::{Seek, SeekFrom, Write};
@@ -13,7 +17,6 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::{fs, io};
 use tar::{Builder, Header};
-use tokio::runtime::Runtime;
 use uuid::Uuid;
 
 struct ImageContext;
@@ -24,6 +27,14 @@ impl ImageContext {
             ".digests",
         ];
         let context_path = container_file_path.parent().unwrap();
+        let ignore = DockerIgnore::load(context_path, container_file_path)?;
+        // The Dockerfile and its ignore files are always sent, as the docker CLI does.
+        let container_file_name = container_file_path.file_name().unwrap().to_str().unwrap();
+        let build_files = vec![
+            container_file_name.to_string(),
+            format!("{}{}", container_file_name, DOCKERIGNORE_FILENAME),
+            DOCKERIGNORE_FILENAME.to_string(),
+        ];
         // Hasher is used for computing all context files hashes.
         // In that way we can determine later with we build the image or not.
         // This is better that just computing context archive hash which include data and metadata
@@ -35,7 +46,9 @@ impl ImageContext {
             &mut hasher,
             context_path,
             context_path,
-            Some(&excluded_filename)
+            Some(&excluded_filename),
+            &ignore,
+            &build_files
         )?;
         let uncompressed = archive.into_inner()?;
         let mut compressed = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
@@ -43,7 +56,7 @@ impl ImageContext {
         let compressed = compressed.finish()?;
         let data = Bytes::from(compressed);
         let hash_bytes = hasher.finalize().to_vec();
-        let digest = HexSlice(hash_bytes.as_slice());
+        let digest = hex::encode(hash_bytes);
         let digest = format!("sha256:{}", digest);
         Ok((data, digest))
     }
@@ -53,18 +66,31 @@ impl ImageContext {
         hasher: &mut impl Write,
         root: &Path,
         directory: &Path,
-        excluded_filenames: Option<&Vec<&str>>
+        excluded_filenames: Option<&Vec<&str>>,
+        ignore: &DockerIgnore,
+        build_files: &Vec<String>
     ) -> io::Result<()> {
         if directory.is_dir() {
             for entry in fs::read_dir(directory)? {
                 let entry = entry?;
                 let path = entry.path();
                 let filename = path.file_name().unwrap().to_str().unwrap();
+                let relative_path = path.strip_prefix(root).unwrap()
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                let excluded = ignore.is_excluded(&relative_path)
+                    && build_files.contains(&relative_path) == false;
                 if path.is_dir() {
-                    Self::read_directory(archive, hasher, root, &path, excluded_filenames)?;
+                    // Files of an excluded directory can only be included back by an exception.
+                    if excluded && ignore.has_exceptions() == false {
+                        continue;
+                    }
+                    Self::read_directory(archive, hasher, root, &path, excluded_filenames, ignore, build_files)?;
                 }
                 else if path.is_file() {
-                    if excluded_filenames.as_ref().unwrap().contains(&filename) {
+                    if excluded_filenames.as_ref().unwrap().contains(&filename) || excluded {
                         continue;
                     }
                     let mut file = File::open(&path)?;
@@ -86,52 +112,120 @@ impl ImageContext {
 }
 
 pub struct ImageApi {
-    runtime: Arc<Runtime>,
-    api: Arc<Docker>
+    api: Arc<Docker>,
+    registry_auths: Arc<RegistryAuths>,
 }
 
 impl ImageApi {
-    pub fn new(runtime: Arc<Runtime>, api: Arc<Docker>) -> Self {
+    /// `registry_auths` can be shared with a client, see `ContainerClient::registry_auths`.
+    pub fn new(api: Arc<Docker>, registry_auths: Arc<RegistryAuths>) -> Self {
         Self {
-            runtime,
             api,
+            registry_auths,
         }
     }
 
-    pub fn inspect(&self, image_name: &String) -> Result<ImageInspect, Error> {
-        let result = self.api.inspect_image(image_name.as_str());
-        self.runtime.block_on(result)
+    pub async fn inspect(&self, image_name: &String) -> Result<ImageInspect, Error> {
+        self.api.inspect_image(image_name.as_str()).await
     }
-    
+
+    /// Pulls `image` with the credentials of its registry, fails when a credential helper fails.
+    /// An error reported in the progress stream fails the pull as with the client of the
+    /// generated API.
+    pub async fn pull(&self, image: &String, options: &mut PullOptions) -> Result<PulledImage, PullError<Error>> {
+        let reference = ImageReference::parse(image)
+            .map_err(|error| PullError::Request(io::Error::new(io::ErrorKind::InvalidInput, error).into()))?;
+        let credentials = match options.registry_auth.clone() {
+            Some(registry_auth) => Some(registry_auth),
+            None => {
+                let registry_auths = self.registry_auths.clone();
+                let registry = registry_host(image);
+                // Credential helpers are programs, they run from the blocking thread pool.
+                tokio::task::spawn_blocking(move || registry_auths.get(&registry))
+                    .await
+                    .map_err(|error| PullError::Request(io::Error::other(error).into()))?
+                    .map_err(|error| PullError::Request(error.into()))?
+            }
+        };
+        println!("Pull container image: {}", image);
+        let create_options = CreateImageOptions {
+            from_image: reference.name(),
+            tag: reference.digest().or(reference.tag()).unwrap_or_default().to_string(),
+            platform: options.platform.clone().unwrap_or_default(),
+            ..Default::default()
+        };
+        let mut stream = self.api.create_image(Some(create_options), None, credentials.map(DockerCredentials::from));
+        let mut tracker = PullTracker::new(&reference, options);
+        while let Some(message) = stream.next().await {
+            match message {
+                Ok(message) => tracker.push_message(message.into())?,
+                // bollard turns the messages with an error into stream errors.
+                Err(Error::DockerStreamError { error }) => {
+                    return Err(PullError::Daemon {
+                        code: None,
+                        message: error,
+                    })
+                }
+                Err(error) => return Err(PullError::Request(error)),
+            }
+        }
+        tracker.finish()
+    }
+
     pub fn context_build(&self, container_file_path: &PathBuf) -> (Bytes, String) {
-        ImageContext::create(&container_file_path).unwrap()
+        ImageContext::create(container_file_path).unwrap()
     }
 
-    pub fn build(
+    /// Streams the build progress messages of the daemon, an invalid image name or tag or an
+    /// unreadable context is the only message of the stream.
+    pub fn build_stream(
         &self,
         container_file_path: &PathBuf,
         image_name: &String,
         image_tag: &String
-    ) {
-        let tag = format!("{}:{}", image_name, image_tag);
+    ) -> impl Stream<Item = Result<BuildInfo, Error>> + '_ {
+        let (options, context) = match Self::build_options(container_file_path, image_name, image_tag) {
+            Ok(value) => value,
+            Err(error) => return futures::stream::once(futures::future::ready(Err(error))).right_stream(),
+        };
+        println!("Build container image: {}", options.t);
+        self.api.build_image(options, None, Some(context)).left_stream()
+    }
+
+    fn build_options(
+        container_file_path: &PathBuf,
+        image_name: &String,
+        image_tag: &String
+    ) -> Result<(BuildImageOptions<String>, Bytes), Error> {
+        let tag = ImageReference::parse(image_name)
+            .and_then(|mut reference| reference.with_tag(image_tag.clone()).map(|reference| reference.familiar()))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
         let options = BuildImageOptions {
-            dockerfile: container_file_path.file_name().unwrap().to_str().unwrap(),
-            t: tag.as_str(),
+            dockerfile: container_file_path.file_name().unwrap().to_str().unwrap().to_string(),
+            t: tag,
             session: Some(Uuid::new_v4().to_string()),
             version: BuilderVersion::BuilderBuildKit,
             ..Default::default()
         };
-        let (context, context_digest) = ImageContext::create(&container_file_path).unwrap();
-        // TODO 
+        let (context, _context_digest) = ImageContext::create(container_file_path)?;
+        Ok((options, context))
+    }
+
+    pub async fn build(
+        &self,
+        container_file_path: &PathBuf,
+        image_name: &String,
+        image_tag: &String
+    ) {
+        // TODO
         // let mut environment = TEST_ENVIRONMENT.lock().unwrap();
         // println!("Container image digest: {}", context_digest);
         // if environment.container_image_registry.is_build_needed(&image_name, &context_digest) == false {
         //     println!("Skip build container image: {}", tag);
         //     return;
         // }
-        println!("Build container image: {}", tag);
-        let mut stream = self.api.build_image(options, None, Some(context));
-        while let Some(message) = self.runtime.block_on(stream.next()) {
+        let mut stream = self.build_stream(container_file_path, image_name, image_tag);
+        while let Some(message) = stream.next().await {
             match message {
                 Ok(message) => {
                     if let Some(stream) = message.stream {
@@ -159,8 +253,8 @@ impl ImageApi {
 impl Clone for ImageApi {
     fn clone(&self) -> Self {
         Self {
-            runtime: self.runtime.clone(),
             api: self.api.clone(),
+            registry_auths: self.registry_auths.clone(),
         }
     }
-}
\ No newline at end of file
+}
@@ -1,2 +1,167 @@
 pub mod container;
-pub mod image;
\ No newline at end of file
+pub mod image;
+
+use crate::api::engine::Engine;
+use crate::api::internals::api_async::{Configuration, ContainerAttachError, ContainerAttachWebsocketError, ContainerExecError, Error, SystemVersionError};
+use crate::api::internals::api_async::{ConfigApi, ContainerApi, DistributionApi, ExecApi, ImageApi, NetworkApi, NodeApi, PluginApi, SecretApi, ServiceApi, SessionApi, SwarmApi, SystemApi, TaskApi, VolumeApi};
+use crate::api::registry_auth::RegistryAuths;
+use crate::api::session::{AsyncAttachSession, AsyncExecSession, AsyncWebSocketSession, WebSocketSession};
+use crate::api::transport::{attach_request, attach_websocket_url, exec_start_request, TlsOptions, TransportError};
+use crate::api::version::ApiVersion;
+use crate::models::ExecConfig;
+use crate::params::{ContainerAttachParams, ContainerAttachWebsocketParams, ContainerExecParams};
+use bollard::{BollardRequest, Docker, API_DEFAULT_VERSION};
+use hyper_rustls::{FixedServerNameResolver, HttpsConnectorBuilder};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use std::io;
+use std::sync::{Arc, OnceLock};
+use url::Url;
+
+pub use crate::api::builder::ContainerClientBuilder;
+
+const CONNECTION_TIMEOUT: u64 = 120;
+
+/// Connects bollard to a TLS protected daemon with the same material as the synchronous client,
+/// including `TlsOptions::with_server_name` and `TlsOptions::with_verify`.
+pub fn connect_with_tls(address: &str, tls: &TlsOptions) -> Result<Docker, bollard::errors::Error> {
+    let address = address.replacen("tcp://", "https://", 1);
+    let url = Url::parse(&address)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid address {}: {}", address, error)))?;
+    let host = url.host_str()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("Address should have a host: {}", address)))?;
+    let server_name = tls.verified_name(host)?;
+    let mut http_connector = HttpConnector::new();
+    http_connector.enforce_http(false);
+    let connector = HttpsConnectorBuilder::new()
+        .with_tls_config(tls.client_config()?.as_ref().clone())
+        .https_only()
+        .with_server_name_resolver(FixedServerNameResolver::new(server_name))
+        .enable_http1()
+        .wrap_connector(http_connector);
+    let client = Client::builder(TokioExecutor::new())
+        .build(connector);
+    Docker::connect_with_custom_transport(
+        move |request: BollardRequest| {
+            let client = client.clone();
+            async move {
+                client.request(request).await
+                    .map_err(bollard::errors::Error::from)
+            }
+        },
+        Some(address.as_str()),
+        CONNECTION_TIMEOUT,
+        API_DEFAULT_VERSION
+    )
+}
+
+/// Async counterpart of `synchronous::ContainerClient`, with the same models and params.
+pub struct ContainerClient {
+    pub config: ConfigApi,
+    pub container: ContainerApi,
+    pub distribution: DistributionApi,
+    pub exec: ExecApi,
+    pub image: ImageApi,
+    pub network: NetworkApi,
+    pub node: NodeApi,
+    pub plugin: PluginApi,
+    pub secret: SecretApi,
+    pub service: ServiceApi,
+    pub session: SessionApi,
+    pub swarm: SwarmApi,
+    pub system: SystemApi,
+    pub task: TaskApi,
+    pub volume: VolumeApi,
+    configuration: Arc<Configuration>,
+    engine: OnceLock<Engine>,
+}
+
+impl ContainerClient {
+    pub(crate) fn new(configuration: Configuration) -> Self {
+        let configuration = Arc::new(configuration);
+        Self {
+            config: ConfigApi::new(configuration.clone()),
+            container: ContainerApi::new(configuration.clone()),
+            distribution: DistributionApi::new(configuration.clone()),
+            exec: ExecApi::new(configuration.clone()),
+            image: ImageApi::new(configuration.clone()),
+            network: NetworkApi::new(configuration.clone()),
+            node: NodeApi::new(configuration.clone()),
+            plugin: PluginApi::new(configuration.clone()),
+            secret: SecretApi::new(configuration.clone()),
+            service: ServiceApi::new(configuration.clone()),
+            session: SessionApi::new(configuration.clone()),
+            swarm: SwarmApi::new(configuration.clone()),
+            system: SystemApi::new(configuration.clone()),
+            task: TaskApi::new(configuration.clone()),
+            volume: VolumeApi::new(configuration.clone()),
+            configuration,
+            engine: OnceLock::new(),
+        }
+    }
+
+    /// Used by the clients of the secondary API versions, see `versions`.
+    #[cfg_attr(not(secondary_api_version), allow(dead_code))]
+    pub(crate) fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Engine flavour and version, queried from `/version` on first call.
+    pub async fn engine(&self) -> Result<&Engine, Error<SystemVersionError>> {
+        if let Some(engine) = self.engine.get() {
+            return Ok(engine);
+        }
+        let version = SystemApi::new(self.configuration.clone()).version().await?;
+        Ok(self.engine.get_or_init(|| Engine::from(&version)))
+    }
+
+    /// API version the requests are sent with, negotiated with the daemon on first use unless pinned.
+    pub async fn api_version(&self) -> Result<ApiVersion, TransportError> {
+        let base_path = Url::parse(&self.configuration.base_path)
+            .expect("Invalid base path");
+        self.configuration.client.api_version(&base_path).await
+    }
+
+    /// Registry credentials attached to pull, push, build and plugin requests, can be updated at
+    /// any time and shared with the bollard `ImageApi` and `ContainerApi`.
+    pub fn registry_auths(&self) -> &Arc<RegistryAuths> {
+        self.configuration.client.registry_auths()
+    }
+
+    /// Same as `synchronous::ContainerClient::attach`.
+    pub async fn attach(&self, params: ContainerAttachParams) -> Result<AsyncAttachSession, Error<ContainerAttachError>> {
+        let request = attach_request(&self.configuration.base_path, self.configuration.user_agent.as_ref(), &params);
+        let hijacked = self.configuration.client.hijack(request).await?;
+        Ok(AsyncAttachSession::new(params.id, self.configuration.clone(), hijacked))
+    }
+
+    /// Same as `synchronous::ContainerClient::attach_websocket`.
+    pub async fn attach_websocket(
+        &self,
+        params: ContainerAttachWebsocketParams
+    ) -> Result<AsyncWebSocketSession, Error<ContainerAttachWebsocketError>> {
+        let url = attach_websocket_url(&self.configuration.base_path, &params);
+        let socket = self.configuration.client.websocket(url).await?;
+        Ok(AsyncWebSocketSession::new(WebSocketSession::new(socket)))
+    }
+
+    /// Same as `synchronous::ContainerClient::exec_session`.
+    pub async fn exec_session(&self, id: &str, exec_config: ExecConfig) -> Result<AsyncExecSession, Error<ContainerExecError>> {
+        let tty = exec_config.tty.unwrap_or(false);
+        let response = ExecApi::new(self.configuration.clone()).container_exec(ContainerExecParams {
+            id: id.to_string(),
+            exec_config,
+        }).await?;
+        let request = exec_start_request(&self.configuration.base_path, self.configuration.user_agent.as_ref(), &response.id, tty);
+        let hijacked = self.configuration.client.hijack(request).await?;
+        Ok(AsyncExecSession::new(response.id, self.configuration.clone(), hijacked, tty))
+    }
+}
+
+impl Default for ContainerClient {
+    fn default() -> Self {
+        let configuration = Configuration::default();
+        Self::new(configuration)
+    }
+}
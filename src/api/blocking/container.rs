@@ -0,0 +1,82 @@
+use crate::api::asynchronous;
+use crate::api::asynchronous::container::ExecOutput;
+use crate::api::options::{CreateContainerOptionsBuilder, ExecOptions, StopContainerOptionsBuilder};
+use crate::api::registry_auth::RegistryAuths;
+use bollard::errors::Error;
+use bollard::models::{ContainerInspectResponse, ContainerState, ContainerSummary};
+use bollard::Docker;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+pub struct ContainerApi {
+    runtime: Arc<Runtime>,
+    api: asynchronous::container::ContainerApi
+}
+
+impl ContainerApi {
+    pub fn new(runtime: Arc<Runtime>, api: Arc<Docker>, registry_auths: Arc<RegistryAuths>) -> Self {
+        Self {
+            runtime,
+            api: asynchronous::container::ContainerApi::new(api, registry_auths),
+        }
+    }
+
+    pub fn get_all(&self) -> Result<Vec<ContainerSummary>, Error>{
+        self.runtime.block_on(self.api.get_all())
+    }
+
+    pub fn clean(&self, id: &String, state: &ContainerState) -> Result<(), Error> {
+        self.runtime.block_on(self.api.clean(id, state))
+    }
+
+    pub fn create(&self, options: &mut CreateContainerOptionsBuilder) -> Result<String, Error> {
+        self.runtime.block_on(self.api.create(options))
+    }
+
+    pub fn start(&self, id: &String) -> Result<(), Error> {
+        self.runtime.block_on(self.api.start(id))
+    }
+
+    pub fn stop(&self, id: &String, options: &mut StopContainerOptionsBuilder) -> Result<(), Error> {
+        self.runtime.block_on(self.api.stop(id, options))
+    }
+
+    pub fn restart(&self, id: &String) -> Result<(), Error> {
+        self.runtime.block_on(self.api.restart(id))
+    }
+
+    pub fn remove(&self, id: &String) -> Result<(), Error> {
+        self.runtime.block_on(self.api.remove(id))
+    }
+
+    pub fn inspect(&self, id: &String) -> Result<ContainerInspectResponse, Error> {
+        self.runtime.block_on(self.api.inspect(id))
+    }
+
+    pub fn upload(&self, id: &String, path: &str, archive: Bytes) -> Result<(), Error> {
+        self.runtime.block_on(self.api.upload(id, path, archive))
+    }
+
+    pub fn wait_healthy(&self, id: &String) -> Result<(), Error> {
+        self.runtime.block_on(self.api.wait_healthy(id))
+    }
+
+    pub fn exec(&self, id: &String, options: &ExecOptions) -> Result<ExecOutput, Error> {
+        self.runtime.block_on(self.api.exec(id, options))
+    }
+
+    pub fn top(&self, id: &String) -> Result<HashMap<String, String>, Error> {
+        self.runtime.block_on(self.api.top(id))
+    }
+}
+
+impl Clone for ContainerApi {
+    fn clone(&self) -> Self {
+        Self {
+            runtime: self.runtime.clone(),
+            api: self.api.clone(),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::api::asynchronous;
+use crate::api::pull::{PullError, PullOptions, PulledImage};
+use crate::api::registry_auth::RegistryAuths;
+use bollard::errors::Error;
+use bollard::models::ImageInspect;
+use bollard::Docker;
+use bytes::Bytes;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+pub struct ImageApi {
+    runtime: Arc<Runtime>,
+    api: asynchronous::image::ImageApi
+}
+
+impl ImageApi {
+    pub fn new(runtime: Arc<Runtime>, api: Arc<Docker>, registry_auths: Arc<RegistryAuths>) -> Self {
+        Self {
+            runtime,
+            api: asynchronous::image::ImageApi::new(api, registry_auths),
+        }
+    }
+
+    pub fn inspect(&self, image_name: &String) -> Result<ImageInspect, Error> {
+        self.runtime.block_on(self.api.inspect(image_name))
+    }
+
+    pub fn pull(&self, image: &String, options: &mut PullOptions) -> Result<PulledImage, PullError<Error>> {
+        self.runtime.block_on(self.api.pull(image, options))
+    }
+
+    pub fn context_build(&self, container_file_path: &PathBuf) -> (Bytes, String) {
+        self.api.context_build(container_file_path)
+    }
+
+    pub fn build(
+        &self,
+        container_file_path: &PathBuf,
+        image_name: &String,
+        image_tag: &String
+    ) {
+        self.runtime.block_on(self.api.build(container_file_path, image_name, image_tag))
+    }
+}
+
+impl Clone for ImageApi {
+    fn clone(&self) -> Self {
+        Self {
+            runtime: self.runtime.clone(),
+            api: self.api.clone(),
+        }
+    }
+}
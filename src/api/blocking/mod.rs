@@ -0,0 +1,6 @@
+//! Blocking facade over the bollard based APIs of `asynchronous`, driven by a Tokio runtime.
+//!
+//! These APIs call `Runtime::block_on`, so they must not be used from inside a Tokio task.
+
+pub mod container;
+pub mod image;
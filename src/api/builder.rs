@@ -0,0 +1,417 @@
+use crate::api::context::DockerContext;
+use crate::api::docker_config::DockerConfig;
+use crate::api::engine::discover_socket;
+use crate::api::internals::{api_async, api_sync};
+use crate::api::registry_auth::{RegistryAuth, RegistryAuths};
+use crate::api::transport::{AsyncTransport, Endpoint, SshEndpoint, TlsOptions, Transport, UpgradeTls};
+use crate::api::version::ApiVersion;
+use crate::api::{asynchronous, synchronous};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::{env, io};
+use url::Url;
+
+struct ResolvedEndpoint {
+    endpoint: Endpoint,
+    base_path: String,
+    server_name: Option<(String, SocketAddr)>,
+    upgrade_tls: UpgradeTls,
+}
+
+/// Builds the synchronous and asynchronous clients, both share the same endpoint resolution.
+#[derive(Default)]
+pub struct ContainerClientBuilder {
+    base_path: Option<String>,
+    user_agent: Option<String>,
+    http1_only: Option<bool>,
+    https_only: Option<bool>,
+    tls: Option<TlsOptions>,
+    api_version: Option<ApiVersion>,
+    registry_auths: Vec<(String, RegistryAuth)>,
+    docker_config: Option<DockerConfig>,
+}
+
+impl ContainerClientBuilder {
+    /// Resolves the endpoint the way the docker CLI does.
+    ///
+    /// `DOCKER_HOST` wins over contexts, TLS then comes from `DOCKER_TLS_VERIFY`/`DOCKER_CERT_PATH`.
+    /// Otherwise the context named by `DOCKER_CONTEXT` or by `currentContext` in `config.json`
+    /// is loaded from the context store. `DOCKER_API_VERSION` overrides the API version.
+    ///
+    /// As with the docker CLI, a context that cannot be loaded or an invalid `DOCKER_API_VERSION`
+    /// is an error rather than a fallback to the local socket.
+    pub fn from_env() -> io::Result<Self> {
+        Self::from_variables(|name| env::var(name).ok())
+    }
+
+    /// Same as `from_env`, with the environment variables given by `variable`.
+    fn from_variables(variable: impl Fn(&str) -> Option<String>) -> io::Result<Self> {
+        let variable = |name: &str| variable(name).filter(|value| value.is_empty() == false);
+        let directory = DockerConfig::directory_from(variable);
+        let mut builder = Self::default();
+        builder.with_user_agent(format!("container-flow-{}", env!("CARGO_PKG_VERSION")));
+        match variable("DOCKER_HOST") {
+            Some(host) => {
+                builder.with_base_path(host);
+                if let Some(tls) = TlsOptions::from_env() {
+                    builder.with_tls(tls);
+                }
+            }
+            None => {
+                let name = DockerContext::selected_name(&directory, variable("DOCKER_CONTEXT"))?;
+                let context = DockerContext::load_from(&directory, &name)?;
+                builder.with_base_path(context.host);
+                if let Some(tls) = context.tls {
+                    builder.with_tls(tls);
+                }
+            }
+        }
+        if let Some(api_version) = variable("DOCKER_API_VERSION") {
+            let api_version = api_version.parse::<ApiVersion>()
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, format!("DOCKER_API_VERSION: {}", error)))?;
+            builder.api_version = Some(api_version);
+        }
+        if let Ok(docker_config) = DockerConfig::load_from(&directory) {
+            builder.with_docker_config(docker_config);
+        }
+        Ok(builder)
+    }
+
+    /// Probes the Podman and Docker sockets and uses the first one accepting connections.
+    ///
+    /// The engine behind the socket is reported by `ContainerClient::engine`. Returns `None`
+    /// when no engine is running.
+    pub fn discover() -> Option<Self> {
+        let socket = discover_socket()?;
+        let mut builder = Self::default();
+        builder.with_user_agent(format!("container-flow-{}", env!("CARGO_PKG_VERSION")));
+        builder.with_unix_socket(socket);
+        Some(builder)
+    }
+
+    /// Accepts `http(s)://host[:port][/<version>]`, `tcp://host:port`, `unix:///path/to/socket`
+    /// and `ssh://[user@]host[:port]`.
+    pub fn with_base_path(&mut self, base_path: String) -> &mut Self {
+        self.base_path = Some(base_path);
+        self
+    }
+
+    /// Relative paths are resolved against the current directory.
+    pub fn with_unix_socket(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        let path = path.into();
+        let path = match path.is_absolute() {
+            true => path,
+            false => env::current_dir()
+                .expect("Unable to resolve the current directory")
+                .join(path),
+        };
+        self.base_path = Some(format!("unix://{}", path.display()));
+        self
+    }
+
+    /// Pins the API version, e.g. `1.43` or `v1.43`, instead of negotiating it with the daemon.
+    pub fn with_api_version(&mut self, api_version: impl Into<String>) -> &mut Self {
+        let api_version = api_version.into();
+        let api_version = api_version.parse::<ApiVersion>()
+            .unwrap_or_else(|error| panic!("{}", error));
+        self.api_version = Some(api_version);
+        self
+    }
+
+    pub fn with_user_agent(&mut self, user_agent: String) -> &mut Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    pub fn http1_only(&mut self, http1_only: bool) -> &mut Self {
+        self.http1_only = Some(http1_only);
+        self
+    }
+
+    pub fn with_https_only(&mut self, https_only: bool) -> &mut Self {
+        self.https_only = Some(https_only);
+        self
+    }
+
+    /// Enables TLS, `tcp://` base paths are then reached over `https://`.
+    pub fn with_tls(&mut self, tls: TlsOptions) -> &mut Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Credentials attached to the pull, push, build and plugin requests sent to `registry`,
+    /// e.g. `ghcr.io` or `localhost:5000`.
+    pub fn with_registry_auth(&mut self, registry: impl Into<String>, auth: RegistryAuth) -> &mut Self {
+        self.registry_auths.push((registry.into(), auth));
+        self
+    }
+
+    /// Falls back to the credentials of `config.json` and its credential helpers for the
+    /// registries without explicit credentials.
+    pub fn with_docker_config(&mut self, docker_config: DockerConfig) -> &mut Self {
+        self.docker_config = Some(docker_config);
+        self
+    }
+
+    pub fn build(&self) -> synchronous::ContainerClient {
+        self.try_build()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `build`, returns the configuration errors instead of panicking, e.g. an
+    /// invalid `ssh://` destination.
+    pub fn try_build(&self) -> Result<synchronous::ContainerClient, String> {
+        let resolved = self.resolve()?;
+        let mut client_builder = reqwest::blocking::ClientBuilder::new();
+        if let Some(_) = self.http1_only {
+            client_builder = client_builder.http1_only();
+        }
+        if let Some(_) = self.https_only {
+            client_builder = client_builder.https_only(true);
+        }
+        if let Some(tls) = self.tls.as_ref() {
+            client_builder = tls.apply(client_builder)
+                .map_err(|error| format!("Unable to load TLS certificates: {}", error))?;
+        }
+        if let Some((server_name, address)) = resolved.server_name {
+            client_builder = client_builder.resolve(&server_name, address);
+        }
+        let upgrade_tls = resolved.upgrade_tls;
+        let mut transport = Transport::with_api_version(
+            resolved.endpoint,
+            client_builder
+                .build()
+                .map_err(|error| format!("Unable to build HTTP client: {}", error))?,
+            self.api_version.unwrap_or(ApiVersion::compiled()),
+            self.api_version.is_none()
+        );
+        transport.with_registry_auths(self.registry_auths())
+            .with_upgrade_tls(upgrade_tls);
+        let configuration = api_sync::Configuration {
+            base_path: resolved.base_path,
+            user_agent: self.user_agent.clone(),
+            client: transport,
+        };
+        Ok(synchronous::ContainerClient::new(configuration))
+    }
+
+    pub fn build_async(&self) -> asynchronous::ContainerClient {
+        self.try_build_async()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `build_async`, returns the configuration errors instead of panicking.
+    pub fn try_build_async(&self) -> Result<asynchronous::ContainerClient, String> {
+        let resolved = self.resolve()?;
+        let mut client_builder = reqwest::ClientBuilder::new();
+        if let Some(_) = self.http1_only {
+            client_builder = client_builder.http1_only();
+        }
+        if let Some(_) = self.https_only {
+            client_builder = client_builder.https_only(true);
+        }
+        if let Some(tls) = self.tls.as_ref() {
+            client_builder = tls.apply(client_builder)
+                .map_err(|error| format!("Unable to load TLS certificates: {}", error))?;
+        }
+        if let Some((server_name, address)) = resolved.server_name {
+            client_builder = client_builder.resolve(&server_name, address);
+        }
+        let upgrade_tls = resolved.upgrade_tls;
+        let mut transport = AsyncTransport::with_api_version(
+            resolved.endpoint,
+            client_builder
+                .build()
+                .map_err(|error| format!("Unable to build HTTP client: {}", error))?,
+            self.api_version.unwrap_or(ApiVersion::compiled()),
+            self.api_version.is_none()
+        );
+        transport.with_registry_auths(self.registry_auths())
+            .with_upgrade_tls(upgrade_tls);
+        let configuration = api_async::Configuration {
+            base_path: resolved.base_path,
+            user_agent: self.user_agent.clone(),
+            client: transport,
+        };
+        Ok(asynchronous::ContainerClient::new(configuration))
+    }
+
+    fn registry_auths(&self) -> Arc<RegistryAuths> {
+        let registry_auths = RegistryAuths::new();
+        for (registry, auth) in self.registry_auths.iter() {
+            registry_auths.insert(registry, auth.clone());
+        }
+        if let Some(docker_config) = self.docker_config.as_ref() {
+            registry_auths.use_docker_config(docker_config.clone());
+        }
+        Arc::new(registry_auths)
+    }
+
+    fn resolve(&self) -> Result<ResolvedEndpoint, String> {
+        let base_path = self.base_path.as_ref()
+            .ok_or("Base path should be provided.")?;
+        if self.user_agent.is_none() {
+            return Err("User agent should be provided.".to_string());
+        }
+        let (endpoint, mut base_path) = self.resolve_base_path(base_path)?;
+        let mut server_name = None;
+        if let Some(name) = self.tls.as_ref().and_then(|tls| tls.server_name()) {
+            let (address, path) = Self::override_server_name(&base_path, name)?;
+            server_name = Some((name.clone(), address));
+            base_path = path;
+        }
+        let upgrade_tls = UpgradeTls {
+            options: self.tls.clone().unwrap_or_default(),
+            address: server_name.as_ref().map(|(_, address)| *address),
+        };
+        Ok(ResolvedEndpoint {
+            endpoint,
+            base_path,
+            server_name,
+            upgrade_tls,
+        })
+    }
+
+    fn resolve_base_path(&self, base_path: &String) -> Result<(Endpoint, String), String> {
+        let url = Url::parse(base_path)
+            .map_err(|error| format!("Invalid base path {}: {}", base_path, error))?;
+        let api_version = self.api_version.unwrap_or(ApiVersion::compiled()).prefix();
+        let resolved = match url.scheme() {
+            "unix" => (
+                Endpoint::Unix(Self::socket_path(&url)),
+                format!("http://localhost/{}", api_version)
+            ),
+            "ssh" => (
+                Endpoint::Ssh(SshEndpoint::parse(base_path)?),
+                format!("http://localhost/{}", api_version)
+            ),
+            "tcp" => {
+                let host = url.host_str()
+                    .ok_or(format!("Base path should have a host: {}", base_path))?;
+                let scheme = match self.tls {
+                    Some(_) => "https",
+                    None => "http",
+                };
+                (
+                    Endpoint::Tcp,
+                    format!("{}://{}/{}", scheme, Self::authority(host, &url), api_version)
+                )
+            }
+            "http" | "https" => {
+                let base_path = base_path.trim_end_matches('/').to_string();
+                match url.path() {
+                    "" | "/" => (Endpoint::Tcp, format!("{}/{}", base_path, api_version)),
+                    _ => (Endpoint::Tcp, base_path),
+                }
+            }
+            scheme => return Err(format!("Unsupported base path scheme: {}", scheme)),
+        };
+        Ok(resolved)
+    }
+
+    /// Resolves the address of the base path host, so the daemon certificate can be verified
+    /// against the server name while the connection still reaches the real host.
+    fn override_server_name(base_path: &String, server_name: &String) -> Result<(SocketAddr, String), String> {
+        let mut url = Url::parse(base_path)
+            .map_err(|error| format!("Invalid base path {}: {}", base_path, error))?;
+        let host = url.host_str()
+            .ok_or(format!("Base path should have a host: {}", base_path))?;
+        let port = url.port_or_known_default()
+            .ok_or(format!("Base path should have a port: {}", base_path))?;
+        let address = (host, port)
+            .to_socket_addrs()
+            .map_err(|error| format!("Unable to resolve {}: {}", base_path, error))?
+            .next()
+            .ok_or(format!("No address found for {}", base_path))?;
+        url.set_host(Some(server_name))
+            .map_err(|error| format!("Invalid server name {}: {}", server_name, error))?;
+        Ok((address, url.as_str().trim_end_matches('/').to_string()))
+    }
+
+    /// `unix://docker.sock` puts the relative path in the host, it is resolved like `with_unix_socket` does.
+    fn socket_path(url: &Url) -> PathBuf {
+        let path = match url.host_str() {
+            Some(host) => PathBuf::from(format!("{}{}", host, url.path())),
+            None => PathBuf::from(url.path()),
+        };
+        match path.is_absolute() {
+            true => path,
+            false => env::current_dir()
+                .expect("Unable to resolve the current directory")
+                .join(path),
+        }
+    }
+
+    fn authority(host: &str, url: &Url) -> String {
+        match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testing::{tls_material, write_context, TempDirectory};
+    use std::collections::HashMap;
+    use std::fs;
+
+    fn from_variables(variables: &[(&str, &str)]) -> io::Result<ContainerClientBuilder> {
+        let variables = variables.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>();
+        ContainerClientBuilder::from_variables(|name| variables.get(name).cloned())
+    }
+
+    #[test]
+    fn resolves_the_context_of_the_environment() {
+        let directory = TempDirectory::new("environment");
+        let config = directory.path().to_str().unwrap();
+        write_context(directory.path(), "remote", r#"{"Host":"tcp://10.0.0.2:2376","SkipTLSVerify":false}"#, &["ca.pem"]);
+        fs::write(directory.path().join("config.json"), r#"{"currentContext":"remote"}"#).unwrap();
+
+        let builder = from_variables(&[("DOCKER_CONFIG", config)]).unwrap();
+        assert_eq!(builder.base_path.as_deref(), Some("tcp://10.0.0.2:2376"));
+        assert!(builder.tls.is_some());
+
+        let builder = from_variables(&[("DOCKER_CONFIG", config), ("DOCKER_CONTEXT", "default"), ("DOCKER_API_VERSION", "1.43")]).unwrap();
+        assert_eq!(builder.base_path.as_deref(), Some("unix:///var/run/docker.sock"));
+        assert_eq!(builder.api_version, Some(ApiVersion::new(1, 43)));
+
+        let builder = from_variables(&[("DOCKER_CONFIG", config), ("DOCKER_HOST", "tcp://10.0.0.3:2375")]).unwrap();
+        assert_eq!(builder.base_path.as_deref(), Some("tcp://10.0.0.3:2375"));
+    }
+
+    #[test]
+    fn fails_on_an_unloadable_context() {
+        let directory = TempDirectory::new("environment");
+        let config = directory.path().to_str().unwrap();
+        let error = from_variables(&[("DOCKER_CONFIG", config), ("DOCKER_CONTEXT", "missing")]).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        fs::write(directory.path().join("config.json"), r#"{"currentContext":"missing"}"#).unwrap();
+        assert!(from_variables(&[("DOCKER_CONFIG", config)]).is_err());
+    }
+
+    #[test]
+    fn fails_on_an_invalid_api_version() {
+        let error = from_variables(&[("DOCKER_HOST", "tcp://10.0.0.3:2375"), ("DOCKER_API_VERSION", "latest")]).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("latest"));
+    }
+
+    #[test]
+    fn reports_an_unresolvable_host_of_a_server_name_override() {
+        let mut tls = TlsOptions::from_cert_path(tls_material());
+        tls.with_server_name("docker.example.test");
+        let mut builder = ContainerClientBuilder::default();
+        builder.with_user_agent("test".to_string())
+            .with_base_path("tcp://docker.invalid:2376".to_string())
+            .with_tls(tls);
+        let error = builder.try_build().err().unwrap();
+        assert!(error.starts_with("Unable to resolve"), "{}", error);
+        assert!(builder.try_build_async().is_err());
+    }
+}
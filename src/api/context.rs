@@ -0,0 +1,178 @@
+use crate::api::docker_config::DockerConfig;
+use crate::api::transport::TlsOptions;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::{env, fs, io};
+
+pub const DEFAULT_CONTEXT: &str = "default";
+pub const DEFAULT_HOST: &str = "unix:///var/run/docker.sock";
+
+#[derive(Debug, Clone, Deserialize)]
+struct ContextMetadata {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Endpoints", default)]
+    endpoints: HashMap<String, ContextEndpoint>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ContextEndpoint {
+    #[serde(rename = "Host")]
+    host: Option<String>,
+    #[serde(rename = "SkipTLSVerify", default)]
+    skip_tls_verify: bool,
+}
+
+/// Docker endpoint as stored by `docker context create`.
+#[derive(Debug, Clone)]
+pub struct DockerContext {
+    pub name: String,
+    pub host: String,
+    pub tls: Option<TlsOptions>,
+}
+
+impl DockerContext {
+    /// Name of the context selected by `DOCKER_CONTEXT` or by `currentContext` in `config.json`.
+    pub fn current_name() -> io::Result<String> {
+        let name = env::var("DOCKER_CONTEXT").ok()
+            .filter(|name| name.is_empty() == false);
+        Self::selected_name(&DockerConfig::directory(), name)
+    }
+
+    /// `name` if any, else `currentContext` of the `config.json` of `directory`.
+    pub(crate) fn selected_name(directory: &Path, name: Option<String>) -> io::Result<String> {
+        if let Some(name) = name {
+            return Ok(name);
+        }
+        let config = DockerConfig::load_from(directory)?;
+        Ok(config.current_context.unwrap_or(DEFAULT_CONTEXT.to_string()))
+    }
+
+    /// Loads the context from the `contexts/meta` store, `default` resolves to the local socket.
+    pub fn load(name: &str) -> io::Result<Self> {
+        Self::load_from(&DockerConfig::directory(), name)
+    }
+
+    /// Same as `load`, from the store of the configuration `directory`.
+    ///
+    /// As with the docker CLI, the TLS material is made of the files of the store, e.g. a
+    /// `ca.pem` alone to verify the daemon without presenting a client certificate.
+    pub(crate) fn load_from(directory: &Path, name: &str) -> io::Result<Self> {
+        if name == DEFAULT_CONTEXT {
+            return Ok(Self {
+                name: name.to_string(),
+                host: DEFAULT_HOST.to_string(),
+                tls: None,
+            });
+        }
+        let contexts_path = directory.join("contexts");
+        let digest = hex::encode(Sha256::digest(name.as_bytes()));
+        let metadata_path = contexts_path.join("meta").join(&digest).join("meta.json");
+        let content = fs::read_to_string(&metadata_path)
+            .map_err(|error| io::Error::new(
+                error.kind(),
+                format!("Context {} not found in {}: {}", name, metadata_path.display(), error)
+            ))?;
+        let metadata: ContextMetadata = serde_json::from_str(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let endpoint = metadata.endpoints.get("docker")
+            .ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Context {} has no docker endpoint", name)
+            ))?;
+        let host = endpoint.host.clone().unwrap_or(DEFAULT_HOST.to_string());
+        let tls_path = contexts_path.join("tls").join(&digest).join("docker");
+        let tls = match tls_path.exists() || endpoint.skip_tls_verify {
+            true => {
+                let mut tls = TlsOptions::default();
+                tls.with_verify(endpoint.skip_tls_verify == false);
+                if tls_path.join("ca.pem").exists() {
+                    tls.with_ca_path(tls_path.join("ca.pem"));
+                }
+                if tls_path.join("cert.pem").exists() {
+                    tls.with_cert_path(tls_path.join("cert.pem"));
+                }
+                if tls_path.join("key.pem").exists() {
+                    tls.with_key_path(tls_path.join("key.pem"));
+                }
+                Some(tls)
+            }
+            false => None,
+        };
+        Ok(Self {
+            name: metadata.name,
+            host,
+            tls,
+        })
+    }
+
+    pub fn current() -> io::Result<Self> {
+        Self::load(&Self::current_name()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testing::{write_context, TempDirectory};
+
+    #[test]
+    fn parses_the_context_metadata() {
+        let directory = TempDirectory::new("contexts");
+        write_context(directory.path(), "remote", r#"{"Host":"tcp://10.0.0.2:2376","SkipTLSVerify":false}"#, &[]);
+        let context = DockerContext::load_from(directory.path(), "remote").unwrap();
+        assert_eq!(context.name, "remote");
+        assert_eq!(context.host, "tcp://10.0.0.2:2376");
+        assert!(context.tls.is_none());
+
+        write_context(directory.path(), "local", r#"{"SkipTLSVerify":false}"#, &[]);
+        assert_eq!(DockerContext::load_from(directory.path(), "local").unwrap().host, DEFAULT_HOST);
+
+        write_context(directory.path(), "kubernetes", "null", &[]);
+        let error = DockerContext::load_from(directory.path(), "kubernetes").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let error = DockerContext::load_from(directory.path(), "missing").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("Context missing not found"));
+
+        let context = DockerContext::load_from(directory.path(), DEFAULT_CONTEXT).unwrap();
+        assert_eq!(context.host, DEFAULT_HOST);
+    }
+
+    #[test]
+    fn selects_the_current_context_of_the_configuration() {
+        let directory = TempDirectory::new("contexts");
+        assert_eq!(DockerContext::selected_name(directory.path(), None).unwrap(), DEFAULT_CONTEXT);
+        fs::write(directory.path().join("config.json"), r#"{"currentContext":"remote"}"#).unwrap();
+        assert_eq!(DockerContext::selected_name(directory.path(), None).unwrap(), "remote");
+        assert_eq!(DockerContext::selected_name(directory.path(), Some("ci".to_string())).unwrap(), "ci");
+    }
+
+    #[test]
+    fn uses_the_tls_files_of_the_store() {
+        let directory = TempDirectory::new("contexts");
+        let endpoint = r#"{"Host":"tcp://10.0.0.2:2376","SkipTLSVerify":false}"#;
+        write_context(directory.path(), "mutual", endpoint, &["ca.pem", "cert.pem", "key.pem"]);
+        let tls = DockerContext::load_from(directory.path(), "mutual").unwrap().tls.unwrap();
+        assert!(tls.verify());
+        assert!(tls.cert_path().is_some());
+        assert!(tls.client_config().is_ok());
+
+        write_context(directory.path(), "server-only", endpoint, &["ca.pem"]);
+        let tls = DockerContext::load_from(directory.path(), "server-only").unwrap().tls.unwrap();
+        assert!(tls.verify());
+        assert!(tls.ca_path().is_some());
+        assert_eq!(tls.cert_path(), None);
+        assert_eq!(tls.key_path(), None);
+        assert!(tls.client_config().is_ok());
+
+        let endpoint = r#"{"Host":"tcp://10.0.0.2:2376","SkipTLSVerify":true}"#;
+        write_context(directory.path(), "insecure", endpoint, &[]);
+        let tls = DockerContext::load_from(directory.path(), "insecure").unwrap().tls.unwrap();
+        assert!(tls.verify() == false);
+        assert_eq!(tls.ca_path(), None);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::api::registry_auth::{normalize_registry, server_address, RegistryAuth};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::{env, fs, io};
+
+const CREDENTIAL_HELPER_PREFIX: &str = "docker-credential-";
+const CREDENTIAL_HELPER_TOKEN_USERNAME: &str = "<token>";
+
+/// Entry of the `auths` section, `auth` is the base64 encoded `username:password`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuthEntry {
+    pub auth: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub email: Option<String>,
+    #[serde(rename = "identitytoken")]
+    pub identity_token: Option<String>,
+    #[serde(rename = "registrytoken")]
+    pub registry_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HelperCredentials {
+    #[serde(rename = "Username")]
+    username: String,
+    #[serde(rename = "Secret")]
+    secret: String,
+}
+
+/// Subset of the docker CLI `config.json` understood by the client.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DockerConfig {
+    #[serde(rename = "currentContext")]
+    pub current_context: Option<String>,
+    #[serde(default)]
+    pub auths: HashMap<String, AuthEntry>,
+    #[serde(rename = "credsStore")]
+    pub creds_store: Option<String>,
+    #[serde(rename = "credHelpers", default)]
+    pub cred_helpers: HashMap<String, String>,
+    /// Directory of the `docker-credential-*` programs, looked up in `PATH` when unset.
+    #[serde(skip)]
+    pub helper_directory: Option<PathBuf>,
+}
+
+impl DockerConfig {
+    /// Docker CLI configuration directory, `DOCKER_CONFIG` or `~/.docker`.
+    pub fn directory() -> PathBuf {
+        Self::directory_from(|name| env::var(name).ok())
+    }
+
+    /// Same as `directory`, with the environment variables given by `variable`.
+    pub(crate) fn directory_from(variable: impl Fn(&str) -> Option<String>) -> PathBuf {
+        match variable("DOCKER_CONFIG") {
+            Some(value) if value.is_empty() == false => PathBuf::from(value),
+            _ => PathBuf::from(variable("HOME").unwrap_or_default()).join(".docker"),
+        }
+    }
+
+    /// Loads `config.json` from the configuration directory, a missing file yields the default configuration.
+    pub fn load() -> io::Result<Self> {
+        Self::load_from(&Self::directory())
+    }
+
+    pub(crate) fn load_from(directory: &Path) -> io::Result<Self> {
+        let path = directory.join("config.json");
+        if path.exists() == false {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Credentials of `registry` resolved the way the docker CLI does: the registry entry of
+    /// `credHelpers` first, then `credsStore`, then the `auths` section.
+    pub fn credentials(&self, registry: &str) -> io::Result<Option<RegistryAuth>> {
+        let registry = normalize_registry(registry);
+        let helper = self.cred_helpers.iter()
+            .find(|(key, _)| normalize_registry(key) == registry)
+            .map(|(_, helper)| helper)
+            .or(self.creds_store.as_ref());
+        if let Some(helper) = helper {
+            return self.helper_credentials(helper, &server_address(&registry));
+        }
+        let entry = self.auths.iter()
+            .find(|(key, _)| normalize_registry(key) == registry)
+            .map(|(_, entry)| entry);
+        match entry {
+            Some(entry) => Self::entry_credentials(entry, &registry).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Credentials of every registry known to the configuration, by registry host.
+    pub fn all_credentials(&self) -> io::Result<BTreeMap<String, RegistryAuth>> {
+        let mut registries = self.auths.keys()
+            .chain(self.cred_helpers.keys())
+            .map(|registry| normalize_registry(registry))
+            .collect::<Vec<_>>();
+        if let Some(helper) = self.creds_store.as_ref() {
+            let listed = self.run_helper(helper, "list", "")?;
+            let listed = serde_json::from_slice::<HashMap<String, String>>(&listed)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            registries.extend(listed.keys().map(|registry| normalize_registry(registry)));
+        }
+        registries.sort();
+        registries.dedup();
+        let mut credentials = BTreeMap::new();
+        for registry in registries {
+            if let Some(auth) = self.credentials(&registry)? {
+                credentials.insert(registry, auth);
+            }
+        }
+        Ok(credentials)
+    }
+
+    fn entry_credentials(entry: &AuthEntry, registry: &str) -> io::Result<RegistryAuth> {
+        let mut auth = RegistryAuth {
+            username: entry.username.clone(),
+            password: entry.password.clone(),
+            email: entry.email.clone(),
+            server_address: Some(server_address(registry)),
+            identity_token: entry.identity_token.clone(),
+            registry_token: entry.registry_token.clone(),
+        };
+        if let Some(encoded) = entry.auth.as_ref().filter(|value| value.is_empty() == false) {
+            let decoded = STANDARD.decode(encoded.trim())
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            let decoded = String::from_utf8(decoded)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            let (username, password) = decoded.split_once(':')
+                .ok_or(io::Error::new(io::ErrorKind::InvalidData, format!("Invalid auth of {}", registry)))?;
+            auth.username = Some(username.to_string());
+            auth.password = Some(password.to_string());
+        }
+        Ok(auth)
+    }
+
+    /// Runs `docker-credential-<helper> get`, a registry unknown to the helper yields `None`.
+    fn helper_credentials(&self, helper: &str, server_address: &str) -> io::Result<Option<RegistryAuth>> {
+        let output = match self.run_helper(helper, "get", server_address) {
+            Ok(value) => value,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let credentials = serde_json::from_slice::<HelperCredentials>(&output)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let mut auth = match credentials.username == CREDENTIAL_HELPER_TOKEN_USERNAME {
+            true => RegistryAuth::with_identity_token(credentials.secret),
+            false => RegistryAuth::with_password(credentials.username, credentials.secret),
+        };
+        auth.with_server_address(server_address);
+        Ok(Some(auth))
+    }
+
+    /// Missing credentials are reported as `NotFound`, like a missing helper program.
+    fn run_helper(&self, helper: &str, action: &str, input: &str) -> io::Result<Vec<u8>> {
+        let program = format!("{}{}", CREDENTIAL_HELPER_PREFIX, helper);
+        let path = match self.helper_directory.as_ref() {
+            Some(directory) => directory.join(&program),
+            None => PathBuf::from(&program),
+        };
+        let mut child = Command::new(path)
+            .arg(action)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(input.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if output.status.success() {
+            return Ok(output.stdout);
+        }
+        let message = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let kind = match message.contains("credentials not found") {
+            true => io::ErrorKind::NotFound,
+            false => io::ErrorKind::Other,
+        };
+        Err(io::Error::new(kind, format!("{} {} failed: {}", program, action, message)))
+    }
+}
@@ -0,0 +1,250 @@
+use std::path::Path;
+use std::{fs, io};
+
+pub const DOCKERIGNORE_FILENAME: &str = ".dockerignore";
+
+struct Pattern {
+    /// Cleaned pattern, e.g. `src/**/*.rs`.
+    glob: String,
+    negated: bool,
+}
+
+/// Exclusion patterns of a build context, with the semantics of the docker CLI.
+///
+/// Patterns are globs relative to the context root where `*` and `?` do not match `/` and
+/// `**` matches any number of directories, e.g. `**/*.go`, also inside a component, e.g.
+/// `**.go`. A pattern also excludes everything below the directories it matches. Patterns starting with `!` re-include paths, the last matching
+/// pattern wins.
+pub struct DockerIgnore {
+    patterns: Vec<Pattern>,
+}
+
+impl DockerIgnore {
+    /// Reads `<Dockerfile>.dockerignore` next to the Dockerfile if present, otherwise
+    /// `.dockerignore` at the context root. Missing files exclude nothing.
+    pub fn load(context_path: &Path, dockerfile_path: &Path) -> io::Result<Self> {
+        let dockerfile_ignore = dockerfile_path.with_file_name(format!(
+            "{}{}",
+            dockerfile_path.file_name().unwrap_or_default().to_string_lossy(),
+            DOCKERIGNORE_FILENAME
+        ));
+        for path in [dockerfile_ignore, context_path.join(DOCKERIGNORE_FILENAME)] {
+            if path.is_file() {
+                return Ok(Self::parse(&fs::read_to_string(&path)?));
+            }
+        }
+        Ok(Self::parse(""))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let patterns = content.lines()
+            .map(|line| line.trim())
+            .filter(|line| line.is_empty() == false && line.starts_with('#') == false)
+            .filter_map(|line| {
+                let (negated, line) = match line.strip_prefix('!') {
+                    Some(line) => (true, line.trim()),
+                    None => (false, line),
+                };
+                let components = Self::clean(line);
+                match components.is_empty() {
+                    true => None,
+                    false => Some(Pattern {
+                        glob: components.join("/"),
+                        negated,
+                    }),
+                }
+            })
+            .collect();
+        Self {
+            patterns
+        }
+    }
+
+    /// Whether `path`, relative to the context root and separated by `/`, is excluded.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let components = Self::clean(path);
+        let paths = (1..=components.len())
+            .map(|length| components[..length].join("/"))
+            .collect::<Vec<_>>();
+        let mut excluded = false;
+        for pattern in self.patterns.iter() {
+            let matched = paths.iter()
+                .any(|path| Self::match_glob(pattern.glob.as_bytes(), path.as_bytes()));
+            if matched {
+                excluded = pattern.negated == false;
+            }
+        }
+        excluded
+    }
+
+    /// Whether a negated pattern may re-include paths below an excluded directory.
+    pub fn has_exceptions(&self) -> bool {
+        self.patterns.iter().any(|pattern| pattern.negated)
+    }
+
+    /// Components of a path without leading `/`, `.` and resolved `..`, like `filepath.Clean`.
+    fn clean(path: &str) -> Vec<String> {
+        let mut components: Vec<String> = Vec::new();
+        for component in path.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    components.pop();
+                }
+                component => components.push(component.to_string()),
+            }
+        }
+        components
+    }
+
+    /// Glob over a whole path, `**` matches across `/` and `**/` also matches no directory.
+    fn match_glob(pattern: &[u8], name: &[u8]) -> bool {
+        match pattern.first() {
+            None => name.is_empty(),
+            Some(b'*') if pattern.get(1) == Some(&b'*') => {
+                let pattern = &pattern[2..];
+                if pattern.first() == Some(&b'/') && Self::match_glob(&pattern[1..], name) {
+                    return true;
+                }
+                (0..=name.len()).any(|index| Self::match_glob(pattern, &name[index..]))
+            }
+            Some(b'*') => {
+                let pattern = &pattern[1..];
+                let length = name.iter().position(|character| *character == b'/').unwrap_or(name.len());
+                (0..=length).any(|index| Self::match_glob(pattern, &name[index..]))
+            }
+            Some(b'?') => {
+                name.first().is_some_and(|character| *character != b'/')
+                    && Self::match_glob(&pattern[1..], &name[1..])
+            }
+            Some(b'[') => {
+                let character = match name.first() {
+                    Some(value) if *value != b'/' => *value,
+                    _ => return false,
+                };
+                match Self::match_class(&pattern[1..], character) {
+                    Some((true, rest)) => Self::match_glob(rest, &name[1..]),
+                    _ => false,
+                }
+            }
+            Some(b'\\') if pattern.len() > 1 => {
+                name.first() == Some(&pattern[1]) && Self::match_glob(&pattern[2..], &name[1..])
+            }
+            Some(character) => {
+                name.first() == Some(character) && Self::match_glob(&pattern[1..], &name[1..])
+            }
+        }
+    }
+
+    /// Matches a `[...]` class, returns the outcome and the pattern after the class, `None`
+    /// when the class is not closed.
+    fn match_class(pattern: &[u8], character: u8) -> Option<(bool, &[u8])> {
+        let (negated, mut pattern) = match pattern.first() {
+            Some(b'^') | Some(b'!') => (true, &pattern[1..]),
+            _ => (false, pattern),
+        };
+        let mut matched = false;
+        let mut first = true;
+        loop {
+            let mut low = *pattern.first()?;
+            if low == b']' && first == false {
+                return Some((matched != negated, &pattern[1..]));
+            }
+            first = false;
+            if low == b'\\' {
+                pattern = &pattern[1..];
+                low = *pattern.first()?;
+            }
+            pattern = &pattern[1..];
+            let mut high = low;
+            if pattern.first() == Some(&b'-') && pattern.get(1).is_some_and(|value| *value != b']') {
+                high = pattern[1];
+                if high == b'\\' {
+                    high = *pattern.get(2)?;
+                    pattern = &pattern[1..];
+                }
+                pattern = &pattern[2..];
+            }
+            if low <= character && character <= high {
+                matched = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn excludes_directories_and_their_content() {
+        let ignore = DockerIgnore::parse("# comment\ntarget\n./docs/\n");
+        assert!(ignore.is_excluded("target"));
+        assert!(ignore.is_excluded("target/debug/app"));
+        assert!(ignore.is_excluded("docs/index.md"));
+        assert!(ignore.is_excluded("src/target") == false);
+        assert!(ignore.is_excluded("src/main.rs") == false);
+    }
+
+    #[test]
+    fn negation_re_includes_paths_and_the_last_match_wins() {
+        let ignore = DockerIgnore::parse("*.md\n!README.md\nREADME*.md\n!README-fr.md\n");
+        assert!(ignore.has_exceptions());
+        assert!(ignore.is_excluded("CHANGELOG.md"));
+        assert!(ignore.is_excluded("README.md"));
+        assert!(ignore.is_excluded("README-fr.md") == false);
+        assert!(ignore.is_excluded("docs/guide.md") == false);
+    }
+
+    #[test]
+    fn double_star_matches_any_number_of_directories() {
+        let ignore = DockerIgnore::parse("**/*.log\nsrc/**/generated\n**.tmp\n");
+        assert!(ignore.is_excluded("app.log"));
+        assert!(ignore.is_excluded("var/log/app.log"));
+        assert!(ignore.is_excluded("src/generated/mod.rs"));
+        assert!(ignore.is_excluded("src/api/v1/generated"));
+        assert!(ignore.is_excluded("cache.tmp"));
+        assert!(ignore.is_excluded("build/cache/data.tmp"));
+        assert!(ignore.is_excluded("src/lib.rs") == false);
+        assert!(ignore.is_excluded("logs/app.txt") == false);
+    }
+
+    #[test]
+    fn single_star_and_question_mark_stay_within_a_component() {
+        let ignore = DockerIgnore::parse("*/temp*\nlog?.txt\n");
+        assert!(ignore.is_excluded("src/temporary"));
+        assert!(ignore.is_excluded("src/deep/temporary") == false);
+        assert!(ignore.is_excluded("log1.txt"));
+        assert!(ignore.is_excluded("log/.txt") == false);
+    }
+
+    #[test]
+    fn classes_match_ranges_negations_and_escapes() {
+        let ignore = DockerIgnore::parse("file[0-9].txt\nout[!a-c]\nliteral\\*\n");
+        assert!(ignore.is_excluded("file7.txt"));
+        assert!(ignore.is_excluded("filex.txt") == false);
+        assert!(ignore.is_excluded("outd"));
+        assert!(ignore.is_excluded("outb") == false);
+        assert!(ignore.is_excluded("literal*"));
+        assert!(ignore.is_excluded("literally") == false);
+    }
+
+    #[test]
+    fn dockerfile_ignore_takes_precedence_over_the_context_ignore() {
+        let context = env::temp_dir().join(format!("container-flow-dockerignore-{}", process::id()));
+        let dockerfile = context.join("build").join("Dockerfile");
+        fs::create_dir_all(dockerfile.parent().unwrap()).unwrap();
+        fs::write(context.join(DOCKERIGNORE_FILENAME), "context-only\n").unwrap();
+
+        let ignore = DockerIgnore::load(&context, &dockerfile).unwrap();
+        assert!(ignore.is_excluded("context-only"));
+
+        fs::write(context.join("build").join("Dockerfile.dockerignore"), "dockerfile-only\n").unwrap();
+        let ignore = DockerIgnore::load(&context, &dockerfile).unwrap();
+        assert!(ignore.is_excluded("dockerfile-only"));
+        assert!(ignore.is_excluded("context-only") == false);
+
+        fs::remove_dir_all(&context).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+use crate::models::SystemVersion;
+use std::env;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineFlavor {
+    Docker,
+    Podman,
+}
+
+/// Container engine behind the endpoint, as reported by `/version`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Engine {
+    pub flavor: EngineFlavor,
+    pub version: Option<String>,
+    pub api_version: Option<String>,
+}
+
+impl From<&SystemVersion> for Engine {
+    fn from(value: &SystemVersion) -> Self {
+        let is_podman = |name: &String| name.to_lowercase().contains("podman");
+        let podman_component = value.components.as_ref()
+            .is_some_and(|components| components.iter().any(|component| is_podman(&component.name)));
+        let podman_platform = value.platform.as_ref()
+            .is_some_and(|platform| is_podman(&platform.name));
+        let flavor = match podman_component || podman_platform {
+            true => EngineFlavor::Podman,
+            false => EngineFlavor::Docker,
+        };
+        Self {
+            flavor,
+            version: value.version.clone(),
+            api_version: value.api_version.clone(),
+        }
+    }
+}
+
+/// Sockets probed by discovery, rootless Podman first, then rootful Podman and Docker.
+pub fn socket_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Ok(runtime_directory) = env::var("XDG_RUNTIME_DIR") {
+        if runtime_directory.is_empty() == false {
+            candidates.push(PathBuf::from(runtime_directory).join("podman").join("podman.sock"));
+        }
+    }
+    candidates.push(PathBuf::from("/run/podman/podman.sock"));
+    candidates.push(PathBuf::from("/var/run/docker.sock"));
+    candidates
+}
+
+/// First candidate socket accepting connections.
+pub fn discover_socket() -> Option<PathBuf> {
+    socket_candidates().into_iter()
+        .find(|path| UnixStream::connect(path).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `/version` of Docker Engine 27, trimmed.
+    const DOCKER_VERSION: &str = r#"{
+        "Platform": {"Name": "Docker Engine - Community"},
+        "Components": [
+            {"Name": "Engine", "Version": "27.3.1", "Details": {"ApiVersion": "1.47", "MinAPIVersion": "1.24"}},
+            {"Name": "containerd", "Version": "1.7.22", "Details": {"GitCommit": "7f7fdf5"}},
+            {"Name": "runc", "Version": "1.1.14", "Details": {"GitCommit": "v1.1.14-0-g2c9f560"}}
+        ],
+        "Version": "27.3.1",
+        "ApiVersion": "1.47",
+        "MinAPIVersion": "1.24",
+        "Os": "linux",
+        "Arch": "amd64"
+    }"#;
+
+    /// `/version` of the compat API of Podman 5, trimmed.
+    const PODMAN_VERSION: &str = r#"{
+        "Platform": {"Name": "linux/amd64/fedora-40"},
+        "Components": [
+            {"Name": "Podman Engine", "Version": "5.2.3", "Details": {"APIVersion": "5.2.3", "MinAPIVersion": "4.0.0"}},
+            {"Name": "Conmon", "Version": "conmon version 2.1.12", "Details": {"Package": "conmon-2.1.12-2.fc40.x86_64"}}
+        ],
+        "Version": "5.2.3",
+        "ApiVersion": "1.41",
+        "MinAPIVersion": "1.24",
+        "Os": "linux",
+        "Arch": "amd64"
+    }"#;
+
+    #[test]
+    fn detects_docker() {
+        let version = serde_json::from_str::<SystemVersion>(DOCKER_VERSION).unwrap();
+        let engine = Engine::from(&version);
+        assert_eq!(engine.flavor, EngineFlavor::Docker);
+        assert_eq!(engine.version.as_deref(), Some("27.3.1"));
+        assert_eq!(engine.api_version.as_deref(), Some("1.47"));
+    }
+
+    #[test]
+    fn detects_podman_from_its_components() {
+        let version = serde_json::from_str::<SystemVersion>(PODMAN_VERSION).unwrap();
+        let engine = Engine::from(&version);
+        assert_eq!(engine.flavor, EngineFlavor::Podman);
+        assert_eq!(engine.version.as_deref(), Some("5.2.3"));
+        assert_eq!(engine.api_version.as_deref(), Some("1.41"));
+
+        let version = serde_json::from_str::<SystemVersion>(r#"{"Platform": {"Name": "Podman Engine"}}"#).unwrap();
+        assert_eq!(Engine::from(&version).flavor, EngineFlavor::Podman);
+    }
+}
@@ -0,0 +1,246 @@
+use bytes::{Buf, Bytes, BytesMut};
+use http::HeaderMap;
+use std::io;
+
+const HEADER_LENGTH: usize = 8;
+const MULTIPLEXED_STREAM: &str = "application/vnd.docker.multiplexed-stream";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamType {
+    Stdin,
+    Stdout,
+    Stderr,
+}
+
+/// Chunk of the output of logs, attach or exec.
+///
+/// Containers with a TTY have a single output stream, reported as `Stdout`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFrame {
+    pub stream: StreamType,
+    /// RFC 3339 timestamp, only present when requested with `timestamps=true`.
+    pub timestamp: Option<String>,
+    pub bytes: Bytes,
+}
+
+impl LogFrame {
+    fn new(stream: StreamType, mut bytes: Bytes, timestamps: bool) -> Self {
+        let mut timestamp = None;
+        if timestamps {
+            if let Some(index) = bytes.iter().position(|byte| *byte == b' ') {
+                timestamp = Some(String::from_utf8_lossy(&bytes[..index]).to_string());
+                bytes.advance(index + 1);
+            }
+        }
+        Self {
+            stream,
+            timestamp,
+            bytes,
+        }
+    }
+}
+
+impl From<bollard::container::LogOutput> for LogFrame {
+    fn from(value: bollard::container::LogOutput) -> Self {
+        use bollard::container::LogOutput;
+        match value {
+            LogOutput::StdIn { message } => LogFrame::new(StreamType::Stdin, message, false),
+            LogOutput::StdOut { message } => LogFrame::new(StreamType::Stdout, message, false),
+            LogOutput::StdErr { message } => LogFrame::new(StreamType::Stderr, message, false),
+            LogOutput::Console { message } => LogFrame::new(StreamType::Stdout, message, false),
+        }
+    }
+}
+
+/// Decodes Docker raw streams into frames.
+///
+/// Without a TTY, every frame starts with an 8 bytes header: the stream type, three zero bytes
+/// and the big endian payload length. With a TTY, the output is sent as is. Daemons older than
+/// v1.42 send both as `application/vnd.docker.raw-stream`, so the framing is then detected from the
+/// first bytes.
+///
+/// A decoding error poisons the decoder, it then decodes nothing more.
+pub(crate) struct FrameDecoder {
+    multiplexed: Option<bool>,
+    timestamps: bool,
+    buffer: BytesMut,
+    failed: bool,
+}
+
+impl FrameDecoder {
+    pub(crate) fn new(multiplexed: Option<bool>) -> Self {
+        Self {
+            multiplexed,
+            timestamps: false,
+            buffer: BytesMut::new(),
+            failed: false,
+        }
+    }
+
+    /// Framing announced by the response headers, `None` when it has to be detected.
+    ///
+    /// Only the multiplexed content type is conclusive: daemons older than v1.42 also send
+    /// multiplexed streams as `application/vnd.docker.raw-stream`.
+    pub(crate) fn framing(headers: &HeaderMap) -> Option<bool> {
+        let content_type = headers.get(http::header::CONTENT_TYPE)?
+            .to_str()
+            .ok()?;
+        match content_type.starts_with(MULTIPLEXED_STREAM) {
+            true => Some(true),
+            false => None,
+        }
+    }
+
+    pub(crate) fn with_timestamps(&mut self, timestamps: bool) -> &mut Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        if self.failed == false {
+            self.buffer.extend_from_slice(bytes);
+        }
+    }
+
+    pub(crate) fn decode(&mut self) -> io::Result<Option<LogFrame>> {
+        if self.failed {
+            return Ok(None);
+        }
+        if self.multiplexed.is_none() {
+            if self.buffer.len() < HEADER_LENGTH {
+                return Ok(None);
+            }
+            self.multiplexed = Some(self.buffer[0] <= 2 && self.buffer[1..4] == [0, 0, 0]);
+        }
+        match self.multiplexed {
+            Some(true) => self.decode_multiplexed(),
+            _ => Ok(self.decode_raw(false)),
+        }
+    }
+
+    /// Frame left at the end of the stream.
+    pub(crate) fn finish(&mut self) -> io::Result<Option<LogFrame>> {
+        if self.failed || self.buffer.is_empty() {
+            return Ok(None);
+        }
+        match self.multiplexed {
+            Some(true) => Err(self.fail(io::ErrorKind::UnexpectedEof, "Truncated stream frame".to_string())),
+            _ => Ok(self.decode_raw(true)),
+        }
+    }
+
+    fn fail(&mut self, kind: io::ErrorKind, message: String) -> io::Error {
+        self.failed = true;
+        self.buffer.clear();
+        io::Error::new(kind, message)
+    }
+
+    fn decode_multiplexed(&mut self) -> io::Result<Option<LogFrame>> {
+        if self.buffer.len() < HEADER_LENGTH {
+            return Ok(None);
+        }
+        let stream = match self.buffer[0] {
+            0 => StreamType::Stdin,
+            1 => StreamType::Stdout,
+            2 => StreamType::Stderr,
+            value => return Err(self.fail(io::ErrorKind::InvalidData, format!("Invalid stream type: {}", value))),
+        };
+        let length = u32::from_be_bytes([self.buffer[4], self.buffer[5], self.buffer[6], self.buffer[7]]) as usize;
+        if self.buffer.len() < HEADER_LENGTH + length {
+            return Ok(None);
+        }
+        self.buffer.advance(HEADER_LENGTH);
+        let payload = self.buffer.split_to(length).freeze();
+        Ok(Some(LogFrame::new(stream, payload, self.timestamps)))
+    }
+
+    /// Raw output has no frames, timestamps are then found at the start of every line.
+    fn decode_raw(&mut self, eof: bool) -> Option<LogFrame> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let length = match self.timestamps && eof == false {
+            true => self.buffer.iter().position(|byte| *byte == b'\n')? + 1,
+            false => self.buffer.len(),
+        };
+        let payload = self.buffer.split_to(length).freeze();
+        Some(LogFrame::new(StreamType::Stdout, payload, self.timestamps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW_STREAM: &str = "application/vnd.docker.raw-stream";
+
+    fn frame(stream: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![stream, 0, 0, 0];
+        bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn decodes_multiplexed_frames_split_across_chunks() {
+        let mut bytes = frame(1, b"out");
+        bytes.extend(frame(2, b"err"));
+        let mut decoder = FrameDecoder::new(Some(true));
+        decoder.push(&bytes[..5]);
+        assert!(decoder.decode().unwrap().is_none());
+        decoder.push(&bytes[5..]);
+        let first = decoder.decode().unwrap().unwrap();
+        assert_eq!((first.stream, first.bytes.as_ref()), (StreamType::Stdout, b"out".as_ref()));
+        let second = decoder.decode().unwrap().unwrap();
+        assert_eq!((second.stream, second.bytes.as_ref()), (StreamType::Stderr, b"err".as_ref()));
+        assert!(decoder.finish().unwrap().is_none());
+    }
+
+    #[test]
+    fn reports_a_truncated_frame_once() {
+        let mut decoder = FrameDecoder::new(Some(true));
+        decoder.push(&frame(1, b"output")[..10]);
+        assert!(decoder.decode().unwrap().is_none());
+        assert_eq!(decoder.finish().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(decoder.finish().unwrap().is_none());
+    }
+
+    #[test]
+    fn stops_after_an_invalid_header() {
+        let mut decoder = FrameDecoder::new(Some(true));
+        decoder.push(&frame(7, b"bad"));
+        assert_eq!(decoder.decode().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        decoder.push(&frame(1, b"out"));
+        assert!(decoder.decode().unwrap().is_none());
+        assert!(decoder.finish().unwrap().is_none());
+    }
+
+    #[test]
+    fn detects_raw_output() {
+        let mut decoder = FrameDecoder::new(None);
+        decoder.push(b"plain output\n");
+        let frame = decoder.decode().unwrap().unwrap();
+        assert_eq!((frame.stream, frame.bytes.as_ref()), (StreamType::Stdout, b"plain output\n".as_ref()));
+    }
+
+    #[test]
+    fn reads_the_framing_from_the_content_type() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(FrameDecoder::framing(&headers), None);
+        headers.insert(http::header::CONTENT_TYPE, MULTIPLEXED_STREAM.parse().unwrap());
+        assert_eq!(FrameDecoder::framing(&headers), Some(true));
+        headers.insert(http::header::CONTENT_TYPE, RAW_STREAM.parse().unwrap());
+        assert_eq!(FrameDecoder::framing(&headers), None);
+    }
+
+    #[test]
+    fn detects_multiplexed_frames_sent_as_a_raw_stream() {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::CONTENT_TYPE, RAW_STREAM.parse().unwrap());
+        let mut decoder = FrameDecoder::new(FrameDecoder::framing(&headers));
+        decoder.push(&[2, 0, 0, 0, 0, 0, 0, 6]);
+        decoder.push(b"error\n");
+        let frame = decoder.decode().unwrap().unwrap();
+        assert_eq!((frame.stream, frame.bytes.as_ref()), (StreamType::Stderr, b"error\n".as_ref()));
+    }
+}
@@ -1,3 +1,11 @@
+#[allow(dead_code, clippy::into_iter_on_ref, clippy::enum_variant_names)]
 pub mod api_sync {
+    use crate::params::*;
     include!(concat!(env!("OUT_DIR"), "/api-sync.rs"));
+}
+
+#[allow(dead_code, clippy::into_iter_on_ref, clippy::enum_variant_names)]
+pub mod api_async {
+    use crate::params::*;
+    include!(concat!(env!("OUT_DIR"), "/api-async.rs"));
 }
\ No newline at end of file
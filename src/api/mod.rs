@@ -1,4 +1,25 @@
 pub(crate) mod internals;
+mod builder;
 pub mod synchronous;
 pub mod asynchronous;
-pub mod options;
\ No newline at end of file
+pub mod blocking;
+pub mod options;
+pub mod session;
+pub mod frame;
+pub mod stream;
+pub mod transport;
+pub mod context;
+pub mod docker_config;
+pub mod dockerignore;
+pub mod pull;
+pub mod push;
+pub mod stats;
+pub mod reference;
+pub mod registry_auth;
+pub mod engine;
+pub mod version;
+#[cfg(test)]
+pub(crate) mod testing;
+
+/// Docker Engine API version the generated client targets, e.g. `v1.47`.
+pub const API_VERSION: &str = env!("CONTAINER_FLOW_API_VERSION");
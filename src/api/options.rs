@@ -1,25 +1,35 @@
 use bollard::container::{Config, StopContainerOptions};
+use bollard::exec::CreateExecOptions;
 use bollard::models::{HealthConfig, HostConfig};
-use pipewire_common::utils::Size;
 use std::collections::HashMap;
 use std::time::Duration;
 
+/// When `ContainerApi::create` pulls the image of the container, like `docker run --pull`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullPolicy {
+    Always,
+    IfNotPresent,
+    Never,
+}
+
 pub struct CreateContainerOptionsBuilder {
     image: Option<String>,
+    pull_policy: PullPolicy,
     environment: Option<HashMap<String, String>>,
     volumes: Option<HashMap<String, String>>,
     labels: Option<HashMap<String, String>>,
     entrypoint: Option<Vec<String>>,
     healthcheck: Option<Vec<String>>,
     cpus: Option<f64>,
-    memory_swap: Option<Size>,
-    memory: Option<Size>
+    memory_swap: Option<i64>,
+    memory: Option<i64>
 }
 
 impl Default for CreateContainerOptionsBuilder {
     fn default() -> Self {
         Self {
             image: None,
+            pull_policy: PullPolicy::IfNotPresent,
             environment: None,
             volumes: None,
             labels: None,
@@ -38,6 +48,15 @@ impl CreateContainerOptionsBuilder {
         self
     }
 
+    pub fn with_pull_policy(&mut self, pull_policy: PullPolicy) -> &mut Self {
+        self.pull_policy = pull_policy;
+        self
+    }
+
+    pub fn pull_policy(&self) -> PullPolicy {
+        self.pull_policy
+    }
+
     pub fn with_environment(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
         if let None = self.environment {
             self.environment = Some(HashMap::new());
@@ -110,12 +129,14 @@ impl CreateContainerOptionsBuilder {
         self
     }
 
-    pub fn with_memory_swap(&mut self, memory_swap: Size) -> &mut Self {
+    /// Memory plus swap limit in bytes, `-1` for unlimited swap.
+    pub fn with_memory_swap(&mut self, memory_swap: i64) -> &mut Self {
         self.memory_swap = Some(memory_swap);
         self
     }
 
-    pub fn with_memory(&mut self, memory: Size) -> &mut Self {
+    /// Memory limit in bytes.
+    pub fn with_memory(&mut self, memory: i64) -> &mut Self {
         self.memory = Some(memory);
         self
     }
@@ -157,17 +178,17 @@ impl CreateContainerOptionsBuilder {
                 ..HealthConfig::default()
             });
         }
-        if let Some(cpus) = self.cpus.clone() {
+        if let Some(cpus) = self.cpus {
             let host_config = builder.host_config.as_mut().unwrap();
             host_config.nano_cpus = Some((1_000_000_000.0 * cpus) as i64);
         }
-        if let Some(memory_swap) = self.memory_swap.clone() {
+        if let Some(memory_swap) = self.memory_swap {
             let host_config = builder.host_config.as_mut().unwrap();
-            host_config.memory_swap = Some(memory_swap.into());
+            host_config.memory_swap = Some(memory_swap);
         }
-        if let Some(memory) = self.memory.clone() {
+        if let Some(memory) = self.memory {
             let host_config = builder.host_config.as_mut().unwrap();
-            host_config.memory = Some(memory.into());
+            host_config.memory = Some(memory);
         }
         builder
     }
@@ -196,4 +217,85 @@ impl StopContainerOptionsBuilder {
         builder.t = self.wait.unwrap().as_secs() as i64;
         builder
     }
-}
\ No newline at end of file
+}
+
+#[derive(Default)]
+pub struct ExecOptions {
+    command: Option<Vec<String>>,
+    environment: Option<HashMap<String, String>>,
+    working_dir: Option<String>,
+    user: Option<String>,
+    privileged: bool,
+    tty: bool,
+    timeout: Option<Duration>,
+}
+
+impl ExecOptions {
+    pub fn with_command(&mut self, command: Vec<impl Into<String>>) -> &mut Self {
+        self.command = Some(command.into_iter().map(|value| value.into()).collect());
+        self
+    }
+
+    pub fn with_environment(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        if let None = self.environment {
+            self.environment = Some(HashMap::new());
+        }
+        if let Some(environment) = self.environment.as_mut() {
+            environment.insert(key.into(), value.into());
+        }
+        self
+    }
+
+    pub fn with_working_dir(&mut self, working_dir: impl Into<String>) -> &mut Self {
+        self.working_dir = Some(working_dir.into());
+        self
+    }
+
+    pub fn with_user(&mut self, user: impl Into<String>) -> &mut Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn with_privileged(&mut self, privileged: bool) -> &mut Self {
+        self.privileged = privileged;
+        self
+    }
+
+    /// With a TTY, stdout and stderr are merged into `ExecOutput::stdout`.
+    pub fn with_tty(&mut self, tty: bool) -> &mut Self {
+        self.tty = tty;
+        self
+    }
+
+    /// Fails `exec` with `RequestTimeoutError` once `timeout` elapsed. The daemon has no API
+    /// to stop an exec instance, so the command keeps running in the container.
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub fn build(&self) -> CreateExecOptions<String> {
+        if self.command.is_none() {
+            panic!("Command is required");
+        }
+        let mut builder = CreateExecOptions::default();
+        builder.cmd = self.command.clone();
+        builder.attach_stdout = Some(true);
+        builder.attach_stderr = Some(true);
+        builder.tty = Some(self.tty);
+        builder.privileged = Some(self.privileged);
+        builder.working_dir = self.working_dir.clone();
+        builder.user = self.user.clone();
+        if let Some(environment) = self.environment.as_ref() {
+            let environment = environment.iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>();
+            builder.env = Some(environment);
+        }
+        builder
+    }
+}
@@ -0,0 +1,461 @@
+use crate::api::internals::{api_async, api_sync};
+use crate::api::reference::ImageReference;
+use crate::api::registry_auth::RegistryAuth;
+use crate::api::stream::ProgressError;
+use crate::models::{CreateImageInfo, ErrorDetail, ProgressDetail};
+use crate::params::ImageCreateParams;
+use futures::StreamExt;
+use std::collections::BTreeMap;
+
+const DIGEST_STATUS_PREFIX: &str = "Digest: ";
+const FINAL_STATUS_PREFIX: &str = "Status: ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LayerState {
+    Waiting,
+    Downloading,
+    Downloaded,
+    Extracting,
+    Complete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerProgress {
+    pub state: LayerState,
+    pub downloaded: u64,
+    /// Compressed size, known once the download started.
+    pub size: Option<u64>,
+    pub extracted: u64,
+}
+
+/// Progress of a pull, aggregated over the layers reported so far.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PullProgress {
+    pub layers: BTreeMap<String, LayerProgress>,
+}
+
+impl PullProgress {
+    /// Bytes downloaded over all layers, layers pulled or already present count as fully downloaded.
+    pub fn downloaded(&self) -> u64 {
+        self.layers.values()
+            .map(|layer| match layer.state >= LayerState::Downloaded {
+                true => layer.size.unwrap_or(layer.downloaded),
+                false => layer.downloaded,
+            })
+            .sum()
+    }
+
+    /// Bytes to download over the layers whose size is known.
+    pub fn size(&self) -> u64 {
+        self.layers.values()
+            .filter_map(|layer| layer.size)
+            .sum()
+    }
+
+    /// Share of the layers complete, between 0 and 1, weighted by layer size when known.
+    pub fn ratio(&self) -> f64 {
+        if self.layers.is_empty() {
+            return 0.0;
+        }
+        if self.layers.values().all(|layer| layer.size.is_some() || layer.state == LayerState::Complete) {
+            let size = self.size();
+            if size > 0 {
+                let done = self.layers.values()
+                    .map(|layer| match layer.state {
+                        LayerState::Complete => layer.size.unwrap_or(0),
+                        LayerState::Extracting => layer.extracted.min(layer.size.unwrap_or(0)),
+                        _ => 0,
+                    })
+                    .sum::<u64>();
+                return (done as f64 / size as f64).min(1.0);
+            }
+        }
+        self.complete() as f64 / self.layers.len() as f64
+    }
+
+    pub fn complete(&self) -> usize {
+        self.layers.values()
+            .filter(|layer| layer.state == LayerState::Complete)
+            .count()
+    }
+
+    /// Applies a message of the pull stream, returns `true` when a layer changed.
+    fn update(&mut self, message: &CreateImageInfo) -> bool {
+        let (id, status) = match (message.id.as_ref(), message.status.as_ref()) {
+            (Some(id), Some(status)) => (id, status),
+            _ => return false,
+        };
+        let state = match status.as_str() {
+            "Pulling fs layer" | "Waiting" => LayerState::Waiting,
+            "Downloading" => LayerState::Downloading,
+            "Verifying Checksum" | "Download complete" => LayerState::Downloaded,
+            "Extracting" => LayerState::Extracting,
+            "Pull complete" | "Already exists" => LayerState::Complete,
+            _ => return false,
+        };
+        let layer = self.layers.entry(id.clone())
+            .or_insert(LayerProgress {
+                state,
+                downloaded: 0,
+                size: None,
+                extracted: 0,
+            });
+        layer.state = state;
+        let detail = message.progress_detail.as_ref();
+        let current = detail.and_then(|detail| detail.current).map(|value| value.max(0) as u64);
+        let total = detail.and_then(|detail| detail.total).filter(|value| *value > 0).map(|value| value as u64);
+        match state {
+            LayerState::Downloading => {
+                layer.downloaded = current.unwrap_or(layer.downloaded);
+                layer.size = total.or(layer.size);
+            }
+            LayerState::Extracting => {
+                layer.extracted = current.unwrap_or(layer.extracted);
+                layer.size = layer.size.or(total);
+            }
+            _ => {}
+        }
+        true
+    }
+}
+
+type PullProgressCallback = Box<dyn FnMut(&PullProgress) + Send>;
+
+/// Options of `ImageApi::pull`.
+#[derive(Default)]
+pub struct PullOptions {
+    pub(crate) platform: Option<String>,
+    pub(crate) registry_auth: Option<RegistryAuth>,
+    progress: Option<PullProgressCallback>,
+}
+
+impl PullOptions {
+    /// Platform in the `os[/arch[/variant]]` format, e.g. `linux/arm64`.
+    pub fn with_platform(&mut self, platform: impl Into<String>) -> &mut Self {
+        self.platform = Some(platform.into());
+        self
+    }
+
+    /// Overrides the credentials resolved by the client for the registry of the reference.
+    pub fn with_registry_auth(&mut self, registry_auth: RegistryAuth) -> &mut Self {
+        self.registry_auth = Some(registry_auth);
+        self
+    }
+
+    /// Called with the aggregated progress every time a layer changes.
+    pub fn with_progress(&mut self, progress: impl FnMut(&PullProgress) + Send + 'static) -> &mut Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    fn params(&self, reference: &ImageReference) -> ImageCreateParams {
+        ImageCreateParams {
+            from_image: Some(reference.name()),
+            from_src: None,
+            repo: None,
+            tag: reference.digest().or(reference.tag()).map(|value| value.to_string()),
+            message: None,
+            x_registry_auth: self.registry_auth.as_ref().map(|auth| auth.encode()),
+            changes: None,
+            platform: self.platform.clone(),
+            input_image: None,
+        }
+    }
+}
+
+/// Image pulled by `ImageApi::pull`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PulledImage {
+    pub reference: ImageReference,
+    /// Manifest digest reported by the registry.
+    pub digest: Option<String>,
+    /// Last status of the daemon, e.g. `Status: Image is up to date for nginx:latest`.
+    pub status: Option<String>,
+    pub progress: PullProgress,
+}
+
+pub type PullError<E> = ProgressError<E>;
+
+/// Folds the messages of the pull stream into a `PulledImage`.
+pub(crate) struct PullTracker<'a> {
+    image: PulledImage,
+    progress: Option<&'a mut (dyn FnMut(&PullProgress) + Send + 'static)>,
+    complete: bool,
+}
+
+impl<'a> PullTracker<'a> {
+    pub(crate) fn new(reference: &ImageReference, options: &'a mut PullOptions) -> Self {
+        Self {
+            image: PulledImage {
+                reference: reference.clone(),
+                digest: None,
+                status: None,
+                progress: PullProgress::default(),
+            },
+            progress: options.progress.as_deref_mut(),
+            complete: false,
+        }
+    }
+
+    fn push<E>(&mut self, message: Result<CreateImageInfo, serde_json::Error>) -> Result<(), PullError<E>> {
+        self.push_message(message.map_err(PullError::Stream)?)
+    }
+
+    pub(crate) fn push_message<E>(&mut self, message: CreateImageInfo) -> Result<(), PullError<E>> {
+        if let Some(error) = PullError::from_message(message.error.as_ref(), message.error_detail.as_deref()) {
+            return Err(error);
+        }
+        if self.image.progress.update(&message) {
+            if let Some(progress) = self.progress.as_mut() {
+                progress(&self.image.progress);
+            }
+            return Ok(());
+        }
+        if let Some(status) = message.status {
+            if let Some(digest) = status.strip_prefix(DIGEST_STATUS_PREFIX) {
+                self.image.digest = Some(digest.trim().to_string());
+            }
+            self.complete = status.starts_with(FINAL_STATUS_PREFIX);
+            self.image.status = Some(status);
+        }
+        Ok(())
+    }
+
+    /// The daemon ends a pull with a `Status: ` message, a stream closed before is a pull
+    /// interrupted by the daemon.
+    pub(crate) fn finish<E>(self) -> Result<PulledImage, PullError<E>> {
+        match self.complete {
+            true => Ok(self.image),
+            false => Err(PullError::Daemon {
+                code: None,
+                message: format!("Pull of {} ended without final status", self.image.reference),
+            }),
+        }
+    }
+}
+
+impl From<bollard::models::CreateImageInfo> for CreateImageInfo {
+    fn from(value: bollard::models::CreateImageInfo) -> Self {
+        let progress_value = |value: Option<i64>| value.map(|value| value.clamp(0, i32::MAX as i64) as i32);
+        Self {
+            id: value.id,
+            error: value.error,
+            error_detail: value.error_detail.map(|detail| Box::new(ErrorDetail {
+                code: detail.code.map(|code| code as i32),
+                message: detail.message,
+            })),
+            status: value.status,
+            progress: value.progress,
+            progress_detail: value.progress_detail.map(|detail| Box::new(ProgressDetail {
+                current: progress_value(detail.current),
+                total: progress_value(detail.total),
+            })),
+        }
+    }
+}
+
+impl api_sync::ImageApi {
+    /// Pulls `reference`, reporting the progress to the callback of `options`.
+    pub fn pull(
+        &mut self,
+        reference: &ImageReference,
+        options: &mut PullOptions
+    ) -> Result<PulledImage, PullError<api_sync::Error<api_sync::ImageCreateError>>> {
+        let stream = self.create(options.params(reference))
+            .map_err(PullError::Request)?;
+        let mut tracker = PullTracker::new(reference, options);
+        for message in stream {
+            tracker.push(message)?;
+        }
+        tracker.finish()
+    }
+}
+
+impl api_async::ImageApi {
+    /// Pulls `reference`, reporting the progress to the callback of `options`.
+    pub async fn pull(
+        &mut self,
+        reference: &ImageReference,
+        options: &mut PullOptions
+    ) -> Result<PulledImage, PullError<api_async::Error<api_async::ImageCreateError>>> {
+        let mut stream = self.create(options.params(reference)).await
+            .map_err(PullError::Request)?;
+        let mut tracker = PullTracker::new(reference, options);
+        while let Some(message) = stream.next().await {
+            tracker.push(message)?;
+        }
+        tracker.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::asynchronous::image::ImageApi;
+    use crate::api::registry_auth::RegistryAuths;
+    use crate::api::stream::JsonStream;
+    use crate::api::testing::MockDaemon;
+    use crate::api::transport::{Endpoint, Transport};
+    use crate::api::version::ApiVersion;
+    use bollard::{Docker, API_DEFAULT_VERSION};
+    use std::io::Cursor;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    /// Messages of `/images/create` as sent by the daemon, without the final status.
+    const PULL_MESSAGES: &str = r#"{"status":"Pulling from library/nginx","id":"latest"}
+{"status":"Pulling fs layer","progressDetail":{},"id":"a1"}
+{"status":"Already exists","progressDetail":{},"id":"b2"}
+{"status":"Downloading","progressDetail":{"current":512,"total":1024},"progress":"[=====>     ]","id":"a1"}
+{"status":"Downloading","progressDetail":{"current":1024,"total":1024},"progress":"[==========>]","id":"a1"}
+{"status":"Verifying Checksum","progressDetail":{},"id":"a1"}
+{"status":"Download complete","progressDetail":{},"id":"a1"}
+{"status":"Extracting","progressDetail":{"current":1024,"total":1024},"id":"a1"}
+{"status":"Pull complete","progressDetail":{},"id":"a1"}
+"#;
+
+    fn replay(messages: String) -> (Result<PulledImage, PullError<()>>, usize) {
+        let reference = ImageReference::parse("nginx").unwrap();
+        let updates = Arc::new(AtomicUsize::new(0));
+        let counter = updates.clone();
+        let mut options = PullOptions::default();
+        options.with_progress(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let mut tracker = PullTracker::new(&reference, &mut options);
+        let result = JsonStream::<CreateImageInfo>::new(Cursor::new(messages))
+            .try_for_each(|message| tracker.push(message));
+        let result = result.and_then(|_| tracker.finish());
+        (result, updates.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn replays_a_complete_pull() {
+        let messages = format!(
+            "{}{{\"status\":\"Digest: {}\"}}\n{{\"status\":\"Status: Downloaded newer image for nginx:latest\"}}\n",
+            PULL_MESSAGES,
+            DIGEST
+        );
+        let (result, updates) = replay(messages);
+        let image = result.unwrap();
+        assert_eq!(image.digest.as_deref(), Some(DIGEST));
+        assert_eq!(image.status.as_deref(), Some("Status: Downloaded newer image for nginx:latest"));
+        assert_eq!(image.progress.complete(), 2);
+        assert_eq!(image.progress.layers["a1"].size, Some(1024));
+        assert_eq!(image.progress.ratio(), 1.0);
+        assert_eq!(updates, 8);
+    }
+
+    #[test]
+    fn fails_a_pull_interrupted_before_the_final_status() {
+        let (result, _) = replay(PULL_MESSAGES.to_string());
+        assert!(matches!(result, Err(PullError::Daemon { code: None, .. })));
+    }
+
+    #[test]
+    fn fails_on_a_daemon_error() {
+        let messages = format!(
+            "{}{{\"errorDetail\":{{\"message\":\"manifest unknown\"}},\"error\":\"manifest unknown\"}}\n",
+            PULL_MESSAGES
+        );
+        let (result, _) = replay(messages);
+        match result {
+            Err(PullError::Daemon { message, .. }) => assert_eq!(message, "manifest unknown"),
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+
+    #[test]
+    fn fails_on_an_error_detail_of_bollard() {
+        let reference = ImageReference::parse("nginx").unwrap();
+        let mut options = PullOptions::default();
+        let mut tracker = PullTracker::new(&reference, &mut options);
+        let message = bollard::models::CreateImageInfo {
+            error_detail: Some(bollard::models::ErrorDetail {
+                code: Some(1),
+                message: Some("manifest unknown".to_string()),
+            }),
+            ..Default::default()
+        };
+        match tracker.push_message::<()>(message.into()) {
+            Err(PullError::Daemon { code, message }) => {
+                assert_eq!(code, Some(1));
+                assert_eq!(message, "manifest unknown");
+            }
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+
+    #[test]
+    fn fails_on_a_truncated_message() {
+        let (result, _) = replay(format!("{}{{\"status\":\"Status: Downl", PULL_MESSAGES));
+        assert!(matches!(result, Err(PullError::Stream(_))));
+    }
+
+    const DAEMON_ERROR: &str = "{\"errorDetail\":{\"message\":\"manifest unknown\"},\"error\":\"manifest unknown\"}\n";
+
+    /// Sends the messages of a pull from a daemon, one HTTP chunk each.
+    fn pull_daemon(last: &str) -> MockDaemon {
+        let mut chunks = PULL_MESSAGES.lines()
+            .map(|line| format!("{}\n", line))
+            .collect::<Vec<_>>();
+        chunks.push(last.to_string());
+        let chunks = chunks.iter().map(String::as_str).collect::<Vec<_>>();
+        MockDaemon::start(vec![MockDaemon::chunked(200, &chunks)])
+    }
+
+    fn pull_through_transport(daemon: &MockDaemon) -> Result<PulledImage, PullError<api_sync::Error<api_sync::ImageCreateError>>> {
+        let configuration = api_sync::Configuration {
+            base_path: "http://localhost/v1.47".to_string(),
+            user_agent: Some("tests".to_string()),
+            client: Transport::with_api_version(daemon.endpoint(), reqwest::blocking::Client::new(), ApiVersion::new(1, 47), false),
+        };
+        let reference = ImageReference::parse("nginx").unwrap();
+        api_sync::ImageApi::new(Arc::new(configuration)).pull(&reference, &mut PullOptions::default())
+    }
+
+    fn pull_through_bollard(daemon: &MockDaemon) -> Result<PulledImage, PullError<bollard::errors::Error>> {
+        let path = match daemon.endpoint() {
+            Endpoint::Unix(path) => path,
+            _ => unreachable!(),
+        };
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let docker = Docker::connect_with_unix(path.to_str().unwrap(), 10, API_DEFAULT_VERSION).unwrap();
+            let api = ImageApi::new(Arc::new(docker), Arc::new(RegistryAuths::new()));
+            api.pull(&"nginx".to_string(), &mut PullOptions::default()).await
+        })
+    }
+
+    #[test]
+    fn pulls_a_streamed_response_through_the_transport() {
+        let daemon = pull_daemon(&format!(
+            "{{\"status\":\"Digest: {}\"}}\n{{\"status\":\"Status: Downloaded newer image for nginx:latest\"}}\n",
+            DIGEST
+        ));
+        let image = pull_through_transport(&daemon).unwrap();
+        assert_eq!(image.digest.as_deref(), Some(DIGEST));
+        assert_eq!(image.progress.complete(), 2);
+        assert_eq!(daemon.requests()[0].line, "POST /v1.47/images/create?fromImage=docker.io%2Flibrary%2Fnginx&tag=latest");
+    }
+
+    #[test]
+    fn fails_on_a_streamed_error_detail_through_the_transport() {
+        let daemon = pull_daemon(DAEMON_ERROR);
+        match pull_through_transport(&daemon) {
+            Err(PullError::Daemon { message, .. }) => assert_eq!(message, "manifest unknown"),
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+
+    #[test]
+    fn fails_on_a_streamed_error_detail_through_bollard() {
+        let daemon = pull_daemon(DAEMON_ERROR);
+        match pull_through_bollard(&daemon) {
+            Err(PullError::Daemon { message, .. }) => assert_eq!(message, "manifest unknown"),
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+}
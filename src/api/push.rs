@@ -0,0 +1,306 @@
+use crate::api::internals::{api_async, api_sync};
+use crate::api::reference::ImageReference;
+use crate::api::registry_auth::RegistryAuth;
+use crate::api::stream::ProgressError;
+use crate::models::{ErrorDetail, ProgressDetail};
+use crate::params::ImagePushParams;
+use futures::StreamExt;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Message of the `/images/{name}/push` stream.
+///
+/// The specification describes it as `PushImageInfo`, without the `aux` message sent once the
+/// manifest is pushed.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PushMessage {
+    pub id: Option<String>,
+    pub status: Option<String>,
+    pub progress: Option<String>,
+    #[serde(rename = "progressDetail")]
+    pub progress_detail: Option<ProgressDetail>,
+    pub error: Option<String>,
+    #[serde(rename = "errorDetail")]
+    pub error_detail: Option<ErrorDetail>,
+    pub aux: Option<PushResult>,
+}
+
+/// Manifest pushed to the registry.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PushResult {
+    #[serde(rename = "Tag")]
+    pub tag: Option<String>,
+    #[serde(rename = "Digest")]
+    pub digest: String,
+    #[serde(rename = "Size")]
+    pub size: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LayerPushState {
+    Preparing,
+    Waiting,
+    Pushing,
+    /// Pushed, already present in the registry or mounted from another repository.
+    Pushed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerPushProgress {
+    pub state: LayerPushState,
+    pub pushed: u64,
+    pub size: Option<u64>,
+}
+
+/// Progress of a push, by layer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PushProgress {
+    pub layers: BTreeMap<String, LayerPushProgress>,
+}
+
+impl PushProgress {
+    pub fn pushed(&self) -> usize {
+        self.layers.values()
+            .filter(|layer| layer.state == LayerPushState::Pushed)
+            .count()
+    }
+
+    /// Applies a message of the push stream, returns `true` when a layer changed.
+    fn update(&mut self, message: &PushMessage) -> bool {
+        let (id, status) = match (message.id.as_ref(), message.status.as_ref()) {
+            (Some(id), Some(status)) => (id, status),
+            _ => return false,
+        };
+        let state = match status.as_str() {
+            "Preparing" => LayerPushState::Preparing,
+            "Waiting" => LayerPushState::Waiting,
+            "Pushing" => LayerPushState::Pushing,
+            "Pushed" | "Layer already exists" => LayerPushState::Pushed,
+            status if status.starts_with("Mounted from") => LayerPushState::Pushed,
+            _ => return false,
+        };
+        let layer = self.layers.entry(id.clone())
+            .or_insert(LayerPushProgress {
+                state,
+                pushed: 0,
+                size: None,
+            });
+        layer.state = state;
+        if let Some(detail) = message.progress_detail.as_ref() {
+            if let Some(current) = detail.current {
+                layer.pushed = current.max(0) as u64;
+            }
+            if let Some(total) = detail.total.filter(|value| *value > 0) {
+                layer.size = Some(total as u64);
+            }
+        }
+        true
+    }
+}
+
+/// Image pushed by `ImageApi::push`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushedImage {
+    pub reference: ImageReference,
+    /// Manifest digest, e.g. `sha256:...`, to reference the image as `name@digest`.
+    pub digest: String,
+    /// Manifest size in bytes.
+    pub size: i64,
+    pub progress: PushProgress,
+}
+
+pub type PushError<E> = ProgressError<E>;
+
+/// Folds the messages of the push stream into a `PushedImage`.
+struct PushTracker<F> {
+    reference: ImageReference,
+    result: Option<PushResult>,
+    progress: PushProgress,
+    report: F,
+}
+
+impl<F: FnMut(&PushProgress)> PushTracker<F> {
+    fn new(reference: &ImageReference, report: F) -> Self {
+        Self {
+            reference: reference.clone(),
+            result: None,
+            progress: PushProgress::default(),
+            report,
+        }
+    }
+
+    fn push<E>(&mut self, message: Result<PushMessage, serde_json::Error>) -> Result<(), PushError<E>> {
+        let message = message.map_err(PushError::Stream)?;
+        if let Some(error) = PushError::from_message(message.error.as_ref(), message.error_detail.as_ref()) {
+            return Err(error);
+        }
+        if self.progress.update(&message) {
+            (self.report)(&self.progress);
+        }
+        if let Some(result) = message.aux {
+            self.result = Some(result);
+        }
+        Ok(())
+    }
+
+    fn finish<E>(self) -> Result<PushedImage, PushError<E>> {
+        let result = self.result.ok_or(PushError::Daemon {
+            code: None,
+            message: format!("No digest reported for {}", self.reference),
+        })?;
+        Ok(PushedImage {
+            reference: self.reference,
+            digest: result.digest,
+            size: result.size,
+            progress: self.progress,
+        })
+    }
+}
+
+/// Credentials not given are resolved by the client from the registry of the reference.
+fn push_params(reference: &ImageReference, auth: Option<&RegistryAuth>) -> ImagePushParams {
+    ImagePushParams {
+        name: reference.name(),
+        x_registry_auth: auth.map(|auth| auth.encode()).unwrap_or_default(),
+        tag: reference.tag().map(|tag| tag.to_string()),
+        platform: None,
+    }
+}
+
+impl api_sync::ImageApi {
+    /// Pushes the tag of `reference` and returns the digest of the pushed manifest.
+    pub fn push(
+        &mut self,
+        reference: &ImageReference,
+        auth: Option<&RegistryAuth>
+    ) -> Result<PushedImage, PushError<api_sync::Error<api_sync::ImagePushError>>> {
+        self.push_with_progress(reference, auth, |_| {})
+    }
+
+    /// Same as `push`, `progress` is called every time a layer changes.
+    pub fn push_with_progress(
+        &mut self,
+        reference: &ImageReference,
+        auth: Option<&RegistryAuth>,
+        progress: impl FnMut(&PushProgress)
+    ) -> Result<PushedImage, PushError<api_sync::Error<api_sync::ImagePushError>>> {
+        let stream = self.push_stream(push_params(reference, auth))
+            .map_err(PushError::Request)?;
+        let mut tracker = PushTracker::new(reference, progress);
+        for message in stream {
+            tracker.push(message)?;
+        }
+        tracker.finish()
+    }
+}
+
+impl api_async::ImageApi {
+    /// Pushes the tag of `reference` and returns the digest of the pushed manifest.
+    pub async fn push(
+        &mut self,
+        reference: &ImageReference,
+        auth: Option<&RegistryAuth>
+    ) -> Result<PushedImage, PushError<api_async::Error<api_async::ImagePushError>>> {
+        self.push_with_progress(reference, auth, |_| {}).await
+    }
+
+    /// Same as `push`, `progress` is called every time a layer changes.
+    pub async fn push_with_progress(
+        &mut self,
+        reference: &ImageReference,
+        auth: Option<&RegistryAuth>,
+        progress: impl FnMut(&PushProgress)
+    ) -> Result<PushedImage, PushError<api_async::Error<api_async::ImagePushError>>> {
+        let mut stream = self.push_stream(push_params(reference, auth)).await
+            .map_err(PushError::Request)?;
+        let mut tracker = PushTracker::new(reference, progress);
+        while let Some(message) = stream.next().await {
+            tracker.push(message)?;
+        }
+        tracker.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::stream::JsonStream;
+    use std::io::Cursor;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    /// Messages of `/images/{name}/push` as sent by the daemon, without the final `aux` message.
+    const PUSH_MESSAGES: &str = r#"{"status":"The push refers to repository [registry.example.com/web]"}
+{"status":"Preparing","progressDetail":{},"id":"a1"}
+{"status":"Preparing","progressDetail":{},"id":"b2"}
+{"status":"Preparing","progressDetail":{},"id":"c3"}
+{"status":"Waiting","progressDetail":{},"id":"a1"}
+{"status":"Mounted from library/nginx","progressDetail":{},"id":"b2"}
+{"status":"Layer already exists","progressDetail":{},"id":"c3"}
+{"status":"Pushing","progressDetail":{"current":512,"total":1024},"progress":"[=====>     ]","id":"a1"}
+{"status":"Pushed","progressDetail":{},"id":"a1"}
+{"status":"latest: digest: sha256:0123 size: 945"}
+"#;
+
+    fn replay(messages: String) -> (Result<PushedImage, PushError<()>>, usize) {
+        let reference = ImageReference::parse("registry.example.com/web:latest").unwrap();
+        let mut updates = 0;
+        let mut tracker = PushTracker::new(&reference, |_: &PushProgress| updates += 1);
+        let result = JsonStream::<PushMessage>::new(Cursor::new(messages))
+            .try_for_each(|message| tracker.push(message))
+            .and_then(|_| tracker.finish());
+        (result, updates)
+    }
+
+    #[test]
+    fn captures_the_digest_of_the_aux_message() {
+        let messages = format!(
+            "{}{{\"progressDetail\":{{}},\"aux\":{{\"Tag\":\"latest\",\"Digest\":\"{}\",\"Size\":945}}}}\n",
+            PUSH_MESSAGES,
+            DIGEST
+        );
+        let (result, updates) = replay(messages);
+        let image = result.unwrap();
+        assert_eq!(image.digest, DIGEST);
+        assert_eq!(image.size, 945);
+        assert_eq!(image.progress.pushed(), 3);
+        assert_eq!(image.progress.layers["a1"].pushed, 512);
+        assert_eq!(image.progress.layers["a1"].size, Some(1024));
+        assert_eq!(updates, 8);
+    }
+
+    #[test]
+    fn counts_mounted_layers_as_pushed() {
+        let mut progress = PushProgress::default();
+        progress.update(&PushMessage {
+            id: Some("b2".to_string()),
+            status: Some("Mounted from library/nginx".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(progress.layers["b2"].state, LayerPushState::Pushed);
+    }
+
+    #[test]
+    fn fails_a_push_without_digest() {
+        let (result, _) = replay(PUSH_MESSAGES.to_string());
+        match result {
+            Err(PushError::Daemon { code: None, message }) => assert!(message.starts_with("No digest reported")),
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+
+    #[test]
+    fn fails_on_a_daemon_error() {
+        let messages = format!(
+            "{}{{\"errorDetail\":{{\"message\":\"denied: requested access to the resource is denied\"}},\"error\":\"denied\"}}\n",
+            PUSH_MESSAGES
+        );
+        let (result, _) = replay(messages);
+        match result {
+            Err(PushError::Daemon { message, .. }) => {
+                assert_eq!(message, "denied: requested access to the resource is denied");
+            }
+            _ => panic!("Expected a daemon error"),
+        }
+    }
+}
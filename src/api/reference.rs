@@ -0,0 +1,345 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::{error, fmt};
+
+pub const DEFAULT_REGISTRY: &str = "docker.io";
+pub const DEFAULT_NAMESPACE: &str = "library";
+pub const DEFAULT_TAG: &str = "latest";
+
+const LEGACY_DEFAULT_REGISTRY: &str = "index.docker.io";
+const MAXIMUM_NAME_LENGTH: usize = 255;
+const MAXIMUM_TAG_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceError {
+    pub reference: String,
+    pub description: String,
+}
+
+impl ReferenceError {
+    fn new(reference: &str, description: impl Into<String>) -> Self {
+        Self {
+            reference: reference.to_string(),
+            description: description.into(),
+        }
+    }
+}
+
+impl Display for ReferenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid reference {}: {}", self.reference, self.description)
+    }
+}
+
+impl error::Error for ReferenceError {}
+
+/// Image reference such as `registry/namespace/repository:tag@sha256:digest`, normalized the
+/// way the docker CLI does.
+///
+/// References without registry belong to Docker Hub, where single component repositories
+/// live in the `library` namespace. References without tag nor digest point to `latest`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImageReference {
+    registry: String,
+    repository: String,
+    tag: Option<String>,
+    digest: Option<String>,
+}
+
+impl ImageReference {
+    pub fn parse(reference: &str) -> Result<Self, ReferenceError> {
+        let (name, digest) = match reference.split_once('@') {
+            Some((name, digest)) => (name, Some(Self::parse_digest(reference, digest)?)),
+            None => (reference, None),
+        };
+        let (name, tag) = match name.rfind(':') {
+            Some(index) if name[index + 1..].contains('/') == false => {
+                (&name[..index], Some(Self::parse_tag(reference, &name[index + 1..])?))
+            }
+            _ => (name, None),
+        };
+        if name.is_empty() {
+            return Err(ReferenceError::new(reference, "repository name is empty"));
+        }
+        if name.len() > MAXIMUM_NAME_LENGTH {
+            return Err(ReferenceError::new(reference, "repository name is too long"));
+        }
+        let (registry, repository) = match name.split_once('/') {
+            Some((domain, rest)) if Self::is_domain(domain) => (domain.to_string(), rest.to_string()),
+            _ => (DEFAULT_REGISTRY.to_string(), name.to_string()),
+        };
+        let registry = match registry.as_str() {
+            LEGACY_DEFAULT_REGISTRY => DEFAULT_REGISTRY.to_string(),
+            _ => registry,
+        };
+        Self::validate_domain(reference, &registry)?;
+        let repository = match registry == DEFAULT_REGISTRY && repository.contains('/') == false {
+            true => format!("{}/{}", DEFAULT_NAMESPACE, repository),
+            false => repository,
+        };
+        for component in repository.split('/') {
+            Self::validate_component(reference, component)?;
+        }
+        let tag = match (&tag, &digest) {
+            (None, None) => Some(DEFAULT_TAG.to_string()),
+            _ => tag,
+        };
+        Ok(Self {
+            registry,
+            repository,
+            tag,
+            digest,
+        })
+    }
+
+    /// Registry host, e.g. `docker.io` or `localhost:5000`.
+    pub fn registry(&self) -> &str {
+        &self.registry
+    }
+
+    /// Repository path inside the registry, e.g. `library/nginx`.
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    pub fn with_tag(&mut self, tag: impl Into<String>) -> Result<&mut Self, ReferenceError> {
+        let tag = tag.into();
+        self.tag = Some(Self::parse_tag(&self.to_string(), &tag)?);
+        Ok(self)
+    }
+
+    pub fn with_digest(&mut self, digest: impl Into<String>) -> Result<&mut Self, ReferenceError> {
+        let digest = digest.into();
+        self.digest = Some(Self::parse_digest(&self.to_string(), &digest)?);
+        Ok(self)
+    }
+
+    /// Fully qualified name without tag nor digest, e.g. `docker.io/library/nginx`.
+    pub fn name(&self) -> String {
+        format!("{}/{}", self.registry, self.repository)
+    }
+
+    /// Shortest name the docker CLI accepts, e.g. `nginx` for `docker.io/library/nginx`.
+    pub fn familiar_name(&self) -> String {
+        if self.registry != DEFAULT_REGISTRY {
+            return self.name();
+        }
+        match self.repository.strip_prefix(&format!("{}/", DEFAULT_NAMESPACE)) {
+            Some(repository) if repository.contains('/') == false => repository.to_string(),
+            _ => self.repository.clone(),
+        }
+    }
+
+    /// Fully qualified reference, e.g. `docker.io/library/nginx:latest`.
+    pub fn canonical(&self) -> String {
+        self.render(self.name())
+    }
+
+    /// Reference as displayed by the docker CLI, e.g. `nginx:latest`.
+    pub fn familiar(&self) -> String {
+        self.render(self.familiar_name())
+    }
+
+    fn render(&self, name: String) -> String {
+        let mut reference = name;
+        if let Some(tag) = self.tag.as_ref() {
+            reference.push(':');
+            reference.push_str(tag);
+        }
+        if let Some(digest) = self.digest.as_ref() {
+            reference.push('@');
+            reference.push_str(digest);
+        }
+        reference
+    }
+
+    /// A leading component is a registry when it has a dot, a port, upper case letters or is `localhost`.
+    fn is_domain(component: &str) -> bool {
+        component.contains(['.', ':'])
+            || component == "localhost"
+            || component.chars().any(|character| character.is_ascii_uppercase())
+    }
+
+    fn validate_domain(reference: &str, domain: &str) -> Result<(), ReferenceError> {
+        let (host, port) = match domain.rsplit_once(':') {
+            Some((host, port)) if host.starts_with('[') == false || host.ends_with(']') => (host, Some(port)),
+            _ => (domain, None),
+        };
+        if let Some(port) = port {
+            if port.is_empty() || port.chars().all(|character| character.is_ascii_digit()) == false {
+                return Err(ReferenceError::new(reference, format!("invalid registry port: {}", port)));
+            }
+        }
+        if host.starts_with('[') && host.ends_with(']') {
+            return Ok(());
+        }
+        let valid = host.split('.')
+            .all(|label| {
+                label.is_empty() == false
+                    && label.starts_with('-') == false
+                    && label.ends_with('-') == false
+                    && label.chars().all(|character| character.is_ascii_alphanumeric() || character == '-')
+            });
+        match valid {
+            true => Ok(()),
+            false => Err(ReferenceError::new(reference, format!("invalid registry: {}", domain))),
+        }
+    }
+
+    /// Path components are lower case alphanumerics separated by `.`, `_`, `__` or dashes.
+    fn validate_component(reference: &str, component: &str) -> Result<(), ReferenceError> {
+        let error = || ReferenceError::new(reference, format!("invalid repository component: {}", component));
+        let bytes = component.as_bytes();
+        if bytes.is_empty() || Self::is_alphanumeric(bytes[0]) == false || Self::is_alphanumeric(bytes[bytes.len() - 1]) == false {
+            return Err(error());
+        }
+        let mut index = 0;
+        while index < bytes.len() {
+            if Self::is_alphanumeric(bytes[index]) {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < bytes.len() && Self::is_alphanumeric(bytes[index]) == false {
+                index += 1;
+            }
+            let separator = &component[start..index];
+            let valid = separator == "." || separator == "_" || separator == "__"
+                || separator.chars().all(|character| character == '-');
+            if valid == false {
+                return Err(error());
+            }
+        }
+        Ok(())
+    }
+
+    fn is_alphanumeric(byte: u8) -> bool {
+        byte.is_ascii_lowercase() || byte.is_ascii_digit()
+    }
+
+    fn parse_tag(reference: &str, tag: &str) -> Result<String, ReferenceError> {
+        let valid = tag.len() <= MAXIMUM_TAG_LENGTH
+            && tag.starts_with(|character: char| character.is_ascii_alphanumeric() || character == '_')
+            && tag.chars().all(|character| character.is_ascii_alphanumeric() || ['_', '.', '-'].contains(&character));
+        match valid {
+            true => Ok(tag.to_string()),
+            false => Err(ReferenceError::new(reference, format!("invalid tag: {}", tag))),
+        }
+    }
+
+    fn parse_digest(reference: &str, digest: &str) -> Result<String, ReferenceError> {
+        let error = || ReferenceError::new(reference, format!("invalid digest: {}", digest));
+        let (algorithm, hex) = digest.split_once(':').ok_or_else(error)?;
+        let valid_algorithm = algorithm.is_empty() == false
+            && algorithm.chars().all(|character| character.is_ascii_lowercase() || character.is_ascii_digit() || ['+', '.', '_', '-'].contains(&character));
+        let valid_hex = match algorithm {
+            "sha256" => hex.len() == 64 && hex.chars().all(|character| character.is_ascii_digit() || ('a'..='f').contains(&character)),
+            "sha512" => hex.len() == 128 && hex.chars().all(|character| character.is_ascii_digit() || ('a'..='f').contains(&character)),
+            _ => hex.len() >= 32 && hex.chars().all(|character| character.is_ascii_alphanumeric() || ['=', '_', '-'].contains(&character)),
+        };
+        match valid_algorithm && valid_hex {
+            true => Ok(digest.to_string()),
+            false => Err(error()),
+        }
+    }
+}
+
+impl FromStr for ImageReference {
+    type Err = ReferenceError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<&str> for ImageReference {
+    type Error = ReferenceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<String> for ImageReference {
+    type Error = ReferenceError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+/// Canonical form.
+impl Display for ImageReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.canonical())
+    }
+}
+
+/// Lets the `name`, `from_image` and `t` fields of the image params builders take a reference.
+impl From<ImageReference> for String {
+    fn from(value: ImageReference) -> Self {
+        value.canonical()
+    }
+}
+
+impl From<&ImageReference> for String {
+    fn from(value: &ImageReference) -> Self {
+        value.canonical()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn parses_a_registry_with_port() {
+        let reference = ImageReference::parse("localhost:5000/x").unwrap();
+        assert_eq!(reference.registry(), "localhost:5000");
+        assert_eq!(reference.repository(), "x");
+        assert_eq!(reference.tag(), Some(DEFAULT_TAG));
+        assert_eq!(reference.canonical(), "localhost:5000/x:latest");
+        assert_eq!(reference.familiar(), "localhost:5000/x:latest");
+    }
+
+    #[test]
+    fn normalizes_the_legacy_docker_hub_host() {
+        let reference = ImageReference::parse("index.docker.io/foo").unwrap();
+        assert_eq!(reference.registry(), DEFAULT_REGISTRY);
+        assert_eq!(reference.repository(), "library/foo");
+        assert_eq!(reference.canonical(), "docker.io/library/foo:latest");
+        assert_eq!(reference.familiar(), "foo:latest");
+    }
+
+    #[test]
+    fn keeps_a_digest_without_default_tag() {
+        let reference = ImageReference::parse(&format!("nginx@{}", DIGEST)).unwrap();
+        assert_eq!(reference.repository(), "library/nginx");
+        assert_eq!(reference.tag(), None);
+        assert_eq!(reference.digest(), Some(DIGEST));
+        assert_eq!(reference.canonical(), format!("docker.io/library/nginx@{}", DIGEST));
+
+        let reference = ImageReference::parse(&format!("nginx:1.27@{}", DIGEST)).unwrap();
+        assert_eq!(reference.tag(), Some("1.27"));
+        assert_eq!(reference.familiar(), format!("nginx:1.27@{}", DIGEST));
+    }
+
+    #[test]
+    fn rejects_invalid_references() {
+        assert!(ImageReference::parse("").is_err());
+        assert!(ImageReference::parse("Nginx").is_err());
+        assert!(ImageReference::parse("nginx:").is_err());
+        assert!(ImageReference::parse("nginx@sha256:abc").is_err());
+        assert!(ImageReference::parse("localhost:port/x").is_err());
+    }
+}
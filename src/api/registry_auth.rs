@@ -0,0 +1,429 @@
+use crate::api::docker_config::DockerConfig;
+use crate::api::reference::{ImageReference, DEFAULT_REGISTRY};
+use crate::models::AuthConfig;
+use base64::engine::general_purpose::URL_SAFE;
+use base64::Engine;
+use bollard::auth::DockerCredentials;
+use percent_encoding::percent_decode_str;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::RwLock;
+use url::Url;
+
+pub(crate) const REGISTRY_AUTH_HEADER: &str = "X-Registry-Auth";
+pub(crate) const REGISTRY_CONFIG_HEADER: &str = "X-Registry-Config";
+
+const DOCKER_HUB_HOST: &str = DEFAULT_REGISTRY;
+const DOCKER_HUB_INDEX: &str = "https://index.docker.io/v1/";
+const DOCKER_HUB_ALIASES: [&str; 3] = ["index.docker.io", "registry-1.docker.io", "registry.hub.docker.com"];
+
+/// Credentials of a registry, sent base64url encoded in the `X-Registry-Auth` header.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegistryAuth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(rename = "serveraddress", skip_serializing_if = "Option::is_none")]
+    pub server_address: Option<String>,
+    #[serde(rename = "identitytoken", skip_serializing_if = "Option::is_none")]
+    pub identity_token: Option<String>,
+    #[serde(rename = "registrytoken", skip_serializing_if = "Option::is_none")]
+    pub registry_token: Option<String>,
+}
+
+impl RegistryAuth {
+    pub fn with_password(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: Some(username.into()),
+            password: Some(password.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Refresh token returned by `/auth`, exchanged by the daemon for an access token.
+    pub fn with_identity_token(identity_token: impl Into<String>) -> Self {
+        Self {
+            identity_token: Some(identity_token.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_server_address(&mut self, server_address: impl Into<String>) -> &mut Self {
+        self.server_address = Some(server_address.into());
+        self
+    }
+
+    /// Value of the `X-Registry-Auth` header.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("Unable to serialize registry auth");
+        URL_SAFE.encode(json)
+    }
+}
+
+impl From<AuthConfig> for RegistryAuth {
+    fn from(value: AuthConfig) -> Self {
+        Self {
+            username: value.username,
+            password: value.password,
+            email: value.email,
+            server_address: value.serveraddress,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<RegistryAuth> for DockerCredentials {
+    fn from(value: RegistryAuth) -> Self {
+        Self {
+            username: value.username,
+            password: value.password,
+            email: value.email,
+            serveraddress: value.server_address,
+            identitytoken: value.identity_token,
+            registrytoken: value.registry_token,
+            ..Self::default()
+        }
+    }
+}
+
+/// Registry credentials of a client, by registry host.
+///
+/// The transports attach them to pull, push, build and plugin requests that do not carry
+/// credentials already, based on the registry host of the image or plugin reference.
+///
+/// Registries without explicit credentials fall back to the docker CLI configuration, if any.
+/// Credentials resolved from it are cached, failed lookups are retried on the next request.
+#[derive(Debug, Default)]
+pub struct RegistryAuths {
+    auths: RwLock<BTreeMap<String, RegistryAuth>>,
+    docker_config: RwLock<Option<DockerConfig>>,
+    resolved: RwLock<BTreeMap<String, Option<RegistryAuth>>>,
+    encoded_config: RwLock<Option<String>>,
+}
+
+impl RegistryAuths {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `registry` is a host such as `ghcr.io` or `localhost:5000`, or a server address such as
+    /// `https://index.docker.io/v1/`.
+    pub fn insert(&self, registry: &str, auth: RegistryAuth) {
+        self.auths.write().unwrap().insert(normalize_registry(registry), auth);
+        self.encoded_config.write().unwrap().take();
+    }
+
+    pub fn remove(&self, registry: &str) -> Option<RegistryAuth> {
+        let auth = self.auths.write().unwrap().remove(&normalize_registry(registry));
+        self.encoded_config.write().unwrap().take();
+        auth
+    }
+
+    /// Resolves the registries without explicit credentials from `config.json` and its
+    /// credential helpers, see `DockerConfig::credentials`.
+    pub fn use_docker_config(&self, docker_config: DockerConfig) {
+        *self.docker_config.write().unwrap() = Some(docker_config);
+        self.resolved.write().unwrap().clear();
+        self.encoded_config.write().unwrap().take();
+    }
+
+    /// Fails when a credential helper of the docker CLI configuration fails.
+    pub fn get(&self, registry: &str) -> io::Result<Option<RegistryAuth>> {
+        let registry = normalize_registry(registry);
+        if let Some(auth) = self.auths.read().unwrap().get(&registry) {
+            return Ok(Some(auth.clone()));
+        }
+        if let Some(auth) = self.resolved.read().unwrap().get(&registry) {
+            return Ok(auth.clone());
+        }
+        let auth = match self.docker_config.read().unwrap().as_ref() {
+            Some(docker_config) => docker_config.credentials(&registry)?,
+            None => None,
+        };
+        self.resolved.write().unwrap().insert(registry, auth.clone());
+        Ok(auth)
+    }
+
+    /// Value of the `X-Registry-Config` header, the credentials of every known registry.
+    pub fn encode_config(&self) -> io::Result<String> {
+        if let Some(config) = self.encoded_config.read().unwrap().as_ref() {
+            return Ok(config.clone());
+        }
+        let mut auths = match self.docker_config.read().unwrap().as_ref() {
+            Some(docker_config) => docker_config.all_credentials()?,
+            None => BTreeMap::new(),
+        };
+        auths.extend(self.auths.read().unwrap().clone());
+        let config = auths.iter()
+            .map(|(registry, auth)| (server_address(registry), auth))
+            .collect::<BTreeMap<_, _>>();
+        let json = serde_json::to_vec(&config).expect("Unable to serialize registry config");
+        let config = URL_SAFE.encode(json);
+        *self.encoded_config.write().unwrap() = Some(config.clone());
+        Ok(config)
+    }
+
+    /// Whether the operation at `url` may carry registry credentials, see `authenticate`.
+    pub(crate) fn is_authenticated(url: &Url) -> bool {
+        Self::operation_reference(operation_path(url.path()), url).is_some()
+    }
+
+    /// Adds the credentials expected by the operation at `url`, whatever its API version.
+    pub(crate) fn authenticate(&self, url: &Url, headers: &mut HeaderMap) -> io::Result<()> {
+        let reference = match Self::operation_reference(operation_path(url.path()), url) {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+        let header = match reference {
+            Reference::Image(_) => REGISTRY_AUTH_HEADER,
+            Reference::All => REGISTRY_CONFIG_HEADER,
+        };
+        let present = headers.get(header)
+            .is_some_and(|value| value.is_empty() == false);
+        if present {
+            return Ok(());
+        }
+        let value = match reference {
+            Reference::Image(reference) => match self.get(&registry_host(&reference))? {
+                Some(auth) => auth.encode(),
+                None => return Ok(()),
+            },
+            Reference::All => {
+                if self.auths.read().unwrap().is_empty() && self.docker_config.read().unwrap().is_none() {
+                    return Ok(());
+                }
+                self.encode_config()?
+            }
+        };
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            headers.insert(header, value);
+        }
+        Ok(())
+    }
+
+    fn operation_reference(path: &str, url: &Url) -> Option<Reference> {
+        let query = |key: &str| url.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.to_string());
+        let path = percent_decode_str(path).decode_utf8_lossy();
+        if path == "/build" {
+            return Some(Reference::All);
+        }
+        if path == "/images/create" {
+            return query("fromImage").map(Reference::Image);
+        }
+        // Pull of the libpod API, `/libpod/images/pull?reference=...`.
+        if path == "/images/pull" {
+            return query("reference").map(Reference::Image);
+        }
+        if path == "/plugins/pull" {
+            return query("remote").map(Reference::Image);
+        }
+        if let Some(name) = path.strip_prefix("/images/").and_then(|path| path.strip_suffix("/push")) {
+            return Some(Reference::Image(name.to_string()));
+        }
+        if let Some(name) = path.strip_prefix("/plugins/") {
+            if let Some(name) = name.strip_suffix("/push") {
+                return Some(Reference::Image(name.to_string()));
+            }
+            if name.ends_with("/upgrade") {
+                return query("remote").map(Reference::Image);
+            }
+        }
+        None
+    }
+}
+
+enum Reference {
+    Image(String),
+    All,
+}
+
+/// Path of an operation without its API root, e.g. `/v1.41/images/create` and
+/// `/v5.0.0/libpod/build` become `/images/create` and `/build`.
+fn operation_path(path: &str) -> &str {
+    let mut offset = 0;
+    let mut rest = path;
+    for segment in path.split('/') {
+        offset += segment.len();
+        if is_version_segment(segment) {
+            rest = &path[offset..];
+            break;
+        }
+        offset += 1;
+    }
+    match rest.strip_prefix("/libpod") {
+        Some(value) if value.is_empty() || value.starts_with('/') => value,
+        _ => rest,
+    }
+}
+
+/// `v1.47` or `v5.0.0`.
+fn is_version_segment(segment: &str) -> bool {
+    let numbers = match segment.strip_prefix('v') {
+        Some(value) => value.split('.').collect::<Vec<_>>(),
+        None => return false,
+    };
+    numbers.len() >= 2 && numbers.iter().all(|number| {
+        number.is_empty() == false && number.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+/// Registry host of an image or plugin reference, `docker.io` for references without one.
+///
+/// References the parser rejects, e.g. with upper case letters, still go to the registry of
+/// their first component when it looks like a host, the daemon reports the actual error.
+pub(crate) fn registry_host(reference: &str) -> String {
+    if let Ok(reference) = ImageReference::parse(reference) {
+        return normalize_registry(reference.registry());
+    }
+    match reference.split_once('/') {
+        Some((host, _)) if host.contains(['.', ':']) || host == "localhost" => normalize_registry(host),
+        _ => DOCKER_HUB_HOST.to_string(),
+    }
+}
+
+/// Host of a registry given as a host or as a server address.
+pub(crate) fn normalize_registry(registry: &str) -> String {
+    let host = registry.trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match DOCKER_HUB_ALIASES.contains(&host.as_str()) {
+        true => DOCKER_HUB_HOST.to_string(),
+        false => host,
+    }
+}
+
+/// Address of a registry host as known to the daemon and the credential helpers, Docker Hub
+/// credentials are stored under the legacy index address.
+pub(crate) fn server_address(registry: &str) -> String {
+    match registry == DOCKER_HUB_HOST {
+        true => DOCKER_HUB_INDEX.to_string(),
+        false => registry.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testing::TempDirectory;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+
+    const FAKE_HELPER: &str = r#"#!/bin/sh
+read server
+case "$1" in
+    get)
+        if [ "$server" = "registry.example.com" ]; then
+            echo '{"ServerURL":"registry.example.com","Username":"user","Secret":"secret"}'
+        else
+            echo "credentials not found in native keychain"
+            exit 1
+        fi;;
+    list)
+        echo '{"registry.example.com":"user"}';;
+esac
+"#;
+
+    const BROKEN_HELPER: &str = "#!/bin/sh\necho 'keychain locked'\nexit 1\n";
+
+    fn write_helper(directory: &Path, name: &str, script: &str) {
+        let path = directory.join(format!("docker-credential-{}", name));
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn operation_path_strips_any_api_root() {
+        assert_eq!(operation_path("/v1.47/images/create"), "/images/create");
+        assert_eq!(operation_path("/v1.41/images/create"), "/images/create");
+        assert_eq!(operation_path("/docker/v1.45/build"), "/build");
+        assert_eq!(operation_path("/v5.0.0/libpod/build"), "/build");
+        assert_eq!(operation_path("/libpod/images/pull"), "/images/pull");
+        assert_eq!(operation_path("/images/create"), "/images/create");
+        assert_eq!(operation_path("/v1.47/images/v1.2/push"), "/images/v1.2/push");
+        assert_eq!(operation_path("/v1.47/libpodx/push"), "/libpodx/push");
+    }
+
+    #[test]
+    fn authenticates_pulls_of_a_negotiated_version() {
+        let auths = RegistryAuths::new();
+        auths.insert("registry.example.com", RegistryAuth::with_password("user", "secret"));
+        let url = Url::parse("http://localhost/v1.41/images/create?fromImage=registry.example.com/app&tag=1").unwrap();
+        let mut headers = HeaderMap::new();
+        auths.authenticate(&url, &mut headers).unwrap();
+        assert_eq!(
+            headers.get(REGISTRY_AUTH_HEADER).unwrap().to_str().unwrap(),
+            RegistryAuth::with_password("user", "secret").encode()
+        );
+    }
+
+    #[test]
+    fn authenticates_libpod_pulls() {
+        let auths = RegistryAuths::new();
+        auths.insert("quay.io", RegistryAuth::with_password("user", "secret"));
+        let url = Url::parse("http://d/v5.0.0/libpod/images/pull?reference=quay.io%2Fpodman%2Fhello%3Alatest").unwrap();
+        assert!(RegistryAuths::is_authenticated(&url));
+        let mut headers = HeaderMap::new();
+        auths.authenticate(&url, &mut headers).unwrap();
+        assert_eq!(
+            headers.get(REGISTRY_AUTH_HEADER).unwrap().to_str().unwrap(),
+            RegistryAuth::with_password("user", "secret").encode()
+        );
+
+        let url = Url::parse("http://d/v5.0.0/libpod/images/pull?reference=docker.io%2Flibrary%2Falpine").unwrap();
+        let mut headers = HeaderMap::new();
+        auths.authenticate(&url, &mut headers).unwrap();
+        assert!(headers.get(REGISTRY_AUTH_HEADER).is_none());
+    }
+
+    #[test]
+    fn registry_host_of_invalid_references_follows_the_first_component() {
+        assert_eq!(registry_host("ghcr.io/owner/app:1.0"), "ghcr.io");
+        assert_eq!(registry_host("ghcr.io/Owner/App"), "ghcr.io");
+        assert_eq!(registry_host("localhost:5000/App"), "localhost:5000");
+        assert_eq!(registry_host("localhost/App"), "localhost");
+        assert_eq!(registry_host("Owner/App"), "docker.io");
+        assert_eq!(registry_host("index.docker.io/Owner/App"), "docker.io");
+    }
+
+    #[test]
+    fn resolves_credentials_through_helpers() {
+        let directory = TempDirectory::new("helpers");
+        write_helper(directory.path(), "fake", FAKE_HELPER);
+        write_helper(directory.path(), "locked", BROKEN_HELPER);
+
+        let auths = RegistryAuths::new();
+        auths.use_docker_config(DockerConfig {
+            creds_store: Some("fake".to_string()),
+            cred_helpers: HashMap::from([("locked.example.com".to_string(), "locked".to_string())]),
+            helper_directory: Some(directory.path().to_path_buf()),
+            ..DockerConfig::default()
+        });
+        let mut expected = RegistryAuth::with_password("user", "secret");
+        expected.with_server_address("registry.example.com");
+        assert_eq!(auths.get("registry.example.com").unwrap(), Some(expected.clone()));
+        assert_eq!(auths.get("other.example.com").unwrap(), None);
+
+        let error = auths.get("locked.example.com").unwrap_err();
+        assert!(error.to_string().contains("keychain locked"));
+        assert!(auths.encode_config().is_err());
+
+        // Failures are not cached, the unlocked helper is run again.
+        write_helper(directory.path(), "locked", FAKE_HELPER);
+        assert_eq!(auths.get("locked.example.com").unwrap(), None);
+        let config = auths.encode_config().unwrap();
+        let config = serde_json::from_slice::<BTreeMap<String, RegistryAuth>>(&URL_SAFE.decode(config).unwrap()).unwrap();
+        assert_eq!(config.get("registry.example.com"), Some(&expected));
+    }
+}
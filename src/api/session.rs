@@ -0,0 +1,482 @@
+use crate::api::frame::FrameDecoder;
+use crate::api::internals::api_async;
+use crate::api::internals::api_sync::{Configuration, ContainerApi, ContainerResizeError, Error, ExecApi, ExecInspectError, ExecResizeError};
+use crate::api::stream::{AsyncLogStream, LogStream};
+use crate::api::transport::{read_stream, Connection, Hijacked, WriteHalf};
+use crate::models::ExecInspectResponse;
+use crate::params::{ContainerResizeParams, ExecInspectParams, ExecResizeParams};
+use bytes::Bytes;
+use std::io;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use tungstenite::{Message, WebSocket};
+
+/// Stdin of a hijacked session.
+pub struct SessionInput {
+    writer: Option<Box<dyn WriteHalf>>,
+}
+
+impl SessionInput {
+    /// Sends end of file to the process, e.g. so `cat` or a database shell exits.
+    pub fn close(&mut self) -> io::Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush()?;
+            writer.shutdown()?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for SessionInput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.writer.as_mut() {
+            Some(writer) => writer.write(buf),
+            None => Err(io::Error::new(io::ErrorKind::BrokenPipe, "Session input is closed")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.writer.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Splits `hijacked` in the input and output of a blocking or async session. Without
+/// `multiplexed` the framing is read from the response headers.
+fn split<I, O>(
+    hijacked: Hijacked,
+    multiplexed: Option<bool>,
+    input: impl FnOnce(Box<dyn WriteHalf>) -> I,
+    output: impl FnOnce(Box<dyn Read + Send>, Option<bool>) -> O
+) -> (I, O) {
+    let multiplexed = multiplexed.or(FrameDecoder::framing(&hijacked.headers));
+    (input(hijacked.writer), output(hijacked.reader, multiplexed))
+}
+
+fn blocking_halves(hijacked: Hijacked, multiplexed: Option<bool>) -> (SessionInput, LogStream) {
+    let input = |writer| SessionInput {
+        writer: Some(writer),
+    };
+    split(hijacked, multiplexed, input, LogStream::from_reader)
+}
+
+/// Interactive session on a running container, opened by `ContainerClient::attach`.
+///
+/// Stdin is written through `input`, stdout and stderr frames are read from `output`. Use
+/// `split` to read and write from different threads.
+pub struct AttachSession {
+    id: String,
+    configuration: Arc<Configuration>,
+    input: SessionInput,
+    output: LogStream,
+}
+
+impl AttachSession {
+    pub(crate) fn new(id: String, configuration: Arc<Configuration>, hijacked: Hijacked) -> Self {
+        let (input, output) = blocking_halves(hijacked, None);
+        Self {
+            id,
+            configuration,
+            input,
+            output,
+        }
+    }
+
+    pub fn input(&mut self) -> &mut SessionInput {
+        &mut self.input
+    }
+
+    pub fn output(&mut self) -> &mut LogStream {
+        &mut self.output
+    }
+
+    /// Resizes the TTY of the container.
+    pub fn resize(&self, height: u16, width: u16) -> Result<(), Error<ContainerResizeError>> {
+        ContainerApi::new(self.configuration.clone()).resize(ContainerResizeParams {
+            id: self.id.clone(),
+            h: height as i32,
+            w: width as i32,
+        })
+    }
+
+    pub fn split(self) -> (SessionInput, LogStream) {
+        (self.input, self.output)
+    }
+}
+
+/// Interactive exec instance, opened by `ContainerClient::exec_session`.
+pub struct ExecSession {
+    id: String,
+    configuration: Arc<Configuration>,
+    input: SessionInput,
+    output: LogStream,
+}
+
+impl ExecSession {
+    pub(crate) fn new(id: String, configuration: Arc<Configuration>, hijacked: Hijacked, tty: bool) -> Self {
+        let (input, output) = blocking_halves(hijacked, Some(tty == false));
+        Self {
+            id,
+            configuration,
+            input,
+            output,
+        }
+    }
+
+    /// Identifier of the exec instance.
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn input(&mut self) -> &mut SessionInput {
+        &mut self.input
+    }
+
+    pub fn output(&mut self) -> &mut LogStream {
+        &mut self.output
+    }
+
+    /// Resizes the TTY of the exec instance.
+    pub fn resize(&self, height: u16, width: u16) -> Result<(), Error<ExecResizeError>> {
+        ExecApi::new(self.configuration.clone()).resize(ExecResizeParams {
+            id: self.id.clone(),
+            h: height as i32,
+            w: width as i32,
+        })
+    }
+
+    /// State of the exec instance, the exit code is set once the output is exhausted.
+    pub fn inspect(&self) -> Result<ExecInspectResponse, Error<ExecInspectError>> {
+        ExecApi::new(self.configuration.clone()).inspect(ExecInspectParams {
+            id: self.id.clone(),
+        })
+    }
+
+    pub fn split(self) -> (SessionInput, LogStream) {
+        (self.input, self.output)
+    }
+}
+
+/// Async counterpart of `SessionInput`, writes are performed from the blocking thread pool.
+///
+/// The input is closed when a write is cancelled before it completes.
+pub struct AsyncSessionInput {
+    writer: Option<Box<dyn WriteHalf>>,
+}
+
+impl AsyncSessionInput {
+    /// Writes and flushes `bytes` to the process stdin.
+    pub async fn write_all(&mut self, bytes: impl Into<Vec<u8>>) -> io::Result<()> {
+        let bytes = bytes.into();
+        self.with_writer(move |writer| {
+            writer.write_all(&bytes)?;
+            writer.flush()
+        }).await
+    }
+
+    /// Sends end of file to the process, e.g. so `cat` or a database shell exits.
+    pub async fn close(&mut self) -> io::Result<()> {
+        if self.writer.is_none() {
+            return Ok(());
+        }
+        self.with_writer(|writer| {
+            writer.flush()?;
+            writer.shutdown()
+        }).await?;
+        self.writer = None;
+        Ok(())
+    }
+
+    async fn with_writer<F>(&mut self, operation: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Box<dyn WriteHalf>) -> io::Result<()> + Send + 'static
+    {
+        let mut writer = self.writer.take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "Session input is closed"))?;
+        let (writer, result) = tokio::task::spawn_blocking(move || {
+            let result = operation(&mut writer);
+            (writer, result)
+        })
+            .await
+            .map_err(io::Error::other)?;
+        self.writer = Some(writer);
+        result
+    }
+}
+
+fn async_halves(hijacked: Hijacked, multiplexed: Option<bool>) -> (AsyncSessionInput, AsyncLogStream) {
+    let input = |writer| AsyncSessionInput {
+        writer: Some(writer),
+    };
+    let output = |reader, multiplexed| AsyncLogStream::from_stream(read_stream(reader), multiplexed);
+    split(hijacked, multiplexed, input, output)
+}
+
+/// Async counterpart of `AttachSession`, opened by `asynchronous::ContainerClient::attach`.
+pub struct AsyncAttachSession {
+    id: String,
+    configuration: Arc<api_async::Configuration>,
+    input: AsyncSessionInput,
+    output: AsyncLogStream,
+}
+
+impl AsyncAttachSession {
+    pub(crate) fn new(id: String, configuration: Arc<api_async::Configuration>, hijacked: Hijacked) -> Self {
+        let (input, output) = async_halves(hijacked, None);
+        Self {
+            id,
+            configuration,
+            input,
+            output,
+        }
+    }
+
+    pub fn input(&mut self) -> &mut AsyncSessionInput {
+        &mut self.input
+    }
+
+    pub fn output(&mut self) -> &mut AsyncLogStream {
+        &mut self.output
+    }
+
+    /// Resizes the TTY of the container.
+    pub async fn resize(&self, height: u16, width: u16) -> Result<(), api_async::Error<api_async::ContainerResizeError>> {
+        api_async::ContainerApi::new(self.configuration.clone()).resize(ContainerResizeParams {
+            id: self.id.clone(),
+            h: height as i32,
+            w: width as i32,
+        }).await
+    }
+
+    pub fn split(self) -> (AsyncSessionInput, AsyncLogStream) {
+        (self.input, self.output)
+    }
+}
+
+/// Async counterpart of `ExecSession`, opened by `asynchronous::ContainerClient::exec_session`.
+pub struct AsyncExecSession {
+    id: String,
+    configuration: Arc<api_async::Configuration>,
+    input: AsyncSessionInput,
+    output: AsyncLogStream,
+}
+
+impl AsyncExecSession {
+    pub(crate) fn new(id: String, configuration: Arc<api_async::Configuration>, hijacked: Hijacked, tty: bool) -> Self {
+        let (input, output) = async_halves(hijacked, Some(tty == false));
+        Self {
+            id,
+            configuration,
+            input,
+            output,
+        }
+    }
+
+    /// Identifier of the exec instance.
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn input(&mut self) -> &mut AsyncSessionInput {
+        &mut self.input
+    }
+
+    pub fn output(&mut self) -> &mut AsyncLogStream {
+        &mut self.output
+    }
+
+    /// Resizes the TTY of the exec instance.
+    pub async fn resize(&self, height: u16, width: u16) -> Result<(), api_async::Error<api_async::ExecResizeError>> {
+        api_async::ExecApi::new(self.configuration.clone()).resize(ExecResizeParams {
+            id: self.id.clone(),
+            h: height as i32,
+            w: width as i32,
+        }).await
+    }
+
+    /// State of the exec instance, the exit code is set once the output is exhausted.
+    pub async fn inspect(&self) -> Result<ExecInspectResponse, api_async::Error<api_async::ExecInspectError>> {
+        api_async::ExecApi::new(self.configuration.clone()).inspect(ExecInspectParams {
+            id: self.id.clone(),
+        }).await
+    }
+
+    pub fn split(self) -> (AsyncSessionInput, AsyncLogStream) {
+        (self.input, self.output)
+    }
+}
+
+/// Duplex console of a container over `/containers/{id}/attach/ws`, opened by
+/// `ContainerClient::attach_websocket`.
+///
+/// Messages carry the raw console bytes, stdout and stderr are not separated. Any stream can
+/// back the session, e.g. an in-process server through `from_stream`.
+pub struct WebSocketSession<S = Box<dyn Connection>> {
+    socket: WebSocket<S>,
+}
+
+impl<S: Read + Write> WebSocketSession<S> {
+    pub(crate) fn new(socket: WebSocket<S>) -> Self {
+        Self {
+            socket
+        }
+    }
+
+    /// Performs the WebSocket handshake for `url` over an already connected stream.
+    pub fn from_stream(stream: S, url: &str) -> io::Result<Self> {
+        let (socket, _) = tungstenite::client::client(url, stream)
+            .map_err(|error| match error {
+                tungstenite::HandshakeError::Failure(error) => websocket_error(error),
+                tungstenite::HandshakeError::Interrupted(_) => io::Error::new(
+                    io::ErrorKind::WouldBlock,
+                    "WebSocket handshake interrupted"
+                ),
+            })?;
+        Ok(Self::new(socket))
+    }
+
+    /// Sends bytes to the container stdin.
+    pub fn send(&mut self, bytes: impl Into<Vec<u8>>) -> io::Result<()> {
+        self.socket.send(Message::Binary(bytes.into()))
+            .map_err(websocket_error)
+    }
+
+    /// Next console output, `None` once the daemon closed the socket.
+    pub fn receive(&mut self) -> io::Result<Option<Bytes>> {
+        loop {
+            match self.socket.read() {
+                Ok(Message::Binary(bytes)) => return Ok(Some(Bytes::from(bytes))),
+                Ok(Message::Text(text)) => return Ok(Some(Bytes::from(text))),
+                Ok(_) => continue,
+                Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => return Ok(None),
+                Err(error) => return Err(websocket_error(error)),
+            }
+        }
+    }
+
+    pub fn close(&mut self) -> io::Result<()> {
+        self.socket.close(None)
+            .map_err(websocket_error)?;
+        self.socket.flush()
+            .map_err(websocket_error)
+    }
+}
+
+impl<S: Read + Write> Iterator for WebSocketSession<S> {
+    type Item = io::Result<Bytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receive().transpose()
+    }
+}
+
+/// Errors of the socket are returned as is, the protocol errors as `InvalidData`.
+fn websocket_error(error: tungstenite::Error) -> io::Error {
+    match error {
+        tungstenite::Error::Io(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, error),
+    }
+}
+
+/// Async counterpart of `WebSocketSession`, opened by `asynchronous::ContainerClient::attach_websocket`.
+///
+/// The socket is driven from the blocking thread pool, it is closed when an operation is
+/// cancelled before it completes.
+pub struct AsyncWebSocketSession<S = Box<dyn Connection>> {
+    session: Option<WebSocketSession<S>>,
+}
+
+impl<S: Read + Write + Send + 'static> AsyncWebSocketSession<S> {
+    pub fn new(session: WebSocketSession<S>) -> Self {
+        Self {
+            session: Some(session),
+        }
+    }
+
+    /// Sends bytes to the container stdin.
+    pub async fn send(&mut self, bytes: impl Into<Vec<u8>>) -> io::Result<()> {
+        let bytes = bytes.into();
+        self.with_session(move |session| session.send(bytes)).await
+    }
+
+    /// Next console output, `None` once the daemon closed the socket.
+    pub async fn receive(&mut self) -> io::Result<Option<Bytes>> {
+        self.with_session(|session| session.receive()).await
+    }
+
+    pub async fn close(&mut self) -> io::Result<()> {
+        self.with_session(|session| session.close()).await
+    }
+
+    async fn with_session<F, T>(&mut self, operation: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut WebSocketSession<S>) -> io::Result<T> + Send + 'static,
+        T: Send + 'static
+    {
+        let mut session = self.session.take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "WebSocket session is closed"))?;
+        let (session, result) = tokio::task::spawn_blocking(move || {
+            let result = operation(&mut session);
+            (session, result)
+        })
+            .await
+            .map_err(io::Error::other)?;
+        self.session = Some(session);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+    use std::thread;
+
+    /// Console answering `ls` then closing the socket.
+    fn start_console() -> (SocketAddr, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            let message = socket.read().unwrap();
+            assert_eq!(message, Message::Binary(b"ls\n".to_vec()));
+            socket.send(Message::Binary(b"bin\netc\n".to_vec())).unwrap();
+            socket.send(Message::Text("$ ".to_string())).unwrap();
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        });
+        (address, server)
+    }
+
+    #[test]
+    fn websocket_session_exchanges_console_bytes() {
+        let (address, server) = start_console();
+        let stream = TcpStream::connect(address).unwrap();
+        let url = format!("ws://{}/containers/web/attach/ws?stream=true", address);
+        let mut session = WebSocketSession::from_stream(stream, &url).unwrap();
+        session.send("ls\n").unwrap();
+        assert_eq!(session.receive().unwrap(), Some(Bytes::from_static(b"bin\netc\n")));
+        assert_eq!(session.receive().unwrap(), Some(Bytes::from_static(b"$ ")));
+        assert_eq!(session.receive().unwrap(), None);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn async_websocket_session_exchanges_console_bytes() {
+        let (address, server) = start_console();
+        let stream = TcpStream::connect(address).unwrap();
+        let url = format!("ws://{}/containers/web/attach/ws?stream=true", address);
+        let mut session = AsyncWebSocketSession::new(WebSocketSession::from_stream(stream, &url).unwrap());
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            session.send("ls\n").await.unwrap();
+            assert_eq!(session.receive().await.unwrap(), Some(Bytes::from_static(b"bin\netc\n")));
+            assert_eq!(session.receive().await.unwrap(), Some(Bytes::from_static(b"$ ")));
+            assert_eq!(session.receive().await.unwrap(), None);
+        });
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,169 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Message of the `/containers/{id}/stats` stream.
+///
+/// The specification describes it as a plain object. Fields missing on a platform, e.g. the
+/// `blkio_stats` of Windows or the `memory_stats.max_usage` of cgroup v2, are left empty.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ContainerStatsResponse {
+    pub name: Option<String>,
+    pub id: Option<String>,
+    /// Time of the sample, in RFC 3339 format.
+    pub read: Option<String>,
+    /// Time of the previous sample, in RFC 3339 format.
+    pub preread: Option<String>,
+    pub pids_stats: Option<PidsStats>,
+    pub blkio_stats: Option<BlkioStats>,
+    pub num_procs: Option<u32>,
+    pub storage_stats: Option<StorageStats>,
+    pub cpu_stats: Option<CpuStats>,
+    /// CPU statistics of the previous sample.
+    pub precpu_stats: Option<CpuStats>,
+    pub memory_stats: Option<MemoryStats>,
+    /// Network statistics by interface name.
+    pub networks: Option<HashMap<String, NetworkStats>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PidsStats {
+    pub current: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct BlkioStats {
+    pub io_service_bytes_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_serviced_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_queue_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_service_time_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_wait_time_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_merged_recursive: Option<Vec<BlkioStatEntry>>,
+    pub io_time_recursive: Option<Vec<BlkioStatEntry>>,
+    pub sectors_recursive: Option<Vec<BlkioStatEntry>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct BlkioStatEntry {
+    pub major: Option<u64>,
+    pub minor: Option<u64>,
+    pub op: Option<String>,
+    pub value: Option<u64>,
+}
+
+/// Disk statistics, only reported on Windows.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct StorageStats {
+    pub read_count_normalized: Option<u64>,
+    pub read_size_bytes: Option<u64>,
+    pub write_count_normalized: Option<u64>,
+    pub write_size_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct CpuStats {
+    pub cpu_usage: Option<CpuUsage>,
+    pub system_cpu_usage: Option<u64>,
+    pub online_cpus: Option<u32>,
+    pub throttling_data: Option<ThrottlingData>,
+}
+
+impl CpuStats {
+    /// CPUs available to the container, the length of `percpu_usage` for older daemons.
+    pub fn cpus(&self) -> Option<u32> {
+        self.online_cpus.or_else(|| {
+            self.cpu_usage.as_ref()
+                .and_then(|usage| usage.percpu_usage.as_ref())
+                .map(|usage| usage.len() as u32)
+        })
+    }
+}
+
+/// CPU time, in nanoseconds.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct CpuUsage {
+    pub total_usage: Option<u64>,
+    pub percpu_usage: Option<Vec<u64>>,
+    pub usage_in_kernelmode: Option<u64>,
+    pub usage_in_usermode: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ThrottlingData {
+    pub periods: Option<u64>,
+    pub throttled_periods: Option<u64>,
+    pub throttled_time: Option<u64>,
+}
+
+/// Memory statistics, in bytes. `stats` holds the cgroup counters, whose names differ between
+/// cgroup v1 and v2.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct MemoryStats {
+    pub usage: Option<u64>,
+    pub max_usage: Option<u64>,
+    pub stats: Option<HashMap<String, u64>>,
+    pub failcnt: Option<u64>,
+    pub limit: Option<u64>,
+    pub commitbytes: Option<u64>,
+    pub commitpeakbytes: Option<u64>,
+    pub privateworkingset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct NetworkStats {
+    pub rx_bytes: Option<u64>,
+    pub rx_packets: Option<u64>,
+    pub rx_errors: Option<u64>,
+    pub rx_dropped: Option<u64>,
+    pub tx_bytes: Option<u64>,
+    pub tx_packets: Option<u64>,
+    pub tx_errors: Option<u64>,
+    pub tx_dropped: Option<u64>,
+    pub endpoint_id: Option<String>,
+    pub instance_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample of a Linux daemon with cgroup v2, trimmed.
+    const STATS: &str = r#"{
+        "name": "/web",
+        "id": "b5f1",
+        "read": "2024-05-02T09:31:03.123456789Z",
+        "preread": "2024-05-02T09:31:02.121212121Z",
+        "pids_stats": {"current": 3, "limit": 18446744073709551615},
+        "blkio_stats": {
+            "io_service_bytes_recursive": [{"major": 8, "minor": 0, "op": "read", "value": 4096}],
+            "io_serviced_recursive": null
+        },
+        "num_procs": 0,
+        "storage_stats": {},
+        "cpu_stats": {
+            "cpu_usage": {"total_usage": 200000000, "usage_in_kernelmode": 50000000, "usage_in_usermode": 150000000},
+            "system_cpu_usage": 9000000000,
+            "online_cpus": 4,
+            "throttling_data": {"periods": 0, "throttled_periods": 0, "throttled_time": 0}
+        },
+        "precpu_stats": {
+            "cpu_usage": {"total_usage": 100000000, "percpu_usage": [60000000, 40000000]},
+            "throttling_data": {}
+        },
+        "memory_stats": {"usage": 10485760, "stats": {"anon": 4096, "file": 8192}, "limit": 2147483648},
+        "networks": {"eth0": {"rx_bytes": 1024, "rx_packets": 8, "tx_bytes": 512, "tx_packets": 4}}
+    }"#;
+
+    #[test]
+    fn parses_a_stats_message() {
+        let stats = serde_json::from_str::<ContainerStatsResponse>(STATS).unwrap();
+        assert_eq!(stats.pids_stats.unwrap().limit, Some(u64::MAX));
+        let blkio_stats = stats.blkio_stats.unwrap();
+        assert_eq!(blkio_stats.io_service_bytes_recursive.unwrap()[0].value, Some(4096));
+        assert_eq!(blkio_stats.io_serviced_recursive, None);
+        assert_eq!(stats.cpu_stats.unwrap().cpus(), Some(4));
+        assert_eq!(stats.precpu_stats.unwrap().cpus(), Some(2));
+        assert_eq!(stats.memory_stats.unwrap().stats.unwrap()["file"], 8192);
+        assert_eq!(stats.networks.unwrap()["eth0"].tx_packets, Some(4));
+    }
+}
@@ -0,0 +1,314 @@
+use crate::api::frame::{FrameDecoder, LogFrame};
+use crate::models::ErrorDetail;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::de::IoRead;
+use serde_json::StreamDeserializer;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Read;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::{error, fmt, io};
+
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Error of an operation reporting its progress as JSON messages, e.g. a pull or a push.
+#[derive(Debug)]
+pub enum ProgressError<E> {
+    /// The operation could not be started.
+    Request(E),
+    /// The progress stream was interrupted or malformed.
+    Stream(serde_json::Error),
+    /// The daemon reported an error in the progress stream, e.g. an unknown manifest or a
+    /// denied access to the repository.
+    Daemon {
+        code: Option<i32>,
+        message: String,
+    },
+}
+
+impl<E> ProgressError<E> {
+    /// Error reported by a progress message, from its `errorDetail` or else its `error`.
+    pub(crate) fn from_message(error: Option<&String>, error_detail: Option<&ErrorDetail>) -> Option<Self> {
+        if error.is_none() && error_detail.is_none() {
+            return None;
+        }
+        Some(ProgressError::Daemon {
+            code: error_detail.and_then(|detail| detail.code),
+            message: error_detail.and_then(|detail| detail.message.clone())
+                .or(error.cloned())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl<E: Display> Display for ProgressError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressError::Request(error) => write!(f, "request error: {}", error),
+            ProgressError::Stream(error) => write!(f, "progress stream error: {}", error),
+            ProgressError::Daemon { message, .. } => write!(f, "daemon error: {}", message),
+        }
+    }
+}
+
+impl<E: Debug + Display> error::Error for ProgressError<E> {}
+
+/// Messages of a streaming endpoint such as `/build`, `/images/create` or `/events`, decoded
+/// as they arrive.
+pub struct JsonStream<T> {
+    messages: StreamDeserializer<'static, IoRead<Box<dyn Read + Send>>, T>,
+}
+
+impl<T: DeserializeOwned> JsonStream<T> {
+    pub fn new(reader: impl Read + Send + 'static) -> Self {
+        let reader: Box<dyn Read + Send> = Box::new(reader);
+        Self {
+            messages: serde_json::Deserializer::from_reader(reader).into_iter(),
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for JsonStream<T> {
+    type Item = Result<T, serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.messages.next()
+    }
+}
+
+/// Async counterpart of `JsonStream`, messages are separated by new lines.
+pub struct AsyncJsonStream<T> {
+    messages: BoxStream<'static, Result<T, serde_json::Error>>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> AsyncJsonStream<T> {
+    pub fn new(response: reqwest::Response) -> Self {
+        Self::from_stream(response.bytes_stream())
+    }
+
+    pub fn from_stream<E>(body: impl Stream<Item = Result<Bytes, E>> + Send + 'static) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static
+    {
+        let lines = Lines::new(body.boxed());
+        let messages = futures::stream::unfold(lines, |mut lines| async move {
+            let line = match lines.next_line().await? {
+                Ok(value) => value,
+                Err(error) => return Some((Err(serde_json::Error::io(error)), lines)),
+            };
+            Some((serde_json::from_slice(&line), lines))
+        });
+        Self {
+            messages: messages.boxed(),
+        }
+    }
+}
+
+impl<T> Stream for AsyncJsonStream<T> {
+    type Item = Result<T, serde_json::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_next_unpin(cx)
+    }
+}
+
+/// Splits a body into non blank lines.
+struct Lines<E> {
+    body: BoxStream<'static, Result<Bytes, E>>,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<E: std::error::Error + Send + Sync + 'static> Lines<E> {
+    fn new(body: BoxStream<'static, Result<Bytes, E>>) -> Self {
+        Self {
+            body,
+            buffer: Vec::new(),
+            done: false,
+        }
+    }
+
+    async fn next_line(&mut self) -> Option<io::Result<Vec<u8>>> {
+        loop {
+            if let Some(position) = self.buffer.iter().position(|byte| *byte == b'\n') {
+                let line = self.buffer.drain(..=position).collect::<Vec<_>>();
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                return Some(Ok(line));
+            }
+            if self.done {
+                if self.buffer.iter().all(u8::is_ascii_whitespace) {
+                    return None;
+                }
+                return Some(Ok(std::mem::take(&mut self.buffer)));
+            }
+            match self.body.next().await {
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Some(Err(error)) => {
+                    self.done = true;
+                    self.buffer.clear();
+                    return Some(Err(io::Error::other(error)));
+                }
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+/// Output of logs, attach and exec, demultiplexed into stdout and stderr frames.
+///
+/// Iterates over the frames, or reads the payloads of every stream through `std::io::Read`.
+pub struct LogStream {
+    reader: Box<dyn Read + Send>,
+    decoder: FrameDecoder,
+    pending: Bytes,
+    done: bool,
+    failed: bool,
+}
+
+impl LogStream {
+    pub fn new(response: reqwest::blocking::Response) -> Self {
+        let multiplexed = FrameDecoder::framing(response.headers());
+        Self::from_reader(response, multiplexed)
+    }
+
+    /// `multiplexed` is `None` when the framing should be detected from the first bytes.
+    pub fn from_reader(reader: impl Read + Send + 'static, multiplexed: Option<bool>) -> Self {
+        Self {
+            reader: Box::new(reader),
+            decoder: FrameDecoder::new(multiplexed),
+            pending: Bytes::new(),
+            done: false,
+            failed: false,
+        }
+    }
+
+    /// Splits the timestamps of `timestamps=true` requests out of the frames.
+    pub fn with_timestamps(&mut self, timestamps: bool) -> &mut Self {
+        self.decoder.with_timestamps(timestamps);
+        self
+    }
+}
+
+impl Iterator for LogStream {
+    type Item = io::Result<LogFrame>;
+
+    /// Ends after the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self.next_frame();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+impl LogStream {
+    fn next_frame(&mut self) -> Option<io::Result<LogFrame>> {
+        let mut chunk = [0; READ_CHUNK_SIZE];
+        loop {
+            match self.decoder.decode() {
+                Ok(Some(frame)) => return Some(Ok(frame)),
+                Ok(None) => {}
+                Err(error) => return Some(Err(error)),
+            }
+            if self.done {
+                return self.decoder.finish().transpose();
+            }
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.done = true,
+                Ok(length) => self.decoder.push(&chunk[..length]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+impl Read for LogStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pending.is_empty() {
+            match self.next() {
+                Some(frame) => self.pending = frame?.bytes,
+                None => return Ok(0),
+            }
+        }
+        let length = buf.len().min(self.pending.len());
+        buf[..length].copy_from_slice(&self.pending.split_to(length));
+        Ok(length)
+    }
+}
+
+/// Async counterpart of `LogStream`.
+pub struct AsyncLogStream {
+    body: BoxStream<'static, io::Result<Bytes>>,
+    decoder: FrameDecoder,
+    done: bool,
+    failed: bool,
+}
+
+impl AsyncLogStream {
+    pub fn new(response: reqwest::Response) -> Self {
+        let multiplexed = FrameDecoder::framing(response.headers());
+        let body = response.bytes_stream()
+            .map(|chunk| chunk.map_err(io::Error::other));
+        Self::from_stream(body, multiplexed)
+    }
+
+    /// `multiplexed` is `None` when the framing should be detected from the first bytes.
+    pub fn from_stream(body: impl Stream<Item = io::Result<Bytes>> + Send + 'static, multiplexed: Option<bool>) -> Self {
+        Self {
+            body: body.boxed(),
+            decoder: FrameDecoder::new(multiplexed),
+            done: false,
+            failed: false,
+        }
+    }
+
+    /// Splits the timestamps of `timestamps=true` requests out of the frames.
+    pub fn with_timestamps(&mut self, timestamps: bool) -> &mut Self {
+        self.decoder.with_timestamps(timestamps);
+        self
+    }
+
+    fn poll_frame(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<LogFrame>>> {
+        loop {
+            match self.decoder.decode() {
+                Ok(Some(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Ok(None) => {}
+                Err(error) => return Poll::Ready(Some(Err(error))),
+            }
+            if self.done {
+                return Poll::Ready(self.decoder.finish().transpose());
+            }
+            match self.body.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(chunk))) => self.decoder.push(&chunk),
+                Poll::Ready(Some(Err(error))) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+                Poll::Ready(None) => self.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Stream for AsyncLogStream {
+    type Item = io::Result<LogFrame>;
+
+    /// Ends after the first error.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.failed {
+            return Poll::Ready(None);
+        }
+        let result = self.poll_frame(cx);
+        self.failed = matches!(result, Poll::Ready(Some(Err(_))));
+        result
+    }
+}
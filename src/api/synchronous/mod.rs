@@ -1,60 +1,16 @@
-use crate::api::internals::api_sync::Configuration;
+use crate::api::engine::Engine;
+use crate::api::internals::api_sync::{Configuration, ContainerAttachError, ContainerAttachWebsocketError, ContainerExecError, Error, SystemVersionError};
 use crate::api::internals::api_sync::{ConfigApi, ContainerApi, DistributionApi, ExecApi, ImageApi, NetworkApi, NodeApi, PluginApi, SecretApi, ServiceApi, SessionApi, SwarmApi, SystemApi, TaskApi, VolumeApi};
-use reqwest::blocking::ClientBuilder;
-use std::sync::Arc;
+use crate::api::registry_auth::RegistryAuths;
+use crate::api::session::{AttachSession, ExecSession, WebSocketSession};
+use crate::api::transport::{attach_request, attach_websocket_url, exec_start_request, TransportError};
+use crate::api::version::ApiVersion;
+use crate::models::ExecConfig;
+use crate::params::{ContainerAttachParams, ContainerAttachWebsocketParams, ContainerExecParams};
+use std::sync::{Arc, OnceLock};
+use url::Url;
 
-pub struct ContainerClientBuilder {
-    base_path: Option<String>,
-    user_agent: Option<String>,
-    http1_only: Option<bool>,
-    https_only: Option<bool>,
-}
-
-impl ContainerClientBuilder {
-    pub fn with_base_path(&mut self, base_path: String) -> &mut Self {
-        self.base_path = Some(base_path);
-        self
-    }
-
-    pub fn with_user_agent(&mut self, user_agent: String) -> &mut Self {
-        self.user_agent = Some(user_agent);
-        self
-    }
-
-    pub fn http1_only(&mut self, http1_only: bool) -> &mut Self {
-        self.http1_only = Some(http1_only);
-        self
-    }
-
-    pub fn with_https_only(&mut self, https_only: bool) -> &mut Self {
-        self.https_only = Some(https_only);
-        self
-    }
-
-    pub fn build(&self) -> ContainerClient {
-        if self.base_path.is_none() {
-            panic!("Base path should be provided.")
-        }
-        if self.user_agent.is_none() {
-            panic!("User agent should be provided.")
-        }
-        let mut client_builder = ClientBuilder::new();
-        if let Some(_) = self.http1_only {
-            client_builder = client_builder.http1_only();
-        }
-        if let Some(_) = self.https_only {
-            client_builder = client_builder.https_only(true);
-        }
-        let configuration = Configuration {
-            base_path: self.base_path.clone().unwrap(),
-            user_agent: self.user_agent.clone(),
-            client: client_builder
-                .build()
-                .unwrap(),
-        };
-        ContainerClient::new(configuration)
-    }
-}
+pub use crate::api::builder::ContainerClientBuilder;
 
 pub struct ContainerClient {
     pub config: ConfigApi,
@@ -72,10 +28,12 @@ pub struct ContainerClient {
     pub system: SystemApi,
     pub task: TaskApi,
     pub volume: VolumeApi,
+    configuration: Arc<Configuration>,
+    engine: OnceLock<Engine>,
 }
 
 impl ContainerClient {
-    pub(self) fn new(configuration: Configuration) -> Self {
+    pub(crate) fn new(configuration: Configuration) -> Self {
         let configuration = Arc::new(configuration);
         Self {
             config: ConfigApi::new(configuration.clone()),
@@ -93,8 +51,71 @@ impl ContainerClient {
             system: SystemApi::new(configuration.clone()),
             task: TaskApi::new(configuration.clone()),
             volume: VolumeApi::new(configuration.clone()),
+            configuration,
+            engine: OnceLock::new(),
         }
     }
+
+    /// Used by the clients of the secondary API versions, see `versions`.
+    #[cfg_attr(not(secondary_api_version), allow(dead_code))]
+    pub(crate) fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Engine flavour and version, queried from `/version` on first call.
+    pub fn engine(&self) -> Result<&Engine, Error<SystemVersionError>> {
+        if let Some(engine) = self.engine.get() {
+            return Ok(engine);
+        }
+        let version = SystemApi::new(self.configuration.clone()).version()?;
+        Ok(self.engine.get_or_init(|| Engine::from(&version)))
+    }
+
+    /// API version the requests are sent with, negotiated with the daemon on first use unless pinned.
+    pub fn api_version(&self) -> Result<ApiVersion, TransportError> {
+        let base_path = Url::parse(&self.configuration.base_path)
+            .expect("Invalid base path");
+        self.configuration.client.api_version(&base_path)
+    }
+
+    /// Registry credentials attached to pull, push, build and plugin requests, can be updated at
+    /// any time and shared with the bollard `ImageApi` and `ContainerApi`.
+    pub fn registry_auths(&self) -> &Arc<RegistryAuths> {
+        self.configuration.client.registry_auths()
+    }
+
+    /// Attaches to a running container over a hijacked connection, so stdin can be written
+    /// while stdout and stderr are read. Stdin is only forwarded to containers created with
+    /// `OpenStdin`.
+    pub fn attach(&self, params: ContainerAttachParams) -> Result<AttachSession, Error<ContainerAttachError>> {
+        let request = attach_request(&self.configuration.base_path, self.configuration.user_agent.as_ref(), &params);
+        let hijacked = self.configuration.client.hijack(request)?;
+        Ok(AttachSession::new(params.id, self.configuration.clone(), hijacked))
+    }
+
+    /// Attaches to the console of a running container over a WebSocket, e.g. to relay it to
+    /// a browser terminal.
+    pub fn attach_websocket(
+        &self,
+        params: ContainerAttachWebsocketParams
+    ) -> Result<WebSocketSession, Error<ContainerAttachWebsocketError>> {
+        let url = attach_websocket_url(&self.configuration.base_path, &params);
+        let socket = self.configuration.client.websocket(url)?;
+        Ok(WebSocketSession::new(socket))
+    }
+
+    /// Creates an exec instance and starts it over a hijacked connection, e.g. to script an
+    /// interactive shell. `AttachStdin` should be set to write to the process.
+    pub fn exec_session(&self, id: &str, exec_config: ExecConfig) -> Result<ExecSession, Error<ContainerExecError>> {
+        let tty = exec_config.tty.unwrap_or(false);
+        let response = ExecApi::new(self.configuration.clone()).container_exec(ContainerExecParams {
+            id: id.to_string(),
+            exec_config,
+        })?;
+        let request = exec_start_request(&self.configuration.base_path, self.configuration.user_agent.as_ref(), &response.id, tty);
+        let hijacked = self.configuration.client.hijack(request)?;
+        Ok(ExecSession::new(response.id, self.configuration.clone(), hijacked, tty))
+    }
 }
 
 impl Default for ContainerClient {
@@ -0,0 +1,254 @@
+//! Helpers shared by the unit tests.
+
+use crate::api::transport::Endpoint;
+use rustls::crypto::ring;
+use sha2::{Digest, Sha256};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig, ServerConnection, StreamOwned};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::{env, fs, thread};
+use uuid::Uuid;
+
+/// Temporary directory, removed when dropped.
+pub(crate) struct TempDirectory {
+    path: PathBuf,
+}
+
+impl TempDirectory {
+    pub(crate) fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("container-flow-{}-{}", name, Uuid::new_v4()));
+        fs::create_dir_all(&path).unwrap();
+        Self {
+            path,
+        }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDirectory {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Request received by a `MockDaemon`.
+#[derive(Debug, Clone)]
+pub(crate) struct MockRequest {
+    /// Method and target, e.g. `GET /v1.47/info`.
+    pub(crate) line: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+impl MockRequest {
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Daemon listening on a Unix socket, answering the requests in order with canned responses.
+///
+/// Connections are kept alive between requests, the daemon stops once every response is sent.
+pub(crate) struct MockDaemon {
+    directory: TempDirectory,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockDaemon {
+    pub(crate) fn start(responses: Vec<String>) -> Self {
+        let directory = TempDirectory::new("daemon");
+        let listener = UnixListener::bind(directory.path().join("docker.sock")).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => return,
+                };
+                if Self::serve(&mut BufReader::new(stream), &mut responses, &received) == false {
+                    return;
+                }
+            }
+        });
+        Self {
+            directory,
+            requests,
+        }
+    }
+
+    /// Response with a JSON body.
+    pub(crate) fn json(status: u16, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+    }
+
+    /// Streamed response, e.g. the progress of a pull, each chunk in its own HTTP chunk.
+    pub(crate) fn chunked(status: u16, chunks: &[&str]) -> String {
+        let mut response = format!(
+            "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n",
+            status
+        );
+        for chunk in chunks {
+            response.push_str(&format!("{:x}\r\n{}\r\n", chunk.len(), chunk));
+        }
+        response.push_str("0\r\n\r\n");
+        response
+    }
+
+    pub(crate) fn endpoint(&self) -> Endpoint {
+        Endpoint::Unix(self.directory.path().join("docker.sock"))
+    }
+
+    pub(crate) fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Answers the requests read from `reader` in order, returns `false` once out of responses.
+    fn serve(
+        reader: &mut BufReader<impl Read + Write>,
+        responses: &mut impl Iterator<Item = String>,
+        received: &Mutex<Vec<MockRequest>>
+    ) -> bool {
+        while let Some(request) = Self::read_request(reader) {
+            received.lock().unwrap().push(request);
+            let response = match responses.next() {
+                Some(response) => response,
+                None => return false,
+            };
+            let writer = reader.get_mut();
+            if writer.write_all(response.as_bytes()).and_then(|_| writer.flush()).is_err() {
+                break;
+            }
+        }
+        true
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Option<MockRequest> {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end().trim_end_matches(" HTTP/1.1").to_string();
+        if line.is_empty() {
+            return None;
+        }
+        let mut headers = Vec::new();
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).ok()?;
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header.split_once(':')?;
+            headers.push((name.to_string(), value.trim().to_string()));
+        }
+        let mut request = MockRequest {
+            line,
+            headers,
+            body: Vec::new(),
+        };
+        let length = request.header("Content-Length")
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0);
+        reader.take(length).read_to_end(&mut request.body).ok()?;
+        Some(request)
+    }
+}
+
+/// Directory of the test TLS material: `ca.pem`, the client `cert.pem` and `key.pem`, and the
+/// `server-cert.pem` and `server-key.pem` of `docker.example.test`, all signed by `ca.pem`.
+pub(crate) fn tls_material() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/tests/tls")
+}
+
+/// Writes a context the way `docker context create` does, with the given TLS files.
+pub(crate) fn write_context(directory: &Path, name: &str, endpoint: &str, tls_files: &[&str]) {
+    let digest = hex::encode(Sha256::digest(name.as_bytes()));
+    let meta_path = directory.join("contexts/meta").join(&digest);
+    fs::create_dir_all(&meta_path).unwrap();
+    let meta = format!(r#"{{"Name":"{}","Metadata":{{"Description":""}},"Endpoints":{{"docker":{}}}}}"#, name, endpoint);
+    fs::write(meta_path.join("meta.json"), meta).unwrap();
+    let tls_path = directory.join("contexts/tls").join(&digest).join("docker");
+    for file in tls_files {
+        fs::create_dir_all(&tls_path).unwrap();
+        fs::copy(tls_material().join(file), tls_path.join(file)).unwrap();
+    }
+}
+
+/// Daemon listening on a loopback TCP port behind TLS, as started with `--tlsverify`.
+///
+/// Clients must present a certificate signed by the test CA. Connections failing the handshake
+/// are dropped, the requests of the others are answered in order like `MockDaemon` does.
+pub(crate) struct TlsDaemon {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl TlsDaemon {
+    pub(crate) fn start(responses: Vec<String>) -> Self {
+        let material = tls_material();
+        let provider = Arc::new(ring::default_provider());
+        let mut roots = RootCertStore::empty();
+        roots.add(CertificateDer::from_pem_file(material.join("ca.pem")).unwrap()).unwrap();
+        let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+            .build()
+            .unwrap();
+        let chain = CertificateDer::pem_file_iter(material.join("server-cert.pem")).unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let key = PrivateKeyDer::from_pem_file(material.join("server-key.pem")).unwrap();
+        let config = ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(chain, key)
+            .unwrap();
+        let config = Arc::new(config);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => return,
+                };
+                let connection = ServerConnection::new(config.clone()).unwrap();
+                let mut reader = BufReader::new(StreamOwned::new(connection, stream));
+                if MockDaemon::serve(&mut reader, &mut responses, &received) == false {
+                    return;
+                }
+            }
+        });
+        Self {
+            address,
+            requests,
+        }
+    }
+
+    pub(crate) fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub(crate) fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
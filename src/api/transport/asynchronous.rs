@@ -0,0 +1,201 @@
+use crate::api::internals::api_async;
+use crate::api::registry_auth::RegistryAuths;
+use crate::api::transport::codec::{ConnectionPool, RawRequest, ReadChunks, ResponseBody};
+use crate::api::transport::hijack::{hijack, websocket, Hijacked, UpgradeTls};
+use crate::api::transport::negotiation::{VersionNegotiation, API_VERSION_HEADER};
+use crate::api::transport::{codec, transport_error_conversion, Connection, Endpoint, TransportError};
+use crate::api::version::ApiVersion;
+use bytes::Bytes;
+use futures::Stream;
+use reqwest::{Client, Method, Request, RequestBuilder, Response};
+use std::io;
+use std::io::Read;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tungstenite::WebSocket;
+use url::Url;
+
+const BODY_CHANNEL_CAPACITY: usize = 16;
+
+transport_error_conversion!(api_async);
+
+/// Async counterpart of `Transport`, used by the generated async API.
+///
+/// Non TCP endpoints are blocking streams, so they are driven from the blocking thread pool
+/// of the runtime and the response body is forwarded to the caller through a channel.
+#[derive(Debug, Clone)]
+pub struct AsyncTransport {
+    endpoint: Endpoint,
+    client: Client,
+    version: Arc<VersionNegotiation>,
+    registry_auths: Arc<RegistryAuths>,
+    connections: Arc<ConnectionPool>,
+    upgrade_tls: UpgradeTls,
+}
+
+impl AsyncTransport {
+    pub fn new(endpoint: Endpoint, client: Client) -> Self {
+        Self::with_api_version(endpoint, client, ApiVersion::compiled(), true)
+    }
+
+    pub fn with_api_version(endpoint: Endpoint, client: Client, version: ApiVersion, negotiate: bool) -> Self {
+        Self {
+            endpoint,
+            client,
+            version: Arc::new(VersionNegotiation::new(version, negotiate)),
+            registry_auths: Arc::new(RegistryAuths::new()),
+            connections: Arc::new(ConnectionPool::default()),
+            upgrade_tls: UpgradeTls::default(),
+        }
+    }
+
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Version in the base path of the requests, before negotiation rewrites it.
+    pub fn requested_api_version(&self) -> ApiVersion {
+        self.version.requested()
+    }
+
+    /// Registry credentials attached to pull, push, build and plugin requests.
+    pub fn registry_auths(&self) -> &Arc<RegistryAuths> {
+        &self.registry_auths
+    }
+
+    pub fn with_registry_auths(&mut self, registry_auths: Arc<RegistryAuths>) -> &mut Self {
+        self.registry_auths = registry_auths;
+        self
+    }
+
+    /// TLS of the hijacked connections to `https://` base paths.
+    pub(crate) fn with_upgrade_tls(&mut self, upgrade_tls: UpgradeTls) -> &mut Self {
+        self.upgrade_tls = upgrade_tls;
+        self
+    }
+
+    pub fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client.request(method, url)
+    }
+
+    pub async fn execute(&self, mut request: Request) -> Result<Response, TransportError> {
+        self.authenticate(&mut request).await?;
+        if self.version.split_path(request.url()).is_some() {
+            let url = request.url().clone();
+            let version = self.api_version(&url).await?;
+            self.version.rewrite(request.url_mut(), version);
+        }
+        self.send(request).await
+    }
+
+    /// Same as `Transport::hijack`, the connection is opened from the blocking thread pool.
+    pub(crate) async fn hijack(&self, mut request: RawRequest) -> Result<Hijacked, TransportError> {
+        if self.version.split_path(&request.url).is_some() {
+            let url = request.url.clone();
+            let version = self.api_version(&url).await?;
+            self.version.rewrite(&mut request.url, version);
+        }
+        let endpoint = self.endpoint.clone();
+        let upgrade_tls = self.upgrade_tls.clone();
+        tokio::task::spawn_blocking(move || hijack(&endpoint, &upgrade_tls, request))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    /// Same as `Transport::websocket`, the handshake is performed from the blocking thread pool.
+    pub(crate) async fn websocket(&self, mut url: Url) -> Result<WebSocket<Box<dyn Connection>>, TransportError> {
+        if self.version.split_path(&url).is_some() {
+            let version = self.api_version(&url).await?;
+            self.version.rewrite(&mut url, version);
+        }
+        let endpoint = self.endpoint.clone();
+        let upgrade_tls = self.upgrade_tls.clone();
+        tokio::task::spawn_blocking(move || websocket(&endpoint, &upgrade_tls, url))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    pub async fn api_version(&self, base_path: &Url) -> Result<ApiVersion, TransportError> {
+        if let Some(version) = self.version.resolved() {
+            return Ok(version);
+        }
+        let ping_url = match self.version.ping_url(base_path) {
+            Some(value) => value,
+            None => return Ok(self.version.requested()),
+        };
+        let request = self.client.get(ping_url).build()?;
+        let response = self.send(request).await?;
+        if response.status().is_success() == false {
+            // Not cached, the next request pings again.
+            return Ok(self.version.requested());
+        }
+        let daemon_version = response.headers()
+            .get(API_VERSION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<ApiVersion>().ok());
+        Ok(self.version.resolve(daemon_version))
+    }
+
+    /// Credential helpers are programs, they run from the blocking thread pool.
+    async fn authenticate(&self, request: &mut Request) -> Result<(), TransportError> {
+        let url = request.url().clone();
+        if RegistryAuths::is_authenticated(&url) == false {
+            return Ok(());
+        }
+        let registry_auths = self.registry_auths.clone();
+        let mut headers = std::mem::take(request.headers_mut());
+        let headers = tokio::task::spawn_blocking(move || {
+            registry_auths.authenticate(&url, &mut headers).map(|_| headers)
+        })
+            .await
+            .map_err(io::Error::other)??;
+        *request.headers_mut() = headers;
+        Ok(())
+    }
+
+    async fn send(&self, request: Request) -> Result<Response, TransportError> {
+        if let Endpoint::Tcp = self.endpoint {
+            return self.client.execute(request).await.map_err(TransportError::from);
+        }
+        let request = RawRequest::from_async(request).await?;
+        let endpoint = self.endpoint.clone();
+        let connections = self.connections.clone();
+        let (builder, body) = tokio::task::spawn_blocking(move || {
+            codec::exchange(&connections, || endpoint.connect(), &request)
+        })
+            .await
+            .map_err(io::Error::other)??;
+        let body = match body {
+            ResponseBody::Buffered(body) => reqwest::Body::from(body),
+            ResponseBody::Streamed(reader) => Self::forward_body(reader),
+        };
+        let response = builder.body(body)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Response::from(response))
+    }
+
+    fn forward_body(reader: Box<dyn Read + Send>) -> reqwest::Body {
+        reqwest::Body::wrap_stream(read_stream(reader))
+    }
+}
+
+/// Chunks of a blocking reader, read from the blocking thread pool.
+pub(crate) fn read_stream(reader: Box<dyn Read + Send>) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static {
+    let (sender, receiver) = mpsc::channel::<io::Result<Bytes>>(BODY_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        for chunk in ReadChunks::new(reader) {
+            if sender.blocking_send(chunk).is_err() {
+                break;
+            }
+        }
+    });
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    })
+}
+
+impl Default for AsyncTransport {
+    fn default() -> Self {
+        Self::new(Endpoint::Tcp, Client::new())
+    }
+}
@@ -0,0 +1,623 @@
+use crate::api::transport::{Connection, TransportError};
+use bytes::Bytes;
+use futures::SinkExt;
+use http_body_util::BodyExt;
+use reqwest::blocking::{Request, Response};
+use reqwest::header::{HeaderMap, CONNECTION, CONTENT_LENGTH, HOST};
+use reqwest::Method;
+use std::fmt::{Debug, Formatter};
+use std::io::{BufRead, BufReader, Read, Take, Write};
+use std::sync::{Arc, Mutex};
+use std::{fmt, io, thread};
+use url::{Position, Url};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+const BODY_CHANNEL_CAPACITY: usize = 16;
+const MAXIMUM_IDLE_CONNECTIONS: usize = 4;
+/// Bodies up to this length are read along with the head, so the connection is released at once.
+const BUFFERED_BODY_LIMIT: u64 = 1024 * 1024;
+
+pub(crate) enum Framing {
+    Chunked,
+    Length(u64),
+    UntilClose,
+}
+
+/// Request as written on the endpoint stream, independent of the blocking or async reqwest request.
+pub(crate) struct RawRequest {
+    pub(crate) method: Method,
+    pub(crate) url: Url,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Option<Vec<u8>>,
+}
+
+impl RawRequest {
+    pub(crate) fn from_blocking(mut request: Request) -> Result<Self, TransportError> {
+        let body = match request.body_mut() {
+            Some(body) => Some(body.buffer()?.to_vec()),
+            None => None,
+        };
+        Ok(Self {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body,
+        })
+    }
+
+    /// Streamed bodies, e.g. build contexts and uploaded archives, are buffered in memory
+    /// since the request is written with a `Content-Length`.
+    pub(crate) async fn from_async(mut request: reqwest::Request) -> Result<Self, TransportError> {
+        let body = match request.body_mut().take() {
+            Some(body) => Some(body.collect().await?.to_bytes().to_vec()),
+            None => None,
+        };
+        Ok(Self {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body,
+        })
+    }
+}
+
+type ConnectionReader = BufReader<Box<dyn Connection>>;
+
+/// Idle keep-alive connections of an endpoint served by the codec.
+#[derive(Default)]
+pub(crate) struct ConnectionPool {
+    idle: Mutex<Vec<Box<dyn Connection>>>,
+}
+
+impl ConnectionPool {
+    fn take(&self) -> Option<Box<dyn Connection>> {
+        self.idle.lock().unwrap().pop()
+    }
+
+    fn release(&self, connection: Box<dyn Connection>) {
+        let mut idle = self.idle.lock().unwrap();
+        if idle.len() < MAXIMUM_IDLE_CONNECTIONS {
+            idle.push(connection);
+        }
+    }
+}
+
+impl Debug for ConnectionPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field("idle", &self.idle.lock().map_or(0, |idle| idle.len()))
+            .finish()
+    }
+}
+
+/// Body of a response read by `exchange`.
+pub(crate) enum ResponseBody {
+    Buffered(Vec<u8>),
+    /// Decoded body, the connection returns to the pool once it is read to the end.
+    Streamed(Box<dyn Read + Send>),
+}
+
+pub(crate) fn send(
+    pool: &Arc<ConnectionPool>,
+    connect: impl Fn() -> io::Result<Box<dyn Connection>>,
+    request: Request
+) -> Result<Response, TransportError> {
+    let request = RawRequest::from_blocking(request)?;
+    let (builder, body) = exchange(pool, connect, &request)?;
+    let body = match body {
+        ResponseBody::Buffered(body) => reqwest::Body::from(body),
+        ResponseBody::Streamed(reader) => forward_body(reader),
+    };
+    let response = builder.body(body)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    Ok(Response::from(response))
+}
+
+/// The blocking response polls its body as a stream, the blocking reads are then done by a
+/// thread of their own.
+fn forward_body(reader: Box<dyn Read + Send>) -> reqwest::Body {
+    let (mut sender, receiver) = futures::channel::mpsc::channel::<io::Result<Bytes>>(BODY_CHANNEL_CAPACITY);
+    thread::spawn(move || {
+        for chunk in ReadChunks::new(reader) {
+            if futures::executor::block_on(sender.send(chunk)).is_err() {
+                break;
+            }
+        }
+    });
+    reqwest::Body::wrap_stream(receiver)
+}
+
+/// Writes the request on an idle or a new connection and reads the response head.
+///
+/// The daemon may close an idle connection at any time, a request failing on one before any
+/// response is read is sent again on the next connection.
+pub(crate) fn exchange(
+    pool: &Arc<ConnectionPool>,
+    connect: impl Fn() -> io::Result<Box<dyn Connection>>,
+    request: &RawRequest
+) -> Result<(http::response::Builder, ResponseBody), TransportError> {
+    let (mut reader, builder, framing) = loop {
+        let (connection, reused) = match pool.take() {
+            Some(connection) => (connection, true),
+            None => (connect()?, false),
+        };
+        match send_request(connection, request) {
+            Ok(value) => break value,
+            Err(error) if reused && is_closed(&error) => continue,
+            Err(error) => return Err(error.into()),
+        }
+    };
+    let keep_alive = builder.headers_ref()
+        .and_then(|headers| headers.get(CONNECTION))
+        .is_none_or(|value| value.as_bytes().eq_ignore_ascii_case(b"close") == false);
+    let body = match framing {
+        Framing::Length(length) if length <= BUFFERED_BODY_LIMIT => {
+            let mut body = Vec::with_capacity(length as usize);
+            (&mut reader).take(length).read_to_end(&mut body)?;
+            if body.len() as u64 != length {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Truncated body").into());
+            }
+            if keep_alive && reader.buffer().is_empty() {
+                pool.release(reader.into_inner());
+            }
+            ResponseBody::Buffered(body)
+        }
+        framing => {
+            let body = match framing {
+                Framing::Chunked => BodyReader::Chunked(ChunkedReader::new(reader)),
+                Framing::Length(length) => BodyReader::Length(reader.take(length)),
+                Framing::UntilClose => BodyReader::UntilClose(reader),
+            };
+            ResponseBody::Streamed(Box::new(PooledBody {
+                body: Some(body),
+                pool: pool.clone(),
+                keep_alive,
+            }))
+        }
+    };
+    Ok((builder, body))
+}
+
+fn send_request(
+    mut connection: Box<dyn Connection>,
+    request: &RawRequest
+) -> io::Result<(ConnectionReader, http::response::Builder, Framing)> {
+    write_request(&mut connection, request, "keep-alive")?;
+    let mut reader = BufReader::new(connection);
+    let (builder, framing) = read_head(&mut reader)?;
+    Ok((reader, builder, framing))
+}
+
+fn is_closed(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+    )
+}
+
+enum BodyReader {
+    Chunked(ChunkedReader<ConnectionReader>),
+    Length(Take<ConnectionReader>),
+    UntilClose(ConnectionReader),
+}
+
+impl BodyReader {
+    /// Connection of a body read to the end, `None` when it cannot carry another request.
+    fn into_connection(self) -> Option<Box<dyn Connection>> {
+        let reader = match self {
+            BodyReader::Chunked(reader) => reader.into_inner()?,
+            BodyReader::Length(reader) if reader.limit() == 0 => reader.into_inner(),
+            _ => return None,
+        };
+        match reader.buffer().is_empty() {
+            true => Some(reader.into_inner()),
+            false => None,
+        }
+    }
+}
+
+impl Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            BodyReader::Chunked(reader) => reader.read(buf),
+            BodyReader::Length(reader) => reader.read(buf),
+            BodyReader::UntilClose(reader) => reader.read(buf),
+        }
+    }
+}
+
+/// Streamed body, releases the connection to the pool at the end of the body.
+struct PooledBody {
+    body: Option<BodyReader>,
+    pool: Arc<ConnectionPool>,
+    keep_alive: bool,
+}
+
+impl Read for PooledBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = match self.body.as_mut() {
+            Some(body) => body.read(buf)?,
+            None => return Ok(0),
+        };
+        if read == 0 && buf.is_empty() == false {
+            let connection = self.body.take().and_then(BodyReader::into_connection);
+            if let (Some(connection), true) = (connection, self.keep_alive) {
+                self.pool.release(connection);
+            }
+        }
+        Ok(read)
+    }
+}
+
+pub(crate) fn write_request(
+    writer: &mut impl Write,
+    request: &RawRequest,
+    connection: &str
+) -> io::Result<()> {
+    let url = &request.url;
+    let mut head = format!("{} {} HTTP/1.1\r\n", request.method, &url[Position::BeforePath..]);
+    if request.headers.contains_key(HOST) == false {
+        head.push_str(&format!("Host: {}\r\n", url.host_str().unwrap_or("localhost")));
+    }
+    writer.write_all(head.as_bytes())?;
+    for (name, value) in request.headers.iter() {
+        if *name == CONTENT_LENGTH || *name == CONNECTION {
+            continue;
+        }
+        writer.write_all(name.as_str().as_bytes())?;
+        writer.write_all(b": ")?;
+        writer.write_all(value.as_bytes())?;
+        writer.write_all(b"\r\n")?;
+    }
+    let content_length = request.body.as_ref().map_or(0, |body| body.len());
+    writer.write_all(format!("Content-Length: {}\r\n", content_length).as_bytes())?;
+    writer.write_all(format!("Connection: {}\r\n\r\n", connection).as_bytes())?;
+    if let Some(body) = request.body.as_ref() {
+        writer.write_all(body)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub(crate) fn read_head(reader: &mut impl BufRead) -> io::Result<(http::response::Builder, Framing)> {
+    let status_line = read_line(reader)?;
+    let status = status_line.split(' ')
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| invalid_data(format!("Malformed status line: {}", status_line)))?;
+    let mut builder = http::Response::builder().status(status);
+    let mut framing = Framing::UntilClose;
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':')
+            .ok_or_else(|| invalid_data(format!("Malformed header: {}", line)))?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") && value.eq_ignore_ascii_case("chunked") {
+            framing = Framing::Chunked;
+        }
+        else if name.eq_ignore_ascii_case("content-length") {
+            if let Framing::UntilClose = framing {
+                let length = value.parse::<u64>()
+                    .map_err(|_| invalid_data(format!("Malformed content length: {}", value)))?;
+                framing = Framing::Length(length);
+            }
+        }
+        builder = builder.header(name, value);
+    }
+    if status == 204 || status == 304 {
+        framing = Framing::Length(0);
+    }
+    Ok((builder, framing))
+}
+
+fn read_line(reader: &mut impl BufRead) -> io::Result<String> {
+    let mut line = Vec::new();
+    reader.read_until(b'\n', &mut line)?;
+    if line.ends_with(b"\n") == false {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Connection closed by daemon"));
+    }
+    let line = String::from_utf8_lossy(&line);
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Decodes a `Transfer-Encoding: chunked` body.
+pub(crate) struct ChunkedReader<R> {
+    reader: R,
+    remaining: u64,
+    done: bool,
+}
+
+impl<R: BufRead> ChunkedReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            remaining: 0,
+            done: false,
+        }
+    }
+
+    fn next_chunk(&mut self) -> io::Result<()> {
+        let line = read_line(&mut self.reader)?;
+        let size = line.split(';').next().unwrap().trim();
+        self.remaining = u64::from_str_radix(size, 16)
+            .map_err(|_| invalid_data(format!("Malformed chunk size: {}", line)))?;
+        if self.remaining == 0 {
+            // Skip trailers up to the terminating empty line.
+            while read_line(&mut self.reader)?.is_empty() == false {}
+            self.done = true;
+        }
+        Ok(())
+    }
+
+    /// Reader positioned after the body, `None` until the last chunk is read.
+    fn into_inner(self) -> Option<R> {
+        self.done.then_some(self.reader)
+    }
+}
+
+impl<R: BufRead> Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done || buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            self.next_chunk()?;
+            if self.done {
+                return Ok(0);
+            }
+        }
+        let size = buf.len().min(self.remaining as usize);
+        let read = self.reader.read(&mut buf[..size])?;
+        if read == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Truncated chunk"));
+        }
+        self.remaining -= read as u64;
+        if self.remaining == 0 {
+            read_line(&mut self.reader)?;
+        }
+        Ok(read)
+    }
+}
+
+/// Exposes a blocking reader as the chunk iterator backing a streamed response body.
+pub(crate) struct ReadChunks {
+    reader: Box<dyn Read + Send>,
+    done: bool,
+}
+
+impl ReadChunks {
+    pub(crate) fn new(reader: Box<dyn Read + Send>) -> Self {
+        Self {
+            reader,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for ReadChunks {
+    type Item = io::Result<Bytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut buffer = vec![0; CHUNK_SIZE];
+        loop {
+            return match self.reader.read(&mut buffer) {
+                Ok(0) => {
+                    self.done = true;
+                    None
+                }
+                Ok(read) => {
+                    buffer.truncate(read);
+                    Some(Ok(Bytes::from(buffer)))
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.done = true;
+                    Some(Err(error))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::io::Cursor;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Connection answering every request with the next response, as a daemon does.
+    struct MockConnection {
+        responses: VecDeque<Vec<u8>>,
+        readable: Cursor<Vec<u8>>,
+        written: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl MockConnection {
+        fn new(responses: &[&str]) -> Self {
+            Self {
+                responses: responses.iter().map(|response| response.as_bytes().to_vec()).collect(),
+                readable: Cursor::new(Vec::new()),
+                written: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+    }
+
+    impl Read for MockConnection {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.readable.read(buf)
+        }
+    }
+
+    impl Write for MockConnection {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.readable = Cursor::new(self.responses.pop_front().unwrap_or_default());
+            Ok(())
+        }
+    }
+
+    fn request(path: &str) -> RawRequest {
+        RawRequest {
+            method: Method::GET,
+            url: Url::parse(&format!("http://localhost{}", path)).unwrap(),
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    fn read_body(body: ResponseBody) -> String {
+        match body {
+            ResponseBody::Buffered(body) => String::from_utf8(body).unwrap(),
+            ResponseBody::Streamed(mut reader) => {
+                let mut body = String::new();
+                reader.read_to_string(&mut body).unwrap();
+                body
+            }
+        }
+    }
+
+    fn idle_connections(pool: &ConnectionPool) -> usize {
+        pool.idle.lock().unwrap().len()
+    }
+
+    #[test]
+    fn decodes_a_chunked_body() {
+        let pool = Arc::new(ConnectionPool::default());
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            let mut connection = MockConnection::new(&[
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5;name=value\r\nhello\r\n6\r\n world\r\n0\r\nTrailer: value\r\n\r\n"
+            ]);
+            connection.written = written.clone();
+            Ok(Box::new(connection))
+        };
+        let (builder, body) = exchange(&pool, connect, &request("/_ping")).unwrap();
+        assert_eq!(builder.body(()).unwrap().status(), http::StatusCode::OK);
+        let written = String::from_utf8(written.lock().unwrap().clone()).unwrap();
+        assert!(written.starts_with("GET /_ping HTTP/1.1\r\nHost: localhost\r\n"));
+        assert!(written.ends_with("Content-Length: 0\r\nConnection: keep-alive\r\n\r\n"));
+        assert_eq!(idle_connections(&pool), 0);
+        assert_eq!(read_body(body), "hello world");
+        assert_eq!(idle_connections(&pool), 1);
+    }
+
+    #[test]
+    fn reuses_the_connection_after_a_content_length_body() {
+        let pool = Arc::new(ConnectionPool::default());
+        let connections = AtomicUsize::new(0);
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            connections.fetch_add(1, Ordering::SeqCst);
+            Ok(Box::new(MockConnection::new(&[
+                "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nOK",
+                "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nAgain",
+            ])))
+        };
+        let (_, body) = exchange(&pool, connect, &request("/_ping")).unwrap();
+        assert_eq!(read_body(body), "OK");
+        let (_, body) = exchange(&pool, connect, &request("/_ping")).unwrap();
+        assert_eq!(read_body(body), "Again");
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn closes_the_connection_when_the_daemon_asks_to() {
+        let pool = Arc::new(ConnectionPool::default());
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            Ok(Box::new(MockConnection::new(&["HTTP/1.1 200 OK\r\nConnection: Close\r\nContent-Length: 2\r\n\r\nOK"])))
+        };
+        let (_, body) = exchange(&pool, connect, &request("/_ping")).unwrap();
+        assert_eq!(read_body(body), "OK");
+        assert_eq!(idle_connections(&pool), 0);
+    }
+
+    #[test]
+    fn sends_the_request_again_when_an_idle_connection_was_closed() {
+        let pool = Arc::new(ConnectionPool::default());
+        pool.release(Box::new(MockConnection::new(&[])));
+        let connections = AtomicUsize::new(0);
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            connections.fetch_add(1, Ordering::SeqCst);
+            Ok(Box::new(MockConnection::new(&["HTTP/1.1 204 No Content\r\n\r\n"])))
+        };
+        let (builder, body) = exchange(&pool, connect, &request("/containers/id/start")).unwrap();
+        assert_eq!(builder.body(()).unwrap().status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(read_body(body), "");
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn fails_on_a_closed_new_connection() {
+        let pool = Arc::new(ConnectionPool::default());
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            Ok(Box::new(MockConnection::new(&[])))
+        };
+        match exchange(&pool, connect, &request("/_ping")) {
+            Err(TransportError::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof),
+            _ => panic!("Expected an io error"),
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_heads() {
+        let heads = [
+            ("HTTP/1.1 OK\r\n\r\n", io::ErrorKind::InvalidData),
+            ("HTTP/1.1 200 OK\r\nContent-Type\r\n\r\n", io::ErrorKind::InvalidData),
+            ("HTTP/1.1 200 OK\r\nContent-Length: ten\r\n\r\n", io::ErrorKind::InvalidData),
+            ("HTTP/1.1 200 OK\r\nContent-Ty", io::ErrorKind::UnexpectedEof),
+        ];
+        for (head, kind) in heads {
+            match read_head(&mut Cursor::new(head)) {
+                Err(error) => assert_eq!(error.kind(), kind, "{:?}", head),
+                Ok(_) => panic!("Expected {:?} to be rejected", head),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_chunks() {
+        let mut body = String::new();
+        let error = ChunkedReader::new(Cursor::new("zz\r\nhello\r\n0\r\n\r\n"))
+            .read_to_string(&mut body)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = ChunkedReader::new(Cursor::new("a\r\nhello"))
+            .read_to_string(&mut body)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn streams_a_blocking_response_body() {
+        let pool = Arc::new(ConnectionPool::default());
+        let connect = || -> io::Result<Box<dyn Connection>> {
+            Ok(Box::new(MockConnection::new(&[
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n3\r\n{}\n\r\n3\r\n{}\n\r\n0\r\n\r\n"
+            ])))
+        };
+        let request = reqwest::blocking::Client::new()
+            .get("http://localhost/events")
+            .build()
+            .unwrap();
+        let response = send(&pool, connect, request).unwrap();
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(response.text().unwrap(), "{}\n{}\n");
+    }
+}
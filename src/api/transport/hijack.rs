@@ -0,0 +1,297 @@
+use crate::api::transport::codec::{read_head, write_request, RawRequest};
+use crate::api::transport::tls::{split, TlsOptions, TlsStream, TlsWriteHalf};
+use crate::api::transport::{Connection, Endpoint, Transport, TransportError};
+use crate::params::{ContainerAttachParams, ContainerAttachWebsocketParams};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, UPGRADE, USER_AGENT};
+use reqwest::Method;
+use std::io;
+use std::io::{BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
+use std::os::unix::net::UnixStream;
+use std::process::ChildStdin;
+use tungstenite::WebSocket;
+use url::Url;
+
+const ERROR_BODY_LIMIT: u64 = 64 * 1024;
+
+/// Write half of a hijacked connection, shut down to signal the end of stdin.
+pub(crate) trait WriteHalf: Write + Send {
+    fn shutdown(&mut self) -> io::Result<()>;
+}
+
+impl WriteHalf for UnixStream {
+    fn shutdown(&mut self) -> io::Result<()> {
+        UnixStream::shutdown(self, Shutdown::Write)
+    }
+}
+
+impl WriteHalf for TcpStream {
+    fn shutdown(&mut self) -> io::Result<()> {
+        TcpStream::shutdown(self, Shutdown::Write)
+    }
+}
+
+impl WriteHalf for TlsWriteHalf {
+    fn shutdown(&mut self) -> io::Result<()> {
+        TlsWriteHalf::shutdown(self)
+    }
+}
+
+impl WriteHalf for ChildStdin {
+    /// The pipe is closed when dropped.
+    fn shutdown(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Connection handed over by the daemon after an `Upgrade: tcp` request.
+pub(crate) struct Hijacked {
+    pub(crate) headers: HeaderMap,
+    pub(crate) reader: Box<dyn Read + Send>,
+    pub(crate) writer: Box<dyn WriteHalf>,
+}
+
+/// TLS of the connections upgraded outside of the HTTP client, the same as its own.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UpgradeTls {
+    pub(crate) options: TlsOptions,
+    /// Address of the base path host when its name is replaced by the server name.
+    pub(crate) address: Option<SocketAddr>,
+}
+
+impl UpgradeTls {
+    fn connect(&self, url: &Url) -> io::Result<TlsStream> {
+        let (host, port) = host_and_port(url)?;
+        let address = match self.address {
+            Some(address) => address,
+            None => (host, port).to_socket_addrs()?
+                .next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("No address found for {}", host)))?,
+        };
+        self.options.connect(address, host)
+    }
+}
+
+fn host_and_port(url: &Url) -> io::Result<(&str, u16)> {
+    let host = url.host_str()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Url should have a host"))?;
+    Ok((host, url.port_or_known_default().unwrap_or(80)))
+}
+
+impl Endpoint {
+    /// Opens a connection to the daemon split in a read and a write half.
+    fn connect_split(&self, url: &Url, tls: &UpgradeTls) -> io::Result<(Box<dyn Read + Send>, Box<dyn WriteHalf>)> {
+        match self {
+            Endpoint::Tcp if is_secure(url) => {
+                let (reader, writer) = split(tls.connect(url)?)?;
+                Ok((Box::new(reader), Box::new(writer)))
+            }
+            Endpoint::Tcp => {
+                let stream = TcpStream::connect(host_and_port(url)?)?;
+                Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            Endpoint::Unix(path) => {
+                let stream = UnixStream::connect(path)?;
+                Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            Endpoint::Ssh(endpoint) => {
+                let mut connection = endpoint.connect()?;
+                let stdin = connection.take_stdin()
+                    .expect("Ssh connection should have a stdin");
+                Ok((Box::new(connection), Box::new(stdin)))
+            }
+        }
+    }
+
+    /// Opens a connection to the daemon, including TCP endpoints.
+    fn connect_stream(&self, url: &Url, tls: &UpgradeTls) -> io::Result<Box<dyn Connection>> {
+        match self {
+            Endpoint::Tcp if is_secure(url) => Ok(Box::new(tls.connect(url)?)),
+            Endpoint::Tcp => Ok(Box::new(TcpStream::connect(host_and_port(url)?)?)),
+            _ => self.connect(),
+        }
+    }
+}
+
+fn is_secure(url: &Url) -> bool {
+    matches!(url.scheme(), "https" | "wss")
+}
+
+/// Url of an operation under the base path of a client.
+fn operation_url(base_path: &str, segments: &[&str]) -> Url {
+    let mut url = Url::parse(base_path)
+        .expect("Invalid base path");
+    url.path_segments_mut()
+        .expect("Base path cannot be a base")
+        .pop_if_empty()
+        .extend(segments);
+    url
+}
+
+fn upgrade_request(url: Url, user_agent: Option<&String>, body: Option<Vec<u8>>) -> RawRequest {
+    let mut headers = HeaderMap::new();
+    headers.insert(UPGRADE, HeaderValue::from_static("tcp"));
+    if let Some(user_agent) = user_agent.and_then(|value| HeaderValue::from_str(value).ok()) {
+        headers.insert(USER_AGENT, user_agent);
+    }
+    if body.is_some() {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+    RawRequest {
+        method: Method::POST,
+        url,
+        headers,
+        body,
+    }
+}
+
+/// `/containers/{id}/attach` request of `attach`, shared by both clients.
+pub(crate) fn attach_request(base_path: &str, user_agent: Option<&String>, params: &ContainerAttachParams) -> RawRequest {
+    let mut url = operation_url(base_path, &["containers", &params.id, "attach"]);
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("stream", "true");
+        query.append_pair("stdin", &params.stdin.unwrap_or(true).to_string());
+        query.append_pair("stdout", &params.stdout.unwrap_or(true).to_string());
+        query.append_pair("stderr", &params.stderr.unwrap_or(true).to_string());
+        query.append_pair("logs", &params.logs.unwrap_or(false).to_string());
+        if let Some(detach_keys) = params.detach_keys.as_ref() {
+            query.append_pair("detachKeys", detach_keys);
+        }
+    }
+    upgrade_request(url, user_agent, None)
+}
+
+/// `/exec/{id}/start` request of `exec_session`, shared by both clients.
+pub(crate) fn exec_start_request(base_path: &str, user_agent: Option<&String>, id: &str, tty: bool) -> RawRequest {
+    let url = operation_url(base_path, &["exec", id, "start"]);
+    let body = serde_json::json!({
+        "Detach": false,
+        "Tty": tty,
+    });
+    upgrade_request(url, user_agent, Some(body.to_string().into_bytes()))
+}
+
+/// `/containers/{id}/attach/ws` url of `attach_websocket`, shared by both clients.
+pub(crate) fn attach_websocket_url(base_path: &str, params: &ContainerAttachWebsocketParams) -> Url {
+    let mut url = operation_url(base_path, &["containers", &params.id, "attach", "ws"]);
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("stream", &params.stream.unwrap_or(true).to_string());
+        query.append_pair("stdin", &params.stdin.unwrap_or(true).to_string());
+        query.append_pair("stdout", &params.stdout.unwrap_or(true).to_string());
+        query.append_pair("stderr", &params.stderr.unwrap_or(true).to_string());
+        query.append_pair("logs", &params.logs.unwrap_or(false).to_string());
+        if let Some(detach_keys) = params.detach_keys.as_ref() {
+            query.append_pair("detachKeys", detach_keys);
+        }
+    }
+    url
+}
+
+impl Transport {
+    /// Sends `request` and takes over the connection, as attach and exec start do to stream
+    /// stdin, stdout and stderr in both directions.
+    pub(crate) fn hijack(&self, mut request: RawRequest) -> Result<Hijacked, TransportError> {
+        if self.version.split_path(&request.url).is_some() {
+            let version = self.api_version(&request.url)?;
+            self.version.rewrite(&mut request.url, version);
+        }
+        hijack(&self.endpoint, &self.upgrade_tls, request)
+    }
+
+    /// Opens a WebSocket to `url`, e.g. `/containers/{id}/attach/ws`.
+    pub(crate) fn websocket(&self, mut url: Url) -> Result<WebSocket<Box<dyn Connection>>, TransportError> {
+        if self.version.split_path(&url).is_some() {
+            let version = self.api_version(&url)?;
+            self.version.rewrite(&mut url, version);
+        }
+        websocket(&self.endpoint, &self.upgrade_tls, url)
+    }
+}
+
+/// Performs the WebSocket handshake on a new connection to `url`.
+pub(crate) fn websocket(
+    endpoint: &Endpoint,
+    tls: &UpgradeTls,
+    mut url: Url
+) -> Result<WebSocket<Box<dyn Connection>>, TransportError> {
+    let stream = endpoint.connect_stream(&url, tls)?;
+    let scheme = match is_secure(&url) {
+        true => "wss",
+        false => "ws",
+    };
+    url.set_scheme(scheme)
+        .expect("Url should accept the ws schemes");
+    let (socket, _) = tungstenite::client::client(url.as_str(), stream)
+        .map_err(|error| io::Error::other(error.to_string()))?;
+    Ok(socket)
+}
+
+/// Sends `request` on a new connection and hands it over once the daemon accepted the upgrade.
+pub(crate) fn hijack(endpoint: &Endpoint, tls: &UpgradeTls, request: RawRequest) -> Result<Hijacked, TransportError> {
+    let (reader, mut writer) = endpoint.connect_split(&request.url, tls)?;
+    write_request(&mut writer, &request, "Upgrade")?;
+    let mut reader = BufReader::new(reader);
+    let (builder, _) = read_head(&mut reader)?;
+    let response = builder.body(())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    let status = response.status();
+    // Daemons answer `101 Switching Protocols`, older ones `200 OK` before streaming.
+    if status.as_u16() != 101 && status.is_success() == false {
+        let mut content = String::new();
+        let _ = reader.take(ERROR_BODY_LIMIT).read_to_string(&mut content);
+        return Err(TransportError::Io(io::Error::other(
+            format!("Hijack failed with status {}: {}", status, content.trim())
+        )));
+    }
+    Ok(Hijacked {
+        headers: response.headers().clone(),
+        reader: Box::new(reader),
+        writer,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testing::{tls_material, TlsDaemon};
+
+    #[test]
+    fn hijacks_a_tls_connection() {
+        let daemon = TlsDaemon::start(vec![
+            "HTTP/1.1 101 UPGRADED\r\nContent-Type: application/vnd.docker.raw-stream\r\nConnection: Upgrade\r\nUpgrade: tcp\r\n\r\nhello\n".to_string(),
+        ]);
+        let mut options = TlsOptions::from_cert_path(tls_material());
+        options.with_server_name("docker.example.test");
+        let tls = UpgradeTls {
+            options,
+            address: Some(daemon.address()),
+        };
+        let params = ContainerAttachParams {
+            id: "web".to_string(),
+            detach_keys: None,
+            logs: None,
+            stream: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        };
+        let request = attach_request("https://docker.example.test:2376/v1.47", Some(&"tests".to_string()), &params);
+        let mut hijacked = hijack(&Endpoint::Tcp, &tls, request).unwrap();
+        let mut greeting = [0; 6];
+        hijacked.reader.read_exact(&mut greeting).unwrap();
+        assert_eq!(&greeting, b"hello\n");
+
+        hijacked.writer.write_all(b"ls\n").unwrap();
+        hijacked.writer.shutdown().unwrap();
+        let mut rest = Vec::new();
+        hijacked.reader.read_to_end(&mut rest).unwrap();
+        let requests = daemon.requests();
+        assert_eq!(requests[0].line, "POST /v1.47/containers/web/attach?stream=true&stdin=true&stdout=true&stderr=true&logs=false");
+        assert_eq!(requests[0].header("Upgrade"), Some("tcp"));
+        assert_eq!(requests[0].header("User-Agent"), Some("tests"));
+        // Stdin reaches the daemon through the upgraded connection.
+        assert_eq!(requests[1].line, "ls");
+    }
+}
@@ -0,0 +1,289 @@
+mod asynchronous;
+mod codec;
+mod hijack;
+mod negotiation;
+mod ssh;
+mod tls;
+
+pub use asynchronous::AsyncTransport;
+pub(crate) use asynchronous::read_stream;
+pub(crate) use hijack::{attach_request, attach_websocket_url, exec_start_request, Hijacked, UpgradeTls, WriteHalf};
+pub use ssh::SshEndpoint;
+pub use tls::TlsOptions;
+
+use crate::api::internals::api_sync;
+use crate::api::registry_auth::RegistryAuths;
+use crate::api::version::ApiVersion;
+use codec::ConnectionPool;
+use negotiation::{VersionNegotiation, API_VERSION_HEADER};
+use reqwest::blocking::{Client, Request, RequestBuilder, Response};
+use reqwest::Method;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::{fmt, io};
+use url::Url;
+
+/// Stream to the daemon, e.g. a Unix socket or the standard streams of an ssh process.
+pub trait Connection: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Connection for T {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Endpoint {
+    Tcp,
+    Unix(PathBuf),
+    Ssh(SshEndpoint),
+}
+
+impl Endpoint {
+    pub(crate) fn connect(&self) -> io::Result<Box<dyn Connection>> {
+        match self {
+            Endpoint::Tcp => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "TCP connections are handled by the HTTP client"
+            )),
+            Endpoint::Unix(path) => {
+                let stream = UnixStream::connect(path)?;
+                Ok(Box::new(stream))
+            }
+            Endpoint::Ssh(endpoint) => {
+                let connection = endpoint.connect()?;
+                Ok(Box::new(connection))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TransportError {
+    Http(reqwest::Error),
+    Io(io::Error),
+    /// The operation is not available over this endpoint.
+    Unsupported(String),
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TransportError::Http(error) => write!(f, "http transport error: {}", error),
+            TransportError::Io(error) => write!(f, "io transport error: {}", error),
+            TransportError::Unsupported(message) => write!(f, "unsupported transport operation: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransportError::Http(error) => Some(error),
+            TransportError::Io(error) => Some(error),
+            TransportError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(value: reqwest::Error) -> Self {
+        TransportError::Http(value)
+    }
+}
+
+impl From<io::Error> for TransportError {
+    fn from(value: io::Error) -> Self {
+        TransportError::Io(value)
+    }
+}
+
+/// Implements `From<TransportError>` for the `Error<T>` of generated API modules.
+macro_rules! transport_error_conversion {
+    ($($module:ident)::+) => {
+        impl<T> From<$crate::api::transport::TransportError> for $($module)::+::Error<T> {
+            fn from(value: $crate::api::transport::TransportError) -> Self {
+                use $crate::api::transport::TransportError;
+                match value {
+                    TransportError::Http(error) => $($module)::+::Error::Reqwest(error),
+                    TransportError::Io(error) => $($module)::+::Error::Io(error),
+                    TransportError::Unsupported(message) => $($module)::+::Error::Io(
+                        std::io::Error::new(std::io::ErrorKind::Unsupported, message)
+                    ),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use transport_error_conversion;
+
+transport_error_conversion!(api_sync);
+
+/// Sends requests built by the generated API to the daemon endpoint.
+///
+/// Requests are always built with the reqwest client, so the generated code does not
+/// need to know about the endpoint. TCP endpoints are executed by reqwest itself, any
+/// other endpoint is served by a minimal HTTP/1.1 client over the endpoint stream, which
+/// keeps the connections alive between requests.
+#[derive(Debug, Clone)]
+pub struct Transport {
+    endpoint: Endpoint,
+    client: Client,
+    version: Arc<VersionNegotiation>,
+    registry_auths: Arc<RegistryAuths>,
+    connections: Arc<ConnectionPool>,
+    upgrade_tls: UpgradeTls,
+}
+
+impl Transport {
+    /// Requests are sent with the compiled API version, downgraded on first use if the daemon is older.
+    pub fn new(endpoint: Endpoint, client: Client) -> Self {
+        Self::with_api_version(endpoint, client, ApiVersion::compiled(), true)
+    }
+
+    /// Requests are sent with `version`, or with the negotiated version when `negotiate` is set.
+    pub fn with_api_version(endpoint: Endpoint, client: Client, version: ApiVersion, negotiate: bool) -> Self {
+        Self {
+            endpoint,
+            client,
+            version: Arc::new(VersionNegotiation::new(version, negotiate)),
+            registry_auths: Arc::new(RegistryAuths::new()),
+            connections: Arc::new(ConnectionPool::default()),
+            upgrade_tls: UpgradeTls::default(),
+        }
+    }
+
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Version in the base path of the requests, before negotiation rewrites it.
+    pub fn requested_api_version(&self) -> ApiVersion {
+        self.version.requested()
+    }
+
+    /// Registry credentials attached to pull, push, build and plugin requests.
+    pub fn registry_auths(&self) -> &Arc<RegistryAuths> {
+        &self.registry_auths
+    }
+
+    pub fn with_registry_auths(&mut self, registry_auths: Arc<RegistryAuths>) -> &mut Self {
+        self.registry_auths = registry_auths;
+        self
+    }
+
+    /// TLS of the hijacked and WebSocket connections to `https://` base paths.
+    pub(crate) fn with_upgrade_tls(&mut self, upgrade_tls: UpgradeTls) -> &mut Self {
+        self.upgrade_tls = upgrade_tls;
+        self
+    }
+
+    pub fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client.request(method, url)
+    }
+
+    pub fn execute(&self, mut request: Request) -> Result<Response, TransportError> {
+        self.authenticate(&mut request)?;
+        if self.version.split_path(request.url()).is_some() {
+            let version = self.api_version(request.url())?;
+            self.version.rewrite(request.url_mut(), version);
+        }
+        self.send(request)
+    }
+
+    /// API version used for requests sent under `base_path`, negotiated with the daemon if needed.
+    pub fn api_version(&self, base_path: &Url) -> Result<ApiVersion, TransportError> {
+        if let Some(version) = self.version.resolved() {
+            return Ok(version);
+        }
+        let ping_url = match self.version.ping_url(base_path) {
+            Some(value) => value,
+            None => return Ok(self.version.requested()),
+        };
+        let request = self.client.get(ping_url).build()?;
+        let response = self.send(request)?;
+        if response.status().is_success() == false {
+            // Not cached, the next request pings again.
+            return Ok(self.version.requested());
+        }
+        let daemon_version = response.headers()
+            .get(API_VERSION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<ApiVersion>().ok());
+        Ok(self.version.resolve(daemon_version))
+    }
+
+    fn authenticate(&self, request: &mut Request) -> Result<(), TransportError> {
+        let url = request.url().clone();
+        self.registry_auths.authenticate(&url, request.headers_mut())?;
+        Ok(())
+    }
+
+    fn send(&self, request: Request) -> Result<Response, TransportError> {
+        match self.endpoint {
+            Endpoint::Tcp => self.client.execute(request).map_err(TransportError::from),
+            _ => codec::send(&self.connections, || self.endpoint.connect(), request),
+        }
+    }
+}
+
+impl Default for Transport {
+    fn default() -> Self {
+        Self::new(Endpoint::Tcp, Client::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testing::MockDaemon;
+
+    fn get(transport: &Transport, url: &str) -> String {
+        let request = transport.request(Method::GET, url).build().unwrap();
+        transport.execute(request).unwrap().text().unwrap()
+    }
+
+    fn ping(version: &str) -> String {
+        format!("HTTP/1.1 200 OK\r\nAPI-Version: {}\r\nContent-Length: 2\r\n\r\nOK", version)
+    }
+
+    #[test]
+    fn downgrades_to_the_version_of_an_older_daemon() {
+        let daemon = MockDaemon::start(vec![
+            ping("1.43"),
+            MockDaemon::json(200, "{}"),
+            MockDaemon::json(200, "[]"),
+        ]);
+        let transport = Transport::with_api_version(daemon.endpoint(), Client::new(), ApiVersion::new(1, 47), true);
+        assert_eq!(get(&transport, "http://localhost/v1.47/info"), "{}");
+        assert_eq!(get(&transport, "http://localhost/v1.47/containers/json?all=true"), "[]");
+        let requests = daemon.requests().into_iter().map(|request| request.line).collect::<Vec<_>>();
+        assert_eq!(requests, ["GET /_ping", "GET /v1.43/info", "GET /v1.43/containers/json?all=true"]);
+    }
+
+    #[test]
+    fn keeps_the_requested_version_for_a_newer_daemon() {
+        let daemon = MockDaemon::start(vec![ping("1.48"), MockDaemon::json(200, "{}")]);
+        let transport = Transport::with_api_version(daemon.endpoint(), Client::new(), ApiVersion::new(1, 47), true);
+        get(&transport, "http://localhost/v1.47/info");
+        assert_eq!(transport.api_version(&Url::parse("http://localhost/v1.47").unwrap()).unwrap(), ApiVersion::new(1, 47));
+        assert_eq!(daemon.requests()[1].line, "GET /v1.47/info");
+    }
+
+    #[test]
+    fn downgrades_the_version_after_a_path_prefix() {
+        let daemon = MockDaemon::start(vec![ping("1.41"), MockDaemon::json(200, "{}")]);
+        let transport = Transport::with_api_version(daemon.endpoint(), Client::new(), ApiVersion::new(1, 47), true);
+        get(&transport, "http://localhost/docker/v1.47/info");
+        let requests = daemon.requests().into_iter().map(|request| request.line).collect::<Vec<_>>();
+        assert_eq!(requests, ["GET /docker/_ping", "GET /docker/v1.41/info"]);
+    }
+
+    #[test]
+    fn sends_a_pinned_version_without_negotiation() {
+        let daemon = MockDaemon::start(vec![MockDaemon::json(200, "{}")]);
+        let transport = Transport::with_api_version(daemon.endpoint(), Client::new(), ApiVersion::new(1, 45), false);
+        get(&transport, "http://localhost/v1.45/info");
+        assert_eq!(daemon.requests()[0].line, "GET /v1.45/info");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::api::version::ApiVersion;
+use std::sync::OnceLock;
+use url::Url;
+
+pub(crate) const API_VERSION_HEADER: &str = "API-Version";
+
+/// Version prefix used by the requests of a transport.
+///
+/// When negotiation is enabled, the prefix is downgraded on first use to the lowest of the
+/// requested version and the maximum version reported by the daemon on `/_ping`.
+#[derive(Debug)]
+pub(crate) struct VersionNegotiation {
+    requested: ApiVersion,
+    negotiate: bool,
+    negotiated: OnceLock<ApiVersion>,
+}
+
+impl VersionNegotiation {
+    pub(crate) fn new(requested: ApiVersion, negotiate: bool) -> Self {
+        Self {
+            requested,
+            negotiate,
+            negotiated: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn requested(&self) -> ApiVersion {
+        self.requested
+    }
+
+    /// Version to use without contacting the daemon, if already known.
+    pub(crate) fn resolved(&self) -> Option<ApiVersion> {
+        match self.negotiate {
+            true => self.negotiated.get().copied(),
+            false => Some(self.requested),
+        }
+    }
+
+    /// Settles the version from a successful ping, `daemon_version` is `None` for daemons
+    /// not reporting their version.
+    pub(crate) fn resolve(&self, daemon_version: Option<ApiVersion>) -> ApiVersion {
+        let version = match daemon_version {
+            Some(daemon_version) => daemon_version.min(self.requested),
+            None => self.requested,
+        };
+        *self.negotiated.get_or_init(|| version)
+    }
+
+    /// Splits the URL path around the requested version segment, `None` when it has none.
+    pub(crate) fn split_path<'a>(&self, url: &'a Url) -> Option<(&'a str, &'a str)> {
+        let segment = format!("/{}", self.requested.prefix());
+        let path = url.path();
+        let index = path.find(&segment)?;
+        let rest = &path[index + segment.len()..];
+        if rest.is_empty() == false && rest.starts_with('/') == false {
+            return None;
+        }
+        Some((&path[..index], rest))
+    }
+
+    /// URL of the unversioned ping endpoint next to the version segment.
+    pub(crate) fn ping_url(&self, url: &Url) -> Option<Url> {
+        let (root, _) = self.split_path(url)?;
+        let mut ping_url = url.clone();
+        ping_url.set_path(&format!("{}/_ping", root));
+        ping_url.set_query(None);
+        Some(ping_url)
+    }
+
+    /// Replaces the requested version segment with `version`.
+    pub(crate) fn rewrite(&self, url: &mut Url, version: ApiVersion) {
+        if version == self.requested {
+            return;
+        }
+        let path = match self.split_path(url) {
+            Some((root, rest)) => format!("{}/{}{}", root, version.prefix(), rest),
+            None => return,
+        };
+        url.set_path(&path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(value: &str) -> ApiVersion {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn negotiates_the_lowest_of_the_daemon_and_requested_versions() {
+        let negotiation = VersionNegotiation::new(version("1.47"), true);
+        assert_eq!(negotiation.resolved(), None);
+        assert_eq!(negotiation.resolve(Some(version("1.43"))), version("1.43"));
+        assert_eq!(negotiation.resolved(), Some(version("1.43")));
+        let negotiation = VersionNegotiation::new(version("1.47"), true);
+        assert_eq!(negotiation.resolve(Some(version("1.48"))), version("1.47"));
+        let negotiation = VersionNegotiation::new(version("1.47"), true);
+        assert_eq!(negotiation.resolve(None), version("1.47"));
+    }
+
+    #[test]
+    fn keeps_a_pinned_version() {
+        let negotiation = VersionNegotiation::new(version("1.45"), false);
+        assert_eq!(negotiation.resolved(), Some(version("1.45")));
+    }
+
+    #[test]
+    fn downgrades_the_version_segment_after_a_path_prefix() {
+        let negotiation = VersionNegotiation::new(version("1.47"), true);
+        let mut url = Url::parse("https://proxy/docker/v1.47/containers/json?all=true").unwrap();
+        assert_eq!(negotiation.ping_url(&url).unwrap().as_str(), "https://proxy/docker/_ping");
+        negotiation.rewrite(&mut url, version("1.43"));
+        assert_eq!(url.as_str(), "https://proxy/docker/v1.43/containers/json?all=true");
+        let mut url = Url::parse("http://localhost/v1.47").unwrap();
+        negotiation.rewrite(&mut url, version("1.43"));
+        assert_eq!(url.path(), "/v1.43");
+    }
+
+    #[test]
+    fn ignores_paths_without_the_version_segment() {
+        let negotiation = VersionNegotiation::new(version("1.47"), true);
+        for path in ["http://localhost/_ping", "http://localhost/v1.470/info", "http://localhost/v1.4/info"] {
+            let mut url = Url::parse(path).unwrap();
+            assert_eq!(negotiation.ping_url(&url), None);
+            negotiation.rewrite(&mut url, version("1.43"));
+            assert_eq!(url.as_str(), path);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::{env, fmt, io};
+use url::Url;
+
+const CONTROL_PERSIST: &str = "ControlPersist=60";
+
+/// Remote daemon reachable through `ssh://[user@]host[:port]`.
+///
+/// Like `docker -H ssh://`, the daemon socket is reached by running
+/// `docker system dial-stdio` on the remote host and speaking HTTP over the
+/// standard streams of the ssh process.
+///
+/// Every request runs its own `dial-stdio` session, the sessions share one authenticated
+/// connection per destination through ssh connection multiplexing, kept open for
+/// `CONTROL_PERSIST` after the last session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SshEndpoint {
+    user: Option<String>,
+    host: String,
+    port: Option<u16>,
+}
+
+impl SshEndpoint {
+    pub fn new(user: Option<String>, host: impl Into<String>, port: Option<u16>) -> Self {
+        Self {
+            user,
+            host: host.into(),
+            port,
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let url = Url::parse(value)
+            .map_err(|error| format!("Invalid ssh destination {}: {}", value, error))?;
+        if url.scheme() != "ssh" {
+            return Err(format!("Expected ssh scheme: {}", value));
+        }
+        if url.path().is_empty() == false && url.path() != "/" {
+            return Err(format!("Ssh destination should not have a path: {}", value));
+        }
+        let host = url.host_str()
+            .ok_or_else(|| format!("Ssh destination should have a host: {}", value))?;
+        let user = match url.username() {
+            "" => None,
+            user => Some(user.to_string()),
+        };
+        Ok(Self::new(user, host, url.port()))
+    }
+
+    pub(crate) fn connect(&self) -> io::Result<SshConnection> {
+        let mut command = Command::new("ssh");
+        command.args(["-o", "ConnectTimeout=30", "-T"]);
+        command.args(["-o", "ControlMaster=auto", "-o", CONTROL_PERSIST]);
+        command.args(["-o", &format!("ControlPath={}", Self::control_path())]);
+        if let Some(user) = self.user.as_ref() {
+            command.args(["-l", user]);
+        }
+        if let Some(port) = self.port {
+            command.args(["-p", &port.to_string()]);
+        }
+        command.args(["--", &self.host, "docker", "system", "dial-stdio"]);
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("Failed to capture ssh stdin");
+        let stdout = child.stdout.take().expect("Failed to capture ssh stdout");
+        Ok(SshConnection {
+            child,
+            stdin: Some(stdin),
+            stdout,
+        })
+    }
+
+    /// `%C` is a hash of the destination, one control socket per user, host and port.
+    fn control_path() -> String {
+        env::temp_dir()
+            .join("container-flow-ssh-%C")
+            .display()
+            .to_string()
+    }
+}
+
+impl Display for SshEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ssh://")?;
+        if let Some(user) = self.user.as_ref() {
+            write!(f, "{}@", user)?;
+        }
+        write!(f, "{}", self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct SshConnection {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    stdout: ChildStdout,
+}
+
+impl SshConnection {
+    /// Takes the write half, the connection then only reads and still owns the ssh process.
+    pub(crate) fn take_stdin(&mut self) -> Option<ChildStdin> {
+        self.stdin.take()
+    }
+
+    fn stdin(&mut self) -> io::Result<&mut ChildStdin> {
+        self.stdin.as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "Ssh stdin was taken"))
+    }
+}
+
+impl Read for SshConnection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stdout.read(buf)
+    }
+}
+
+impl Write for SshConnection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stdin()?.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdin()?.flush()
+    }
+}
+
+impl Drop for SshConnection {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
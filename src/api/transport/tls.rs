@@ -0,0 +1,520 @@
+use reqwest::blocking::ClientBuilder;
+use reqwest::{Certificate, Identity};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{ClientConfig, ClientConnection, DigitallySignedStruct, RootCertStore, SignatureScheme, StreamOwned};
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::{env, fs, io};
+
+/// TLS material used to reach a daemon protected by `--tlsverify`, e.g. `tcp://host:2376`.
+///
+/// Follows the docker CLI layout: a directory holding `ca.pem`, `cert.pem` and `key.pem`.
+///
+/// The default options trust the built-in roots and present no client certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsOptions {
+    ca_path: Option<PathBuf>,
+    cert_path: Option<PathBuf>,
+    key_path: Option<PathBuf>,
+    verify: bool,
+    server_name: Option<String>,
+}
+
+impl Default for TlsOptions {
+    fn default() -> Self {
+        Self {
+            ca_path: None,
+            cert_path: None,
+            key_path: None,
+            verify: true,
+            server_name: None,
+        }
+    }
+}
+
+impl TlsOptions {
+    pub fn from_cert_path(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref();
+        Self {
+            ca_path: Some(directory.join("ca.pem")),
+            cert_path: Some(directory.join("cert.pem")),
+            key_path: Some(directory.join("key.pem")),
+            ..Self::default()
+        }
+    }
+
+    /// Reads `DOCKER_TLS_VERIFY`, `DOCKER_TLS` and `DOCKER_CERT_PATH`.
+    ///
+    /// Returns `None` when neither `DOCKER_TLS_VERIFY` nor `DOCKER_TLS` is set. As with the
+    /// docker CLI, certificates default to `~/.docker` when `DOCKER_CERT_PATH` is not set.
+    pub fn from_env() -> Option<Self> {
+        let is_set = |name: &str| env::var(name).is_ok_and(|value| value.is_empty() == false);
+        let verify = is_set("DOCKER_TLS_VERIFY");
+        if verify == false && is_set("DOCKER_TLS") == false {
+            return None;
+        }
+        let cert_path = match env::var("DOCKER_CERT_PATH") {
+            Ok(value) if value.is_empty() == false => PathBuf::from(value),
+            _ => PathBuf::from(env::var("HOME").unwrap_or_default()).join(".docker"),
+        };
+        let mut options = Self::from_cert_path(cert_path);
+        options.with_verify(verify);
+        Some(options)
+    }
+
+    /// Trusts the certificates of `path` only, instead of the built-in roots.
+    pub fn with_ca_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.ca_path = Some(path.into());
+        self
+    }
+
+    /// Client certificate, presented along with the key of `with_key_path`.
+    pub fn with_cert_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.cert_path = Some(path.into());
+        self
+    }
+
+    pub fn with_key_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.key_path = Some(path.into());
+        self
+    }
+
+    pub fn with_verify(&mut self, verify: bool) -> &mut Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Name checked against the daemon certificate instead of the host of the base path.
+    pub fn with_server_name(&mut self, server_name: impl Into<String>) -> &mut Self {
+        self.server_name = Some(server_name.into());
+        self
+    }
+
+    pub fn ca_path(&self) -> Option<&Path> {
+        self.ca_path.as_deref()
+    }
+
+    pub fn cert_path(&self) -> Option<&Path> {
+        self.cert_path.as_deref()
+    }
+
+    pub fn key_path(&self) -> Option<&Path> {
+        self.key_path.as_deref()
+    }
+
+    pub fn verify(&self) -> bool {
+        self.verify
+    }
+
+    pub fn server_name(&self) -> Option<&String> {
+        self.server_name.as_ref()
+    }
+
+    /// Configures either client builder, see `TlsClientBuilder`.
+    pub(crate) fn apply<B: TlsClientBuilder>(&self, builder: B) -> io::Result<B> {
+        let (certificate, identity) = self.load()?;
+        let mut builder = builder.use_rustls();
+        if let Some(certificate) = certificate {
+            builder = builder.with_root_certificate(certificate);
+        }
+        if let Some(identity) = identity {
+            builder = builder.with_identity(identity);
+        }
+        Ok(builder.accept_invalid_certs(self.verify == false))
+    }
+
+    /// Configuration of the connections upgraded outside of the HTTP client, e.g. the hijacked
+    /// attach streams, and of the bollard client. The server name is checked by the caller.
+    pub(crate) fn client_config(&self) -> io::Result<Arc<ClientConfig>> {
+        let provider = Arc::new(ring::default_provider());
+        let builder = ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        let builder = match self.verify {
+            true => {
+                let mut roots = RootCertStore::empty();
+                match self.ca_pem()? {
+                    Some(pem) => {
+                        for certificate in CertificateDer::pem_slice_iter(&pem) {
+                            let certificate = certificate
+                                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                            roots.add(certificate)
+                                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                        }
+                    }
+                    None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+                }
+                builder.with_root_certificates(roots)
+            }
+            false => builder.dangerous()
+                .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider))),
+        };
+        let config = match self.identity_pem()? {
+            Some((cert, key)) => {
+                let chain = CertificateDer::pem_slice_iter(&cert)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                let key = PrivateKeyDer::from_pem_slice(&key)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                builder.with_client_auth_cert(chain, key)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        Ok(Arc::new(config))
+    }
+
+    /// Name the daemon certificate is checked against, the server name or else `host`.
+    pub(crate) fn verified_name(&self, host: &str) -> io::Result<ServerName<'static>> {
+        let name = self.server_name.as_deref().unwrap_or(host);
+        ServerName::try_from(name.trim_start_matches('[').trim_end_matches(']').to_string())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid server name {}: {}", name, error)))
+    }
+
+    /// Opens a connection outside of the HTTP client, e.g. to hijack it. The daemon certificate
+    /// is checked against the server name or else `host`.
+    pub(crate) fn connect(&self, address: impl ToSocketAddrs, host: &str) -> io::Result<TlsStream> {
+        let mut connection = ClientConnection::new(self.client_config()?, self.verified_name(host)?)
+            .map_err(io::Error::other)?;
+        let mut socket = TcpStream::connect(address)?;
+        while connection.is_handshaking() {
+            connection.complete_io(&mut socket)?;
+        }
+        Ok(StreamOwned::new(connection, socket))
+    }
+
+    fn load(&self) -> io::Result<(Option<Certificate>, Option<Identity>)> {
+        let certificate = match self.ca_pem()? {
+            Some(pem) => Some(Certificate::from_pem(&pem)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?),
+            None => None,
+        };
+        let identity = match self.identity_pem()? {
+            Some((mut cert, mut key)) => {
+                cert.push(b'\n');
+                cert.append(&mut key);
+                Some(Identity::from_pem(&cert)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?)
+            }
+            None => None,
+        };
+        Ok((certificate, identity))
+    }
+
+    /// With verification every file given is required, as with `--tlsverify`. Without it the
+    /// files present are used.
+    fn ca_pem(&self) -> io::Result<Option<Vec<u8>>> {
+        match self.ca_path.as_ref() {
+            Some(path) if self.verify || path.exists() => Self::read(path).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Certificate and key of the client, a certificate needs its key and the other way around.
+    fn identity_pem(&self) -> io::Result<Option<(Vec<u8>, Vec<u8>)>> {
+        let present = |path: &Option<PathBuf>| path.as_ref()
+            .is_some_and(|path| self.verify || path.exists());
+        if present(&self.cert_path) == false && present(&self.key_path) == false {
+            return Ok(None);
+        }
+        let read = |path: &Option<PathBuf>, name: &str| match path.as_ref() {
+            Some(path) => Self::read(path),
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("The client {} is not set", name))),
+        };
+        Ok(Some((read(&self.cert_path, "certificate")?, read(&self.key_path, "key")?)))
+    }
+
+    fn read(path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path)
+            .map_err(|error| io::Error::new(error.kind(), format!("Unable to read {}: {}", path.display(), error)))
+    }
+}
+
+pub(crate) type TlsStream = StreamOwned<ClientConnection, TcpStream>;
+
+/// Splits `stream` in halves usable from different threads, as the hijacked sessions do.
+///
+/// Both halves share the TLS state, each uses its own handle of the socket so a read waiting
+/// for the daemon does not block the writes.
+pub(crate) fn split(stream: TlsStream) -> io::Result<(TlsReadHalf, TlsWriteHalf)> {
+    let StreamOwned { conn, sock } = stream;
+    let connection = Arc::new(Mutex::new(conn));
+    let reader = TlsReadHalf {
+        connection: connection.clone(),
+        socket: sock.try_clone()?,
+        writer: sock.try_clone()?,
+        buffer: vec![0; 16 * 1024],
+    };
+    let writer = TlsWriteHalf {
+        connection,
+        socket: sock,
+    };
+    Ok((reader, writer))
+}
+
+/// Writes the pending TLS records, the caller holds the connection lock.
+fn write_records(connection: &mut ClientConnection, socket: &mut TcpStream) -> io::Result<()> {
+    while connection.wants_write() {
+        connection.write_tls(socket)?;
+    }
+    Ok(())
+}
+
+pub(crate) struct TlsReadHalf {
+    connection: Arc<Mutex<ClientConnection>>,
+    socket: TcpStream,
+    /// Handle used to answer the records read, e.g. alerts.
+    writer: TcpStream,
+    buffer: Vec<u8>,
+}
+
+impl Read for TlsReadHalf {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            match self.connection.lock().unwrap().reader().read(buf) {
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => {}
+                result => return result,
+            }
+            let read = self.socket.read(&mut self.buffer)?;
+            let mut connection = self.connection.lock().unwrap();
+            if read == 0 {
+                // The daemon closed the socket without a close_notify alert.
+                return Ok(0);
+            }
+            let mut records = &self.buffer[..read];
+            while records.is_empty() == false {
+                connection.read_tls(&mut records)?;
+                connection.process_new_packets()
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            }
+            write_records(&mut connection, &mut self.writer)?;
+        }
+    }
+}
+
+pub(crate) struct TlsWriteHalf {
+    connection: Arc<Mutex<ClientConnection>>,
+    socket: TcpStream,
+}
+
+impl TlsWriteHalf {
+    /// Sends a close_notify alert and shuts the socket down for writes, the daemon can still
+    /// send the remaining output.
+    pub(crate) fn shutdown(&mut self) -> io::Result<()> {
+        let mut connection = self.connection.lock().unwrap();
+        connection.send_close_notify();
+        write_records(&mut connection, &mut self.socket)?;
+        self.socket.shutdown(Shutdown::Write)
+    }
+}
+
+impl Write for TlsWriteHalf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut connection = self.connection.lock().unwrap();
+        let written = connection.writer().write(buf)?;
+        write_records(&mut connection, &mut self.socket)?;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut connection = self.connection.lock().unwrap();
+        connection.writer().flush()?;
+        write_records(&mut connection, &mut self.socket)?;
+        self.socket.flush()
+    }
+}
+
+/// Verifier of `TlsOptions::with_verify(false)`, the daemon certificate is trusted as is.
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// TLS settings shared by the blocking and async reqwest client builders.
+pub(crate) trait TlsClientBuilder: Sized {
+    fn use_rustls(self) -> Self;
+    /// Trusts `certificate` only, instead of the built-in roots.
+    fn with_root_certificate(self, certificate: Certificate) -> Self;
+    fn with_identity(self, identity: Identity) -> Self;
+    fn accept_invalid_certs(self, accept: bool) -> Self;
+}
+
+impl TlsClientBuilder for ClientBuilder {
+    fn use_rustls(self) -> Self {
+        self.use_rustls_tls()
+    }
+
+    fn with_root_certificate(self, certificate: Certificate) -> Self {
+        self.tls_built_in_root_certs(false)
+            .add_root_certificate(certificate)
+    }
+
+    fn with_identity(self, identity: Identity) -> Self {
+        self.identity(identity)
+    }
+
+    fn accept_invalid_certs(self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs(accept)
+    }
+}
+
+impl TlsClientBuilder for reqwest::ClientBuilder {
+    fn use_rustls(self) -> Self {
+        self.use_rustls_tls()
+    }
+
+    fn with_root_certificate(self, certificate: Certificate) -> Self {
+        self.tls_built_in_root_certs(false)
+            .add_root_certificate(certificate)
+    }
+
+    fn with_identity(self, identity: Identity) -> Self {
+        self.identity(identity)
+    }
+
+    fn accept_invalid_certs(self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs(accept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::asynchronous::connect_with_tls;
+    use crate::api::testing::{tls_material, MockDaemon, TempDirectory, TlsDaemon};
+    use rustls::{ClientConnection, StreamOwned};
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    const SERVER_NAME: &str = "docker.example.test";
+
+    fn handshake(tls: &TlsOptions, daemon: &TlsDaemon) -> io::Result<String> {
+        let connection = ClientConnection::new(tls.client_config()?, tls.verified_name("127.0.0.1")?)
+            .map_err(io::Error::other)?;
+        let mut stream = StreamOwned::new(connection, TcpStream::connect(daemon.address())?);
+        stream.write_all(b"GET /_ping HTTP/1.1\r\nHost: docker\r\n\r\n")?;
+        // The daemon keeps the connection alive, the response is read up to its body.
+        let mut response = Vec::new();
+        let mut buffer = [0; 1024];
+        while response.ends_with(b"\"OK\"") == false {
+            let read = stream.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            response.extend_from_slice(&buffer[..read]);
+        }
+        Ok(String::from_utf8_lossy(&response).to_string())
+    }
+
+    #[test]
+    fn requires_every_file_when_verifying() {
+        let directory = TempDirectory::new("tls");
+        let error = TlsOptions::from_cert_path(directory.path()).load().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("ca.pem"));
+
+        fs::copy(tls_material().join("ca.pem"), directory.path().join("ca.pem")).unwrap();
+        let error = TlsOptions::from_cert_path(directory.path()).client_config().unwrap_err();
+        assert!(error.to_string().contains("cert.pem"));
+    }
+
+    #[test]
+    fn uses_the_files_present_without_verification() {
+        let directory = TempDirectory::new("tls");
+        fs::copy(tls_material().join("ca.pem"), directory.path().join("ca.pem")).unwrap();
+        let mut tls = TlsOptions::from_cert_path(directory.path());
+        tls.with_verify(false);
+        let (certificate, identity) = tls.load().unwrap();
+        assert!(certificate.is_some());
+        assert!(identity.is_none());
+        assert!(tls.client_config().is_ok());
+    }
+
+    #[test]
+    fn requires_the_key_of_a_client_certificate() {
+        let mut tls = TlsOptions::default();
+        tls.with_cert_path(tls_material().join("cert.pem"));
+        let error = tls.load().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(tls.client_config().is_err());
+    }
+
+    #[test]
+    fn loads_the_docker_cli_material() {
+        let tls = TlsOptions::from_cert_path(tls_material());
+        let (certificate, identity) = tls.load().unwrap();
+        assert!(certificate.is_some());
+        assert!(identity.is_some());
+        assert!(tls.apply(reqwest::blocking::ClientBuilder::new()).unwrap().build().is_ok());
+    }
+
+    #[test]
+    fn checks_the_daemon_certificate_against_the_server_name() {
+        let daemon = TlsDaemon::start(vec![
+            MockDaemon::json(200, "\"OK\""),
+            MockDaemon::json(200, "\"OK\""),
+        ]);
+        let mut tls = TlsOptions::from_cert_path(tls_material());
+        assert!(handshake(&tls, &daemon).is_err());
+
+        tls.with_server_name(SERVER_NAME);
+        assert!(handshake(&tls, &daemon).unwrap().ends_with("\"OK\""));
+
+        let mut tls = TlsOptions::from_cert_path(tls_material());
+        tls.with_ca_path(tls_material().join("server-cert.pem"));
+        tls.with_verify(false);
+        assert!(handshake(&tls, &daemon).unwrap().ends_with("\"OK\""));
+        assert_eq!(daemon.requests().len(), 2);
+    }
+
+    #[test]
+    fn connects_bollard_with_the_server_name() {
+        let daemon = TlsDaemon::start(vec![
+            format!("HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nOK"),
+        ]);
+        let mut tls = TlsOptions::from_cert_path(tls_material());
+        tls.with_server_name(SERVER_NAME);
+        let docker = connect_with_tls(&format!("tcp://{}", daemon.address()), &tls).unwrap();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(runtime.block_on(docker.ping()).unwrap(), "OK");
+        assert_eq!(daemon.requests()[0].line, "GET /_ping");
+    }
+}
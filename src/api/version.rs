@@ -0,0 +1,52 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::str::FromStr;
+
+/// Docker Engine API version, e.g. `1.47`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ApiVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self {
+            major,
+            minor,
+        }
+    }
+
+    /// Version compiled into the client, from the selected specification.
+    pub fn compiled() -> Self {
+        crate::api::API_VERSION.parse()
+            .expect("Invalid compiled API version")
+    }
+
+    /// Path segment prefixing every endpoint, e.g. `v1.47`.
+    pub fn prefix(&self) -> String {
+        format!("v{}", self)
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = String;
+
+    /// Accepts `1.47` and `v1.47`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let version = value.trim().trim_start_matches('v');
+        let (major, minor) = version.split_once('.')
+            .ok_or_else(|| format!("Invalid API version: {}", value))?;
+        let major = major.parse::<u32>()
+            .map_err(|_| format!("Invalid API version: {}", value))?;
+        let minor = minor.parse::<u32>()
+            .map_err(|_| format!("Invalid API version: {}", value))?;
+        Ok(Self::new(major, minor))
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
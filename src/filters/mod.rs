@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `map[string][]string` accepted as JSON by the `filters` parameter of list, prune and events
+/// endpoints. Typed builders such as `ContainerListFilters` are generated from the filter keys
+/// documented in the specification, the params builders take them for their `filters` field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Filters {
+    values: BTreeMap<String, Vec<String>>,
+}
+
+impl Filters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value to `key`, values of the same key are combined by the daemon.
+    pub fn with(&mut self, key: &str, value: impl Into<String>) -> &mut Self {
+        self.values.entry(key.to_string())
+            .or_default()
+            .push(value.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Vec<String>> {
+        self.values.get(key)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self)
+            .expect("Filters should serialize to JSON")
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+impl From<Filters> for String {
+    fn from(value: Filters) -> Self {
+        value.to_json()
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/filters.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_values_by_key() {
+        let mut filters = Filters::new();
+        filters.with("status", "running")
+            .with("status", "paused")
+            .with("label", "com.example=1");
+        assert_eq!(filters.to_json(), r#"{"label":["com.example=1"],"status":["running","paused"]}"#);
+        assert_eq!(Filters::new().to_json(), "{}");
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut filters = Filters::new();
+        filters.with("dangling", "true")
+            .with("reference", "nginx:*");
+        let parsed = Filters::from_json(&filters.to_json()).unwrap();
+        assert_eq!(parsed, filters);
+        assert_eq!(parsed.get("reference"), Some(&vec!["nginx:*".to_string()]));
+    }
+
+    #[test]
+    fn typed_filters_convert_to_the_parameter_value() {
+        let mut filters = ContainerListFilters::new();
+        filters.status(ContainerListStatus::Running)
+            .label("com.example=1");
+        let value: String = filters.into();
+        let parsed = Filters::from_json(&value).unwrap();
+        assert_eq!(parsed.get("status"), Some(&vec!["running".to_string()]));
+        assert_eq!(parsed.get("label"), Some(&vec!["com.example=1".to_string()]));
+    }
+}
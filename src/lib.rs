@@ -1,4 +1,22 @@
+// Idioms of the builders and APIs: negations are written `== false`, easier to spot than a
+// leading `!`, optional collections are created with `if let None = ...`, options are assigned
+// field by field on a default value and the APIs take `&String` ids and `&PathBuf` paths.
+#![allow(
+    clippy::bool_comparison,
+    clippy::redundant_pattern_matching,
+    clippy::field_reassign_with_default,
+    clippy::ptr_arg
+)]
+
 pub mod api;
+// The models and builders are generated, see resources/tools/generator.
+#[allow(clippy::derivable_impls, clippy::empty_docs, clippy::too_many_arguments)]
 pub mod models;
+#[allow(clippy::clone_on_copy, clippy::useless_conversion)]
 pub mod builders;
-mod params;
\ No newline at end of file
+pub mod params;
+pub mod filters;
+pub mod versions;
+
+#[cfg(feature = "libpod")]
+pub mod libpod;
@@ -0,0 +1,122 @@
+//! Podman libpod API, enabled by the `libpod` feature.
+//!
+//! Pods, `play kube` and manifest lists are only reachable through the libpod endpoints. Clients
+//! are created from the root clients, so they share the transport, e.g.
+//! `libpod::synchronous::PodmanClient::from(&client)`.
+
+use crate::api::transport::transport_error_conversion;
+
+/// Podman REST API version the libpod specification was generated from, e.g. `v5.0.0`.
+pub const API_VERSION: &str = env!("CONTAINER_FLOW_LIBPOD_API_VERSION");
+
+#[allow(clippy::derivable_impls, clippy::empty_docs, clippy::too_many_arguments)]
+pub mod models {
+    include!(concat!(env!("OUT_DIR"), "/libpod/models.rs"));
+}
+
+pub mod params {
+    include!(concat!(env!("OUT_DIR"), "/libpod/params.rs"));
+}
+
+#[allow(dead_code, clippy::into_iter_on_ref, clippy::enum_variant_names, clippy::result_large_err)]
+pub(crate) mod api_sync {
+    use super::params::*;
+    include!(concat!(env!("OUT_DIR"), "/libpod/api-sync.rs"));
+}
+
+#[allow(dead_code, clippy::into_iter_on_ref, clippy::enum_variant_names, clippy::result_large_err)]
+pub(crate) mod api_async {
+    use super::params::*;
+    include!(concat!(env!("OUT_DIR"), "/libpod/api-async.rs"));
+}
+
+transport_error_conversion!(api_sync);
+transport_error_conversion!(api_async);
+
+/// Replaces the Docker version segment of a root client base path with the libpod one.
+fn rebase_path(base_path: &str, root_version: &str) -> String {
+    let segment = format!("/{}", root_version);
+    match base_path.rfind(&segment) {
+        Some(index) => format!("{}/{}{}", &base_path[..index], API_VERSION, &base_path[index + segment.len()..]),
+        None => base_path.to_string(),
+    }
+}
+
+// Defines `libpod_apis!`, generated from the tags of the specification.
+include!(concat!(env!("OUT_DIR"), "/libpod/clients.rs"));
+
+macro_rules! podman_client {
+    ($api:ident, $root_client:path, $($field:ident: $type:ident),*) => {
+        pub struct PodmanClient {
+            $(pub $field: $api::$type,)*
+        }
+
+        impl PodmanClient {
+            fn new(configuration: $api::Configuration) -> Self {
+                let configuration = std::sync::Arc::new(configuration);
+                Self {
+                    $($field: $api::$type::new(configuration.clone()),)*
+                }
+            }
+        }
+
+        impl From<&$root_client> for PodmanClient {
+            fn from(value: &$root_client) -> Self {
+                let configuration = value.configuration();
+                Self::new($api::Configuration {
+                    base_path: super::rebase_path(
+                        &configuration.base_path,
+                        &configuration.client.requested_api_version().prefix()
+                    ),
+                    user_agent: configuration.user_agent.clone(),
+                    client: configuration.client.clone(),
+                })
+            }
+        }
+    };
+}
+
+pub mod synchronous {
+    use super::api_sync;
+
+    libpod_apis!(podman_client, api_sync, crate::api::synchronous::ContainerClient);
+}
+
+pub mod asynchronous {
+    use super::api_async;
+
+    libpod_apis!(podman_client, api_async, crate::api::asynchronous::ContainerClient);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::internals::api_sync::Configuration;
+    use crate::api::synchronous::ContainerClient;
+    use crate::api::testing::MockDaemon;
+    use crate::api::transport::Transport;
+    use crate::api::version::ApiVersion;
+    use reqwest::blocking::Client;
+
+    #[test]
+    fn rebases_the_version_segment_of_the_root_base_path() {
+        assert_eq!(rebase_path("http://localhost/v1.47", "v1.47"), format!("http://localhost/{}", API_VERSION));
+        assert_eq!(rebase_path("http://localhost/docker/v1.47", "v1.47"), format!("http://localhost/docker/{}", API_VERSION));
+        assert_eq!(rebase_path("http://localhost", "v1.47"), "http://localhost");
+    }
+
+    #[test]
+    fn lists_pods_through_the_transport_of_the_root_client() {
+        let daemon = MockDaemon::start(vec![MockDaemon::json(200, r#"[{"Id":"abc","Name":"web","Status":"Running"}]"#)]);
+        let client = ContainerClient::new(Configuration {
+            base_path: "http://localhost/v1.47".to_string(),
+            user_agent: None,
+            client: Transport::with_api_version(daemon.endpoint(), Client::new(), ApiVersion::new(1, 47), false),
+        });
+        let podman = synchronous::PodmanClient::from(&client);
+        let pods = podman.pod.list(params::PodListLibpodParams { filters: None }).unwrap();
+        assert_eq!(pods.len(), 1);
+        assert_eq!(pods[0].name.as_deref(), Some("web"));
+        assert_eq!(daemon.requests()[0].line, format!("GET /{}/libpod/pods/json", API_VERSION));
+    }
+}
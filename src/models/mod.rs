@@ -39,4 +39,17 @@ impl ExposedPort {
             protocol
         }
     }
-}
\ No newline at end of file
+}
+impl Display for ExposedPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let protocol: &str = self.protocol.clone().into();
+        write!(f, "{}/{}", self.port, protocol)
+    }
+}
+
+/// Key of the `ExposedPorts` map, e.g. `80/tcp`.
+impl From<ExposedPort> for String {
+    fn from(value: ExposedPort) -> Self {
+        value.to_string()
+    }
+}
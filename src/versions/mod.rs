@@ -0,0 +1,158 @@
+//! APIs generated for the additional versions enabled through the `v1_*` features.
+//!
+//! The highest enabled version lives at the crate root, every other enabled version gets
+//! a module here with its own models, params, filters and clients. Clients are created from
+//! the root clients, so they share the transport, and can be picked at runtime from the negotiated
+//! version, e.g. `v1_41::synchronous::ContainerClient::from(&client)`.
+//!
+//! A version needs its specification in `resources/specs`, a `v1_XX_0` feature, an entry in
+//! `SUPPORTED_API_VERSIONS` of `build.rs` and an `api_version!` line below.
+
+/// Replaces the `root_version` segment of a root client base path with `api_version`.
+///
+/// `root_version` is the version the root transport was built with, which may differ from the
+/// compiled one when pinned with `ContainerClientBuilder::with_api_version`.
+#[cfg_attr(not(secondary_api_version), allow(dead_code))]
+fn rebase_path(base_path: &str, root_version: &str, api_version: &str) -> String {
+    let segment = format!("/{}", root_version);
+    match base_path.rfind(&segment) {
+        Some(index) => format!("{}/{}{}", &base_path[..index], api_version, &base_path[index + segment.len()..]),
+        None => base_path.to_string(),
+    }
+}
+
+#[cfg(secondary_api_version)]
+macro_rules! container_client {
+    ($api:ident, $root_client:path) => {
+        pub struct ContainerClient {
+            pub config: $api::ConfigApi,
+            pub container: $api::ContainerApi,
+            pub distribution: $api::DistributionApi,
+            pub exec: $api::ExecApi,
+            pub image: $api::ImageApi,
+            pub network: $api::NetworkApi,
+            pub node: $api::NodeApi,
+            pub plugin: $api::PluginApi,
+            pub secret: $api::SecretApi,
+            pub service: $api::ServiceApi,
+            pub session: $api::SessionApi,
+            pub swarm: $api::SwarmApi,
+            pub system: $api::SystemApi,
+            pub task: $api::TaskApi,
+            pub volume: $api::VolumeApi,
+        }
+
+        impl ContainerClient {
+            fn new(configuration: $api::Configuration) -> Self {
+                let configuration = std::sync::Arc::new(configuration);
+                Self {
+                    config: $api::ConfigApi::new(configuration.clone()),
+                    container: $api::ContainerApi::new(configuration.clone()),
+                    distribution: $api::DistributionApi::new(configuration.clone()),
+                    exec: $api::ExecApi::new(configuration.clone()),
+                    image: $api::ImageApi::new(configuration.clone()),
+                    network: $api::NetworkApi::new(configuration.clone()),
+                    node: $api::NodeApi::new(configuration.clone()),
+                    plugin: $api::PluginApi::new(configuration.clone()),
+                    secret: $api::SecretApi::new(configuration.clone()),
+                    service: $api::ServiceApi::new(configuration.clone()),
+                    session: $api::SessionApi::new(configuration.clone()),
+                    swarm: $api::SwarmApi::new(configuration.clone()),
+                    system: $api::SystemApi::new(configuration.clone()),
+                    task: $api::TaskApi::new(configuration.clone()),
+                    volume: $api::VolumeApi::new(configuration),
+                }
+            }
+        }
+
+        impl From<&$root_client> for ContainerClient {
+            fn from(value: &$root_client) -> Self {
+                let configuration = value.configuration();
+                Self::new($api::Configuration {
+                    base_path: crate::versions::rebase_path(
+                        &configuration.base_path,
+                        &configuration.client.requested_api_version().prefix(),
+                        API_VERSION
+                    ),
+                    user_agent: configuration.user_agent.clone(),
+                    client: configuration.client.clone(),
+                })
+            }
+        }
+    };
+}
+
+macro_rules! api_version {
+    ($name:ident, $module:literal, $version:literal) => {
+        #[cfg(secondary_api_version = $module)]
+        pub mod $name {
+            /// API version of this module, e.g. `v1.41`.
+            pub const API_VERSION: &str = $version;
+
+            pub mod models {
+                include!(concat!(env!("OUT_DIR"), "/", $module, "/models.rs"));
+            }
+
+            pub mod params {
+                include!(concat!(env!("OUT_DIR"), "/", $module, "/params.rs"));
+            }
+
+            pub mod filters {
+                use crate::filters::Filters;
+                include!(concat!(env!("OUT_DIR"), "/", $module, "/filters.rs"));
+            }
+
+            pub(crate) mod api_sync {
+                use super::params::*;
+                include!(concat!(env!("OUT_DIR"), "/", $module, "/api-sync.rs"));
+            }
+
+            pub(crate) mod api_async {
+                use super::params::*;
+                include!(concat!(env!("OUT_DIR"), "/", $module, "/api-async.rs"));
+            }
+
+            crate::api::transport::transport_error_conversion!(api_sync);
+            crate::api::transport::transport_error_conversion!(api_async);
+
+            pub mod synchronous {
+                use super::{api_sync, API_VERSION};
+
+                container_client!(api_sync, crate::api::synchronous::ContainerClient);
+            }
+
+            pub mod asynchronous {
+                use super::{api_async, API_VERSION};
+
+                container_client!(api_async, crate::api::asynchronous::ContainerClient);
+            }
+        }
+    };
+}
+
+api_version!(v1_41, "v1_41", "v1.41");
+api_version!(v1_42, "v1_42", "v1.42");
+api_version!(v1_43, "v1_43", "v1.43");
+api_version!(v1_44, "v1_44", "v1.44");
+api_version!(v1_45, "v1_45", "v1.45");
+api_version!(v1_46, "v1_46", "v1.46");
+api_version!(v1_47, "v1_47", "v1.47");
+api_version!(v1_48, "v1_48", "v1.48");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebases_the_version_segment() {
+        assert_eq!(rebase_path("http://localhost/v1.47", "v1.47", "v1.41"), "http://localhost/v1.41");
+        assert_eq!(rebase_path("http://localhost/v1.45/", "v1.45", "v1.41"), "http://localhost/v1.41/");
+        assert_eq!(rebase_path("http://localhost:2375/v1.47", "v1.47", "v1.43"), "http://localhost:2375/v1.43");
+    }
+
+    #[test]
+    fn keeps_base_paths_without_the_version() {
+        assert_eq!(rebase_path("http://localhost", "v1.47", "v1.41"), "http://localhost");
+        assert_eq!(rebase_path("http://localhost/v1.44", "v1.47", "v1.41"), "http://localhost/v1.44");
+    }
+}